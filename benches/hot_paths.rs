@@ -0,0 +1,229 @@
+//! Benchmarks for hot paths
+//!
+//! Covers the work done for every command or on large workspaces: envelope
+//! wrapping, `conv list` filter/sort on 10k channels, mention resolution,
+//! users cache load/save and JSONL streaming through the output queue.
+//! The performance budget for each benchmark is documented in
+//! CONTRIBUTING.md; run with `cargo bench --bench hot_paths`.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use serde_json::{json, Value};
+use slack_rs::api::{ApiResponse, CommandResponse};
+use slack_rs::commands::users_cache::{
+    resolve_mentions, CachedUser, MentionFormat, UsersCacheFile, WorkspaceCache,
+};
+use slack_rs::commands::{
+    apply_filters, format_response, sort_conversations, ConversationFilter, OutputFormat,
+    SortDirection, SortKey,
+};
+use slack_rs::stream::{OutputQueue, StreamConfig};
+use std::collections::HashMap;
+use std::io;
+
+const CHANNELS: usize = 10_000;
+const USERS: usize = 5_000;
+const STREAM_LINES: usize = 10_000;
+
+fn channel(i: usize) -> Value {
+    json!({
+        "id": format!("C{:08}", i),
+        "name": format!("team-{}-channel-{}", i % 97, i),
+        "is_private": i.is_multiple_of(5),
+        "is_member": i.is_multiple_of(3),
+        "created": 1_600_000_000 + (i * 7919 % CHANNELS) as u64,
+        "num_members": (i * 31) % 500,
+        "topic": {"value": "Topic text", "creator": "U00000001", "last_set": 0},
+        "purpose": {"value": "Purpose text", "creator": "U00000001", "last_set": 0},
+    })
+}
+
+fn conv_list_response() -> ApiResponse {
+    let channels: Vec<Value> = (0..CHANNELS).map(channel).collect();
+    ApiResponse {
+        ok: true,
+        data: HashMap::from([
+            ("channels".to_string(), Value::Array(channels)),
+            (
+                "response_metadata".to_string(),
+                json!({"next_cursor": "dGVhbTpDMDA0"}),
+            ),
+        ]),
+        error: None,
+    }
+}
+
+fn workspace_cache() -> WorkspaceCache {
+    let users = (0..USERS)
+        .map(|i| {
+            let id = format!("U{:08}", i);
+            let user = CachedUser {
+                id: id.clone(),
+                name: format!("user{}", i),
+                real_name: Some(format!("User Number {}", i)),
+                display_name: Some(format!("user-{}", i)),
+                deleted: false,
+                is_bot: false,
+                is_restricted: false,
+                is_ultra_restricted: false,
+                team_id: None,
+                tz: Some("Asia/Tokyo".to_string()),
+                tz_offset: Some(32400),
+            };
+            (id, user)
+        })
+        .collect();
+    WorkspaceCache {
+        team_id: "T123ABC".to_string(),
+        updated_at: 1_700_000_000,
+        users,
+    }
+}
+
+/// About 64 KiB of text with a mention every few words
+fn mention_text() -> String {
+    let mut text = String::new();
+    let mut i = 0;
+    while text.len() < 64 * 1024 {
+        text.push_str(&format!(
+            "please review <@U{:08}> and <@U{:08}|someone> before the deploy window. ",
+            i % USERS,
+            (i * 13) % (USERS * 2)
+        ));
+        i += 1;
+    }
+    text
+}
+
+fn bench_envelope(c: &mut Criterion) {
+    let data = serde_json::to_value(conv_list_response()).unwrap();
+    c.bench_function("envelope/wrap_conv_list_10k", |b| {
+        b.iter(|| {
+            let response = CommandResponse::new(
+                black_box(data.clone()),
+                Some("acme".to_string()),
+                "T123ABC".to_string(),
+                "U456DEF".to_string(),
+                "conversations.list".to_string(),
+                "conv list".to_string(),
+            );
+            serde_json::to_string(&response).unwrap()
+        })
+    });
+}
+
+fn bench_conv_filter_sort(c: &mut Criterion) {
+    let filters = [
+        ConversationFilter::parse("name:team-1*").unwrap(),
+        ConversationFilter::parse("is_private:false").unwrap(),
+    ];
+
+    let mut group = c.benchmark_group("conv");
+    group.throughput(Throughput::Elements(CHANNELS as u64));
+    group.bench_function("filter_10k", |b| {
+        b.iter_batched(
+            conv_list_response,
+            |mut response| {
+                apply_filters(&mut response, black_box(&filters));
+                response
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("sort_name_10k", |b| {
+        b.iter_batched(
+            conv_list_response,
+            |mut response| {
+                sort_conversations(&mut response, SortKey::Name, SortDirection::Asc);
+                response
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("sort_num_members_10k", |b| {
+        b.iter_batched(
+            conv_list_response,
+            |mut response| {
+                sort_conversations(&mut response, SortKey::NumMembers, SortDirection::Desc);
+                response
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_mentions(c: &mut Criterion) {
+    let cache = workspace_cache();
+    let text = mention_text();
+
+    let mut group = c.benchmark_group("mentions");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("resolve_64k", |b| {
+        b.iter(|| resolve_mentions(black_box(&text), &cache, MentionFormat::DisplayName))
+    });
+    group.finish();
+}
+
+fn bench_users_cache(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("users_cache.json");
+    let mut file = UsersCacheFile::new();
+    file.set_workspace(workspace_cache());
+    file.save(&path).unwrap();
+
+    let mut group = c.benchmark_group("users_cache");
+    group.throughput(Throughput::Elements(USERS as u64));
+    group.bench_function("load_5k", |b| {
+        b.iter(|| UsersCacheFile::load(black_box(&path)).unwrap())
+    });
+    group.bench_function("save_5k", |b| {
+        b.iter(|| file.save(black_box(&path)).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_jsonl_streaming(c: &mut Criterion) {
+    let response = conv_list_response();
+    let lines: Vec<String> = (0..STREAM_LINES)
+        .map(|i| {
+            json!({
+                "type": "message",
+                "channel": "C001",
+                "ts": format!("{}.{:06}", 1_700_000_000 + i, i),
+                "text": "hello",
+            })
+            .to_string()
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("jsonl");
+    group.throughput(Throughput::Elements(CHANNELS as u64));
+    group.bench_function("format_conv_list_10k", |b| {
+        b.iter(|| format_response(black_box(&response), OutputFormat::Jsonl).unwrap())
+    });
+    group.throughput(Throughput::Elements(STREAM_LINES as u64));
+    group.bench_function("output_queue_10k", |b| {
+        b.iter_batched(
+            || lines.clone(),
+            |lines| {
+                let queue = OutputQueue::start(StreamConfig::default(), io::sink());
+                for line in lines {
+                    queue.push(line).unwrap();
+                }
+                queue.finish().unwrap();
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_envelope,
+    bench_conv_filter_sort,
+    bench_mentions,
+    bench_users_cache,
+    bench_jsonl_streaming
+);
+criterion_main!(benches);
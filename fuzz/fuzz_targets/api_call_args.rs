@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use slack_rs::api::ApiCallArgs;
+
+// Arguments are split on NUL bytes, so one input becomes an argv
+fuzz_target!(|data: &str| {
+    let args: Vec<String> = data.split('\0').map(str::to_string).collect();
+    if let Ok(parsed) = ApiCallArgs::parse(&args) {
+        assert_eq!(parsed.method, args[0]);
+        for (key, value) in &parsed.params {
+            assert!(args.iter().any(|a| a == &format!("{}={}", key, value)));
+        }
+    }
+});
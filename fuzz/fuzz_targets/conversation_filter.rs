@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use slack_rs::commands::{ConversationFilter, SortDirection, SortKey};
+
+fuzz_target!(|data: &str| {
+    if let Ok(filter) = ConversationFilter::parse(data) {
+        let reparsed = ConversationFilter::parse(&filter.to_string()).unwrap();
+        assert_eq!(reparsed, filter);
+    }
+    let _ = SortKey::parse(data);
+    let _ = SortDirection::parse(data);
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use slack_rs::commands::thread_ref::parse_permalink;
+use slack_rs::commands::ThreadRef;
+
+fuzz_target!(|data: &str| {
+    if let Some(ThreadRef::Permalink { ts, .. }) = parse_permalink(data) {
+        let (secs, micros) = ts.split_once('.').unwrap();
+        assert_eq!(micros.len(), 6);
+        assert!(!secs.is_empty());
+    }
+    let _ = ThreadRef::parse(data);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use chrono::{TimeZone, Utc};
+use libfuzzer_sys::fuzz_target;
+use slack_rs::commands::schedule::{parse_at, parse_duration};
+
+fuzz_target!(|data: &str| {
+    if let Ok(seconds) = parse_duration(data) {
+        assert!(seconds > 0);
+    }
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let _ = parse_at(data, &now);
+});
@@ -3,7 +3,7 @@
 //! Parses command-line arguments into structured API call parameters:
 //! - Method name (e.g., "chat.postMessage")
 //! - Key-value pairs (e.g., "channel=C123456" "text=hello")
-//! - Flags: --json, --get
+//! - Flags: --json, --get, --get-url, --all/--paginate
 
 use crate::profile::TokenType;
 use serde_json::{json, Value};
@@ -44,6 +44,12 @@ pub struct ApiCallArgs {
 
     /// Output raw Slack API response without envelope
     pub raw: bool,
+
+    /// Print the fully-resolved request instead of sending it
+    pub get_url: bool,
+
+    /// Follow `response_metadata.next_cursor` and merge all pages
+    pub paginate: bool,
 }
 
 impl ApiCallArgs {
@@ -58,6 +64,8 @@ impl ApiCallArgs {
         let mut use_json = false;
         let mut use_get = false;
         let mut token_type = None;
+        let mut get_url = false;
+        let mut paginate = false;
 
         // Check SLACKRS_OUTPUT environment variable for default output mode
         // --raw flag will override this
@@ -74,6 +82,10 @@ impl ApiCallArgs {
                 use_json = true;
             } else if arg == "--get" {
                 use_get = true;
+            } else if arg == "--get-url" {
+                get_url = true;
+            } else if arg == "--all" || arg == "--paginate" {
+                paginate = true;
             } else if arg == "--raw" {
                 // --raw flag always overrides environment variable
                 raw = true;
@@ -122,6 +134,8 @@ impl ApiCallArgs {
             use_get,
             token_type,
             raw,
+            get_url,
+            paginate,
         })
     }
 
@@ -241,6 +255,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_with_get_url_flag() {
+        let args = vec![
+            "users.info".to_string(),
+            "user=U123456".to_string(),
+            "--get-url".to_string(),
+        ];
+        let result = ApiCallArgs::parse(&args).unwrap();
+
+        assert!(result.get_url);
+        assert_eq!(result.params.len(), 1);
+    }
+
     #[test]
     fn test_to_json() {
         let args = ApiCallArgs {
@@ -256,6 +283,8 @@ mod tests {
             use_get: false,
             token_type: None,
             raw: false,
+            get_url: false,
+            paginate: false,
         };
 
         let json = args.to_json();
@@ -278,6 +307,8 @@ mod tests {
             use_get: false,
             token_type: None,
             raw: false,
+            get_url: false,
+            paginate: false,
         };
 
         let form = args.to_form();
@@ -0,0 +1,455 @@
+//! Write budget guard for autonomous callers
+//!
+//! `--max-writes N` caps the number of write API calls a single invocation
+//! may make, and `--max-writes-per-day N` caps them across invocations using
+//! a local counter (`write_budget.json`, UTC days). Once a limit is reached,
+//! further writes are refused before the request is sent with a
+//! `write_budget_exceeded` error. Both limits can also be set through the
+//! `SLACK_RS_MAX_WRITES` / `SLACK_RS_MAX_WRITES_PER_DAY` environment
+//! variables, so an agent runtime can impose them on every command.
+//!
+//! Retries of the same request (rate limiting) count once.
+
+use super::usage::day_key;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Environment variable for the per-invocation write limit
+pub const MAX_WRITES_ENV: &str = "SLACK_RS_MAX_WRITES";
+/// Environment variable for the per-day write limit
+pub const MAX_WRITES_PER_DAY_ENV: &str = "SLACK_RS_MAX_WRITES_PER_DAY";
+/// Environment variable naming a file that receives this process's write count
+///
+/// Set by a JSONL `--stdin` run for each child, so the next object is only
+/// given the writes left of the shared `--max-writes` budget.
+pub const WRITE_COUNT_FILE_ENV: &str = "SLACK_RS_WRITE_COUNT_FILE";
+
+const UNLIMITED: u64 = u64::MAX;
+
+static MAX_WRITES: AtomicU64 = AtomicU64::new(UNLIMITED);
+static MAX_WRITES_PER_DAY: AtomicU64 = AtomicU64::new(UNLIMITED);
+static SESSION_WRITES: AtomicU64 = AtomicU64::new(0);
+
+/// Which limit refused a write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetScope {
+    Invocation,
+    Day,
+}
+
+impl BudgetScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetScope::Invocation => "invocation",
+            BudgetScope::Day => "day",
+        }
+    }
+}
+
+/// A write refused by the budget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetExceeded {
+    pub scope: BudgetScope,
+    pub limit: u64,
+    pub method: String,
+}
+
+impl BudgetExceeded {
+    /// Machine-readable form of the error
+    pub fn to_json(&self) -> Value {
+        json!({
+            "error": "write_budget_exceeded",
+            "scope": self.scope.as_str(),
+            "limit": self.limit,
+            "method": self.method,
+        })
+    }
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flag = match self.scope {
+            BudgetScope::Invocation => "--max-writes",
+            BudgetScope::Day => "--max-writes-per-day",
+        };
+        write!(
+            f,
+            "write_budget_exceeded: {} write(s) per {} allowed ({}), refusing {} {}",
+            self.limit,
+            self.scope.as_str(),
+            flag,
+            self.method,
+            self.to_json()
+        )
+    }
+}
+
+/// Set the per-invocation write limit (`None` = unlimited)
+pub fn set_max_writes(limit: Option<u64>) {
+    MAX_WRITES.store(limit.unwrap_or(UNLIMITED), Ordering::Relaxed);
+}
+
+/// Set the per-day write limit (`None` = unlimited)
+pub fn set_max_writes_per_day(limit: Option<u64>) {
+    MAX_WRITES_PER_DAY.store(limit.unwrap_or(UNLIMITED), Ordering::Relaxed);
+}
+
+/// Clear both limits and the write count (each `slack-rs shell` line is its own invocation)
+pub fn reset() {
+    set_max_writes(None);
+    set_max_writes_per_day(None);
+    SESSION_WRITES.store(0, Ordering::Relaxed);
+}
+
+/// Apply limits from the environment (flags given later take precedence)
+pub fn init_from_env() -> Result<(), String> {
+    for (var, set) in [
+        (MAX_WRITES_ENV, set_max_writes as fn(Option<u64>)),
+        (MAX_WRITES_PER_DAY_ENV, set_max_writes_per_day),
+    ] {
+        if let Ok(value) = std::env::var(var) {
+            let limit = value
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid {} value '{}'", var, value))?;
+            set(Some(limit));
+        }
+    }
+    Ok(())
+}
+
+/// Writes made by this process so far
+pub(crate) fn session_writes() -> u64 {
+    SESSION_WRITES.load(Ordering::Relaxed)
+}
+
+/// Reserve one write for `method`, or refuse it
+///
+/// Every write is counted in the daily counter, also while no daily limit
+/// is set, so a limit set later in the day sees the writes made before it.
+pub(crate) fn consume_write(method: &str) -> Result<(), BudgetExceeded> {
+    check_invocation(
+        SESSION_WRITES.load(Ordering::Relaxed),
+        MAX_WRITES.load(Ordering::Relaxed),
+        method,
+    )?;
+
+    if let Ok(path) = DailyWriteCounter::default_path() {
+        DailyWriteCounter::consume(
+            &path,
+            &day_key(now_secs()),
+            MAX_WRITES_PER_DAY.load(Ordering::Relaxed),
+            method,
+        )?;
+    }
+
+    let writes = SESSION_WRITES.fetch_add(1, Ordering::Relaxed) + 1;
+    if let Ok(path) = std::env::var(WRITE_COUNT_FILE_ENV) {
+        if let Err(e) = fs::write(&path, writes.to_string()) {
+            crate::debug::log(format!("Failed to report write count to {}: {}", path, e));
+        }
+    }
+    Ok(())
+}
+
+/// Take the `--max-writes` limit out of `args` (the last value wins)
+pub fn take_max_writes_flag(args: Vec<String>) -> Result<(Vec<String>, Option<u64>), String> {
+    let mut result = Vec::with_capacity(args.len());
+    let mut limit = None;
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        let value = if arg == "--max-writes" {
+            iter.next().unwrap_or_default()
+        } else if let Some(v) = arg.strip_prefix("--max-writes=") {
+            v.to_string()
+        } else {
+            result.push(arg);
+            continue;
+        };
+        limit = Some(value.parse::<u64>().map_err(|_| {
+            format!(
+                "invalid --max-writes value '{}': expected a non-negative integer",
+                value
+            )
+        })?);
+    }
+    Ok((result, limit))
+}
+
+/// Read the write count a child process reported in `path` (none = 0)
+pub fn read_write_count(path: &Path) -> u64 {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+fn check_invocation(writes: u64, limit: u64, method: &str) -> Result<(), BudgetExceeded> {
+    if writes >= limit {
+        return Err(BudgetExceeded {
+            scope: BudgetScope::Invocation,
+            limit,
+            method: method.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Whether a generic `api call` endpoint writes, judged by its verb
+///
+/// Wrapper commands use [`ApiMethod::is_write`](super::ApiMethod::is_write);
+/// arbitrary endpoints are classified by the last segment of the method name
+/// (`chat.postMessage` -> `postMessage`).
+pub(crate) fn is_write_endpoint(endpoint: &str) -> bool {
+    const WRITE_VERBS: &[&str] = &[
+        "post",
+        "update",
+        "delete",
+        "add",
+        "remove",
+        "set",
+        "create",
+        "archive",
+        "unarchive",
+        "invite",
+        "kick",
+        "join",
+        "leave",
+        "rename",
+        "schedule",
+        "upload",
+        "complete",
+        "share",
+        "accept",
+        "approve",
+        "decline",
+        "deny",
+        "close",
+        "mark",
+        "revoke",
+        "publish",
+        "push",
+        "enable",
+        "disable",
+        "end",
+    ];
+    let verb = endpoint.rsplit('.').next().unwrap_or(endpoint);
+    WRITE_VERBS.iter().any(|w| verb.starts_with(w))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Write count of the current UTC day
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct DailyWriteCounter {
+    pub day: String,
+    pub count: u64,
+}
+
+impl DailyWriteCounter {
+    /// Default counter path in the config directory
+    pub fn default_path() -> Result<PathBuf, String> {
+        crate::profile::config_dir()
+            .map(|dir| dir.join("write_budget.json"))
+            .ok_or_else(|| "Could not determine config directory".to_string())
+    }
+
+    /// Load the counter; a missing or unreadable file starts at zero
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the counter atomically (temp file + rename), creating the parent directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize write budget: {}", e))?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, content)
+            .and_then(|_| fs::rename(&temp_path, path))
+            .map_err(|e| format!("Failed to write write budget: {}", e))
+    }
+
+    /// Count one write in the counter at `path` unless `limit` is reached
+    ///
+    /// An exclusive lock on `<path>.lock` is held from load to save, so
+    /// concurrent invocations never lose a write. A counter that cannot be
+    /// locked or saved is logged and does not block the write.
+    pub fn consume(path: &Path, day: &str, limit: u64, method: &str) -> Result<(), BudgetExceeded> {
+        let lock = Self::lock(path)
+            .map_err(|e| crate::debug::log(format!("Failed to lock write budget: {}", e)))
+            .ok();
+        let mut counter = Self::load(path);
+        counter.try_consume(day, limit, method)?;
+        if let Err(e) = counter.save(path) {
+            crate::debug::log(format!("Failed to record write budget: {}", e));
+        }
+        drop(lock);
+        Ok(())
+    }
+
+    /// Open `<path>.lock` and take an exclusive lock on it (released on drop)
+    fn lock(path: &Path) -> std::io::Result<fs::File> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path.with_extension("json.lock"))?;
+        file.lock()?;
+        Ok(file)
+    }
+
+    /// Count one write on `day` unless `limit` is already reached
+    pub fn try_consume(
+        &mut self,
+        day: &str,
+        limit: u64,
+        method: &str,
+    ) -> Result<(), BudgetExceeded> {
+        if self.day != day {
+            self.day = day.to_string();
+            self.count = 0;
+        }
+        if self.count >= limit {
+            return Err(BudgetExceeded {
+                scope: BudgetScope::Day,
+                limit,
+                method: method.to_string(),
+            });
+        }
+        self.count += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invocation_limit() {
+        assert!(check_invocation(0, 2, "chat.postMessage").is_ok());
+        assert!(check_invocation(1, 2, "reactions.add").is_ok());
+        let err = check_invocation(2, 2, "chat.postMessage").unwrap_err();
+        assert_eq!(err.scope, BudgetScope::Invocation);
+        assert_eq!(err.limit, 2);
+        assert_eq!(err.to_json()["error"], "write_budget_exceeded");
+        assert!(err.to_string().contains("--max-writes"));
+
+        // Unlimited by default
+        assert!(check_invocation(1_000, UNLIMITED, "chat.postMessage").is_ok());
+    }
+
+    #[test]
+    fn test_take_max_writes_flag() {
+        let args = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let (rest, limit) =
+            take_max_writes_flag(args(&["slack-rs", "--max-writes", "3", "msg", "post"])).unwrap();
+        assert_eq!(rest, args(&["slack-rs", "msg", "post"]));
+        assert_eq!(limit, Some(3));
+
+        let (rest, limit) =
+            take_max_writes_flag(args(&["slack-rs", "msg", "post", "--max-writes=1"])).unwrap();
+        assert_eq!(rest, args(&["slack-rs", "msg", "post"]));
+        assert_eq!(limit, Some(1));
+
+        assert_eq!(take_max_writes_flag(args(&["slack-rs"])).unwrap().1, None);
+        assert!(take_max_writes_flag(args(&["slack-rs", "--max-writes=x"])).is_err());
+    }
+
+    #[test]
+    fn test_read_write_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("writes.txt");
+        assert_eq!(read_write_count(&path), 0);
+        fs::write(&path, "2").unwrap();
+        assert_eq!(read_write_count(&path), 2);
+    }
+
+    #[test]
+    fn test_daily_counter_resets_on_new_day() {
+        let mut counter = DailyWriteCounter::default();
+        assert!(counter
+            .try_consume("2024-07-01", 1, "chat.postMessage")
+            .is_ok());
+        let err = counter
+            .try_consume("2024-07-01", 1, "chat.postMessage")
+            .unwrap_err();
+        assert_eq!(err.scope, BudgetScope::Day);
+
+        assert!(counter
+            .try_consume("2024-07-02", 1, "chat.postMessage")
+            .is_ok());
+        assert_eq!(counter.count, 1);
+    }
+
+    #[test]
+    fn test_daily_counter_roundtrip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("write_budget.json");
+        let mut counter = DailyWriteCounter::load(&path);
+        counter.try_consume("2024-07-01", 5, "chat.update").unwrap();
+        counter.save(&path).unwrap();
+
+        let loaded = DailyWriteCounter::load(&path);
+        assert_eq!(loaded.day, "2024-07-01");
+        assert_eq!(loaded.count, 1);
+    }
+
+    #[test]
+    fn test_daily_counter_consume_is_locked_and_atomic() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("write_budget.json");
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..10 {
+                        DailyWriteCounter::consume(&path, "2024-07-01", UNLIMITED, "chat.update")
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(DailyWriteCounter::load(&path).count, 40);
+        assert!(!temp_dir.path().join("write_budget.json.tmp").exists());
+
+        // A refused write is not counted
+        assert!(DailyWriteCounter::consume(&path, "2024-07-01", 40, "chat.update").is_err());
+        assert_eq!(DailyWriteCounter::load(&path).count, 40);
+    }
+
+    #[test]
+    fn test_is_write_endpoint() {
+        assert!(is_write_endpoint("chat.postMessage"));
+        assert!(is_write_endpoint("reactions.add"));
+        assert!(is_write_endpoint("conversations.archive"));
+        assert!(is_write_endpoint("files.completeUploadExternal"));
+        assert!(!is_write_endpoint("conversations.history"));
+        assert!(!is_write_endpoint("users.list"));
+        assert!(!is_write_endpoint("chat.getPermalink"));
+        assert!(!is_write_endpoint("auth.test"));
+    }
+}
@@ -9,6 +9,8 @@
 use reqwest::{Client, Method, Response, StatusCode};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
 use std::time::Duration;
 use thiserror::Error;
 
@@ -19,7 +21,7 @@ use super::types::{ApiMethod, ApiResponse};
 #[derive(Error, Debug)]
 pub enum ApiError {
     #[error("HTTP request failed: {0}")]
-    RequestFailed(#[from] reqwest::Error),
+    RequestFailed(#[source] reqwest::Error),
 
     #[error("JSON serialization failed: {0}")]
     JsonError(#[from] serde_json::Error),
@@ -39,13 +41,16 @@ pub enum ApiError {
 
     #[error("Non-interactive mode error: {0}")]
     NonInteractiveError(String),
+
+    #[error("{0}")]
+    WriteBudgetExceeded(super::budget::BudgetExceeded),
 }
 
 /// API client errors (for generic API calls)
 #[derive(Debug, Error)]
 pub enum ApiClientError {
     #[error("HTTP request failed: {0}")]
-    RequestFailed(#[from] reqwest::Error),
+    RequestFailed(#[source] reqwest::Error),
 
     #[error("Rate limit exceeded, retry after {0} seconds")]
     RateLimitExceeded(u64),
@@ -55,10 +60,94 @@ pub enum ApiClientError {
 
     #[error("Invalid response: {0}")]
     InvalidResponse(String),
+
+    #[error("{0}")]
+    WriteBudgetExceeded(super::budget::BudgetExceeded),
 }
 
 pub type Result<T> = std::result::Result<T, ApiClientError>;
 
+/// Retryable failure of a Slack request, which selects its own exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestFailure {
+    /// Connection, DNS, TLS or timeout failure
+    Network,
+    /// Still rate limited after the retries
+    RateLimited,
+}
+
+/// Failure of the last request sent by this process (`None` once one succeeds)
+static LAST_REQUEST_FAILURE: Mutex<Option<RequestFailure>> = Mutex::new(None);
+
+fn record_request_outcome(failure: Option<RequestFailure>) {
+    if let Ok(mut last) = LAST_REQUEST_FAILURE.lock() {
+        *last = failure;
+    }
+}
+
+/// Failure of the last request sent by this process, if it failed
+pub fn last_request_failure() -> Option<RequestFailure> {
+    LAST_REQUEST_FAILURE.lock().ok().and_then(|last| *last)
+}
+
+/// Whether a Slack error code reports rate limiting
+fn is_rate_limit_code(code: &str) -> bool {
+    matches!(code, "ratelimited" | "rate_limited")
+}
+
+impl ApiError {
+    /// Retryable failure this error reports, if any
+    pub fn request_failure(&self) -> Option<RequestFailure> {
+        match self {
+            ApiError::RequestFailed(_) => Some(RequestFailure::Network),
+            ApiError::SlackError(code) if is_rate_limit_code(code) => {
+                Some(RequestFailure::RateLimited)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ApiClientError {
+    /// Retryable failure this error reports, if any
+    pub fn request_failure(&self) -> Option<RequestFailure> {
+        match self {
+            ApiClientError::RequestFailed(_) => Some(RequestFailure::Network),
+            ApiClientError::RateLimitExceeded(_) => Some(RequestFailure::RateLimited),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(e: reqwest::Error) -> Self {
+        record_request_outcome(Some(RequestFailure::Network));
+        ApiError::RequestFailed(e)
+    }
+}
+
+impl From<reqwest::Error> for ApiClientError {
+    fn from(e: reqwest::Error) -> Self {
+        record_request_outcome(Some(RequestFailure::Network));
+        ApiClientError::RequestFailed(e)
+    }
+}
+
+/// Retry attempts used when `--max-retries` is not given
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+static MAX_RETRIES: AtomicU32 = AtomicU32::new(DEFAULT_MAX_RETRIES);
+
+/// Set the retry limit used by [`ApiClientConfig::default`] (`--max-retries`)
+pub fn set_default_max_retries(max_retries: u32) {
+    MAX_RETRIES.store(max_retries, Ordering::Relaxed);
+}
+
+/// Retry limit used by [`ApiClientConfig::default`]
+pub fn default_max_retries() -> u32 {
+    MAX_RETRIES.load(Ordering::Relaxed)
+}
+
 /// Configuration for the API client
 #[derive(Debug, Clone)]
 pub struct ApiClientConfig {
@@ -79,7 +168,7 @@ impl Default for ApiClientConfig {
     fn default() -> Self {
         Self {
             base_url: "https://slack.com/api".to_string(),
-            max_retries: 3,
+            max_retries: default_max_retries(),
             initial_backoff_ms: 1000,
             max_backoff_ms: 32000,
         }
@@ -149,6 +238,9 @@ impl ApiClient {
     }
 
     /// Call a Slack API method using the ApiMethod enum (for wrapper commands)
+    ///
+    /// HTTP 429 responses are retried up to `max_retries` times, waiting for
+    /// `Retry-After` (or exponential backoff when absent) plus jitter.
     pub async fn call_method(
         &self,
         method: ApiMethod,
@@ -159,23 +251,81 @@ impl ApiClient {
             .as_ref()
             .ok_or_else(|| ApiError::SlackError("No token configured".to_string()))?;
 
+        if method.is_write() {
+            super::budget::consume_write(method.as_str()).map_err(ApiError::WriteBudgetExceeded)?;
+        }
+        crate::profile::token_meta::record_token_sent(token);
+
         let url = format!("{}/{}", self.config.base_url, method.as_str());
+        let mut attempt = 0;
+
+        let response = loop {
+            super::usage::record_call(method.as_str());
+            let response = self
+                .send_method_request(&url, &method, token, &params)
+                .await?;
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS
+                && attempt < self.config.max_retries
+            {
+                let delay = self.retry_delay(attempt, self.extract_retry_after(&response));
+                crate::debug::log(format!(
+                    "{} rate limited, retrying in {:.1}s ({}/{})",
+                    method.as_str(),
+                    delay.as_secs_f64(),
+                    attempt + 1,
+                    self.config.max_retries
+                ));
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            break response;
+        };
+
+        let response_json: ApiResponse = response.json().await?;
+
+        if !response_json.ok {
+            let error_code = response_json.error.as_deref().unwrap_or("Unknown error");
+
+            // Display error guidance if available
+            if let Some(guidance) = format_error_guidance(error_code) {
+                eprintln!("{}", guidance);
+            }
+
+            let error = ApiError::SlackError(error_code.to_string());
+            record_request_outcome(error.request_failure());
+            return Err(error);
+        }
+
+        record_request_outcome(None);
+        Ok(response_json)
+    }
 
+    /// Send a single wrapper-command request
+    async fn send_method_request(
+        &self,
+        url: &str,
+        method: &ApiMethod,
+        token: &str,
+        params: &HashMap<String, Value>,
+    ) -> std::result::Result<Response, ApiError> {
         let response = if method.uses_get_method() {
             // Use GET request with query parameters
             let mut query_params = vec![];
             for (key, value) in params {
                 let value_str = match value {
-                    Value::String(s) => s,
+                    Value::String(s) => s.clone(),
                     Value::Number(n) => n.to_string(),
                     Value::Bool(b) => b.to_string(),
-                    _ => serde_json::to_string(&value).unwrap_or_default(),
+                    _ => serde_json::to_string(value).unwrap_or_default(),
                 };
-                query_params.push((key, value_str));
+                query_params.push((key.clone(), value_str));
             }
 
             self.client
-                .get(&url)
+                .get(url)
                 .bearer_auth(token)
                 .query(&query_params)
                 .send()
@@ -183,27 +333,13 @@ impl ApiClient {
         } else {
             // Use POST request with JSON body
             self.client
-                .post(&url)
+                .post(url)
                 .bearer_auth(token)
-                .json(&params)
+                .json(params)
                 .send()
                 .await?
         };
-
-        let response_json: ApiResponse = response.json().await?;
-
-        if !response_json.ok {
-            let error_code = response_json.error.as_deref().unwrap_or("Unknown error");
-
-            // Display error guidance if available
-            if let Some(guidance) = format_error_guidance(error_code) {
-                eprintln!("{}", guidance);
-            }
-
-            return Err(ApiError::SlackError(error_code.to_string()));
-        }
-
-        Ok(response_json)
+        Ok(response)
     }
 
     /// Make an API call with automatic retry logic (for generic API calls)
@@ -215,10 +351,16 @@ impl ApiClient {
         body: RequestBody,
         query_params: Vec<(String, String)>,
     ) -> Result<Response> {
+        if super::budget::is_write_endpoint(endpoint) {
+            super::budget::consume_write(endpoint).map_err(ApiClientError::WriteBudgetExceeded)?;
+        }
+        crate::profile::token_meta::record_token_sent(token);
+
         let url = format!("{}/{}", self.config.base_url, endpoint);
         let mut attempt = 0;
 
         loop {
+            super::usage::record_call(endpoint);
             let response = self
                 .execute_request(&url, &method, token, &body, &query_params)
                 .await?;
@@ -227,13 +369,17 @@ impl ApiClient {
             if response.status() == StatusCode::TOO_MANY_REQUESTS {
                 // Extract Retry-After header
                 let retry_after = self.extract_retry_after(&response);
+                let delay = self.retry_delay(attempt, retry_after);
 
                 if attempt >= self.config.max_retries {
-                    return Err(ApiClientError::RateLimitExceeded(retry_after));
+                    record_request_outcome(Some(RequestFailure::RateLimited));
+                    return Err(ApiClientError::RateLimitExceeded(
+                        retry_after.unwrap_or(delay.as_secs()),
+                    ));
                 }
 
                 // Wait for the specified duration
-                tokio::time::sleep(Duration::from_secs(retry_after)).await;
+                tokio::time::sleep(delay).await;
                 attempt += 1;
                 continue;
             }
@@ -246,6 +392,7 @@ impl ApiClient {
                 continue;
             }
 
+            record_request_outcome(None);
             return Ok(response);
         }
     }
@@ -288,14 +435,25 @@ impl ApiClient {
         Ok(response)
     }
 
-    /// Extract Retry-After header value
-    fn extract_retry_after(&self, response: &Response) -> u64 {
+    /// Extract Retry-After header value (seconds)
+    fn extract_retry_after(&self, response: &Response) -> Option<u64> {
         response
             .headers()
             .get("Retry-After")
             .and_then(|v| v.to_str().ok())
-            .and_then(|s| s.parse::<u64>().ok())
-            .unwrap_or(60) // Default to 60 seconds if not specified
+            .and_then(|s| s.trim().parse::<u64>().ok())
+    }
+
+    /// Delay before retrying a rate-limited request
+    ///
+    /// `Retry-After` is honoured as a minimum; later attempts back off
+    /// exponentially beyond it so bursts of scripts spread out.
+    fn retry_delay(&self, attempt: u32, retry_after: Option<u64>) -> Duration {
+        let backoff = self.calculate_backoff(attempt);
+        match retry_after {
+            Some(secs) => backoff.max(Duration::from_secs(secs)),
+            None => backoff,
+        }
     }
 
     /// Calculate exponential backoff with jitter
@@ -304,15 +462,13 @@ impl ApiClient {
         let max = self.config.max_backoff_ms;
 
         // Exponential backoff: base * 2^attempt
-        let backoff = base * 2_u64.pow(attempt);
+        let backoff = base.saturating_mul(2_u64.saturating_pow(attempt));
         let backoff = backoff.min(max);
 
         // Add jitter (±25%)
         let jitter = (backoff as f64 * 0.25) as u64;
-        let jitter = rand::random::<u64>() % (jitter * 2 + 1);
-        let backoff = backoff
-            .saturating_sub(jitter / 2)
-            .saturating_add(jitter / 2);
+        let offset = rand::random::<u64>() % (jitter * 2 + 1);
+        let backoff = backoff.saturating_sub(jitter).saturating_add(offset);
 
         Duration::from_millis(backoff)
     }
@@ -339,6 +495,8 @@ mod tests {
     #[test]
     fn test_api_method_as_str() {
         assert_eq!(ApiMethod::SearchMessages.as_str(), "search.messages");
+        assert_eq!(ApiMethod::SearchFiles.as_str(), "search.files");
+        assert_eq!(ApiMethod::SearchAll.as_str(), "search.all");
         assert_eq!(ApiMethod::ConversationsList.as_str(), "conversations.list");
         assert_eq!(
             ApiMethod::ConversationsHistory.as_str(),
@@ -348,25 +506,121 @@ mod tests {
             ApiMethod::ConversationsReplies.as_str(),
             "conversations.replies"
         );
+        assert_eq!(
+            ApiMethod::ConversationsMembers.as_str(),
+            "conversations.members"
+        );
         assert_eq!(ApiMethod::UsersInfo.as_str(), "users.info");
+        assert_eq!(
+            ApiMethod::UsersConversations.as_str(),
+            "users.conversations"
+        );
+        assert_eq!(ApiMethod::UsergroupsList.as_str(), "usergroups.list");
+        assert_eq!(ApiMethod::FilesList.as_str(), "files.list");
+        assert_eq!(ApiMethod::FilesDelete.as_str(), "files.delete");
+        assert_eq!(ApiMethod::EmojiList.as_str(), "emoji.list");
+        assert_eq!(
+            ApiMethod::UsergroupsUsersUpdate.as_str(),
+            "usergroups.users.update"
+        );
         assert_eq!(ApiMethod::ChatPostMessage.as_str(), "chat.postMessage");
         assert_eq!(ApiMethod::ChatUpdate.as_str(), "chat.update");
         assert_eq!(ApiMethod::ChatDelete.as_str(), "chat.delete");
+        assert_eq!(ApiMethod::ChatGetPermalink.as_str(), "chat.getPermalink");
+        assert_eq!(
+            ApiMethod::ChatScheduleMessage.as_str(),
+            "chat.scheduleMessage"
+        );
+        assert_eq!(
+            ApiMethod::ChatScheduledMessagesList.as_str(),
+            "chat.scheduledMessages.list"
+        );
+        assert_eq!(
+            ApiMethod::ChatDeleteScheduledMessage.as_str(),
+            "chat.deleteScheduledMessage"
+        );
+        assert_eq!(
+            ApiMethod::AppsConnectionsOpen.as_str(),
+            "apps.connections.open"
+        );
+        assert_eq!(
+            ApiMethod::FilesCompleteUploadExternal.as_str(),
+            "files.completeUploadExternal"
+        );
         assert_eq!(ApiMethod::ReactionsAdd.as_str(), "reactions.add");
         assert_eq!(ApiMethod::ReactionsRemove.as_str(), "reactions.remove");
+        assert_eq!(ApiMethod::PinsAdd.as_str(), "pins.add");
+        assert_eq!(ApiMethod::PinsRemove.as_str(), "pins.remove");
+        assert_eq!(ApiMethod::PinsList.as_str(), "pins.list");
+        assert_eq!(ApiMethod::BookmarksAdd.as_str(), "bookmarks.add");
+        assert_eq!(ApiMethod::BookmarksEdit.as_str(), "bookmarks.edit");
+        assert_eq!(ApiMethod::RemindersComplete.as_str(), "reminders.complete");
+        assert_eq!(ApiMethod::DndSetSnooze.as_str(), "dnd.setSnooze");
+        assert_eq!(ApiMethod::UsersSetPresence.as_str(), "users.setPresence");
+        assert_eq!(
+            ApiMethod::ConversationsUnarchive.as_str(),
+            "conversations.unarchive"
+        );
+        assert_eq!(ApiMethod::ConversationsKick.as_str(), "conversations.kick");
+        assert_eq!(ApiMethod::ConversationsJoin.as_str(), "conversations.join");
+        assert_eq!(
+            ApiMethod::ConversationsLeave.as_str(),
+            "conversations.leave"
+        );
+        assert_eq!(
+            ApiMethod::ConversationsRequestSharedInviteList.as_str(),
+            "conversations.requestSharedInvite.list"
+        );
+        assert_eq!(
+            ApiMethod::ConversationsExternalInvitePermissionsSet.as_str(),
+            "conversations.externalInvitePermissions.set"
+        );
     }
 
     #[test]
     fn test_api_method_is_write() {
         assert!(!ApiMethod::SearchMessages.is_write());
+        assert!(!ApiMethod::SearchFiles.is_write());
         assert!(!ApiMethod::ConversationsList.is_write());
         assert!(!ApiMethod::ConversationsHistory.is_write());
         assert!(!ApiMethod::UsersInfo.is_write());
+        assert!(!ApiMethod::ChatGetPermalink.is_write());
+        assert!(!ApiMethod::AppsConnectionsOpen.is_write());
+        assert!(ApiMethod::FilesGetUploadUrlExternal.is_write());
+        assert!(ApiMethod::FilesCompleteUploadExternal.is_write());
+        assert!(ApiMethod::FilesDelete.is_write());
+        assert!(!ApiMethod::FilesList.is_write());
         assert!(ApiMethod::ChatPostMessage.is_write());
         assert!(ApiMethod::ChatUpdate.is_write());
         assert!(ApiMethod::ChatDelete.is_write());
+        assert!(ApiMethod::ChatScheduleMessage.is_write());
+        assert!(ApiMethod::ChatDeleteScheduledMessage.is_write());
+        assert!(!ApiMethod::ChatScheduledMessagesList.is_write());
         assert!(ApiMethod::ReactionsAdd.is_write());
         assert!(ApiMethod::ReactionsRemove.is_write());
+        assert!(ApiMethod::PinsAdd.is_write());
+        assert!(ApiMethod::PinsRemove.is_write());
+        assert!(!ApiMethod::PinsList.is_write());
+        assert!(ApiMethod::BookmarksAdd.is_write());
+        assert!(ApiMethod::BookmarksEdit.is_write());
+        assert!(!ApiMethod::BookmarksList.is_write());
+        assert!(ApiMethod::RemindersAdd.is_write());
+        assert!(!ApiMethod::RemindersList.is_write());
+        assert!(ApiMethod::DndSetSnooze.is_write());
+        assert!(ApiMethod::UsersSetPresence.is_write());
+        assert!(!ApiMethod::DndInfo.is_write());
+        assert!(ApiMethod::ConversationsCreate.is_write());
+        assert!(ApiMethod::ConversationsRename.is_write());
+        assert!(ApiMethod::ConversationsInvite.is_write());
+        assert!(ApiMethod::ConversationsJoin.is_write());
+        assert!(ApiMethod::ConversationsLeave.is_write());
+        assert!(ApiMethod::UsergroupsCreate.is_write());
+        assert!(ApiMethod::UsergroupsUsersUpdate.is_write());
+        assert!(!ApiMethod::UsergroupsUsersList.is_write());
+        assert!(ApiMethod::ConversationsInviteShared.is_write());
+        assert!(ApiMethod::ConversationsRequestSharedInviteApprove.is_write());
+        assert!(!ApiMethod::ConversationsListConnectInvites.is_write());
+        assert!(!ApiMethod::ConversationsRequestSharedInviteList.is_write());
     }
 
     #[test]
@@ -378,19 +632,49 @@ mod tests {
         assert!(!ApiMethod::ChatPostMessage.is_destructive());
         assert!(ApiMethod::ChatUpdate.is_destructive());
         assert!(ApiMethod::ChatDelete.is_destructive());
+        assert!(ApiMethod::UsergroupsUsersUpdate.is_destructive());
+        assert!(!ApiMethod::UsergroupsUpdate.is_destructive());
+        assert!(ApiMethod::ChatDeleteScheduledMessage.is_destructive());
+        assert!(!ApiMethod::ChatScheduleMessage.is_destructive());
         assert!(!ApiMethod::ReactionsAdd.is_destructive());
         assert!(ApiMethod::ReactionsRemove.is_destructive());
+        assert!(!ApiMethod::PinsAdd.is_destructive());
+        assert!(ApiMethod::PinsRemove.is_destructive());
+        assert!(ApiMethod::BookmarksRemove.is_destructive());
+        assert!(!ApiMethod::BookmarksAdd.is_destructive());
+        assert!(ApiMethod::RemindersDelete.is_destructive());
+        assert!(!ApiMethod::RemindersComplete.is_destructive());
+        assert!(ApiMethod::ConversationsArchive.is_destructive());
+        assert!(ApiMethod::ConversationsKick.is_destructive());
+        assert!(!ApiMethod::ConversationsCreate.is_destructive());
+        assert!(ApiMethod::ConversationsDeclineSharedInvite.is_destructive());
+        assert!(ApiMethod::ConversationsRequestSharedInviteDeny.is_destructive());
+        assert!(!ApiMethod::ConversationsAcceptSharedInvite.is_destructive());
     }
 
     #[test]
     fn test_api_method_uses_get() {
         // GET methods
         assert!(ApiMethod::SearchMessages.uses_get_method());
+        assert!(ApiMethod::SearchAll.uses_get_method());
         assert!(ApiMethod::ConversationsList.uses_get_method());
         assert!(ApiMethod::ConversationsHistory.uses_get_method());
         assert!(ApiMethod::ConversationsReplies.uses_get_method());
+        assert!(ApiMethod::ConversationsMembers.uses_get_method());
+        assert!(ApiMethod::ConversationsInfo.uses_get_method());
         assert!(ApiMethod::UsersInfo.uses_get_method());
         assert!(ApiMethod::UsersList.uses_get_method());
+        assert!(ApiMethod::UsersConversations.uses_get_method());
+        assert!(ApiMethod::FilesList.uses_get_method());
+        assert!(!ApiMethod::FilesDelete.uses_get_method());
+        assert!(ApiMethod::ChatGetPermalink.uses_get_method());
+        assert!(ApiMethod::ChatScheduledMessagesList.uses_get_method());
+        assert!(ApiMethod::PinsList.uses_get_method());
+        assert!(ApiMethod::BookmarksList.uses_get_method());
+        assert!(ApiMethod::RemindersList.uses_get_method());
+        assert!(ApiMethod::DndInfo.uses_get_method());
+        assert!(ApiMethod::UsersGetPresence.uses_get_method());
+        assert!(ApiMethod::ConversationsRequestSharedInviteList.uses_get_method());
 
         // POST methods
         assert!(!ApiMethod::ChatPostMessage.uses_get_method());
@@ -398,6 +682,8 @@ mod tests {
         assert!(!ApiMethod::ChatDelete.uses_get_method());
         assert!(!ApiMethod::ReactionsAdd.uses_get_method());
         assert!(!ApiMethod::ReactionsRemove.uses_get_method());
+        assert!(!ApiMethod::PinsAdd.uses_get_method());
+        assert!(!ApiMethod::AppsConnectionsOpen.uses_get_method());
     }
 
     #[test]
@@ -428,4 +714,79 @@ mod tests {
         assert_eq!(client.base_url(), "https://test.example.com");
         assert_eq!(client.config.max_retries, 5);
     }
+
+    #[test]
+    fn test_calculate_backoff_bounds() {
+        let client = ApiClient::with_config(ApiClientConfig {
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 4000,
+            ..Default::default()
+        });
+        for _ in 0..20 {
+            let first = client.calculate_backoff(0).as_millis();
+            assert!((750..=1250).contains(&first), "{}", first);
+            let capped = client.calculate_backoff(10).as_millis();
+            assert!((3000..=5000).contains(&capped), "{}", capped);
+        }
+        assert!(client.retry_delay(0, Some(30)) >= Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn test_call_method_retries_rate_limit() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "0")
+                    .set_body_json(serde_json::json!({"ok": false, "error": "ratelimited"})),
+            )
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"ok": true, "ts": "1.000"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        client.config.initial_backoff_ms = 1;
+        let response = client
+            .call_method(ApiMethod::ChatPostMessage, HashMap::new())
+            .await
+            .unwrap();
+        assert!(response.ok);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_call_method_gives_up_after_max_retries() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .set_body_json(serde_json::json!({"ok": false, "error": "ratelimited"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        client.config.max_retries = 1;
+        client.config.initial_backoff_ms = 1;
+        let result = client
+            .call_method(ApiMethod::UsersList, HashMap::new())
+            .await;
+        assert!(matches!(result, Err(ApiError::SlackError(ref code)) if code == "ratelimited"));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
 }
@@ -40,6 +40,25 @@ pub struct CommandMeta {
     pub idempotency_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub idempotency_status: Option<String>,
+    /// Results of composite follow-up steps (e.g. `msg post --pin --react`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow_ups: Option<Value>,
+    /// Cursor for the next page, present only when more data exists
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Snapshot boundary and changes seen during a `--cursor-stability` read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consistency: Option<Value>,
+}
+
+/// Extract a non-empty `response_metadata.next_cursor` from a Slack response
+pub fn next_cursor_of(response: &Value) -> Option<String> {
+    response
+        .get("response_metadata")
+        .and_then(|meta| meta.get("next_cursor"))
+        .and_then(|c| c.as_str())
+        .filter(|c| !c.is_empty())
+        .map(|c| c.to_string())
 }
 
 impl CommandResponse {
@@ -61,6 +80,7 @@ impl CommandResponse {
 
         // Generate type from method (e.g., "conversations.list" -> "conversations.list")
         let response_type = method.clone();
+        let next_cursor = next_cursor_of(&response);
 
         Self {
             schema_version: 1,
@@ -76,6 +96,9 @@ impl CommandResponse {
                 token_type: None,
                 idempotency_key: None,
                 idempotency_status: None,
+                follow_ups: None,
+                consistency: None,
+                next_cursor,
             },
         }
     }
@@ -99,6 +122,7 @@ impl CommandResponse {
 
         // Generate type from method (e.g., "conversations.list" -> "conversations.list")
         let response_type = method.clone();
+        let next_cursor = next_cursor_of(&response);
 
         Self {
             schema_version: 1,
@@ -114,6 +138,9 @@ impl CommandResponse {
                 token_type,
                 idempotency_key: None,
                 idempotency_status: None,
+                follow_ups: None,
+                consistency: None,
+                next_cursor,
             },
         }
     }
@@ -124,4 +151,17 @@ impl CommandResponse {
         self.meta.idempotency_status = Some(status);
         self
     }
+
+    /// Attach the follow-up step report; a failed step marks the response not ok
+    pub fn with_follow_ups(mut self, report: Value, ok: bool) -> Self {
+        self.meta.follow_ups = Some(report);
+        self.ok = self.ok && ok;
+        self
+    }
+
+    /// Attach the `--cursor-stability` consistency report
+    pub fn with_consistency(mut self, report: Value) -> Self {
+        self.meta.consistency = Some(report);
+        self
+    }
 }
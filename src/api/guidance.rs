@@ -3,6 +3,7 @@
 //! This module provides user-friendly guidance for common Slack API errors,
 //! helping users understand the cause and how to resolve issues.
 
+use crate::i18n::{current_language, Language, Messages};
 use std::collections::HashMap;
 
 /// Error guidance information
@@ -27,135 +28,67 @@ impl ErrorGuidance {
     }
 }
 
-/// Get error guidance for a given error code
+/// Slack error codes with guidance entries in the message catalog
+const GUIDED_ERROR_CODES: &[&str] = &[
+    "not_allowed_token_type",
+    "missing_scope",
+    "invalid_auth",
+    "token_revoked",
+    "token_expired",
+    "not_authed",
+    "account_inactive",
+    "no_permission",
+    "org_login_required",
+    "ekm_access_denied",
+    "channel_not_found",
+    "method_not_supported_for_channel_type",
+];
+
+/// Get error guidance for a given error code in the active language
 pub fn get_error_guidance(error_code: &str) -> Option<ErrorGuidance> {
-    let guidance_map = build_guidance_map();
+    get_error_guidance_in(error_code, current_language())
+}
+
+/// Get error guidance for a given error code in a specific language
+pub(crate) fn get_error_guidance_in(error_code: &str, lang: Language) -> Option<ErrorGuidance> {
+    let guidance_map = build_guidance_map(lang);
     guidance_map.get(error_code).cloned()
 }
 
-/// Build the complete error guidance mapping
-fn build_guidance_map() -> HashMap<String, ErrorGuidance> {
-    let mut map = HashMap::new();
-
-    // not_allowed_token_type
-    map.insert(
-        "not_allowed_token_type".to_string(),
-        ErrorGuidance::new(
-            "not_allowed_token_type",
-            "The token type used for this request is not allowed for this API method",
-            "Use a different token type (bot or user). Try: --token-type user or --token-type bot",
-        ),
-    );
-
-    // missing_scope
-    map.insert(
-        "missing_scope".to_string(),
-        ErrorGuidance::new(
-            "missing_scope",
-            "The token does not have the required OAuth scope for this API method",
-            "Re-authenticate with the required scopes. Run: slack auth login",
-        ),
-    );
-
-    // invalid_auth
-    map.insert(
-        "invalid_auth".to_string(),
-        ErrorGuidance::new(
-            "invalid_auth",
-            "The authentication token is invalid, expired, or revoked",
-            "Re-authenticate to obtain a new token. Run: slack auth login",
-        ),
-    );
-
-    // token_revoked
-    map.insert(
-        "token_revoked".to_string(),
-        ErrorGuidance::new(
-            "token_revoked",
-            "The authentication token has been revoked",
-            "Re-authenticate to obtain a new token. Run: slack auth login",
-        ),
-    );
-
-    // token_expired
-    map.insert(
-        "token_expired".to_string(),
-        ErrorGuidance::new(
-            "token_expired",
-            "The authentication token has expired",
-            "Re-authenticate to obtain a new token. Run: slack auth login",
-        ),
-    );
-
-    // not_authed
-    map.insert(
-        "not_authed".to_string(),
-        ErrorGuidance::new(
-            "not_authed",
-            "No authentication token was provided",
-            "Authenticate first. Run: slack auth login",
-        ),
-    );
-
-    // account_inactive
-    map.insert(
-        "account_inactive".to_string(),
-        ErrorGuidance::new(
-            "account_inactive",
-            "The authentication token is for a deleted user or workspace",
-            "Use a valid workspace account and re-authenticate. Run: slack auth login",
-        ),
-    );
-
-    // no_permission
-    map.insert(
-        "no_permission".to_string(),
-        ErrorGuidance::new(
-            "no_permission",
-            "The token does not have permission to perform this action",
-            "Check workspace permissions or use a token with appropriate privileges",
-        ),
-    );
-
-    // org_login_required
-    map.insert(
-        "org_login_required".to_string(),
-        ErrorGuidance::new(
-            "org_login_required",
-            "The workspace requires organization-wide login",
-            "Contact your workspace administrator for access",
-        ),
-    );
-
-    // ekm_access_denied
-    map.insert(
-        "ekm_access_denied".to_string(),
-        ErrorGuidance::new(
-            "ekm_access_denied",
-            "Enterprise Key Management (EKM) access was denied",
-            "Contact your workspace administrator to check EKM settings",
-        ),
-    );
-
-    // channel_not_found
-    map.insert(
-        "channel_not_found".to_string(),
-        ErrorGuidance::new(
-            "channel_not_found",
-            "The channel was not found. Possible causes: private channel without membership, incorrect channel ID, wrong workspace profile, or wrong token type",
-            "Check: 1) Use --include-private or --all for private channels, 2) Verify channel ID, 3) Confirm correct --profile, 4) Try --token-type user for private channels",
-        ),
-    );
-
-    map
+/// Build the complete error guidance mapping from the message catalog
+fn build_guidance_map(lang: Language) -> HashMap<String, ErrorGuidance> {
+    let messages = Messages::new(lang);
+
+    GUIDED_ERROR_CODES
+        .iter()
+        .map(|code| {
+            let cause = messages.get(&format!("guidance.{}.cause", code));
+            let resolution = messages.get(&format!("guidance.{}.resolution", code));
+            (
+                code.to_string(),
+                ErrorGuidance::new(code, cause, resolution),
+            )
+        })
+        .collect()
 }
 
 /// Format error guidance for display on stderr
 pub fn format_error_guidance(error_code: &str) -> Option<String> {
-    get_error_guidance(error_code).map(|guidance| {
+    format_error_guidance_in(error_code, current_language())
+}
+
+/// Format error guidance for display in a specific language
+pub fn format_error_guidance_in(error_code: &str, lang: Language) -> Option<String> {
+    let messages = Messages::new(lang);
+    get_error_guidance_in(error_code, lang).map(|guidance| {
         format!(
-            "\nError: {}\nCause: {}\nResolution: {}\n",
-            guidance.error_code, guidance.cause, guidance.resolution
+            "\n{}: {}\n{}: {}\n{}: {}\n",
+            messages.get("guidance.error"),
+            guidance.error_code,
+            messages.get("guidance.cause"),
+            guidance.cause,
+            messages.get("guidance.resolution"),
+            guidance.resolution
         )
     })
 }
@@ -241,6 +174,15 @@ mod tests {
         assert!(guidance.is_none());
     }
 
+    #[test]
+    fn test_get_error_guidance_channel_type() {
+        let guidance =
+            get_error_guidance_in("method_not_supported_for_channel_type", Language::English)
+                .unwrap();
+        assert!(guidance.cause.contains("public channels"));
+        assert!(guidance.resolution.contains("conv invite"));
+    }
+
     #[test]
     fn test_format_error_guidance() {
         let formatted = format_error_guidance("missing_scope");
@@ -251,6 +193,30 @@ mod tests {
         assert!(formatted.contains("Resolution:"));
     }
 
+    #[test]
+    fn test_format_error_guidance_japanese() {
+        let formatted = format_error_guidance_in("missing_scope", Language::Japanese).unwrap();
+        assert!(formatted.contains("エラー: missing_scope"));
+        assert!(formatted.contains("原因: "));
+        assert!(formatted.contains("対処: "));
+        assert!(formatted.contains("slack auth login"));
+    }
+
+    #[test]
+    fn test_guidance_catalog_complete() {
+        for lang in [Language::English, Language::Japanese] {
+            for code in GUIDED_ERROR_CODES {
+                let guidance = get_error_guidance_in(code, lang).unwrap();
+                assert!(!guidance.cause.is_empty(), "missing cause for {}", code);
+                assert!(
+                    !guidance.resolution.is_empty(),
+                    "missing resolution for {}",
+                    code
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_format_error_guidance_unknown() {
         let formatted = format_error_guidance("unknown_error");
@@ -4,32 +4,49 @@
 //! - HTTP client with retry logic
 //! - Argument parsing
 //! - API call execution with metadata
+//! - Automatic cursor pagination
+//! - Planned (built but unsent) wrapper-command requests
+//! - Write budget guard (`--max-writes`)
 //! - Wrapper commands for common operations
 
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
 pub mod args;
+pub mod budget;
 pub mod call;
 pub mod client;
 pub mod envelope;
 pub mod guidance;
+pub mod paginate;
+pub mod plan;
 pub mod types;
+pub mod usage;
 
 // Re-export commonly used types for generic API calls
 pub use args::{ApiCallArgs, ArgsError};
 pub use call::{
-    display_error_guidance, execute_api_call, ApiCallContext, ApiCallError, ApiCallMeta,
-    ApiCallResponse,
+    display_error_guidance, execute_api_call, prepare_api_request, ApiCallContext, ApiCallError,
+    ApiCallMeta, ApiCallResponse, PreparedRequest,
+};
+pub use client::{
+    default_max_retries, last_request_failure, set_default_max_retries, ApiClient, ApiClientConfig,
+    ApiClientError, ApiError, RequestBody, RequestFailure, DEFAULT_MAX_RETRIES,
 };
-pub use client::{ApiClient, ApiClientConfig, ApiClientError, ApiError, RequestBody};
 
 // Re-export unified envelope types
-pub use envelope::{CommandMeta, CommandResponse};
+pub use envelope::{next_cursor_of, CommandMeta, CommandResponse};
+
+// Re-export automatic pagination helpers
+pub use paginate::{merge_page, paginate, MAX_PAGINATE_PAGES};
 
 // Re-export types for wrapper commands
+pub use plan::PlannedRequest;
 pub use types::{ApiMethod, ApiResponse};
 
+// Re-export usage accounting
+pub use usage::{format_usage_report, RateLimitTier, UsageError, UsageStore};
+
 // Re-export error guidance utilities
 pub use guidance::{
     display_json_error_guidance, display_wrapper_error_guidance, format_error_guidance,
@@ -0,0 +1,132 @@
+//! Automatic cursor pagination (`--all` / `--paginate`)
+//!
+//! Pages are fetched while `response_metadata.next_cursor` is non-empty and
+//! merged into the first page: top-level arrays (channels, members,
+//! messages, ...) are concatenated, other fields keep their first-page value.
+
+use serde_json::Value;
+
+use super::envelope::next_cursor_of;
+
+/// Safety limit on pages fetched by one paginated call
+pub const MAX_PAGINATE_PAGES: usize = 1000;
+
+/// Merge `page` into `acc`
+///
+/// Arrays present in both are concatenated; arrays only in `page` are
+/// added. `response_metadata` is taken from the latest page.
+pub fn merge_page(acc: &mut Value, page: Value) {
+    let (Some(acc_obj), Value::Object(page_obj)) = (acc.as_object_mut(), page) else {
+        return;
+    };
+
+    for (key, value) in page_obj {
+        match (acc_obj.get_mut(&key), value) {
+            (Some(Value::Array(existing)), Value::Array(items)) => existing.extend(items),
+            (None, value @ Value::Array(_)) => {
+                acc_obj.insert(key, value);
+            }
+            (_, value) if key == "response_metadata" => {
+                acc_obj.insert(key, value);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Fetch every page using `fetch(cursor)` and return the merged response
+///
+/// Stops at the first page that is not `ok` and returns it as-is, so
+/// callers see Slack's error rather than a partial merge. After
+/// [`MAX_PAGINATE_PAGES`] pages the merged result is returned with the
+/// remaining `next_cursor` left in place.
+pub async fn paginate<F, Fut, E>(mut fetch: F) -> Result<Value, E>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<Value, E>>,
+{
+    let mut merged = fetch(None).await?;
+    let mut pages = 1;
+
+    while let Some(cursor) = next_cursor_of(&merged) {
+        if pages >= MAX_PAGINATE_PAGES {
+            break;
+        }
+
+        let page = fetch(Some(cursor)).await?;
+        if page.get("ok").and_then(|v| v.as_bool()) == Some(false) {
+            return Ok(page);
+        }
+        merge_page(&mut merged, page);
+        pages += 1;
+    }
+
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_page() {
+        let mut acc = json!({
+            "ok": true,
+            "channels": [{"id": "C1"}],
+            "response_metadata": {"next_cursor": "a"}
+        });
+        merge_page(
+            &mut acc,
+            json!({
+                "ok": true,
+                "channels": [{"id": "C2"}],
+                "warning": "ignored",
+                "response_metadata": {"next_cursor": ""}
+            }),
+        );
+        assert_eq!(acc["channels"], json!([{"id": "C1"}, {"id": "C2"}]));
+        assert!(acc.get("warning").is_none());
+        assert_eq!(acc["response_metadata"]["next_cursor"], "");
+    }
+
+    #[tokio::test]
+    async fn test_paginate_until_exhausted() {
+        let pages = [
+            json!({"ok": true, "members": [1, 2], "response_metadata": {"next_cursor": "p2"}}),
+            json!({"ok": true, "members": [3], "response_metadata": {"next_cursor": "p3"}}),
+            json!({"ok": true, "members": [4], "response_metadata": {"next_cursor": ""}}),
+        ];
+        let mut seen = Vec::new();
+        let merged: Result<Value, String> = paginate(|cursor| {
+            let index = seen.len();
+            seen.push(cursor);
+            let page = pages[index].clone();
+            async move { Ok(page) }
+        })
+        .await;
+
+        assert_eq!(merged.unwrap()["members"], json!([1, 2, 3, 4]));
+        assert_eq!(
+            seen,
+            vec![None, Some("p2".to_string()), Some("p3".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error_page() {
+        let pages = [
+            json!({"ok": true, "members": [1], "response_metadata": {"next_cursor": "p2"}}),
+            json!({"ok": false, "error": "ratelimited"}),
+        ];
+        let mut calls = 0;
+        let result: Result<Value, String> = paginate(|_| {
+            let page = pages[calls].clone();
+            calls += 1;
+            async move { Ok(page) }
+        })
+        .await;
+
+        assert_eq!(result.unwrap()["error"], "ratelimited");
+    }
+}
@@ -0,0 +1,125 @@
+//! Planned wrapper-command requests
+//!
+//! Wrapper commands are split into two layers:
+//! - a pure builder (`plan_*` in `commands`) that turns arguments into a
+//!   [`PlannedRequest`] without touching the network
+//! - an executor ([`ApiClient::execute_planned`]) that sends it
+//!
+//! Keeping construction pure lets dry-run, explain, record/replay and tests
+//! inspect exactly what would be sent.
+
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+use super::client::{ApiClient, ApiError};
+use super::types::{ApiMethod, ApiResponse};
+
+/// A Slack API request built by a wrapper command but not yet sent
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedRequest {
+    pub method: ApiMethod,
+    pub params: HashMap<String, Value>,
+}
+
+impl PlannedRequest {
+    /// Start a request with no parameters
+    pub fn new(method: ApiMethod) -> Self {
+        Self {
+            method,
+            params: HashMap::new(),
+        }
+    }
+
+    /// Add a parameter
+    pub fn param(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.params.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Add a parameter when `value` is `Some`
+    pub fn param_opt(self, key: &str, value: Option<impl Into<Value>>) -> Self {
+        match value {
+            Some(value) => self.param(key, value),
+            None => self,
+        }
+    }
+
+    /// HTTP method the executor will use
+    pub fn http_method(&self) -> &'static str {
+        if self.method.uses_get_method() {
+            "GET"
+        } else {
+            "POST"
+        }
+    }
+
+    /// Whether sending this request modifies the workspace
+    pub fn is_write(&self) -> bool {
+        self.method.is_write()
+    }
+
+    /// JSON description with parameters in a stable (sorted) order
+    pub fn to_json(&self) -> Value {
+        let mut keys: Vec<&String> = self.params.keys().collect();
+        keys.sort();
+        let params: Map<String, Value> = keys
+            .into_iter()
+            .map(|k| (k.clone(), self.params[k].clone()))
+            .collect();
+        json!({
+            "method": self.method.as_str(),
+            "http_method": self.http_method(),
+            "params": params,
+        })
+    }
+}
+
+impl ApiClient {
+    /// Send a planned request
+    pub async fn execute_planned(&self, request: PlannedRequest) -> Result<ApiResponse, ApiError> {
+        self.call_method(request.method, request.params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_request_builder() {
+        let request = PlannedRequest::new(ApiMethod::ChatPostMessage)
+            .param("text", "hi")
+            .param("channel", "C1")
+            .param_opt("thread_ts", None::<String>);
+
+        assert_eq!(request.http_method(), "POST");
+        assert!(request.is_write());
+        assert_eq!(
+            serde_json::to_string(&request.to_json()).unwrap(),
+            r#"{"http_method":"POST","method":"chat.postMessage","params":{"channel":"C1","text":"hi"}}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_planned() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users.info"))
+            .and(query_param("user", "U1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"ok": true, "user": {"id": "U1"}})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let response = client
+            .execute_planned(PlannedRequest::new(ApiMethod::UsersInfo).param("user", "U1"))
+            .await
+            .unwrap();
+        assert!(response.ok);
+    }
+}
@@ -8,26 +8,132 @@ use std::collections::HashMap;
 pub enum ApiMethod {
     /// Search messages
     SearchMessages,
+    /// Search files
+    SearchFiles,
+    /// Search messages and files
+    SearchAll,
     /// List conversations
     ConversationsList,
     /// Get conversation history
     ConversationsHistory,
     /// Get conversation replies (thread messages)
     ConversationsReplies,
+    /// List conversation members
+    ConversationsMembers,
+    /// Get conversation details
+    ConversationsInfo,
+    /// Create a channel
+    ConversationsCreate,
+    /// Archive a channel
+    ConversationsArchive,
+    /// Unarchive a channel
+    ConversationsUnarchive,
+    /// Rename a channel
+    ConversationsRename,
+    /// Invite users to a channel
+    ConversationsInvite,
+    /// Remove a user from a channel
+    ConversationsKick,
+    /// Join a public channel
+    ConversationsJoin,
+    /// Leave a channel
+    ConversationsLeave,
     /// Get user info
     UsersInfo,
     /// List users
     UsersList,
+    /// List conversations a user is a member of
+    UsersConversations,
+    /// List usergroups
+    UsergroupsList,
+    /// Create a usergroup
+    UsergroupsCreate,
+    /// Update a usergroup's name, handle, description or default channels
+    UsergroupsUpdate,
+    /// List the members of a usergroup
+    UsergroupsUsersList,
+    /// Replace the members of a usergroup
+    UsergroupsUsersUpdate,
+    /// List files
+    FilesList,
+    /// Delete a file
+    FilesDelete,
+    /// List custom emoji
+    EmojiList,
     /// Post message
     ChatPostMessage,
     /// Update message
     ChatUpdate,
     /// Delete message
     ChatDelete,
+    /// Get a permalink URL for a message
+    ChatGetPermalink,
+    /// Schedule a message for later delivery
+    ChatScheduleMessage,
+    /// List pending scheduled messages
+    ChatScheduledMessagesList,
+    /// Delete a pending scheduled message
+    ChatDeleteScheduledMessage,
     /// Add reaction
     ReactionsAdd,
     /// Remove reaction
     ReactionsRemove,
+    /// Pin a message
+    PinsAdd,
+    /// Unpin a message
+    PinsRemove,
+    /// List pinned items in a channel
+    PinsList,
+    /// List the bookmarks of a channel
+    BookmarksList,
+    /// Add a bookmark to a channel
+    BookmarksAdd,
+    /// Edit the title, link or emoji of a bookmark
+    BookmarksEdit,
+    /// Remove a bookmark from a channel
+    BookmarksRemove,
+    /// Create a reminder
+    RemindersAdd,
+    /// List reminders
+    RemindersList,
+    /// Mark a reminder as complete
+    RemindersComplete,
+    /// Delete a reminder
+    RemindersDelete,
+    /// Get Do Not Disturb status
+    DndInfo,
+    /// Turn on Do Not Disturb for a number of minutes
+    DndSetSnooze,
+    /// End the current Do Not Disturb snooze
+    DndEndSnooze,
+    /// Get a user's presence
+    UsersGetPresence,
+    /// Set the token user's presence
+    UsersSetPresence,
+    /// Invite an external user or organization to a channel (Slack Connect)
+    ConversationsInviteShared,
+    /// List pending Slack Connect invites
+    ConversationsListConnectInvites,
+    /// Accept a Slack Connect invite
+    ConversationsAcceptSharedInvite,
+    /// Approve a Slack Connect invite as an admin
+    ConversationsApproveSharedInvite,
+    /// Decline a Slack Connect invite
+    ConversationsDeclineSharedInvite,
+    /// List requests to send external invitations
+    ConversationsRequestSharedInviteList,
+    /// Approve a request to send an external invitation
+    ConversationsRequestSharedInviteApprove,
+    /// Deny a request to send an external invitation
+    ConversationsRequestSharedInviteDeny,
+    /// Upgrade or downgrade Slack Connect channel permissions
+    ConversationsExternalInvitePermissionsSet,
+    /// Open a Socket Mode connection (app-level token)
+    AppsConnectionsOpen,
+    /// Reserve an upload URL for a new file
+    FilesGetUploadUrlExternal,
+    /// Finish an external upload and share the file
+    FilesCompleteUploadExternal,
 }
 
 impl ApiMethod {
@@ -35,16 +141,77 @@ impl ApiMethod {
     pub fn as_str(&self) -> &str {
         match self {
             ApiMethod::SearchMessages => "search.messages",
+            ApiMethod::SearchFiles => "search.files",
+            ApiMethod::SearchAll => "search.all",
             ApiMethod::ConversationsList => "conversations.list",
             ApiMethod::ConversationsHistory => "conversations.history",
             ApiMethod::ConversationsReplies => "conversations.replies",
+            ApiMethod::ConversationsMembers => "conversations.members",
+            ApiMethod::ConversationsInfo => "conversations.info",
+            ApiMethod::ConversationsCreate => "conversations.create",
+            ApiMethod::ConversationsArchive => "conversations.archive",
+            ApiMethod::ConversationsUnarchive => "conversations.unarchive",
+            ApiMethod::ConversationsRename => "conversations.rename",
+            ApiMethod::ConversationsInvite => "conversations.invite",
+            ApiMethod::ConversationsKick => "conversations.kick",
+            ApiMethod::ConversationsJoin => "conversations.join",
+            ApiMethod::ConversationsLeave => "conversations.leave",
             ApiMethod::UsersInfo => "users.info",
             ApiMethod::UsersList => "users.list",
+            ApiMethod::UsersConversations => "users.conversations",
+            ApiMethod::UsergroupsList => "usergroups.list",
+            ApiMethod::UsergroupsCreate => "usergroups.create",
+            ApiMethod::UsergroupsUpdate => "usergroups.update",
+            ApiMethod::UsergroupsUsersList => "usergroups.users.list",
+            ApiMethod::UsergroupsUsersUpdate => "usergroups.users.update",
+            ApiMethod::FilesList => "files.list",
+            ApiMethod::FilesDelete => "files.delete",
+            ApiMethod::EmojiList => "emoji.list",
             ApiMethod::ChatPostMessage => "chat.postMessage",
             ApiMethod::ChatUpdate => "chat.update",
             ApiMethod::ChatDelete => "chat.delete",
+            ApiMethod::ChatGetPermalink => "chat.getPermalink",
+            ApiMethod::ChatScheduleMessage => "chat.scheduleMessage",
+            ApiMethod::ChatScheduledMessagesList => "chat.scheduledMessages.list",
+            ApiMethod::ChatDeleteScheduledMessage => "chat.deleteScheduledMessage",
             ApiMethod::ReactionsAdd => "reactions.add",
             ApiMethod::ReactionsRemove => "reactions.remove",
+            ApiMethod::PinsAdd => "pins.add",
+            ApiMethod::PinsRemove => "pins.remove",
+            ApiMethod::PinsList => "pins.list",
+            ApiMethod::BookmarksList => "bookmarks.list",
+            ApiMethod::BookmarksAdd => "bookmarks.add",
+            ApiMethod::BookmarksEdit => "bookmarks.edit",
+            ApiMethod::BookmarksRemove => "bookmarks.remove",
+            ApiMethod::RemindersAdd => "reminders.add",
+            ApiMethod::RemindersList => "reminders.list",
+            ApiMethod::RemindersComplete => "reminders.complete",
+            ApiMethod::RemindersDelete => "reminders.delete",
+            ApiMethod::DndInfo => "dnd.info",
+            ApiMethod::DndSetSnooze => "dnd.setSnooze",
+            ApiMethod::DndEndSnooze => "dnd.endSnooze",
+            ApiMethod::UsersGetPresence => "users.getPresence",
+            ApiMethod::UsersSetPresence => "users.setPresence",
+            ApiMethod::ConversationsInviteShared => "conversations.inviteShared",
+            ApiMethod::ConversationsListConnectInvites => "conversations.listConnectInvites",
+            ApiMethod::ConversationsAcceptSharedInvite => "conversations.acceptSharedInvite",
+            ApiMethod::ConversationsApproveSharedInvite => "conversations.approveSharedInvite",
+            ApiMethod::ConversationsDeclineSharedInvite => "conversations.declineSharedInvite",
+            ApiMethod::ConversationsRequestSharedInviteList => {
+                "conversations.requestSharedInvite.list"
+            }
+            ApiMethod::ConversationsRequestSharedInviteApprove => {
+                "conversations.requestSharedInvite.approve"
+            }
+            ApiMethod::ConversationsRequestSharedInviteDeny => {
+                "conversations.requestSharedInvite.deny"
+            }
+            ApiMethod::ConversationsExternalInvitePermissionsSet => {
+                "conversations.externalInvitePermissions.set"
+            }
+            ApiMethod::AppsConnectionsOpen => "apps.connections.open",
+            ApiMethod::FilesGetUploadUrlExternal => "files.getUploadURLExternal",
+            ApiMethod::FilesCompleteUploadExternal => "files.completeUploadExternal",
         }
     }
 
@@ -53,11 +220,28 @@ impl ApiMethod {
         matches!(
             self,
             ApiMethod::SearchMessages
+                | ApiMethod::SearchFiles
+                | ApiMethod::SearchAll
                 | ApiMethod::ConversationsList
                 | ApiMethod::ConversationsHistory
                 | ApiMethod::ConversationsReplies
+                | ApiMethod::ConversationsMembers
+                | ApiMethod::ConversationsInfo
                 | ApiMethod::UsersInfo
                 | ApiMethod::UsersList
+                | ApiMethod::UsersConversations
+                | ApiMethod::UsergroupsList
+                | ApiMethod::UsergroupsUsersList
+                | ApiMethod::FilesList
+                | ApiMethod::EmojiList
+                | ApiMethod::ChatGetPermalink
+                | ApiMethod::ChatScheduledMessagesList
+                | ApiMethod::PinsList
+                | ApiMethod::BookmarksList
+                | ApiMethod::RemindersList
+                | ApiMethod::DndInfo
+                | ApiMethod::UsersGetPresence
+                | ApiMethod::ConversationsRequestSharedInviteList
         )
     }
 
@@ -69,8 +253,42 @@ impl ApiMethod {
             ApiMethod::ChatPostMessage
                 | ApiMethod::ChatUpdate
                 | ApiMethod::ChatDelete
+                | ApiMethod::ChatScheduleMessage
+                | ApiMethod::ChatDeleteScheduledMessage
                 | ApiMethod::ReactionsAdd
                 | ApiMethod::ReactionsRemove
+                | ApiMethod::PinsAdd
+                | ApiMethod::PinsRemove
+                | ApiMethod::BookmarksAdd
+                | ApiMethod::BookmarksEdit
+                | ApiMethod::BookmarksRemove
+                | ApiMethod::RemindersAdd
+                | ApiMethod::RemindersComplete
+                | ApiMethod::RemindersDelete
+                | ApiMethod::DndSetSnooze
+                | ApiMethod::DndEndSnooze
+                | ApiMethod::UsersSetPresence
+                | ApiMethod::ConversationsCreate
+                | ApiMethod::ConversationsArchive
+                | ApiMethod::ConversationsUnarchive
+                | ApiMethod::ConversationsRename
+                | ApiMethod::ConversationsInvite
+                | ApiMethod::ConversationsKick
+                | ApiMethod::ConversationsJoin
+                | ApiMethod::ConversationsLeave
+                | ApiMethod::UsergroupsCreate
+                | ApiMethod::UsergroupsUpdate
+                | ApiMethod::UsergroupsUsersUpdate
+                | ApiMethod::ConversationsInviteShared
+                | ApiMethod::ConversationsAcceptSharedInvite
+                | ApiMethod::ConversationsApproveSharedInvite
+                | ApiMethod::ConversationsDeclineSharedInvite
+                | ApiMethod::ConversationsRequestSharedInviteApprove
+                | ApiMethod::ConversationsRequestSharedInviteDeny
+                | ApiMethod::ConversationsExternalInvitePermissionsSet
+                | ApiMethod::FilesGetUploadUrlExternal
+                | ApiMethod::FilesCompleteUploadExternal
+                | ApiMethod::FilesDelete
         )
     }
 
@@ -79,7 +297,19 @@ impl ApiMethod {
     pub fn is_destructive(&self) -> bool {
         matches!(
             self,
-            ApiMethod::ChatDelete | ApiMethod::ChatUpdate | ApiMethod::ReactionsRemove
+            ApiMethod::ChatDelete
+                | ApiMethod::ChatUpdate
+                | ApiMethod::ChatDeleteScheduledMessage
+                | ApiMethod::ReactionsRemove
+                | ApiMethod::PinsRemove
+                | ApiMethod::BookmarksRemove
+                | ApiMethod::RemindersDelete
+                | ApiMethod::ConversationsArchive
+                | ApiMethod::ConversationsKick
+                | ApiMethod::UsergroupsUsersUpdate
+                | ApiMethod::ConversationsDeclineSharedInvite
+                | ApiMethod::ConversationsRequestSharedInviteDeny
+                | ApiMethod::FilesDelete
         )
     }
 }
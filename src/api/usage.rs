@@ -0,0 +1,341 @@
+//! Outgoing API call accounting
+//!
+//! Every Slack Web API request made by this process is counted per method.
+//! At the end of a command the counts can be printed (`--report-usage` or
+//! verbose mode) and are folded into daily counters on disk so that
+//! `slack-rs usage` can show consumption trends against rate-limit tiers.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Number of days of history kept in the usage store
+pub const USAGE_RETENTION_DAYS: u64 = 90;
+
+/// Calls made by the current process, keyed by API method
+static SESSION_CALLS: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+/// Usage store errors
+#[derive(Debug, Error)]
+pub enum UsageError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("JSON serialization error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("Usage store error: {0}")]
+    StoreError(String),
+}
+
+/// Slack Web API rate-limit tier
+///
+/// See <https://api.slack.com/apis/rate-limits>. Limits are per method,
+/// per workspace, per app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitTier {
+    Tier1,
+    Tier2,
+    Tier3,
+    Tier4,
+    /// Method-specific limit (e.g. chat.postMessage: ~1 per second per channel)
+    Special,
+    /// Method not in the built-in table
+    Unknown,
+}
+
+impl RateLimitTier {
+    /// Look up the documented tier for an API method
+    pub fn for_method(method: &str) -> Self {
+        match method {
+            "users.list" | "conversations.list" | "search.messages" | "search.files"
+            | "search.all" | "reactions.remove" | "files.list" | "usergroups.list"
+            | "emoji.list" | "pins.list" | "bookmarks.list" | "reminders.add"
+            | "reminders.list" | "reminders.complete" | "reminders.delete" => RateLimitTier::Tier2,
+            "conversations.history"
+            | "conversations.replies"
+            | "chat.update"
+            | "chat.delete"
+            | "chat.scheduleMessage"
+            | "reactions.add"
+            | "conversations.create"
+            | "conversations.archive"
+            | "users.conversations"
+            | "conversations.unarchive"
+            | "conversations.rename"
+            | "conversations.invite"
+            | "conversations.kick"
+            | "conversations.join"
+            | "conversations.leave"
+            | "usergroups.users.list"
+            | "dnd.info"
+            | "pins.add"
+            | "pins.remove"
+            | "files.delete" => RateLimitTier::Tier3,
+            "users.info"
+            | "conversations.members"
+            | "conversations.info"
+            | "chat.getPermalink"
+            | "files.info"
+            | "files.getUploadURLExternal"
+            | "files.completeUploadExternal"
+            | "users.profile.get"
+            | "users.getPresence" => RateLimitTier::Tier4,
+            "chat.postMessage" | "chat.postEphemeral" | "auth.test" => RateLimitTier::Special,
+            "usergroups.create" | "usergroups.update" | "usergroups.users.update" => {
+                RateLimitTier::Tier2
+            }
+            "dnd.setSnooze" | "dnd.endSnooze" | "users.setPresence" => RateLimitTier::Tier2,
+            "bookmarks.add" | "bookmarks.remove" => RateLimitTier::Tier2,
+            "admin.users.list" | "team.accessLogs" => RateLimitTier::Tier1,
+            _ => RateLimitTier::Unknown,
+        }
+    }
+
+    /// Documented minimum number of requests allowed per minute
+    pub fn per_minute(&self) -> Option<u32> {
+        match self {
+            RateLimitTier::Tier1 => Some(1),
+            RateLimitTier::Tier2 => Some(20),
+            RateLimitTier::Tier3 => Some(50),
+            RateLimitTier::Tier4 => Some(100),
+            RateLimitTier::Special | RateLimitTier::Unknown => None,
+        }
+    }
+
+    /// Short label used in reports
+    pub fn label(&self) -> &'static str {
+        match self {
+            RateLimitTier::Tier1 => "tier1",
+            RateLimitTier::Tier2 => "tier2",
+            RateLimitTier::Tier3 => "tier3",
+            RateLimitTier::Tier4 => "tier4",
+            RateLimitTier::Special => "special",
+            RateLimitTier::Unknown => "unknown",
+        }
+    }
+}
+
+/// Record one outgoing request for `method`
+pub(crate) fn record_call(method: &str) {
+    if let Ok(mut calls) = SESSION_CALLS.lock() {
+        *calls.entry(method.to_string()).or_insert(0) += 1;
+    }
+}
+
+/// Snapshot of the calls made so far by this process
+pub fn session_calls() -> BTreeMap<String, u64> {
+    SESSION_CALLS
+        .lock()
+        .map(|calls| calls.clone())
+        .unwrap_or_default()
+}
+
+/// Render per-method counts with their rate-limit tier
+///
+/// Returns `None` when no calls were made.
+pub fn format_usage_report(calls: &BTreeMap<String, u64>) -> Option<String> {
+    if calls.is_empty() {
+        return None;
+    }
+
+    let width = calls.keys().map(|m| m.len()).max().unwrap_or(0);
+    let total: u64 = calls.values().sum();
+    let mut lines = vec![format!("API usage: {} call(s)", total)];
+    for (method, count) in calls {
+        let tier = RateLimitTier::for_method(method);
+        let limit = tier
+            .per_minute()
+            .map(|n| format!(" (~{}/min)", n))
+            .unwrap_or_default();
+        lines.push(format!(
+            "  {:<width$}  {:>5}  {}{}",
+            method,
+            count,
+            tier.label(),
+            limit,
+            width = width
+        ));
+    }
+    Some(lines.join("\n"))
+}
+
+/// Format a UNIX timestamp as a UTC `YYYY-MM-DD` day key
+pub(crate) fn day_key(epoch_secs: u64) -> String {
+    // Civil-from-days conversion (Howard Hinnant's algorithm)
+    let z = (epoch_secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Persistent daily call counters
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UsageStore {
+    /// Day key (`YYYY-MM-DD`, UTC) -> method -> call count
+    days: BTreeMap<String, BTreeMap<String, u64>>,
+
+    /// Store file path
+    #[serde(skip)]
+    store_path: PathBuf,
+}
+
+impl UsageStore {
+    /// Open the store at the default location
+    pub fn open() -> Result<Self, UsageError> {
+        Self::with_path(Self::default_path()?)
+    }
+
+    /// Open the store at a custom path
+    pub fn with_path(store_path: PathBuf) -> Result<Self, UsageError> {
+        let mut store = if store_path.exists() {
+            let content = fs::read_to_string(&store_path)?;
+            serde_json::from_str(&content)?
+        } else {
+            UsageStore::default()
+        };
+        store.store_path = store_path;
+        Ok(store)
+    }
+
+    /// Default store path in the config directory
+    pub fn default_path() -> Result<PathBuf, UsageError> {
+        let config_dir = crate::profile::config_dir()
+            .ok_or_else(|| UsageError::StoreError("Cannot find config directory".into()))?;
+        Ok(config_dir.join("usage.json"))
+    }
+
+    /// Add `calls` to the counters for `day` and drop days past retention
+    pub fn accumulate(&mut self, day: &str, calls: &BTreeMap<String, u64>) {
+        let counters = self.days.entry(day.to_string()).or_default();
+        for (method, count) in calls {
+            *counters.entry(method.clone()).or_insert(0) += count;
+        }
+
+        self.prune_expired(now_secs());
+    }
+
+    /// Drop days older than the retention window, returning how many were dropped
+    pub fn prune_expired(&mut self, now: u64) -> usize {
+        let before = self.days.len();
+        let cutoff = day_key(now.saturating_sub(USAGE_RETENTION_DAYS * 86_400));
+        self.days.retain(|d, _| d.as_str() >= cutoff.as_str());
+        before - self.days.len()
+    }
+
+    /// Daily counters, oldest first
+    pub fn days(&self) -> &BTreeMap<String, BTreeMap<String, u64>> {
+        &self.days
+    }
+
+    /// Persist the store to disk
+    pub fn save(&self) -> Result<(), UsageError> {
+        if let Some(parent) = self.store_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        let temp_path = self.store_path.with_extension("json.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, &self.store_path)?;
+        Ok(())
+    }
+}
+
+/// Fold the calls made since the last flush into today's counters on disk
+///
+/// Does nothing when no calls were made.
+pub fn flush_session_usage() -> Result<(), UsageError> {
+    let calls = SESSION_CALLS
+        .lock()
+        .map(|mut calls| std::mem::take(&mut *calls))
+        .unwrap_or_default();
+    if calls.is_empty() {
+        return Ok(());
+    }
+    let mut store = UsageStore::open()?;
+    store.accumulate(&day_key(now_secs()), &calls);
+    store.save()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_tier_lookup() {
+        assert_eq!(
+            RateLimitTier::for_method("conversations.history"),
+            RateLimitTier::Tier3
+        );
+        assert_eq!(
+            RateLimitTier::for_method("users.list"),
+            RateLimitTier::Tier2
+        );
+        assert_eq!(
+            RateLimitTier::for_method("chat.postMessage"),
+            RateLimitTier::Special
+        );
+        assert_eq!(RateLimitTier::for_method("made.up"), RateLimitTier::Unknown);
+        assert_eq!(RateLimitTier::Tier4.per_minute(), Some(100));
+        assert_eq!(RateLimitTier::Special.per_minute(), None);
+    }
+
+    #[test]
+    fn test_day_key() {
+        assert_eq!(day_key(0), "1970-01-01");
+        assert_eq!(day_key(951_782_400), "2000-02-29");
+        assert_eq!(day_key(1_700_000_000), "2023-11-14");
+    }
+
+    #[test]
+    fn test_format_usage_report() {
+        assert!(format_usage_report(&BTreeMap::new()).is_none());
+
+        let mut calls = BTreeMap::new();
+        calls.insert("conversations.history".to_string(), 3);
+        calls.insert("chat.postMessage".to_string(), 1);
+        let report = format_usage_report(&calls).unwrap();
+        assert!(report.starts_with("API usage: 4 call(s)"));
+        assert!(report.contains("conversations.history"));
+        assert!(report.contains("tier3 (~50/min)"));
+        assert!(report.contains("special"));
+    }
+
+    #[test]
+    fn test_store_accumulates_and_persists() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("usage.json");
+        let today = day_key(now_secs());
+
+        let mut calls = BTreeMap::new();
+        calls.insert("users.info".to_string(), 2);
+
+        let mut store = UsageStore::with_path(path.clone()).unwrap();
+        store.accumulate(&today, &calls);
+        store.accumulate(&today, &calls);
+        store.accumulate("1999-01-01", &calls);
+        store.save().unwrap();
+
+        let reloaded = UsageStore::with_path(path).unwrap();
+        assert_eq!(reloaded.days().len(), 1, "expired days are dropped");
+        assert_eq!(reloaded.days()[&today]["users.info"], 4);
+    }
+}
@@ -7,6 +7,7 @@ use crate::profile::{
     store_oauth_client_secret, Profile, TokenStore, TokenStoreError,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::io;
 use std::path::Path;
@@ -26,6 +27,12 @@ pub enum ExportImportError {
     ProfileExists(String),
     #[error("Empty passphrase not allowed")]
     EmptyPassphrase,
+    #[error("Invalid profile mapping '{0}' (expected old:new)")]
+    InvalidMapping(String),
+    #[error("Profile not found in import file: {0}")]
+    ProfileNotInBundle(String),
+    #[error("Multiple profiles would be imported as '{0}'")]
+    DuplicateTarget(String),
     #[cfg(unix)]
     #[error("File permission error: {0}")]
     PermissionError(String),
@@ -54,7 +61,7 @@ pub struct ExportOptions {
 }
 
 /// Options for import command
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ImportOptions {
     pub input_path: String,
     pub passphrase: String,
@@ -62,6 +69,42 @@ pub struct ImportOptions {
     pub force: bool,
     pub dry_run: bool,
     pub json: bool,
+    /// Import only these profiles (names as stored in the bundle)
+    pub only: Option<Vec<String>>,
+    /// Explicit renames from bundle profile name to local profile name
+    pub rename_map: HashMap<String, String>,
+    /// Prefix applied to imported profile names not covered by `rename_map`
+    pub prefix: Option<String>,
+}
+
+impl ImportOptions {
+    /// Resolve the local profile name for a profile from the bundle
+    pub fn target_name(&self, source_name: &str) -> String {
+        if let Some(mapped) = self.rename_map.get(source_name) {
+            mapped.clone()
+        } else if let Some(prefix) = &self.prefix {
+            format!("{}{}", prefix, source_name)
+        } else {
+            source_name.to_string()
+        }
+    }
+}
+
+/// Parse a profile mapping in `old:new` form
+///
+/// # Arguments
+/// * `spec` - Mapping string, e.g. "work:work-old"
+///
+/// # Returns
+/// * `Ok((old, new))` if both names are non-empty
+/// * `Err(ExportImportError::InvalidMapping)` otherwise
+pub fn parse_profile_mapping(spec: &str) -> Result<(String, String)> {
+    match spec.split_once(':') {
+        Some((old, new)) if !old.trim().is_empty() && !new.trim().is_empty() => {
+            Ok((old.trim().to_string(), new.trim().to_string()))
+        }
+        _ => Err(ExportImportError::InvalidMapping(spec.to_string())),
+    }
 }
 
 /// Import action taken for a profile
@@ -87,6 +130,9 @@ impl std::fmt::Display for ImportAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProfileImportResult {
     pub profile_name: String,
+    /// Original name in the bundle when the profile was renamed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
     pub action: ImportAction,
     pub reason: String,
 }
@@ -277,11 +323,13 @@ pub fn import_profiles(
         ));
     }
 
+    let selected = select_profiles(payload.profiles, options)?;
+
     // Track results for each profile
     let mut profile_results = Vec::new();
 
     // Import profiles - no early validation, handle conflicts during import
-    for (name, export_profile) in payload.profiles {
+    for (name, source_name, export_profile) in selected {
         // Helper to find conflicting profile name (different name, same team_id)
         let find_conflicting_name = || -> Option<String> {
             config
@@ -382,6 +430,8 @@ pub fn import_profiles(
                 bot_scopes: None,   // Not exported/imported for security
                 user_scopes: None,  // Not exported/imported for security
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             };
 
             config.set(name.clone(), profile);
@@ -407,6 +457,7 @@ pub fn import_profiles(
 
         profile_results.push(ProfileImportResult {
             profile_name: name,
+            source_name,
             action,
             reason,
         });
@@ -445,6 +496,48 @@ pub fn import_profiles(
     })
 }
 
+/// Apply `--only` selection and renaming to the profiles in a bundle
+///
+/// Returns `(target_name, source_name, profile)` tuples sorted by target name,
+/// where `source_name` is set only when the profile was renamed.
+fn select_profiles(
+    profiles: HashMap<String, ExportProfile>,
+    options: &ImportOptions,
+) -> Result<Vec<(String, Option<String>, ExportProfile)>> {
+    let requested = options
+        .only
+        .iter()
+        .flatten()
+        .chain(options.rename_map.keys());
+    for name in requested {
+        if !profiles.contains_key(name) {
+            return Err(ExportImportError::ProfileNotInBundle(name.clone()));
+        }
+    }
+
+    let mut selected: Vec<(String, Option<String>, ExportProfile)> = profiles
+        .into_iter()
+        .filter(|(name, _)| {
+            options
+                .only
+                .as_ref()
+                .is_none_or(|only| only.iter().any(|n| n == name))
+        })
+        .map(|(name, profile)| {
+            let target = options.target_name(&name);
+            let source = (target != name).then_some(name);
+            (target, source, profile)
+        })
+        .collect();
+
+    selected.sort_by(|a, b| a.0.cmp(&b.0));
+    if let Some(dup) = selected.windows(2).find(|w| w[0].0 == w[1].0) {
+        return Err(ExportImportError::DuplicateTarget(dup[0].0.clone()));
+    }
+
+    Ok(selected)
+}
+
 /// Check file permissions (Unix only)
 #[cfg(unix)]
 fn check_file_permissions(path: &Path) -> Result<()> {
@@ -552,6 +645,7 @@ mod tests {
             force: false,
             dry_run: false,
             json: false,
+            ..Default::default()
         };
 
         let result = import_profiles(&token_store, &options);
@@ -562,6 +656,102 @@ mod tests {
         ));
     }
 
+    fn sample_export_profile(team_id: &str) -> ExportProfile {
+        ExportProfile {
+            team_id: team_id.to_string(),
+            user_id: "U1".to_string(),
+            team_name: None,
+            user_name: None,
+            token: "xoxb-test".to_string(),
+            client_id: None,
+            client_secret: None,
+            user_token: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_profile_mapping() {
+        assert_eq!(
+            parse_profile_mapping("work:work-old").unwrap(),
+            ("work".to_string(), "work-old".to_string())
+        );
+        assert!(matches!(
+            parse_profile_mapping("work"),
+            Err(ExportImportError::InvalidMapping(_))
+        ));
+        assert!(parse_profile_mapping(":new").is_err());
+        assert!(parse_profile_mapping("old:").is_err());
+    }
+
+    #[test]
+    fn test_import_options_target_name() {
+        let mut options = ImportOptions {
+            prefix: Some("imported-".to_string()),
+            ..Default::default()
+        };
+        options
+            .rename_map
+            .insert("work".to_string(), "work-old".to_string());
+
+        assert_eq!(options.target_name("work"), "work-old");
+        assert_eq!(options.target_name("personal"), "imported-personal");
+        assert_eq!(ImportOptions::default().target_name("work"), "work");
+    }
+
+    #[test]
+    fn test_select_profiles_only_and_rename() {
+        let mut profiles = HashMap::new();
+        profiles.insert("work".to_string(), sample_export_profile("T1"));
+        profiles.insert("personal".to_string(), sample_export_profile("T2"));
+        profiles.insert("other".to_string(), sample_export_profile("T3"));
+
+        let mut options = ImportOptions {
+            only: Some(vec!["work".to_string(), "personal".to_string()]),
+            ..Default::default()
+        };
+        options
+            .rename_map
+            .insert("work".to_string(), "work-old".to_string());
+
+        let selected = select_profiles(profiles, &options).unwrap();
+        let names: Vec<_> = selected
+            .iter()
+            .map(|(target, source, _)| (target.as_str(), source.as_deref()))
+            .collect();
+        assert_eq!(names, vec![("personal", None), ("work-old", Some("work"))]);
+    }
+
+    #[test]
+    fn test_select_profiles_unknown_name() {
+        let mut profiles = HashMap::new();
+        profiles.insert("work".to_string(), sample_export_profile("T1"));
+
+        let options = ImportOptions {
+            only: Some(vec!["missing".to_string()]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            select_profiles(profiles, &options),
+            Err(ExportImportError::ProfileNotInBundle(name)) if name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_select_profiles_duplicate_target() {
+        let mut profiles = HashMap::new();
+        profiles.insert("work".to_string(), sample_export_profile("T1"));
+        profiles.insert("personal".to_string(), sample_export_profile("T2"));
+
+        let mut options = ImportOptions::default();
+        options
+            .rename_map
+            .insert("work".to_string(), "personal".to_string());
+        assert!(matches!(
+            select_profiles(profiles, &options),
+            Err(ExportImportError::DuplicateTarget(name)) if name == "personal"
+        ));
+    }
+
     #[test]
     fn test_export_import_round_trip() {
         let temp_dir = TempDir::new().unwrap();
@@ -583,6 +773,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         save_config(&config_path, &config).unwrap();
@@ -642,6 +834,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         save_config(&config_path, &config).unwrap();
@@ -689,6 +883,7 @@ mod tests {
             force: false,
             dry_run: true,
             json: false,
+            ..Default::default()
         };
 
         let result = import_profiles(&token_store, &options).unwrap();
@@ -751,6 +946,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         config.set(
@@ -766,6 +963,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         save_config(&config_path, &config).unwrap();
@@ -829,6 +1028,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         config.set(
@@ -844,6 +1045,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         save_config(&config_path, &config).unwrap();
@@ -900,6 +1103,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         save_config(&config_path, &config).unwrap();
@@ -961,6 +1166,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         save_config(&config_path, &config).unwrap();
@@ -996,6 +1203,7 @@ mod tests {
             force: false,
             dry_run: false,
             json: false,
+            ..Default::default()
         };
         let import_result = import_profiles(&token_store, &import_options).unwrap();
         assert_eq!(import_result.summary.updated, 1);
@@ -1041,6 +1249,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         save_config(&config_path, &config).unwrap();
@@ -1073,6 +1283,7 @@ mod tests {
             force: false,
             dry_run: false,
             json: false,
+            ..Default::default()
         };
         let import_result = import_profiles(&token_store, &import_options).unwrap();
         assert_eq!(import_result.summary.updated, 1);
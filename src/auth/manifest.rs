@@ -4,6 +4,7 @@
 //! from OAuth configuration and scope information.
 
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// Slack App Manifest structure
 #[derive(Debug, Serialize, Deserialize)]
@@ -147,10 +148,101 @@ pub fn generate_manifest(
     Ok(yaml_string)
 }
 
+/// Read the bot and user scopes declared in an existing app manifest
+///
+/// Accepts YAML or JSON manifests; only `oauth_config.scopes` is read, so
+/// the rest of the manifest may use any fields Slack supports. Both lists
+/// are always returned (empty when a section is absent) so the login
+/// requests exactly what the manifest declares.
+pub fn scopes_from_manifest(content: &str) -> Result<Scopes, String> {
+    let manifest: serde_yaml::Value =
+        serde_yaml::from_str(content).map_err(|e| format!("Failed to parse manifest: {}", e))?;
+    let scopes = &manifest["oauth_config"]["scopes"];
+
+    let list = |kind: &str| -> Result<Vec<String>, String> {
+        match &scopes[kind] {
+            serde_yaml::Value::Null => Ok(Vec::new()),
+            serde_yaml::Value::Sequence(items) => items
+                .iter()
+                .map(|item| {
+                    item.as_str().map(|s| s.trim().to_string()).ok_or_else(|| {
+                        format!("oauth_config.scopes.{} must be a list of strings", kind)
+                    })
+                })
+                .collect(),
+            _ => Err(format!(
+                "oauth_config.scopes.{} must be a list of strings",
+                kind
+            )),
+        }
+    };
+    let bot = list("bot")?;
+    let user = list("user")?;
+    if bot.is_empty() && user.is_empty() {
+        return Err("Manifest declares no scopes under oauth_config.scopes".to_string());
+    }
+
+    Ok(Scopes {
+        bot: Some(bot),
+        user: Some(user),
+    })
+}
+
+/// Read a manifest file and extract its scopes
+pub fn load_manifest_scopes(path: &Path) -> Result<Scopes, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read manifest {}: {}", path.display(), e))?;
+    scopes_from_manifest(&content).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_scopes_from_manifest_yaml() {
+        let yaml = r#"
+display_information:
+  name: Deploy Bot
+features:
+  bot_user:
+    display_name: deploy-bot
+oauth_config:
+  scopes:
+    bot:
+      - chat:write
+      - channels:history
+settings:
+  socket_mode_enabled: true
+"#;
+        let scopes = scopes_from_manifest(yaml).unwrap();
+        assert_eq!(
+            scopes.bot,
+            Some(vec![
+                "chat:write".to_string(),
+                "channels:history".to_string()
+            ])
+        );
+        assert_eq!(scopes.user, Some(vec![]));
+    }
+
+    #[test]
+    fn test_scopes_from_manifest_json_and_errors() {
+        let json =
+            r#"{"oauth_config": {"scopes": {"bot": ["chat:write"], "user": ["search:read"]}}}"#;
+        let scopes = scopes_from_manifest(json).unwrap();
+        assert_eq!(scopes.user, Some(vec!["search:read".to_string()]));
+
+        assert!(scopes_from_manifest("display_information:\n  name: x\n")
+            .unwrap_err()
+            .contains("no scopes"));
+        assert!(
+            scopes_from_manifest("oauth_config:\n  scopes:\n    bot: chat:write\n")
+                .unwrap_err()
+                .contains("list of strings")
+        );
+    }
+
     #[test]
     fn test_generate_manifest_with_bot_scopes_only() {
         let bot_scopes = vec!["chat:write".to_string(), "users:read".to_string()];
@@ -6,6 +6,8 @@
 //! - list: List all profiles
 //! - rename: Rename a profile
 //! - logout: Remove authentication
+//! - set-token: Store a token (e.g. an app-level xapp token) for a profile
+//! - tokens list: List token store keys with redacted values
 //! - export: Export profiles to encrypted file
 //! - import: Import profiles from encrypted file
 
@@ -18,16 +20,22 @@ pub mod format;
 pub mod i18n;
 pub mod manifest;
 pub mod ngrok;
+pub mod tokens;
 
 pub use cloudflared::{CloudflaredError, CloudflaredTunnel};
 pub use commands::{
-    list, login_with_credentials, login_with_credentials_extended, logout,
-    prompt_for_client_secret, rename, status, ExtendedLoginOptions,
+    build_profile_list, list, list_json, login_with_credentials, login_with_credentials_extended,
+    logout, prompt_for_client_secret, record_auth_test_identity, record_auth_test_success,
+    refresh_profile_names, rename, set_token, status, ExtendedLoginOptions, OAuthConfigPresence,
+    ProfileListEntry,
 };
 pub use export_import::{
-    export_profiles, import_profiles, ExportOptions, ExportResult, ImportAction, ImportOptions,
-    ImportResult, ImportSummary, ProfileImportResult,
+    export_profiles, import_profiles, parse_profile_mapping, ExportOptions, ExportResult,
+    ImportAction, ImportOptions, ImportResult, ImportSummary, ProfileImportResult,
 };
 pub use i18n::{Language, Messages};
 pub use manifest::generate_manifest;
 pub use ngrok::{NgrokError, NgrokTunnel};
+pub use tokens::{
+    build_token_list, format_token_list_text, redact_secret, token_list, TokenStoreEntry,
+};
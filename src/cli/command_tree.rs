@@ -0,0 +1,532 @@
+//! clap command tree generated from the introspection definitions
+//!
+//! Every command line is parsed with this tree before it is dispatched, so
+//! unknown commands and flags, flags missing their value and malformed
+//! numbers are rejected the same way for every command, and `--help` is
+//! rendered from the same [`CommandDef`]s as `commands --json`. `main`
+//! dispatches on the subcommand path clap matched ([`parse_args`]); the
+//! handlers read their values from the validated command line, so they only
+//! ever see flags their definition declares.
+
+use super::introspection::get_command_definitions;
+use super::CommandDef;
+use clap::{Arg, ArgAction, Command};
+use std::collections::BTreeMap;
+
+/// Flags accepted by every command: (name, value name, description)
+///
+/// Most are consumed in `main` before dispatch; the value name is `None`
+/// for boolean flags.
+pub const GLOBAL_FLAGS: &[(&str, Option<&str>, &str)] = &[
+    ("--profile", Some("name"), "Profile name"),
+    ("--non-interactive", None, "Run without interactive prompts"),
+    ("--format", Some("fmt"), "Output format"),
+    ("--columns", Some("a,b.c"), "Columns for table/tsv/csv"),
+    (
+        "--query",
+        Some("expr"),
+        "Print only the values extracted by a jq-style path",
+    ),
+    ("--jq", Some("expr"), "Alias of --query"),
+    (
+        "--results-dir",
+        Some("dir"),
+        "Also save each JSON envelope in dir",
+    ),
+    (
+        "--results-keep",
+        Some("n"),
+        "Result files kept in --results-dir",
+    ),
+    ("--lang", Some("en|ja"), "Language for messages"),
+    ("--no-color", None, "Disable colored output"),
+    ("--debug", None, "Show debug information"),
+    ("--trace", None, "Show verbose trace information"),
+    ("--explain", None, "Show effective output settings"),
+    (
+        "--report-usage",
+        None,
+        "Print API calls per method when done",
+    ),
+    ("--progress", Some("json"), "Progress events on stderr"),
+    (
+        "--max-retries",
+        Some("n"),
+        "Retries for rate-limited requests",
+    ),
+    (
+        "--max-writes",
+        Some("n"),
+        "Refuse write API calls beyond n in this invocation",
+    ),
+    (
+        "--max-writes-per-day",
+        Some("n"),
+        "Refuse write API calls beyond n per UTC day",
+    ),
+    ("--gha", None, "GitHub Actions mode"),
+    (
+        "--stdin",
+        None,
+        "Read parameters from a JSON object (or JSONL stream) on stdin",
+    ),
+    (
+        "--input",
+        Some("json"),
+        "Read write command parameters from a JSON object on stdin",
+    ),
+    (
+        "--context",
+        Some("key=value"),
+        "Override the saved session context",
+    ),
+    ("--allow-write", None, "Enable write operations"),
+    (
+        "--config-dir",
+        Some("dir"),
+        "Directory for profiles, tokens and caches",
+    ),
+    (
+        "--ephemeral",
+        None,
+        "Use a temporary config directory for this run",
+    ),
+];
+
+/// Value names for flags whose usage string does not name the value
+const VALUE_NAMES: &[(&str, &str)] = &[
+    ("--profile", "name"),
+    ("--out", "file"),
+    ("--in", "file"),
+    ("--passphrase-env", "var"),
+    ("--token-type", "type"),
+    ("--idempotency-key", "key"),
+    ("--channel", "channel"),
+    ("--channels", "channels"),
+    ("--user", "user"),
+    ("--cursor", "cursor"),
+    ("--filter", "key:value"),
+    ("--types", "types"),
+    ("--oldest", "ts"),
+    ("--latest", "ts"),
+    ("--thread-ts", "ts"),
+];
+
+const HELP_TEMPLATE: &str = "\
+{about-with-newline}
+USAGE:
+    {usage}
+
+{all-args}{after-help}";
+
+/// Build the command tree from the introspection definitions
+pub fn build_command() -> Command {
+    build_command_from(&get_command_definitions())
+}
+
+/// Build the command tree from `defs`
+pub fn build_command_from(defs: &[CommandDef]) -> Command {
+    let mut root = Command::new("slack-rs")
+        .bin_name("slack-rs")
+        .disable_help_flag(true)
+        .disable_version_flag(true)
+        .disable_help_subcommand(true)
+        .subcommand_help_heading("COMMANDS")
+        .args_override_self(true)
+        .help_template(HELP_TEMPLATE);
+    for (name, value_name, description) in GLOBAL_FLAGS {
+        root = root.arg(
+            flag_arg(name, *value_name, description, false)
+                .help_heading("GLOBAL OPTIONS")
+                .global(true),
+        );
+    }
+    root = root.arg(
+        Arg::new("help")
+            .short('h')
+            .long("help")
+            .action(ArgAction::Help)
+            .help("Show this help message")
+            .help_heading("OPTIONS")
+            .global(true),
+    );
+
+    let by_name: BTreeMap<&str, &CommandDef> = defs.iter().map(|d| (d.name.as_str(), d)).collect();
+    let mut children: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for def in defs {
+        let words: Vec<&str> = def.name.split_whitespace().collect();
+        for depth in 0..words.len() {
+            let siblings = children.entry(words[..depth].join(" ")).or_default();
+            if !siblings.iter().any(|w| w == words[depth]) {
+                siblings.push(words[depth].to_string());
+            }
+        }
+    }
+    for word in children.get("").into_iter().flatten() {
+        root = root.subcommand(build_node(word, &by_name, &children));
+    }
+    root
+}
+
+fn build_node(
+    path: &str,
+    by_name: &BTreeMap<&str, &CommandDef>,
+    children: &BTreeMap<String, Vec<String>>,
+) -> Command {
+    let name = path.rsplit(' ').next().unwrap_or(path).to_string();
+    let mut cmd = Command::new(name)
+        .subcommand_help_heading("COMMANDS")
+        .args_override_self(true)
+        .help_template(HELP_TEMPLATE);
+
+    match by_name.get(path) {
+        Some(def) => {
+            cmd = cmd
+                .about(def.description.clone())
+                .override_usage(def.usage.clone())
+                .arg(
+                    Arg::new("args")
+                        .num_args(0..)
+                        .action(ArgAction::Append)
+                        .hide(true),
+                );
+            let usage_values = usage_value_names(&def.usage);
+            for flag in def.flags.iter().filter(|f| f.name.starts_with("--")) {
+                let value_name = (flag.flag_type != "boolean").then(|| {
+                    usage_values
+                        .get(flag.name.as_str())
+                        .copied()
+                        .or_else(|| value_name_for(&flag.name))
+                        .unwrap_or(if flag.flag_type == "integer" {
+                            "n"
+                        } else {
+                            "value"
+                        })
+                });
+                let mut help = flag.description.clone();
+                if let Some(default) = &flag.default {
+                    help.push_str(&format!(" [default: {}]", default));
+                }
+                if flag.required {
+                    help.push_str(" (required)");
+                }
+                cmd = cmd.arg(flag_arg(
+                    &flag.name,
+                    value_name,
+                    &help,
+                    flag.flag_type == "integer",
+                ));
+            }
+            if !def.examples.is_empty() {
+                let mut examples = String::from("\nEXAMPLES:\n");
+                for example in &def.examples {
+                    examples.push_str(&format!(
+                        "    # {}\n    {}\n\n",
+                        example.description, example.command
+                    ));
+                }
+                cmd = cmd.after_help(examples.trim_end().to_string());
+            }
+        }
+        None => {
+            cmd = cmd.override_usage(format!("slack-rs {} <COMMAND> [OPTIONS]", path));
+        }
+    }
+
+    for word in children.get(path).into_iter().flatten() {
+        cmd = cmd.subcommand(build_node(&format!("{} {}", path, word), by_name, children));
+    }
+    cmd
+}
+
+fn flag_arg(name: &str, value_name: Option<&str>, help: &str, integer: bool) -> Arg {
+    let long = name.trim_start_matches("--").to_string();
+    let arg = Arg::new(long.clone())
+        .long(long)
+        .help(help.to_string())
+        .help_heading("OPTIONS");
+    match value_name {
+        None => arg.action(ArgAction::SetTrue),
+        Some(value_name) => {
+            let arg = arg
+                .num_args(1)
+                .action(ArgAction::Append)
+                .value_name(value_name.to_string());
+            if integer {
+                arg.value_parser(clap::value_parser!(i64))
+            } else {
+                arg
+            }
+        }
+    }
+}
+
+fn value_name_for(flag: &str) -> Option<&'static str> {
+    VALUE_NAMES
+        .iter()
+        .find(|(name, _)| *name == flag)
+        .map(|(_, value)| *value)
+}
+
+/// Value names written in a usage string (`--limit=N`, `--out <file>`)
+fn usage_value_names(usage: &str) -> BTreeMap<&str, &str> {
+    let tokens: Vec<&str> = usage
+        .split_whitespace()
+        .map(|t| t.trim_matches(|c| c == '[' || c == ']'))
+        .collect();
+    let mut names = BTreeMap::new();
+    for (i, token) in tokens.iter().enumerate() {
+        if !token.starts_with("--") {
+            continue;
+        }
+        let (flag, value) = match token.split_once('=') {
+            Some((flag, value)) => (flag, Some(value)),
+            None => (
+                *token,
+                tokens.get(i + 1).filter(|v| v.starts_with('<')).copied(),
+            ),
+        };
+        if let Some(value) = value {
+            let value = value.trim_matches(|c| c == '<' || c == '>');
+            if !value.is_empty() && !value.contains("...") {
+                names.insert(flag, value);
+            }
+        }
+    }
+    names
+}
+
+/// Check a command line (including the program name) against the tree
+///
+/// Help requests come back as an error whose kind is
+/// [`clap::error::ErrorKind::DisplayHelp`].
+pub fn validate_args(args: &[String]) -> Result<(), clap::Error> {
+    parse_args(args).map(|_| ())
+}
+
+/// Parse a command line (including the program name) with the tree
+///
+/// Returns the subcommand path clap matched (`["msg", "scheduled", "list"]`);
+/// `main` dispatches on it, and the handlers read their values from the
+/// validated command line as typed.
+///
+/// Tokens starting with a single `-` (other than `-h`) are not flags for
+/// this CLI, so they are parsed as positional values (e.g. message text).
+/// Help requests come back as an error whose kind is
+/// [`clap::error::ErrorKind::DisplayHelp`].
+pub fn parse_args(args: &[String]) -> Result<Vec<String>, clap::Error> {
+    let mut after_separator = false;
+    let tokens = args.iter().enumerate().map(|(i, arg)| {
+        if arg == "--" {
+            after_separator = true;
+        }
+        if i > 0
+            && !after_separator
+            && arg.starts_with('-')
+            && !arg.starts_with("--")
+            && arg != "-h"
+            && arg != "-"
+        {
+            format!("\\{}", arg)
+        } else {
+            arg.clone()
+        }
+    });
+    let matches = build_command().try_get_matches_from(tokens)?;
+    let mut path = Vec::new();
+    let mut leaf = &matches;
+    while let Some((name, sub)) = leaf.subcommand() {
+        path.push(name.to_string());
+        leaf = sub;
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        std::iter::once("slack-rs")
+            .chain(line.split_whitespace())
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_build_command_debug_asserts() {
+        build_command().debug_assert();
+    }
+
+    #[test]
+    fn test_validate_args() {
+        assert!(validate_args(&args("conv list --limit=5 --format table")).is_ok());
+        assert!(validate_args(&args("msg post C123 hello --thread-ts 1.2 --profile work")).is_ok());
+        assert!(validate_args(&args("msg post C123 -not-a-flag")).is_ok());
+        assert!(validate_args(&args("api call chat.postMessage channel=C1 --json")).is_ok());
+        assert!(validate_args(&args("usergroup users S123")).is_ok());
+        assert!(validate_args(&args("usergroup users update S123 --users=U1")).is_ok());
+        assert!(validate_args(&args("conv")).is_ok());
+
+        assert!(validate_args(&args("conv list --limt=5")).is_err());
+        assert!(validate_args(&args("conv lsit")).is_err());
+        assert!(validate_args(&args("conv list --limit=ten")).is_err());
+        assert!(validate_args(&args("conv history C1 --limit")).is_err());
+        assert!(validate_args(&args("nonexistent")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_path() {
+        let path = |line: &str| parse_args(&args(line)).unwrap();
+        assert_eq!(
+            path("msg post --thread-ts 1.2 C123 -hello --profile work"),
+            vec!["msg", "post"]
+        );
+        assert_eq!(
+            path("usergroup users update S123 --users=U1"),
+            vec!["usergroup", "users", "update"]
+        );
+        assert_eq!(
+            path("ctx show --context channel=C1 thread=1.2"),
+            vec!["ctx", "show"]
+        );
+        assert_eq!(path("conv"), vec!["conv"]);
+    }
+
+    #[test]
+    fn test_help_on_every_command() {
+        for def in get_command_definitions() {
+            let line = format!("{} --help", def.name);
+            let err = validate_args(&args(&line)).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                clap::error::ErrorKind::DisplayHelp,
+                "{}",
+                def.name
+            );
+            let help = err.to_string();
+            assert!(help.contains(&def.description), "{}", def.name);
+            assert!(help.contains("USAGE:"), "{}", def.name);
+            assert!(help.contains("-h, --help"), "{}", def.name);
+        }
+    }
+
+    #[test]
+    fn test_help_value_names() {
+        let help = validate_args(&args("auth export --help"))
+            .unwrap_err()
+            .to_string();
+        assert!(help.contains("--out <file>"));
+        assert!(help.contains("EXAMPLES:"));
+        assert_eq!(
+            usage_value_names("slack-rs conv history <channel> [--limit=N] [--out <path>]"),
+            BTreeMap::from([("--limit", "N"), ("--out", "path")])
+        );
+    }
+
+    /// Every documented invocation must be accepted by the tree
+    #[test]
+    fn test_documented_examples_validate() {
+        let docs = [
+            include_str!("../../docs/commands.md"),
+            include_str!("../../docs/recipes.md"),
+            include_str!("../../README.md"),
+        ];
+        let mut failures = Vec::new();
+        for line in docs.iter().flat_map(|d| d.lines()) {
+            let Some(rest) = line.trim().strip_prefix("slack-rs ") else {
+                continue;
+            };
+            let tokens = shell_words(rest);
+            if tokens.is_empty() || tokens.iter().any(|t| t.contains("...")) {
+                continue;
+            }
+            let mut argv = vec!["slack-rs".to_string()];
+            argv.extend(tokens.into_iter().map(placeholder_value));
+            // Skip the generic syntax line and top-level help/version
+            if argv[1].starts_with('[')
+                || matches!(argv[1].as_str(), "--help" | "-h" | "--version" | "-v")
+            {
+                continue;
+            }
+            if let Err(e) = validate_args(&argv) {
+                if e.kind() != clap::error::ErrorKind::DisplayHelp {
+                    failures.push(format!("{}\n  {}", line.trim(), error_summary(&e)));
+                }
+            }
+        }
+        for def in get_command_definitions() {
+            for example in &def.examples {
+                let Some(rest) = example.command.strip_prefix("slack-rs ") else {
+                    continue;
+                };
+                let mut argv = vec!["slack-rs".to_string()];
+                argv.extend(shell_words(rest).into_iter().map(placeholder_value));
+                if let Err(e) = validate_args(&argv) {
+                    if e.kind() != clap::error::ErrorKind::DisplayHelp {
+                        failures.push(format!("{}\n  {}", example.command, error_summary(&e)));
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    fn error_summary(e: &clap::Error) -> String {
+        use clap::error::ContextKind;
+        let detail = [ContextKind::InvalidArg, ContextKind::InvalidSubcommand]
+            .into_iter()
+            .find_map(|kind| e.get(kind))
+            .map(|value| value.to_string())
+            .unwrap_or_default();
+        format!("{}: {}", e.kind(), detail)
+    }
+
+    /// Split a documented command line: quotes, and stop at pipes,
+    /// redirections and comments
+    fn shell_words(line: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut current = String::new();
+        let mut quote = None;
+        let mut in_word = false;
+        for c in line.chars() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), c) => current.push(c),
+                (None, '\'' | '"') => {
+                    quote = Some(c);
+                    in_word = true;
+                }
+                (None, c) if c.is_whitespace() => {
+                    if in_word {
+                        words.push(std::mem::take(&mut current));
+                        in_word = false;
+                    }
+                }
+                (None, '|' | '>' | '#' | ';' | '&' | '`') if !in_word => break,
+                (None, c) => {
+                    current.push(c);
+                    in_word = true;
+                }
+            }
+        }
+        if in_word {
+            words.push(current);
+        }
+        words
+    }
+
+    /// Documented placeholders such as `--limit=N` or `--days <n>`
+    fn placeholder_value(token: String) -> String {
+        let is_placeholder = |v: &str| {
+            v.starts_with('<') || v.starts_with('$') || (!v.is_empty() && v == v.to_uppercase())
+        };
+        match token.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") && is_placeholder(value) => {
+                format!("{}=1", flag)
+            }
+            _ if token.starts_with('<') || token.starts_with('$') => "1".to_string(),
+            _ => token,
+        }
+    }
+}
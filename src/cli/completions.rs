@@ -0,0 +1,632 @@
+//! Shell completion scripts (`slack-rs completions <shell>`)
+//!
+//! Scripts are generated from the introspection [`CommandDef`]s: command
+//! paths, subcommands and per-command flags are embedded in the script.
+//! Profile names and channels change over time, so the scripts call back
+//! into `slack-rs completions --list=profiles|channels`, which reads the
+//! profiles config and the channel cache kept by `shell` (no API calls).
+
+use super::command_tree::GLOBAL_FLAGS;
+use super::shell::ChannelNameCache;
+use super::{generate_commands_list, get_option, resolve_profile_name, CommandDef};
+use crate::profile::{default_config_path, load_config};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Target shell of a completion script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+}
+
+impl CompletionShell {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            "powershell" | "pwsh" => Ok(Self::PowerShell),
+            _ => Err(format!(
+                "Unknown shell '{}'. Valid values: bash, zsh, fish, powershell",
+                s
+            )),
+        }
+    }
+}
+
+/// Flag of a command as used by the scripts
+#[derive(Debug, Clone, PartialEq)]
+struct CompletionFlag {
+    name: String,
+    description: String,
+    takes_value: bool,
+}
+
+/// Command tree derived from the introspection definitions
+#[derive(Debug, Clone, Default)]
+struct CommandTree {
+    /// Command path ("" for the root) -> next words
+    children: BTreeMap<String, BTreeSet<String>>,
+    /// Full command name -> flags
+    flags: BTreeMap<String, Vec<CompletionFlag>>,
+    /// Commands whose first argument is a channel
+    channel_commands: BTreeSet<String>,
+    /// Flags that take their value as the next word
+    value_flags: BTreeSet<String>,
+}
+
+impl CommandTree {
+    fn from_definitions(defs: &[CommandDef]) -> Self {
+        let mut tree = Self::default();
+        for (name, value_name, _) in GLOBAL_FLAGS {
+            if value_name.is_some() {
+                tree.value_flags.insert(name.to_string());
+            }
+        }
+        for def in defs {
+            let words: Vec<&str> = def.name.split_whitespace().collect();
+            for depth in 0..words.len() {
+                tree.children
+                    .entry(words[..depth].join(" "))
+                    .or_default()
+                    .insert(words[depth].to_string());
+            }
+            let flags: Vec<CompletionFlag> = def
+                .flags
+                .iter()
+                .filter(|f| f.name.starts_with("--"))
+                .map(|f| CompletionFlag {
+                    name: f
+                        .name
+                        .split(['=', ' '])
+                        .next()
+                        .unwrap_or(&f.name)
+                        .to_string(),
+                    description: f.description.clone(),
+                    takes_value: f.flag_type != "boolean",
+                })
+                .collect();
+            for flag in flags.iter().filter(|f| f.takes_value) {
+                tree.value_flags.insert(flag.name.clone());
+            }
+            tree.flags.insert(def.name.clone(), flags);
+            let first_arg = def.usage.split_whitespace().nth(1 + words.len());
+            if first_arg == Some("<channel>") {
+                tree.channel_commands.insert(def.name.clone());
+            }
+        }
+        tree
+    }
+}
+
+/// Generate the completion script for `shell`
+pub fn generate_completions(shell: CompletionShell) -> String {
+    let tree = CommandTree::from_definitions(&generate_commands_list().commands);
+    match shell {
+        CompletionShell::Bash => bash_script(&tree),
+        CompletionShell::Zsh => zsh_script(&tree),
+        CompletionShell::Fish => fish_script(&tree),
+        CompletionShell::PowerShell => powershell_script(&tree),
+    }
+}
+
+/// Dynamic candidates for the completion scripts
+///
+/// `profiles` lists profile names; `channels` lists `ID<TAB>#name` for the
+/// profile's workspace from the channel cache.
+pub fn list_candidates(kind: &str, profile_name: &str) -> Result<Vec<String>, String> {
+    let config_path = default_config_path().map_err(|e| e.to_string())?;
+    let config = load_config(&config_path).map_err(|e| e.to_string())?;
+    match kind {
+        "profiles" => {
+            let mut names = config.list_names();
+            names.sort();
+            Ok(names)
+        }
+        "channels" => {
+            let Some(profile) = config.get(profile_name) else {
+                return Ok(Vec::new());
+            };
+            let cache = ChannelNameCache::load(&ChannelNameCache::default_path()?);
+            Ok(cache
+                .channels(&profile.team_id)
+                .into_iter()
+                .map(|c| format!("{}\t#{}", c.id, c.name))
+                .collect())
+        }
+        _ => Err(format!(
+            "Unknown list '{}'. Valid values: profiles, channels",
+            kind
+        )),
+    }
+}
+
+/// Run `completions <shell>` / `completions --list=profiles|channels`
+pub fn run_completions(args: &[String]) -> Result<(), String> {
+    if let Some(kind) = get_option(args, "--list=") {
+        // Completion must never print errors into the user's prompt
+        for candidate in list_candidates(&kind, &resolve_profile_name(args)).unwrap_or_default() {
+            println!("{}", candidate);
+        }
+        return Ok(());
+    }
+    let shell = args
+        .get(2)
+        .filter(|a| !a.starts_with("--"))
+        .ok_or_else(|| "Usage: completions <bash|zsh|fish|powershell>".to_string())?;
+    print!("{}", generate_completions(CompletionShell::parse(shell)?));
+    Ok(())
+}
+
+fn words(items: impl IntoIterator<Item = impl AsRef<str>>) -> String {
+    items
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn single_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// `case` helpers shared by the bash and zsh scripts
+fn posix_helpers(tree: &CommandTree) -> String {
+    let mut out = String::new();
+    out.push_str("_slack_rs_children() {\n    case \"$1\" in\n");
+    for (path, children) in &tree.children {
+        out.push_str(&format!(
+            "        {}) echo {} ;;\n",
+            single_quote(path),
+            single_quote(&words(children))
+        ));
+    }
+    out.push_str("    esac\n}\n\n");
+
+    out.push_str("_slack_rs_flags() {\n    case \"$1\" in\n");
+    for (name, flags) in &tree.flags {
+        out.push_str(&format!(
+            "        {}) echo {} ;;\n",
+            single_quote(name),
+            single_quote(&words(flags.iter().map(|f| &f.name)))
+        ));
+    }
+    out.push_str("    esac\n}\n\n");
+
+    out.push_str(&format!(
+        "_slack_rs_global_flags={}\n\n",
+        single_quote(&words(GLOBAL_FLAGS.iter().map(|f| f.0)))
+    ));
+
+    out.push_str("_slack_rs_takes_value() {\n    case \"$1\" in\n");
+    out.push_str(&format!(
+        "        {}) return 0 ;;\n",
+        tree.value_flags
+            .iter()
+            .map(|f| single_quote(f))
+            .collect::<Vec<_>>()
+            .join("|")
+    ));
+    out.push_str("    esac\n    return 1\n}\n\n");
+
+    out.push_str("_slack_rs_channel_command() {\n    case \"$1\" in\n");
+    out.push_str(&format!(
+        "        {}) return 0 ;;\n",
+        tree.channel_commands
+            .iter()
+            .map(|c| single_quote(c))
+            .collect::<Vec<_>>()
+            .join("|")
+    ));
+    out.push_str("    esac\n    return 1\n}\n\n");
+
+    // Sets `_slack_rs_path` (command path), `_slack_rs_npos` (positional
+    // arguments after it) and `_slack_rs_profile` from the words before the
+    // cursor
+    out.push_str(
+        r#"_slack_rs_scan() {
+    _slack_rs_path=""
+    _slack_rs_npos=0
+    _slack_rs_profile=""
+    local skip="" w
+    for w in "$@"; do
+        if [ -n "$skip" ]; then
+            [ "$skip" = "--profile" ] && _slack_rs_profile="$w"
+            skip=""
+            continue
+        fi
+        case "$w" in
+            --profile=*) _slack_rs_profile="${w#--profile=}"; continue ;;
+            --*=*) continue ;;
+            -*) _slack_rs_takes_value "$w" && skip="$w"; continue ;;
+        esac
+        if [ "$_slack_rs_npos" -eq 0 ] && [[ " $(_slack_rs_children "$_slack_rs_path") " == *" $w "* ]]; then
+            _slack_rs_path="${_slack_rs_path:+$_slack_rs_path }$w"
+        else
+            _slack_rs_npos=$((_slack_rs_npos + 1))
+        fi
+    done
+}
+
+"#,
+    );
+    out
+}
+
+fn bash_script(tree: &CommandTree) -> String {
+    let mut out = String::from(
+        "# bash completion for slack-rs\n# Load with: source <(slack-rs completions bash)\n\n",
+    );
+    out.push_str(&posix_helpers(tree));
+    out.push_str(
+        r#"_slack_rs() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local prev="${COMP_WORDS[COMP_CWORD-1]}"
+    COMPREPLY=()
+    _slack_rs_scan "${COMP_WORDS[@]:1:COMP_CWORD-1}"
+    local profile_arg="${_slack_rs_profile:+--profile=$_slack_rs_profile}"
+
+    case "$prev" in
+        --profile)
+            COMPREPLY=($(compgen -W "$(slack-rs completions --list=profiles 2>/dev/null)" -- "$cur"))
+            return ;;
+        --channel)
+            COMPREPLY=($(compgen -W "$(slack-rs completions --list=channels $profile_arg 2>/dev/null | cut -f1)" -- "$cur"))
+            return ;;
+    esac
+    if [[ "$cur" == --profile=* ]]; then
+        COMPREPLY=($(compgen -P "--profile=" -W "$(slack-rs completions --list=profiles 2>/dev/null)" -- "${cur#--profile=}"))
+        return
+    fi
+    if [[ "$cur" == -* ]]; then
+        COMPREPLY=($(compgen -W "$(_slack_rs_flags "$_slack_rs_path") $_slack_rs_global_flags" -- "$cur"))
+        return
+    fi
+    if [ "$_slack_rs_npos" -eq 0 ]; then
+        local children
+        children="$(_slack_rs_children "$_slack_rs_path")"
+        if [ -n "$children" ]; then
+            COMPREPLY=($(compgen -W "$children" -- "$cur"))
+        elif _slack_rs_channel_command "$_slack_rs_path"; then
+            COMPREPLY=($(compgen -W "$(slack-rs completions --list=channels $profile_arg 2>/dev/null | cut -f1)" -- "$cur"))
+        fi
+    fi
+}
+
+complete -o default -F _slack_rs slack-rs
+"#,
+    );
+    out
+}
+
+fn zsh_script(tree: &CommandTree) -> String {
+    let mut out = String::from("#compdef slack-rs\n# zsh completion for slack-rs\n# Load with: source <(slack-rs completions zsh)\n\n");
+    out.push_str(&posix_helpers(tree));
+    out.push_str("_slack_rs_describe_flags() {\n    case \"$1\" in\n");
+    for (name, flags) in &tree.flags {
+        let items: Vec<String> = flags
+            .iter()
+            .map(|f| single_quote(&format!("{}:{}", f.name, f.description.replace(':', "\\:"))))
+            .collect();
+        out.push_str(&format!(
+            "        {}) reply=({}) ;;\n",
+            single_quote(name),
+            items.join(" ")
+        ));
+    }
+    out.push_str("        *) reply=() ;;\n    esac\n");
+    let globals: Vec<String> = GLOBAL_FLAGS
+        .iter()
+        .map(|(name, _, description)| single_quote(&format!("{}:{}", name, description)))
+        .collect();
+    out.push_str(&format!("    reply+=({})\n}}\n\n", globals.join(" ")));
+    out.push_str(
+        r#"_slack_rs_channels() {
+    local -a channels
+    local line
+    for line in ${(f)"$(slack-rs completions --list=channels ${_slack_rs_profile:+--profile=$_slack_rs_profile} 2>/dev/null)"}; do
+        channels+=("${line%%$'\t'*}:${line#*$'\t'}")
+    done
+    _describe -t channels 'channel' channels
+}
+
+_slack_rs() {
+    local cur="${words[CURRENT]}"
+    local prev="${words[CURRENT-1]}"
+    _slack_rs_scan "${(@)words[2,CURRENT-1]}"
+
+    case "$prev" in
+        --profile)
+            compadd -- ${(f)"$(slack-rs completions --list=profiles 2>/dev/null)"}
+            return ;;
+        --channel)
+            _slack_rs_channels
+            return ;;
+    esac
+    if [[ "$cur" == -* ]]; then
+        local -a reply
+        _slack_rs_describe_flags "$_slack_rs_path"
+        _describe -t flags 'flag' reply
+        return
+    fi
+    if [ "$_slack_rs_npos" -eq 0 ]; then
+        local children
+        children="$(_slack_rs_children "$_slack_rs_path")"
+        if [ -n "$children" ]; then
+            compadd -- ${=children}
+        elif _slack_rs_channel_command "$_slack_rs_path"; then
+            _slack_rs_channels
+        else
+            _files
+        fi
+    else
+        _files
+    fi
+}
+
+compdef _slack_rs slack-rs
+"#,
+    );
+    out
+}
+
+fn fish_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn fish_script(tree: &CommandTree) -> String {
+    let mut out = String::from(
+        "# fish completion for slack-rs\n# Load with: slack-rs completions fish | source\n\n",
+    );
+    out.push_str(&format!(
+        "set -g __slack_rs_value_flags {}\n",
+        words(tree.value_flags.iter().map(|f| fish_quote(f)))
+    ));
+    out.push_str(&format!(
+        "set -g __slack_rs_channel_commands {}\n\n",
+        words(tree.channel_commands.iter().map(|c| fish_quote(c)))
+    ));
+    let mut children = String::from("function __slack_rs_children\n    switch $argv[1]\n");
+    for (path, next) in &tree.children {
+        children.push_str(&format!(
+            "        case {}\n            printf '%s\\n' {}\n",
+            fish_quote(path),
+            words(next.iter().map(|c| fish_quote(c)))
+        ));
+    }
+    children.push_str("    end\nend\n\n");
+    out.push_str(&children);
+    out.push_str(
+        r#"# Prints the command path and the number of positional arguments after it
+function __slack_rs_scan
+    set -l path ''
+    set -l npos 0
+    set -l skip 0
+    set -l tokens (commandline -opc)
+    set -e tokens[1]
+    for w in $tokens
+        if test $skip -eq 1
+            set skip 0
+            continue
+        end
+        switch $w
+            case '--*=*'
+                continue
+            case '-*'
+                contains -- $w $__slack_rs_value_flags; and set skip 1
+                continue
+        end
+        if test $npos -eq 0; and contains -- $w (__slack_rs_children "$path")
+            set path (string trim -- "$path $w")
+        else
+            set npos (math $npos + 1)
+        end
+    end
+    echo $path
+    echo $npos
+end
+
+function __slack_rs_using
+    set -l scan (__slack_rs_scan)
+    test "$scan[1]" = "$argv"
+end
+
+function __slack_rs_next_words
+    set -l scan (__slack_rs_scan)
+    test "$scan[2]" -eq 0; and __slack_rs_children "$scan[1]"
+end
+
+function __slack_rs_has_next_words
+    set -l next (__slack_rs_next_words)
+    test (count $next) -gt 0
+end
+
+function __slack_rs_wants_channel
+    set -l scan (__slack_rs_scan)
+    test "$scan[2]" -eq 0; and contains -- "$scan[1]" $__slack_rs_channel_commands
+end
+
+function __slack_rs_profile
+    set -l tokens (commandline -opc)
+    for i in (seq (count $tokens))
+        if test "$tokens[$i]" = --profile; and test $i -lt (count $tokens)
+            echo --profile=$tokens[(math $i + 1)]
+        else if string match -q -- '--profile=*' $tokens[$i]
+            echo $tokens[$i]
+        end
+    end
+end
+
+complete -c slack-rs -f
+complete -c slack-rs -n __slack_rs_has_next_words -a '(__slack_rs_next_words)'
+complete -c slack-rs -n __slack_rs_wants_channel -a '(slack-rs completions --list=channels (__slack_rs_profile) 2>/dev/null)'
+complete -c slack-rs -l profile -x -a '(slack-rs completions --list=profiles 2>/dev/null)' -d 'Profile name'
+complete -c slack-rs -l channel -x -a '(slack-rs completions --list=channels (__slack_rs_profile) 2>/dev/null)'
+"#,
+    );
+    for (name, value_name, description) in GLOBAL_FLAGS.iter().skip(1) {
+        out.push_str(&format!(
+            "complete -c slack-rs -l {}{} -d {}\n",
+            name.trim_start_matches("--"),
+            if value_name.is_some() { " -r" } else { "" },
+            fish_quote(description)
+        ));
+    }
+    for (name, flags) in &tree.flags {
+        for flag in flags.iter().filter(|f| f.name != "--profile") {
+            out.push_str(&format!(
+                "complete -c slack-rs -n {} -l {}{} -d {}\n",
+                fish_quote(&format!("__slack_rs_using {}", name)),
+                flag.name.trim_start_matches("--"),
+                if flag.takes_value { " -r" } else { "" },
+                fish_quote(&flag.description)
+            ));
+        }
+    }
+    out
+}
+
+fn ps_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+fn powershell_script(tree: &CommandTree) -> String {
+    let mut out = String::from("# PowerShell completion for slack-rs\n# Load with: slack-rs completions powershell | Out-String | Invoke-Expression\n\n");
+    out.push_str("$__slackRsChildren = @{\n");
+    for (path, children) in &tree.children {
+        out.push_str(&format!(
+            "    {} = @({})\n",
+            ps_quote(path),
+            children
+                .iter()
+                .map(|c| ps_quote(c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    out.push_str("}\n\n$__slackRsFlags = @{\n");
+    for (name, flags) in &tree.flags {
+        out.push_str(&format!(
+            "    {} = @({})\n",
+            ps_quote(name),
+            flags
+                .iter()
+                .map(|f| format!("@({}, {})", ps_quote(&f.name), ps_quote(&f.description)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    out.push_str(&format!(
+        "}}\n\n$__slackRsGlobalFlags = @({})\n",
+        GLOBAL_FLAGS
+            .iter()
+            .map(|(name, _, description)| format!(
+                "@({}, {})",
+                ps_quote(name),
+                ps_quote(description)
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    out.push_str(&format!(
+        "$__slackRsValueFlags = @({})\n",
+        tree.value_flags
+            .iter()
+            .map(|f| ps_quote(f))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    out.push_str(&format!(
+        "$__slackRsChannelCommands = @({})\n\n",
+        tree.channel_commands
+            .iter()
+            .map(|c| ps_quote(c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    out.push_str(
+        r#"Register-ArgumentCompleter -Native -CommandName 'slack-rs' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    # Words before the one being completed, without the program name
+    $words = @($commandAst.CommandElements |
+        Where-Object { $_.Extent.EndOffset -lt $cursorPosition } |
+        Select-Object -Skip 1 |
+        ForEach-Object { $_.ToString() })
+
+    $path = ''
+    $npos = 0
+    $skip = $false
+    $profileArg = @()
+    $prev = ''
+    foreach ($w in $words) {
+        if ($skip) {
+            if ($prev -eq '--profile') { $profileArg = @("--profile=$w") }
+            $skip = $false
+        } elseif ($w -like '--profile=*') {
+            $profileArg = @($w)
+        } elseif ($w -like '--*=*') {
+        } elseif ($w -like '-*') {
+            $skip = $__slackRsValueFlags -contains $w
+        } elseif ($npos -eq 0 -and $__slackRsChildren.ContainsKey($path) -and $__slackRsChildren[$path] -contains $w) {
+            $path = "$path $w".Trim()
+        } else {
+            $npos++
+        }
+        $prev = $w
+    }
+
+    $result = @()
+    if ($prev -eq '--profile') {
+        $result = @(& slack-rs completions --list=profiles 2>$null | ForEach-Object { , @($_, $_) })
+    } elseif ($prev -eq '--channel' -or ($npos -eq 0 -and -not $__slackRsChildren.ContainsKey($path) -and $__slackRsChannelCommands -contains $path -and $wordToComplete -notlike '-*')) {
+        $result = @(& slack-rs completions --list=channels @profileArg 2>$null | ForEach-Object { , @($_.Split("`t")) })
+    } elseif ($wordToComplete -like '-*') {
+        $result = @($__slackRsFlags[$path]) + $__slackRsGlobalFlags | Where-Object { $_ }
+    } elseif ($npos -eq 0 -and $__slackRsChildren.ContainsKey($path)) {
+        $result = @($__slackRsChildren[$path] | ForEach-Object { , @($_, $_) })
+    }
+
+    $result | Where-Object { $_[0] -like "$wordToComplete*" } | ForEach-Object {
+        [System.Management.Automation.CompletionResult]::new($_[0], $_[0], 'ParameterValue', $_[1])
+    }
+}
+"#,
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_tree() {
+        let tree = CommandTree::from_definitions(&generate_commands_list().commands);
+        assert!(tree.children[""].contains("msg"));
+        assert!(tree.children["msg"].contains("post"));
+        assert!(tree.children["msg scheduled"].contains("list"));
+        assert!(tree.channel_commands.contains("msg post"));
+        assert!(!tree.channel_commands.contains("conv list"));
+        assert!(tree.flags["msg post"]
+            .iter()
+            .any(|f| f.name == "--thread-ts" && f.takes_value));
+        assert!(tree.value_flags.contains("--profile"));
+        assert!(!tree.value_flags.contains("--yes"));
+    }
+
+    #[test]
+    fn test_generate_completions() {
+        let bash = generate_completions(CompletionShell::Bash);
+        assert!(bash.contains("complete -o default -F _slack_rs slack-rs"));
+        assert!(bash.contains("'msg') echo "));
+        assert!(generate_completions(CompletionShell::Zsh).starts_with("#compdef slack-rs"));
+        assert!(generate_completions(CompletionShell::Fish)
+            .contains("-n '__slack_rs_using msg post' -l thread-ts -r"));
+        assert!(generate_completions(CompletionShell::PowerShell)
+            .contains("Register-ArgumentCompleter -Native -CommandName 'slack-rs'"));
+        assert!(CompletionShell::parse("tcsh").is_err());
+    }
+}
@@ -3,12 +3,16 @@
 //! This module contains handler functions for CLI commands that were extracted from main.rs
 //! to improve code organization and maintainability.
 
-use crate::api::{execute_api_call, ApiCallArgs, ApiCallContext, ApiCallResponse, ApiClient};
+use crate::api::{
+    execute_api_call, prepare_api_request, ApiCallArgs, ApiCallContext, ApiCallResponse, ApiClient,
+    RequestFailure,
+};
 use crate::auth;
 use crate::debug;
 use crate::oauth;
 use crate::profile::{
-    create_token_store, default_config_path, make_token_key, resolve_profile_full, TokenType,
+    create_token_store, default_config_path, make_token_key, resolve_profile_full,
+    resolve_token_backend, TokenType,
 };
 
 /// Parsed login arguments structure
@@ -63,6 +67,7 @@ impl TunnelMode {
 /// 1. --cloudflared and --ngrok are mutually exclusive
 /// 2. Unknown options are rejected
 /// 3. Scope inputs are normalized (comma-separated, whitespace-trimmed)
+/// 4. --scopes-from-manifest excludes --bot-scopes and --user-scopes
 pub fn parse_login_args(args: &[String]) -> Result<LoginArgs, String> {
     let mut profile_name: Option<String> = None;
     let mut client_id: Option<String> = None;
@@ -70,6 +75,7 @@ pub fn parse_login_args(args: &[String]) -> Result<LoginArgs, String> {
     let mut ngrok_path: Option<String> = None;
     let mut bot_scopes: Option<Vec<String>> = None;
     let mut user_scopes: Option<Vec<String>> = None;
+    let mut manifest_path: Option<String> = None;
 
     let mut i = 0;
     while i < args.len() {
@@ -125,6 +131,14 @@ pub fn parse_login_args(args: &[String]) -> Result<LoginArgs, String> {
                         return Err("--user-scopes requires a value".to_string());
                     }
                 }
+                "--scopes-from-manifest" => {
+                    i += 1;
+                    if i < args.len() {
+                        manifest_path = Some(args[i].clone());
+                    } else {
+                        return Err("--scopes-from-manifest requires a value".to_string());
+                    }
+                }
                 _ => {
                     return Err(format!("Unknown option: {}", args[i]));
                 }
@@ -142,6 +156,19 @@ pub fn parse_login_args(args: &[String]) -> Result<LoginArgs, String> {
         return Err("Cannot specify both --cloudflared and --ngrok at the same time".to_string());
     }
 
+    // Scopes from an app manifest replace both scope flags
+    if let Some(path) = manifest_path {
+        if bot_scopes.is_some() || user_scopes.is_some() {
+            return Err(
+                "--scopes-from-manifest cannot be combined with --bot-scopes or --user-scopes"
+                    .to_string(),
+            );
+        }
+        let scopes = auth::manifest::load_manifest_scopes(std::path::Path::new(&path))?;
+        bot_scopes = scopes.bot;
+        user_scopes = scopes.user;
+    }
+
     // Determine tunnel mode
     let tunnel_mode = if let Some(path) = cloudflared_path {
         TunnelMode::Cloudflared(Some(path))
@@ -310,6 +337,8 @@ fn infer_default_token_type(
 struct ResolvedToken {
     token: String,
     token_type: TokenType,
+    /// Store key the token was read from (`None` for `SLACK_TOKEN`)
+    token_key: Option<String>,
 }
 
 /// Resolves and retrieves the appropriate token for an API call
@@ -355,6 +384,7 @@ fn resolve_token(
     let token_key = match resolved_token_type {
         TokenType::Bot => token_key_bot.clone(),
         TokenType::User => token_key_user.clone(),
+        TokenType::App => TokenType::App.token_key(team_id, user_id),
     };
 
     // Determine if the token type was explicitly requested via CLI flag OR default_token_type
@@ -362,12 +392,12 @@ fn resolve_token(
     let explicit_request = cli_token_type.is_some() || profile_default_token_type.is_some();
 
     // PRIORITY 1: Check SLACK_TOKEN environment variable first (highest priority)
-    let token = if let Ok(env_token) = std::env::var("SLACK_TOKEN") {
-        env_token
+    let (token, token_key) = if let Ok(env_token) = std::env::var("SLACK_TOKEN") {
+        (env_token, None)
     } else {
         // PRIORITY 2: Try to retrieve token from token store
         match token_store.get(&token_key) {
-            Ok(t) => t,
+            Ok(t) => (t, Some(token_key)),
             Err(_) => {
                 // PRIORITY 3: If token not found in store, apply fallback logic
                 if explicit_request {
@@ -387,6 +417,7 @@ fn resolve_token(
                             return Ok(ResolvedToken {
                                 token: bot_token,
                                 token_type: TokenType::Bot,
+                                token_key: Some(token_key_bot),
                             });
                         } else {
                             return Err(format!(
@@ -408,9 +439,41 @@ fn resolve_token(
     Ok(ResolvedToken {
         token,
         token_type: resolved_token_type,
+        token_key,
     })
 }
 
+/// [`resolve_token`] for a profile, refreshing a rotated token that is about
+/// to expire before it is used
+async fn resolve_fresh_token(
+    token_store: &dyn crate::profile::TokenStore,
+    profile: &crate::profile::Profile,
+    profile_name: &str,
+    cli_token_type: Option<TokenType>,
+    base_url: Option<&str>,
+) -> Result<ResolvedToken, String> {
+    let mut resolved = resolve_token(
+        token_store,
+        &profile.team_id,
+        &profile.user_id,
+        cli_token_type,
+        profile.default_token_type,
+        profile_name,
+    )?;
+    if let Some(token_key) = &resolved.token_key {
+        resolved.token = crate::cli::refresh_stored_token(
+            token_store,
+            profile_name,
+            profile.client_id.as_deref(),
+            token_key,
+            std::mem::take(&mut resolved.token),
+            base_url,
+        )
+        .await?;
+    }
+    Ok(resolved)
+}
+
 /// Run the api call command
 pub async fn run_api_call(args: Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
     // Parse arguments
@@ -438,14 +501,14 @@ pub async fn run_api_call(args: Vec<String>) -> Result<(), Box<dyn std::error::E
         create_token_store().map_err(|e| format!("Failed to create token store: {}", e))?;
 
     // Resolve token using dedicated function
-    let resolved = resolve_token(
+    let resolved = resolve_fresh_token(
         &*token_store,
-        &profile.team_id,
-        &profile.user_id,
-        api_args.token_type,
-        profile.default_token_type,
+        &profile,
         &profile_name,
+        api_args.token_type,
+        None,
     )
+    .await
     .map_err(|e| -> Box<dyn std::error::Error> { e.into() })?;
 
     let token = resolved.token;
@@ -475,6 +538,13 @@ pub async fn run_api_call(args: Vec<String>) -> Result<(), Box<dyn std::error::E
     // Create API client
     let client = ApiClient::new();
 
+    // --get-url: print the resolved request and exit without sending it
+    if api_args.get_url {
+        let request = prepare_api_request(client.base_url(), &api_args, &token);
+        print!("{}", request.to_wire_string());
+        return Ok(());
+    }
+
     // Execute API call with token type information and command name
     let response = execute_api_call(
         &client,
@@ -489,8 +559,18 @@ pub async fn run_api_call(args: Vec<String>) -> Result<(), Box<dyn std::error::E
     // Log error code if present
     debug::log_error_code(debug_level, &response.response);
 
+    // Remember successful auth.test calls for `auth list --json`
+    if api_args.method == "auth.test"
+        && response.response.get("ok").and_then(|v| v.as_bool()) == Some(true)
+    {
+        if let Err(e) = crate::auth::record_auth_test_success(&profile_name) {
+            debug::log(format!("Failed to record auth.test result: {}", e));
+        }
+    }
+
     // Display error guidance if response contains a known error
     crate::api::display_error_guidance(&response);
+    crate::gha::record_response(&response.response);
 
     // Check if we should show guidance for private_channel with bot token
     if should_show_private_channel_guidance(&api_args, resolved_token_type.as_str(), &response) {
@@ -653,7 +733,7 @@ pub async fn handle_export_command(args: &[String]) {
                     }
                 }
                 eprintln!("Unknown option: {}", arg);
-                std::process::exit(1);
+                super::exit(1);
             }
         }
     }
@@ -665,7 +745,7 @@ pub async fn handle_export_command(args: &[String]) {
     if !common_args.yes {
         eprintln!("{}", messages.get("warn.export_sensitive"));
         eprintln!("Error: --yes flag is required to confirm this dangerous operation");
-        std::process::exit(1);
+        super::exit(1);
     }
 
     // Validate required options
@@ -673,7 +753,7 @@ pub async fn handle_export_command(args: &[String]) {
         Some(path) => path,
         None => {
             eprintln!("Error: --out <file> is required");
-            std::process::exit(1);
+            super::exit(1);
         }
     };
 
@@ -682,7 +762,7 @@ pub async fn handle_export_command(args: &[String]) {
         Ok(pass) => pass,
         Err(e) => {
             eprintln!("{}", e);
-            std::process::exit(1);
+            super::exit(1);
         }
     };
 
@@ -717,7 +797,7 @@ pub async fn handle_export_command(args: &[String]) {
         }
         Err(e) => {
             eprintln!("Export failed: {}", e);
-            std::process::exit(1);
+            super::exit(1);
         }
     }
 }
@@ -738,6 +818,9 @@ pub async fn handle_import_command(args: &[String]) {
     let mut force = false;
     let mut dry_run = false;
     let mut json = false;
+    let mut only: Option<Vec<String>> = None;
+    let mut rename_map = std::collections::HashMap::new();
+    let mut prefix: Option<String> = None;
 
     for (idx, arg) in remaining {
         match arg.as_str() {
@@ -747,6 +830,38 @@ pub async fn handle_import_command(args: &[String]) {
                     input_path = Some(args[idx + 1].clone());
                 }
             }
+            "--map" => {
+                // Accepts old:new, repeatable or comma-separated
+                if let Some(value) = args.get(idx + 1) {
+                    for spec in value.split(',').filter(|s| !s.trim().is_empty()) {
+                        match auth::parse_profile_mapping(spec) {
+                            Ok((old, new)) => {
+                                rename_map.insert(old, new);
+                            }
+                            Err(e) => {
+                                eprintln!("Error: {}", e);
+                                super::exit(1);
+                            }
+                        }
+                    }
+                }
+            }
+            "--prefix" => {
+                if let Some(value) = args.get(idx + 1) {
+                    prefix = Some(value.clone());
+                }
+            }
+            "--only" => {
+                if let Some(value) = args.get(idx + 1) {
+                    only = Some(
+                        value
+                            .split(',')
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty())
+                            .collect(),
+                    );
+                }
+            }
             "--force" => {
                 force = true;
             }
@@ -756,17 +871,23 @@ pub async fn handle_import_command(args: &[String]) {
             "--json" => {
                 json = true;
             }
+            "--all" => {
+                // Every profile in the file is imported unless --only is given
+            }
             _ => {
                 // Check if this is a value for a previous flag
                 if idx > 0 {
                     let prev = &args[idx - 1];
-                    if prev == "--in" || prev == "--passphrase-env" || prev == "--lang" {
+                    if matches!(
+                        prev.as_str(),
+                        "--in" | "--passphrase-env" | "--lang" | "--map" | "--prefix" | "--only"
+                    ) {
                         // This is a value, not an unknown option
                         continue;
                     }
                 }
                 eprintln!("Unknown option: {}", arg);
-                std::process::exit(1);
+                super::exit(1);
             }
         }
     }
@@ -779,7 +900,7 @@ pub async fn handle_import_command(args: &[String]) {
         Some(path) => path,
         None => {
             eprintln!("Error: --in <file> is required");
-            std::process::exit(1);
+            super::exit(1);
         }
     };
 
@@ -788,7 +909,7 @@ pub async fn handle_import_command(args: &[String]) {
         Ok(pass) => pass,
         Err(e) => {
             eprintln!("{}", e);
-            std::process::exit(1);
+            super::exit(1);
         }
     };
 
@@ -799,6 +920,9 @@ pub async fn handle_import_command(args: &[String]) {
         force,
         dry_run,
         json,
+        only,
+        rename_map,
+        prefix,
     };
 
     let token_store = create_token_store().expect("Failed to create token store");
@@ -812,7 +936,7 @@ pub async fn handle_import_command(args: &[String]) {
                     }
                     Err(e) => {
                         eprintln!("Failed to serialize result to JSON: {}", e);
-                        std::process::exit(1);
+                        super::exit(1);
                     }
                 }
             } else {
@@ -830,9 +954,15 @@ pub async fn handle_import_command(args: &[String]) {
                 println!();
                 println!("Profile Details:");
                 for profile_result in &result.profiles {
+                    let name = match &profile_result.source_name {
+                        Some(source) => {
+                            format!("{} (from {})", profile_result.profile_name, source)
+                        }
+                        None => profile_result.profile_name.clone(),
+                    };
                     println!(
                         "  {} - {} ({})",
-                        profile_result.profile_name, profile_result.action, profile_result.reason
+                        name, profile_result.action, profile_result.reason
                     );
                 }
                 println!();
@@ -846,9 +976,240 @@ pub async fn handle_import_command(args: &[String]) {
         }
         Err(e) => {
             eprintln!("Import failed: {}", e);
-            std::process::exit(1);
+            super::exit(1);
+        }
+    }
+}
+
+/// Run `auth set-token [profile] [--token-type=app|bot|user]`
+///
+/// The token is read from stdin when it is piped, otherwise from a hidden
+/// prompt, so it never appears in the shell history. Defaults to an
+/// app-level token.
+pub fn run_auth_set_token(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use std::io::{IsTerminal, Read};
+
+    let profile_name = args.iter().find(|arg| !arg.starts_with("--")).cloned();
+    let token_type = match crate::cli::get_option(args, "--token-type=") {
+        Some(value) => value.parse::<TokenType>().map_err(|e| e.to_string())?,
+        None => TokenType::App,
+    };
+
+    let token = if !std::io::stdin().is_terminal() {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Failed to read token from stdin: {}", e))?;
+        input
+    } else if non_interactive {
+        return Err(
+            "Non-interactive mode error: pipe the token on stdin, e.g. echo \"$SLACK_APP_TOKEN\" | slack-rs auth set-token"
+                .to_string(),
+        );
+    } else {
+        rpassword::prompt_password(format!("Enter {} token: ", token_type))
+            .map_err(|e| format!("Failed to read token: {}", e))?
+    };
+    if token.trim().is_empty() {
+        return Err("Token cannot be empty".to_string());
+    }
+
+    auth::set_token(profile_name, token_type, &token)
+}
+
+/// Client for the token stored under `token_key`, refreshed if it is about to expire
+async fn stored_client(
+    token_store: &dyn crate::profile::TokenStore,
+    profile: &crate::profile::Profile,
+    profile_name: &str,
+    token_key: &str,
+) -> Result<Option<ApiClient>, String> {
+    let Ok(token) = token_store.get(token_key) else {
+        return Ok(None);
+    };
+    let token = crate::cli::refresh_stored_token(
+        token_store,
+        profile_name,
+        profile.client_id.as_deref(),
+        token_key,
+        token,
+        None,
+    )
+    .await?;
+    Ok(Some(ApiClient::with_token(token)))
+}
+
+/// Run `auth compare-tokens [profile] [--json]`
+///
+/// Loads both tokens of the profile from the token store (`SLACK_TOKEN` is
+/// ignored here), refreshing rotated tokens that are about to expire, and
+/// runs the same read calls with each.
+pub async fn run_auth_compare_tokens(args: &[String]) -> Result<(), String> {
+    let profile_name = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .cloned()
+        .unwrap_or_else(|| "default".to_string());
+    let json_output = args.iter().any(|arg| arg == "--json");
+
+    let config_path = default_config_path().map_err(|e| e.to_string())?;
+    let profile = resolve_profile_full(&config_path, &profile_name)
+        .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
+
+    let token_store = create_token_store().map_err(|e| e.to_string())?;
+    let bot_key = make_token_key(&profile.team_id, &profile.user_id);
+    let user_key = format!("{}:{}:user", profile.team_id, profile.user_id);
+    let bot_client = stored_client(&*token_store, &profile, &profile_name, &bot_key).await?;
+    let user_client = stored_client(&*token_store, &profile, &profile_name, &user_key).await?;
+
+    if bot_client.is_none() && user_client.is_none() {
+        return Err(format!(
+            "Profile '{}' has no bot or user token. Run: slack-rs auth login {}",
+            profile_name, profile_name
+        ));
+    }
+
+    let results = crate::commands::compare_tokens(bot_client.as_ref(), user_client.as_ref()).await;
+
+    if json_output {
+        let output = serde_json::json!({
+            "profile": profile_name,
+            "results": results,
+        });
+        let json = serde_json::to_string_pretty(&output).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        println!("Profile: {}", profile_name);
+        print!("{}", crate::commands::format_token_comparison(&results));
+        println!();
+        println!("Counts are from the first page; \"N+\" means more results exist.");
+        println!("Bot tokens only see private channels and DMs the bot is a member of,");
+        println!("and cannot use search. Use --token-type user for workspace-wide reads.");
+    }
+    Ok(())
+}
+
+/// Run `auth tokens list [--json]`
+pub fn run_auth_tokens_list(args: &[String]) -> Result<(), String> {
+    let entries = auth::token_list()?;
+    if args.iter().any(|arg| arg == "--json") {
+        let json = serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        print!("{}", auth::format_token_list_text(&entries));
+    }
+    Ok(())
+}
+
+/// Run `auth test [--profile NAME] [--token-type bot|user|app] [--json]`
+///
+/// Calls `auth.test` with the resolved token and refreshes the profile's
+/// team/user names when they drifted. Mismatching IDs are only reported.
+pub async fn run_auth_test(args: &[String]) -> Result<(), String> {
+    let profile_name = crate::cli::resolve_profile_name(args);
+    let token_type = crate::cli::parse_token_type(args)?;
+    let json_output = args.iter().any(|arg| arg == "--json");
+
+    let config_path = default_config_path().map_err(|e| e.to_string())?;
+    let profile = resolve_profile_full(&config_path, &profile_name)
+        .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
+    let token_store = create_token_store().map_err(|e| e.to_string())?;
+    let resolved =
+        resolve_fresh_token(&*token_store, &profile, &profile_name, token_type, None).await?;
+
+    let client = ApiClient::with_token(resolved.token);
+    let report = crate::commands::auth_test(&client)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let team_matches = report.team_id == profile.team_id;
+    let user_matches = report.user_id == profile.user_id;
+    let is_user_token = report.token_type == TokenType::User.as_str();
+    if !team_matches {
+        crate::gha::warn(&format!(
+            "Warning: token belongs to team {} but profile '{}' is for team {}",
+            report.team_id, profile_name, profile.team_id
+        ));
+    } else if is_user_token && !user_matches {
+        crate::gha::warn(&format!(
+            "Warning: token belongs to user {} but profile '{}' is for user {}",
+            report.user_id, profile_name, profile.user_id
+        ));
+    }
+
+    // Bot tokens report the bot user, so only a user token names the profile's user
+    let team_name = report.team.as_deref().filter(|_| team_matches);
+    let user_name = report
+        .user
+        .as_deref()
+        .filter(|_| team_matches && user_matches && is_user_token);
+    let updated = auth::record_auth_test_identity(&profile_name, team_name, user_name)?;
+
+    if json_output {
+        let mut output = serde_json::to_value(&report).map_err(|e| e.to_string())?;
+        output["profile"] = serde_json::json!(profile_name);
+        output["profileUpdated"] = serde_json::json!(updated);
+        let json = serde_json::to_string_pretty(&output).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        print!(
+            "{}",
+            crate::commands::format_auth_test_text(&profile_name, &report)
+        );
+        if !updated.is_empty() {
+            println!("Updated profile: {}", updated.join(", "));
         }
     }
+    Ok(())
+}
+
+/// Run `whoami [--profile NAME] [--token-type bot|user|app] [--json]`
+///
+/// Read-only identity check: unlike `auth test` it never updates the profile.
+pub async fn run_whoami(args: &[String]) -> Result<(), String> {
+    let profile_name = crate::cli::resolve_profile_name(args);
+    let token_type = crate::cli::parse_token_type(args)?;
+    let json_output = args.iter().any(|arg| arg == "--json");
+
+    let config_path = default_config_path().map_err(|e| e.to_string())?;
+    let profile = resolve_profile_full(&config_path, &profile_name)
+        .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
+    let token_store = create_token_store().map_err(|e| e.to_string())?;
+    let resolved =
+        resolve_fresh_token(&*token_store, &profile, &profile_name, token_type, None).await?;
+    // resolve_token prefers SLACK_TOKEN over the store
+    let token_source = if std::env::var("SLACK_TOKEN").is_ok() {
+        "SLACK_TOKEN".to_string()
+    } else {
+        resolve_token_backend()
+            .map(|backend| backend.to_string())
+            .map_err(|e| e.to_string())?
+    };
+
+    let client = ApiClient::with_token(resolved.token);
+    let report = crate::commands::auth_test(&client)
+        .await
+        .map_err(|e| e.to_string())?;
+    if report.team_id != profile.team_id {
+        crate::gha::warn(&format!(
+            "Warning: token belongs to team {} but profile '{}' is for team {}",
+            report.team_id, profile_name, profile.team_id
+        ));
+    }
+
+    if json_output {
+        let mut output = serde_json::to_value(&report).map_err(|e| e.to_string())?;
+        output["profile"] = serde_json::json!(profile_name);
+        output["tokenSource"] = serde_json::json!(token_source);
+        let json = serde_json::to_string_pretty(&output).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        print!(
+            "{}",
+            crate::commands::format_whoami_text(&profile_name, &token_source, &report)
+        );
+    }
+    Ok(())
 }
 
 /// Run install-skills command
@@ -894,6 +1255,90 @@ pub fn run_install_skill(args: &[String]) -> Result<(), String> {
     Ok(())
 }
 
+/// Exit code for authentication failures (missing, invalid or revoked token)
+pub const EXIT_AUTH: i32 = 2;
+/// Exit code for missing channels, users, messages and other `*_not_found` errors
+pub const EXIT_NOT_FOUND: i32 = 3;
+/// Exit code when Slack rate limiting persists after retries
+pub const EXIT_RATE_LIMITED: i32 = 4;
+/// Exit code for missing scopes and other permission errors
+pub const EXIT_PERMISSION: i32 = 5;
+/// Exit code for network failures (connection, DNS, timeout)
+pub const EXIT_NETWORK: i32 = 6;
+/// Exit code when a command needs confirmation in non-interactive mode (`--yes` missing)
+pub const EXIT_CONFIRMATION_REQUIRED: i32 = 7;
+
+/// Failure exit codes other than the general code 1, with their meaning
+pub const FAILURE_EXIT_CODES: [(i32, &str); 6] = [
+    (EXIT_AUTH, "Authentication error"),
+    (EXIT_NOT_FOUND, "Target not found"),
+    (EXIT_RATE_LIMITED, "Rate limited (retryable)"),
+    (EXIT_PERMISSION, "Permission denied or missing scope"),
+    (EXIT_NETWORK, "Network error (retryable)"),
+    (
+        EXIT_CONFIRMATION_REQUIRED,
+        "Confirmation required in non-interactive mode (pass --yes)",
+    ),
+];
+
+const AUTH_ERRORS: &[&str] = &[
+    "invalid_auth",
+    "not_authed",
+    "token_revoked",
+    "token_expired",
+    "account_inactive",
+    "no_token",
+    "No token found",
+    "No tokens found",
+    "No token configured",
+    "Token not found",
+    "No authentication token",
+];
+
+const PERMISSION_ERRORS: &[&str] = &[
+    "missing_scope",
+    "not_allowed_token_type",
+    "no_permission",
+    "restricted_action",
+    "access_denied",
+    "not_in_channel",
+    "ekm_access_denied",
+    "Write operation denied",
+];
+
+/// Map a command's error to a process exit code
+///
+/// Slack error codes embedded in the message select the auth, permission
+/// and not-found codes. Network and rate-limit failures come from the typed
+/// error of the last request (see [`crate::api::last_request_failure`]);
+/// anything else exits with 1.
+pub fn exit_code_for_error(error: &str) -> i32 {
+    exit_code_for(error, crate::api::last_request_failure())
+}
+
+fn exit_code_for(error: &str, request_failure: Option<RequestFailure>) -> i32 {
+    let matches = |patterns: &[&str]| patterns.iter().any(|p| error.contains(p));
+    if super::is_non_interactive_error(error) {
+        EXIT_CONFIRMATION_REQUIRED
+    } else if matches(AUTH_ERRORS) {
+        EXIT_AUTH
+    } else if matches(PERMISSION_ERRORS) {
+        EXIT_PERMISSION
+    } else if let Some(failure) = request_failure {
+        match failure {
+            RequestFailure::Network => EXIT_NETWORK,
+            RequestFailure::RateLimited => EXIT_RATE_LIMITED,
+        }
+    } else if error
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .any(|word| word.ends_with("not_found"))
+    {
+        EXIT_NOT_FOUND
+    } else {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -903,6 +1348,130 @@ mod tests {
     use serial_test::serial;
     use std::collections::HashMap;
 
+    /// `api call` refreshes an expired rotated token before sending it
+    #[tokio::test]
+    #[serial]
+    async fn test_resolve_fresh_token_refreshes_expired_rotated_token() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        std::env::remove_var("SLACK_TOKEN");
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth.v2.access"))
+            .and(body_string_contains("refresh_token=xoxe-1-old"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "access_token": "xoxe.xoxp-new",
+                "refresh_token": "xoxe-1-new",
+                "expires_in": 43200
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let token_store = InMemoryTokenStore::new();
+        token_store.set("T1:U1:user", "xoxe.xoxp-old").unwrap();
+        crate::oauth::refresh::store_rotation(
+            &token_store,
+            "T1:U1:user",
+            &crate::oauth::TokenRotation {
+                refresh_token: "xoxe-1-old".to_string(),
+                // Expired long ago
+                expires_at: Some(1),
+            },
+        )
+        .unwrap();
+        crate::profile::store_oauth_client_secret(&token_store, "work", "secret").unwrap();
+        let profile: crate::profile::Profile = serde_json::from_value(json!({
+            "team_id": "T1",
+            "user_id": "U1",
+            "team_name": null,
+            "user_name": null,
+            "client_id": "cid"
+        }))
+        .unwrap();
+
+        let resolved = resolve_fresh_token(
+            &token_store,
+            &profile,
+            "work",
+            Some(TokenType::User),
+            Some(&mock_server.uri()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(resolved.token, "xoxe.xoxp-new");
+        assert_eq!(token_store.get("T1:U1:user").unwrap(), "xoxe.xoxp-new");
+    }
+
+    #[test]
+    fn test_exit_code_for_error() {
+        assert_eq!(
+            exit_code_for("Slack API error: invalid_auth", None),
+            EXIT_AUTH
+        );
+        assert_eq!(
+            exit_code_for("Non-interactive mode error: confirmation required", None),
+            EXIT_CONFIRMATION_REQUIRED
+        );
+        assert_eq!(
+            exit_code_for("API error: channel_not_found", None),
+            EXIT_NOT_FOUND
+        );
+        assert_eq!(
+            exit_code_for("Slack API error: missing_scope", None),
+            EXIT_PERMISSION
+        );
+        assert_eq!(
+            exit_code_for(
+                "Slack API error: ratelimited",
+                Some(RequestFailure::RateLimited)
+            ),
+            EXIT_RATE_LIMITED
+        );
+        assert_eq!(
+            exit_code_for("Failed to post", Some(RequestFailure::Network)),
+            EXIT_NETWORK
+        );
+        // Transport failures are not guessed from the text
+        assert_eq!(
+            exit_code_for("Invalid --text value: connection refused", None),
+            1
+        );
+        assert_eq!(exit_code_for("Invalid --limit value", None), 1);
+    }
+
+    /// Network and rate-limit failures are classified from the error variants
+    #[tokio::test]
+    #[serial]
+    async fn test_request_failure_from_typed_errors() {
+        use crate::api::{ApiClientError, ApiError};
+
+        assert_eq!(
+            ApiClientError::RateLimitExceeded(30).request_failure(),
+            Some(RequestFailure::RateLimited)
+        );
+        assert_eq!(
+            ApiError::SlackError("ratelimited".to_string()).request_failure(),
+            Some(RequestFailure::RateLimited)
+        );
+        assert_eq!(
+            ApiError::SlackError("channel_not_found".to_string()).request_failure(),
+            None
+        );
+
+        // Nothing listens on port 1
+        let client =
+            ApiClient::new_with_base_url("xoxb-test".to_string(), "http://127.0.0.1:1".to_string());
+        let error = client
+            .call_method(crate::api::ApiMethod::ConversationsList, HashMap::new())
+            .await
+            .unwrap_err();
+        assert_eq!(error.request_failure(), Some(RequestFailure::Network));
+        assert_eq!(exit_code_for_error(&error.to_string()), EXIT_NETWORK);
+    }
+
     #[test]
     fn test_parse_login_args_empty() {
         let args = vec![];
@@ -1037,6 +1606,33 @@ mod tests {
         assert!(parsed.tunnel_mode.is_cloudflared());
     }
 
+    #[test]
+    fn test_parse_login_args_scopes_from_manifest() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manifest = temp_dir.path().join("manifest.yml");
+        std::fs::write(
+            &manifest,
+            "oauth_config:\n  scopes:\n    bot:\n      - chat:write\n    user:\n      - search:read\n",
+        )
+        .unwrap();
+        let manifest = manifest.to_string_lossy().to_string();
+
+        let args = vec!["--scopes-from-manifest".to_string(), manifest.clone()];
+        let parsed = parse_login_args(&args).unwrap();
+        assert_eq!(parsed.bot_scopes, Some(vec!["chat:write".to_string()]));
+        assert_eq!(parsed.user_scopes, Some(vec!["search:read".to_string()]));
+
+        let args = vec![
+            "--scopes-from-manifest".to_string(),
+            manifest,
+            "--bot-scopes".to_string(),
+            "users:read".to_string(),
+        ];
+        assert!(parse_login_args(&args)
+            .unwrap_err()
+            .contains("cannot be combined"));
+    }
+
     #[test]
     fn test_parse_login_args_unknown_option() {
         let args = vec!["--unknown-flag".to_string()];
@@ -1107,6 +1703,8 @@ mod tests {
             use_get: false,
             token_type: None,
             raw: false,
+            get_url: false,
+            paginate: false,
         };
 
         let response = ApiCallResponse {
@@ -1142,6 +1740,8 @@ mod tests {
             use_get: false,
             token_type: None,
             raw: false,
+            get_url: false,
+            paginate: false,
         };
 
         let response = ApiCallResponse {
@@ -1179,6 +1779,8 @@ mod tests {
             use_get: false,
             token_type: None,
             raw: false,
+            get_url: false,
+            paginate: false,
         };
 
         let response = ApiCallResponse {
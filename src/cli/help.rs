@@ -41,6 +41,15 @@ pub fn print_import_help() {
     println!("    --force                    Overwrite existing profiles");
     println!("    --dry-run                  Preview changes without writing");
     println!("    --json                     Output import result as JSON");
+    println!(
+        "    --map <old:new>            Rename a profile on import (repeatable or comma-separated)"
+    );
+    println!(
+        "    --prefix <prefix>          Prefix names of imported profiles not covered by --map"
+    );
+    println!(
+        "    --only <names>             Import only these profiles (comma-separated bundle names)"
+    );
     println!("    --lang <code>              Language code (en/ja)");
     println!("    -h, --help                 Show this help message");
     println!();
@@ -52,6 +61,9 @@ pub fn print_import_help() {
     println!("    # Import with force overwrite");
     println!("    slack-rs auth import --in backup.enc --passphrase-prompt --force --yes");
     println!();
+    println!("    # Import a subset, keeping existing local profiles intact");
+    println!("    slack-rs auth import --in backup.enc --passphrase-env PASSPHRASE --only work,personal --map work:work-old --prefix imported-");
+    println!();
     println!("    # Preview import plan as JSON without writing changes");
     println!(
         "    slack-rs auth import --in backup.enc --passphrase-env PASSPHRASE --dry-run --json"
@@ -84,7 +84,7 @@ pub struct SchemaResponse {
 
 /// Get all command definitions
 pub fn get_command_definitions() -> Vec<CommandDef> {
-    vec![
+    let mut commands = vec![
         // api call
         CommandDef {
             name: "api call".to_string(),
@@ -112,6 +112,21 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                     description: "Output raw Slack API response (without envelope)".to_string(),
                     default: None,
                 },
+                FlagDef {
+                    name: "--get-url".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Print the resolved request (URL, headers with token redacted, body) without sending it".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Follow next_cursor and merge all result pages (alias: --paginate)"
+                        .to_string(),
+                    default: None,
+                },
                 FlagDef {
                     name: "--profile".to_string(),
                     flag_type: "string".to_string(),
@@ -119,12 +134,31 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                     description: "Profile name".to_string(),
                     default: Some("default".to_string()),
                 },
+                FlagDef {
+                    name: "--paginate".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Alias of --all".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
             ],
             examples: vec![
                 ExampleDef {
                     description: "Get user info".to_string(),
                     command: "slack-rs api call users.info user=U123456 --get".to_string(),
                 },
+                ExampleDef {
+                    description: "Show the request without sending it".to_string(),
+                    command: "slack-rs api call chat.postMessage channel=C123 text=Hello --get-url"
+                        .to_string(),
+                },
                 ExampleDef {
                     description: "Post message".to_string(),
                     command: "slack-rs api call chat.postMessage channel=C123 text=Hello"
@@ -169,11 +203,42 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                     description: "User scopes (comma-separated or 'all')".to_string(),
                     default: None,
                 },
+                FlagDef {
+                    name: "--scopes-from-manifest".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Read bot and user scopes from an app manifest file (YAML or JSON)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--cloudflared".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Use a cloudflared tunnel for the redirect URI (optional path to the binary)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--ngrok".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Use an ngrok tunnel for the redirect URI (optional path to the binary)"
+                        .to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Login with default profile".to_string(),
+                    command: "slack-rs auth login".to_string(),
+                },
+                ExampleDef {
+                    description: "Request the scopes declared in the app manifest".to_string(),
+                    command: "slack-rs auth login work --scopes-from-manifest manifest.yml"
+                        .to_string(),
+                },
             ],
-            examples: vec![ExampleDef {
-                description: "Login with default profile".to_string(),
-                command: "slack-rs auth login".to_string(),
-            }],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -210,12 +275,25 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
         CommandDef {
             name: "auth list".to_string(),
             description: "List all profiles".to_string(),
-            usage: "slack-rs auth list".to_string(),
-            flags: vec![],
-            examples: vec![ExampleDef {
-                description: "List profiles".to_string(),
-                command: "slack-rs auth list".to_string(),
+            usage: "slack-rs auth list [--json]".to_string(),
+            flags: vec![FlagDef {
+                name: "--json".to_string(),
+                flag_type: "boolean".to_string(),
+                required: false,
+                description: "Output profiles with token presence and health as JSON"
+                    .to_string(),
+                default: None,
             }],
+            examples: vec![
+                ExampleDef {
+                    description: "List profiles".to_string(),
+                    command: "slack-rs auth list".to_string(),
+                },
+                ExampleDef {
+                    description: "List profiles as JSON".to_string(),
+                    command: "slack-rs auth list --json".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -227,6 +305,92 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
             ],
         },
+        // auth test
+        CommandDef {
+            name: "auth test".to_string(),
+            description: "Show the token's identity and granted scopes (auth.test)".to_string(),
+            usage: "slack-rs auth test [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user, app)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output in JSON format".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Check the user token of a profile".to_string(),
+                command: "slack-rs auth test --profile work --token-type user".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Token invalid, revoked or not found".to_string(),
+                },
+            ],
+        },
+        // whoami
+        CommandDef {
+            name: "whoami".to_string(),
+            description: "Show which identity commands will act as (read-only)".to_string(),
+            usage: "slack-rs whoami [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user, app)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output in JSON format".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Check the identity behind a profile".to_string(),
+                command: "slack-rs whoami --profile work".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Token invalid, revoked or not found".to_string(),
+                },
+            ],
+        },
         // auth logout
         CommandDef {
             name: "auth logout".to_string(),
@@ -248,6 +412,38 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
             ],
         },
+        // auth set-token
+        CommandDef {
+            name: "auth set-token".to_string(),
+            description: "Store a token read from stdin for a profile (default: app-level token)"
+                .to_string(),
+            usage: "slack-rs auth set-token [profile_name] [flags]".to_string(),
+            flags: vec![FlagDef {
+                name: "--token-type".to_string(),
+                flag_type: "string".to_string(),
+                required: false,
+                description: "Token type (app, bot, user)".to_string(),
+                default: Some("app".to_string()),
+            }],
+            examples: vec![ExampleDef {
+                description: "Store an app-level token for Socket Mode".to_string(),
+                command: "echo \"$SLACK_APP_TOKEN\" | slack-rs auth set-token acme".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Invalid token or profile not found".to_string(),
+                },
+                ExitCodeDef {
+                    code: 2,
+                    description: "Non-interactive mode without a token on stdin".to_string(),
+                },
+            ],
+        },
         // conv list
         CommandDef {
             name: "conv list".to_string(),
@@ -279,9 +475,31 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                     name: "--format".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Output format (json, jsonl, table, tsv)".to_string(),
+                    description: "Output format (json, jsonl, table, tsv, csv, yaml)".to_string(),
                     default: Some("json".to_string()),
                 },
+                FlagDef {
+                    name: "--columns".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Comma-separated columns (dotted paths) for table, tsv and csv"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--diff-against".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Report channels created, removed, archived, renamed or with changed membership since this snapshot".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--snapshot-out".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Write a snapshot of the listed channels to this file".to_string(),
+                    default: None,
+                },
                 FlagDef {
                     name: "--raw".to_string(),
                     flag_type: "boolean".to_string(),
@@ -296,6 +514,56 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                     description: "Profile name".to_string(),
                     default: Some("default".to_string()),
                 },
+                FlagDef {
+                    name: "--include-private".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include private channels".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include all conversation types".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sort".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort key (name, created, num_members, last_activity)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sort-dir".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort direction (asc or desc)".to_string(),
+                    default: Some("asc".to_string()),
+                },
+                FlagDef {
+                    name: "--with-last-activity".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Add last_activity_ts (newest message ts) to each channel"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--max-enrich".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Fetch last activity for at most N channels".to_string(),
+                    default: Some("200".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
             ],
             examples: vec![
                 ExampleDef {
@@ -306,6 +574,12 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                     description: "List with filter".to_string(),
                     command: "slack-rs conv list --filter is_member:true".to_string(),
                 },
+                ExampleDef {
+                    description: "Report changes since the last run and roll the snapshot"
+                        .to_string(),
+                    command: "slack-rs conv list --diff-against channels.json --snapshot-out channels.json"
+                        .to_string(),
+                },
             ],
             exit_codes: vec![
                 ExitCodeDef {
@@ -323,52 +597,75 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
             name: "conv search".to_string(),
             description: "Search conversations by name".to_string(),
             usage: "slack-rs conv search <pattern> [flags]".to_string(),
-            flags: vec![FlagDef {
-                name: "--profile".to_string(),
-                flag_type: "string".to_string(),
-                required: false,
-                description: "Profile name".to_string(),
-                default: Some("default".to_string()),
-            }],
-            examples: vec![ExampleDef {
-                description: "Search conversations".to_string(),
-                command: "slack-rs conv search general".to_string(),
-            }],
-            exit_codes: vec![
-                ExitCodeDef {
-                    code: 0,
-                    description: "Success".to_string(),
-                },
-                ExitCodeDef {
-                    code: 1,
-                    description: "Command failed".to_string(),
-                },
-            ],
-        },
-        // conv history
-        CommandDef {
-            name: "conv history".to_string(),
-            description: "Get conversation history".to_string(),
-            usage: "slack-rs conv history <channel> [flags]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--limit".to_string(),
-                    flag_type: "integer".to_string(),
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
                     required: false,
-                    description: "Maximum number of messages".to_string(),
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--select".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Interactively select from results and output the channel ID only"
+                        .to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--profile".to_string(),
+                    name: "--types".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Conversation types (comma-separated)".to_string(),
+                    default: None,
                 },
-            ],
-            examples: vec![ExampleDef {
-                description: "Get history".to_string(),
-                command: "slack-rs conv history C123456".to_string(),
+                FlagDef {
+                    name: "--limit".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Maximum number of conversations to fetch".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filter".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Filter (key:value format, can be repeated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sort".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort key (name, created, num_members, last_activity)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sort-dir".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort direction (asc or desc)".to_string(),
+                    default: Some("asc".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Search conversations".to_string(),
+                command: "slack-rs conv search general".to_string(),
             }],
             exit_codes: vec![
                 ExitCodeDef {
@@ -381,31 +678,224 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
             ],
         },
-        // thread get
+        // conv history
         CommandDef {
-            name: "thread get".to_string(),
-            description: "Get thread messages (conversation replies)".to_string(),
-            usage: "slack-rs thread get <channel> <thread_ts> [flags]".to_string(),
+            name: "conv history".to_string(),
+            description: "Get conversation history".to_string(),
+            usage: "slack-rs conv history <channel> [flags]".to_string(),
             flags: vec![
                 FlagDef {
                     name: "--limit".to_string(),
                     flag_type: "integer".to_string(),
                     required: false,
-                    description: "Number of messages per page".to_string(),
-                    default: Some("100".to_string()),
+                    description: "Maximum number of messages".to_string(),
+                    default: None,
                 },
                 FlagDef {
-                    name: "--inclusive".to_string(),
+                    name: "--cursor".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Continue from meta.next_cursor of a previous run"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--cursor-stability".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Include parent message in results".to_string(),
+                    description: "Pin pages to a snapshot boundary and report edits/deletions made during the read"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sync".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Fetch only messages newer than the previous --sync run (implies --all)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sync-state".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sync state file (default: history_sync.json in the config directory)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--dedupe-edits".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Collapse message_changed events into the final message version"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--only-threads".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Keep only thread parents with at least one reply".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--only-top-level".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Keep only messages posted to the channel itself (no thread replies)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--only-broadcasts".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Keep only thread replies also sent to the channel".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Follow cursors and merge all result pages (alias: --paginate)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--paginate".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Alias of --all".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--oldest".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only messages after this timestamp".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--latest".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only messages before this timestamp".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--interactive".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Select the channel interactively".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--types".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Conversation types offered by --interactive (comma-separated)"
+                        .to_string(),
                     default: None,
                 },
                 FlagDef {
                     name: "--raw".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Output raw Slack API response".to_string(),
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filter".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Message filter (user, text, bot, thread, subtype); repeatable"
+                        .to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Get history".to_string(),
+                command: "slack-rs conv history C123456".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv replies
+        CommandDef {
+            name: "conv replies".to_string(),
+            description: "Get a thread's parent message and replies".to_string(),
+            usage: "slack-rs conv replies <channel> <thread_ts> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--limit".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Maximum number of messages per page".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--oldest".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only messages after this timestamp".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--latest".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only messages before this timestamp".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Follow cursors and return every reply".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filter".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Message filter (user, text, bot, thread, subtype); repeatable"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format (json, jsonl, table, tsv, csv, yaml)".to_string(),
+                    default: Some("json".to_string()),
+                },
+                FlagDef {
+                    name: "--columns".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Comma-separated columns (dotted paths) for table, tsv and csv"
+                        .to_string(),
                     default: None,
                 },
                 FlagDef {
@@ -415,6 +905,4971 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                     description: "Profile name".to_string(),
                     default: Some("default".to_string()),
                 },
+                FlagDef {
+                    name: "--cursor".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Pagination cursor".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--paginate".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Alias of --all".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Show a thread as a table".to_string(),
+                command: "slack-rs conv replies C123456 1700000000.000100 --format table"
+                    .to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv export
+        CommandDef {
+            name: "conv export".to_string(),
+            description: "Export conversation history as JSON or static HTML".to_string(),
+            usage: "slack-rs conv export <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format (json, html)".to_string(),
+                    default: Some("json".to_string()),
+                },
+                FlagDef {
+                    name: "--out".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output file path (default: stdout)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--no-threads".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip fetching thread replies".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--avatars".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Show user avatars in HTML output".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--redact".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Mask PII classes: emails, phones, custom:<regex> (comma-separated, repeatable)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--dedupe-edits".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Collapse message_changed events into the edited messages"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--oldest".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only messages after this timestamp".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--latest".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only messages before this timestamp".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--title".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Document title".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Export channel to an HTML page".to_string(),
+                command: "slack-rs conv export C123456 --format html --out general.html"
+                    .to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv members
+        CommandDef {
+            name: "conv members".to_string(),
+            description: "List every member of a conversation".to_string(),
+            usage: "slack-rs conv members <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--resolve".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Add name, display_name and real_name from the users cache"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--only-guests".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Keep only multi- and single-channel guests (implies --resolve)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--only-external".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description:
+                        "Keep only people from other organizations (implies --resolve)"
+                            .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format (json, jsonl, table, tsv, csv, yaml)".to_string(),
+                    default: Some("json".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Show members with their names as a table".to_string(),
+                command: "slack-rs conv members C123456 --resolve --format table".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "API call failed".to_string(),
+                },
+            ],
+        },
+        // conv tail
+        CommandDef {
+            name: "conv tail".to_string(),
+            description: "Watch a channel for new messages and dispatch them".to_string(),
+            usage: "slack-rs conv tail <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--exec".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Shell command run per message (message JSON on stdin)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filter".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Filter condition KEY:VALUE (user, text, bot, thread, subtype); repeatable".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--interval".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Seconds between polls".to_string(),
+                    default: Some("5".to_string()),
+                },
+                FlagDef {
+                    name: "--since".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only dispatch messages newer than this timestamp (default: now)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--max-events".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Exit after dispatching N messages".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--dedupe-edits".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Emit edits as the final message version under the original ts"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--queue-size".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Lines buffered in memory before --overflow applies".to_string(),
+                    default: Some("1000".to_string()),
+                },
+                FlagDef {
+                    name: "--overflow".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "When the queue is full: block, drop-oldest or spill".to_string(),
+                    default: Some("block".to_string()),
+                },
+                FlagDef {
+                    name: "--spill-file".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "File used by --overflow=spill".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--meta-interval".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Seconds between meta lines reporting dropped/spilled lines".to_string(),
+                    default: Some("10".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Run a handler for every message mentioning deploy".to_string(),
+                command: "slack-rs conv tail C123456 --filter 'text:*deploy*' --exec ./handler.sh"
+                    .to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv watch
+        CommandDef {
+            name: "conv watch".to_string(),
+            description: "Live tail of a channel as JSON lines with resolved user names".to_string(),
+            usage: "slack-rs conv watch <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--interval".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Polling interval (e.g. 5s, 1m)".to_string(),
+                    default: Some("5s".to_string()),
+                },
+                FlagDef {
+                    name: "--socket".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Receive messages through Socket Mode instead of polling".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--app-token".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "App-level token for --socket (default: SLACK_APP_TOKEN)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filter".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Filter condition KEY:VALUE (user, text, bot, thread, subtype); repeatable".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--since".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Start after this timestamp instead of the saved cursor".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--state".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Cursor state file (default: watch_state.json in the config directory)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--max-events".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Exit after printing N messages".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--queue-size".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Lines buffered in memory before --overflow applies".to_string(),
+                    default: Some("1000".to_string()),
+                },
+                FlagDef {
+                    name: "--overflow".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "When the queue is full: block, drop-oldest or spill".to_string(),
+                    default: Some("block".to_string()),
+                },
+                FlagDef {
+                    name: "--spill-file".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "File used by --overflow=spill".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--meta-interval".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Seconds between meta lines reporting dropped/spilled lines".to_string(),
+                    default: Some("10".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Poll a channel every 10 seconds".to_string(),
+                    command: "slack-rs conv watch C123456 --interval 10s".to_string(),
+                },
+                ExampleDef {
+                    description: "Receive messages through Socket Mode".to_string(),
+                    command: "slack-rs conv watch C123456 --socket --app-token xapp-1-...".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv create
+        CommandDef {
+            name: "conv create".to_string(),
+            description: "Create a channel".to_string(),
+            usage: "slack-rs conv create <name> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--private".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Create a private channel".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Create a private channel".to_string(),
+                command: "slack-rs conv create ops-alerts --private --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv archive
+        CommandDef {
+            name: "conv archive".to_string(),
+            description: "Archive a channel".to_string(),
+            usage: "slack-rs conv archive <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Archive a channel".to_string(),
+                command: "slack-rs conv archive C123456 --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv unarchive
+        CommandDef {
+            name: "conv unarchive".to_string(),
+            description: "Unarchive a channel".to_string(),
+            usage: "slack-rs conv unarchive <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Unarchive a channel".to_string(),
+                command: "slack-rs conv unarchive C123456 --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv rename
+        CommandDef {
+            name: "conv rename".to_string(),
+            description: "Rename a channel".to_string(),
+            usage: "slack-rs conv rename <channel> <name> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Rename a channel".to_string(),
+                command: "slack-rs conv rename C123456 ops-archive --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv invite
+        CommandDef {
+            name: "conv invite".to_string(),
+            description: "Invite users to a channel".to_string(),
+            usage: "slack-rs conv invite <channel> <user>... [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Invite two users".to_string(),
+                command: "slack-rs conv invite C123456 U111 U222 --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv kick
+        CommandDef {
+            name: "conv kick".to_string(),
+            description: "Remove a user from a channel".to_string(),
+            usage: "slack-rs conv kick <channel> <user> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Remove a user".to_string(),
+                command: "slack-rs conv kick C123456 U111 --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv join
+        CommandDef {
+            name: "conv join".to_string(),
+            description: "Join a public channel".to_string(),
+            usage: "slack-rs conv join <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Add the bot to a public channel".to_string(),
+                command: "slack-rs conv join C123456 --token-type bot --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // conv leave
+        CommandDef {
+            name: "conv leave".to_string(),
+            description: "Leave a channel".to_string(),
+            usage: "slack-rs conv leave <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Leave a channel".to_string(),
+                command: "slack-rs conv leave C123456 --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // thread get
+        CommandDef {
+            name: "thread get".to_string(),
+            description: "Get thread messages (conversation replies)".to_string(),
+            usage: "slack-rs thread get <channel> <thread_ts> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--limit".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Number of messages per page".to_string(),
+                    default: Some("100".to_string()),
+                },
+                FlagDef {
+                    name: "--inclusive".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include parent message in results".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Get thread messages".to_string(),
+                    command: "slack-rs thread get C123456 1234567890.123456".to_string(),
+                },
+                ExampleDef {
+                    description: "Get thread with parent message".to_string(),
+                    command: "slack-rs thread get C123456 1234567890.123456 --inclusive".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // msg post
+        CommandDef {
+            name: "msg post".to_string(),
+            description: "Post a message to a channel".to_string(),
+            usage: "slack-rs msg post <channel> <text> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--blocks".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Block Kit blocks as JSON, @file or - for stdin (text becomes the fallback)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--text".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Message text instead of the <text> argument (- reads stdin)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--text-file".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Read the message text from a file (- for stdin); text over 40,000 characters is truncated unless --split is given".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--thread-ts".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Thread to reply to: timestamp, message permalink, or latest[:#channel][:from=@name][:text=PATTERN]".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--reply-broadcast".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Broadcast reply to channel".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--split".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Split long text into chunks threaded under the first message"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--split-limit".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Chunk size in characters for --split".to_string(),
+                    default: Some("3900".to_string()),
+                },
+                FlagDef {
+                    name: "--auto-snippet".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Post text over 4000 characters as a snippet file with a lead-in"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--from".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Source format of the text to convert to mrkdwn (markdown)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--markdown".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Convert the text from standard Markdown to mrkdwn (same as --from markdown)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--resolve-mentions".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Rewrite @name and #channel in the text into mentions using the local caches".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--pin".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Pin the message after posting".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--react".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Comma-separated reactions to add after posting".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--no-rollback".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Keep the message if a --pin/--react step fails".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--notify-group".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Comma-separated usergroup handles to mention".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--no-footer".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Do not append the output.post_footer footer".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--preview".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Show the message as it will read and ask before sending (interactive only)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--unique-per".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Post at most once per UTC day or hour (day, hour)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Post message".to_string(),
+                command: "slack-rs msg post C123 'Hello world'".to_string(),
+            },
+            ExampleDef {
+                description: "Post an announcement, pin it and add reactions".to_string(),
+                command: "slack-rs msg post C123 'Release v2 is out' --pin --react rocket,eyes"
+                    .to_string(),
+            },
+            ExampleDef {
+                description: "Post release notes from a file as a thread of chunks".to_string(),
+                command: "slack-rs msg post C123 --text-file NOTES.md --split".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Post failed".to_string(),
+                },
+            ],
+        },
+        // msg update
+        CommandDef {
+            name: "msg update".to_string(),
+            description: "Update a message".to_string(),
+            usage: "slack-rs msg update <channel> <ts> <text> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--from".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Source format of the text to convert to mrkdwn (markdown)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--markdown".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Convert the text from standard Markdown to mrkdwn (same as --from markdown)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Update message".to_string(),
+                command: "slack-rs msg update C123 1234567890.123456 'Updated text'".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Update failed".to_string(),
+                },
+            ],
+        },
+        // msg delete
+        CommandDef {
+            name: "msg delete".to_string(),
+            description: "Delete a message".to_string(),
+            usage: "slack-rs msg delete <channel> <ts> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Delete message".to_string(),
+                command: "slack-rs msg delete C123 1234567890.123456".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Delete failed".to_string(),
+                },
+            ],
+        },
+        // msg schedule
+        CommandDef {
+            name: "msg schedule".to_string(),
+            description: "Schedule a message for later delivery".to_string(),
+            usage: "slack-rs msg schedule <channel> <text> (--at=TIME | --in=DURATION) [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--at".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Delivery time: \"YYYY-MM-DD HH:MM\" (local), HH:MM, RFC 3339 or UNIX timestamp".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--in".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Delivery delay, e.g. 30m, 2h, 1h30m, 3d".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--thread-ts".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Schedule as a reply in this thread".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--no-footer".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Do not append the output.post_footer footer".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Post a reminder in two hours".to_string(),
+                command: "slack-rs msg schedule C123 \"Standup in 5 minutes\" --in 2h".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Schedule failed".to_string(),
+                },
+            ],
+        },
+        // msg scheduled list
+        CommandDef {
+            name: "msg scheduled list".to_string(),
+            description: "List pending scheduled messages".to_string(),
+            usage: "slack-rs msg scheduled list [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only list messages scheduled in this channel".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--limit".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Maximum number of messages".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--cursor".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Pagination cursor".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "List scheduled messages in a channel".to_string(),
+                command: "slack-rs msg scheduled list --channel C123".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // msg scheduled delete
+        CommandDef {
+            name: "msg scheduled delete".to_string(),
+            description: "Cancel a pending scheduled message".to_string(),
+            usage: "slack-rs msg scheduled delete <channel> <scheduled_message_id> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Cancel a scheduled message".to_string(),
+                command: "slack-rs msg scheduled delete C123 Q1298393284".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Delete failed".to_string(),
+                },
+            ],
+        },
+        // users info
+        CommandDef {
+            name: "users info".to_string(),
+            description: "Get user information".to_string(),
+            usage: "slack-rs users info <user_id> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Get user info".to_string(),
+                command: "slack-rs users info U123456".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // msg bulk
+        CommandDef {
+            name: "msg bulk".to_string(),
+            description: "Post one message per JSONL/CSV row with a per-row result report".to_string(),
+            usage: "slack-rs msg bulk --input <file> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--input".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "JSONL or CSV file with channel/text/thread_ts rows (- for stdin)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--input-format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Input format (jsonl, csv; default from the file extension)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--rate".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Posting rate (N/s, N/m, N/h)".to_string(),
+                    default: Some("1/s".to_string()),
+                },
+                FlagDef {
+                    name: "--dry-run".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Validate and report rows without posting".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--report".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Write the per-row JSONL report to a file (default: stdout)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--resume".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Skip rows recorded as done in a previous report".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--no-footer".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Do not append the output.post_footer footer".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Post announcements and keep a resumable report".to_string(),
+                command: "slack-rs msg bulk --input announcements.csv --report report.jsonl --yes"
+                    .to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // msg inspect
+        CommandDef {
+            name: "msg inspect".to_string(),
+            description: "Show a message with rendered blocks, reactions, thread stats and files"
+                .to_string(),
+            usage: "slack-rs msg inspect <permalink> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output the inspection report in JSON format".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output the message JSON as returned by Slack".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Inspect a message from its permalink".to_string(),
+                command: "slack-rs msg inspect https://acme.slack.com/archives/C123456/p1700000000000100"
+                    .to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Invalid permalink, message not found or API call failed"
+                        .to_string(),
+                },
+            ],
+        },
+        // react add
+        CommandDef {
+            name: "react add".to_string(),
+            description: "Add a reaction to a message".to_string(),
+            usage: "slack-rs react add <channel> <ts> <emoji> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--check-emoji".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Resolve aliases and warn about unknown names using the emoji cache".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Add reaction".to_string(),
+                command: "slack-rs react add C123 1234567890.123456 thumbsup".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // react remove
+        CommandDef {
+            name: "react remove".to_string(),
+            description: "Remove a reaction from a message".to_string(),
+            usage: "slack-rs react remove <channel> <ts> <emoji> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--check-emoji".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Resolve aliases and warn about unknown names using the emoji cache".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Remove reaction".to_string(),
+                command: "slack-rs react remove C123 1234567890.123456 thumbsup".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // emoji list
+        CommandDef {
+            name: "emoji list".to_string(),
+            description: "List custom emoji (cached per workspace for 24 hours)".to_string(),
+            usage: "slack-rs emoji list [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--refresh".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Fetch from the API even if the cache is still valid".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format (json, jsonl, table, tsv, csv, yaml)".to_string(),
+                    default: Some("json".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "List custom emoji as a table".to_string(),
+                command: "slack-rs emoji list --format=table".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // pin add
+        CommandDef {
+            name: "pin add".to_string(),
+            description: "Pin a message to its channel".to_string(),
+            usage: "slack-rs pin add <channel> <ts> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Pin a message".to_string(),
+                command: "slack-rs pin add C123 1234567890.123456 --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // pin remove
+        CommandDef {
+            name: "pin remove".to_string(),
+            description: "Unpin a message".to_string(),
+            usage: "slack-rs pin remove <channel> <ts> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Unpin a message".to_string(),
+                command: "slack-rs pin remove C123 1234567890.123456 --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // pin list
+        CommandDef {
+            name: "pin list".to_string(),
+            description: "List the items pinned in a channel".to_string(),
+            usage: "slack-rs pin list <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "List pins".to_string(),
+                command: "slack-rs pin list C123".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // usergroup list
+        CommandDef {
+            name: "usergroup list".to_string(),
+            description: "List usergroups".to_string(),
+            usage: "slack-rs usergroup list [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--include-users".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include member IDs of each group".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--include-disabled".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include disabled groups".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format (json, jsonl, table, tsv, csv, yaml)".to_string(),
+                    default: Some("json".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "List usergroups as a table".to_string(),
+                    command: "slack-rs usergroup list --format=table".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // usergroup create
+        CommandDef {
+            name: "usergroup create".to_string(),
+            description: "Create a usergroup".to_string(),
+            usage: "slack-rs usergroup create <name> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--handle".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Mention handle (without @)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--description".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Group description".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--channels".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Default channel IDs (comma-separated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--name".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Group name (alternative to the positional argument)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Create an on-call group".to_string(),
+                    command: "slack-rs usergroup create \"On-call\" --handle=oncall --yes".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // usergroup update
+        CommandDef {
+            name: "usergroup update".to_string(),
+            description: "Update a usergroup's name, handle, description or default channels".to_string(),
+            usage: "slack-rs usergroup update <usergroup> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--name".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "New group name".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--handle".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Mention handle (without @)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--description".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Group description".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--channels".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Default channel IDs (comma-separated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Change the handle".to_string(),
+                    command: "slack-rs usergroup update S123 --handle=oncall-team --yes".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // usergroup users
+        CommandDef {
+            name: "usergroup users".to_string(),
+            description: "List the members of a usergroup".to_string(),
+            usage: "slack-rs usergroup users <usergroup> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "List members".to_string(),
+                    command: "slack-rs usergroup users S123".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // usergroup users update
+        CommandDef {
+            name: "usergroup users update".to_string(),
+            description: "Replace the members of a usergroup".to_string(),
+            usage: "slack-rs usergroup users update <usergroup> (--users=U1,U2 | --from-file=PATH) [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--users".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Member user IDs (comma-separated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--from-file".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "File with one user ID per line (# starts a comment)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Sync the on-call rotation".to_string(),
+                    command: "slack-rs usergroup users update S123 --from-file=oncall.txt --yes".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // bookmark list
+        CommandDef {
+            name: "bookmark list".to_string(),
+            description: "List the bookmarks of a channel".to_string(),
+            usage: "slack-rs bookmark list <channel> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format (json, jsonl, table, tsv, csv, yaml)".to_string(),
+                    default: Some("json".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Show bookmarks as a table".to_string(),
+                command: "slack-rs bookmark list C123456 --format table".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // bookmark add
+        CommandDef {
+            name: "bookmark add".to_string(),
+            description: "Add a link bookmark to a channel".to_string(),
+            usage: "slack-rs bookmark add <channel> --title <title> --link <url> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--title".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "Bookmark title".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--link".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "URL the bookmark opens".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--emoji".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Emoji shown next to the title".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Prevent duplicate writes (replays stored result on retry)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Bookmark the runbook from CI".to_string(),
+                command: "slack-rs bookmark add C123456 --title Runbook --link https://example.com/runbook --emoji :book: --yes --idempotency-key runbook".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // bookmark remove
+        CommandDef {
+            name: "bookmark remove".to_string(),
+            description: "Remove a bookmark from a channel".to_string(),
+            usage: "slack-rs bookmark remove <channel> <bookmark_id> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Prevent duplicate writes (replays stored result on retry)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Remove a bookmark".to_string(),
+                command: "slack-rs bookmark remove C123456 Bk0123ABCD --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // bookmark apply
+        CommandDef {
+            name: "bookmark apply".to_string(),
+            description: "Create, edit and remove bookmarks so a channel matches a YAML/JSON spec".to_string(),
+            usage: "slack-rs bookmark apply <channel> --spec <file> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--spec".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "YAML or JSON list of {title, link, emoji} entries (- for stdin)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Channel ID or #name (instead of the <channel> argument)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--dry-run".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Show the planned changes without applying them".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--keep-unlisted".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Keep link bookmarks the spec does not list".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Print the report as JSON".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot, user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Preview the changes for #ops".to_string(),
+                    command: "slack-rs bookmark apply '#ops' --spec bookmarks.yml --dry-run".to_string(),
+                },
+                ExampleDef {
+                    description: "Apply the same runbook links to several channels".to_string(),
+                    command: "for c in ops sre; do slack-rs bookmark apply \"#$c\" --spec bookmarks.yml --yes; done".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Invalid spec, or a change failed".to_string(),
+                },
+            ],
+        },
+        // remind add
+        CommandDef {
+            name: "remind add".to_string(),
+            description: "Create a reminder (user token only)".to_string(),
+            usage: "slack-rs remind add <text> --time <when> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--time".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "When to remind: UNIX timestamp, seconds from now or natural language".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--user".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "User ID to remind (default: yourself)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Remind yourself every weekday".to_string(),
+                command: "slack-rs remind add 'Stand-up' --time 'every weekday at 9:45am' --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // remind list
+        CommandDef {
+            name: "remind list".to_string(),
+            description: "List your reminders (user token only)".to_string(),
+            usage: "slack-rs remind list [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format (json, jsonl, table, tsv, csv, yaml)".to_string(),
+                    default: Some("json".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Show reminders as a table".to_string(),
+                command: "slack-rs remind list --format table".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // remind complete
+        CommandDef {
+            name: "remind complete".to_string(),
+            description: "Mark a reminder as complete (user token only)".to_string(),
+            usage: "slack-rs remind complete <reminder_id> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Complete a reminder".to_string(),
+                command: "slack-rs remind complete Rm0123ABCD --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // remind delete
+        CommandDef {
+            name: "remind delete".to_string(),
+            description: "Delete a reminder (user token only)".to_string(),
+            usage: "slack-rs remind delete <reminder_id> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Delete a reminder".to_string(),
+                command: "slack-rs remind delete Rm0123ABCD --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // dnd info
+        CommandDef {
+            name: "dnd info".to_string(),
+            description: "Show Do Not Disturb status (user token only)".to_string(),
+            usage: "slack-rs dnd info [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--user".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "User ID (default: yourself)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Check whether you are snoozed".to_string(),
+                command: "slack-rs dnd info".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // dnd set
+        CommandDef {
+            name: "dnd set".to_string(),
+            description: "Snooze notifications for a number of minutes (user token only)".to_string(),
+            usage: "slack-rs dnd set <minutes|duration> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Focus for an hour and a half".to_string(),
+                command: "slack-rs dnd set 1h30m --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // dnd end
+        CommandDef {
+            name: "dnd end".to_string(),
+            description: "End the current snooze (user token only)".to_string(),
+            usage: "slack-rs dnd end [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Resume notifications".to_string(),
+                command: "slack-rs dnd end --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // presence get
+        CommandDef {
+            name: "presence get".to_string(),
+            description: "Show a user's presence (user token only)".to_string(),
+            usage: "slack-rs presence get [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--user".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "User ID (default: yourself)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Check a teammate's presence".to_string(),
+                command: "slack-rs presence get --user U0123ABCD".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // presence set
+        CommandDef {
+            name: "presence set".to_string(),
+            description: "Set your presence to auto or away (user token only)".to_string(),
+            usage: "slack-rs presence set <auto|away> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Appear away".to_string(),
+                command: "slack-rs presence set away --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // file upload
+        CommandDef {
+            name: "file upload".to_string(),
+            description: "Upload a file".to_string(),
+            usage: "slack-rs file upload <path|pattern|->... [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--max-dim".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Downscale PNG/JPEG images so neither side exceeds this many pixels (requires image-processing feature)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--strip-exif".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Re-encode PNG/JPEG images to remove EXIF metadata; other formats are refused (requires image-processing feature)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--parallel".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Files transferred at the same time when uploading several".to_string(),
+                    default: Some("4".to_string()),
+                },
+                FlagDef {
+                    name: "--force".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Upload even if identical content was already uploaded to the same channels".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--idempotency-key".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Channel ID to share the file in".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--channels".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Channel IDs to share the file in (comma-separated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--title".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "File title".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--comment".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Initial comment posted with the file".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filename".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "File name shown in Slack (default: the file's own name, 'stdin' for -)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--snippet-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Snippet syntax type for text files (e.g. text, python, shell)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Upload file".to_string(),
+                    command: "slack-rs file upload document.pdf".to_string(),
+                },
+                ExampleDef {
+                    description: "Upload every PDF in a directory with one comment".to_string(),
+                    command: "slack-rs file upload 'reports/*.pdf' --channel C123 --comment \"nightly\"".to_string(),
+                },
+                ExampleDef {
+                    description: "Upload a CI log from stdin as a text snippet".to_string(),
+                    command: "./build.sh 2>&1 | slack-rs file upload - --filename build.log --snippet-type text --channel C123 --yes".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Upload failed".to_string(),
+                },
+            ],
+        },
+        // file download
+        CommandDef {
+            name: "file download".to_string(),
+            description: "Download a file from Slack".to_string(),
+            usage: "slack-rs file download [<file_id>] [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--url".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Direct download URL (alternative to file_id)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--out".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output path (omit for current directory, '-' for stdout, directory for auto-naming)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Download by file ID".to_string(),
+                    command: "slack-rs file download F123456".to_string(),
+                },
+                ExampleDef {
+                    description: "Download to stdout".to_string(),
+                    command: "slack-rs file download F123456 --out -".to_string(),
+                },
+                ExampleDef {
+                    description: "Download by URL".to_string(),
+                    command: "slack-rs file download --url https://files.slack.com/...".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Download failed".to_string(),
+                },
+            ],
+        },
+        // file list
+        CommandDef {
+            name: "file list".to_string(),
+            description: "List files".to_string(),
+            usage: "slack-rs file list [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only files shared in this channel ID".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--user".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only files created by this user ID".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--types".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "File types: all, spaces, snippets, images, gdocs, zips, pdfs (comma-separated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--older-than".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only files created at least this long ago (e.g. 90d, 12w)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--count".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Files per page".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--page".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Page number".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Follow every result page (up to 100)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "List images older than 90 days".to_string(),
+                    command: "slack-rs file list --types images --older-than 90d --all".to_string(),
+                },
+                ExampleDef {
+                    description: "Table of one user's files in a channel".to_string(),
+                    command: "slack-rs file list --channel C123456 --user U123456 --format table".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Listing failed".to_string(),
+                },
+            ],
+        },
+        // file delete
+        CommandDef {
+            name: "file delete".to_string(),
+            description: "Delete files".to_string(),
+            usage: "slack-rs file delete <file_id>... [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--from-list".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Read file IDs from a file or '-' for stdin (file list JSON/JSONL, or one ID per line)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Delete one file".to_string(),
+                    command: "slack-rs file delete F123456 --yes".to_string(),
+                },
+                ExampleDef {
+                    description: "Delete every image older than 90 days".to_string(),
+                    command: "slack-rs file list --types images --older-than 90d --all --format jsonl | slack-rs file delete --from-list - --yes".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Deletion failed or some files could not be deleted".to_string(),
+                },
+            ],
+        },
+        // search
+        CommandDef {
+            name: "search".to_string(),
+            description: "Search messages".to_string(),
+            usage: "slack-rs search <query> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--count".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Number of results".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--page".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Page number".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--in".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches in this channel (ID, #name or @user; repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--from".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches from this user (ID, @name or me; repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--after".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches after this date (YYYY-MM-DD)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--before".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches before this date (YYYY-MM-DD)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--has".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches with this reaction (:emoji:) or link, pin, star (repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--during".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches during a month, year, YYYY-MM, today, yesterday, week".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--group-by".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Summarize matches per channel or user (channel, user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--summary".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output counts and first/last match ts instead of matches"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--profiles".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Comma-separated profiles to search concurrently and merge"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--all-profiles".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Search every configured profile and merge the results"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Follow cursors and merge all result pages (alias: --paginate)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--paginate".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Alias of --all".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--max-results".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Fetch pages until N matches are collected".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--flatten".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output matches as a top-level messages array".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sort".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort by timestamp or score".to_string(),
+                    default: Some("score".to_string()),
+                },
+                FlagDef {
+                    name: "--sort_dir".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort direction (asc or desc)".to_string(),
+                    default: Some("desc".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Search messages".to_string(),
+                    command: "slack-rs search 'important announcement'".to_string(),
+                },
+                ExampleDef {
+                    description: "Search a channel for one user's messages since a date"
+                        .to_string(),
+                    command:
+                        "slack-rs search deploy --in '#releases' --from @alice --after 2026-01-01"
+                            .to_string(),
+                },
+                ExampleDef {
+                    description: "Fetch up to 500 matches as JSON lines".to_string(),
+                    command: "slack-rs search 'deploy' --max-results 500 --format jsonl"
+                        .to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Search failed".to_string(),
+                },
+            ],
+        },
+        // search files
+        CommandDef {
+            name: "search files".to_string(),
+            description: "Search files".to_string(),
+            usage: "slack-rs search files <query> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--count".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Number of results".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--page".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Page number".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--in".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches in this channel (ID, #name or @user; repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--from".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches from this user (ID, @name or me; repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--after".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches after this date (YYYY-MM-DD)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--before".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches before this date (YYYY-MM-DD)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--has".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches with this reaction (:emoji:) or link, pin, star (repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--during".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches during a month, year, YYYY-MM, today, yesterday, week".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sort".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort by timestamp or score".to_string(),
+                    default: Some("score".to_string()),
+                },
+                FlagDef {
+                    name: "--sort_dir".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort direction (asc or desc)".to_string(),
+                    default: Some("desc".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Find spreadsheets shared in a channel".to_string(),
+                command: "slack-rs search files 'budget in:#finance type:spreadsheets'".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Search failed".to_string(),
+                },
+            ],
+        },
+        // search all
+        CommandDef {
+            name: "search all".to_string(),
+            description: "Search messages and files".to_string(),
+            usage: "slack-rs search all <query> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--count".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Number of results".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--page".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Page number".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--in".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches in this channel (ID, #name or @user; repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--from".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches from this user (ID, @name or me; repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--after".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches after this date (YYYY-MM-DD)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--before".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches before this date (YYYY-MM-DD)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--has".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches with this reaction (:emoji:) or link, pin, star (repeatable)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--during".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only matches during a month, year, YYYY-MM, today, yesterday, week".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--sort".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort by timestamp or score".to_string(),
+                    default: Some("score".to_string()),
+                },
+                FlagDef {
+                    name: "--sort_dir".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Sort direction (asc or desc)".to_string(),
+                    default: Some("desc".to_string()),
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Search messages and files at once".to_string(),
+                command: "slack-rs search all 'quarterly report'".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Search failed".to_string(),
+                },
+            ],
+        },
+        // auth rename
+        CommandDef {
+            name: "auth rename".to_string(),
+            description: "Rename a profile".to_string(),
+            usage: "slack-rs auth rename <old_name> <new_name>".to_string(),
+            flags: vec![],
+            examples: vec![ExampleDef {
+                description: "Rename profile".to_string(),
+                command: "slack-rs auth rename work personal".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Rename failed".to_string(),
+                },
+            ],
+        },
+        // auth export
+        CommandDef {
+            name: "auth export".to_string(),
+            description: "Export profiles to encrypted file".to_string(),
+            usage: "slack-rs auth export [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Export specific profile".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Export all profiles".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--out".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "Output file path".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--passphrase-env".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Environment variable containing passphrase".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--passphrase-prompt".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Prompt for passphrase".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Confirm dangerous operation".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Export all profiles".to_string(),
+                command: "slack-rs auth export --all --out profiles.enc --yes".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Export failed".to_string(),
+                },
+            ],
+        },
+        // auth import
+        CommandDef {
+            name: "auth import".to_string(),
+            description: "Import profiles from encrypted file".to_string(),
+            usage: "slack-rs auth import [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--in".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "Input file path".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--passphrase-env".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Environment variable containing passphrase".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--passphrase-prompt".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Prompt for passphrase".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Automatically accept conflicts".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--force".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Overwrite existing profiles".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--dry-run".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Show what would be imported without making changes".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output results in JSON format".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--map".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Rename profiles on import (old:new, repeatable or comma-separated)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--only".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Import only these profiles (comma-separated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--prefix".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Prefix added to imported profile names".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--all".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Import every profile in the file (the default)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Import profiles".to_string(),
+                    command: "slack-rs auth import --in profiles.enc".to_string(),
+                },
+                ExampleDef {
+                    description: "Preview import without making changes".to_string(),
+                    command: "slack-rs auth import --in profiles.enc --dry-run".to_string(),
+                },
+                ExampleDef {
+                    description: "Preview import with JSON output".to_string(),
+                    command: "slack-rs auth import --in profiles.enc --dry-run --json".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Import failed".to_string(),
+                },
+            ],
+        },
+        // config oauth set
+        CommandDef {
+            name: "config oauth set".to_string(),
+            description: "Set OAuth configuration for a profile".to_string(),
+            usage: "slack-rs config oauth set <profile> --client-id <id> --redirect-uri <uri> --scopes <scopes> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--client-id".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "OAuth client ID".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--redirect-uri".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "OAuth redirect URI".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--scopes".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "Comma-separated list of scopes or 'all'".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--client-secret-env".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Read secret from environment variable".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--client-secret-file".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Read secret from file".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--client-secret".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Direct secret value (requires --yes, unsafe)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Confirm dangerous operation".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Set OAuth config".to_string(),
+                command: "slack-rs config oauth set work --client-id 123.456 --redirect-uri http://127.0.0.1:8765/callback --scopes all".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Config set failed".to_string(),
+                },
+            ],
+        },
+        // config oauth show
+        CommandDef {
+            name: "config oauth show".to_string(),
+            description: "Show OAuth configuration for a profile".to_string(),
+            usage: "slack-rs config oauth show <profile>".to_string(),
+            flags: vec![],
+            examples: vec![ExampleDef {
+                description: "Show OAuth config".to_string(),
+                command: "slack-rs config oauth show work".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Config show failed".to_string(),
+                },
+            ],
+        },
+        // config oauth delete
+        CommandDef {
+            name: "config oauth delete".to_string(),
+            description: "Delete OAuth configuration for a profile".to_string(),
+            usage: "slack-rs config oauth delete <profile>".to_string(),
+            flags: vec![],
+            examples: vec![ExampleDef {
+                description: "Delete OAuth config".to_string(),
+                command: "slack-rs config oauth delete work".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Config delete failed".to_string(),
+                },
+            ],
+        },
+        // config set
+        CommandDef {
+            name: "config set".to_string(),
+            description: "Set default token type, output and display preferences for a profile"
+                .to_string(),
+            usage: "slack-rs config set <profile> --token-type <type>".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "Default token type (bot or user)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--global".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Set output defaults for every profile".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Set token type".to_string(),
+                    command: "slack-rs config set work --token-type bot".to_string(),
+                },
+                ExampleDef {
+                    description: "Show real names and 12-hour times for a profile".to_string(),
+                    command: "slack-rs config set work output.user_name=real_name output.time_format=12h"
+                        .to_string(),
+                },
+                ExampleDef {
+                    description: "Sign automated posts with the host and CI job".to_string(),
+                    command: "slack-rs config set work 'output.post_footer=sent by slack-rs on {hostname} for {job}'"
+                        .to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Config set failed".to_string(),
+                },
+            ],
+        },
+        // conv select
+        CommandDef {
+            name: "conv select".to_string(),
+            description: "Interactively select a conversation".to_string(),
+            usage: "slack-rs conv select [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--types".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Conversation types (comma-separated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--limit".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Maximum number of conversations to fetch".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filter".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Filter (key:value format, can be repeated)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Select conversation".to_string(),
+                command: "slack-rs conv select".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Selection failed".to_string(),
+                },
+            ],
+        },
+        // users cache-update
+        CommandDef {
+            name: "users cache-update".to_string(),
+            description: "Update user cache for mention resolution".to_string(),
+            usage: "slack-rs users cache-update [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--force".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Force cache update".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Update user cache".to_string(),
+                command: "slack-rs users cache-update".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Cache update failed".to_string(),
+                },
+            ],
+        },
+        // users resolve-mentions
+        CommandDef {
+            name: "users resolve-mentions".to_string(),
+            description: "Resolve user mentions in text".to_string(),
+            usage: "slack-rs users resolve-mentions <text> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--format".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Output format".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Resolve mentions".to_string(),
+                command: "slack-rs users resolve-mentions '@john said hello'".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Resolution failed".to_string(),
+                },
+            ],
+        },
+        // users tz-report
+        CommandDef {
+            name: "users tz-report".to_string(),
+            description: "Show user timezones, overlapping working hours and a suggested meeting slot".to_string(),
+            usage: "slack-rs users tz-report [<user_id>...] [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Include all members of this channel".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--hours".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Local working hours (HH:MM-HH:MM)".to_string(),
+                    default: Some("09:00-17:00".to_string()),
+                },
+                FlagDef {
+                    name: "--duration".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Meeting length in minutes".to_string(),
+                    default: Some("30".to_string()),
+                },
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output report as JSON".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Find a slot for two users".to_string(),
+                    command: "slack-rs users tz-report U123 U456".to_string(),
+                },
+                ExampleDef {
+                    description: "Find an hour-long slot for a channel".to_string(),
+                    command: "slack-rs users tz-report --channel=C123 --duration=60".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Report failed".to_string(),
+                },
+            ],
+        },
+        // users offboard-report
+        CommandDef {
+            name: "users offboard-report".to_string(),
+            description: "Checklist of channels, usergroups, files and scheduled messages to handle before deactivating a user".to_string(),
+            usage: "slack-rs users offboard-report <user_id> [flags]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output report as JSON".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Profile name".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Review what a departing user owns".to_string(),
+                command: "slack-rs users offboard-report U123 --token-type=user".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Report failed".to_string(),
+                },
+            ],
+        },
+        // commands
+        CommandDef {
+            name: "commands".to_string(),
+            description: "List all available commands in machine-readable format".to_string(),
+            usage: "slack-rs commands --json".to_string(),
+            flags: vec![FlagDef {
+                name: "--json".to_string(),
+                flag_type: "boolean".to_string(),
+                required: true,
+                description: "Output in JSON format".to_string(),
+                default: None,
+            }],
+            examples: vec![ExampleDef {
+                description: "List commands".to_string(),
+                command: "slack-rs commands --json".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Command failed".to_string(),
+                },
+            ],
+        },
+        // schema
+        CommandDef {
+            name: "schema".to_string(),
+            description: "Show output schema for a command".to_string(),
+            usage: "slack-rs schema --command <cmd> --output json-schema".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--command".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "Command name".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--output".to_string(),
+                    flag_type: "string".to_string(),
+                    required: true,
+                    description: "Output format (json-schema)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Show schema".to_string(),
+                command: "slack-rs schema --command conv.list --output json-schema".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Schema generation failed".to_string(),
+                },
+            ],
+        },
+        // install-skills
+        CommandDef {
+            name: "install-skills".to_string(),
+            description: "Install agent skill from embedded or local source".to_string(),
+            usage: "slack-rs install-skills [source] [--global]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "source".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Source to install from: 'self' (embedded) or 'local:<path>'".to_string(),
+                    default: Some("self".to_string()),
+                },
+                FlagDef {
+                    name: "--global".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Install to ~/.agents instead of ./.agents".to_string(),
+                    default: Some("false".to_string()),
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Install embedded skill (default)".to_string(),
+                    command: "slack-rs install-skills".to_string(),
+                },
+                ExampleDef {
+                    description: "Install from local path".to_string(),
+                    command: "slack-rs install-skills local:/path/to/skill".to_string(),
+                },
+                ExampleDef {
+                    description: "Install globally to ~/.agents".to_string(),
+                    command: "slack-rs install-skills --global".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success - skill installed".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Failure - installation error".to_string(),
+                },
+            ],
+        },
+        // demo
+        CommandDef {
+            name: "demo".to_string(),
+            description: "Run demonstration".to_string(),
+            usage: "slack-rs demo".to_string(),
+            flags: vec![],
+            examples: vec![ExampleDef {
+                description: "Run demo".to_string(),
+                command: "slack-rs demo".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+            ],
+        },
+        // shell
+        CommandDef {
+            name: "shell".to_string(),
+            description: "Interactive shell with history and tab completion".to_string(),
+            usage: "slack-rs shell [--profile=NAME] [--token-type=bot|user]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--profile".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Initial session profile (change with 'set profile')".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Initial session token type (change with 'set token-type')"
+                        .to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![ExampleDef {
+                description: "Start a shell for the work profile".to_string(),
+                command: "slack-rs shell --profile=work".to_string(),
+            }],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Shell exited normally".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Shell could not start".to_string(),
+                },
+            ],
+        },
+        // auth compare-tokens
+        CommandDef {
+            name: "auth compare-tokens".to_string(),
+            description: "Compare what the bot and user tokens can see".to_string(),
+            usage: "slack-rs auth compare-tokens [profile] [--json]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output in JSON format".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Compare tokens of the default profile".to_string(),
+                    command: "slack-rs auth compare-tokens".to_string(),
+                },
+                ExampleDef {
+                    description: "Machine-readable comparison".to_string(),
+                    command: "slack-rs auth compare-tokens work --json".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Comparison failed".to_string(),
+                },
+            ],
+        },
+        // auth tokens list
+        CommandDef {
+            name: "auth tokens list".to_string(),
+            description: "List token store keys with redacted values, owning profile and usage times".to_string(),
+            usage: "slack-rs auth tokens list [--json]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output in JSON format".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Show what is in the token store".to_string(),
+                    command: "slack-rs auth tokens list".to_string(),
+                },
+                ExampleDef {
+                    description: "Keys no profile uses anymore".to_string(),
+                    command: "slack-rs auth tokens list --json | jq '.[] | select(.orphaned)'".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Token store could not be read".to_string(),
+                },
+            ],
+        },
+        // config token-backend
+        CommandDef {
+            name: "config token-backend".to_string(),
+            description: "Migrate stored tokens to another token store backend".to_string(),
+            usage: "slack-rs config token-backend <file|encrypted-file>".to_string(),
+            flags: vec![],
+            examples: vec![
+                ExampleDef {
+                    description: "Move tokens to the encrypted file store".to_string(),
+                    command: "slack-rs config token-backend encrypted-file".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Migration failed".to_string(),
+                },
+            ],
+        },
+        // events listen
+        CommandDef {
+            name: "events listen".to_string(),
+            description: "Receive events over Socket Mode and print each as a JSON line"
+                .to_string(),
+            usage: "slack-rs events listen [--app-token=XAPP] [--filter=KEY=VALUE]... [--max-events=N] [--no-reconnect] [--heartbeat-file=PATH] [--heartbeat-url=URL] [--health-addr=HOST:PORT] [--queue-size=N] [--overflow=POLICY] [--spill-file=PATH] [--meta-interval=SECS]"
+                .to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--app-token".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "App-level token (default: SLACK_APP_TOKEN)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--filter".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Only print matching events (event_type, envelope_type, channel, user); repeatable"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--max-events".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Exit after printing N events".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--no-reconnect".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Exit when the connection ends instead of reconnecting"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--heartbeat-file".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Touch this file on every event and ping".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--heartbeat-url".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Request this URL on every event and ping".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--health-addr".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Serve /healthz and /metrics on HOST:PORT".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--queue-size".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Lines buffered while stdout is slow".to_string(),
+                    default: Some("1000".to_string()),
+                },
+                FlagDef {
+                    name: "--overflow".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "When the queue is full: block, drop-oldest or spill".to_string(),
+                    default: Some("block".to_string()),
+                },
+                FlagDef {
+                    name: "--spill-file".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "File used by --overflow spill".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--meta-interval".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Minimum seconds between stream meta lines".to_string(),
+                    default: Some("10".to_string()),
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Print message events".to_string(),
+                    command: "slack-rs events listen --filter event_type=message".to_string(),
+                },
+                ExampleDef {
+                    description: "Run under a supervisor with a health endpoint".to_string(),
+                    command: "slack-rs events listen --health-addr 127.0.0.1:9464".to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Listener failed".to_string(),
+                },
+            ],
+        },
+        // kv set
+        CommandDef {
+            name: "kv set".to_string(),
+            description: "Store a JSON value in the channel-backed key-value store".to_string(),
+            usage: "slack-rs kv set <key> <value> [--namespace=NS] [--channel=C] [--if-version=N]"
+                .to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--namespace".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Key namespace".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Store channel (default: SLACK_RS_KV_CHANNEL)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--if-version".to_string(),
+                    flag_type: "integer".to_string(),
+                    required: false,
+                    description: "Only write when the stored version is N (0 = key must not exist)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Store a value".to_string(),
+                    command: "slack-rs kv set last-deploy '{\"sha\":\"abc123\"}' --namespace deploybot"
+                        .to_string(),
+                },
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Write failed or version conflict".to_string(),
+                },
+            ],
+        },
+        // kv get
+        CommandDef {
+            name: "kv get".to_string(),
+            description: "Read a value from the channel-backed key-value store".to_string(),
+            usage: "slack-rs kv get <key> [--namespace=NS] [--channel=C]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--namespace".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Key namespace".to_string(),
+                    default: Some("default".to_string()),
+                },
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Store channel (default: SLACK_RS_KV_CHANNEL)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
                 FlagDef {
                     name: "--token-type".to_string(),
                     flag_type: "string".to_string(),
@@ -425,12 +5880,8 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
             ],
             examples: vec![
                 ExampleDef {
-                    description: "Get thread messages".to_string(),
-                    command: "slack-rs thread get C123456 1234567890.123456".to_string(),
-                },
-                ExampleDef {
-                    description: "Get thread with parent message".to_string(),
-                    command: "slack-rs thread get C123456 1234567890.123456 --inclusive".to_string(),
+                    description: "Read a value".to_string(),
+                    command: "slack-rs kv get last-deploy --namespace deploybot".to_string(),
                 },
             ],
             exit_codes: vec![
@@ -440,49 +5891,51 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Command failed".to_string(),
+                    description: "Read failed".to_string(),
                 },
             ],
         },
-        // msg post
+        // kv list
         CommandDef {
-            name: "msg post".to_string(),
-            description: "Post a message to a channel".to_string(),
-            usage: "slack-rs msg post <channel> <text> [flags]".to_string(),
+            name: "kv list".to_string(),
+            description: "List keys in the channel-backed key-value store".to_string(),
+            usage: "slack-rs kv list [--namespace=NS] [--channel=C]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--thread-ts".to_string(),
+                    name: "--namespace".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Thread timestamp for reply".to_string(),
-                    default: None,
+                    description: "Key namespace".to_string(),
+                    default: Some("default".to_string()),
                 },
                 FlagDef {
-                    name: "--reply-broadcast".to_string(),
-                    flag_type: "boolean".to_string(),
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
                     required: false,
-                    description: "Broadcast reply to channel".to_string(),
+                    description: "Store channel (default: SLACK_RS_KV_CHANNEL)".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--profile".to_string(),
-                    flag_type: "string".to_string(),
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
                 },
                 FlagDef {
-                    name: "--idempotency-key".to_string(),
+                    name: "--token-type".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    description: "Token type (bot or user)".to_string(),
                     default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Post message".to_string(),
-                command: "slack-rs msg post C123 'Hello world'".to_string(),
-            }],
+            examples: vec![
+                ExampleDef {
+                    description: "List keys".to_string(),
+                    command: "slack-rs kv list --namespace deploybot".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -490,35 +5943,30 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Post failed".to_string(),
+                    description: "List failed".to_string(),
                 },
             ],
         },
-        // msg update
+        // ctx set
         CommandDef {
-            name: "msg update".to_string(),
-            description: "Update a message".to_string(),
-            usage: "slack-rs msg update <channel> <ts> <text> [flags]".to_string(),
+            name: "ctx set".to_string(),
+            description: "Save a default channel and thread".to_string(),
+            usage: "slack-rs ctx set [channel <channel>] [thread <ts>]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--profile".to_string(),
-                    flag_type: "string".to_string(),
-                    required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
-                },
-                FlagDef {
-                    name: "--idempotency-key".to_string(),
+                    name: "--token-type".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    description: "Token type (bot or user)".to_string(),
                     default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Update message".to_string(),
-                command: "slack-rs msg update C123 1234567890.123456 'Updated text'".to_string(),
-            }],
+            examples: vec![
+                ExampleDef {
+                    description: "Work in one thread".to_string(),
+                    command: "slack-rs ctx set channel '#ops' thread 1700000000.000100".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -526,35 +5974,30 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Update failed".to_string(),
+                    description: "Invalid context".to_string(),
                 },
             ],
         },
-        // msg delete
+        // ctx show
         CommandDef {
-            name: "msg delete".to_string(),
-            description: "Delete a message".to_string(),
-            usage: "slack-rs msg delete <channel> <ts> [flags]".to_string(),
+            name: "ctx show".to_string(),
+            description: "Show the saved session context".to_string(),
+            usage: "slack-rs ctx show [--json]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--profile".to_string(),
-                    flag_type: "string".to_string(),
-                    required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
-                },
-                FlagDef {
-                    name: "--idempotency-key".to_string(),
-                    flag_type: "string".to_string(),
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    description: "Output in JSON format".to_string(),
                     default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Delete message".to_string(),
-                command: "slack-rs msg delete C123 1234567890.123456".to_string(),
-            }],
+            examples: vec![
+                ExampleDef {
+                    description: "Show the context".to_string(),
+                    command: "slack-rs ctx show".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -562,26 +6005,22 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Delete failed".to_string(),
+                    description: "Context could not be read".to_string(),
                 },
             ],
         },
-        // users info
+        // ctx clear
         CommandDef {
-            name: "users info".to_string(),
-            description: "Get user information".to_string(),
-            usage: "slack-rs users info <user_id> [flags]".to_string(),
-            flags: vec![FlagDef {
-                name: "--profile".to_string(),
-                flag_type: "string".to_string(),
-                required: false,
-                description: "Profile name".to_string(),
-                default: Some("default".to_string()),
-            }],
-            examples: vec![ExampleDef {
-                description: "Get user info".to_string(),
-                command: "slack-rs users info U123456".to_string(),
-            }],
+            name: "ctx clear".to_string(),
+            description: "Remove the saved context or one of its keys".to_string(),
+            usage: "slack-rs ctx clear [channel|thread]".to_string(),
+            flags: vec![],
+            examples: vec![
+                ExampleDef {
+                    description: "Forget the thread".to_string(),
+                    command: "slack-rs ctx clear thread".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -589,35 +6028,45 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Command failed".to_string(),
+                    description: "Context could not be written".to_string(),
                 },
             ],
         },
-        // react add
+        // idempotency list
         CommandDef {
-            name: "react add".to_string(),
-            description: "Add a reaction to a message".to_string(),
-            usage: "slack-rs react add <channel> <ts> <emoji> [flags]".to_string(),
-            flags: vec![
-                FlagDef {
-                    name: "--profile".to_string(),
-                    flag_type: "string".to_string(),
-                    required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+            name: "idempotency list".to_string(),
+            description: "List stored idempotent operations (oldest first)".to_string(),
+            usage: "slack-rs idempotency list".to_string(),
+            flags: vec![],
+            examples: vec![
+                ExampleDef {
+                    description: "List entries".to_string(),
+                    command: "slack-rs idempotency list".to_string(),
                 },
-                FlagDef {
-                    name: "--idempotency-key".to_string(),
-                    flag_type: "string".to_string(),
-                    required: false,
-                    description: "Idempotency key for preventing duplicate operations".to_string(),
-                    default: None,
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Store could not be read".to_string(),
+                },
+            ],
+        },
+        // idempotency show
+        CommandDef {
+            name: "idempotency show".to_string(),
+            description: "Show one stored operation with its response".to_string(),
+            usage: "slack-rs idempotency show <key>".to_string(),
+            flags: vec![],
+            examples: vec![
+                ExampleDef {
+                    description: "Show an entry".to_string(),
+                    command: "slack-rs idempotency show deploy-42".to_string(),
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Add reaction".to_string(),
-                command: "slack-rs react add C123 1234567890.123456 thumbsup".to_string(),
-            }],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -625,35 +6074,46 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Command failed".to_string(),
+                    description: "Entry not found".to_string(),
                 },
             ],
         },
-        // react remove
+        // idempotency clear
         CommandDef {
-            name: "react remove".to_string(),
-            description: "Remove a reaction from a message".to_string(),
-            usage: "slack-rs react remove <channel> <ts> <emoji> [flags]".to_string(),
+            name: "idempotency clear".to_string(),
+            description: "Remove stored operations so they run again".to_string(),
+            usage: "slack-rs idempotency clear [--key=KEY] [--older-than=DURATION] [--yes]"
+                .to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--profile".to_string(),
+                    name: "--key".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Only remove this key".to_string(),
+                    default: None,
                 },
                 FlagDef {
-                    name: "--idempotency-key".to_string(),
+                    name: "--older-than".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Idempotency key for preventing duplicate operations".to_string(),
+                    description: "Only remove entries older than this duration (30m, 12h, 3d)"
+                        .to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Skip the confirmation prompt".to_string(),
                     default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Remove reaction".to_string(),
-                command: "slack-rs react remove C123 1234567890.123456 thumbsup".to_string(),
-            }],
+            examples: vec![
+                ExampleDef {
+                    description: "Post a deploy announcement again".to_string(),
+                    command: "slack-rs idempotency clear --key=deploy-42".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -661,35 +6121,22 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Command failed".to_string(),
+                    description: "Clear failed".to_string(),
                 },
             ],
         },
-        // file upload
+        // idempotency gc
         CommandDef {
-            name: "file upload".to_string(),
-            description: "Upload a file".to_string(),
-            usage: "slack-rs file upload <path> [flags]".to_string(),
-            flags: vec![
-                FlagDef {
-                    name: "--profile".to_string(),
-                    flag_type: "string".to_string(),
-                    required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
-                },
-                FlagDef {
-                    name: "--idempotency-key".to_string(),
-                    flag_type: "string".to_string(),
-                    required: false,
-                    description: "Idempotency key for preventing duplicate operations".to_string(),
-                    default: None,
+            name: "idempotency gc".to_string(),
+            description: "Remove expired stored operations".to_string(),
+            usage: "slack-rs idempotency gc".to_string(),
+            flags: vec![],
+            examples: vec![
+                ExampleDef {
+                    description: "Drop expired entries".to_string(),
+                    command: "slack-rs idempotency gc".to_string(),
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Upload file".to_string(),
-                command: "slack-rs file upload document.pdf".to_string(),
-            }],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -697,36 +6144,44 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Upload failed".to_string(),
+                    description: "Store could not be written".to_string(),
                 },
             ],
         },
-        // file download
+        // connect invite
         CommandDef {
-            name: "file download".to_string(),
-            description: "Download a file from Slack".to_string(),
-            usage: "slack-rs file download [<file_id>] [flags]".to_string(),
+            name: "connect invite".to_string(),
+            description: "Invite an external organization to a channel".to_string(),
+            usage: "slack-rs connect invite <channel> [--emails=a@x.com,...] [--user-ids=U1,...] [--external-limited]"
+                .to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--url".to_string(),
+                    name: "--emails".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Direct download URL (alternative to file_id)".to_string(),
+                    description: "Email addresses to invite (comma-separated)".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--out".to_string(),
+                    name: "--user-ids".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Output path (omit for current directory, '-' for stdout, directory for auto-naming)".to_string(),
+                    description: "User IDs to invite (comma-separated)".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--profile".to_string(),
-                    flag_type: "string".to_string(),
+                    name: "--external-limited".to_string(),
+                    flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Invite as an external limited member".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
                 },
                 FlagDef {
                     name: "--token-type".to_string(),
@@ -738,16 +6193,61 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
             ],
             examples: vec![
                 ExampleDef {
-                    description: "Download by file ID".to_string(),
-                    command: "slack-rs file download F123456".to_string(),
+                    description: "Invite a partner".to_string(),
+                    command: "slack-rs connect invite C123456 --emails=alice@partner.com"
+                        .to_string(),
                 },
-                ExampleDef {
-                    description: "Download to stdout".to_string(),
-                    command: "slack-rs file download F123456 --out -".to_string(),
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Invite failed".to_string(),
+                },
+            ],
+        },
+        // connect invites
+        CommandDef {
+            name: "connect invites".to_string(),
+            description: "List pending incoming Slack Connect invitations".to_string(),
+            usage: "slack-rs connect invites [--team-id=<id>] [--cursor=<cursor>]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--team-id".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Team ID for Enterprise Grid workspaces".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--cursor".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Pagination cursor".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
                 },
+            ],
+            examples: vec![
                 ExampleDef {
-                    description: "Download by URL".to_string(),
-                    command: "slack-rs file download --url https://files.slack.com/...".to_string(),
+                    description: "List invitations".to_string(),
+                    command: "slack-rs connect invites".to_string(),
                 },
             ],
             exit_codes: vec![
@@ -757,63 +6257,44 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Download failed".to_string(),
+                    description: "List failed".to_string(),
                 },
             ],
         },
-        // search
+        // connect accept
         CommandDef {
-            name: "search".to_string(),
-            description: "Search messages".to_string(),
-            usage: "slack-rs search <query> [flags]".to_string(),
+            name: "connect accept".to_string(),
+            description: "Accept a Slack Connect invitation".to_string(),
+            usage: "slack-rs connect accept <invite_id> <channel_name> [--private]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--count".to_string(),
-                    flag_type: "integer".to_string(),
+                    name: "--private".to_string(),
+                    flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Number of results".to_string(),
+                    description: "Create the channel as private".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--page".to_string(),
-                    flag_type: "integer".to_string(),
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Page number".to_string(),
+                    description: "Output raw Slack API response (without envelope)".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--profile".to_string(),
+                    name: "--token-type".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Search messages".to_string(),
-                command: "slack-rs search 'important announcement'".to_string(),
-            }],
-            exit_codes: vec![
-                ExitCodeDef {
-                    code: 0,
-                    description: "Success".to_string(),
-                },
-                ExitCodeDef {
-                    code: 1,
-                    description: "Search failed".to_string(),
+            examples: vec![
+                ExampleDef {
+                    description: "Accept an invitation".to_string(),
+                    command: "slack-rs connect accept I012345 partner-shared".to_string(),
                 },
             ],
-        },
-        // auth rename
-        CommandDef {
-            name: "auth rename".to_string(),
-            description: "Rename a profile".to_string(),
-            usage: "slack-rs auth rename <old_name> <new_name>".to_string(),
-            flags: vec![],
-            examples: vec![ExampleDef {
-                description: "Rename profile".to_string(),
-                command: "slack-rs auth rename work personal".to_string(),
-            }],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -821,63 +6302,44 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Rename failed".to_string(),
+                    description: "Accept failed".to_string(),
                 },
             ],
         },
-        // auth export
+        // connect approve
         CommandDef {
-            name: "auth export".to_string(),
-            description: "Export profiles to encrypted file".to_string(),
-            usage: "slack-rs auth export [flags]".to_string(),
+            name: "connect approve".to_string(),
+            description: "Approve a Slack Connect invitation".to_string(),
+            usage: "slack-rs connect approve <invite_id> [--target-team=<id>]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--profile".to_string(),
+                    name: "--target-team".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Export specific profile".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Team ID of the external organization".to_string(),
+                    default: None,
                 },
                 FlagDef {
-                    name: "--all".to_string(),
+                    name: "--raw".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Export all profiles".to_string(),
-                    default: None,
-                },
-                FlagDef {
-                    name: "--out".to_string(),
-                    flag_type: "string".to_string(),
-                    required: true,
-                    description: "Output file path".to_string(),
+                    description: "Output raw Slack API response (without envelope)".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--passphrase-env".to_string(),
+                    name: "--token-type".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Environment variable containing passphrase".to_string(),
-                    default: None,
-                },
-                FlagDef {
-                    name: "--passphrase-prompt".to_string(),
-                    flag_type: "boolean".to_string(),
-                    required: false,
-                    description: "Prompt for passphrase".to_string(),
+                    description: "Token type (bot or user)".to_string(),
                     default: None,
                 },
-                FlagDef {
-                    name: "--yes".to_string(),
-                    flag_type: "boolean".to_string(),
-                    required: false,
-                    description: "Confirm dangerous operation".to_string(),
-                    default: None,
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Approve an invitation".to_string(),
+                    command: "slack-rs connect approve I012345".to_string(),
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Export all profiles".to_string(),
-                command: "slack-rs auth export --all --out profiles.enc --yes".to_string(),
-            }],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -885,78 +6347,49 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Export failed".to_string(),
+                    description: "Approve failed".to_string(),
                 },
             ],
         },
-        // auth import
+        // connect decline
         CommandDef {
-            name: "auth import".to_string(),
-            description: "Import profiles from encrypted file".to_string(),
-            usage: "slack-rs auth import [flags]".to_string(),
+            name: "connect decline".to_string(),
+            description: "Decline a Slack Connect invitation".to_string(),
+            usage: "slack-rs connect decline <invite_id> [--target-team=<id>] [--yes]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--in".to_string(),
-                    flag_type: "string".to_string(),
-                    required: true,
-                    description: "Input file path".to_string(),
-                    default: None,
-                },
-                FlagDef {
-                    name: "--passphrase-env".to_string(),
+                    name: "--target-team".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Environment variable containing passphrase".to_string(),
-                    default: None,
-                },
-                FlagDef {
-                    name: "--passphrase-prompt".to_string(),
-                    flag_type: "boolean".to_string(),
-                    required: false,
-                    description: "Prompt for passphrase".to_string(),
+                    description: "Team ID of the external organization".to_string(),
                     default: None,
                 },
                 FlagDef {
                     name: "--yes".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Automatically accept conflicts".to_string(),
-                    default: None,
-                },
-                FlagDef {
-                    name: "--force".to_string(),
-                    flag_type: "boolean".to_string(),
-                    required: false,
-                    description: "Overwrite existing profiles".to_string(),
+                    description: "Skip the confirmation prompt".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--dry-run".to_string(),
+                    name: "--raw".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Show what would be imported without making changes".to_string(),
+                    description: "Output raw Slack API response (without envelope)".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--json".to_string(),
-                    flag_type: "boolean".to_string(),
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
                     required: false,
-                    description: "Output results in JSON format".to_string(),
+                    description: "Token type (bot or user)".to_string(),
                     default: None,
                 },
             ],
             examples: vec![
                 ExampleDef {
-                    description: "Import profiles".to_string(),
-                    command: "slack-rs auth import --in profiles.enc".to_string(),
-                },
-                ExampleDef {
-                    description: "Preview import without making changes".to_string(),
-                    command: "slack-rs auth import --in profiles.enc --dry-run".to_string(),
-                },
-                ExampleDef {
-                    description: "Preview import with JSON output".to_string(),
-                    command: "slack-rs auth import --in profiles.enc --dry-run --json".to_string(),
+                    description: "Decline an invitation".to_string(),
+                    command: "slack-rs connect decline I012345 --yes".to_string(),
                 },
             ],
             exit_codes: vec![
@@ -966,139 +6399,80 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Import failed".to_string(),
+                    description: "Decline failed".to_string(),
                 },
             ],
         },
-        // config oauth set
+        // connect requests list
         CommandDef {
-            name: "config oauth set".to_string(),
-            description: "Set OAuth configuration for a profile".to_string(),
-            usage: "slack-rs config oauth set <profile> --client-id <id> --redirect-uri <uri> --scopes <scopes> [flags]".to_string(),
+            name: "connect requests list".to_string(),
+            description: "List members' requests to send external invitations".to_string(),
+            usage: "slack-rs connect requests list [--include-approved] [--include-denied] [--include-expired] [--user=<id>] [--limit=<n>] [--cursor=<cursor>]"
+                .to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--client-id".to_string(),
-                    flag_type: "string".to_string(),
-                    required: true,
-                    description: "OAuth client ID".to_string(),
+                    name: "--include-approved".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include approved requests".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--redirect-uri".to_string(),
-                    flag_type: "string".to_string(),
-                    required: true,
-                    description: "OAuth redirect URI".to_string(),
+                    name: "--include-denied".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include denied requests".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--scopes".to_string(),
-                    flag_type: "string".to_string(),
-                    required: true,
-                    description: "Comma-separated list of scopes or 'all'".to_string(),
+                    name: "--include-expired".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Include expired requests".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--client-secret-env".to_string(),
+                    name: "--user".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Read secret from environment variable".to_string(),
+                    description: "Only requests from this user".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--client-secret-file".to_string(),
-                    flag_type: "string".to_string(),
+                    name: "--limit".to_string(),
+                    flag_type: "integer".to_string(),
                     required: false,
-                    description: "Read secret from file".to_string(),
+                    description: "Maximum number of requests".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--client-secret".to_string(),
+                    name: "--cursor".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Direct secret value (requires --yes, unsafe)".to_string(),
+                    description: "Pagination cursor".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--yes".to_string(),
+                    name: "--raw".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Confirm dangerous operation".to_string(),
+                    description: "Output raw Slack API response (without envelope)".to_string(),
                     default: None,
                 },
-            ],
-            examples: vec![ExampleDef {
-                description: "Set OAuth config".to_string(),
-                command: "slack-rs config oauth set work --client-id 123.456 --redirect-uri http://127.0.0.1:8765/callback --scopes all".to_string(),
-            }],
-            exit_codes: vec![
-                ExitCodeDef {
-                    code: 0,
-                    description: "Success".to_string(),
-                },
-                ExitCodeDef {
-                    code: 1,
-                    description: "Config set failed".to_string(),
-                },
-            ],
-        },
-        // config oauth show
-        CommandDef {
-            name: "config oauth show".to_string(),
-            description: "Show OAuth configuration for a profile".to_string(),
-            usage: "slack-rs config oauth show <profile>".to_string(),
-            flags: vec![],
-            examples: vec![ExampleDef {
-                description: "Show OAuth config".to_string(),
-                command: "slack-rs config oauth show work".to_string(),
-            }],
-            exit_codes: vec![
-                ExitCodeDef {
-                    code: 0,
-                    description: "Success".to_string(),
-                },
-                ExitCodeDef {
-                    code: 1,
-                    description: "Config show failed".to_string(),
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
                 },
             ],
-        },
-        // config oauth delete
-        CommandDef {
-            name: "config oauth delete".to_string(),
-            description: "Delete OAuth configuration for a profile".to_string(),
-            usage: "slack-rs config oauth delete <profile>".to_string(),
-            flags: vec![],
-            examples: vec![ExampleDef {
-                description: "Delete OAuth config".to_string(),
-                command: "slack-rs config oauth delete work".to_string(),
-            }],
-            exit_codes: vec![
-                ExitCodeDef {
-                    code: 0,
-                    description: "Success".to_string(),
-                },
-                ExitCodeDef {
-                    code: 1,
-                    description: "Config delete failed".to_string(),
+            examples: vec![
+                ExampleDef {
+                    description: "List pending requests".to_string(),
+                    command: "slack-rs connect requests list".to_string(),
                 },
-            ],
-        },
-        // config set
-        CommandDef {
-            name: "config set".to_string(),
-            description: "Set default token type for a profile".to_string(),
-            usage: "slack-rs config set <profile> --token-type <type>".to_string(),
-            flags: vec![FlagDef {
-                name: "--token-type".to_string(),
-                flag_type: "string".to_string(),
-                required: true,
-                description: "Default token type (bot or user)".to_string(),
-                default: None,
-            }],
-            examples: vec![ExampleDef {
-                description: "Set token type".to_string(),
-                command: "slack-rs config set work --token-type bot".to_string(),
-            }],
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -1106,26 +6480,59 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Config set failed".to_string(),
+                    description: "List failed".to_string(),
                 },
             ],
         },
-        // conv select
+        // connect requests approve
         CommandDef {
-            name: "conv select".to_string(),
-            description: "Interactively select a conversation".to_string(),
-            usage: "slack-rs conv select [flags]".to_string(),
-            flags: vec![FlagDef {
-                name: "--profile".to_string(),
-                flag_type: "string".to_string(),
-                required: false,
-                description: "Profile name".to_string(),
-                default: Some("default".to_string()),
-            }],
-            examples: vec![ExampleDef {
-                description: "Select conversation".to_string(),
-                command: "slack-rs conv select".to_string(),
-            }],
+            name: "connect requests approve".to_string(),
+            description: "Approve a member's request to send an external invitation".to_string(),
+            usage: "slack-rs connect requests approve <invite_id> [--channel=<id>] [--external-limited] [--message=<text>]"
+                .to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--channel".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Channel to share".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--external-limited".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Invite as an external limited member".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--message".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Message sent to the requesting user".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Approve a request".to_string(),
+                    command: "slack-rs connect requests approve I012345".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -1133,35 +6540,52 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Selection failed".to_string(),
+                    description: "Approve failed".to_string(),
                 },
             ],
         },
-        // users cache-update
+        // connect requests deny
         CommandDef {
-            name: "users cache-update".to_string(),
-            description: "Update user cache for mention resolution".to_string(),
-            usage: "slack-rs users cache-update [flags]".to_string(),
+            name: "connect requests deny".to_string(),
+            description: "Deny a member's request to send an external invitation".to_string(),
+            usage: "slack-rs connect requests deny <invite_id> [--message=<text>] [--yes]"
+                .to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--profile".to_string(),
+                    name: "--message".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Message sent to the requesting user".to_string(),
+                    default: None,
                 },
                 FlagDef {
-                    name: "--force".to_string(),
+                    name: "--yes".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Force cache update".to_string(),
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
+                    flag_type: "string".to_string(),
+                    required: false,
+                    description: "Token type (bot or user)".to_string(),
                     default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Update user cache".to_string(),
-                command: "slack-rs users cache-update".to_string(),
-            }],
+            examples: vec![
+                ExampleDef {
+                    description: "Deny a request".to_string(),
+                    command: "slack-rs connect requests deny I012345 --yes".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -1169,35 +6593,46 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Cache update failed".to_string(),
+                    description: "Deny failed".to_string(),
                 },
             ],
         },
-        // users resolve-mentions
+        // connect permissions
         CommandDef {
-            name: "users resolve-mentions".to_string(),
-            description: "Resolve user mentions in text".to_string(),
-            usage: "slack-rs users resolve-mentions <text> [flags]".to_string(),
+            name: "connect permissions".to_string(),
+            description: "Change an external organization's access to a shared channel".to_string(),
+            usage: "slack-rs connect permissions <channel> <target_team> <upgrade|downgrade> [--yes]"
+                .to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--profile".to_string(),
-                    flag_type: "string".to_string(),
+                    name: "--yes".to_string(),
+                    flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Profile name".to_string(),
-                    default: Some("default".to_string()),
+                    description: "Skip the confirmation prompt".to_string(),
+                    default: None,
                 },
                 FlagDef {
-                    name: "--format".to_string(),
+                    name: "--raw".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output raw Slack API response (without envelope)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--token-type".to_string(),
                     flag_type: "string".to_string(),
                     required: false,
-                    description: "Output format".to_string(),
+                    description: "Token type (bot or user)".to_string(),
                     default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Resolve mentions".to_string(),
-                command: "slack-rs users resolve-mentions '@john said hello'".to_string(),
-            }],
+            examples: vec![
+                ExampleDef {
+                    description: "Limit a partner to posting only".to_string(),
+                    command: "slack-rs connect permissions C123456 T0PARTNER downgrade --yes"
+                        .to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -1205,26 +6640,34 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Resolution failed".to_string(),
+                    description: "Update failed".to_string(),
                 },
             ],
         },
-        // commands
+        // doctor
         CommandDef {
-            name: "commands".to_string(),
-            description: "List all available commands in machine-readable format".to_string(),
-            usage: "slack-rs commands --json".to_string(),
-            flags: vec![FlagDef {
-                name: "--json".to_string(),
-                flag_type: "boolean".to_string(),
-                required: true,
-                description: "Output in JSON format".to_string(),
-                default: None,
-            }],
-            examples: vec![ExampleDef {
-                description: "List commands".to_string(),
-                command: "slack-rs commands --json".to_string(),
-            }],
+            name: "doctor".to_string(),
+            description: "Show diagnostic information about the CLI environment".to_string(),
+            usage: "slack-rs doctor [--profile=<name>] [--json]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output in JSON format".to_string(),
+                    default: None,
+                },
+            ],
+            examples: vec![
+                ExampleDef {
+                    description: "Diagnose the default profile".to_string(),
+                    command: "slack-rs doctor".to_string(),
+                },
+                ExampleDef {
+                    description: "Machine-readable diagnostics".to_string(),
+                    command: "slack-rs doctor --profile=work --json".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
@@ -1232,110 +6675,191 @@ pub fn get_command_definitions() -> Vec<CommandDef> {
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Command failed".to_string(),
+                    description: "Diagnostics failed".to_string(),
                 },
             ],
         },
-        // schema
+        // doctor network
         CommandDef {
-            name: "schema".to_string(),
-            description: "Show output schema for a command".to_string(),
-            usage: "slack-rs schema --command <cmd> --output json-schema".to_string(),
+            name: "doctor network".to_string(),
+            description: "Probe DNS, TCP, TLS and API latency, proxy settings and Socket Mode connectivity".to_string(),
+            usage: "slack-rs doctor network [--base-url=<url>] [--app-token=<xapp>] [--json]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "--command".to_string(),
+                    name: "--base-url".to_string(),
                     flag_type: "string".to_string(),
-                    required: true,
-                    description: "Command name".to_string(),
+                    required: false,
+                    description: "Also probe this API base URL".to_string(),
                     default: None,
                 },
                 FlagDef {
-                    name: "--output".to_string(),
+                    name: "--app-token".to_string(),
                     flag_type: "string".to_string(),
-                    required: true,
-                    description: "Output format (json-schema)".to_string(),
+                    required: false,
+                    description: "App-level token for a full Socket Mode check (default: SLACK_APP_TOKEN)".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output in JSON format".to_string(),
                     default: None,
                 },
             ],
-            examples: vec![ExampleDef {
-                description: "Show schema".to_string(),
-                command: "slack-rs schema --command conv.list --output json-schema".to_string(),
-            }],
+            examples: vec![
+                ExampleDef {
+                    description: "Check connectivity".to_string(),
+                    command: "slack-rs doctor network".to_string(),
+                },
+                ExampleDef {
+                    description: "Report to attach to a support ticket".to_string(),
+                    command: "slack-rs doctor network --json > network-report.json".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
-                    description: "Success".to_string(),
+                    description: "All checks passed".to_string(),
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Schema generation failed".to_string(),
+                    description: "A check failed".to_string(),
                 },
             ],
         },
-        // install-skills
+        // usage
         CommandDef {
-            name: "install-skills".to_string(),
-            description: "Install agent skill from embedded or local source".to_string(),
-            usage: "slack-rs install-skills [source] [--global]".to_string(),
+            name: "usage".to_string(),
+            description: "Show locally recorded API calls per day and per method".to_string(),
+            usage: "slack-rs usage [--days=<n>] [--json]".to_string(),
             flags: vec![
                 FlagDef {
-                    name: "source".to_string(),
-                    flag_type: "string".to_string(),
+                    name: "--days".to_string(),
+                    flag_type: "integer".to_string(),
                     required: false,
-                    description: "Source to install from: 'self' (embedded) or 'local:<path>'".to_string(),
-                    default: Some("self".to_string()),
+                    description: "Number of days to show".to_string(),
+                    default: Some("7".to_string()),
                 },
                 FlagDef {
-                    name: "--global".to_string(),
+                    name: "--json".to_string(),
                     flag_type: "boolean".to_string(),
                     required: false,
-                    description: "Install to ~/.agents instead of ./.agents".to_string(),
-                    default: Some("false".to_string()),
+                    description: "Output in JSON format".to_string(),
+                    default: None,
                 },
             ],
             examples: vec![
                 ExampleDef {
-                    description: "Install embedded skill (default)".to_string(),
-                    command: "slack-rs install-skills".to_string(),
+                    description: "Last 30 days as JSON".to_string(),
+                    command: "slack-rs usage --days=30 --json".to_string(),
                 },
-                ExampleDef {
-                    description: "Install from local path".to_string(),
-                    command: "slack-rs install-skills local:/path/to/skill".to_string(),
+            ],
+            exit_codes: vec![
+                ExitCodeDef {
+                    code: 0,
+                    description: "Success".to_string(),
+                },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Invalid options or usage file".to_string(),
+                },
+            ],
+        },
+        // maintenance run
+        CommandDef {
+            name: "maintenance run".to_string(),
+            description: "Prune expired local state in the config directory".to_string(),
+            usage: "slack-rs maintenance run [--dry-run] [--json]".to_string(),
+            flags: vec![
+                FlagDef {
+                    name: "--dry-run".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Report what would be removed without writing".to_string(),
+                    default: None,
+                },
+                FlagDef {
+                    name: "--json".to_string(),
+                    flag_type: "boolean".to_string(),
+                    required: false,
+                    description: "Output in JSON format".to_string(),
+                    default: None,
                 },
+            ],
+            examples: vec![
                 ExampleDef {
-                    description: "Install globally to ~/.agents".to_string(),
-                    command: "slack-rs install-skills --global".to_string(),
+                    description: "Preview the cleanup".to_string(),
+                    command: "slack-rs maintenance run --dry-run".to_string(),
                 },
             ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
-                    description: "Success - skill installed".to_string(),
+                    description: "Success".to_string(),
                 },
                 ExitCodeDef {
                     code: 1,
-                    description: "Failure - installation error".to_string(),
+                    description: "A store could not be cleaned".to_string(),
                 },
             ],
         },
-        // demo
+        // completions
         CommandDef {
-            name: "demo".to_string(),
-            description: "Run demonstration".to_string(),
-            usage: "slack-rs demo".to_string(),
-            flags: vec![],
-            examples: vec![ExampleDef {
-                description: "Run demo".to_string(),
-                command: "slack-rs demo".to_string(),
+            name: "completions".to_string(),
+            description: "Print a shell completion script".to_string(),
+            usage: "slack-rs completions <bash|zsh|fish|powershell>".to_string(),
+            flags: vec![FlagDef {
+                name: "--list".to_string(),
+                flag_type: "string".to_string(),
+                required: false,
+                description: "Print dynamic candidates used by the scripts (profiles, channels)"
+                    .to_string(),
+                default: None,
             }],
+            examples: vec![
+                ExampleDef {
+                    description: "Enable completion in bash".to_string(),
+                    command: "source <(slack-rs completions bash)".to_string(),
+                },
+                ExampleDef {
+                    description: "Enable completion in fish".to_string(),
+                    command: "slack-rs completions fish | source".to_string(),
+                },
+            ],
             exit_codes: vec![
                 ExitCodeDef {
                     code: 0,
                     description: "Success".to_string(),
                 },
+                ExitCodeDef {
+                    code: 1,
+                    description: "Unknown shell".to_string(),
+                },
             ],
         },
-    ]
+    ];
+    for cmd in &mut commands {
+        add_failure_exit_codes(&mut cmd.exit_codes);
+    }
+    commands
+}
+
+/// Append the shared failure exit codes to a command that can fail
+///
+/// Codes the command already documents keep their own description.
+fn add_failure_exit_codes(exit_codes: &mut Vec<ExitCodeDef>) {
+    if !exit_codes.iter().any(|c| c.code == 1) {
+        return;
+    }
+    for (code, description) in super::FAILURE_EXIT_CODES {
+        if !exit_codes.iter().any(|c| c.code == code) {
+            exit_codes.push(ExitCodeDef {
+                code,
+                description: description.to_string(),
+            });
+        }
+    }
 }
 
 /// Normalize command name (converts "conv.list" to "conv list", etc.)
@@ -1489,6 +7013,13 @@ mod tests {
         assert!(commands.iter().any(|c| c.name == "conv list"));
     }
 
+    #[test]
+    fn test_failure_exit_codes_documented() {
+        let cmd = get_command_definition("msg post").unwrap();
+        let codes: Vec<i32> = cmd.exit_codes.iter().map(|c| c.code).collect();
+        assert_eq!(codes, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
     #[test]
     fn test_get_command_definition() {
         let cmd = get_command_definition("conv list");
@@ -1,26 +1,35 @@
 //! CLI command routing and handlers
 
+mod command_tree;
+mod completions;
 mod context;
 mod handlers;
 mod help;
 pub mod introspection;
+mod shell;
 
+pub use command_tree::{build_command, parse_args, validate_args, GLOBAL_FLAGS};
+pub use completions::{generate_completions, run_completions, CompletionShell};
 pub use context::CliContext;
 pub use handlers::{
-    handle_export_command, handle_import_command, run_api_call, run_auth_login, run_install_skill,
+    exit_code_for_error, handle_export_command, handle_import_command, run_api_call,
+    run_auth_compare_tokens, run_auth_login, run_auth_set_token, run_auth_test,
+    run_auth_tokens_list, run_install_skill, run_whoami, FAILURE_EXIT_CODES,
 };
 pub use introspection::{
     generate_commands_list, generate_help, generate_schema, CommandDef, CommandsListResponse,
     HelpResponse, SchemaResponse,
 };
+pub use shell::{exit, run_shell, CommandRunner};
 
 use crate::api::{ApiClient, CommandResponse};
 use crate::commands;
 use crate::commands::ConversationSelector;
 use crate::debug;
+use crate::i18n;
 use crate::profile::{
     create_token_store, default_config_path, load_config, make_token_key, resolve_profile_full,
-    TokenStore, TokenType,
+    Profile, TokenStore, TokenType,
 };
 use serde_json::Value;
 
@@ -64,7 +73,7 @@ pub fn resolve_token_for_wrapper(
     if !explicit_request {
         if let Some(fallback_key) = fallback_token_key {
             if let Ok(token) = token_store.get(fallback_key) {
-                eprintln!("Warning: Primary token not found, falling back to alternative token");
+                eprintln!("{}", i18n::t("warn.token_fallback"));
                 return Ok(token);
             }
         }
@@ -72,13 +81,9 @@ pub fn resolve_token_for_wrapper(
 
     // Priority 4: Error
     if explicit_request {
-        Err(
-            "No token found for explicitly requested token type. Set SLACK_TOKEN environment variable or run 'slack login' to obtain a token.".to_string()
-        )
+        Err(i18n::t("error.no_token_explicit").to_string())
     } else {
-        Err(
-            "No token found. Set SLACK_TOKEN environment variable or run 'slack login' to obtain a token.".to_string()
-        )
+        Err(i18n::t("error.no_token").to_string())
     }
 }
 
@@ -93,13 +98,26 @@ pub fn resolve_token_for_wrapper(
 /// 2. CLI flag token_type parameter (if provided)
 /// 3. Profile's default_token_type (if set)
 /// 4. Try user token first, fall back to bot token
+///
+/// Tokens issued with token rotation are refreshed first when they are about
+/// to expire.
 pub async fn get_api_client_with_token_type(
     profile_name: Option<String>,
     token_type: Option<TokenType>,
 ) -> Result<ApiClient, String> {
+    resolve_token_with_type(profile_name, token_type)
+        .await
+        .map(ApiClient::with_token)
+}
+
+/// Resolve the access token used by [`get_api_client_with_token_type`]
+pub async fn resolve_token_with_type(
+    profile_name: Option<String>,
+    token_type: Option<TokenType>,
+) -> Result<String, String> {
     // Check for SLACK_TOKEN environment variable first
     if let Ok(env_token) = std::env::var("SLACK_TOKEN") {
-        return Ok(ApiClient::with_token(env_token));
+        return Ok(env_token);
     }
 
     let profile_name = profile_name.unwrap_or_else(|| "default".to_string());
@@ -118,34 +136,85 @@ pub async fn get_api_client_with_token_type(
     let bot_token_key = make_token_key(&profile.team_id, &profile.user_id);
     let user_token_key = format!("{}:{}:user", profile.team_id, profile.user_id);
 
-    let token = match resolved_token_type {
+    let (token_key, token) = match resolved_token_type {
         Some(TokenType::Bot) => {
             // Explicitly requested bot token
-            token_store
+            let token = token_store
                 .get(&bot_token_key)
-                .map_err(|e| format!("Failed to get bot token: {}", e))?
+                .map_err(|e| format!("Failed to get bot token: {}", e))?;
+            (bot_token_key, token)
         }
         Some(TokenType::User) => {
             // Explicitly requested user token
-            token_store
+            let token = token_store
                 .get(&user_token_key)
-                .map_err(|e| format!("Failed to get user token: {}", e))?
+                .map_err(|e| format!("Failed to get user token: {}", e))?;
+            (user_token_key, token)
+        }
+        Some(TokenType::App) => {
+            // App-level tokens are never a fallback; only used when requested
+            let app_token_key = TokenType::App.token_key(&profile.team_id, &profile.user_id);
+            let token = token_store
+                .get(&app_token_key)
+                .map_err(|e| format!("Failed to get app token: {}", e))?;
+            (app_token_key, token)
         }
         None => {
             // No explicit preference, try user token first (for APIs that require user scope)
             match token_store.get(&user_token_key) {
-                Ok(user_token) => user_token,
+                Ok(user_token) => (user_token_key, user_token),
                 Err(_) => {
                     // Fall back to bot token
-                    token_store
+                    let token = token_store
                         .get(&bot_token_key)
-                        .map_err(|e| format!("Failed to get token: {}", e))?
+                        .map_err(|e| format!("Failed to get token: {}", e))?;
+                    (bot_token_key, token)
                 }
             }
         }
     };
 
-    Ok(ApiClient::with_token(token))
+    // Rotated tokens expire after 12 hours; refresh before the call if needed
+    refresh_stored_token(
+        &*token_store,
+        &profile_name,
+        profile.client_id.as_deref(),
+        &token_key,
+        token,
+        None,
+    )
+    .await
+}
+
+/// Refresh the rotated token stored under `token_key` when it is about to expire
+///
+/// Uses the profile's OAuth client ID and the client secret saved in the
+/// token store. Tokens without rotation data are returned unchanged.
+/// `base_url` overrides the Slack API URL (for testing).
+pub(crate) async fn refresh_stored_token(
+    token_store: &dyn crate::profile::TokenStore,
+    profile_name: &str,
+    client_id: Option<&str>,
+    token_key: &str,
+    token: String,
+    base_url: Option<&str>,
+) -> Result<String, String> {
+    let client_secret = crate::profile::get_oauth_client_secret(token_store, profile_name).ok();
+    let client_credentials = client_id.zip(client_secret.as_deref());
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    crate::oauth::refresh_if_expired(
+        token_store,
+        token_key,
+        token,
+        client_credentials,
+        now,
+        base_url,
+    )
+    .await
+    .map_err(|e| format!("Failed to refresh token: {}", e))
 }
 
 /// Get API client for a profile (legacy function, maintains backward compatibility)
@@ -159,32 +228,276 @@ pub fn has_flag(args: &[String], flag: &str) -> bool {
     args.iter().any(|arg| arg == flag)
 }
 
-/// Determine if output should be raw based on SLACKRS_OUTPUT environment variable and --raw flag
-///
-/// # Arguments
-/// * `args` - Command line arguments
-///
-/// # Returns
-/// * `true` if output should be raw (without envelope)
-/// * `false` if output should include envelope
+/// Where an effective output setting came from (reported by `--explain`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingSource {
+    /// Command-line flag
+    Flag(&'static str),
+    /// Environment variable
+    Env(&'static str),
+    /// `output` section of the named profile
+    Profile(String),
+    /// Global `output` section of the config file
+    Global,
+    /// Built-in default
+    Default,
+}
+
+impl SettingSource {
+    /// True for sources given on this invocation (flag or environment)
+    pub fn is_explicit(&self) -> bool {
+        matches!(self, SettingSource::Flag(_) | SettingSource::Env(_))
+    }
+}
+
+impl std::fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingSource::Flag(flag) => write!(f, "flag {}", flag),
+            SettingSource::Env(var) => write!(f, "env {}", var),
+            SettingSource::Profile(name) => write!(f, "profile '{}' config", name),
+            SettingSource::Global => write!(f, "global config"),
+            SettingSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// Effective output preferences for a command
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputPreferences {
+    /// Output raw Slack responses (no envelope)
+    pub raw: bool,
+    pub raw_source: SettingSource,
+    /// Output format for list-style commands (`None` = command default)
+    pub format: Option<String>,
+    pub format_source: SettingSource,
+}
+
+/// Resolve output preferences from flags, environment and config
 ///
 /// # Priority
-/// 1. --raw flag (highest priority)
-/// 2. SLACKRS_OUTPUT environment variable ("raw" or "envelope")
-/// 3. Default to envelope (false)
+/// 1. --raw / --format flags
+/// 2. SLACKRS_OUTPUT environment variable ("raw" or "envelope"; envelope only)
+/// 3. `output.envelope` / `output.format` of the selected profile
+/// 4. Global `output.envelope` / `output.format`
+/// 5. Default: envelope, command's default format
+pub fn resolve_output_preferences(args: &[String]) -> OutputPreferences {
+    let config = default_config_path()
+        .ok()
+        .and_then(|path| load_config(&path).ok());
+    resolve_output_preferences_with(
+        args,
+        std::env::var("SLACKRS_OUTPUT").ok(),
+        config.as_ref(),
+        &resolve_profile_name(args),
+    )
+}
+
+fn resolve_output_preferences_with(
+    args: &[String],
+    env_output: Option<String>,
+    config: Option<&crate::profile::ProfilesConfig>,
+    profile_name: &str,
+) -> OutputPreferences {
+    let profile_output = config
+        .and_then(|c| c.get(profile_name))
+        .and_then(|p| p.output.as_ref());
+    let global_output = config.and_then(|c| c.output.as_ref());
+
+    let (raw, raw_source) = if has_flag(args, "--raw") {
+        (true, SettingSource::Flag("--raw"))
+    } else if let Some(mode) = env_output {
+        (
+            mode.trim().to_lowercase() == "raw",
+            SettingSource::Env("SLACKRS_OUTPUT"),
+        )
+    } else if let Some(envelope) = profile_output.and_then(|o| o.envelope) {
+        (!envelope, SettingSource::Profile(profile_name.to_string()))
+    } else if let Some(envelope) = global_output.and_then(|o| o.envelope) {
+        (!envelope, SettingSource::Global)
+    } else {
+        (false, SettingSource::Default)
+    };
+
+    let (format, format_source) = if let Some(format) = get_option(args, "--format=") {
+        (Some(format), SettingSource::Flag("--format"))
+    } else if let Some(format) = profile_output.and_then(|o| o.format.clone()) {
+        (
+            Some(format),
+            SettingSource::Profile(profile_name.to_string()),
+        )
+    } else if let Some(format) = global_output.and_then(|o| o.format.clone()) {
+        (Some(format), SettingSource::Global)
+    } else {
+        (None, SettingSource::Default)
+    };
+
+    OutputPreferences {
+        raw,
+        raw_source,
+        format,
+        format_source,
+    }
+}
+
+/// Determine if output should be raw (without envelope)
+///
+/// See [`resolve_output_preferences`] for the priority order.
 pub fn should_output_raw(args: &[String]) -> bool {
-    // Priority 1: --raw flag always wins
-    if has_flag(args, "--raw") {
-        return true;
+    resolve_output_preferences(args).raw
+}
+
+/// Describe the effective output settings and where they came from
+pub fn explain_output_preferences(args: &[String]) -> String {
+    let prefs = resolve_output_preferences(args);
+    format!(
+        "Effective output settings (profile '{}'):\n  output.envelope = {:<8} ({})\n  output.format   = {:<8} ({})",
+        resolve_profile_name(args),
+        !prefs.raw,
+        prefs.raw_source,
+        prefs.format.as_deref().unwrap_or("json"),
+        prefs.format_source
+    )
+}
+
+/// Effective display preferences for a command
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DisplayPreferences {
+    /// How user names are rendered
+    pub user_name: commands::MentionFormat,
+    /// Clock style for human-readable times
+    pub time_format: commands::output::ClockFormat,
+    /// Locale such as `ja-JP`
+    pub locale: Option<String>,
+    /// Request `include_locale` from APIs that support it
+    pub include_locale: bool,
+}
+
+/// Resolve display preferences (`output.user_name`, `output.time_format`,
+/// `output.locale`, `output.include_locale`) from config
+///
+/// Each key of the selected profile overrides the global one; values that
+/// cannot be parsed (hand-edited config) fall back to the default.
+pub fn resolve_display_preferences(args: &[String]) -> DisplayPreferences {
+    let config = default_config_path()
+        .ok()
+        .and_then(|path| load_config(&path).ok());
+    resolve_display_preferences_with(config.as_ref(), &resolve_profile_name(args))
+}
+
+fn resolve_display_preferences_with(
+    config: Option<&crate::profile::ProfilesConfig>,
+    profile_name: &str,
+) -> DisplayPreferences {
+    let profile_output = config
+        .and_then(|c| c.get(profile_name))
+        .and_then(|p| p.output.clone())
+        .unwrap_or_default();
+    let global_output = config.and_then(|c| c.output.clone()).unwrap_or_default();
+    let pick = |get: fn(&crate::profile::OutputSettings) -> Option<String>| {
+        get(&profile_output).or_else(|| get(&global_output))
+    };
+
+    DisplayPreferences {
+        user_name: pick(|o| o.user_name.clone())
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default(),
+        time_format: pick(|o| o.time_format.clone())
+            .and_then(|v| commands::output::ClockFormat::parse(&v).ok())
+            .unwrap_or_default(),
+        locale: pick(|o| o.locale.clone()),
+        include_locale: profile_output
+            .include_locale
+            .or(global_output.include_locale)
+            .unwrap_or(false),
+    }
+}
+
+/// Expanded `output.post_footer` for a posting command
+///
+/// The profile template overrides the global one. Returns `None` when no
+/// footer is configured or `--no-footer` is given.
+pub fn resolve_post_footer(args: &[String]) -> Option<String> {
+    if has_flag(args, "--no-footer") {
+        return None;
     }
+    let config = default_config_path()
+        .ok()
+        .and_then(|path| load_config(&path).ok());
+    let profile_name = resolve_profile_name(args);
+    resolve_post_footer_with(config.as_ref(), &profile_name).map(|template| {
+        commands::expand_footer(&template, &commands::FooterContext::detect(&profile_name))
+    })
+}
+
+fn resolve_post_footer_with(
+    config: Option<&crate::profile::ProfilesConfig>,
+    profile_name: &str,
+) -> Option<String> {
+    config
+        .and_then(|c| c.get(profile_name))
+        .and_then(|p| p.output.as_ref())
+        .and_then(|o| o.post_footer.clone())
+        .or_else(|| {
+            config
+                .and_then(|c| c.output.as_ref())
+                .and_then(|o| o.post_footer.clone())
+        })
+}
+
+/// Resolve the list output format and raw mode for conv commands
+///
+/// Rejects `--raw`/`SLACKRS_OUTPUT=raw` combined with an explicit non-JSON
+/// `--format`; raw preferences that only come from config are ignored for
+/// non-JSON formats, which never carry an envelope.
+fn resolve_list_output(args: &[String]) -> Result<(commands::OutputFormat, bool), String> {
+    let prefs = resolve_output_preferences(args);
+    let format = match prefs.format {
+        Some(ref fmt_str) => commands::OutputFormat::parse(fmt_str)?,
+        None => commands::OutputFormat::Json,
+    };
 
-    // Priority 2: Check SLACKRS_OUTPUT environment variable
-    if let Ok(output_mode) = std::env::var("SLACKRS_OUTPUT") {
-        return output_mode.trim().to_lowercase() == "raw";
+    if prefs.raw
+        && prefs.raw_source.is_explicit()
+        && prefs.format_source.is_explicit()
+        && format != commands::OutputFormat::Json
+    {
+        return Err(format!(
+            "--raw is only valid with --format json, but got --format {}",
+            format
+        ));
     }
 
-    // Priority 3: Default to envelope (false)
-    false
+    Ok((format, prefs.raw))
+}
+
+/// Render a wrapper command response in a non-JSON `--format`
+///
+/// `--columns=a,b.c` selects the columns of tabular formats through the
+/// shared formatter; without it, conv list/search and history/replies keep
+/// their dedicated layouts.
+fn render_formatted<T: serde::Serialize>(
+    args: &[String],
+    command: &str,
+    response: &T,
+    format: commands::OutputFormat,
+) -> Result<String, String> {
+    let columns = get_option(args, "--columns=")
+        .map(|spec| commands::output::parse_columns(&spec))
+        .transpose()?;
+    let value = serde_json::to_value(response).map_err(|e| e.to_string())?;
+    if columns.is_none() {
+        if let Ok(response) = serde_json::from_value::<crate::api::ApiResponse>(value.clone()) {
+            match command {
+                "conv list" | "conv search" => return commands::format_response(&response, format),
+                "conv history" | "conv replies" => {
+                    return commands::format_messages(&response, format)
+                }
+                _ => {}
+            }
+        }
+    }
+    commands::output::format_output(&value, command, format, columns.as_deref())
 }
 
 /// Check if error message indicates non-interactive mode failure
@@ -311,8 +624,8 @@ pub fn get_option(args: &[String], prefix: &str) -> Option<String> {
     let flag = prefix.strip_suffix('=').unwrap_or(prefix);
     if let Some(pos) = args.iter().position(|arg| arg == flag) {
         if let Some(value) = args.get(pos + 1) {
-            // Only treat as value if it doesn't start with '-'
-            if !value.starts_with('-') {
+            // Only treat as value if it doesn't start with '-' ('-' alone means stdin)
+            if !value.starts_with('-') || value == "-" {
                 return Some(value.clone());
             }
         }
@@ -347,44 +660,321 @@ pub fn parse_token_type(args: &[String]) -> Result<Option<TokenType>, String> {
     Ok(None)
 }
 
+/// Search query text plus the modifiers from `--in`, `--from`, `--after`,
+/// `--before`, `--has` and `--during`
+///
+/// `--in` / `--from` names are resolved with the channel and users caches of
+/// the profile's workspace; without a cache they are used as typed.
+async fn build_search_query_from_args(
+    args: &[String],
+    text: &str,
+    profile_name: &str,
+) -> Result<String, String> {
+    let modifiers = commands::QueryModifiers {
+        channels: get_all_options(args, "--in="),
+        users: get_all_options(args, "--from="),
+        after: get_option(args, "--after="),
+        before: get_option(args, "--before="),
+        has: get_all_options(args, "--has="),
+        during: get_option(args, "--during="),
+    };
+    if modifiers.is_empty() && !text.trim().is_empty() {
+        return Ok(text.to_string());
+    }
+
+    let team_id = if modifiers.channels.is_empty() && modifiers.users.is_empty() {
+        None
+    } else {
+        get_team_and_user_ids_from_profile(profile_name)
+            .await
+            .ok()
+            .map(|(team_id, _)| team_id)
+    };
+    let channel_names: std::collections::HashMap<String, String> = match &team_id {
+        Some(team_id) if !modifiers.channels.is_empty() => {
+            shell::ChannelNameCache::load(&shell::ChannelNameCache::default_path()?)
+                .channels(team_id)
+                .into_iter()
+                .map(|c| (c.id, c.name))
+                .collect()
+        }
+        _ => Default::default(),
+    };
+    let users_cache = match &team_id {
+        Some(_) if !modifiers.users.is_empty() => commands::UsersCacheFile::default_path()
+            .and_then(|path| commands::UsersCacheFile::load(&path))
+            .ok(),
+        _ => None,
+    };
+    let workspace = users_cache
+        .as_ref()
+        .zip(team_id.as_deref())
+        .and_then(|(cache, team_id)| cache.get_workspace(team_id));
+    commands::build_search_query(text, &modifiers, &channel_names, workspace)
+}
+
 pub async fn run_search(args: &[String]) -> Result<(), String> {
-    let query = args[2].clone();
+    // `search files <query>` / `search all <query>`; a lone `files` or `all`
+    // is still a message query
+    let (target, query) = match (args[2].as_str(), args.get(3)) {
+        ("files", Some(query)) if !query.starts_with("--") => {
+            (commands::SearchTarget::Files, query.clone())
+        }
+        ("all", Some(query)) if !query.starts_with("--") => {
+            (commands::SearchTarget::All, query.clone())
+        }
+        // Only modifier flags (e.g. `search --from=@alice`): no free text
+        (first, _) if first.starts_with("--") => (commands::SearchTarget::Messages, String::new()),
+        _ => (commands::SearchTarget::Messages, args[2].clone()),
+    };
+    let profile_name = resolve_profile_name(args);
+    let query = build_search_query_from_args(args, &query, &profile_name).await?;
     let count = get_option(args, "--count=").and_then(|s| s.parse().ok());
     let page = get_option(args, "--page=").and_then(|s| s.parse().ok());
     let sort = get_option(args, "--sort=");
     let sort_dir = get_option(args, "--sort_dir=");
-    let profile_name = resolve_profile_name(args);
+    let all = has_flag(args, "--all") || has_flag(args, "--paginate");
+    let max_results = match get_option(args, "--max-results=") {
+        Some(value) => Some(
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| "Error: --max-results must be a positive number".to_string())?,
+        ),
+        None => None,
+    };
+    if max_results.is_some() && page.is_some() {
+        return Err("Error: --max-results cannot be combined with --page".to_string());
+    }
     let token_type = parse_token_type(args)?;
-    let raw = should_output_raw(args);
+    let (format, raw) = resolve_list_output(args)?;
 
-    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
-    let response = commands::search(&client, query, count, page, sort, sort_dir)
-        .await
-        .map_err(|e| e.to_string())?;
+    // --summary / --group-by: per-channel or per-user counts instead of matches
+    let group_by = match get_option(args, "--group-by=") {
+        Some(value) => Some(commands::SearchGroupBy::parse(&value)?),
+        None if has_flag(args, "--summary") => Some(commands::SearchGroupBy::Channel),
+        None => None,
+    };
+
+    // --profiles / --all-profiles: search every selected workspace and merge
+    let profiles = if has_flag(args, "--all-profiles") {
+        let config = load_config(&default_config_path().map_err(|e| e.to_string())?)
+            .map_err(|e| e.to_string())?;
+        let mut names: Vec<String> = config.profiles.keys().cloned().collect();
+        names.sort();
+        Some(names)
+    } else {
+        get_option(args, "--profiles=").map(|_| split_list_option(args, "--profiles="))
+    };
+
+    if target != commands::SearchTarget::Messages
+        && (profiles.is_some() || group_by.is_some() || all || max_results.is_some())
+    {
+        return Err(format!(
+            "Error: {} does not support --profiles, --summary, --all or --max-results",
+            target.command()
+        ));
+    }
+
+    let (response, workspaces) = match profiles {
+        Some(profiles) => {
+            // --max-results: the top N of each workspace, merged into one page of N
+            let (response, workspaces) = search_profiles(
+                query,
+                &profiles,
+                max_results.unwrap_or(count.unwrap_or(20) as usize),
+                page.unwrap_or(1) as usize,
+                all && max_results.is_none(),
+                sort,
+                sort_dir,
+                token_type,
+            )
+            .await?;
+            (response, Some(workspaces))
+        }
+        None => {
+            let client =
+                get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+            let response = if target != commands::SearchTarget::Messages {
+                commands::search_target(&client, target, query, count, page, sort, sort_dir).await
+            } else if let Some(limit) = max_results {
+                commands::search_top(&client, query, limit, sort, sort_dir).await
+            } else if all {
+                commands::search_all(&client, query, count, sort, sort_dir).await
+            } else {
+                commands::search(&client, query, count, page, sort, sort_dir).await
+            }
+            .map_err(|e| e.to_string())?;
+            (response, None)
+        }
+    };
 
     // Display error guidance if response contains a known error
     crate::api::display_wrapper_error_guidance(&response);
 
-    // Output with or without envelope
-    let output = if raw {
+    let wrap = async |value: Value| -> Result<String, String> {
+        let wrapped = match &workspaces {
+            Some(workspaces) => {
+                let joined = |field: fn(&(String, Profile)) -> &str| {
+                    workspaces.iter().map(field).collect::<Vec<_>>().join(",")
+                };
+                CommandResponse::with_token_type(
+                    value,
+                    Some(joined(|(name, _)| name)),
+                    joined(|(_, profile)| &profile.team_id),
+                    joined(|(_, profile)| &profile.user_id),
+                    "search.messages".to_string(),
+                    "search".to_string(),
+                    token_type.map(|t| t.to_string()),
+                )
+            }
+            None => {
+                wrap_with_envelope_and_token_type(
+                    value,
+                    target.method().as_str(),
+                    target.command(),
+                    Some(profile_name.clone()),
+                    token_type,
+                )
+                .await?
+            }
+        };
+        Ok(serde_json::to_string_pretty(&wrapped).unwrap())
+    };
+
+    if let Some(group_by) = group_by {
+        let groups = commands::group_search_matches(&response, group_by);
+        let output = if format != commands::OutputFormat::Json {
+            commands::format_search_summary(&groups, group_by, format)?
+        } else {
+            let summary = commands::search_summary_json(&groups, group_by);
+            if raw {
+                serde_json::to_string_pretty(&summary).unwrap()
+            } else {
+                wrap(summary).await?
+            }
+        };
+        print!("{}", output);
+        if !output.ends_with('\n') {
+            println!();
+        }
+        return Ok(());
+    }
+
+    // Format output: non-JSON formats bypass raw/envelope logic
+    let view = if workspaces.is_some() {
+        "search --profiles"
+    } else {
+        target.command()
+    };
+    let mut response = response;
+    if format == commands::OutputFormat::Json && has_flag(args, "--flatten") {
+        commands::flatten_search_matches(&mut response);
+    }
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, view, &response, format)?
+    } else if raw {
         serde_json::to_string_pretty(&response).unwrap()
     } else {
-        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
-        let wrapped = wrap_with_envelope_and_token_type(
-            response_value,
-            "search.messages",
-            "search",
-            Some(profile_name),
-            token_type,
-        )
-        .await?;
-        serde_json::to_string_pretty(&wrapped).unwrap()
+        wrap(serde_json::to_value(&response).map_err(|e| e.to_string())?).await?
     };
 
     println!("{}", output);
     Ok(())
 }
 
+/// Run a search in every profile concurrently and merge the results
+///
+/// Workspaces that fail are reported in `workspaces` and as a warning; the
+/// search only fails when every workspace does.
+#[allow(clippy::too_many_arguments)]
+async fn search_profiles(
+    query: String,
+    profiles: &[String],
+    count: usize,
+    page: usize,
+    all: bool,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+    token_type: Option<TokenType>,
+) -> Result<(crate::api::ApiResponse, Vec<(String, Profile)>), String> {
+    if profiles.is_empty() {
+        return Err("No profiles to search (see: auth list)".to_string());
+    }
+    let config = load_config(&default_config_path().map_err(|e| e.to_string())?)
+        .map_err(|e| e.to_string())?;
+    let workspaces = profiles
+        .iter()
+        .map(|name| {
+            config
+                .profiles
+                .get(name)
+                .map(|profile| (name.clone(), profile.clone()))
+                .ok_or_else(|| format!("Profile '{}' not found", name))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let order = match sort.as_deref() {
+        Some("timestamp") => commands::MergeOrder::Timestamp,
+        _ => commands::MergeOrder::Score,
+    };
+    let ascending = sort_dir.as_deref() == Some("asc");
+
+    let searches = futures_util::future::join_all(workspaces.iter().map(|(name, profile)| {
+        let (query, sort, sort_dir) = (query.clone(), sort.clone(), sort_dir.clone());
+        async move {
+            let result = async {
+                let client = get_api_client_with_token_type(Some(name.clone()), token_type).await?;
+                if all {
+                    commands::search_all(&client, query, Some(100), sort, sort_dir).await
+                } else {
+                    // Enough matches from each workspace to fill the merged page
+                    commands::search_top(&client, query, page.max(1) * count, sort, sort_dir).await
+                }
+                .map_err(|e| e.to_string())
+            }
+            .await;
+            commands::WorkspaceSearch {
+                profile: name.clone(),
+                team_id: profile.team_id.clone(),
+                team_name: profile.team_name.clone(),
+                result,
+            }
+        }
+    }))
+    .await;
+
+    let failures: Vec<String> = searches
+        .iter()
+        .filter_map(|s| {
+            s.result
+                .as_ref()
+                .err()
+                .map(|e| format!("{}: {}", s.profile, e))
+        })
+        .collect();
+    if failures.len() == searches.len() {
+        return Err(format!(
+            "Search failed in every profile ({})",
+            failures.join("; ")
+        ));
+    }
+    for failure in &failures {
+        crate::gha::warn(&format!("Warning: search skipped profile {}", failure));
+    }
+
+    let merged = commands::merge_workspace_searches(
+        &searches,
+        order,
+        ascending,
+        count,
+        (!all).then_some(page),
+    );
+    let response = serde_json::from_value(merged).map_err(|e| e.to_string())?;
+    Ok((response, workspaces))
+}
+
 /// Get all options with a specific prefix from args
 /// Supports both --key=value and --key value formats (can be mixed)
 /// When using space-separated format, value must not start with '-'
@@ -433,7 +1023,6 @@ pub async fn run_conv_list(args: &[String]) -> Result<(), String> {
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
     let filter_strings = get_all_options(args, "--filter=");
-    let raw = should_output_raw(args);
 
     // Validate: --types is mutually exclusive with --include-private and --all
     if types.is_some() && (include_private || all) {
@@ -455,20 +1044,8 @@ pub async fn run_conv_list(args: &[String]) -> Result<(), String> {
         Some("public_channel,private_channel".to_string())
     };
 
-    // Parse format option (default: json)
-    let format = if let Some(fmt_str) = get_option(args, "--format=") {
-        commands::OutputFormat::parse(&fmt_str)?
-    } else {
-        commands::OutputFormat::Json
-    };
-
-    // Validate --raw compatibility
-    if raw && format != commands::OutputFormat::Json {
-        return Err(format!(
-            "--raw is only valid with --format json, but got --format {}",
-            format
-        ));
-    }
+    // Parse format option (default: json) and validate --raw compatibility
+    let (format, raw) = resolve_list_output(args)?;
 
     // Parse sort options
     let sort_key = if let Some(sort_str) = get_option(args, "--sort=") {
@@ -490,6 +1067,22 @@ pub async fn run_conv_list(args: &[String]) -> Result<(), String> {
         .collect();
     let filters = filters.map_err(|e| e.to_string())?;
 
+    // Sorting by activity needs the enrichment
+    let with_last_activity =
+        has_flag(args, "--with-last-activity") || sort_key == Some(commands::SortKey::LastActivity);
+    let max_enrich = match get_option(args, "--max-enrich=") {
+        Some(v) => v
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid --max-enrich value '{}'", v))?,
+        None => commands::conv::activity::DEFAULT_MAX_ENRICH,
+    };
+
+    // Change feed: compare with (and/or write) a channel list snapshot
+    let previous_snapshot = get_option(args, "--diff-against=")
+        .map(|path| commands::ChannelSnapshot::load(std::path::Path::new(&path)))
+        .transpose()?;
+    let snapshot_out = get_option(args, "--snapshot-out=");
+
     // Get debug level from args
     let debug_level = debug::get_debug_level(args);
 
@@ -551,14 +1144,64 @@ pub async fn run_conv_list(args: &[String]) -> Result<(), String> {
     // Apply filters
     commands::apply_filters(&mut response, &filters);
 
+    if with_last_activity {
+        let (team_id, _) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        let cache_path = commands::ActivityCache::default_path()?;
+        let mut cache = commands::ActivityCache::load(&cache_path);
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let report = commands::enrich_last_activity(
+            &client,
+            &mut response,
+            &mut cache,
+            &team_id,
+            max_enrich,
+            now,
+        )
+        .await;
+        if let Err(e) = cache.save(&cache_path) {
+            crate::gha::warn(&format!("Warning: {}", e));
+        }
+        if report.skipped > 0 {
+            crate::gha::warn(&format!(
+                "Warning: last activity fetched for {} channels only; {} skipped (raise --max-enrich)",
+                max_enrich, report.skipped
+            ));
+        }
+        if report.failed > 0 {
+            crate::gha::warn(&format!(
+                "Warning: could not read history of {} channels (not a member?)",
+                report.failed
+            ));
+        }
+    }
+
     // Apply sorting if specified
     if let Some(key) = sort_key {
         commands::sort_conversations(&mut response, key, sort_dir);
     }
 
+    let mut view = "conv list";
+    if response.ok && (previous_snapshot.is_some() || snapshot_out.is_some()) {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let current = commands::ChannelSnapshot::from_response(&response, now);
+        if let Some(path) = &snapshot_out {
+            current.save(std::path::Path::new(path))?;
+        }
+        if let Some(previous) = &previous_snapshot {
+            response = commands::delta_response(previous, &current);
+            view = "conv list delta";
+        }
+    }
+
     // Format output: non-JSON formats bypass raw/envelope logic
     let output = if format != commands::OutputFormat::Json {
-        commands::format_response(&response, format)?
+        render_formatted(args, view, &response, format)?
     } else if raw {
         serde_json::to_string_pretty(&response).unwrap()
     } else {
@@ -636,26 +1279,13 @@ pub async fn run_conv_search(args: &[String]) -> Result<(), String> {
     let limit = get_option(args, "--limit=").and_then(|s| s.parse().ok());
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
-    let raw = should_output_raw(args);
     let select = has_flag(args, "--select");
 
     // Parse additional filters from --filter= flags
     let filter_strings = get_all_options(args, "--filter=");
 
-    // Parse format option (default: json)
-    let format = if let Some(fmt_str) = get_option(args, "--format=") {
-        commands::OutputFormat::parse(&fmt_str)?
-    } else {
-        commands::OutputFormat::Json
-    };
-
-    // Validate --raw compatibility
-    if raw && format != commands::OutputFormat::Json {
-        return Err(format!(
-            "--raw is only valid with --format json, but got --format {}",
-            format
-        ));
-    }
+    // Parse format option (default: json) and validate --raw compatibility
+    let (format, raw) = resolve_list_output(args)?;
 
     // Parse sort options
     let sort_key = if let Some(sort_str) = get_option(args, "--sort=") {
@@ -706,7 +1336,7 @@ pub async fn run_conv_search(args: &[String]) -> Result<(), String> {
 
     // Format output: non-JSON formats bypass raw/envelope logic
     let output = if format != commands::OutputFormat::Json {
-        commands::format_response(&response, format)?
+        render_formatted(args, "conv search", &response, format)?
     } else if raw {
         serde_json::to_string_pretty(&response).unwrap()
     } else {
@@ -775,9 +1405,10 @@ pub async fn run_conv_history(args: &[String]) -> Result<(), String> {
     let limit = get_option(args, "--limit=").and_then(|s| s.parse().ok());
     let oldest = get_option(args, "--oldest=");
     let latest = get_option(args, "--latest=");
+    let cursor = get_option(args, "--cursor=");
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
-    let raw = should_output_raw(args);
+    let (format, raw) = resolve_list_output(args)?;
 
     // Get debug level from args
     let debug_level = debug::get_debug_level(args);
@@ -822,9 +1453,65 @@ pub async fn run_conv_history(args: &[String]) -> Result<(), String> {
     );
 
     let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
-    let response = commands::conv_history(&client, channel, limit, oldest, latest)
-        .await
-        .map_err(|e| e.to_string())?;
+    let sync = has_flag(args, "--sync");
+    let all = sync || has_flag(args, "--all") || has_flag(args, "--paginate");
+    if all && cursor.is_some() {
+        return Err("Error: --cursor cannot be combined with --all".to_string());
+    }
+    if sync && (latest.is_some() || has_flag(args, "--cursor-stability")) {
+        return Err(
+            "Error: --sync cannot be combined with --latest or --cursor-stability".to_string(),
+        );
+    }
+
+    let thread_shape: Vec<_> = commands::thread_shape_filter(
+        has_flag(args, "--only-threads"),
+        has_flag(args, "--only-top-level"),
+        has_flag(args, "--only-broadcasts"),
+    )?
+    .into_iter()
+    .collect();
+
+    // Incremental sync: resume after the newest message of the previous run
+    let mut sync_state = None;
+    let mut oldest = oldest;
+    if sync {
+        let (team_id, _) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        let state_path = match get_option(args, "--sync-state=") {
+            Some(path) => std::path::PathBuf::from(path),
+            None => commands::HistorySyncState::default_path()?,
+        };
+        let state = commands::HistorySyncState::load(&state_path)?;
+        if let Some(entry) = state.get(&team_id, &channel) {
+            oldest = Some(entry.latest_ts.clone());
+        }
+        sync_state = Some((state, state_path, team_id, channel.clone()));
+    }
+    let mut consistency = None;
+    let mut response = if has_flag(args, "--cursor-stability") {
+        let (response, report) =
+            commands::conv_history_stable(&client, channel, limit, oldest, latest, cursor, all)
+                .await
+                .map_err(|e| e.to_string())?;
+        if !report.is_consistent() {
+            crate::gha::warn(&format!(
+                "Warning: {} message(s) edited and {} deleted during the read (snapshot latest={})",
+                report.edited.len(),
+                report.deleted.len(),
+                report.snapshot_latest
+            ));
+        }
+        consistency = Some(report);
+        response
+    } else if all {
+        commands::conv_history_all(&client, channel, limit, oldest, latest)
+            .await
+            .map_err(|e| e.to_string())?
+    } else {
+        commands::conv_history(&client, channel, limit, oldest, latest, cursor)
+            .await
+            .map_err(|e| e.to_string())?
+    };
 
     // Log error code if present
     debug::log_error_code(
@@ -835,8 +1522,17 @@ pub async fn run_conv_history(args: &[String]) -> Result<(), String> {
     // Display error guidance if response contains a known error
     crate::api::display_wrapper_error_guidance(&response);
 
-    // Output with or without envelope
-    let output = if raw {
+    // The sync cursor follows the raw history, including edit events
+    let newest_ts = commands::newest_message_ts(&response);
+    if has_flag(args, "--dedupe-edits") {
+        commands::apply_dedupe_edits(&mut response);
+    }
+    commands::apply_message_filters(&mut response, &thread_shape);
+
+    // Format output: non-JSON formats bypass raw/envelope logic
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "conv history", &response, format)?
+    } else if raw {
         serde_json::to_string_pretty(&response).unwrap()
     } else {
         let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
@@ -848,99 +1544,89 @@ pub async fn run_conv_history(args: &[String]) -> Result<(), String> {
             token_type,
         )
         .await?;
+        let wrapped = match &consistency {
+            Some(report) => wrapped.with_consistency(report.to_json()),
+            None => wrapped,
+        };
         serde_json::to_string_pretty(&wrapped).unwrap()
     };
 
     println!("{}", output);
+
+    // Advance the cursor only after the messages were written out
+    if let Some((mut state, state_path, team_id, channel)) = sync_state {
+        if response.ok {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            state.record(&team_id, &channel, newest_ts, now);
+            state.save(&state_path)?;
+        }
+    }
     Ok(())
 }
 
-pub async fn run_thread_get(args: &[String]) -> Result<(), String> {
-    // Check for --help flag before API call
+pub async fn run_conv_replies(args: &[String]) -> Result<(), String> {
     if has_flag(args, "--help") || has_flag(args, "-h") {
-        print_thread_usage(&args[0]);
+        print_conv_usage(&args[0]);
         return Ok(());
     }
 
-    // Parse required arguments: channel and thread_ts
-    if args.len() < 5 {
-        return Err("Usage: slack-rs thread get <channel> <thread_ts> [--limit=N] [--inclusive] [--raw] [--profile=NAME] [--token-type=bot|user]".to_string());
-    }
-
-    let channel = args[3].clone();
-    let thread_ts = args[4].clone();
+    let positional: Vec<&String> = args[3..]
+        .iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .take(2)
+        .collect();
+    let [channel, thread_ts] = positional[..] else {
+        return Err("Usage: conv replies <channel> <thread_ts> [--limit=N] [--oldest=TS] [--latest=TS] [--cursor=CURSOR] [--all] [--filter=KEY:VALUE]... [--format=FORMAT] [--raw] [--profile=NAME] [--token-type=bot|user]".to_string());
+    };
+    let (channel, thread_ts) = (channel.clone(), thread_ts.clone());
+
     let limit = get_option(args, "--limit=").and_then(|s| s.parse().ok());
-    let inclusive = has_flag(args, "--inclusive");
+    let oldest = get_option(args, "--oldest=");
+    let latest = get_option(args, "--latest=");
+    let cursor = get_option(args, "--cursor=");
+    let filters = get_all_options(args, "--filter=")
+        .iter()
+        .map(|s| commands::MessageFilter::parse(s))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let (format, raw) = resolve_list_output(args)?;
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
-    let raw = should_output_raw(args);
-
-    // Get debug level from args
     let debug_level = debug::get_debug_level(args);
 
-    // Log debug information if --debug or --trace flag is present
-    let token_store_backend = if std::env::var("SLACK_TOKEN").is_ok() {
-        "environment"
-    } else {
-        "file"
-    };
-
-    // Resolve actual token type for debug output
-    let resolved_token_type = if let Some(explicit) = token_type {
-        explicit
-    } else {
-        let config_path = default_config_path().map_err(|e| e.to_string())?;
-        let profile = resolve_profile_full(&config_path, &profile_name)
-            .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
-
-        if let Some(default_type) = profile.default_token_type {
-            default_type
-        } else {
-            let token_store = create_token_store().map_err(|e| e.to_string())?;
-            let user_token_key = format!("{}:{}:user", profile.team_id, profile.user_id);
-            if token_store.get(&user_token_key).is_ok() {
-                TokenType::User
-            } else {
-                TokenType::Bot
-            }
-        }
-    };
-
-    let endpoint = "https://slack.com/api/conversations.replies";
-
-    debug::log_api_context(
-        debug_level,
-        Some(&profile_name),
-        token_store_backend,
-        resolved_token_type.as_str(),
-        "conversations.replies",
-        endpoint,
-    );
-
     let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
-    let inclusive_opt = if inclusive { Some(true) } else { None };
-    let response = commands::thread_get(&client, channel, thread_ts, limit, inclusive_opt)
-        .await
-        .map_err(|e| e.to_string())?;
+    let mut response = if has_flag(args, "--all") || has_flag(args, "--paginate") {
+        if cursor.is_some() {
+            return Err("Error: --cursor cannot be combined with --all".to_string());
+        }
+        commands::conv_replies_all(&client, channel, thread_ts, limit, oldest, latest).await
+    } else {
+        commands::conv_replies(&client, channel, thread_ts, limit, oldest, latest, cursor).await
+    }
+    .map_err(|e| e.to_string())?;
 
-    // Log error code if present
     debug::log_error_code(
         debug_level,
         &serde_json::to_value(&response).unwrap_or_default(),
     );
-
-    // Display error guidance if response contains a known error
     crate::api::display_wrapper_error_guidance(&response);
 
-    // Output with or without envelope
-    let output = if raw {
+    commands::apply_message_filters(&mut response, &filters);
+
+    // Format output: non-JSON formats bypass raw/envelope logic
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "conv replies", &response, format)?
+    } else if raw {
         serde_json::to_string_pretty(&response).unwrap()
     } else {
         let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
         let wrapped = wrap_with_envelope_and_token_type(
             response_value,
             "conversations.replies",
-            "thread get",
+            "conv replies",
             Some(profile_name),
             token_type,
         )
@@ -952,77 +1638,80 @@ pub async fn run_thread_get(args: &[String]) -> Result<(), String> {
     Ok(())
 }
 
-pub async fn run_users_info(args: &[String]) -> Result<(), String> {
-    let user = args[3].clone();
-    let profile_name = resolve_profile_name(args);
-    let token_type = parse_token_type(args)?;
-    let raw = should_output_raw(args);
-
-    // Get debug level from args
-    let debug_level = debug::get_debug_level(args);
-
-    // Log debug information if --debug or --trace flag is present
-    let token_store_backend = if std::env::var("SLACK_TOKEN").is_ok() {
-        "environment"
-    } else {
-        "file"
-    };
-
-    // Resolve actual token type for debug output
-    let resolved_token_type = if let Some(explicit) = token_type {
-        explicit
-    } else {
-        let config_path = default_config_path().map_err(|e| e.to_string())?;
-        let profile = resolve_profile_full(&config_path, &profile_name)
-            .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
-
-        if let Some(default_type) = profile.default_token_type {
-            default_type
-        } else {
-            let token_store = create_token_store().map_err(|e| e.to_string())?;
-            let user_token_key = format!("{}:{}:user", profile.team_id, profile.user_id);
-            if token_store.get(&user_token_key).is_ok() {
-                TokenType::User
-            } else {
-                TokenType::Bot
-            }
+pub async fn run_conv_members(args: &[String]) -> Result<(), String> {
+    let channel = required_positional(
+        args,
+        3,
+        "conv members <channel> [--resolve] [--only-guests|--only-external] [--format=FORMAT] [--raw] [--profile=NAME] [--token-type=bot|user]",
+    )?;
+    let access_filter = match (
+        has_flag(args, "--only-guests"),
+        has_flag(args, "--only-external"),
+    ) {
+        (true, true) => {
+            return Err("Error: --only-guests and --only-external cannot be combined".to_string())
         }
+        (true, false) => Some(commands::MemberAccessFilter::Guests),
+        (false, true) => Some(commands::MemberAccessFilter::External),
+        (false, false) => None,
     };
-
-    let endpoint = "https://slack.com/api/users.info";
-
-    debug::log_api_context(
-        debug_level,
-        Some(&profile_name),
-        token_store_backend,
-        resolved_token_type.as_str(),
-        "users.info",
-        endpoint,
-    );
+    // The access filters work on resolved records
+    let resolve = has_flag(args, "--resolve") || access_filter.is_some();
+    let (format, raw) = resolve_list_output(args)?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
 
     let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
-    let response = commands::users_info(&client, user)
+    let mut response = commands::conv_members(&client, &channel)
         .await
         .map_err(|e| e.to_string())?;
-
-    // Log error code if present
-    debug::log_error_code(
-        debug_level,
-        &serde_json::to_value(&response).unwrap_or_default(),
-    );
-
-    // Display error guidance if response contains a known error
     crate::api::display_wrapper_error_guidance(&response);
 
-    // Output with or without envelope
-    let output = if raw {
+    // Tabular formats need records, so bare IDs become `{"id": ...}` there
+    if response.ok && (resolve || format != commands::OutputFormat::Json) {
+        let team_id = if resolve {
+            Some(get_team_and_user_ids_from_profile(&profile_name).await?.0)
+        } else {
+            None
+        };
+        let workspace_cache = match &team_id {
+            Some(team_id) => {
+                let cache_path = commands::UsersCacheFile::default_path()?;
+                commands::UsersCacheFile::load(&cache_path)?
+                    .get_workspace(team_id)
+                    .cloned()
+            }
+            None => None,
+        };
+        let unresolved = commands::resolve_member_records(&mut response, workspace_cache.as_ref());
+        if let Some(team_id) = &team_id {
+            // Slack Connect channels: members from other organizations
+            let connect = commands::fetch_connect_info(&client, &channel)
+                .await
+                .map_err(|e| e.to_string())?;
+            commands::annotate_external_members(&mut response, team_id, &connect);
+            if unresolved > 0 && !connect.is_ext_shared {
+                crate::gha::warn(&format!(
+                    "Warning: {} member(s) not found in the users cache (run `slack-rs users cache-update`)",
+                    unresolved
+                ));
+            }
+        }
+        if let Some(filter) = access_filter {
+            commands::retain_members(&mut response, filter);
+        }
+    }
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "conv members", &response, format)?
+    } else if raw {
         serde_json::to_string_pretty(&response).unwrap()
     } else {
         let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
         let wrapped = wrap_with_envelope_and_token_type(
             response_value,
-            "users.info",
-            "users info",
+            "conversations.members",
+            "conv members",
             Some(profile_name),
             token_type,
         )
@@ -1034,236 +1723,581 @@ pub async fn run_users_info(args: &[String]) -> Result<(), String> {
     Ok(())
 }
 
-pub async fn run_users_cache_update(args: &[String]) -> Result<(), String> {
-    let profile_name = resolve_profile_name(args);
-    let force = has_flag(args, "--force");
-    let token_type = parse_token_type(args)?;
+/// Output queue settings shared by streaming commands
+/// (`--queue-size`, `--overflow`, `--spill-file`, `--meta-interval`)
+fn parse_stream_config(args: &[String]) -> Result<crate::stream::StreamConfig, String> {
+    let mut config = crate::stream::StreamConfig::default();
+    if let Some(v) = get_option(args, "--queue-size=") {
+        config.capacity = v.parse::<usize>().ok().filter(|n| *n > 0).ok_or_else(|| {
+            format!(
+                "Invalid --queue-size value '{}': expected a positive integer",
+                v
+            )
+        })?;
+    }
+    if let Some(v) = get_option(args, "--overflow=") {
+        config.policy = crate::stream::OverflowPolicy::parse(&v)?;
+    }
+    config.spill_path = get_option(args, "--spill-file=").map(std::path::PathBuf::from);
+    if let Some(v) = get_option(args, "--meta-interval=") {
+        let secs = v.parse::<u64>().ok().filter(|n| *n > 0).ok_or_else(|| {
+            format!(
+                "Invalid --meta-interval value '{}': expected a positive number of seconds",
+                v
+            )
+        })?;
+        config.meta_interval = std::time::Duration::from_secs(secs);
+    }
+    Ok(config)
+}
 
-    let config_path = default_config_path().map_err(|e| e.to_string())?;
-    let config = load_config(&config_path).map_err(|e| e.to_string())?;
+pub async fn run_events_listen(args: &[String]) -> Result<(), String> {
+    if has_flag(args, "--help") || has_flag(args, "-h") {
+        print_events_usage(&args[0]);
+        return Ok(());
+    }
 
-    let profile = config
-        .get(&profile_name)
-        .ok_or_else(|| format!("Profile '{}' not found", profile_name))?;
+    let app_token = get_option(args, "--app-token=")
+        .or_else(|| std::env::var("SLACK_APP_TOKEN").ok())
+        .ok_or_else(|| {
+            "An app-level token is required: pass --app-token=xapp-... or set SLACK_APP_TOKEN"
+                .to_string()
+        })?;
+    let filters = get_all_options(args, "--filter=")
+        .iter()
+        .map(|s| crate::socket::EventFilter::parse(s))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let max_events = match get_option(args, "--max-events=") {
+        Some(n) => Some(
+            n.parse::<usize>()
+                .map_err(|_| format!("Invalid --max-events value: {}", n))?,
+        ),
+        None => None,
+    };
+    let health = match get_option(args, "--health-addr=") {
+        Some(addr) => {
+            let health = crate::socket::Health::new();
+            let (bound, _) = crate::socket::serve_health(&addr, health.clone()).await?;
+            eprintln!("Health endpoint: http://{}/healthz", bound);
+            Some(health)
+        }
+        None => None,
+    };
+    let options = crate::socket::ListenerOptions {
+        filters,
+        max_events,
+        reconnect: !has_flag(args, "--no-reconnect"),
+        health,
+    };
 
-    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let heartbeat_config = crate::socket::HeartbeatConfig {
+        file: get_option(args, "--heartbeat-file=").map(std::path::PathBuf::from),
+        url: get_option(args, "--heartbeat-url="),
+    };
+    let heartbeat = heartbeat_config
+        .is_enabled()
+        .then(|| crate::socket::Heartbeat::new(heartbeat_config));
 
-    commands::update_cache(&client, profile.team_id.clone(), force)
+    let queue = crate::stream::OutputQueue::stdout(parse_stream_config(args)?);
+
+    let client = crate::api::ApiClient::with_token(app_token);
+    let mut write_error = None;
+    let result = crate::socket::listen(&client, &options, heartbeat.as_ref(), |event| {
+        if write_error.is_none() {
+            write_error = queue.push(event.to_json().to_string()).err();
+        }
+    })
+    .await
+    .map_err(|e| e.to_string());
+    queue.finish()?;
+    match write_error {
+        Some(e) => Err(e),
+        None => result,
+    }
+}
+
+pub async fn run_conv_export(args: &[String]) -> Result<(), String> {
+    if has_flag(args, "--help") || has_flag(args, "-h") {
+        print_conv_usage(&args[0]);
+        return Ok(());
+    }
+
+    let channel = args
+        .get(3)
+        .filter(|arg| !arg.starts_with("--"))
+        .cloned()
+        .ok_or_else(|| "Usage: conv export <channel> [--format=json|html] [--out=PATH] [--oldest=TS] [--latest=TS] [--no-threads] [--avatars] [--title=TITLE] [--redact=RULES] [--dedupe-edits] [--profile=NAME] [--token-type=bot|user]".to_string())?;
+    let format = commands::ExportFormat::parse(
+        &get_option(args, "--format=").unwrap_or_else(|| "json".to_string()),
+    )?;
+    let out = get_option(args, "--out=");
+    let oldest = get_option(args, "--oldest=");
+    let latest = get_option(args, "--latest=");
+    let include_threads = !has_flag(args, "--no-threads");
+    let redactor = commands::Redactor::parse(&get_all_options(args, "--redact="))?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let mut export = commands::conv_export(&client, channel, oldest, latest, include_threads)
         .await
         .map_err(|e| e.to_string())?;
+    if has_flag(args, "--dedupe-edits") {
+        commands::dedupe_edits(&mut export.messages);
+    }
+    if !redactor.is_empty() {
+        export.redaction = Some(redactor.redact_export(&mut export));
+    }
 
-    println!("Cache updated successfully for team {}", profile.team_id);
+    // Resolve user names from the users cache when available
+    let user_names = match (
+        get_team_and_user_ids_from_profile(&profile_name).await,
+        commands::UsersCacheFile::default_path(),
+    ) {
+        (Ok((team_id, _)), Ok(cache_path)) => commands::UsersCacheFile::load(&cache_path)
+            .ok()
+            .and_then(|cache| cache.get_workspace(&team_id).cloned())
+            .map(|ws| {
+                let style = resolve_display_preferences(args).user_name;
+                ws.users
+                    .into_values()
+                    .map(|u| {
+                        let name = u.display_as(style).to_string();
+                        (u.id, name)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Default::default(),
+    };
+
+    let html_options = commands::HtmlOptions {
+        title: get_option(args, "--title="),
+        avatars: has_flag(args, "--avatars"),
+        user_names,
+    };
+    let output = export.render(format, &html_options)?;
+
+    match out {
+        Some(path) => {
+            std::fs::write(&path, output)
+                .map_err(|e| format!("Failed to write export to {}: {}", path, e))?;
+            eprintln!("Exported {} messages to {}", export.message_count(), path);
+            if let Some(report) = &export.redaction {
+                for rule in &report.rules {
+                    eprintln!("  Redacted {} match(es) of {}", rule.count, rule.rule);
+                }
+            }
+        }
+        None => println!("{}", output),
+    }
     Ok(())
 }
 
-pub async fn run_users_resolve_mentions(args: &[String]) -> Result<(), String> {
-    if args.len() < 4 {
-        return Err(
-            "Usage: users resolve-mentions <text> [--profile=NAME] [--format=FORMAT]".to_string(),
-        );
+pub async fn run_conv_tail(args: &[String]) -> Result<(), String> {
+    if has_flag(args, "--help") || has_flag(args, "-h") {
+        print_conv_usage(&args[0]);
+        return Ok(());
     }
 
-    let text = args[3].clone();
+    let channel = args
+        .get(3)
+        .filter(|arg| !arg.starts_with("--"))
+        .cloned()
+        .ok_or_else(|| "Usage: conv tail <channel> [--exec=CMD] [--filter=KEY:VALUE]... [--interval=SECS] [--since=TS] [--max-events=N] [--dedupe-edits] [--queue-size=N] [--overflow=POLICY] [--profile=NAME] [--token-type=bot|user]".to_string())?;
+    let exec = get_option(args, "--exec=");
+    let filters = get_all_options(args, "--filter=")
+        .iter()
+        .map(|s| commands::MessageFilter::parse(s))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let interval = match get_option(args, "--interval=") {
+        Some(v) => v.parse::<u64>().ok().filter(|i| *i > 0).ok_or_else(|| {
+            format!(
+                "Invalid --interval value '{}': expected a positive number of seconds",
+                v
+            )
+        })?,
+        None => 5,
+    };
+    let max_events = match get_option(args, "--max-events=") {
+        Some(v) => Some(v.parse::<usize>().ok().filter(|n| *n > 0).ok_or_else(|| {
+            format!(
+                "Invalid --max-events value '{}': expected a positive integer",
+                v
+            )
+        })?),
+        None => None,
+    };
+    let dedupe_edits = has_flag(args, "--dedupe-edits");
+    let mut cursor = get_option(args, "--since=").unwrap_or_else(commands::now_ts);
     let profile_name = resolve_profile_name(args);
-    let format_str = get_option(args, "--format=").unwrap_or_else(|| "display_name".to_string());
-
-    let format = format_str.parse::<commands::MentionFormat>().map_err(|_| {
-        format!(
-            "Invalid format: {}. Use display_name, real_name, or username",
-            format_str
-        )
-    })?;
+    let token_type = parse_token_type(args)?;
 
-    let config_path = default_config_path().map_err(|e| e.to_string())?;
-    let config = load_config(&config_path).map_err(|e| e.to_string())?;
+    let stream_config = parse_stream_config(args)?;
 
-    let profile = config
-        .get(&profile_name)
-        .ok_or_else(|| format!("Profile '{}' not found", profile_name))?;
+    let client = get_api_client_with_token_type(Some(profile_name), token_type).await?;
+    let queue = crate::stream::OutputQueue::stdout(stream_config);
+    let mut dispatched = 0usize;
+
+    let result = async {
+        loop {
+            let mut messages = match commands::fetch_new_messages(&client, &channel, &cursor).await
+            {
+                Ok(messages) => messages,
+                // Slack-level errors (e.g. channel_not_found) will not go away by retrying
+                Err(e @ crate::api::ApiError::SlackError(_)) => return Err(e.to_string()),
+                Err(e) => {
+                    eprintln!("Warning: poll failed, retrying: {}", e);
+                    Vec::new()
+                }
+            };
+            cursor = commands::advance_cursor(&cursor, &messages);
+            if dedupe_edits {
+                commands::dedupe_edits(&mut messages);
+            }
 
-    let cache_path = commands::UsersCacheFile::default_path()?;
-    let cache_file = commands::UsersCacheFile::load(&cache_path)?;
+            for message in messages
+                .iter()
+                .filter(|m| commands::message_matches(m, &filters))
+            {
+                match exec {
+                    Some(ref command) => {
+                        let status = commands::run_exec(command, &channel, message)?;
+                        if !status.success() {
+                            eprintln!(
+                                "Warning: handler exited with {} for message {}",
+                                status,
+                                message.get("ts").and_then(|t| t.as_str()).unwrap_or("?")
+                            );
+                        }
+                    }
+                    None => {
+                        queue.push(serde_json::to_string(message).map_err(|e| e.to_string())?)?
+                    }
+                }
 
-    let workspace_cache = cache_file.get_workspace(&profile.team_id).ok_or_else(|| {
-        format!(
-            "No cache found for team {}. Run 'users cache-update' first.",
-            profile.team_id
-        )
-    })?;
+                dispatched += 1;
+                if max_events.is_some_and(|max| dispatched >= max) {
+                    return Ok(());
+                }
+            }
 
-    let result = commands::resolve_mentions(&text, workspace_cache, format);
-    println!("{}", result);
-    Ok(())
+            tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+        }
+    }
+    .await;
+    queue.finish()?;
+    result
 }
 
-/// Get team_id and user_id from profile
-async fn get_team_and_user_ids_from_profile(
-    profile_name: &str,
-) -> Result<(String, String), String> {
-    let config_path = default_config_path().map_err(|e| e.to_string())?;
-    let profile = resolve_profile_full(&config_path, profile_name)
-        .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
-    Ok((profile.team_id, profile.user_id))
+/// Current UNIX time in seconds
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
 
-pub async fn run_msg_post(args: &[String], non_interactive: bool) -> Result<(), String> {
-    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
-
-    if args.len() < 5 {
-        return Err("Usage: msg post <channel> <text> [--thread-ts=TS] [--reply-broadcast] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]".to_string());
+pub async fn run_conv_watch(args: &[String]) -> Result<(), String> {
+    if has_flag(args, "--help") || has_flag(args, "-h") {
+        print_conv_usage(&args[0]);
+        return Ok(());
     }
 
-    let channel = args[3].clone();
-    let text = args[4].clone();
-    let thread_ts = get_option(args, "--thread-ts=");
-    let reply_broadcast = has_flag(args, "--reply-broadcast");
-    let yes = has_flag(args, "--yes");
+    let channel = args
+        .get(3)
+        .filter(|arg| !arg.starts_with("--"))
+        .cloned()
+        .ok_or_else(|| "Usage: conv watch <channel> [--interval=DURATION | --socket] [--app-token=TOKEN] [--filter=KEY:VALUE]... [--since=TS] [--state=PATH] [--max-events=N] [--queue-size=N] [--overflow=POLICY] [--profile=NAME] [--token-type=bot|user]".to_string())?;
+    let socket = has_flag(args, "--socket");
+    if socket && get_option(args, "--interval=").is_some() {
+        return Err("--interval cannot be combined with --socket".to_string());
+    }
+    let interval = commands::schedule::parse_duration(
+        &get_option(args, "--interval=").unwrap_or_else(|| "5s".to_string()),
+    )
+    .map_err(|e| format!("Invalid --interval value: {}", e))?;
+    if interval <= 0 {
+        return Err("--interval must be positive".to_string());
+    }
+    let filters = get_all_options(args, "--filter=")
+        .iter()
+        .map(|s| commands::MessageFilter::parse(s))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let max_events = match get_option(args, "--max-events=") {
+        Some(v) => Some(v.parse::<usize>().ok().filter(|n| *n > 0).ok_or_else(|| {
+            format!(
+                "Invalid --max-events value '{}': expected a positive integer",
+                v
+            )
+        })?),
+        None => None,
+    };
+    let state_path = match get_option(args, "--state=") {
+        Some(path) => std::path::PathBuf::from(path),
+        None => commands::default_watch_state_path()?,
+    };
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
-    let idempotency_key = get_option(args, "--idempotency-key=");
-
-    // Validate: --reply-broadcast requires --thread-ts
-    if reply_broadcast && thread_ts.is_none() {
-        return Err("Error: --reply-broadcast requires --thread-ts".to_string());
-    }
-
-    let raw = should_output_raw(args);
-    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
-
-    // Check idempotency if key provided
-    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
-        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+    let stream_config = parse_stream_config(args)?;
+
+    let (team_id, _) = get_team_and_user_ids_from_profile(&profile_name).await?;
+    let mut state = commands::HistorySyncState::load(&state_path)?;
+    let mut cursor = get_option(args, "--since=")
+        .or_else(|| {
+            state
+                .get(&team_id, &channel)
+                .map(|entry| entry.latest_ts.clone())
+        })
+        .unwrap_or_else(commands::now_ts);
+    let mut names = {
+        let cache = commands::UsersCacheFile::default_path()
+            .and_then(|path| commands::UsersCacheFile::load(&path))
+            .ok();
+        commands::UserNames::from_cache(
+            cache.as_ref().and_then(|c| c.get_workspace(&team_id)),
+            resolve_display_preferences(args).user_name,
+        )
+    };
 
-        // Build params for fingerprinting
-        let mut params = serde_json::Map::new();
-        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
-        params.insert("text".to_string(), serde_json::json!(text.clone()));
-        if let Some(ref ts) = thread_ts {
-            params.insert("thread_ts".to_string(), serde_json::json!(ts));
-            if reply_broadcast {
-                params.insert("reply_broadcast".to_string(), serde_json::json!(true));
+    let queue = crate::stream::OutputQueue::stdout(stream_config);
+    let mut dispatched = 0usize;
+
+    let result = if socket {
+        let app_token = get_option(args, "--app-token=")
+            .or_else(|| std::env::var("SLACK_APP_TOKEN").ok())
+            .ok_or_else(|| {
+                "--socket requires an app-level token: pass --app-token=xapp-... or set SLACK_APP_TOKEN"
+                    .to_string()
+            })?;
+        let options = crate::socket::ListenerOptions {
+            filters: vec![
+                crate::socket::EventFilter::EventType("message".to_string()),
+                crate::socket::EventFilter::Channel(channel.clone()),
+            ],
+            max_events: None,
+            reconnect: true,
+            health: None,
+        };
+        let client = crate::api::ApiClient::with_token(app_token);
+        let done = tokio::sync::Notify::new();
+        let mut error = None;
+        let listener = crate::socket::listen(&client, &options, None, |event| {
+            if error.is_some() || max_events.is_some_and(|max| dispatched >= max) {
+                return;
             }
-        }
-
-        // Get team_id and user_id from profile
-        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
-
-        match handler
-            .check(
-                Some(key.clone()),
-                team_id.clone(),
-                user_id.clone(),
-                "chat.postMessage".to_string(),
-                &params,
-            )
-            .map_err(|e| e.to_string())?
-        {
-            IdempotencyCheckResult::Replay {
-                response, status, ..
-            } => {
-                // Return cached response
-                (response, Some(status))
+            let Some(message) = commands::socket_message(event, &channel) else {
+                return;
+            };
+            cursor = commands::advance_cursor(&cursor, std::slice::from_ref(&message));
+            state.record(&team_id, &channel, Some(cursor.clone()), unix_now());
+            if let Err(e) = state.save(&state_path) {
+                error = Some(e);
+                done.notify_one();
+                return;
             }
-            IdempotencyCheckResult::Execute {
-                key: scoped_key,
-                fingerprint,
-            } => {
-                // Execute and store
-                let response = commands::msg_post(
-                    &client,
-                    channel,
-                    text,
-                    thread_ts,
-                    reply_broadcast,
-                    yes,
-                    non_interactive,
-                )
-                .await
-                .map_err(|e| e.to_string())?;
-
-                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
-
-                // Store result
-                handler
-                    .store(scoped_key, fingerprint, response_value.clone())
-                    .map_err(|e| e.to_string())?;
-
-                (
-                    response_value,
-                    Some(crate::idempotency::IdempotencyStatus::Executed),
-                )
+            if !commands::message_matches(&message, &filters) {
+                return;
             }
-            IdempotencyCheckResult::NoKey => unreachable!(),
+            if let Err(e) = queue.push(names.annotate(&channel, &message).to_string()) {
+                error = Some(e);
+                done.notify_one();
+                return;
+            }
+            dispatched += 1;
+            if max_events.is_some_and(|max| dispatched >= max) {
+                done.notify_one();
+            }
+        });
+        let listened = tokio::select! {
+            result = listener => result.map_err(|e| e.to_string()),
+            _ = done.notified() => Ok(()),
+        };
+        match error {
+            Some(e) => Err(e),
+            None => listened,
         }
     } else {
-        // No idempotency key - execute normally
-        let response = commands::msg_post(
-            &client,
-            channel,
-            text,
-            thread_ts,
-            reply_broadcast,
-            yes,
-            non_interactive,
-        )
-        .await
-        .map_err(|e| e.to_string())?;
+        let client = get_api_client_with_token_type(Some(profile_name), token_type).await?;
+        async {
+            loop {
+                let messages = match commands::fetch_new_messages(&client, &channel, &cursor).await
+                {
+                    Ok(messages) => messages,
+                    // Slack-level errors (e.g. channel_not_found) will not go away by retrying
+                    Err(e @ crate::api::ApiError::SlackError(_)) => return Err(e.to_string()),
+                    Err(e) => {
+                        eprintln!("Warning: poll failed, retrying: {}", e);
+                        Vec::new()
+                    }
+                };
+                names.resolve_missing(&client, &messages).await;
+
+                // The saved cursor never moves past a message that was not printed
+                let previous = cursor.clone();
+                let mut finished = false;
+                for message in &messages {
+                    cursor = commands::advance_cursor(&cursor, std::slice::from_ref(message));
+                    if commands::message_matches(message, &filters) {
+                        queue.push(names.annotate(&channel, message).to_string())?;
+                        dispatched += 1;
+                    }
+                    if max_events.is_some_and(|max| dispatched >= max) {
+                        finished = true;
+                        break;
+                    }
+                }
+                if cursor != previous {
+                    state.record(&team_id, &channel, Some(cursor.clone()), unix_now());
+                    state.save(&state_path)?;
+                }
+                if finished {
+                    return Ok(());
+                }
 
-        (
-            serde_json::to_value(&response).map_err(|e| e.to_string())?,
-            None,
-        )
+                tokio::time::sleep(std::time::Duration::from_secs(interval as u64)).await;
+            }
+        }
+        .await
     };
+    queue.finish()?;
+    result
+}
 
-    // Display error guidance if response contains a known error
-    if let Ok(api_response) =
-        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
-    {
-        crate::api::display_wrapper_error_guidance(&api_response);
-    }
+pub async fn run_conv_create(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let name = required_positional(
+        args,
+        3,
+        "conv create <name> [--private] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+    )?;
+    let is_private = has_flag(args, "--private");
+    let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_create(&name, is_private);
+    run_planned_write(args, "conv create", plan, async |client| {
+        commands::conv_create(client, name, is_private, yes, non_interactive).await
+    })
+    .await
+}
 
-    // Output with or without envelope
-    let output = if raw {
-        serde_json::to_string_pretty(&response_value).unwrap()
-    } else {
-        let mut wrapped = wrap_with_envelope_and_token_type(
-            response_value,
-            "chat.postMessage",
-            "msg post",
-            Some(profile_name),
-            token_type,
-        )
-        .await?;
+pub async fn run_conv_archive(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let channel = required_positional(
+        args,
+        3,
+        "conv archive <channel> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+    )?;
+    let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_archive(&channel);
+    run_planned_write(args, "conv archive", plan, async |client| {
+        commands::conv_archive(client, channel, yes, non_interactive).await
+    })
+    .await
+}
 
-        // Add idempotency metadata if key was provided
-        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
-            wrapped = wrapped.with_idempotency(
-                key,
-                match status {
-                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
-                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
-                },
-            );
-        }
+pub async fn run_conv_unarchive(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let channel = required_positional(
+        args,
+        3,
+        "conv unarchive <channel> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+    )?;
+    let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_unarchive(&channel);
+    run_planned_write(args, "conv unarchive", plan, async |client| {
+        commands::conv_unarchive(client, channel, yes, non_interactive).await
+    })
+    .await
+}
 
-        serde_json::to_string_pretty(&wrapped).unwrap()
-    };
+pub async fn run_conv_rename(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let usage = "conv rename <channel> <name> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]";
+    let channel = required_positional(args, 3, usage)?;
+    let name = required_positional(args, 4, usage)?;
+    let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_rename(&channel, &name);
+    run_planned_write(args, "conv rename", plan, async |client| {
+        commands::conv_rename(client, channel, name, yes, non_interactive).await
+    })
+    .await
+}
 
-    println!("{}", output);
-    Ok(())
+pub async fn run_conv_invite(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let usage = "conv invite <channel> <user>... [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]";
+    let channel = required_positional(args, 3, usage)?;
+    // Users may be given as separate arguments or comma-separated
+    let users: Vec<String> = args[4..]
+        .iter()
+        .take_while(|arg| !arg.starts_with("--"))
+        .flat_map(|arg| arg.split(','))
+        .map(|user| user.trim().to_string())
+        .filter(|user| !user.is_empty())
+        .collect();
+    if users.is_empty() {
+        return Err(format!("Usage: {}", usage));
+    }
+    let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_invite(&channel, &users);
+    run_planned_write(args, "conv invite", plan, async |client| {
+        commands::conv_invite(client, channel, users, yes, non_interactive).await
+    })
+    .await
 }
 
-pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<(), String> {
-    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+pub async fn run_conv_kick(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let usage = "conv kick <channel> <user> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]";
+    let channel = required_positional(args, 3, usage)?;
+    let user = required_positional(args, 4, usage)?;
+    let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_kick(&channel, &user);
+    run_planned_write(args, "conv kick", plan, async |client| {
+        commands::conv_kick(client, channel, user, yes, non_interactive).await
+    })
+    .await
+}
 
-    if args.len() < 6 {
-        return Err("Usage: msg update <channel> <ts> <text> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]".to_string());
-    }
+pub async fn run_conv_join(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let channel = required_positional(
+        args,
+        3,
+        "conv join <channel> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+    )?;
+    let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_join(&channel);
+    run_planned_write(args, "conv join", plan, async |client| {
+        commands::conv_join(client, channel, yes, non_interactive).await
+    })
+    .await
+}
 
-    let channel = args[3].clone();
-    let ts = args[4].clone();
-    let text = args[5].clone();
+pub async fn run_conv_leave(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let channel = required_positional(
+        args,
+        3,
+        "conv leave <channel> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+    )?;
     let yes = has_flag(args, "--yes");
+    let plan = commands::plan_conv_leave(&channel);
+    run_planned_write(args, "conv leave", plan, async |client| {
+        commands::conv_leave(client, channel, yes, non_interactive).await
+    })
+    .await
+}
+
+/// Shared flow for planned write subcommands (idempotency, envelope output)
+///
+/// `plan` supplies the method and the parameters fingerprinted for
+/// `--idempotency-key`; `execute` performs the guarded call. `command` is
+/// the envelope command name, e.g. `conv archive`.
+async fn run_planned_write(
+    args: &[String],
+    command: &str,
+    plan: crate::api::PlannedRequest,
+    execute: impl AsyncFnOnce(&ApiClient) -> Result<crate::api::ApiResponse, crate::api::ApiError>,
+) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    let method = plan.method.as_str().to_string();
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
     let idempotency_key = get_option(args, "--idempotency-key=");
@@ -1271,25 +2305,12 @@ pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<()
 
     let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
 
-    // Check idempotency if key provided
     let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
         let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
-
-        let mut params = serde_json::Map::new();
-        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
-        params.insert("ts".to_string(), serde_json::json!(ts.clone()));
-        params.insert("text".to_string(), serde_json::json!(text.clone()));
-
+        let params: serde_json::Map<String, Value> = plan.params.into_iter().collect();
         let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
-
         match handler
-            .check(
-                Some(key.clone()),
-                team_id,
-                user_id,
-                "chat.update".to_string(),
-                &params,
-            )
+            .check(Some(key.clone()), team_id, user_id, method.clone(), &params)
             .map_err(|e| e.to_string())?
         {
             IdempotencyCheckResult::Replay {
@@ -1299,10 +2320,7 @@ pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<()
                 key: scoped_key,
                 fingerprint,
             } => {
-                let response =
-                    commands::msg_update(&client, channel, ts, text, yes, non_interactive)
-                        .await
-                        .map_err(|e| e.to_string())?;
+                let response = execute(&client).await.map_err(|e| e.to_string())?;
                 let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
                 handler
                     .store(scoped_key, fingerprint, response_value.clone())
@@ -1315,9 +2333,7 @@ pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<()
             IdempotencyCheckResult::NoKey => unreachable!(),
         }
     } else {
-        let response = commands::msg_update(&client, channel, ts, text, yes, non_interactive)
-            .await
-            .map_err(|e| e.to_string())?;
+        let response = execute(&client).await.map_err(|e| e.to_string())?;
         (
             serde_json::to_value(&response).map_err(|e| e.to_string())?,
             None,
@@ -1335,13 +2351,12 @@ pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<()
     } else {
         let mut wrapped = wrap_with_envelope_and_token_type(
             response_value,
-            "chat.update",
-            "msg update",
+            &method,
+            command,
             Some(profile_name),
             token_type,
         )
         .await?;
-
         if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
             wrapped = wrapped.with_idempotency(
                 key,
@@ -1351,7 +2366,6 @@ pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<()
                 },
             );
         }
-
         serde_json::to_string_pretty(&wrapped).unwrap()
     };
 
@@ -1359,99 +2373,2447 @@ pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<()
     Ok(())
 }
 
-pub async fn run_msg_delete(args: &[String], non_interactive: bool) -> Result<(), String> {
-    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+pub async fn run_thread_get(args: &[String]) -> Result<(), String> {
+    // Check for --help flag before API call
+    if has_flag(args, "--help") || has_flag(args, "-h") {
+        print_thread_usage(&args[0]);
+        return Ok(());
+    }
 
+    // Parse required arguments: channel and thread_ts
     if args.len() < 5 {
-        return Err(
-            "Usage: msg delete <channel> <ts> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]"
-                .to_string(),
-        );
+        return Err("Usage: slack-rs thread get <channel> <thread_ts> [--limit=N] [--inclusive] [--raw] [--profile=NAME] [--token-type=bot|user]".to_string());
     }
 
     let channel = args[3].clone();
-    let ts = args[4].clone();
-    let yes = has_flag(args, "--yes");
+    let thread_ts = args[4].clone();
+    let limit = get_option(args, "--limit=").and_then(|s| s.parse().ok());
+    let inclusive = has_flag(args, "--inclusive");
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
-    let idempotency_key = get_option(args, "--idempotency-key=");
     let raw = should_output_raw(args);
 
-    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    // Get debug level from args
+    let debug_level = debug::get_debug_level(args);
 
-    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
-        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
-        let mut params = serde_json::Map::new();
-        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
-        params.insert("ts".to_string(), serde_json::json!(ts.clone()));
-        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
-        match handler
-            .check(
-                Some(key.clone()),
-                team_id,
-                user_id,
-                "chat.delete".to_string(),
-                &params,
-            )
-            .map_err(|e| e.to_string())?
-        {
-            IdempotencyCheckResult::Replay {
-                response, status, ..
-            } => (response, Some(status)),
-            IdempotencyCheckResult::Execute {
-                key: scoped_key,
-                fingerprint,
-            } => {
-                let response = commands::msg_delete(&client, channel, ts, yes, non_interactive)
-                    .await
-                    .map_err(|e| e.to_string())?;
-                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
-                handler
-                    .store(scoped_key, fingerprint, response_value.clone())
-                    .map_err(|e| e.to_string())?;
-                (
-                    response_value,
-                    Some(crate::idempotency::IdempotencyStatus::Executed),
-                )
-            }
-            IdempotencyCheckResult::NoKey => unreachable!(),
-        }
+    // Log debug information if --debug or --trace flag is present
+    let token_store_backend = if std::env::var("SLACK_TOKEN").is_ok() {
+        "environment"
     } else {
-        let response = commands::msg_delete(&client, channel, ts, yes, non_interactive)
-            .await
-            .map_err(|e| e.to_string())?;
-        (
+        "file"
+    };
+
+    // Resolve actual token type for debug output
+    let resolved_token_type = if let Some(explicit) = token_type {
+        explicit
+    } else {
+        let config_path = default_config_path().map_err(|e| e.to_string())?;
+        let profile = resolve_profile_full(&config_path, &profile_name)
+            .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
+
+        if let Some(default_type) = profile.default_token_type {
+            default_type
+        } else {
+            let token_store = create_token_store().map_err(|e| e.to_string())?;
+            let user_token_key = format!("{}:{}:user", profile.team_id, profile.user_id);
+            if token_store.get(&user_token_key).is_ok() {
+                TokenType::User
+            } else {
+                TokenType::Bot
+            }
+        }
+    };
+
+    let endpoint = "https://slack.com/api/conversations.replies";
+
+    debug::log_api_context(
+        debug_level,
+        Some(&profile_name),
+        token_store_backend,
+        resolved_token_type.as_str(),
+        "conversations.replies",
+        endpoint,
+    );
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let inclusive_opt = if inclusive { Some(true) } else { None };
+    let response = commands::thread_get(&client, channel, thread_ts, limit, inclusive_opt)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Log error code if present
+    debug::log_error_code(
+        debug_level,
+        &serde_json::to_value(&response).unwrap_or_default(),
+    );
+
+    // Display error guidance if response contains a known error
+    crate::api::display_wrapper_error_guidance(&response);
+
+    // Output with or without envelope
+    let output = if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "conversations.replies",
+            "thread get",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_users_info(args: &[String]) -> Result<(), String> {
+    let user = args[3].clone();
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let (format, raw) = resolve_list_output(args)?;
+
+    // Get debug level from args
+    let debug_level = debug::get_debug_level(args);
+
+    // Log debug information if --debug or --trace flag is present
+    let token_store_backend = if std::env::var("SLACK_TOKEN").is_ok() {
+        "environment"
+    } else {
+        "file"
+    };
+
+    // Resolve actual token type for debug output
+    let resolved_token_type = if let Some(explicit) = token_type {
+        explicit
+    } else {
+        let config_path = default_config_path().map_err(|e| e.to_string())?;
+        let profile = resolve_profile_full(&config_path, &profile_name)
+            .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
+
+        if let Some(default_type) = profile.default_token_type {
+            default_type
+        } else {
+            let token_store = create_token_store().map_err(|e| e.to_string())?;
+            let user_token_key = format!("{}:{}:user", profile.team_id, profile.user_id);
+            if token_store.get(&user_token_key).is_ok() {
+                TokenType::User
+            } else {
+                TokenType::Bot
+            }
+        }
+    };
+
+    let endpoint = "https://slack.com/api/users.info";
+
+    debug::log_api_context(
+        debug_level,
+        Some(&profile_name),
+        token_store_backend,
+        resolved_token_type.as_str(),
+        "users.info",
+        endpoint,
+    );
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let include_locale = resolve_display_preferences(args).include_locale;
+    let response = client
+        .execute_planned(commands::plan_users_info(&user, include_locale))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Log error code if present
+    debug::log_error_code(
+        debug_level,
+        &serde_json::to_value(&response).unwrap_or_default(),
+    );
+
+    // Display error guidance if response contains a known error
+    crate::api::display_wrapper_error_guidance(&response);
+
+    // Format output: non-JSON formats bypass raw/envelope logic
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "users info", &response, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "users.info",
+            "users info",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_users_cache_update(args: &[String]) -> Result<(), String> {
+    let profile_name = resolve_profile_name(args);
+    let force = has_flag(args, "--force");
+    let token_type = parse_token_type(args)?;
+
+    let config_path = default_config_path().map_err(|e| e.to_string())?;
+    let config = load_config(&config_path).map_err(|e| e.to_string())?;
+
+    let profile = config
+        .get(&profile_name)
+        .ok_or_else(|| format!("Profile '{}' not found", profile_name))?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    commands::update_cache(&client, profile.team_id.clone(), force)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    println!("Cache updated successfully for team {}", profile.team_id);
+    Ok(())
+}
+
+pub async fn run_users_resolve_mentions(args: &[String]) -> Result<(), String> {
+    if args.len() < 4 {
+        return Err(
+            "Usage: users resolve-mentions <text> [--profile=NAME] [--format=FORMAT]".to_string(),
+        );
+    }
+
+    let text = args[3].clone();
+    let profile_name = resolve_profile_name(args);
+    let format = match get_option(args, "--format=") {
+        Some(format_str) => format_str.parse::<commands::MentionFormat>().map_err(|_| {
+            format!(
+                "Invalid format: {}. Use display_name, real_name, or username",
+                format_str
+            )
+        })?,
+        None => resolve_display_preferences(args).user_name,
+    };
+
+    let config_path = default_config_path().map_err(|e| e.to_string())?;
+    let config = load_config(&config_path).map_err(|e| e.to_string())?;
+
+    let profile = config
+        .get(&profile_name)
+        .ok_or_else(|| format!("Profile '{}' not found", profile_name))?;
+
+    let cache_path = commands::UsersCacheFile::default_path()?;
+    let cache_file = commands::UsersCacheFile::load(&cache_path)?;
+
+    let workspace_cache = cache_file.get_workspace(&profile.team_id).ok_or_else(|| {
+        format!(
+            "No cache found for team {}. Run 'users cache-update' first.",
+            profile.team_id
+        )
+    })?;
+
+    let result = commands::resolve_mentions(&text, workspace_cache, format);
+    println!("{}", result);
+    Ok(())
+}
+
+/// Rewrite `@name` / `#channel` tokens of outgoing text (`--resolve-mentions`)
+///
+/// Users come from the users cache, channels from the shell's channel cache;
+/// channel names missing there are looked up with one `conversations.list`
+/// call. Tokens that still do not resolve are sent as typed, with a warning.
+async fn encode_outgoing_mentions(
+    text: &str,
+    profile_name: &str,
+    token_type: Option<TokenType>,
+) -> Result<String, String> {
+    let (team_id, _) = get_team_and_user_ids_from_profile(profile_name).await?;
+    let cache_path = commands::UsersCacheFile::default_path()?;
+    let cache_file = commands::UsersCacheFile::load(&cache_path)?;
+    let workspace_cache = cache_file.get_workspace(&team_id).ok_or_else(|| {
+        format!(
+            "No cache found for team {}. Run 'users cache-update' first.",
+            team_id
+        )
+    })?;
+
+    let mut channels: std::collections::HashMap<String, String> =
+        shell::ChannelNameCache::load(&shell::ChannelNameCache::default_path()?)
+            .channels(&team_id)
+            .into_iter()
+            .map(|c| (c.name.to_lowercase(), c.id))
+            .collect();
+    let mut encoded = commands::encode_mentions(text, workspace_cache, &channels);
+    if encoded.unresolved.iter().any(|t| t.starts_with('#')) {
+        let client =
+            get_api_client_with_token_type(Some(profile_name.to_string()), token_type).await?;
+        let response = commands::conv_list(
+            &client,
+            Some("public_channel,private_channel".to_string()),
+            None,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        channels.extend(
+            response
+                .data
+                .get("channels")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|c| {
+                    Some((
+                        c.get("name")?.as_str()?.to_lowercase(),
+                        c.get("id")?.as_str()?.to_string(),
+                    ))
+                }),
+        );
+        encoded = commands::encode_mentions(text, workspace_cache, &channels);
+    }
+    if !encoded.unresolved.is_empty() {
+        crate::gha::warn(&format!(
+            "Could not resolve {}; sent as typed",
+            encoded.unresolved.join(", ")
+        ));
+    }
+    Ok(encoded.text)
+}
+
+pub async fn run_users_tz_report(args: &[String]) -> Result<(), String> {
+    const USAGE: &str = "Usage: users tz-report [<user_id>...] [--channel=ID] [--hours=HH:MM-HH:MM] [--duration=MINUTES] [--json] [--profile=NAME] [--token-type=bot|user]";
+    const VALUE_FLAGS: &[&str] = &[
+        "--channel",
+        "--hours",
+        "--duration",
+        "--profile",
+        "--token-type",
+    ];
+
+    // Positional user IDs, skipping values of space-separated flags
+    let mut user_ids = Vec::new();
+    let mut i = 3;
+    while i < args.len() {
+        let arg = &args[i];
+        if VALUE_FLAGS.contains(&arg.as_str()) {
+            i += 2;
+            continue;
+        }
+        if !arg.starts_with('-') {
+            user_ids.push(arg.clone());
+        }
+        i += 1;
+    }
+
+    let channel = get_option(args, "--channel=");
+    if user_ids.is_empty() && channel.is_none() {
+        return Err(USAGE.to_string());
+    }
+
+    let hours = match get_option(args, "--hours=") {
+        Some(spec) => commands::WorkingHours::parse(&spec)?,
+        None => commands::WorkingHours::default(),
+    };
+    let duration = match get_option(args, "--duration=") {
+        Some(value) => value
+            .parse::<u32>()
+            .ok()
+            .filter(|d| *d > 0 && *d <= 24 * 60)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid --duration '{}'. Expected minutes between 1 and 1440",
+                    value
+                )
+            })?,
+        None => 30,
+    };
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    if let Some(channel) = channel {
+        for member in commands::fetch_channel_members(&client, &channel)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            if !user_ids.contains(&member) {
+                user_ids.push(member);
+            }
+        }
+    }
+
+    // Prefer timezones from the users cache to avoid one users.info call per user
+    let workspace_cache = match (
+        get_team_and_user_ids_from_profile(&profile_name).await,
+        commands::UsersCacheFile::default_path(),
+    ) {
+        (Ok((team_id, _)), Ok(cache_path)) => commands::UsersCacheFile::load(&cache_path)
+            .ok()
+            .and_then(|cache| cache.get_workspace(&team_id).cloned()),
+        _ => None,
+    };
+
+    let display = resolve_display_preferences(args);
+    let users = commands::resolve_user_timezones(
+        &client,
+        &user_ids,
+        workspace_cache.as_ref(),
+        display.user_name,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let report = commands::build_tz_report(users, hours, duration);
+
+    if has_flag(args, "--json") {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?
+        );
+    } else {
+        print!(
+            "{}",
+            commands::render_tz_table(&report, display.time_format)
+        );
+    }
+    Ok(())
+}
+
+pub async fn run_users_offboard_report(args: &[String]) -> Result<(), String> {
+    const USAGE: &str =
+        "users offboard-report <user_id> [--json] [--profile=NAME] [--token-type=bot|user]";
+    let user = required_positional(args, 3, USAGE)?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    // chat.scheduledMessages.list only covers the token's own user
+    let include_scheduled = matches!(
+        get_team_and_user_ids_from_profile(&profile_name).await,
+        Ok((_, user_id)) if user_id == user
+    );
+
+    let report = commands::offboard_report(&client, &user, include_scheduled)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if has_flag(args, "--json") {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?
+        );
+    } else {
+        print!("{}", commands::format_offboard_text(&report));
+    }
+    Ok(())
+}
+
+pub async fn run_msg_inspect(args: &[String]) -> Result<(), String> {
+    const USAGE: &str =
+        "msg inspect <permalink> [--json] [--raw] [--profile=NAME] [--token-type=bot|user]";
+    let permalink = required_positional(args, 3, USAGE)?;
+    let Some(commands::ThreadRef::Permalink {
+        channel,
+        ts,
+        thread_ts,
+    }) = commands::parse_permalink(&permalink)
+    else {
+        return Err(format!("Not a message permalink: {}", permalink));
+    };
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name), token_type).await?;
+    let report = commands::msg_inspect(&client, &channel, &ts, thread_ts.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if has_flag(args, "--raw") {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report.message).map_err(|e| e.to_string())?
+        );
+    } else if has_flag(args, "--json") {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?
+        );
+    } else {
+        print!("{}", commands::format_inspect_text(&report));
+    }
+    Ok(())
+}
+
+/// Get team_id and user_id from profile
+async fn get_team_and_user_ids_from_profile(
+    profile_name: &str,
+) -> Result<(String, String), String> {
+    let config_path = default_config_path().map_err(|e| e.to_string())?;
+    let profile = resolve_profile_full(&config_path, profile_name)
+        .map_err(|e| format!("Failed to resolve profile '{}': {}", profile_name, e))?;
+    Ok((profile.team_id, profile.user_id))
+}
+
+/// Read message text from a file, or from stdin for `-`
+fn read_message_text(source: &str) -> Result<String, String> {
+    let text = if source == "-" {
+        let mut text = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut text)
+            .map_err(|e| format!("Failed to read text from stdin: {}", e))?;
+        text
+    } else {
+        std::fs::read_to_string(source)
+            .map_err(|e| format!("Failed to read text from {}: {}", source, e))?
+    };
+    let text = text.trim_end().to_string();
+    if text.is_empty() {
+        return Err(format!(
+            "Error: message text from {} is empty",
+            if source == "-" { "stdin" } else { source }
+        ));
+    }
+    Ok(text)
+}
+
+pub async fn run_msg_post(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    let blocks = get_option(args, "--blocks=")
+        .map(|spec| commands::load_blocks(&spec))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    let positional_text = args
+        .get(4)
+        .filter(|a| blocks.is_none() || !a.starts_with("--"))
+        .cloned();
+    // --text=VALUE|- and --text-file=PATH|- replace the positional text
+    let option_text = match (
+        get_option(args, "--text="),
+        get_option(args, "--text-file="),
+    ) {
+        (Some(_), Some(_)) => {
+            return Err("Error: --text cannot be combined with --text-file".to_string())
+        }
+        (Some(text), None) if text != "-" => Some(text),
+        (Some(source), None) | (None, Some(source)) => {
+            if source == "-" && get_option(args, "--blocks=").as_deref() == Some("-") {
+                return Err(
+                    "Error: only one of the text and --blocks can be read from stdin".to_string(),
+                );
+            }
+            Some(read_message_text(&source)?)
+        }
+        (None, None) => None,
+    };
+    let text_arg = match option_text {
+        Some(_)
+            if positional_text
+                .as_deref()
+                .is_some_and(|a| !a.starts_with("--")) =>
+        {
+            return Err(
+                "Error: give the text either as an argument or with --text/--text-file".to_string(),
+            )
+        }
+        Some(text) => Some(text),
+        None => positional_text,
+    };
+    if args.len() < 4 || (text_arg.is_none() && blocks.is_none()) {
+        return Err("Usage: msg post <channel> [<text> | --text=TEXT|- | --text-file=PATH|-] [--blocks=JSON|@FILE|-] [--thread-ts=TS|PERMALINK|latest[:#channel][:from=@name]] [--reply-broadcast] [--split] [--split-limit=N] [--auto-snippet] [--markdown] [--from=markdown] [--resolve-mentions] [--pin] [--react=EMOJI,...] [--no-rollback] [--notify-group=HANDLE,...] [--no-footer] [--preview] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY] [--unique-per=day|hour]".to_string());
+    }
+
+    // --preview asks on the terminal, so the message cannot come from stdin
+    let preview = has_flag(args, "--preview");
+    if preview {
+        if non_interactive {
+            return Err("Error: --preview requires an interactive terminal".to_string());
+        }
+        let from_stdin = ["--text=", "--text-file=", "--blocks="]
+            .iter()
+            .any(|prefix| get_option(args, prefix).as_deref() == Some("-"));
+        if from_stdin {
+            return Err("Error: --preview cannot be used when reading from stdin".to_string());
+        }
+    }
+
+    let channel = args[3].clone();
+    // With --blocks, text is optional and used as the notification fallback
+    let text = match (text_arg, &blocks) {
+        (Some(text), _) => match commands::mrkdwn::source_format(
+            get_option(args, "--from="),
+            has_flag(args, "--markdown"),
+        )? {
+            Some(format) => commands::mrkdwn::convert_from(&format, &text)?,
+            None => text,
+        },
+        (None, Some(blocks)) => commands::blocks::fallback_text(blocks),
+        (None, None) => unreachable!(),
+    };
+    let thread_ts = get_option(args, "--thread-ts=");
+    let reply_broadcast = has_flag(args, "--reply-broadcast");
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let text = if has_flag(args, "--resolve-mentions") {
+        encode_outgoing_mentions(&text, &profile_name, token_type).await?
+    } else {
+        text
+    };
+    // --unique-per keys on the final text; the key itself needs the
+    // resolved channel and thread, so it is derived after the client exists
+    let unique_per = match get_option(args, "--unique-per=") {
+        Some(window) => {
+            if get_option(args, "--idempotency-key=").is_some() {
+                return Err(
+                    "Error: --unique-per cannot be combined with --idempotency-key".to_string(),
+                );
+            }
+            Some((
+                crate::idempotency::UniqueWindow::parse(&window)?,
+                text.clone(),
+            ))
+        }
+        None => None,
+    };
+    // output.post_footer: a last line of text, or a context block with --blocks
+    let footer = resolve_post_footer(args);
+    let blocks = match (&footer, blocks) {
+        (Some(footer), Some(blocks)) => {
+            let blocks = commands::append_footer_block(&blocks, footer);
+            commands::validate_blocks(&blocks).map_err(|e| e.to_string())?;
+            Some(blocks)
+        }
+        (_, blocks) => blocks,
+    };
+    // The text footer is kept apart from the body so truncation never cuts it
+    let text_footer = footer.filter(|_| blocks.is_none());
+    let body = text;
+    let text = match &text_footer {
+        Some(footer) => commands::append_footer_text(&body, footer),
+        None => body.clone(),
+    };
+    let follow_ups = commands::PostFollowUps {
+        pin: has_flag(args, "--pin"),
+        reactions: get_option(args, "--react=")
+            .map(|v| commands::PostFollowUps::parse_reactions(&v))
+            .unwrap_or_default(),
+    };
+    let rollback = !has_flag(args, "--no-rollback");
+    let notify_groups = split_list_option(args, "--notify-group=");
+    if !notify_groups.is_empty() && blocks.is_some() {
+        return Err("Error: --notify-group cannot be combined with --blocks".to_string());
+    }
+
+    // Validate: --reply-broadcast requires --thread-ts
+    if reply_broadcast && thread_ts.is_none() {
+        return Err("Error: --reply-broadcast requires --thread-ts".to_string());
+    }
+
+    // --split: chunk long text and thread the remainder under the first chunk
+    let split_limit = match get_option(args, "--split-limit=") {
+        Some(value) => Some(
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|n| *n >= commands::split::MIN_SPLIT_LIMIT)
+                .ok_or_else(|| {
+                    format!(
+                        "Error: --split-limit must be a number of at least {}",
+                        commands::split::MIN_SPLIT_LIMIT
+                    )
+                })?,
+        ),
+        None if has_flag(args, "--split") => Some(commands::DEFAULT_SPLIT_LIMIT),
+        None => None,
+    };
+    if split_limit.is_some() && blocks.is_some() {
+        return Err("Error: --split cannot be combined with --blocks".to_string());
+    }
+
+    // Text beyond the message limit: upload it as a snippet with --auto-snippet,
+    // or offer to when running interactively
+    let auto_snippet = has_flag(args, "--auto-snippet");
+    if auto_snippet && split_limit.is_some() {
+        return Err("Error: --auto-snippet cannot be combined with --split".to_string());
+    }
+    let too_long = blocks.is_none()
+        && split_limit.is_none()
+        && text.chars().count() > commands::SNIPPET_THRESHOLD;
+    let mut yes = yes;
+    let as_snippet = if !too_long {
+        false
+    } else if auto_snippet {
+        true
+    } else if !yes && !non_interactive {
+        let operation = format!(
+            "upload this {}-character text as a snippet instead of a message",
+            text.chars().count()
+        );
+        let accepted =
+            crate::commands::guards::confirm_destructive(false, &operation, false).is_ok();
+        // Accepting the snippet also confirms posting it
+        yes = accepted;
+        accepted
+    } else {
+        crate::gha::warn(&format!(
+            "Warning: text is {} characters; Slack may truncate it (use --auto-snippet or --split)",
+            text.chars().count()
+        ));
+        false
+    };
+    if as_snippet && !follow_ups.is_empty() {
+        return Err("Error: --pin/--react are not supported when posting as a snippet".to_string());
+    }
+    let mode = match split_limit {
+        Some(limit) => PostMode::Split(limit),
+        None if as_snippet => PostMode::Snippet,
+        None => PostMode::Message,
+    };
+    let (format, raw) = resolve_list_output(args)?;
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    // --notify-group: mention usergroups by handle; unknown handles fail before
+    // posting. Resolved before the length check so the mentions count too.
+    let mention_prefix = if notify_groups.is_empty() {
+        String::new()
+    } else {
+        let (team_id, _) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        let mentions =
+            commands::resolve_usergroup_mentions(&client, team_id, &notify_groups).await?;
+        format!("{} ", mentions.join(" "))
+    };
+    let text = format!("{}{}", mention_prefix, text);
+
+    // Beyond Slack's hard limit the text would be cut off by the API anyway;
+    // cut it here at a line break and say so
+    let text = if matches!(mode, PostMode::Message)
+        && blocks.is_none()
+        && text.chars().count() > commands::MAX_MESSAGE_CHARS
+    {
+        crate::gha::warn(&format!(
+            "Warning: text is {} characters; truncated to {} (use --split to post it as a thread)",
+            text.chars().count(),
+            commands::MAX_MESSAGE_CHARS
+        ));
+        let suffix = match &text_footer {
+            Some(footer) => format!("\n{}", footer),
+            None => String::new(),
+        };
+        commands::truncate_message_body(
+            &mention_prefix,
+            &body,
+            &suffix,
+            commands::MAX_MESSAGE_CHARS,
+        )
+    } else {
+        text
+    };
+
+    // Resolve permalinks and `latest:...` references to the thread root ts
+    let thread_ts = match thread_ts {
+        Some(value) => {
+            let thread_ref = commands::ThreadRef::parse(&value).map_err(|e| e.to_string())?;
+            Some(
+                commands::resolve_thread_ts(&client, &thread_ref, &channel)
+                    .await
+                    .map_err(|e| e.to_string())?,
+            )
+        }
+        None => None,
+    };
+
+    let idempotency_key = match unique_per {
+        Some((window, unique_text)) => {
+            // `#general` and its ID must share a key; user IDs (DMs) are
+            // not conversation names and are keyed as given
+            let channel_id = match commands::resolve_channel_id(&client, &channel).await {
+                Ok(id) => id,
+                Err(commands::ThreadRefError::ChannelNotFound(_)) => channel.clone(),
+                Err(e) => return Err(e.to_string()),
+            };
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            Some(window.derive_key(&channel_id, thread_ts.as_deref(), &unique_text, now))
+        }
+        None => get_option(args, "--idempotency-key="),
+    };
+
+    // --preview: show the message as it will read and ask before sending
+    if preview {
+        let users = match (
+            get_team_and_user_ids_from_profile(&profile_name).await,
+            commands::UsersCacheFile::default_path(),
+        ) {
+            (Ok((team_id, _)), Ok(cache_path)) => commands::UsersCacheFile::load(&cache_path)
+                .ok()
+                .and_then(|cache| cache.get_workspace(&team_id).cloned()),
+            _ => None,
+        };
+        let lines = commands::render_post_preview(
+            &commands::PostPreview {
+                channel: &channel,
+                thread_ts: thread_ts.as_deref(),
+                reply_broadcast,
+                text: &text,
+                blocks: blocks.as_ref(),
+                split_chunks: match mode {
+                    PostMode::Split(limit) => Some(commands::split_message(&text, limit).len()),
+                    _ => None,
+                },
+                as_snippet: matches!(mode, PostMode::Snippet),
+                follow_ups: &follow_ups,
+            },
+            users.as_ref(),
+        );
+        for line in lines {
+            eprintln!("{}", line);
+        }
+        crate::commands::guards::confirm_destructive(false, "send this message", false)
+            .map_err(|e| e.to_string())?;
+        // The preview confirmation replaces the usual one
+        yes = true;
+    }
+
+    // Check idempotency if key provided
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+
+        // Fingerprint the exact request that would be sent
+        let params: serde_json::Map<String, serde_json::Value> = commands::plan_msg_post(
+            &channel,
+            &text,
+            blocks.as_ref(),
+            thread_ts.as_deref(),
+            reply_broadcast,
+        )
+        .params
+        .into_iter()
+        .collect();
+
+        // Get team_id and user_id from profile
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id.clone(),
+                user_id.clone(),
+                "chat.postMessage".to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => {
+                // Return cached response
+                (response, Some(status))
+            }
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                // Execute and store
+                let response_value = post_message_value(
+                    &client,
+                    channel,
+                    text,
+                    blocks,
+                    thread_ts,
+                    reply_broadcast,
+                    mode,
+                    yes,
+                    non_interactive,
+                )
+                .await?;
+
+                // Store result; a partially posted split is not replayed
+                if response_value.pointer("/split/error").is_none() {
+                    handler
+                        .store(scoped_key, fingerprint, response_value.clone())
+                        .map_err(|e| e.to_string())?;
+                }
+
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        // No idempotency key - execute normally
+        let response_value = post_message_value(
+            &client,
+            channel,
+            text,
+            blocks,
+            thread_ts,
+            reply_broadcast,
+            mode,
+            yes,
+            non_interactive,
+        )
+        .await?;
+
+        (response_value, None)
+    };
+    let split_error = response_value
+        .pointer("/split/error")
+        .and_then(|v| v.as_str())
+        .map(|e| format!("Split post stopped at {}; earlier chunks remain posted", e));
+
+    // Display error guidance if response contains a known error
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+
+    // Composite follow-ups (--pin / --react) run only when the post was executed now
+    let follow_up_report = if follow_ups.is_empty()
+        || idempotency_status == Some(crate::idempotency::IdempotencyStatus::Replayed)
+    {
+        None
+    } else {
+        let posted_channel = response_value
+            .get("channel")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| "Posted message response has no channel".to_string())?;
+        let posted_ts = response_value
+            .get("ts")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| "Posted message response has no ts".to_string())?;
+        Some(
+            commands::run_post_follow_ups(
+                &client,
+                posted_channel,
+                posted_ts,
+                &follow_ups,
+                rollback,
+            )
+            .await,
+        )
+    };
+    let follow_up_error = follow_up_report.as_ref().and_then(|report| {
+        report.failed_step().map(|failed| {
+            let outcome = if report.rolled_back {
+                "message was deleted (rolled back)".to_string()
+            } else if let Some(ref e) = report.rollback_error {
+                format!("rollback failed, message remains posted: {}", e)
+            } else {
+                "message remains posted".to_string()
+            };
+            format!(
+                "Follow-up step '{}' failed: {}; {}",
+                failed.step,
+                failed.error.as_deref().unwrap_or("unknown error"),
+                outcome
+            )
+        })
+    });
+
+    // --gha: expose channel, ts and permalink as step outputs
+    if crate::gha::enabled() {
+        let mut outputs = crate::gha::response_outputs(&response_value);
+        if let (Some(channel), Some(ts)) = (
+            response_value.get("channel").and_then(|v| v.as_str()),
+            response_value.get("ts").and_then(|v| v.as_str()),
+        ) {
+            match commands::msg_permalink(&client, channel, ts).await {
+                Ok(permalink) => outputs.push(("permalink".to_string(), permalink)),
+                Err(e) => crate::gha::warn(&format!("Warning: could not fetch permalink: {}", e)),
+            }
+        }
+        if let Err(e) = crate::gha::write_outputs(&outputs) {
+            crate::gha::warn(&format!("Warning: {}", e));
+        }
+    }
+
+    // Format output: non-JSON formats bypass raw/envelope logic
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "msg post", &response_value, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "chat.postMessage",
+            "msg post",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+
+        if let Some(ref report) = follow_up_report {
+            wrapped = wrapped.with_follow_ups(
+                serde_json::to_value(report).map_err(|e| e.to_string())?,
+                report.failed_step().is_none(),
+            );
+        }
+
+        // Add idempotency metadata if key was provided
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+
+    match follow_up_error.or(split_error) {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// How `msg post` delivers its text
+#[derive(Debug, Clone, Copy)]
+enum PostMode {
+    /// A single `chat.postMessage`
+    Message,
+    /// Chunks of at most this many characters, threaded (`--split`)
+    Split(usize),
+    /// A text snippet file with a lead-in message (`--auto-snippet`)
+    Snippet,
+}
+
+/// Post `msg post` content as one message, a split thread or a snippet
+///
+/// With [`PostMode::Split`], the response is the first chunk's with a
+/// `split` report (`chunks`, `thread_ts`, every `ts`, and the `error` of a
+/// chunk that could not be posted). With [`PostMode::Snippet`], it holds
+/// the shared file and a `snippet` object linking it to the lead-in.
+#[allow(clippy::too_many_arguments)]
+async fn post_message_value(
+    client: &crate::api::ApiClient,
+    channel: String,
+    text: String,
+    blocks: Option<serde_json::Value>,
+    thread_ts: Option<String>,
+    reply_broadcast: bool,
+    mode: PostMode,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<serde_json::Value, String> {
+    let limit = match mode {
+        PostMode::Message => {
+            let response = commands::msg_post_with_blocks(
+                client,
+                channel,
+                text,
+                blocks,
+                thread_ts,
+                reply_broadcast,
+                yes,
+                non_interactive,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+            return serde_json::to_value(&response).map_err(|e| e.to_string());
+        }
+        PostMode::Snippet => {
+            let lead_in = commands::snippet_lead_in(&text);
+            return commands::file_upload_snippet(
+                client,
+                channel,
+                text,
+                lead_in,
+                thread_ts,
+                yes,
+                non_interactive,
+            )
+            .await
+            .map_err(|e| e.to_string());
+        }
+        PostMode::Split(limit) => limit,
+    };
+
+    let chunks = commands::split_message(&text, limit);
+    let (response, report) = commands::msg_post_split(
+        client,
+        channel,
+        chunks,
+        thread_ts,
+        reply_broadcast,
+        yes,
+        non_interactive,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let mut value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+    value["split"] = serde_json::to_value(&report).map_err(|e| e.to_string())?;
+    Ok(value)
+}
+
+pub async fn run_msg_update(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    if args.len() < 6 {
+        return Err("Usage: msg update <channel> <ts> <text> [--markdown] [--from=markdown] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]".to_string());
+    }
+
+    let channel = args[3].clone();
+    let ts = args[4].clone();
+    let text = match commands::mrkdwn::source_format(
+        get_option(args, "--from="),
+        has_flag(args, "--markdown"),
+    )? {
+        Some(format) => commands::mrkdwn::convert_from(&format, &args[5])?,
+        None => args[5].clone(),
+    };
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let idempotency_key = get_option(args, "--idempotency-key=");
+    let (format, raw) = resolve_list_output(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    // Check idempotency if key provided
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+
+        let mut params = serde_json::Map::new();
+        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
+        params.insert("ts".to_string(), serde_json::json!(ts.clone()));
+        params.insert("text".to_string(), serde_json::json!(text.clone()));
+
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id,
+                user_id,
+                "chat.update".to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => (response, Some(status)),
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                let response =
+                    commands::msg_update(&client, channel, ts, text, yes, non_interactive)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+                handler
+                    .store(scoped_key, fingerprint, response_value.clone())
+                    .map_err(|e| e.to_string())?;
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        let response = commands::msg_update(&client, channel, ts, text, yes, non_interactive)
+            .await
+            .map_err(|e| e.to_string())?;
+        (
+            serde_json::to_value(&response).map_err(|e| e.to_string())?,
+            None,
+        )
+    };
+
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+    crate::gha::record_response(&response_value);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "msg update", &response_value, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "chat.update",
+            "msg update",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_msg_delete(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    if args.len() < 5 {
+        return Err(
+            "Usage: msg delete <channel> <ts> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]"
+                .to_string(),
+        );
+    }
+
+    let channel = args[3].clone();
+    let ts = args[4].clone();
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let idempotency_key = get_option(args, "--idempotency-key=");
+    let (format, raw) = resolve_list_output(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        let mut params = serde_json::Map::new();
+        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
+        params.insert("ts".to_string(), serde_json::json!(ts.clone()));
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id,
+                user_id,
+                "chat.delete".to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => (response, Some(status)),
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                let response = commands::msg_delete(&client, channel, ts, yes, non_interactive)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+                handler
+                    .store(scoped_key, fingerprint, response_value.clone())
+                    .map_err(|e| e.to_string())?;
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        let response = commands::msg_delete(&client, channel, ts, yes, non_interactive)
+            .await
+            .map_err(|e| e.to_string())?;
+        (
+            serde_json::to_value(&response).map_err(|e| e.to_string())?,
+            None,
+        )
+    };
+
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "msg delete", &response_value, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "chat.delete",
+            "msg delete",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_msg_schedule(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    let usage = "Usage: msg schedule <channel> <text> (--at=TIME | --in=DURATION) [--thread-ts=TS] [--no-footer] [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]";
+    let positional: Vec<&String> = args[3..]
+        .iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .take(2)
+        .collect();
+    let [channel, text] = positional[..] else {
+        return Err(usage.to_string());
+    };
+    let channel = channel.clone();
+    let text = match resolve_post_footer(args) {
+        Some(footer) => commands::append_footer_text(text, &footer),
+        None => text.clone(),
+    };
+
+    let post_at = commands::resolve_post_at(
+        get_option(args, "--at=").as_deref(),
+        get_option(args, "--in=").as_deref(),
+        &chrono::Local::now(),
+    )?;
+    let thread_ts = get_option(args, "--thread-ts=");
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let idempotency_key = get_option(args, "--idempotency-key=");
+    let (format, raw) = resolve_list_output(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        // post_at is left out so a retry with a relative --in still replays
+        let mut params = serde_json::Map::new();
+        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
+        params.insert("text".to_string(), serde_json::json!(text.clone()));
+        params.insert(
+            "thread_ts".to_string(),
+            serde_json::json!(thread_ts.clone()),
+        );
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id,
+                user_id,
+                "chat.scheduleMessage".to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => (response, Some(status)),
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                let response = commands::msg_schedule(
+                    &client,
+                    channel,
+                    text,
+                    post_at,
+                    thread_ts,
+                    yes,
+                    non_interactive,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+                handler
+                    .store(scoped_key, fingerprint, response_value.clone())
+                    .map_err(|e| e.to_string())?;
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        let response = commands::msg_schedule(
+            &client,
+            channel,
+            text,
+            post_at,
+            thread_ts,
+            yes,
+            non_interactive,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        (
+            serde_json::to_value(&response).map_err(|e| e.to_string())?,
+            None,
+        )
+    };
+
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+    crate::gha::record_response(&response_value);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "msg schedule", &response_value, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "chat.scheduleMessage",
+            "msg schedule",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_msg_scheduled_list(args: &[String]) -> Result<(), String> {
+    let channel = get_option(args, "--channel=");
+    let limit = get_option(args, "--limit=").and_then(|s| s.parse().ok());
+    let cursor = get_option(args, "--cursor=");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let (format, raw) = resolve_list_output(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let response = commands::msg_scheduled_list(&client, channel, limit, cursor)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    crate::api::display_wrapper_error_guidance(&response);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "msg scheduled list", &response, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "chat.scheduledMessages.list",
+            "msg scheduled list",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_msg_scheduled_delete(
+    args: &[String],
+    non_interactive: bool,
+) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    if args.len() < 6 {
+        return Err(
+            "Usage: msg scheduled delete <channel> <scheduled_message_id> [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]"
+                .to_string(),
+        );
+    }
+
+    let channel = args[4].clone();
+    let scheduled_message_id = args[5].clone();
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let idempotency_key = get_option(args, "--idempotency-key=");
+    let raw = should_output_raw(args);
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        let mut params = serde_json::Map::new();
+        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
+        params.insert(
+            "scheduled_message_id".to_string(),
+            serde_json::json!(scheduled_message_id.clone()),
+        );
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id,
+                user_id,
+                "chat.deleteScheduledMessage".to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => (response, Some(status)),
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                let response = commands::msg_scheduled_delete(
+                    &client,
+                    channel,
+                    scheduled_message_id,
+                    yes,
+                    non_interactive,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+                handler
+                    .store(scoped_key, fingerprint, response_value.clone())
+                    .map_err(|e| e.to_string())?;
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        let response = commands::msg_scheduled_delete(
+            &client,
+            channel,
+            scheduled_message_id,
+            yes,
+            non_interactive,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        (
+            serde_json::to_value(&response).map_err(|e| e.to_string())?,
+            None,
+        )
+    };
+
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+
+    let output = if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "chat.deleteScheduledMessage",
+            "msg scheduled delete",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+/// `--check-emoji`: resolve aliases and warn about unknown names using the emoji cache
+async fn check_reaction_emoji(
+    args: &[String],
+    client: &ApiClient,
+    profile_name: &str,
+    emoji: String,
+) -> Result<String, String> {
+    if !has_flag(args, "--check-emoji") {
+        return Ok(emoji);
+    }
+    let (team_id, _) = get_team_and_user_ids_from_profile(profile_name).await?;
+    let (cache, _) = commands::load_emoji(client, team_id, false).await?;
+    match commands::check_emoji_name(&emoji, &cache) {
+        commands::EmojiCheck::Known(resolved) => {
+            if resolved != emoji {
+                eprintln!("Note: ':{}:' is an alias of ':{}:'", emoji, resolved);
+            }
+            Ok(resolved)
+        }
+        commands::EmojiCheck::Unknown { suggestions } => {
+            let hint = if suggestions.is_empty() {
+                String::new()
+            } else {
+                format!(" (did you mean: {})", suggestions.join(", "))
+            };
+            crate::gha::warn(&format!(
+                "Warning: ':{}:' is not a custom emoji of this workspace; Slack answers invalid_name unless it is a standard emoji{}",
+                emoji, hint
+            ));
+            Ok(emoji)
+        }
+    }
+}
+
+pub async fn run_msg_bulk(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use crate::idempotency::IdempotencyHandler;
+    use std::io::Write;
+
+    let input = get_option(args, "--input=").ok_or_else(|| {
+        "Usage: msg bulk --input=PATH|- [--input-format=jsonl|csv] [--rate=N/s|N/m] [--dry-run] [--report=PATH] [--resume=REPORT] [--no-footer] [--yes] [--profile=NAME] [--token-type=bot|user]".to_string()
+    })?;
+    let format = match get_option(args, "--input-format=") {
+        Some(format) => commands::BulkFormat::parse(&format)?,
+        None => commands::BulkFormat::from_path(&input),
+    };
+    let content = if input == "-" {
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut content)
+            .map_err(|e| format!("Failed to read stdin: {}", e))?;
+        content
+    } else {
+        std::fs::read_to_string(&input).map_err(|e| format!("Failed to read {}: {}", input, e))?
+    };
+    let mut rows = commands::parse_bulk_input(&content, format)?;
+    // Keys are derived before the footer is added so --resume still matches
+    // reports written on another host or day
+    if let Some(footer) = resolve_post_footer(args) {
+        for row in &mut rows {
+            row.idempotency_key = Some(row.key());
+            row.text = commands::append_footer_text(&row.text, &footer);
+        }
+    }
+    let rate = get_option(args, "--rate=")
+        .map(|rate| commands::Rate::parse(&rate))
+        .transpose()?
+        .unwrap_or_default();
+    let completed = match get_option(args, "--resume=") {
+        Some(path) => commands::completed_keys(
+            &std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read report {}: {}", path, e))?,
+        ),
+        None => Default::default(),
+    };
+    let options = commands::BulkOptions {
+        rate,
+        dry_run: has_flag(args, "--dry-run"),
+        completed,
+    };
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let mut handler = None;
+    let idempotency = if options.dry_run {
+        None
+    } else {
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        let handler = handler.insert(IdempotencyHandler::new().map_err(|e| e.to_string())?);
+        Some(commands::BulkIdempotency {
+            handler,
+            team_id,
+            user_id,
+        })
+    };
+
+    let report_path = get_option(args, "--report=");
+    let mut report: Box<dyn Write> = match &report_path {
+        Some(path) => Box::new(
+            std::fs::File::create(path)
+                .map_err(|e| format!("Failed to create report {}: {}", path, e))?,
+        ),
+        None => Box::new(std::io::stdout()),
+    };
+    let results = commands::msg_bulk(
+        &client,
+        &rows,
+        &options,
+        idempotency,
+        yes,
+        non_interactive,
+        |result| {
+            if let Ok(line) = serde_json::to_string(result) {
+                let _ = writeln!(report, "{}", line);
+                let _ = report.flush();
+            }
+        },
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let count =
+        |status: commands::BulkStatus| results.iter().filter(|r| r.status == status).count();
+    let failed = count(commands::BulkStatus::Failed);
+    if options.dry_run {
+        eprintln!(
+            "Dry run: {} row(s) would be posted, {} skipped",
+            count(commands::BulkStatus::DryRun),
+            count(commands::BulkStatus::Skipped)
+        );
+    } else {
+        eprintln!(
+            "Posted {}, replayed {}, skipped {}, failed {} of {} row(s)",
+            count(commands::BulkStatus::Posted),
+            count(commands::BulkStatus::Replayed),
+            count(commands::BulkStatus::Skipped),
+            failed,
+            rows.len()
+        );
+    }
+    if failed > 0 {
+        return Err(format!(
+            "{} row(s) failed; re-run with --resume={} to retry only those",
+            failed,
+            report_path.as_deref().unwrap_or("<report>")
+        ));
+    }
+    Ok(())
+}
+
+pub async fn run_emoji_list(args: &[String]) -> Result<(), String> {
+    let refresh = has_flag(args, "--refresh");
+    let (format, raw) = resolve_list_output(args)?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let (team_id, _) = get_team_and_user_ids_from_profile(&profile_name).await?;
+    let (cache, refreshed) = commands::load_emoji(&client, team_id, refresh).await?;
+    let response = commands::emoji_list_response(&cache, !refreshed);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "emoji list", &response, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "emoji.list",
+            "emoji list",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_react_add(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    if args.len() < 6 {
+        return Err(
+            "Usage: react add <channel> <ts> <emoji> [--check-emoji] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]"
+                .to_string(),
+        );
+    }
+
+    let channel = args[3].clone();
+    let ts = args[4].clone();
+    let emoji = commands::normalize_emoji_name(&args[5]);
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let idempotency_key = get_option(args, "--idempotency-key=");
+    let raw = should_output_raw(args);
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let emoji = check_reaction_emoji(args, &client, &profile_name, emoji).await?;
+
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        let mut params = serde_json::Map::new();
+        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
+        params.insert("timestamp".to_string(), serde_json::json!(ts.clone()));
+        params.insert("name".to_string(), serde_json::json!(emoji.clone()));
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id,
+                user_id,
+                "reactions.add".to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => (response, Some(status)),
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                let response =
+                    commands::react_add(&client, channel, ts, emoji, yes, non_interactive)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+                handler
+                    .store(scoped_key, fingerprint, response_value.clone())
+                    .map_err(|e| e.to_string())?;
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        let response = commands::react_add(&client, channel, ts, emoji, yes, non_interactive)
+            .await
+            .map_err(|e| e.to_string())?;
+        (
+            serde_json::to_value(&response).map_err(|e| e.to_string())?,
+            None,
+        )
+    };
+
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+
+    let output = if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "reactions.add",
+            "react add",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_react_remove(args: &[String], non_interactive: bool) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    if args.len() < 6 {
+        return Err(
+            "Usage: react remove <channel> <ts> <emoji> [--check-emoji] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]".to_string(),
+        );
+    }
+
+    let channel = args[3].clone();
+    let ts = args[4].clone();
+    let emoji = commands::normalize_emoji_name(&args[5]);
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let idempotency_key = get_option(args, "--idempotency-key=");
+    let raw = should_output_raw(args);
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let emoji = check_reaction_emoji(args, &client, &profile_name, emoji).await?;
+
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        let mut params = serde_json::Map::new();
+        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
+        params.insert("timestamp".to_string(), serde_json::json!(ts.clone()));
+        params.insert("name".to_string(), serde_json::json!(emoji.clone()));
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id,
+                user_id,
+                "reactions.remove".to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => (response, Some(status)),
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                let response =
+                    commands::react_remove(&client, channel, ts, emoji, yes, non_interactive)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+                handler
+                    .store(scoped_key, fingerprint, response_value.clone())
+                    .map_err(|e| e.to_string())?;
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        let response = commands::react_remove(&client, channel, ts, emoji, yes, non_interactive)
+            .await
+            .map_err(|e| e.to_string())?;
+        (
+            serde_json::to_value(&response).map_err(|e| e.to_string())?,
+            None,
+        )
+    };
+
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+
+    let output = if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "reactions.remove",
+            "react remove",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_pin_add(args: &[String], non_interactive: bool) -> Result<(), String> {
+    run_pin_write(args, non_interactive, false).await
+}
+
+pub async fn run_pin_remove(args: &[String], non_interactive: bool) -> Result<(), String> {
+    run_pin_write(args, non_interactive, true).await
+}
+
+/// Shared `pin add` / `pin remove` flow (idempotency, envelope output)
+async fn run_pin_write(args: &[String], non_interactive: bool, remove: bool) -> Result<(), String> {
+    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+
+    let (subcommand, method) = if remove {
+        ("remove", "pins.remove")
+    } else {
+        ("add", "pins.add")
+    };
+    if args.len() < 5 {
+        return Err(format!(
+            "Usage: pin {} <channel> <ts> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+            subcommand
+        ));
+    }
+
+    let channel = args[3].clone();
+    let ts = args[4].clone();
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let idempotency_key = get_option(args, "--idempotency-key=");
+    let raw = should_output_raw(args);
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let execute = |channel: String, ts: String| {
+        let client = &client;
+        async move {
+            if remove {
+                commands::pin_remove(client, channel, ts, yes, non_interactive).await
+            } else {
+                commands::pin_add(client, channel, ts, yes, non_interactive).await
+            }
+        }
+    };
+
+    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        let mut params = serde_json::Map::new();
+        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
+        params.insert("timestamp".to_string(), serde_json::json!(ts.clone()));
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        match handler
+            .check(
+                Some(key.clone()),
+                team_id,
+                user_id,
+                method.to_string(),
+                &params,
+            )
+            .map_err(|e| e.to_string())?
+        {
+            IdempotencyCheckResult::Replay {
+                response, status, ..
+            } => (response, Some(status)),
+            IdempotencyCheckResult::Execute {
+                key: scoped_key,
+                fingerprint,
+            } => {
+                let response = execute(channel, ts).await.map_err(|e| e.to_string())?;
+                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+                handler
+                    .store(scoped_key, fingerprint, response_value.clone())
+                    .map_err(|e| e.to_string())?;
+                (
+                    response_value,
+                    Some(crate::idempotency::IdempotencyStatus::Executed),
+                )
+            }
+            IdempotencyCheckResult::NoKey => unreachable!(),
+        }
+    } else {
+        let response = execute(channel, ts).await.map_err(|e| e.to_string())?;
+        (
             serde_json::to_value(&response).map_err(|e| e.to_string())?,
             None,
         )
     };
 
-    if let Ok(api_response) =
-        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
-    {
-        crate::api::display_wrapper_error_guidance(&api_response);
-    }
+    if let Ok(api_response) =
+        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
+    {
+        crate::api::display_wrapper_error_guidance(&api_response);
+    }
+
+    let output = if raw {
+        serde_json::to_string_pretty(&response_value).unwrap()
+    } else {
+        let mut wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            method,
+            &format!("pin {}", subcommand),
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
+            wrapped = wrapped.with_idempotency(
+                key,
+                match status {
+                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
+                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
+                },
+            );
+        }
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_pin_list(args: &[String]) -> Result<(), String> {
+    let channel = args
+        .get(3)
+        .filter(|arg| !arg.starts_with("--"))
+        .cloned()
+        .ok_or_else(|| {
+            "Usage: pin list <channel> [--raw] [--profile=NAME] [--token-type=bot|user]".to_string()
+        })?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let raw = should_output_raw(args);
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let response = commands::pin_list(&client, channel)
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::api::display_wrapper_error_guidance(&response);
+
+    let output = if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "pins.list",
+            "pin list",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_bookmark_list(args: &[String]) -> Result<(), String> {
+    let channel = required_positional(
+        args,
+        3,
+        "bookmark list <channel> [--format=FORMAT] [--raw] [--profile=NAME] [--token-type=bot|user]",
+    )?;
+    let (format, raw) = resolve_list_output(args)?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let response = commands::bookmark_list(&client, &channel)
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::api::display_wrapper_error_guidance(&response);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "bookmark list", &response, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "bookmarks.list",
+            "bookmark list",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_bookmark_add(args: &[String], non_interactive: bool) -> Result<(), String> {
+    const USAGE: &str = "bookmark add <channel> --title=TITLE --link=URL [--emoji=EMOJI] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]";
+    let channel = required_positional(args, 3, USAGE)?;
+    let (Some(title), Some(link)) = (get_option(args, "--title="), get_option(args, "--link="))
+    else {
+        return Err(format!("--title and --link are required\nUsage: {}", USAGE));
+    };
+    let emoji = get_option(args, "--emoji=");
+    let yes = has_flag(args, "--yes");
+    let plan = commands::bookmark::plan_bookmarks_add(&channel, &title, &link, emoji.as_deref());
+    run_planned_write(args, "bookmark add", plan, async |client| {
+        commands::bookmark_add(client, channel, title, link, emoji, yes, non_interactive).await
+    })
+    .await
+}
+
+pub async fn run_bookmark_remove(args: &[String], non_interactive: bool) -> Result<(), String> {
+    const USAGE: &str = "bookmark remove <channel> <bookmark_id> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]";
+    let channel = required_positional(args, 3, USAGE)?;
+    let bookmark_id = required_positional(args, 4, USAGE)?;
+    let yes = has_flag(args, "--yes");
+    let plan = commands::bookmark::plan_bookmarks_remove(&channel, &bookmark_id);
+    run_planned_write(args, "bookmark remove", plan, async |client| {
+        commands::bookmark_remove(client, channel, bookmark_id, yes, non_interactive).await
+    })
+    .await
+}
+
+pub async fn run_bookmark_apply(args: &[String], non_interactive: bool) -> Result<(), String> {
+    const USAGE: &str = "bookmark apply (<channel> | --channel=CHANNEL) --spec=PATH|- [--dry-run] [--keep-unlisted] [--json] [--yes] [--profile=NAME] [--token-type=bot|user]";
+    let channel = get_option(args, "--channel=")
+        .or_else(|| args.get(3).filter(|a| !a.starts_with("--")).cloned())
+        .ok_or_else(|| format!("Usage: {}", USAGE))?;
+    let spec_path = get_option(args, "--spec=")
+        .ok_or_else(|| format!("--spec is required\nUsage: {}", USAGE))?;
+    let content = if spec_path == "-" {
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut content)
+            .map_err(|e| format!("Failed to read stdin: {}", e))?;
+        content
+    } else {
+        std::fs::read_to_string(&spec_path)
+            .map_err(|e| format!("Failed to read {}: {}", spec_path, e))?
+    };
+    let desired = commands::parse_bookmark_spec(&content)?;
+    let dry_run = has_flag(args, "--dry-run");
+    let keep_unlisted = has_flag(args, "--keep-unlisted");
+    let yes = has_flag(args, "--yes");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name), token_type).await?;
+    let channel = commands::thread_ref::resolve_channel_id(&client, &channel)
+        .await
+        .map_err(|e| e.to_string())?;
+    let report = commands::bookmark_apply(
+        &client,
+        &channel,
+        &desired,
+        keep_unlisted,
+        dry_run,
+        yes,
+        non_interactive,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    if has_flag(args, "--json") {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        println!("{}", commands::format_bookmark_apply_text(&report));
+    }
+    if report.failed > 0 {
+        return Err(format!("{} bookmark change(s) failed", report.failed));
+    }
+    Ok(())
+}
+
+pub async fn run_usergroup_list(args: &[String]) -> Result<(), String> {
+    let include_users = has_flag(args, "--include-users");
+    let include_disabled = has_flag(args, "--include-disabled");
+    let (format, raw) = resolve_list_output(args)?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let response = commands::usergroup_list(&client, include_users, include_disabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::api::display_wrapper_error_guidance(&response);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "usergroup list", &response, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "usergroups.list",
+            "usergroup list",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+/// `--name/--handle/--description/--channels` of `usergroup create/update`
+fn usergroup_fields(args: &[String]) -> commands::UsergroupFields {
+    commands::UsergroupFields {
+        name: get_option(args, "--name="),
+        handle: get_option(args, "--handle="),
+        description: get_option(args, "--description="),
+        channels: get_option(args, "--channels=").map(|_| split_list_option(args, "--channels=")),
+    }
+}
+
+pub async fn run_usergroup_create(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let name = required_positional(
+        args,
+        3,
+        "usergroup create <name> [--handle=HANDLE] [--description=TEXT] [--channels=C1,C2] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+    )?;
+    let fields = usergroup_fields(args);
+    let yes = has_flag(args, "--yes");
+    let plan = commands::usergroup::plan_usergroups_create(&name, &fields);
+    run_planned_write(args, "usergroup create", plan, async |client| {
+        commands::usergroup_create(client, name, fields, yes, non_interactive).await
+    })
+    .await
+}
+
+pub async fn run_usergroup_update(args: &[String], non_interactive: bool) -> Result<(), String> {
+    const USAGE: &str = "usergroup update <usergroup> [--name=NAME] [--handle=HANDLE] [--description=TEXT] [--channels=C1,C2] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]";
+    let usergroup = required_positional(args, 3, USAGE)?;
+    let fields = usergroup_fields(args);
+    if fields.is_empty() {
+        return Err(format!(
+            "Nothing to update: pass at least one of --name, --handle, --description, --channels\nUsage: {}",
+            USAGE
+        ));
+    }
+    let yes = has_flag(args, "--yes");
+    let plan = commands::usergroup::plan_usergroups_update(&usergroup, &fields);
+    run_planned_write(args, "usergroup update", plan, async |client| {
+        commands::usergroup_update(client, usergroup, fields, yes, non_interactive).await
+    })
+    .await
+}
+
+pub async fn run_usergroup_users(args: &[String]) -> Result<(), String> {
+    let usergroup = required_positional(
+        args,
+        3,
+        "usergroup users <usergroup> [--raw] [--profile=NAME] [--token-type=bot|user]",
+    )?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let raw = should_output_raw(args);
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let response = commands::usergroup_users(&client, &usergroup)
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::api::display_wrapper_error_guidance(&response);
+
+    let output = if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "usergroups.users.list",
+            "usergroup users",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_usergroup_users_update(
+    args: &[String],
+    non_interactive: bool,
+) -> Result<(), String> {
+    const USAGE: &str = "usergroup users update <usergroup> (--users=U1,U2 | --from-file=PATH) [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]";
+    let usergroup = required_positional(args, 4, USAGE)?;
+    let users = match get_option(args, "--from-file=") {
+        Some(path) => commands::parse_members_file(
+            &std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read members file {}: {}", path, e))?,
+        ),
+        None => split_list_option(args, "--users="),
+    };
+    if users.is_empty() {
+        return Err(format!(
+            "A usergroup needs at least one member (--users or --from-file)\nUsage: {}",
+            USAGE
+        ));
+    }
+    let yes = has_flag(args, "--yes");
+    let plan = commands::usergroup::plan_usergroups_users_update(&usergroup, &users);
+    run_planned_write(args, "usergroup users update", plan, async |client| {
+        commands::usergroup_users_update(client, usergroup, users, yes, non_interactive).await
+    })
+    .await
+}
+
+/// API client for methods that only accept user tokens
+///
+/// A bot token is rejected before any request, with a hint naming the user
+/// scopes to log in with.
+async fn get_user_token_client(
+    args: &[String],
+    feature: &str,
+    user_scopes: &str,
+) -> Result<(ApiClient, String), String> {
+    let hint = |e: String| {
+        format!(
+            "{}
+  Hint: use a profile with a user token, e.g. slack-rs auth login <profile> --user-scopes {}",
+            e, user_scopes
+        )
+    };
+    let profile_name = resolve_profile_name(args);
+    commands::require_user_token_type(feature, parse_token_type(args)?).map_err(hint)?;
+    let token = resolve_token_with_type(Some(profile_name.clone()), Some(TokenType::User)).await?;
+    commands::check_user_token(feature, &token).map_err(hint)?;
+    Ok((ApiClient::with_token(token), profile_name))
+}
+
+/// API client for `reminders.*`, which only accept user tokens
+async fn get_reminder_client(args: &[String]) -> Result<(ApiClient, String), String> {
+    get_user_token_client(args, "Reminders", "reminders:read,reminders:write").await
+}
 
-    let output = if raw {
-        serde_json::to_string_pretty(&response_value).unwrap()
+/// Print a user-token command response (raw or enveloped)
+async fn print_user_token_response(
+    args: &[String],
+    response: &crate::api::ApiResponse,
+    method: &str,
+    command: &str,
+    profile_name: String,
+) -> Result<(), String> {
+    crate::api::display_wrapper_error_guidance(response);
+
+    let output = if should_output_raw(args) {
+        serde_json::to_string_pretty(response).unwrap()
     } else {
-        let mut wrapped = wrap_with_envelope_and_token_type(
+        let response_value = serde_json::to_value(response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
             response_value,
-            "chat.delete",
-            "msg delete",
+            method,
+            command,
             Some(profile_name),
-            token_type,
+            Some(TokenType::User),
         )
         .await?;
-        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
-            wrapped = wrapped.with_idempotency(
-                key,
-                match status {
-                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
-                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
-                },
-            );
-        }
         serde_json::to_string_pretty(&wrapped).unwrap()
     };
 
@@ -1459,102 +4821,385 @@ pub async fn run_msg_delete(args: &[String], non_interactive: bool) -> Result<()
     Ok(())
 }
 
-pub async fn run_react_add(args: &[String], non_interactive: bool) -> Result<(), String> {
-    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+pub async fn run_remind_add(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let usage = "remind add <text> --time=WHEN [--user=USER_ID] [--yes] [--raw] [--profile=NAME]";
+    let text = required_positional(args, 3, usage)?;
+    let time = get_option(args, "--time=").ok_or_else(|| format!("Usage: {}", usage))?;
+    let user = get_option(args, "--user=");
+    let yes = has_flag(args, "--yes");
 
-    if args.len() < 6 {
-        return Err(
-            "Usage: react add <channel> <ts> <emoji> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]"
-                .to_string(),
-        );
+    let (client, profile_name) = get_reminder_client(args).await?;
+    let response = commands::remind_add(&client, text, time, user, yes, non_interactive)
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(args, &response, "reminders.add", "remind add", profile_name).await
+}
+
+pub async fn run_remind_list(args: &[String]) -> Result<(), String> {
+    let (format, raw) = resolve_list_output(args)?;
+    let (client, profile_name) = get_reminder_client(args).await?;
+    let response = commands::remind_list(&client)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    crate::api::display_wrapper_error_guidance(&response);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "remind list", &response, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "reminders.list",
+            "remind list",
+            Some(profile_name),
+            Some(TokenType::User),
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_remind_complete(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let reminder = required_positional(
+        args,
+        3,
+        "remind complete <reminder_id> [--yes] [--raw] [--profile=NAME]",
+    )?;
+    let yes = has_flag(args, "--yes");
+
+    let (client, profile_name) = get_reminder_client(args).await?;
+    let response = commands::remind_complete(&client, reminder, yes, non_interactive)
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(
+        args,
+        &response,
+        "reminders.complete",
+        "remind complete",
+        profile_name,
+    )
+    .await
+}
+
+pub async fn run_remind_delete(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let reminder = required_positional(
+        args,
+        3,
+        "remind delete <reminder_id> [--yes] [--raw] [--profile=NAME]",
+    )?;
+    let yes = has_flag(args, "--yes");
+
+    let (client, profile_name) = get_reminder_client(args).await?;
+    let response = commands::remind_delete(&client, reminder, yes, non_interactive)
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(
+        args,
+        &response,
+        "reminders.delete",
+        "remind delete",
+        profile_name,
+    )
+    .await
+}
+
+/// API client for `dnd.*` and `users.*Presence`
+async fn get_dnd_client(args: &[String]) -> Result<(ApiClient, String), String> {
+    get_user_token_client(
+        args,
+        "DND and presence commands",
+        "dnd:read,dnd:write,users:read,users:write",
+    )
+    .await
+}
+
+pub async fn run_dnd_info(args: &[String]) -> Result<(), String> {
+    let user = get_option(args, "--user=");
+    let (client, profile_name) = get_dnd_client(args).await?;
+    let response = commands::dnd_info(&client, user.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(args, &response, "dnd.info", "dnd info", profile_name).await
+}
+
+pub async fn run_dnd_set(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let minutes = required_positional(
+        args,
+        3,
+        "dnd set <minutes|duration> [--yes] [--raw] [--profile=NAME]",
+    )?;
+    let minutes = commands::parse_snooze_minutes(&minutes)?;
+    let yes = has_flag(args, "--yes");
+
+    let (client, profile_name) = get_dnd_client(args).await?;
+    let response = commands::dnd_set(&client, minutes, yes, non_interactive)
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(args, &response, "dnd.setSnooze", "dnd set", profile_name).await
+}
+
+pub async fn run_dnd_end(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let yes = has_flag(args, "--yes");
+    let (client, profile_name) = get_dnd_client(args).await?;
+    let response = commands::dnd_end(&client, yes, non_interactive)
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(args, &response, "dnd.endSnooze", "dnd end", profile_name).await
+}
+
+pub async fn run_presence_get(args: &[String]) -> Result<(), String> {
+    let user = get_option(args, "--user=");
+    let (client, profile_name) = get_dnd_client(args).await?;
+    let response = commands::presence_get(&client, user.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(
+        args,
+        &response,
+        "users.getPresence",
+        "presence get",
+        profile_name,
+    )
+    .await
+}
+
+pub async fn run_presence_set(args: &[String], non_interactive: bool) -> Result<(), String> {
+    let presence = required_positional(
+        args,
+        3,
+        "presence set <auto|away> [--yes] [--raw] [--profile=NAME]",
+    )?;
+    let presence = commands::parse_presence(&presence)?;
+    let yes = has_flag(args, "--yes");
+
+    let (client, profile_name) = get_dnd_client(args).await?;
+    let response = commands::presence_set(&client, presence, yes, non_interactive)
+        .await
+        .map_err(|e| e.to_string())?;
+    print_user_token_response(
+        args,
+        &response,
+        "users.setPresence",
+        "presence set",
+        profile_name,
+    )
+    .await
+}
+
+/// Idempotency store method used for content-addressable upload dedupe
+const UPLOAD_DEDUPE_METHOD: &str = "files.upload.content";
+
+/// Get a required positional argument, rejecting flags in its place
+fn required_positional(args: &[String], index: usize, usage: &str) -> Result<String, String> {
+    args.get(index)
+        .filter(|arg| !arg.starts_with("--"))
+        .cloned()
+        .ok_or_else(|| format!("Usage: {}", usage))
+}
+
+/// Split a comma-separated option value into trimmed, non-empty items
+fn split_list_option(args: &[String], prefix: &str) -> Vec<String> {
+    get_option(args, prefix)
+        .map(|v| {
+            v.split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub async fn run_connect(args: &[String], non_interactive: bool) -> Result<(), String> {
+    if args.len() < 3 || has_flag(args, "--help") || has_flag(args, "-h") {
+        print_connect_usage(&args[0]);
+        return Ok(());
     }
 
-    let channel = args[3].clone();
-    let ts = args[4].clone();
-    let emoji = args[5].clone();
     let yes = has_flag(args, "--yes");
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
-    let idempotency_key = get_option(args, "--idempotency-key=");
     let raw = should_output_raw(args);
 
-    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let subcommand = args[2].as_str();
+    let requests_action = if subcommand == "requests" {
+        Some(args.get(3).map(String::as_str).unwrap_or(""))
+    } else {
+        None
+    };
 
-    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
-        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
-        let mut params = serde_json::Map::new();
-        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
-        params.insert("timestamp".to_string(), serde_json::json!(ts.clone()));
-        params.insert("name".to_string(), serde_json::json!(emoji.clone()));
-        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
-        match handler
-            .check(
-                Some(key.clone()),
-                team_id,
-                user_id,
-                "reactions.add".to_string(),
-                &params,
+    // Validate arguments before resolving credentials
+    enum ConnectCall {
+        Invite(String, Vec<String>, Vec<String>, bool),
+        Invites(Option<String>, Option<String>),
+        Accept(String, String, bool),
+        Approve(String, Option<String>),
+        Decline(String, Option<String>),
+        RequestsList(commands::InviteRequestFilter),
+        RequestsApprove(String, Option<String>, bool, Option<String>),
+        RequestsDeny(String, Option<String>),
+        Permissions(String, String, commands::ExternalPermissionAction),
+    }
+
+    let call = match (subcommand, requests_action) {
+        ("invite", _) => {
+            let usage = "connect invite <channel> [--emails=a@x.com,...] [--user-ids=U1,...] [--external-limited]";
+            ConnectCall::Invite(
+                required_positional(args, 3, usage)?,
+                split_list_option(args, "--emails="),
+                split_list_option(args, "--user-ids="),
+                has_flag(args, "--external-limited"),
             )
-            .map_err(|e| e.to_string())?
-        {
-            IdempotencyCheckResult::Replay {
-                response, status, ..
-            } => (response, Some(status)),
-            IdempotencyCheckResult::Execute {
-                key: scoped_key,
-                fingerprint,
-            } => {
-                let response =
-                    commands::react_add(&client, channel, ts, emoji, yes, non_interactive)
-                        .await
-                        .map_err(|e| e.to_string())?;
-                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
-                handler
-                    .store(scoped_key, fingerprint, response_value.clone())
-                    .map_err(|e| e.to_string())?;
-                (
-                    response_value,
-                    Some(crate::idempotency::IdempotencyStatus::Executed),
-                )
-            }
-            IdempotencyCheckResult::NoKey => unreachable!(),
         }
-    } else {
-        let response = commands::react_add(&client, channel, ts, emoji, yes, non_interactive)
-            .await
-            .map_err(|e| e.to_string())?;
-        (
-            serde_json::to_value(&response).map_err(|e| e.to_string())?,
-            None,
-        )
+        ("invites", _) => {
+            ConnectCall::Invites(get_option(args, "--team-id="), get_option(args, "--cursor="))
+        }
+        ("accept", _) => {
+            let usage = "connect accept <invite_id> <channel_name> [--private]";
+            ConnectCall::Accept(
+                required_positional(args, 3, usage)?,
+                required_positional(args, 4, usage)?,
+                has_flag(args, "--private"),
+            )
+        }
+        ("approve", _) => ConnectCall::Approve(
+            required_positional(args, 3, "connect approve <invite_id> [--target-team=T]")?,
+            get_option(args, "--target-team="),
+        ),
+        ("decline", _) => ConnectCall::Decline(
+            required_positional(args, 3, "connect decline <invite_id> [--target-team=T] [--yes]")?,
+            get_option(args, "--target-team="),
+        ),
+        ("requests", Some("list")) => {
+            let limit = match get_option(args, "--limit=") {
+                Some(v) => Some(
+                    v.parse::<u32>()
+                        .map_err(|_| format!("Invalid --limit value '{}'", v))?,
+                ),
+                None => None,
+            };
+            ConnectCall::RequestsList(commands::InviteRequestFilter {
+                include_approved: has_flag(args, "--include-approved"),
+                include_denied: has_flag(args, "--include-denied"),
+                include_expired: has_flag(args, "--include-expired"),
+                user_id: get_option(args, "--user="),
+                limit,
+                cursor: get_option(args, "--cursor="),
+            })
+        }
+        ("requests", Some("approve")) => ConnectCall::RequestsApprove(
+            required_positional(
+                args,
+                4,
+                "connect requests approve <invite_id> [--channel=C] [--external-limited] [--message=TEXT]",
+            )?,
+            get_option(args, "--channel="),
+            has_flag(args, "--external-limited"),
+            get_option(args, "--message="),
+        ),
+        ("requests", Some("deny")) => ConnectCall::RequestsDeny(
+            required_positional(
+                args,
+                4,
+                "connect requests deny <invite_id> [--message=TEXT] [--yes]",
+            )?,
+            get_option(args, "--message="),
+        ),
+        ("permissions", _) => {
+            let usage = "connect permissions <channel> <target_team> <upgrade|downgrade> [--yes]";
+            ConnectCall::Permissions(
+                required_positional(args, 3, usage)?,
+                required_positional(args, 4, usage)?,
+                commands::ExternalPermissionAction::parse(&required_positional(args, 5, usage)?)?,
+            )
+        }
+        _ => {
+            print_connect_usage(&args[0]);
+            return Err(format!("Unknown connect subcommand: {}", args[2..].join(" ")));
+        }
     };
 
-    if let Ok(api_response) =
-        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
-    {
-        crate::api::display_wrapper_error_guidance(&api_response);
-    }
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+
+    let (method, command, result) = match call {
+        ConnectCall::Invite(channel, emails, user_ids, limited) => (
+            "conversations.inviteShared",
+            "connect invite",
+            commands::connect_invite(&client, channel, emails, user_ids, limited).await,
+        ),
+        ConnectCall::Invites(team_id, cursor) => (
+            "conversations.listConnectInvites",
+            "connect invites",
+            commands::connect_list_invites(&client, team_id, cursor).await,
+        ),
+        ConnectCall::Accept(invite_id, channel_name, is_private) => (
+            "conversations.acceptSharedInvite",
+            "connect accept",
+            commands::connect_accept(&client, invite_id, channel_name, is_private).await,
+        ),
+        ConnectCall::Approve(invite_id, target_team) => (
+            "conversations.approveSharedInvite",
+            "connect approve",
+            commands::connect_approve(&client, invite_id, target_team).await,
+        ),
+        ConnectCall::Decline(invite_id, target_team) => (
+            "conversations.declineSharedInvite",
+            "connect decline",
+            commands::connect_decline(&client, invite_id, target_team, yes, non_interactive).await,
+        ),
+        ConnectCall::RequestsList(filter) => (
+            "conversations.requestSharedInvite.list",
+            "connect requests list",
+            commands::connect_requests_list(&client, filter).await,
+        ),
+        ConnectCall::RequestsApprove(invite_id, channel, limited, message) => (
+            "conversations.requestSharedInvite.approve",
+            "connect requests approve",
+            commands::connect_requests_approve(&client, invite_id, channel, limited, message).await,
+        ),
+        ConnectCall::RequestsDeny(invite_id, message) => (
+            "conversations.requestSharedInvite.deny",
+            "connect requests deny",
+            commands::connect_requests_deny(&client, invite_id, message, yes, non_interactive)
+                .await,
+        ),
+        ConnectCall::Permissions(channel, target_team, action) => (
+            "conversations.externalInvitePermissions.set",
+            "connect permissions",
+            commands::connect_set_permissions(
+                &client,
+                channel,
+                target_team,
+                action,
+                yes,
+                non_interactive,
+            )
+            .await,
+        ),
+    };
+
+    let response = result.map_err(|e| e.to_string())?;
+
+    // Display error guidance if response contains a known error
+    crate::api::display_wrapper_error_guidance(&response);
 
     let output = if raw {
-        serde_json::to_string_pretty(&response_value).unwrap()
+        serde_json::to_string_pretty(&response).unwrap()
     } else {
-        let mut wrapped = wrap_with_envelope_and_token_type(
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
             response_value,
-            "reactions.add",
-            "react add",
+            method,
+            command,
             Some(profile_name),
             token_type,
         )
         .await?;
-        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
-            wrapped = wrapped.with_idempotency(
-                key,
-                match status {
-                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
-                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
-                },
-            );
-        }
         serde_json::to_string_pretty(&wrapped).unwrap()
     };
 
@@ -1562,101 +5207,92 @@ pub async fn run_react_add(args: &[String], non_interactive: bool) -> Result<(),
     Ok(())
 }
 
-pub async fn run_react_remove(args: &[String], non_interactive: bool) -> Result<(), String> {
-    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
-
-    if args.len() < 6 {
-        return Err(
-            "Usage: react remove <channel> <ts> <emoji> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]".to_string(),
-        );
+pub async fn run_kv(args: &[String]) -> Result<(), String> {
+    if args.len() < 3 || has_flag(args, "--help") || has_flag(args, "-h") {
+        print_kv_usage(&args[0]);
+        return Ok(());
     }
 
-    let channel = args[3].clone();
-    let ts = args[4].clone();
-    let emoji = args[5].clone();
-    let yes = has_flag(args, "--yes");
+    let namespace =
+        get_option(args, "--namespace=").unwrap_or_else(|| commands::kv::DEFAULT_NAMESPACE.into());
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
-    let idempotency_key = get_option(args, "--idempotency-key=");
-    let raw = should_output_raw(args);
-
-    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
 
-    let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
-        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
-        let mut params = serde_json::Map::new();
-        params.insert("channel".to_string(), serde_json::json!(channel.clone()));
-        params.insert("timestamp".to_string(), serde_json::json!(ts.clone()));
-        params.insert("name".to_string(), serde_json::json!(emoji.clone()));
-        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
-        match handler
-            .check(
-                Some(key.clone()),
-                team_id,
-                user_id,
-                "reactions.remove".to_string(),
-                &params,
-            )
-            .map_err(|e| e.to_string())?
-        {
-            IdempotencyCheckResult::Replay {
-                response, status, ..
-            } => (response, Some(status)),
-            IdempotencyCheckResult::Execute {
-                key: scoped_key,
-                fingerprint,
-            } => {
-                let response =
-                    commands::react_remove(&client, channel, ts, emoji, yes, non_interactive)
-                        .await
-                        .map_err(|e| e.to_string())?;
-                let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
-                handler
-                    .store(scoped_key, fingerprint, response_value.clone())
-                    .map_err(|e| e.to_string())?;
-                (
-                    response_value,
-                    Some(crate::idempotency::IdempotencyStatus::Executed),
-                )
-            }
-            IdempotencyCheckResult::NoKey => unreachable!(),
+    enum KvCall {
+        Set(String, serde_json::Value, Option<u64>),
+        Get(String),
+        List,
+    }
+    let call = match args[2].as_str() {
+        "set" => {
+            let usage = "kv set <key> <value> [--namespace=NS] [--channel=C] [--if-version=N]";
+            let if_version = match get_option(args, "--if-version=") {
+                Some(v) => Some(
+                    v.parse::<u64>()
+                        .map_err(|_| format!("Invalid --if-version value '{}'", v))?,
+                ),
+                None => None,
+            };
+            KvCall::Set(
+                required_positional(args, 3, usage)?,
+                commands::kv::parse_kv_value(&required_positional(args, 4, usage)?)
+                    .map_err(|e| e.to_string())?,
+                if_version,
+            )
+        }
+        "get" => KvCall::Get(required_positional(
+            args,
+            3,
+            "kv get <key> [--namespace=NS] [--channel=C]",
+        )?),
+        "list" => KvCall::List,
+        _ => {
+            print_kv_usage(&args[0]);
+            return Err(format!("Unknown kv subcommand: {}", args[2]));
         }
-    } else {
-        let response = commands::react_remove(&client, channel, ts, emoji, yes, non_interactive)
-            .await
-            .map_err(|e| e.to_string())?;
-        (
-            serde_json::to_value(&response).map_err(|e| e.to_string())?,
-            None,
-        )
     };
+    let channel = commands::kv::resolve_kv_channel(get_option(args, "--channel="))
+        .map_err(|e| e.to_string())?;
 
-    if let Ok(api_response) =
-        serde_json::from_value::<crate::api::ApiResponse>(response_value.clone())
-    {
-        crate::api::display_wrapper_error_guidance(&api_response);
-    }
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let (method, command, response) = match call {
+        KvCall::Set(key, value, if_version) => {
+            let entry =
+                commands::kv::kv_set(&client, &channel, &namespace, &key, value, if_version)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            ("chat.postMessage", "kv set", entry.to_json())
+        }
+        KvCall::Get(key) => {
+            let entry = commands::kv::kv_get(&client, &channel, &namespace, &key)
+                .await
+                .map_err(|e| e.to_string())?;
+            ("conversations.history", "kv get", entry.to_json())
+        }
+        KvCall::List => {
+            let entries = commands::kv::kv_list(&client, &channel, &namespace)
+                .await
+                .map_err(|e| e.to_string())?;
+            let entries: Vec<_> = entries.iter().map(|e| e.to_json()).collect();
+            (
+                "conversations.history",
+                "kv list",
+                serde_json::json!({ "namespace": namespace, "entries": entries }),
+            )
+        }
+    };
 
-    let output = if raw {
-        serde_json::to_string_pretty(&response_value).unwrap()
+    let output = if should_output_raw(args) {
+        serde_json::to_string_pretty(&response).unwrap()
     } else {
-        let mut wrapped = wrap_with_envelope_and_token_type(
-            response_value,
-            "reactions.remove",
-            "react remove",
+        let wrapped = wrap_with_envelope_and_token_type(
+            response,
+            method,
+            command,
             Some(profile_name),
             token_type,
         )
         .await?;
-        if let (Some(key), Some(status)) = (idempotency_key, idempotency_status) {
-            wrapped = wrapped.with_idempotency(
-                key,
-                match status {
-                    crate::idempotency::IdempotencyStatus::Executed => "executed".to_string(),
-                    crate::idempotency::IdempotencyStatus::Replayed => "replayed".to_string(),
-                },
-            );
-        }
         serde_json::to_string_pretty(&wrapped).unwrap()
     };
 
@@ -1664,28 +5300,240 @@ pub async fn run_react_remove(args: &[String], non_interactive: bool) -> Result<
     Ok(())
 }
 
+pub fn run_idempotency(args: &[String], non_interactive: bool) -> Result<(), String> {
+    if args.len() < 3 || has_flag(args, "--help") || has_flag(args, "-h") {
+        print_idempotency_usage(&args[0]);
+        return Ok(());
+    }
+
+    match args[2].as_str() {
+        "list" => commands::idempotency::idempotency_list(),
+        "show" => {
+            let key = required_positional(args, 3, "idempotency show <key>")?;
+            commands::idempotency::idempotency_show(&key)
+        }
+        "clear" => {
+            let older_than = match get_option(args, "--older-than=") {
+                Some(v) => Some(commands::schedule::parse_duration(&v)?.max(0) as u64),
+                None => None,
+            };
+            commands::idempotency::idempotency_clear(
+                get_option(args, "--key=").as_deref(),
+                older_than,
+                has_flag(args, "--yes"),
+                non_interactive,
+            )
+        }
+        "gc" => commands::idempotency::idempotency_gc(),
+        _ => {
+            print_idempotency_usage(&args[0]);
+            Err(format!("Unknown idempotency subcommand: {}", args[2]))
+        }
+    }
+}
+
+pub async fn run_ctx(args: &[String]) -> Result<(), String> {
+    if args.len() < 3 || has_flag(args, "--help") || has_flag(args, "-h") {
+        print_ctx_usage(&args[0]);
+        return Ok(());
+    }
+
+    let path = commands::SessionContext::default_path()?;
+    let mut context = commands::SessionContext::load(&path)?;
+    match args[2].as_str() {
+        "set" => {
+            let pairs: Vec<String> = args[3..]
+                .iter()
+                .take_while(|arg| !arg.starts_with("--"))
+                .cloned()
+                .collect();
+            if pairs.is_empty() {
+                return Err("Usage: ctx set [channel <C>] [thread <TS|PERMALINK>]".to_string());
+            }
+            let mut update = commands::SessionContext::parse_pairs(&pairs)?;
+            // Store IDs so every command can use the channel, not only those resolving names
+            if let Some(channel) = update.channel.as_deref() {
+                let name = channel.trim_start_matches('#');
+                if !commands::thread_ref::is_conversation_id(name) {
+                    let client = get_api_client_with_token_type(
+                        Some(resolve_profile_name(args)),
+                        parse_token_type(args)?,
+                    )
+                    .await?;
+                    update.channel = Some(
+                        commands::thread_ref::resolve_channel_id(&client, name)
+                            .await
+                            .map_err(|e| e.to_string())?,
+                    );
+                }
+            }
+            context = context.merged_with(&update);
+            context.save(&path)?;
+        }
+        "clear" => {
+            match args
+                .get(3)
+                .filter(|arg| !arg.starts_with("--"))
+                .map(String::as_str)
+            {
+                None => context = commands::SessionContext::default(),
+                Some("channel") => context.channel = None,
+                Some("thread") => context.thread = None,
+                Some(other) => {
+                    return Err(format!(
+                        "Unknown context key '{}' (valid: channel, thread)",
+                        other
+                    ))
+                }
+            }
+            context.save(&path)?;
+        }
+        "show" => {}
+        other => {
+            print_ctx_usage(&args[0]);
+            return Err(format!("Unknown ctx subcommand: {}", other));
+        }
+    }
+
+    if has_flag(args, "--json") {
+        println!("{}", serde_json::to_string_pretty(&context).unwrap());
+    } else {
+        print!("{}", commands::format_context_text(&context));
+    }
+    Ok(())
+}
+
 pub async fn run_file_upload(args: &[String], non_interactive: bool) -> Result<(), String> {
-    use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+    use crate::idempotency::{
+        IdempotencyCheckResult, IdempotencyHandler, RequestFingerprint, ScopedKey,
+    };
 
-    if args.len() < 4 {
+    let patterns: Vec<String> = args
+        .iter()
+        .skip(3)
+        .take_while(|arg| !arg.starts_with("--"))
+        .cloned()
+        .collect();
+    if patterns.is_empty() {
         return Err(
-            "Usage: file upload <path> [--channel=ID] [--channels=IDs] [--title=TITLE] [--comment=TEXT] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]"
+            "Usage: file upload <path|pattern|->... [--channel=ID] [--channels=IDs] [--title=TITLE] [--comment=TEXT] [--filename=NAME] [--snippet-type=TYPE] [--parallel=N] [--max-dim=PX] [--strip-exif] [--force] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]"
                 .to_string(),
         );
     }
+    if patterns.len() > 1 && patterns.iter().any(|p| p == commands::STDIN_UPLOAD_PATH) {
+        return Err("Error: - (stdin) cannot be combined with other paths".to_string());
+    }
+    // Several paths or a pattern: upload as a batch with a per-file report
+    let paths = commands::expand_upload_paths(&patterns)?;
+    if patterns.len() > 1 || paths.len() > 1 || paths[0] != patterns[0] {
+        return run_file_upload_batch(args, &paths, non_interactive).await;
+    }
 
-    let file_path = args[3].clone();
+    let file_path = paths[0].clone();
     let channels = get_option(args, "--channel=").or_else(|| get_option(args, "--channels="));
     let title = get_option(args, "--title=");
     let comment = get_option(args, "--comment=");
+    let max_dim = match get_option(args, "--max-dim=") {
+        Some(v) => Some(v.parse::<u32>().ok().filter(|d| *d > 0).ok_or_else(|| {
+            format!(
+                "Invalid --max-dim value '{}': expected a positive integer",
+                v
+            )
+        })?),
+        None => None,
+    };
+    let upload_options = commands::UploadOptions {
+        max_dim,
+        strip_exif: has_flag(args, "--strip-exif"),
+        file_name: get_option(args, "--filename="),
+        snippet_type: get_option(args, "--snippet-type="),
+    };
     let yes = has_flag(args, "--yes");
     let profile_name = resolve_profile_name(args);
     let token_type = parse_token_type(args)?;
     let idempotency_key = get_option(args, "--idempotency-key=");
-    let raw = should_output_raw(args);
+    let (format, raw) = resolve_list_output(args)?;
+
+    let force = has_flag(args, "--force");
+
+    // `-`: the content comes from stdin, which then cannot answer a prompt
+    let stdin_content = if file_path == commands::STDIN_UPLOAD_PATH {
+        if !yes {
+            return Err(
+                "Error: file upload - reads the file from stdin and cannot ask for confirmation; pass --yes"
+                    .to_string(),
+            );
+        }
+        Some(commands::read_upload_content(std::io::stdin().lock())?)
+    } else {
+        None
+    };
 
     let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
 
+    // Content-addressable dedupe: skip re-uploading identical bytes to the same channels
+    let content_key = match &stdin_content {
+        Some(bytes) => Some(commands::upload_content_key(
+            bytes,
+            channels.as_deref(),
+            title.as_deref(),
+            comment.as_deref(),
+            &upload_options,
+        )),
+        None => std::fs::read(&file_path).ok().map(|bytes| {
+            commands::upload_content_key(
+                &bytes,
+                channels.as_deref(),
+                title.as_deref(),
+                comment.as_deref(),
+                &upload_options,
+            )
+        }),
+    };
+    if let (Some(content_key), false) = (&content_key, force) {
+        let handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        if let IdempotencyCheckResult::Replay { response, .. } = handler
+            .check(
+                Some(content_key.clone()),
+                team_id,
+                user_id,
+                UPLOAD_DEDUPE_METHOD.to_string(),
+                &serde_json::Map::new(),
+            )
+            .map_err(|e| e.to_string())?
+        {
+            let previous = response
+                .get("files")
+                .and_then(|f| f.get(0))
+                .and_then(|f| f.get("permalink").or_else(|| f.get("id")))
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown");
+            eprintln!(
+                "Skipped upload: identical content was already uploaded ({}). Use --force to upload again.",
+                previous
+            );
+            let output = if format != commands::OutputFormat::Json {
+                render_formatted(args, "file upload", &response, format)?
+            } else if raw {
+                serde_json::to_string_pretty(&response).unwrap()
+            } else {
+                let wrapped = wrap_with_envelope_and_token_type(
+                    response,
+                    "files.upload",
+                    "file upload",
+                    Some(profile_name),
+                    token_type,
+                )
+                .await?
+                .with_idempotency(content_key.clone(), "deduplicated".to_string());
+                serde_json::to_string_pretty(&wrapped).unwrap()
+            };
+            println!("{}", output);
+            return Ok(());
+        }
+    }
+
     let (response_value, idempotency_status) = if let Some(key) = idempotency_key.clone() {
         let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
         let mut params = serde_json::Map::new();
@@ -1699,6 +5547,18 @@ pub async fn run_file_upload(args: &[String], non_interactive: bool) -> Result<(
         if let Some(ref c) = comment {
             params.insert("comment".to_string(), serde_json::json!(c));
         }
+        if let Some(d) = upload_options.max_dim {
+            params.insert("max_dim".to_string(), serde_json::json!(d));
+        }
+        if upload_options.strip_exif {
+            params.insert("strip_exif".to_string(), serde_json::json!(true));
+        }
+        if let Some(ref name) = upload_options.file_name {
+            params.insert("name".to_string(), serde_json::json!(name));
+        }
+        if let Some(ref snippet_type) = upload_options.snippet_type {
+            params.insert("snippet_type".to_string(), serde_json::json!(snippet_type));
+        }
         let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
         match handler
             .check(
@@ -1717,17 +5577,18 @@ pub async fn run_file_upload(args: &[String], non_interactive: bool) -> Result<(
                 key: scoped_key,
                 fingerprint,
             } => {
-                let response = commands::file_upload(
+                let response = upload_single_file(
                     &client,
                     file_path,
+                    stdin_content,
                     channels,
                     title,
                     comment,
                     yes,
                     non_interactive,
+                    &upload_options,
                 )
-                .await
-                .map_err(|e| e.to_string())?;
+                .await?;
                 let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
                 handler
                     .store(scoped_key, fingerprint, response_value.clone())
@@ -1740,17 +5601,18 @@ pub async fn run_file_upload(args: &[String], non_interactive: bool) -> Result<(
             IdempotencyCheckResult::NoKey => unreachable!(),
         }
     } else {
-        let response = commands::file_upload(
+        let response = upload_single_file(
             &client,
             file_path,
+            stdin_content,
             channels,
             title,
             comment,
             yes,
             non_interactive,
+            &upload_options,
         )
-        .await
-        .map_err(|e| e.to_string())?;
+        .await?;
         (
             serde_json::to_value(&response).map_err(|e| e.to_string())?,
             None,
@@ -1758,8 +5620,30 @@ pub async fn run_file_upload(args: &[String], non_interactive: bool) -> Result<(
     };
 
     crate::api::display_json_error_guidance(&response_value);
+    crate::gha::record_response(&response_value);
 
-    let output = if raw {
+    // Remember successful uploads so identical re-uploads can be skipped
+    let uploaded = response_value.get("ok").and_then(|v| v.as_bool()) == Some(true);
+    if let (Some(content_key), true) = (content_key, uploaded) {
+        let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+        let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+        handler
+            .store(
+                ScopedKey::new(
+                    team_id,
+                    user_id,
+                    UPLOAD_DEDUPE_METHOD.to_string(),
+                    content_key,
+                ),
+                RequestFingerprint::from_params(&serde_json::Map::new()),
+                response_value.clone(),
+            )
+            .map_err(|e| e.to_string())?;
+    }
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "file upload", &response_value, format)?
+    } else if raw {
         serde_json::to_string_pretty(&response_value).unwrap()
     } else {
         let mut wrapped = wrap_with_envelope_and_token_type(
@@ -1786,6 +5670,236 @@ pub async fn run_file_upload(args: &[String], non_interactive: bool) -> Result<(
     Ok(())
 }
 
+/// Upload the single file of `file upload`: `content` holds what was read
+/// from stdin for `-`, otherwise the file at `file_path` is read
+#[allow(clippy::too_many_arguments)]
+async fn upload_single_file(
+    client: &crate::api::ApiClient,
+    file_path: String,
+    content: Option<Vec<u8>>,
+    channels: Option<String>,
+    title: Option<String>,
+    comment: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+    options: &commands::UploadOptions,
+) -> Result<serde_json::Value, String> {
+    match content {
+        Some(content) => {
+            commands::file_upload_content(
+                client,
+                content,
+                channels,
+                title,
+                comment,
+                yes,
+                non_interactive,
+                options,
+            )
+            .await
+        }
+        None => {
+            commands::file_upload_with_options(
+                client,
+                file_path,
+                channels,
+                title,
+                comment,
+                yes,
+                non_interactive,
+                options,
+            )
+            .await
+        }
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// `file upload` with several files: per-file dedupe, concurrent transfer
+/// and a per-file report
+async fn run_file_upload_batch(
+    args: &[String],
+    paths: &[String],
+    non_interactive: bool,
+) -> Result<(), String> {
+    use crate::idempotency::{
+        IdempotencyCheckResult, IdempotencyHandler, RequestFingerprint, ScopedKey,
+    };
+
+    if get_option(args, "--title=").is_some()
+        || get_option(args, "--filename=").is_some()
+        || get_option(args, "--idempotency-key=").is_some()
+    {
+        return Err(
+            "Error: --title, --filename and --idempotency-key only apply when uploading a single file"
+                .to_string(),
+        );
+    }
+    let channels = get_option(args, "--channel=").or_else(|| get_option(args, "--channels="));
+    let comment = get_option(args, "--comment=");
+    let parallelism = match get_option(args, "--parallel=") {
+        Some(v) => v.parse::<usize>().ok().filter(|n| *n > 0).ok_or_else(|| {
+            format!(
+                "Invalid --parallel value '{}': expected a positive integer",
+                v
+            )
+        })?,
+        None => commands::DEFAULT_UPLOAD_PARALLELISM,
+    };
+    let max_dim = match get_option(args, "--max-dim=") {
+        Some(v) => Some(v.parse::<u32>().ok().filter(|d| *d > 0).ok_or_else(|| {
+            format!(
+                "Invalid --max-dim value '{}': expected a positive integer",
+                v
+            )
+        })?),
+        None => None,
+    };
+    let upload_options = commands::UploadOptions {
+        max_dim,
+        strip_exif: has_flag(args, "--strip-exif"),
+        file_name: None,
+        snippet_type: get_option(args, "--snippet-type="),
+    };
+    let yes = has_flag(args, "--yes");
+    let force = has_flag(args, "--force");
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+    let (format, raw) = resolve_list_output(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let (team_id, user_id) = get_team_and_user_ids_from_profile(&profile_name).await?;
+
+    // Content-addressable dedupe per file, as for a single upload
+    let mut handler = IdempotencyHandler::new().map_err(|e| e.to_string())?;
+    let mut content_keys = std::collections::HashMap::new();
+    let mut skipped = std::collections::HashMap::new();
+    for path in paths {
+        let Some(content_key) = std::fs::read(path).ok().map(|bytes| {
+            commands::upload_content_key(
+                &bytes,
+                channels.as_deref(),
+                None,
+                comment.as_deref(),
+                &upload_options,
+            )
+        }) else {
+            continue;
+        };
+        if !force {
+            if let IdempotencyCheckResult::Replay { response, .. } = handler
+                .check(
+                    Some(content_key.clone()),
+                    team_id.clone(),
+                    user_id.clone(),
+                    UPLOAD_DEDUPE_METHOD.to_string(),
+                    &serde_json::Map::new(),
+                )
+                .map_err(|e| e.to_string())?
+            {
+                let previous = response.get("files").and_then(|f| f.get(0));
+                skipped.insert(
+                    path.clone(),
+                    commands::FileUploadResult::skipped(path, previous),
+                );
+                continue;
+            }
+        }
+        content_keys.insert(path.clone(), content_key);
+    }
+    if !skipped.is_empty() {
+        eprintln!(
+            "Skipping {} file(s) with identical content already uploaded. Use --force to upload again.",
+            skipped.len()
+        );
+    }
+
+    let pending: Vec<String> = paths
+        .iter()
+        .filter(|path| !skipped.contains_key(*path))
+        .cloned()
+        .collect();
+    let mut uploaded = if pending.is_empty() {
+        Vec::new()
+    } else {
+        commands::file_upload_many(
+            &client,
+            &pending,
+            channels,
+            comment,
+            yes,
+            non_interactive,
+            &upload_options,
+            parallelism,
+        )
+        .await
+        .map_err(|e| e.to_string())?
+        .results
+    }
+    .into_iter();
+
+    // Remember successful uploads so identical re-uploads can be skipped
+    let mut results = Vec::new();
+    for path in paths {
+        let result = match skipped.remove(path) {
+            Some(result) => result,
+            None => uploaded
+                .next()
+                .ok_or_else(|| format!("Missing upload result for {}", path))?,
+        };
+        if let (Some(content_key), "uploaded") = (content_keys.remove(path), result.status.as_str())
+        {
+            let response = serde_json::json!({
+                "ok": true,
+                "files": [{"id": result.id, "name": result.name, "permalink": result.permalink}],
+            });
+            handler
+                .store(
+                    ScopedKey::new(
+                        team_id.clone(),
+                        user_id.clone(),
+                        UPLOAD_DEDUPE_METHOD.to_string(),
+                        content_key,
+                    ),
+                    RequestFingerprint::from_params(&serde_json::Map::new()),
+                    response,
+                )
+                .map_err(|e| e.to_string())?;
+        }
+        results.push(result);
+    }
+    let report = commands::FileUploadReport::from_results(results);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "file upload batch", &report, format)?
+    } else {
+        let report_value = serde_json::to_value(&report).map_err(|e| e.to_string())?;
+        if raw {
+            serde_json::to_string_pretty(&report_value).unwrap()
+        } else {
+            let wrapped = wrap_with_envelope_and_token_type(
+                report_value,
+                "files.upload",
+                "file upload",
+                Some(profile_name),
+                token_type,
+            )
+            .await?;
+            serde_json::to_string_pretty(&wrapped).unwrap()
+        }
+    };
+    println!("{}", output);
+
+    if report.failed > 0 {
+        return Err(format!(
+            "{} of {} files could not be uploaded",
+            report.failed,
+            paths.len()
+        ));
+    }
+    Ok(())
+}
+
 pub async fn run_file_download(args: &[String]) -> Result<(), String> {
     if args.len() < 3 {
         return Err(
@@ -1841,10 +5955,142 @@ pub async fn run_file_download(args: &[String]) -> Result<(), String> {
     Ok(())
 }
 
+pub async fn run_file_list(args: &[String]) -> Result<(), String> {
+    let count = match get_option(args, "--count=") {
+        Some(v) => Some(
+            v.parse::<u32>()
+                .map_err(|_| format!("Error: invalid --count value '{}'", v))?,
+        ),
+        None => None,
+    };
+    let page = match get_option(args, "--page=") {
+        Some(v) => Some(
+            v.parse::<u32>()
+                .map_err(|_| format!("Error: invalid --page value '{}'", v))?,
+        ),
+        None => None,
+    };
+    let all = has_flag(args, "--all");
+    if all && page.is_some() {
+        return Err("Error: --all cannot be combined with --page".to_string());
+    }
+    // --older-than 90d: files created at least that long ago
+    let ts_to = match get_option(args, "--older-than=") {
+        Some(v) => Some(chrono::Utc::now().timestamp() - commands::schedule::parse_duration(&v)?),
+        None => None,
+    };
+    let options = commands::FileListOptions {
+        channel: get_option(args, "--channel="),
+        user: get_option(args, "--user="),
+        types: get_option(args, "--types="),
+        ts_to,
+        ts_from: None,
+        count,
+        page,
+    };
+    let (format, raw) = resolve_list_output(args)?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let response = commands::file_list(&client, &options, all)
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::api::display_wrapper_error_guidance(&response);
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "file list", &response, format)?
+    } else if raw {
+        serde_json::to_string_pretty(&response).unwrap()
+    } else {
+        let response_value = serde_json::to_value(&response).map_err(|e| e.to_string())?;
+        let wrapped = wrap_with_envelope_and_token_type(
+            response_value,
+            "files.list",
+            "file list",
+            Some(profile_name),
+            token_type,
+        )
+        .await?;
+        serde_json::to_string_pretty(&wrapped).unwrap()
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+pub async fn run_file_delete(args: &[String], non_interactive: bool) -> Result<(), String> {
+    const USAGE: &str = "Usage: file delete <file_id>... [--from-list=PATH|-] [--yes] [--profile=NAME] [--token-type=bot|user]";
+    let mut file_ids: Vec<String> = args
+        .iter()
+        .skip(3)
+        .take_while(|arg| !arg.starts_with("--"))
+        .cloned()
+        .collect();
+    // --from-list: IDs from a file or stdin (`file list` output, JSONL or one per line)
+    if let Some(source) = get_option(args, "--from-list=") {
+        let content = if source == "-" {
+            let mut content = String::new();
+            std::io::Read::read_to_string(&mut std::io::stdin(), &mut content)
+                .map_err(|e| format!("Failed to read stdin: {}", e))?;
+            content
+        } else {
+            std::fs::read_to_string(&source)
+                .map_err(|e| format!("Failed to read {}: {}", source, e))?
+        };
+        for id in commands::parse_file_id_list(&content)? {
+            if !file_ids.contains(&id) {
+                file_ids.push(id);
+            }
+        }
+    }
+    if file_ids.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    let yes = has_flag(args, "--yes");
+    let (format, raw) = resolve_list_output(args)?;
+    let profile_name = resolve_profile_name(args);
+    let token_type = parse_token_type(args)?;
+
+    let client = get_api_client_with_token_type(Some(profile_name.clone()), token_type).await?;
+    let report = commands::file_delete(&client, &file_ids, yes, non_interactive)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let output = if format != commands::OutputFormat::Json {
+        render_formatted(args, "file delete", &report, format)?
+    } else {
+        let report_value = serde_json::to_value(&report).map_err(|e| e.to_string())?;
+        if raw {
+            serde_json::to_string_pretty(&report_value).unwrap()
+        } else {
+            let wrapped = wrap_with_envelope_and_token_type(
+                report_value,
+                "files.delete",
+                "file delete",
+                Some(profile_name),
+                token_type,
+            )
+            .await?;
+            serde_json::to_string_pretty(&wrapped).unwrap()
+        }
+    };
+    println!("{}", output);
+
+    if report.failed > 0 {
+        return Err(format!(
+            "{} of {} files could not be deleted",
+            report.failed,
+            file_ids.len()
+        ));
+    }
+    Ok(())
+}
+
 pub fn print_conv_usage(prog: &str) {
     println!("Conv command usage:");
     println!(
-        "  {} conv list [--types=TYPE] [--include-private] [--all] [--limit=N] [--filter=KEY:VALUE]... [--format=FORMAT] [--sort=KEY] [--sort-dir=DIR] [--raw] [--profile=NAME] [--token-type=bot|user]",
+        "  {} conv list [--types=TYPE] [--include-private] [--all] [--limit=N] [--filter=KEY:VALUE]... [--format=FORMAT] [--sort=KEY] [--sort-dir=DIR] [--with-last-activity] [--max-enrich=N] [--diff-against=PATH] [--snapshot-out=PATH] [--raw] [--profile=NAME] [--token-type=bot|user]",
         prog
     );
     println!("    List conversations with optional filtering and sorting");
@@ -1859,16 +6105,25 @@ pub fn print_conv_usage(prog: &str) {
     println!("      - name:<glob>: Filter by channel name (supports * and ? wildcards)");
     println!("      - is_member:true|false: Filter by membership status");
     println!("      - is_private:true|false: Filter by channel privacy");
-    println!("    Formats: json (default), jsonl, table, tsv");
+    println!("    Formats: json (default), jsonl, table, tsv, csv, yaml");
     println!("      - json: JSON format with envelope (use --raw for raw Slack API response)");
     println!("      - jsonl: JSON Lines format (one object per line)");
     println!("      - table: Human-readable table format");
     println!("      - tsv: Tab-separated values");
-    println!("    Sort keys: name, created, num_members");
+    println!("      - csv: Comma-separated values");
+    println!("      - yaml: Whole response as YAML");
+    println!("    --columns=a,b.c: Columns for table/tsv/csv (dotted paths into each record)");
+    println!("    Sort keys: name, created, num_members, last_activity");
     println!("      - name: Sort by channel name");
     println!("      - created: Sort by creation timestamp");
     println!("      - num_members: Sort by member count");
+    println!("      - last_activity: Sort by newest message (implies --with-last-activity)");
     println!("    Sort direction: asc (default), desc");
+    println!("    --with-last-activity: Add last_activity_ts (newest message ts) to each channel");
+    println!("      - One cached conversations.history call per channel, up to --max-enrich (default: 200)");
+    println!("    --snapshot-out=PATH: Write a snapshot of the listed channels (after filters)");
+    println!("    --diff-against=PATH: Print changes since that snapshot instead of the list:");
+    println!("      created, removed, archived, unarchived, renamed, membership_changed");
     println!("    Note: --raw is only valid with --format json");
     println!();
     println!(
@@ -1889,9 +6144,12 @@ pub fn print_conv_usage(prog: &str) {
     println!("    Options accept both --option=value and --option value formats");
     println!();
     println!(
-        "  {} conv history <channel> [--limit=N] [--oldest=TS] [--latest=TS] [--profile=NAME] [--token-type=bot|user]",
+        "  {} conv history <channel> [--limit=N] [--oldest=TS] [--latest=TS] [--cursor=CURSOR] [--all] [--cursor-stability] [--sync] [--sync-state=PATH] [--dedupe-edits] [--only-threads|--only-top-level|--only-broadcasts] [--profile=NAME] [--token-type=bot|user]",
         prog
     );
+    println!("    --sync: fetch only messages newer than the previous --sync run (implies --all)");
+    println!("    --dedupe-edits: collapse message_changed events into the final message version");
+    println!("    --only-threads: keep thread parents with replies (--only-top-level, --only-broadcasts likewise)");
     println!(
         "  {} conv history --interactive [--types=TYPE] [--filter=KEY:VALUE]... [--limit=N] [--profile=NAME]",
         prog
@@ -1899,6 +6157,81 @@ pub fn print_conv_usage(prog: &str) {
     println!("    Select channel interactively before fetching history");
     println!("    Default: Includes public and private channels (limit=1000, auto-paginated)");
     println!("    Options accept both --option=value and --option value formats");
+    println!();
+    println!(
+        "  {} conv replies <channel> <thread_ts> [--limit=N] [--oldest=TS] [--latest=TS] [--cursor=CURSOR] [--all] [--filter=KEY:VALUE]... [--format=FORMAT] [--raw] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Get a thread: the parent message followed by its replies");
+    println!("    Filters: user:<id>, text:<glob>, bot:true|false, thread:true|false, parent:true|false, broadcast:true|false, subtype:<name|none>");
+    println!("    Formats: json (default), jsonl, table, tsv");
+    println!("    --all: Follow cursors and return every reply in one response");
+    println!();
+    println!(
+        "  {} conv members <channel> [--resolve] [--only-guests|--only-external] [--format=FORMAT] [--raw] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    List every member of a conversation (auto-paginated)");
+    println!("    --resolve: Add name, display_name and real_name from the users cache");
+    println!("    --only-guests / --only-external: Keep guests / people from other organizations");
+    println!();
+    println!(
+        "  {} conv export <channel> [--format=json|html] [--out=PATH] [--oldest=TS] [--latest=TS] [--no-threads] [--avatars] [--title=TITLE] [--redact=RULES] [--dedupe-edits] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Export full conversation history (auto-paginated, thread replies included)");
+    println!("    Formats: json (default), html");
+    println!("      - html: Self-contained static page with thread folding and a search box");
+    println!("    --out: Write to file instead of stdout");
+    println!("    --no-threads: Skip fetching thread replies");
+    println!("    --avatars: Show user avatars in HTML output (loads images from Slack)");
+    println!("    --title: Page title for HTML output (default: channel ID)");
+    println!("    --redact: Mask PII before writing: emails, phones, custom:<regex> (comma-separated, repeatable)");
+    println!("    --dedupe-edits: Collapse message_changed events into the edited messages");
+    println!();
+    println!(
+        "  {} conv tail <channel> [--exec=CMD] [--filter=KEY:VALUE]... [--interval=SECS] [--since=TS] [--max-events=N] [--dedupe-edits] [--queue-size=N] [--overflow=POLICY] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!(
+        "  {} conv watch <channel> [--interval=DURATION | --socket] [--app-token=TOKEN] [--filter=KEY:VALUE]... [--since=TS] [--state=PATH] [--max-events=N] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Poll a channel for new messages and print each as a JSON line");
+    println!("    --exec: Run CMD via the shell for each message (message JSON on stdin;");
+    println!("            SLACK_CHANNEL, SLACK_TS and SLACK_USER are set)");
+    println!("    Filters: user:<id>, text:<glob>, bot:true|false, thread:true|false, parent:true|false, broadcast:true|false, subtype:<name|none>");
+    println!("    --interval: Seconds between polls (default: 5)");
+    println!("    --since: Start after this timestamp (default: now)");
+    println!("    --max-events: Exit after dispatching N messages");
+    println!("    --dedupe-edits: Emit an edit as the final message version under its original ts");
+    println!("    --queue-size / --overflow / --spill-file / --meta-interval: Output backpressure (see events listen)");
+    println!();
+    println!(
+        "  {} conv create <name> [--private] [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+        prog
+    );
+    println!("    Create a channel (--private for a private channel)");
+    println!(
+        "  {} conv archive|unarchive <channel> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+        prog
+    );
+    println!(
+        "  {} conv rename <channel> <name> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+        prog
+    );
+    println!(
+        "  {} conv invite <channel> <user>... [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+        prog
+    );
+    println!("    Users may be separate arguments or comma-separated");
+    println!(
+        "  {} conv kick <channel> <user> [--yes] [--profile=NAME] [--token-type=bot|user] [--idempotency-key=KEY]",
+        prog
+    );
+    println!(
+        "    Write subcommands require SLACKCLI_ALLOW_WRITE=true and confirm unless --yes is given"
+    );
 }
 
 pub fn print_thread_usage(prog: &str) {
@@ -1931,18 +6264,35 @@ pub fn print_users_usage(prog: &str) {
         prog
     );
     println!("  {} users resolve-mentions <text> [--profile=NAME] [--format=display_name|real_name|username]", prog);
+    println!("  {} users tz-report [<user_id>...] [--channel=ID] [--hours=09:00-17:00] [--duration=30] [--json] [--profile=NAME] [--token-type=bot|user]", prog);
+    println!("    Shows each user's timezone, overlapping working hours (UTC) and a suggested meeting slot");
+    println!(
+        "  {} users offboard-report <user_id> [--json] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Lists channels, usergroups, files and scheduled messages to handle before deactivation");
     println!("  Options accept both --option=value and --option value formats");
 }
 
 pub fn print_msg_usage(prog: &str) {
     println!("Msg command usage:");
     println!(
-        "  {} msg post <channel> <text> [--thread-ts=TS] [--reply-broadcast] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
+        "  {} msg post <channel> <text> [--text=TEXT|-] [--text-file=PATH|-] [--thread-ts=TS] [--reply-broadcast] [--split] [--split-limit=N] [--auto-snippet] [--markdown] [--from=markdown] [--resolve-mentions] [--pin] [--react=EMOJI,...] [--no-rollback] [--no-footer] [--preview] [--idempotency-key=KEY] [--unique-per=day|hour] [--profile=NAME] [--token-type=bot|user]",
         prog
     );
     println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
+    println!("    --pin / --react: pin the message and/or add reactions after posting;");
+    println!("      if a step fails the message is deleted again unless --no-rollback is given");
+    println!("    --split: chunk long text (code fences respected) and thread the rest under the first chunk");
+    println!("    --auto-snippet: post text over 4000 characters as a snippet file with a lead-in message");
+    println!("    --from=markdown: convert standard Markdown (headings, lists, links, bold) to Slack mrkdwn");
+    println!(
+        "  {} msg inspect <permalink> [--json] [--raw] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Show a message with rendered blocks, reactions, thread stats and files (--raw: message JSON)");
     println!(
-        "  {} msg update <channel> <ts> <text> [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
+        "  {} msg update <channel> <ts> <text> [--markdown] [--from=markdown] [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
         prog
     );
     println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
@@ -1951,8 +6301,39 @@ pub fn print_msg_usage(prog: &str) {
         prog
     );
     println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
+    println!(
+        "  {} msg schedule <channel> <text> (--at=TIME | --in=DURATION) [--thread-ts=TS] [--no-footer] [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
+    println!("    --at: \"2024-07-01 09:00\" (local time), 09:00 (next occurrence), RFC 3339 or UNIX timestamp");
+    println!("    --in: Relative delay, e.g. 30m, 2h, 1h30m, 3d (at most 120 days ahead)");
+    println!(
+        "  {} msg scheduled list [--channel=ID] [--limit=N] [--cursor=CURSOR] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!(
+        "  {} msg scheduled delete <channel> <scheduled_message_id> [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
     println!("  Options accept both --option=value and --option value formats");
     println!("  --idempotency-key: Prevent duplicate writes (replays stored result on retry)");
+    println!(
+        "  --unique-per=day|hour: Post at most once per UTC day/hour for the same channel and text"
+    );
+    println!("  --input json: Read post/update parameters from a JSON object on stdin");
+    println!("  --stdin: Like --input json, running once per object of a JSONL stream");
+    println!();
+    println!(
+        "  {} msg bulk --input=PATH|- [--input-format=jsonl|csv] [--rate=N/s|N/m] [--dry-run] [--report=PATH] [--resume=REPORT] [--no-footer] [--yes] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Post one message per JSONL/CSV row (channel, text, thread_ts, idempotency_key)");
+    println!("    --rate: Posting rate (default: 1/s)");
+    println!("    --report: Write one JSONL result per row to PATH (default: stdout)");
+    println!("    --resume: Skip rows recorded as done in a previous report");
+    println!("    Rows are idempotent: re-running the same input replays rows already posted");
 }
 
 pub fn print_react_usage(prog: &str) {
@@ -1969,16 +6350,264 @@ pub fn print_react_usage(prog: &str) {
     println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
     println!("  Options accept both --option=value and --option value formats");
     println!("  --idempotency-key: Prevent duplicate writes (replays stored result on retry)");
+    println!("  --input json: Read channel, ts and emoji from a JSON object on stdin");
+    println!("  --stdin: Like --input json, running once per object of a JSONL stream");
+    println!("  Colons around the emoji name are stripped (:eyes: -> eyes)");
+    println!("  --check-emoji: resolve aliases and warn about unknown names using the emoji cache");
+}
+
+pub fn print_emoji_usage(prog: &str) {
+    println!("Emoji command usage:");
+    println!(
+        "  {} emoji list [--refresh] [--format=FORMAT] [--raw] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Lists custom emoji; results are cached for 24 hours (--refresh to fetch again)");
+}
+
+pub fn print_pin_usage(prog: &str) {
+    println!("Pin command usage:");
+    println!(
+        "  {} pin add <channel> <ts> [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
+    println!(
+        "  {} pin remove <channel> <ts> [--yes] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
+    println!(
+        "  {} pin list <channel> [--raw] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    List the messages and files pinned in a channel");
+    println!("  Options accept both --option=value and --option value formats");
+    println!("  --idempotency-key: Prevent duplicate writes (replays stored result on retry)");
+}
+
+pub fn print_usergroup_usage(prog: &str) {
+    println!("Usergroup command usage:");
+    println!("  {} usergroup list [--include-users] [--include-disabled] [--format=FORMAT] [--raw] [--profile=NAME] [--token-type=bot|user]", prog);
+    println!("  {} usergroup create <name> [--handle=HANDLE] [--description=TEXT] [--channels=C1,C2] [--yes] [--idempotency-key=KEY]", prog);
+    println!("  {} usergroup update <usergroup> [--name=NAME] [--handle=HANDLE] [--description=TEXT] [--channels=C1,C2] [--yes] [--idempotency-key=KEY]", prog);
+    println!(
+        "  {} usergroup users <usergroup> [--raw] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("  {} usergroup users update <usergroup> (--users=U1,U2 | --from-file=PATH) [--yes] [--idempotency-key=KEY]", prog);
+    println!("    Replaces the member list; the members file holds one user ID per line (# starts a comment)");
+    println!(
+        "  create, update and users update require SLACKCLI_ALLOW_WRITE=true environment variable"
+    );
+    println!("  Options accept both --option=value and --option value formats");
+    println!("  --idempotency-key: Prevent duplicate writes (replays stored result on retry)");
+}
+
+pub fn print_bookmark_usage(prog: &str) {
+    println!("Bookmark command usage:");
+    println!(
+        "  {} bookmark list <channel> [--format=FORMAT] [--profile=NAME]",
+        prog
+    );
+    println!(
+        "  {} bookmark add <channel> --title=TITLE --link=URL [--emoji=EMOJI] [--yes] [--profile=NAME]",
+        prog
+    );
+    println!(
+        "  {} bookmark remove <channel> <bookmark_id> [--yes] [--profile=NAME]",
+        prog
+    );
+    println!(
+        "  {} bookmark apply <channel> --spec=PATH|- [--dry-run] [--keep-unlisted] [--json] [--yes] [--profile=NAME]",
+        prog
+    );
+    println!("  add, remove and apply require SLACKCLI_ALLOW_WRITE=true (or unset)");
+    println!("  Options accept both --option=value and --option value formats");
+}
+
+pub fn print_remind_usage(prog: &str) {
+    println!("Remind command usage:");
+    println!(
+        "  {} remind add <text> --time=WHEN [--user=USER_ID] [--yes] [--profile=NAME]",
+        prog
+    );
+    println!("    WHEN is passed to Slack as is: a UNIX timestamp, seconds from now,");
+    println!("    or natural language (\"in 15 minutes\", \"every Thursday at 9am\")");
+    println!("  {} remind list [--format=FORMAT] [--profile=NAME]", prog);
+    println!(
+        "  {} remind complete <reminder_id> [--yes] [--profile=NAME]",
+        prog
+    );
+    println!(
+        "  {} remind delete <reminder_id> [--yes] [--profile=NAME]",
+        prog
+    );
+    println!("  Reminders always use the profile's user token (--token-type bot is rejected)");
+    println!("  add, complete and delete require SLACKCLI_ALLOW_WRITE=true (or unset)");
+    println!("  Options accept both --option=value and --option value formats");
+}
+
+pub fn print_dnd_usage(prog: &str) {
+    println!("DND command usage:");
+    println!("  {} dnd info [--user=USER_ID] [--profile=NAME]", prog);
+    println!(
+        "  {} dnd set <minutes|duration> [--yes] [--profile=NAME]",
+        prog
+    );
+    println!("    e.g. 45, 90m or 1h30m; durations are rounded up to whole minutes");
+    println!("  {} dnd end [--yes] [--profile=NAME]", prog);
+    println!("  DND commands always use the profile's user token (--token-type bot is rejected)");
+    println!("  set and end require SLACKCLI_ALLOW_WRITE=true (or unset)");
+    println!("  Options accept both --option=value and --option value formats");
+}
+
+pub fn print_presence_usage(prog: &str) {
+    println!("Presence command usage:");
+    println!("  {} presence get [--user=USER_ID] [--profile=NAME]", prog);
+    println!(
+        "  {} presence set <auto|away> [--yes] [--profile=NAME]",
+        prog
+    );
+    println!(
+        "  Presence commands always use the profile's user token (--token-type bot is rejected)"
+    );
+    println!("  set requires SLACKCLI_ALLOW_WRITE=true (or unset)");
+    println!("  Options accept both --option=value and --option value formats");
+}
+
+pub fn print_connect_usage(prog: &str) {
+    println!("Connect (Slack Connect) command usage:");
+    println!(
+        "  {} connect invite <channel> [--emails=a@x.com,...] [--user-ids=U1,...] [--external-limited]",
+        prog
+    );
+    println!("    Invite an external organization to a channel");
+    println!("  {} connect invites [--team-id=T] [--cursor=C]", prog);
+    println!("    List pending incoming invitations");
+    println!(
+        "  {} connect accept <invite_id> <channel_name> [--private]",
+        prog
+    );
+    println!("  {} connect approve <invite_id> [--target-team=T]", prog);
+    println!(
+        "  {} connect decline <invite_id> [--target-team=T] [--yes]",
+        prog
+    );
+    println!(
+        "  {} connect requests list [--include-approved] [--include-denied] [--include-expired] [--user=U] [--limit=N] [--cursor=C]",
+        prog
+    );
+    println!("    List members' requests to send external invitations (admin)");
+    println!(
+        "  {} connect requests approve <invite_id> [--channel=C] [--external-limited] [--message=TEXT]",
+        prog
+    );
+    println!(
+        "  {} connect requests deny <invite_id> [--message=TEXT] [--yes]",
+        prog
+    );
+    println!(
+        "  {} connect permissions <channel> <target_team> <upgrade|downgrade> [--yes]",
+        prog
+    );
+    println!("    Change an external organization's access to a shared channel");
+    println!("  Write subcommands require SLACKCLI_ALLOW_WRITE to not be false");
+    println!("  Common options: [--profile=NAME] [--token-type=bot|user]");
+}
+
+pub fn print_events_usage(prog: &str) {
+    println!("Events command usage:");
+    println!(
+        "  {} events listen [--app-token=XAPP] [--filter=KEY=VALUE]... [--max-events=N] [--no-reconnect] [--heartbeat-file=PATH] [--heartbeat-url=URL] [--health-addr=HOST:PORT] [--queue-size=N] [--overflow=block|drop-oldest|spill] [--spill-file=PATH] [--meta-interval=SECS]",
+        prog
+    );
+    println!("    Receive events over Socket Mode and print each as a JSON line");
+    println!("    Requires an app-level token (xapp-...) via --app-token or SLACK_APP_TOKEN");
+    println!("    Filters: event_type=<type>, envelope_type=<type>, channel=<id>, user=<id>");
+    println!("    --max-events: Exit after printing N events");
+    println!("    --no-reconnect: Exit when the connection ends instead of reconnecting");
+    println!("    --heartbeat-file / --heartbeat-url: Signal liveness on every event and ping");
+    println!("    --health-addr: Serve Prometheus-style /healthz and /metrics on HOST:PORT");
+    println!("    --queue-size: Lines buffered while stdout is slow (default: 1000)");
+    println!("    --overflow: When the queue is full: block (default), drop-oldest or spill to --spill-file");
+    println!("    --meta-interval: Seconds between {{\"meta\":\"stream\"}} lines reporting drops (default: 10)");
+}
+
+pub fn print_kv_usage(prog: &str) {
+    println!("KV command usage:");
+    println!(
+        "  {} kv set <key> <value> [--namespace=NS] [--channel=C] [--if-version=N]",
+        prog
+    );
+    println!("    Store a JSON value (bare words are stored as strings)");
+    println!("    --if-version: Fail unless the stored version is N (0 = key must not exist)");
+    println!("  {} kv get <key> [--namespace=NS] [--channel=C]", prog);
+    println!("  {} kv list [--namespace=NS] [--channel=C]", prog);
+    println!("    Each key is one message in the store channel (--channel or SLACK_RS_KV_CHANNEL)");
+    println!(
+        "    Namespace defaults to 'default'; set requires SLACKCLI_ALLOW_WRITE to not be false"
+    );
+    println!("  Common options: [--profile=NAME] [--token-type=bot|user] [--raw]");
+}
+
+pub fn print_ctx_usage(prog: &str) {
+    println!("Ctx (session context) command usage:");
+    println!(
+        "  {} ctx set [channel <C|#name>] [thread <TS|PERMALINK>]",
+        prog
+    );
+    println!("    Save a default channel/thread; channel names are resolved to IDs");
+    println!("  {} ctx show [--json]", prog);
+    println!("  {} ctx clear [channel|thread]", prog);
+    println!(
+        "  When the leading channel/ts arguments are omitted, these commands use the context:"
+    );
+    println!(
+        "    msg post, react add/remove, pin add/remove, thread get, conv history, conv replies"
+    );
+    println!("    (msg post also replies in the context thread unless --thread-ts is given)");
+    println!("  Global --context channel=C thread=TS overrides the saved context for one command");
+}
+
+pub fn print_idempotency_usage(prog: &str) {
+    println!("Idempotency command usage:");
+    println!("  {} idempotency list", prog);
+    println!("    List stored operations (oldest first)");
+    println!("  {} idempotency show <key>", prog);
+    println!("    Show one entry with its stored response; <key> is the --idempotency-key");
+    println!("    value or the full team/user/method/key");
+    println!(
+        "  {} idempotency clear [--key=KEY] [--older-than=DURATION] [--yes]",
+        prog
+    );
+    println!("    Remove entries so the next call with the same key runs again");
+    println!("    Clearing everything (no --key/--older-than) asks for confirmation");
+    println!("  {} idempotency gc", prog);
+    println!("    Remove expired entries (kept for 7 days) and persist the store");
+    println!("  Output is JSON");
 }
 
 pub fn print_file_usage(prog: &str) {
     println!("File command usage:");
     println!(
-        "  {} file upload <path> [--channel=ID] [--channels=IDs] [--title=TITLE] [--comment=TEXT] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
+        "  {} file upload <path|pattern|->... [--channel=ID] [--channels=IDs] [--title=TITLE] [--comment=TEXT] [--filename=NAME] [--snippet-type=TYPE] [--parallel=N] [--max-dim=PX] [--strip-exif] [--force] [--idempotency-key=KEY] [--profile=NAME] [--token-type=bot|user]",
         prog
     );
     println!("    Upload a file using external upload method");
+    println!(
+        "    Several paths or a pattern ('reports/*.pdf') upload a batch with a per-file report;"
+    );
+    println!("    --parallel: files transferred at the same time (default: 4)");
     println!("    Requires SLACKCLI_ALLOW_WRITE=true environment variable");
+    println!("    Content type is detected from file bytes; large images (>5 MB) print a warning");
+    println!("    --max-dim: Downscale PNG/JPEG so neither side exceeds PX pixels");
+    println!("    --strip-exif: Re-encode PNG/JPEG to remove EXIF metadata");
+    println!("    (--max-dim/--strip-exif require the `image-processing` build feature)");
+    println!(
+        "    Identical content already uploaded to the same channels (within 7 days) is skipped"
+    );
+    println!("    and the previous file is returned; --force uploads anyway");
     println!(
         "  {} file download [<file_id>] [--url=URL] [--out=PATH] [--profile=NAME] [--token-type=bot|user]",
         prog
@@ -1986,6 +6615,20 @@ pub fn print_file_usage(prog: &str) {
     println!("    Download a file from Slack");
     println!("    Either <file_id> or --url must be provided");
     println!("    --out: Output path (omit for current directory, '-' for stdout, directory for auto-naming)");
+    println!(
+        "  {} file list [--channel=ID] [--user=ID] [--types=TYPES] [--older-than=DURATION] [--count=N] [--page=N] [--all] [--format=FORMAT] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!(
+        "    List files (files.list); --older-than=90d keeps files created at least 90 days ago"
+    );
+    println!("    --types: all, spaces, snippets, images, gdocs, zips, pdfs (comma-separated)");
+    println!(
+        "  {} file delete <file_id>... [--from-list=PATH|-] [--yes] [--profile=NAME] [--token-type=bot|user]",
+        prog
+    );
+    println!("    Delete files after one confirmation; the result lists each file");
+    println!("    --from-list: IDs from a file or stdin (file list JSON/JSONL output, or one ID per line)");
     println!("  Options accept both --option=value and --option value formats");
     println!("  --idempotency-key: Prevent duplicate writes (replays stored result on retry, upload only)");
 }
@@ -2211,6 +6854,16 @@ mod tests {
         assert_eq!(get_option(&args, "--filter="), None);
     }
 
+    #[test]
+    fn test_get_option_space_separated_accepts_stdin_dash() {
+        let args = vec![
+            "cmd".to_string(),
+            "--from-list".to_string(),
+            "-".to_string(),
+        ];
+        assert_eq!(get_option(&args, "--from-list="), Some("-".to_string()));
+    }
+
     #[test]
     fn test_get_option_space_separated_missing_value() {
         let args = vec!["cmd".to_string(), "--filter".to_string()];
@@ -2611,4 +7264,196 @@ mod tests {
         assert!(all);
         // This should trigger error in run_conv_list
     }
+
+    fn output_config(
+        global: Option<crate::profile::OutputSettings>,
+        profile: Option<crate::profile::OutputSettings>,
+    ) -> crate::profile::ProfilesConfig {
+        let mut config = crate::profile::ProfilesConfig::new();
+        config.output = global;
+        let mut work = crate::profile::Profile::with_scopes(
+            "T1".to_string(),
+            "U1".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        work.output = profile;
+        config.set("work".to_string(), work);
+        config
+    }
+
+    #[test]
+    fn test_output_preferences_default() {
+        let args = vec!["slack".to_string(), "conv".to_string(), "list".to_string()];
+        let prefs = resolve_output_preferences_with(&args, None, None, "work");
+        assert!(!prefs.raw);
+        assert_eq!(prefs.raw_source, SettingSource::Default);
+        assert_eq!(prefs.format, None);
+        assert_eq!(prefs.format_source, SettingSource::Default);
+    }
+
+    #[test]
+    fn test_output_preferences_profile_overrides_global() {
+        let config = output_config(
+            Some(crate::profile::OutputSettings {
+                envelope: Some(true),
+                format: Some("table".to_string()),
+                ..Default::default()
+            }),
+            Some(crate::profile::OutputSettings {
+                envelope: Some(false),
+                format: None,
+                ..Default::default()
+            }),
+        );
+        let args = vec!["slack".to_string(), "conv".to_string(), "list".to_string()];
+
+        let prefs = resolve_output_preferences_with(&args, None, Some(&config), "work");
+        assert!(prefs.raw);
+        assert_eq!(prefs.raw_source, SettingSource::Profile("work".to_string()));
+        assert_eq!(prefs.format, Some("table".to_string()));
+        assert_eq!(prefs.format_source, SettingSource::Global);
+
+        // Other profiles only see the global settings
+        let prefs = resolve_output_preferences_with(&args, None, Some(&config), "other");
+        assert!(!prefs.raw);
+        assert_eq!(prefs.raw_source, SettingSource::Global);
+    }
+
+    #[test]
+    fn test_output_preferences_flags_and_env_take_precedence() {
+        let config = output_config(
+            None,
+            Some(crate::profile::OutputSettings {
+                envelope: Some(false),
+                format: Some("jsonl".to_string()),
+                ..Default::default()
+            }),
+        );
+
+        let args = vec![
+            "slack".to_string(),
+            "conv".to_string(),
+            "list".to_string(),
+            "--format=tsv".to_string(),
+        ];
+        let prefs = resolve_output_preferences_with(
+            &args,
+            Some("envelope".to_string()),
+            Some(&config),
+            "work",
+        );
+        assert!(!prefs.raw);
+        assert_eq!(prefs.raw_source, SettingSource::Env("SLACKRS_OUTPUT"));
+        assert_eq!(prefs.format, Some("tsv".to_string()));
+        assert_eq!(prefs.format_source, SettingSource::Flag("--format"));
+
+        let args = vec!["slack".to_string(), "--raw".to_string()];
+        let prefs = resolve_output_preferences_with(
+            &args,
+            Some("envelope".to_string()),
+            Some(&config),
+            "work",
+        );
+        assert!(prefs.raw);
+        assert_eq!(prefs.raw_source, SettingSource::Flag("--raw"));
+    }
+
+    #[test]
+    fn test_display_preferences() {
+        let prefs = resolve_display_preferences_with(None, "work");
+        assert_eq!(prefs, DisplayPreferences::default());
+        assert_eq!(prefs.user_name, commands::MentionFormat::DisplayName);
+
+        let config = output_config(
+            Some(crate::profile::OutputSettings {
+                user_name: Some("username".to_string()),
+                time_format: Some("12h".to_string()),
+                locale: Some("en-US".to_string()),
+                ..Default::default()
+            }),
+            Some(crate::profile::OutputSettings {
+                user_name: Some("real_name".to_string()),
+                include_locale: Some(true),
+                ..Default::default()
+            }),
+        );
+        let prefs = resolve_display_preferences_with(Some(&config), "work");
+        assert_eq!(prefs.user_name, commands::MentionFormat::RealName);
+        assert_eq!(prefs.time_format, commands::output::ClockFormat::H12);
+        assert_eq!(prefs.locale.as_deref(), Some("en-US"));
+        assert!(prefs.include_locale);
+
+        // Other profiles only see the global settings
+        let prefs = resolve_display_preferences_with(Some(&config), "other");
+        assert_eq!(prefs.user_name, commands::MentionFormat::Username);
+        assert!(!prefs.include_locale);
+    }
+
+    #[test]
+    fn test_read_message_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.md");
+        std::fs::write(&path, "# Notes\n\n- one\n\n").unwrap();
+        assert_eq!(
+            read_message_text(path.to_str().unwrap()).unwrap(),
+            "# Notes\n\n- one"
+        );
+
+        std::fs::write(&path, " \n").unwrap();
+        assert!(read_message_text(path.to_str().unwrap())
+            .unwrap_err()
+            .contains("is empty"));
+        assert!(
+            read_message_text(dir.path().join("missing.md").to_str().unwrap())
+                .unwrap_err()
+                .starts_with("Failed to read text from")
+        );
+    }
+
+    #[test]
+    fn test_post_footer_profile_overrides_global() {
+        assert_eq!(resolve_post_footer_with(None, "work"), None);
+
+        let footer = |template: &str| crate::profile::OutputSettings {
+            post_footer: Some(template.to_string()),
+            ..Default::default()
+        };
+        let config = output_config(Some(footer("global {job}")), None);
+        assert_eq!(
+            resolve_post_footer_with(Some(&config), "work").as_deref(),
+            Some("global {job}")
+        );
+        let config = output_config(Some(footer("global {job}")), Some(footer("work {job}")));
+        assert_eq!(
+            resolve_post_footer_with(Some(&config), "work").as_deref(),
+            Some("work {job}")
+        );
+        assert_eq!(
+            resolve_post_footer_with(Some(&config), "other").as_deref(),
+            Some("global {job}")
+        );
+    }
+
+    #[test]
+    fn test_render_formatted_columns_override_dedicated_layout() {
+        let response = serde_json::json!({
+            "ok": true,
+            "channels": [{"id": "C1", "name": "general", "topic": {"value": "hi"}}]
+        });
+        let args = vec!["slack".to_string(), "conv".to_string(), "list".to_string()];
+        let tsv =
+            render_formatted(&args, "conv list", &response, commands::OutputFormat::Tsv).unwrap();
+        assert!(tsv.starts_with("id\tname\tis_private\tis_member\tnum_members\n"));
+
+        let mut args = args;
+        args.push("--columns=name,topic.value".to_string());
+        let csv =
+            render_formatted(&args, "conv list", &response, commands::OutputFormat::Csv).unwrap();
+        assert_eq!(csv, "name,topic.value\ngeneral,hi\n");
+    }
 }
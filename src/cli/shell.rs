@@ -0,0 +1,665 @@
+//! Interactive REPL (`slack-rs shell`)
+//!
+//! Reads commands with line editing, persistent history and tab completion
+//! of subcommands and cached channel names. The session profile and token
+//! type can be changed with `set profile <name>` / `set token-type <type>`.
+//!
+//! Every line runs in the shell's own process with the session's
+//! `--profile` / `--token-type` (unless the line gives its own), so the
+//! token store is unlocked once per session instead of once per command.
+//! Global flags apply to their line only, and a failing command only
+//! reports its exit code instead of ending the session.
+
+use super::{generate_commands_list, get_option, parse_token_type, resolve_token_with_type};
+use crate::commands::conv_list;
+use crate::profile::{create_token_store, default_config_path, load_config, TokenType};
+use futures_util::FutureExt;
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::FileHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Commands handled by the shell itself
+pub const SHELL_BUILTINS: &[&str] = &["set", "unset", "show", "refresh-channels", "help", "exit"];
+
+/// Commands that cannot run inside the shell
+const NESTED_COMMANDS: &[&str] = &["shell"];
+
+/// Runs one command line (program name first) the way `main` runs its own
+pub type CommandRunner = dyn Fn(Vec<String>) -> Pin<Box<dyn Future<Output = ()>>>;
+
+/// Set while the shell runs a command line
+static IN_SHELL: AtomicBool = AtomicBool::new(false);
+
+/// Exit code of a command line that ended inside the shell
+struct ShellExit(i32);
+
+/// Exit the process with `code`
+///
+/// Inside `slack-rs shell` only the running command line ends; the shell
+/// reports the code and reads the next line.
+pub fn exit(code: i32) -> ! {
+    if IN_SHELL.load(Ordering::Relaxed) {
+        std::panic::resume_unwind(Box::new(ShellExit(code)));
+    }
+    std::process::exit(code)
+}
+
+/// Run one command line to completion and return its exit code
+async fn run_command_line(command: Pin<Box<dyn Future<Output = ()>>>) -> i32 {
+    IN_SHELL.store(true, Ordering::Relaxed);
+    let result = AssertUnwindSafe(command).catch_unwind().await;
+    IN_SHELL.store(false, Ordering::Relaxed);
+    match result {
+        Ok(()) => 0,
+        Err(payload) => match payload.downcast::<ShellExit>() {
+            Ok(exit) => exit.0,
+            // A panic was already reported by the panic hook
+            Err(_) => 101,
+        },
+    }
+}
+
+/// Per-session settings applied to every command
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShellSession {
+    pub profile: Option<String>,
+    pub token_type: Option<TokenType>,
+}
+
+impl ShellSession {
+    /// Prompt showing the session profile and token type
+    pub fn prompt(&self) -> String {
+        let profile = self.profile.as_deref().unwrap_or("default");
+        match self.token_type {
+            Some(token_type) => format!("slack-rs[{}:{}]> ", profile, token_type),
+            None => format!("slack-rs[{}]> ", profile),
+        }
+    }
+
+    /// Append session settings the command line does not override itself
+    pub fn command_args(&self, words: &[String]) -> Vec<String> {
+        let mut args = words.to_vec();
+        if let Some(profile) = &self.profile {
+            if get_option(words, "--profile=").is_none() {
+                args.push(format!("--profile={}", profile));
+            }
+        }
+        if let Some(token_type) = self.token_type {
+            if !words.iter().any(|w| w.starts_with("--token-type")) {
+                args.push(format!("--token-type={}", token_type));
+            }
+        }
+        args
+    }
+}
+
+/// Built-in shell command
+#[derive(Debug, Clone, PartialEq)]
+pub enum Builtin {
+    SetProfile(String),
+    SetTokenType(TokenType),
+    UnsetProfile,
+    UnsetTokenType,
+    Show,
+    RefreshChannels,
+    Help,
+}
+
+/// Parsed input line
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShellLine {
+    Empty,
+    Exit,
+    Builtin(Builtin),
+    /// Arguments of a `slack-rs` command (without the program name)
+    Command(Vec<String>),
+}
+
+/// Split a line into words, honouring single/double quotes and backslashes
+pub fn split_words(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"'), '\\') | (None, '\\') => {
+                let escaped = chars
+                    .next()
+                    .ok_or_else(|| "Trailing backslash".to_string())?;
+                current.push(escaped);
+                in_word = true;
+            }
+            (Some(_), c) => current.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return Err("Unterminated quote".to_string());
+    }
+    if in_word {
+        words.push(current);
+    }
+    Ok(words)
+}
+
+/// Parse one input line
+pub fn parse_shell_line(line: &str) -> Result<ShellLine, String> {
+    let mut words = split_words(line)?;
+    // Allow pasting full command lines
+    if words.first().map(String::as_str) == Some("slack-rs") {
+        words.remove(0);
+    }
+    let Some(first) = words.first() else {
+        return Ok(ShellLine::Empty);
+    };
+
+    let rest: Vec<&str> = words[1..].iter().map(String::as_str).collect();
+    let builtin = match (first.as_str(), rest.as_slice()) {
+        ("exit" | "quit", []) => return Ok(ShellLine::Exit),
+        ("set", ["profile", name]) => Builtin::SetProfile(name.to_string()),
+        ("set", ["token-type", value]) => {
+            Builtin::SetTokenType(value.parse::<TokenType>().map_err(|e| e.to_string())?)
+        }
+        ("unset", ["profile"]) => Builtin::UnsetProfile,
+        ("unset", ["token-type"]) => Builtin::UnsetTokenType,
+        ("set" | "unset", _) => return Err(
+            "Usage: set profile <name> | set token-type <bot|user> | unset <profile|token-type>"
+                .to_string(),
+        ),
+        ("show", []) => Builtin::Show,
+        ("refresh-channels", []) => Builtin::RefreshChannels,
+        ("help", []) => Builtin::Help,
+        (cmd, _) if NESTED_COMMANDS.contains(&cmd) => {
+            return Err(format!("'{}' cannot be run inside the shell", cmd))
+        }
+        _ => return Ok(ShellLine::Command(words)),
+    };
+    Ok(ShellLine::Builtin(builtin))
+}
+
+/// Cached channel ID and name
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CachedChannel {
+    pub id: String,
+    pub name: String,
+}
+
+/// Channels of one workspace
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkspaceChannels {
+    pub updated_at: u64,
+    pub channels: Vec<CachedChannel>,
+}
+
+/// Channel names used for completion, keyed by team ID
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ChannelNameCache {
+    #[serde(default)]
+    pub teams: HashMap<String, WorkspaceChannels>,
+}
+
+impl ChannelNameCache {
+    /// Default cache location next to the users cache
+    pub fn default_path() -> Result<PathBuf, String> {
+        crate::profile::config_dir()
+            .map(|dir| dir.join("channels_cache.json"))
+            .ok_or_else(|| "Could not determine config directory".to_string())
+    }
+
+    /// Load the cache; a missing or unreadable file yields an empty cache
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the cache, creating the parent directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize channel cache: {}", e))?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write channel cache: {}", e))
+    }
+
+    /// Cached channels of a workspace
+    pub fn channels(&self, team_id: &str) -> Vec<CachedChannel> {
+        self.teams
+            .get(team_id)
+            .map(|w| w.channels.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Command tree and channels used for tab completion
+#[derive(Debug, Clone, Default)]
+pub struct Completions {
+    /// Top-level command -> subcommands
+    pub commands: BTreeMap<String, BTreeSet<String>>,
+    pub channels: Vec<CachedChannel>,
+}
+
+impl Completions {
+    /// Build the command tree from the introspection definitions
+    pub fn from_definitions() -> Self {
+        let mut commands: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for def in generate_commands_list().commands {
+            let mut parts = def.name.split_whitespace();
+            if let Some(top) = parts.next() {
+                let subs = commands.entry(top.to_string()).or_default();
+                if let Some(sub) = parts.next() {
+                    subs.insert(sub.to_string());
+                }
+            }
+        }
+        for builtin in SHELL_BUILTINS {
+            commands.entry(builtin.to_string()).or_default();
+        }
+        for sub in ["profile", "token-type"] {
+            commands.get_mut("set").unwrap().insert(sub.to_string());
+            commands.get_mut("unset").unwrap().insert(sub.to_string());
+        }
+        Self {
+            commands,
+            channels: Vec::new(),
+        }
+    }
+
+    /// Candidates for the word ending at the end of `line`
+    ///
+    /// # Returns
+    /// Start offset of the word being completed and `(display, replacement)` pairs
+    pub fn candidates(&self, line: &str) -> (usize, Vec<(String, String)>) {
+        let start = line.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+        let prefix = &line[start..];
+        let before: Vec<&str> = line[..start].split_whitespace().collect();
+        let plain = |names: Vec<&String>| {
+            names
+                .into_iter()
+                .filter(|n| n.starts_with(prefix))
+                .map(|n| (n.clone(), n.clone()))
+                .collect()
+        };
+
+        let candidates = match before.as_slice() {
+            [] => plain(self.commands.keys().collect()),
+            [top] if !prefix.starts_with('#') => match self.commands.get(*top) {
+                Some(subs) if !subs.is_empty() => plain(subs.iter().collect()),
+                _ => self.channel_candidates(prefix),
+            },
+            _ if prefix.starts_with('-') => Vec::new(),
+            _ => self.channel_candidates(prefix),
+        };
+        (start, candidates)
+    }
+
+    fn channel_candidates(&self, prefix: &str) -> Vec<(String, String)> {
+        let name_prefix = prefix.trim_start_matches('#');
+        self.channels
+            .iter()
+            .filter(|c| c.name.starts_with(name_prefix) || c.id.starts_with(prefix))
+            .map(|c| (format!("#{} ({})", c.name, c.id), c.id.clone()))
+            .collect()
+    }
+}
+
+struct ShellHelper {
+    completions: Completions,
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let (start, candidates) = self.completions.candidates(&line[..pos]);
+        Ok((
+            start,
+            candidates
+                .into_iter()
+                .map(|(display, replacement)| Pair {
+                    display,
+                    replacement,
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Team ID of the session profile, used as the channel cache key
+fn session_team_id(session: &ShellSession) -> Option<String> {
+    let profile_name = session
+        .profile
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    let config = load_config(&default_config_path().ok()?).ok()?;
+    config.get(&profile_name).map(|p| p.team_id.clone())
+}
+
+/// Fetch channel names for the session and store them in the cache
+async fn refresh_channels(session: &ShellSession) -> Result<usize, String> {
+    let token = resolve_token_with_type(session.profile.clone(), session.token_type).await?;
+    let team_id = session_team_id(session).ok_or_else(|| "Profile not found".to_string())?;
+    let client = crate::api::ApiClient::with_token(token);
+    let response = conv_list(
+        &client,
+        Some("public_channel,private_channel".to_string()),
+        None,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    if !response.ok {
+        return Err(response
+            .error
+            .unwrap_or_else(|| "unknown_error".to_string()));
+    }
+
+    let channels: Vec<CachedChannel> = response
+        .data
+        .get("channels")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|c| {
+            Some(CachedChannel {
+                id: c.get("id")?.as_str()?.to_string(),
+                name: c.get("name")?.as_str()?.to_string(),
+            })
+        })
+        .collect();
+    let count = channels.len();
+
+    let path = ChannelNameCache::default_path()?;
+    let mut cache = ChannelNameCache::load(&path);
+    cache.teams.insert(
+        team_id,
+        WorkspaceChannels {
+            updated_at: now_secs(),
+            channels,
+        },
+    );
+    cache.save(&path)?;
+    Ok(count)
+}
+
+fn print_shell_help() {
+    println!("Shell commands:");
+    println!("  <command> [args]          Run any slack-rs command (e.g. conv list --limit=5)");
+    println!("  set profile <name>        Use a profile for the rest of the session");
+    println!("  set token-type <bot|user> Use a token type for the rest of the session");
+    println!("  unset <profile|token-type>");
+    println!("  show                      Show session settings");
+    println!("  refresh-channels          Reload channel names used for tab completion");
+    println!("  help                      Show this help");
+    println!("  exit, quit, Ctrl-D        Leave the shell");
+}
+
+/// Run the interactive shell
+///
+/// # Arguments
+/// * `args` - Command-line arguments (`--profile` / `--token-type` seed the session)
+/// * `run_command` - Runs each command line in this process
+pub async fn run_shell(args: &[String], run_command: &CommandRunner) -> Result<(), String> {
+    let mut session = ShellSession {
+        profile: get_option(args, "--profile="),
+        token_type: parse_token_type(args)?,
+    };
+    let program = args
+        .first()
+        .cloned()
+        .unwrap_or_else(|| "slack-rs".to_string());
+    // Unlock the token store now, so an encrypted store asks for its
+    // passphrase once for the whole session
+    create_token_store().map_err(|e| e.to_string())?;
+
+    let cache_path = ChannelNameCache::default_path()?;
+    let load_channels = |session: &ShellSession| {
+        session_team_id(session)
+            .map(|team_id| ChannelNameCache::load(&cache_path).channels(&team_id))
+            .unwrap_or_default()
+    };
+
+    let mut editor: Editor<ShellHelper, FileHistory> =
+        Editor::new().map_err(|e| format!("Failed to start line editor: {}", e))?;
+    let mut completions = Completions::from_definitions();
+    completions.channels = load_channels(&session);
+    editor.set_helper(Some(ShellHelper { completions }));
+    let history_path = cache_path.with_file_name("shell_history.txt");
+    let _ = editor.load_history(&history_path);
+
+    println!("slack-rs shell. Type 'help' for shell commands, 'exit' to quit.");
+
+    loop {
+        let line = match editor.readline(&session.prompt()) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(format!("Failed to read input: {}", e)),
+        };
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+
+        let parsed = match parse_shell_line(&line) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("{}", e);
+                continue;
+            }
+        };
+
+        match parsed {
+            ShellLine::Empty => {}
+            ShellLine::Exit => break,
+            ShellLine::Builtin(builtin) => {
+                let settings_changed = matches!(
+                    builtin,
+                    Builtin::SetProfile(_)
+                        | Builtin::SetTokenType(_)
+                        | Builtin::UnsetProfile
+                        | Builtin::UnsetTokenType
+                );
+                match builtin {
+                    Builtin::SetProfile(name) => session.profile = Some(name),
+                    Builtin::SetTokenType(token_type) => session.token_type = Some(token_type),
+                    Builtin::UnsetProfile => session.profile = None,
+                    Builtin::UnsetTokenType => session.token_type = None,
+                    Builtin::Show => {
+                        println!(
+                            "profile: {}",
+                            session.profile.as_deref().unwrap_or("default")
+                        );
+                        println!(
+                            "token-type: {}",
+                            session
+                                .token_type
+                                .map(|t| t.to_string())
+                                .unwrap_or_else(|| "(profile default)".to_string())
+                        );
+                    }
+                    Builtin::RefreshChannels => match refresh_channels(&session).await {
+                        Ok(count) => {
+                            println!("Cached {} channels", count);
+                            if let Some(helper) = editor.helper_mut() {
+                                helper.completions.channels = load_channels(&session);
+                            }
+                        }
+                        Err(e) => eprintln!("Failed to refresh channels: {}", e),
+                    },
+                    Builtin::Help => print_shell_help(),
+                }
+                if settings_changed {
+                    if let Some(helper) = editor.helper_mut() {
+                        helper.completions.channels = load_channels(&session);
+                    }
+                }
+            }
+            ShellLine::Command(words) => {
+                let mut command_line = vec![program.clone()];
+                command_line.extend(session.command_args(&words));
+                let code = run_command_line(run_command(command_line)).await;
+                if code != 0 {
+                    eprintln!("(exit {})", code);
+                }
+            }
+        }
+    }
+
+    let _ = editor.save_history(&history_path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words_quotes() {
+        assert_eq!(
+            split_words(r#"msg post C1 "hello world" 'it''s' a\ b"#).unwrap(),
+            vec!["msg", "post", "C1", "hello world", "its", "a b"]
+        );
+        assert_eq!(split_words(r#"x "" y"#).unwrap(), vec!["x", "", "y"]);
+        assert!(split_words(r#"msg post "open"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_shell_line() {
+        assert_eq!(parse_shell_line("   ").unwrap(), ShellLine::Empty);
+        assert_eq!(parse_shell_line("quit").unwrap(), ShellLine::Exit);
+        assert_eq!(
+            parse_shell_line("set profile work").unwrap(),
+            ShellLine::Builtin(Builtin::SetProfile("work".to_string()))
+        );
+        assert_eq!(
+            parse_shell_line("set token-type user").unwrap(),
+            ShellLine::Builtin(Builtin::SetTokenType(TokenType::User))
+        );
+        assert!(parse_shell_line("set token-type admin").is_err());
+        assert!(parse_shell_line("shell").is_err());
+        assert_eq!(
+            parse_shell_line("slack-rs conv list --limit=5").unwrap(),
+            ShellLine::Command(vec![
+                "conv".to_string(),
+                "list".to_string(),
+                "--limit=5".to_string()
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_command_line_captures_exit() {
+        assert_eq!(run_command_line(Box::pin(async {})).await, 0);
+        assert_eq!(run_command_line(Box::pin(async { exit(3) })).await, 3);
+        assert!(!IN_SHELL.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_session_command_args() {
+        let session = ShellSession {
+            profile: Some("work".to_string()),
+            token_type: Some(TokenType::Bot),
+        };
+        let words: Vec<String> = vec!["conv".into(), "list".into()];
+        assert_eq!(
+            session.command_args(&words),
+            vec!["conv", "list", "--profile=work", "--token-type=bot"]
+        );
+        assert_eq!(session.prompt(), "slack-rs[work:bot]> ");
+
+        let words: Vec<String> = vec!["search".into(), "x".into(), "--profile=other".into()];
+        assert_eq!(
+            session.command_args(&words),
+            vec!["search", "x", "--profile=other", "--token-type=bot"]
+        );
+    }
+
+    #[test]
+    fn test_completion_candidates() {
+        let mut completions = Completions::from_definitions();
+        completions.channels = vec![
+            CachedChannel {
+                id: "C001".to_string(),
+                name: "general".to_string(),
+            },
+            CachedChannel {
+                id: "C002".to_string(),
+                name: "random".to_string(),
+            },
+        ];
+
+        let (start, top) = completions.candidates("co");
+        assert_eq!(start, 0);
+        assert!(top.iter().any(|(_, r)| r == "conv"));
+
+        let (start, subs) = completions.candidates("conv hi");
+        assert_eq!(start, 5);
+        assert_eq!(subs, vec![("history".to_string(), "history".to_string())]);
+
+        let (_, channels) = completions.candidates("conv history #gen");
+        assert_eq!(
+            channels,
+            vec![("#general (C001)".to_string(), "C001".to_string())]
+        );
+        let (_, channels) = completions.candidates("msg post r");
+        assert_eq!(channels[0].1, "C002");
+        assert!(completions.candidates("conv list --li").1.is_empty());
+
+        let (_, set) = completions.candidates("set to");
+        assert_eq!(set[0].0, "token-type");
+    }
+}
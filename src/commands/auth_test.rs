@@ -0,0 +1,228 @@
+//! Token identity check (`auth test`)
+//!
+//! Calls `auth.test` with a profile's token and reports who the token acts
+//! as: team, user, bot and the OAuth scopes granted to it. The scopes are not
+//! part of the response body; Slack returns them in the `x-oauth-scopes`
+//! response header.
+//!
+//! `whoami` shows the same identity in one compact view.
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::{format_error_guidance, ApiClient, ApiClientError, ApiError, RequestBody};
+use crate::profile::TokenType;
+
+/// Identity of a token as reported by `auth.test`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthTestReport {
+    pub team_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    pub user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    /// Token type judged by its prefix ("bot", "user", "app"), or "unknown"
+    pub token_type: String,
+    /// Scopes from the `x-oauth-scopes` header, empty when not reported
+    pub scopes: Vec<String>,
+}
+
+/// Parse a comma-separated `x-oauth-scopes` header value
+pub fn parse_scopes_header(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Call `auth.test` with the client's token
+///
+/// # Returns
+/// * `Ok(AuthTestReport)` - Identity and scopes of the token
+/// * `Err(ApiError)` - Request failed or Slack returned an error
+pub async fn auth_test(client: &ApiClient) -> Result<AuthTestReport, ApiError> {
+    let token = client
+        .token
+        .as_deref()
+        .ok_or_else(|| ApiError::SlackError("No token configured".to_string()))?;
+
+    let response = client
+        .call(
+            Method::POST,
+            "auth.test",
+            token,
+            RequestBody::None,
+            Vec::new(),
+        )
+        .await
+        .map_err(|e| match e {
+            ApiClientError::RequestFailed(e) => ApiError::RequestFailed(e),
+            other => ApiError::SlackError(other.to_string()),
+        })?;
+
+    let scopes = response
+        .headers()
+        .get("x-oauth-scopes")
+        .and_then(|v| v.to_str().ok())
+        .map(parse_scopes_header)
+        .unwrap_or_default();
+    let body: Value = response.json().await?;
+
+    if body.get("ok").and_then(|v| v.as_bool()) != Some(true) {
+        let error_code = body
+            .get("error")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown error");
+        if let Some(guidance) = format_error_guidance(error_code) {
+            eprintln!("{}", guidance);
+        }
+        return Err(ApiError::SlackError(error_code.to_string()));
+    }
+
+    let text = |key: &str| body.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    Ok(AuthTestReport {
+        team_id: text("team_id").unwrap_or_default(),
+        team: text("team"),
+        user_id: text("user_id").unwrap_or_default(),
+        user: text("user"),
+        bot_id: text("bot_id"),
+        url: text("url"),
+        enterprise_id: text("enterprise_id"),
+        token_type: TokenType::from_token(token)
+            .map(|t| t.as_str())
+            .unwrap_or("unknown")
+            .to_string(),
+        scopes,
+    })
+}
+
+/// Render a report as plain text
+pub fn format_auth_test_text(profile_name: &str, report: &AuthTestReport) -> String {
+    let named = |name: &Option<String>, id: &str| match name {
+        Some(name) => format!("{} ({})", name, id),
+        None => id.to_string(),
+    };
+
+    let mut out = format!("Profile: {}\n", profile_name);
+    out.push_str(&format!(
+        "Team:    {}",
+        named(&report.team, &report.team_id)
+    ));
+    if let Some(ref url) = report.url {
+        out.push_str(&format!("  {}", url));
+    }
+    out.push('\n');
+    out.push_str(&format!(
+        "User:    {}\n",
+        named(&report.user, &report.user_id)
+    ));
+    if let Some(ref bot_id) = report.bot_id {
+        out.push_str(&format!("Bot:     {}\n", bot_id));
+    }
+    let prefix = report
+        .token_type
+        .parse::<TokenType>()
+        .map(|t| format!(" ({})", t.token_prefix()))
+        .unwrap_or_default();
+    out.push_str(&format!("Token:   {}{}\n", report.token_type, prefix));
+    if report.scopes.is_empty() {
+        out.push_str("Scopes:  (not reported)\n");
+    } else {
+        out.push_str(&format!("Scopes:  {}\n", report.scopes.join(", ")));
+    }
+    out
+}
+
+/// Render a report as the compact `whoami` view
+///
+/// # Arguments
+/// * `profile_name` - Resolved profile name
+/// * `token_source` - Where the token came from (`SLACK_TOKEN` or the token store backend)
+/// * `report` - Identity reported by `auth.test`
+pub fn format_whoami_text(
+    profile_name: &str,
+    token_source: &str,
+    report: &AuthTestReport,
+) -> String {
+    let named = |name: &Option<String>, id: &str| match name {
+        Some(name) => format!("{} ({})", name, id),
+        None => id.to_string(),
+    };
+    let scopes = if report.scopes.is_empty() {
+        "(not reported)".to_string()
+    } else {
+        report.scopes.join(",")
+    };
+    format!(
+        "{} @ {} as {} [{} token from {}]\nscopes: {}\n",
+        profile_name,
+        named(&report.team, &report.team_id),
+        named(&report.user, &report.user_id),
+        report.token_type,
+        token_source,
+        scopes
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scopes_header() {
+        assert_eq!(
+            parse_scopes_header("chat:write, channels:read,,users:read"),
+            vec!["chat:write", "channels:read", "users:read"]
+        );
+        assert!(parse_scopes_header("").is_empty());
+    }
+
+    #[test]
+    fn test_format_auth_test_text() {
+        let report = AuthTestReport {
+            team_id: "T1".to_string(),
+            team: Some("Acme".to_string()),
+            user_id: "U1".to_string(),
+            user: Some("acme-bot".to_string()),
+            bot_id: Some("B1".to_string()),
+            url: Some("https://acme.slack.com/".to_string()),
+            enterprise_id: None,
+            token_type: "bot".to_string(),
+            scopes: vec!["chat:write".to_string(), "users:read".to_string()],
+        };
+        let text = format_auth_test_text("work", &report);
+        assert!(text.contains("Team:    Acme (T1)  https://acme.slack.com/\n"));
+        assert!(text.contains("Bot:     B1\n"));
+        assert!(text.contains("Token:   bot (xoxb-)\n"));
+        assert!(text.contains("Scopes:  chat:write, users:read\n"));
+    }
+
+    #[test]
+    fn test_format_whoami_text() {
+        let report = AuthTestReport {
+            team_id: "T1".to_string(),
+            team: Some("Acme".to_string()),
+            user_id: "U1".to_string(),
+            user: Some("alice".to_string()),
+            bot_id: None,
+            url: None,
+            enterprise_id: None,
+            token_type: "user".to_string(),
+            scopes: vec!["search:read".to_string(), "chat:write".to_string()],
+        };
+        assert_eq!(
+            format_whoami_text("work", "file", &report),
+            "work @ Acme (T1) as alice (U1) [user token from file]\nscopes: search:read,chat:write\n"
+        );
+    }
+}
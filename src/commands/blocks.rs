@@ -0,0 +1,275 @@
+//! Block Kit payloads for `msg post --blocks`
+//!
+//! `--blocks` accepts inline JSON, `@path` to read a file, or `-` for stdin.
+//! Both a bare block array and a Block Kit Builder export (`{"blocks": [...]}`)
+//! are accepted. The structure is checked locally against the limits Slack
+//! enforces so mistakes fail fast with a pointer to the offending block
+//! instead of an opaque `invalid_blocks` from the API.
+
+use serde_json::Value;
+use std::io::Read;
+use thiserror::Error;
+
+/// Maximum blocks in one message
+pub const MAX_BLOCKS: usize = 50;
+/// Maximum elements in an `actions` block
+pub const MAX_ACTION_ELEMENTS: usize = 25;
+/// Maximum elements in a `context` block
+pub const MAX_CONTEXT_ELEMENTS: usize = 10;
+/// Maximum length of a `block_id`
+pub const MAX_BLOCK_ID_LEN: usize = 255;
+/// Maximum length of a section text
+pub const MAX_SECTION_TEXT_LEN: usize = 3000;
+/// Maximum length of a header text
+pub const MAX_HEADER_TEXT_LEN: usize = 150;
+
+/// Block Kit errors
+#[derive(Debug, Error)]
+pub enum BlocksError {
+    #[error("Failed to read blocks from {source_name}: {message}")]
+    Read {
+        source_name: String,
+        message: String,
+    },
+    #[error("Blocks are not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid blocks: {0}")]
+    Invalid(String),
+    #[error("Invalid block #{index} ({block_type}): {message}")]
+    InvalidBlock {
+        index: usize,
+        block_type: String,
+        message: String,
+    },
+}
+
+/// Load and validate a `--blocks` value (`<json>`, `@file` or `-`)
+pub fn load_blocks(spec: &str) -> Result<Value, BlocksError> {
+    let content = if spec == "-" {
+        let mut buf = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buf)
+            .map_err(|e| BlocksError::Read {
+                source_name: "stdin".to_string(),
+                message: e.to_string(),
+            })?;
+        buf
+    } else if let Some(path) = spec.strip_prefix('@') {
+        std::fs::read_to_string(path).map_err(|e| BlocksError::Read {
+            source_name: path.to_string(),
+            message: e.to_string(),
+        })?
+    } else {
+        spec.to_string()
+    };
+
+    parse_blocks(&content)
+}
+
+/// Parse a blocks JSON document and validate it
+///
+/// # Returns
+/// The block array to send as the `blocks` parameter
+pub fn parse_blocks(content: &str) -> Result<Value, BlocksError> {
+    let value: Value = serde_json::from_str(content)?;
+    let blocks = match value {
+        Value::Object(mut obj) if obj.contains_key("blocks") => obj.remove("blocks").unwrap(),
+        other => other,
+    };
+    validate_blocks(&blocks)?;
+    Ok(blocks)
+}
+
+/// Validate the structure of a block array
+pub fn validate_blocks(blocks: &Value) -> Result<(), BlocksError> {
+    let blocks = blocks
+        .as_array()
+        .ok_or_else(|| BlocksError::Invalid("expected a JSON array of blocks".to_string()))?;
+    if blocks.is_empty() {
+        return Err(BlocksError::Invalid(
+            "at least one block is required".to_string(),
+        ));
+    }
+    if blocks.len() > MAX_BLOCKS {
+        return Err(BlocksError::Invalid(format!(
+            "{} blocks given, maximum is {}",
+            blocks.len(),
+            MAX_BLOCKS
+        )));
+    }
+
+    for (index, block) in blocks.iter().enumerate() {
+        let block_type = block
+            .get("type")
+            .and_then(|t| t.as_str())
+            .unwrap_or_default();
+        let invalid = |message: String| BlocksError::InvalidBlock {
+            index,
+            block_type: if block_type.is_empty() {
+                "?".to_string()
+            } else {
+                block_type.to_string()
+            },
+            message,
+        };
+
+        if !block.is_object() {
+            return Err(invalid("block must be an object".to_string()));
+        }
+        if block_type.is_empty() {
+            return Err(invalid("missing required field 'type'".to_string()));
+        }
+        if let Some(block_id) = block.get("block_id").and_then(|v| v.as_str()) {
+            if block_id.len() > MAX_BLOCK_ID_LEN {
+                return Err(invalid(format!(
+                    "block_id is longer than {} characters",
+                    MAX_BLOCK_ID_LEN
+                )));
+            }
+        }
+
+        match block_type {
+            "section" => {
+                if block.get("text").is_none() && block.get("fields").is_none() {
+                    return Err(invalid("requires 'text' or 'fields'".to_string()));
+                }
+                if let Some(text) = block.get("text") {
+                    check_text_object(text, MAX_SECTION_TEXT_LEN).map_err(invalid)?;
+                }
+            }
+            "header" => {
+                let text = block
+                    .get("text")
+                    .ok_or_else(|| invalid("missing required field 'text'".to_string()))?;
+                check_text_object(text, MAX_HEADER_TEXT_LEN).map_err(invalid)?;
+                if text.get("type").and_then(|t| t.as_str()) != Some("plain_text") {
+                    return Err(invalid("header text must be plain_text".to_string()));
+                }
+            }
+            "context" => check_elements(block, MAX_CONTEXT_ELEMENTS).map_err(invalid)?,
+            "actions" => check_elements(block, MAX_ACTION_ELEMENTS).map_err(invalid)?,
+            "image" => {
+                if block.get("image_url").is_none() && block.get("slack_file").is_none() {
+                    return Err(invalid("requires 'image_url' or 'slack_file'".to_string()));
+                }
+                if block.get("alt_text").and_then(|v| v.as_str()).is_none() {
+                    return Err(invalid("missing required field 'alt_text'".to_string()));
+                }
+            }
+            // Other block types (divider, rich_text, input, file, video, ...)
+            // are passed through to Slack as-is
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn check_text_object(text: &Value, max_len: usize) -> Result<(), String> {
+    let kind = text.get("type").and_then(|t| t.as_str());
+    if !matches!(kind, Some("plain_text") | Some("mrkdwn")) {
+        return Err("text object 'type' must be plain_text or mrkdwn".to_string());
+    }
+    let body = text
+        .get("text")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| "text object is missing 'text'".to_string())?;
+    if body.chars().count() > max_len {
+        return Err(format!("text is longer than {} characters", max_len));
+    }
+    Ok(())
+}
+
+fn check_elements(block: &Value, max: usize) -> Result<(), String> {
+    let elements = block
+        .get("elements")
+        .and_then(|e| e.as_array())
+        .ok_or_else(|| "missing required field 'elements'".to_string())?;
+    if elements.is_empty() || elements.len() > max {
+        return Err(format!("requires 1 to {} elements", max));
+    }
+    Ok(())
+}
+
+/// Plain-text fallback for notifications, from header and section texts
+pub fn fallback_text(blocks: &Value) -> String {
+    blocks
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|b| {
+            b.get("text")
+                .and_then(|t| t.get("text"))
+                .and_then(|t| t.as_str())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_blocks_accepts_builder_export() {
+        let blocks = parse_blocks(
+            r#"{"blocks": [{"type": "header", "text": {"type": "plain_text", "text": "Deploy"}},
+                          {"type": "divider"},
+                          {"type": "section", "text": {"type": "mrkdwn", "text": "*ok*"}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(blocks.as_array().unwrap().len(), 3);
+        assert_eq!(fallback_text(&blocks), "Deploy\n*ok*");
+    }
+
+    #[test]
+    fn test_validate_blocks_errors() {
+        assert!(matches!(
+            validate_blocks(&json!({"type": "divider"})),
+            Err(BlocksError::Invalid(_))
+        ));
+        assert!(matches!(
+            validate_blocks(&json!([])),
+            Err(BlocksError::Invalid(_))
+        ));
+        let too_many: Vec<Value> = (0..=MAX_BLOCKS)
+            .map(|_| json!({"type": "divider"}))
+            .collect();
+        assert!(matches!(
+            validate_blocks(&Value::Array(too_many)),
+            Err(BlocksError::Invalid(_))
+        ));
+
+        let err = validate_blocks(&json!([{"type": "divider"}, {"type": "section"}])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid block #1 (section): requires 'text' or 'fields'"
+        );
+        assert!(validate_blocks(&json!([{"text": "no type"}])).is_err());
+        assert!(validate_blocks(&json!([
+            {"type": "header", "text": {"type": "mrkdwn", "text": "bold"}}
+        ]))
+        .is_err());
+        assert!(validate_blocks(&json!([{"type": "actions", "elements": []}])).is_err());
+        assert!(validate_blocks(&json!([{"type": "image", "image_url": "https://x"}])).is_err());
+    }
+
+    #[test]
+    fn test_load_blocks_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blocks.json");
+        std::fs::write(&path, r#"[{"type": "divider"}]"#).unwrap();
+
+        let blocks = load_blocks(&format!("@{}", path.display())).unwrap();
+        assert_eq!(blocks, json!([{"type": "divider"}]));
+        assert!(matches!(
+            load_blocks("@/nonexistent/blocks.json"),
+            Err(BlocksError::Read { .. })
+        ));
+        assert!(matches!(
+            load_blocks("{not json"),
+            Err(BlocksError::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,631 @@
+//! Channel bookmark command implementations (`bookmark list/add/remove/apply`)
+//!
+//! `bookmark apply` converges a channel on a declared bookmark set: the spec
+//! (YAML or JSON) is diffed against `bookmarks.list` and only the missing,
+//! changed or unlisted link bookmarks are created, edited or removed.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Build a `bookmarks.list` request
+pub fn plan_bookmarks_list(channel: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::BookmarksList).param("channel_id", channel)
+}
+
+/// Build a `bookmarks.add` request for a link bookmark
+pub fn plan_bookmarks_add(
+    channel: &str,
+    title: &str,
+    link: &str,
+    emoji: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::BookmarksAdd)
+        .param("channel_id", channel)
+        .param("title", title)
+        .param("type", "link")
+        .param("link", link)
+        .param_opt("emoji", emoji)
+}
+
+/// Build a `bookmarks.edit` request
+pub fn plan_bookmarks_edit(
+    channel: &str,
+    bookmark_id: &str,
+    title: &str,
+    link: &str,
+    emoji: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::BookmarksEdit)
+        .param("channel_id", channel)
+        .param("bookmark_id", bookmark_id)
+        .param("title", title)
+        .param("link", link)
+        .param_opt("emoji", emoji)
+}
+
+/// Build a `bookmarks.remove` request
+pub fn plan_bookmarks_remove(channel: &str, bookmark_id: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::BookmarksRemove)
+        .param("channel_id", channel)
+        .param("bookmark_id", bookmark_id)
+}
+
+/// List the bookmarks of a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with `bookmarks`
+/// * `Err(ApiError)` if the operation fails
+pub async fn bookmark_list(client: &ApiClient, channel: &str) -> Result<ApiResponse, ApiError> {
+    client.execute_planned(plan_bookmarks_list(channel)).await
+}
+
+/// Add a link bookmark to a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `title` - Bookmark title
+/// * `link` - URL the bookmark opens
+/// * `emoji` - Optional emoji shown next to the title (`:rocket:`)
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the created `bookmark`
+/// * `Err(ApiError)` if the operation fails
+pub async fn bookmark_add(
+    client: &ApiClient,
+    channel: String,
+    title: String,
+    link: String,
+    emoji: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs bookmark add {} --title=\"{}\" --link={} --yes",
+        channel, title, link
+    );
+    confirm_destructive_with_hint(yes, "add this bookmark", non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_bookmarks_add(
+            &channel,
+            &title,
+            &link,
+            emoji.as_deref(),
+        ))
+        .await
+}
+
+/// Remove a bookmark from a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `bookmark_id` - Bookmark ID (`Bk...`)
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with removal confirmation
+/// * `Err(ApiError)` if the operation fails
+pub async fn bookmark_remove(
+    client: &ApiClient,
+    channel: String,
+    bookmark_id: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs bookmark remove {} {} --yes",
+        channel, bookmark_id
+    );
+    confirm_destructive_with_hint(yes, "remove this bookmark", non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_bookmarks_remove(&channel, &bookmark_id))
+        .await
+}
+
+/// One desired bookmark in a `bookmark apply` spec
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BookmarkSpec {
+    pub title: String,
+    pub link: String,
+    /// Emoji shown next to the title; left as-is on the channel when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BookmarkSpecFile {
+    List(Vec<BookmarkSpec>),
+    Wrapped { bookmarks: Vec<BookmarkSpec> },
+}
+
+/// Parse a bookmark spec: a list of `{title, link, emoji}` entries, or an
+/// object with such a list under `bookmarks`
+///
+/// YAML and JSON are both accepted. Emoji are normalized to `:name:`, and
+/// titles and links must be unique so every entry maps to one bookmark.
+pub fn parse_bookmark_spec(content: &str) -> Result<Vec<BookmarkSpec>, String> {
+    let file: BookmarkSpecFile =
+        serde_yaml::from_str(content).map_err(|e| format!("Invalid bookmark spec: {}", e))?;
+    let mut specs = match file {
+        BookmarkSpecFile::List(specs) | BookmarkSpecFile::Wrapped { bookmarks: specs } => specs,
+    };
+    for (i, spec) in specs.iter_mut().enumerate() {
+        spec.title = spec.title.trim().to_string();
+        spec.link = spec.link.trim().to_string();
+        if spec.title.is_empty() || spec.link.is_empty() {
+            return Err(format!(
+                "Invalid bookmark spec: entry #{} needs a title and a link",
+                i + 1
+            ));
+        }
+        spec.emoji = spec.emoji.as_deref().and_then(normalize_emoji);
+    }
+    for (i, spec) in specs.iter().enumerate() {
+        if let Some(dup) = specs[..i]
+            .iter()
+            .find(|s| s.title == spec.title || s.link == spec.link)
+        {
+            let what = if dup.title == spec.title {
+                format!("title '{}'", spec.title)
+            } else {
+                format!("link {}", spec.link)
+            };
+            return Err(format!(
+                "Invalid bookmark spec: duplicate {} (entry #{})",
+                what,
+                i + 1
+            ));
+        }
+    }
+    Ok(specs)
+}
+
+fn normalize_emoji(emoji: &str) -> Option<String> {
+    let name = emoji.trim().trim_matches(':');
+    (!name.is_empty()).then(|| format!(":{}:", name))
+}
+
+/// What `bookmark apply` does with one bookmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BookmarkAction {
+    Create,
+    Update,
+    Remove,
+    Unchanged,
+}
+
+/// One entry of a `bookmark apply` plan or report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookmarkChange {
+    pub action: BookmarkAction,
+    /// ID of the existing bookmark (all actions except `create`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bookmark_id: Option<String>,
+    pub title: String,
+    pub link: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// Title, link and emoji before an `update`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<BookmarkSpec>,
+    /// Error returned by Slack when the change could not be applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of `bookmark apply`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookmarkApplyReport {
+    pub channel: String,
+    pub dry_run: bool,
+    pub changes: Vec<BookmarkChange>,
+    pub created: usize,
+    pub updated: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+    pub failed: usize,
+}
+
+impl BookmarkApplyReport {
+    fn new(channel: &str, dry_run: bool, changes: Vec<BookmarkChange>) -> Self {
+        let applied = |action| {
+            changes
+                .iter()
+                .filter(|c| c.action == action && c.error.is_none())
+                .count()
+        };
+        Self {
+            channel: channel.to_string(),
+            dry_run,
+            created: applied(BookmarkAction::Create),
+            updated: applied(BookmarkAction::Update),
+            removed: applied(BookmarkAction::Remove),
+            unchanged: applied(BookmarkAction::Unchanged),
+            failed: changes.iter().filter(|c| c.error.is_some()).count(),
+            changes,
+        }
+    }
+}
+
+/// Existing link bookmark as returned by `bookmarks.list`
+fn existing_link(bookmark: &Value) -> Option<(String, BookmarkSpec)> {
+    let field = |name: &str| bookmark.get(name).and_then(|v| v.as_str());
+    if field("type").is_some_and(|t| t != "link") {
+        return None;
+    }
+    Some((
+        field("id")?.to_string(),
+        BookmarkSpec {
+            title: field("title").unwrap_or_default().to_string(),
+            link: field("link")?.to_string(),
+            emoji: field("emoji").and_then(normalize_emoji),
+        },
+    ))
+}
+
+/// Diff the desired bookmarks against the `bookmarks` of a `bookmarks.list`
+/// response
+///
+/// A desired entry matches the existing bookmark with the same link, or
+/// failing that the same title, so renames and link moves become edits.
+/// Existing link bookmarks matched by no entry are removed unless
+/// `keep_unlisted`; folders and other bookmark types are never touched.
+pub fn diff_bookmarks(
+    existing: &[Value],
+    desired: &[BookmarkSpec],
+    keep_unlisted: bool,
+) -> Vec<BookmarkChange> {
+    let mut existing: Vec<Option<(String, BookmarkSpec)>> =
+        existing.iter().map(existing_link).collect();
+    let mut take = |matches: &dyn Fn(&BookmarkSpec) -> bool| {
+        existing
+            .iter_mut()
+            .find(|e| e.as_ref().is_some_and(|(_, spec)| matches(spec)))
+            .and_then(Option::take)
+    };
+
+    let mut changes = Vec::new();
+    for spec in desired {
+        let found = take(&|e| e.link == spec.link).or_else(|| take(&|e| e.title == spec.title));
+        let change = |action, bookmark_id, previous| BookmarkChange {
+            action,
+            bookmark_id,
+            title: spec.title.clone(),
+            link: spec.link.clone(),
+            emoji: spec.emoji.clone(),
+            previous,
+            error: None,
+        };
+        changes.push(match found {
+            None => change(BookmarkAction::Create, None, None),
+            Some((id, current)) => {
+                // An omitted emoji leaves the current one alone
+                let same = current.title == spec.title
+                    && current.link == spec.link
+                    && (spec.emoji.is_none() || current.emoji == spec.emoji);
+                if same {
+                    change(BookmarkAction::Unchanged, Some(id), None)
+                } else {
+                    change(BookmarkAction::Update, Some(id), Some(current))
+                }
+            }
+        });
+    }
+    if !keep_unlisted {
+        changes.extend(
+            existing
+                .into_iter()
+                .flatten()
+                .map(|(id, current)| BookmarkChange {
+                    action: BookmarkAction::Remove,
+                    bookmark_id: Some(id),
+                    title: current.title,
+                    link: current.link,
+                    emoji: current.emoji,
+                    previous: None,
+                    error: None,
+                }),
+        );
+    }
+    changes
+}
+
+/// Converge the bookmarks of a channel on a spec
+///
+/// Changes are applied in plan order; a failing change is recorded in the
+/// report and the remaining ones still run. Confirmation is asked once,
+/// and only when there is something to change.
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `desired` - Parsed spec
+/// * `keep_unlisted` - Keep link bookmarks the spec does not list
+/// * `dry_run` - Only report the plan
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(BookmarkApplyReport)` with one entry per bookmark
+/// * `Err(ApiError)` if listing fails or the write is not allowed
+pub async fn bookmark_apply(
+    client: &ApiClient,
+    channel: &str,
+    desired: &[BookmarkSpec],
+    keep_unlisted: bool,
+    dry_run: bool,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<BookmarkApplyReport, ApiError> {
+    let response = bookmark_list(client, channel).await?;
+    let existing = response
+        .data
+        .get("bookmarks")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+    let mut changes = diff_bookmarks(&existing, desired, keep_unlisted);
+
+    let pending = changes
+        .iter()
+        .filter(|c| c.action != BookmarkAction::Unchanged)
+        .count();
+    if dry_run || pending == 0 {
+        return Ok(BookmarkApplyReport::new(channel, dry_run, changes));
+    }
+
+    check_write_allowed()?;
+    let hint = format!(
+        "Preview with: slack-rs bookmark apply {} --spec=<file> --dry-run",
+        channel
+    );
+    confirm_destructive_with_hint(
+        yes,
+        &format!("apply {} bookmark change(s)", pending),
+        non_interactive,
+        Some(&hint),
+    )?;
+
+    for change in &mut changes {
+        let id = change.bookmark_id.as_deref().unwrap_or_default();
+        let emoji = change.emoji.as_deref();
+        let plan = match change.action {
+            BookmarkAction::Create => {
+                plan_bookmarks_add(channel, &change.title, &change.link, emoji)
+            }
+            BookmarkAction::Update => {
+                plan_bookmarks_edit(channel, id, &change.title, &change.link, emoji)
+            }
+            BookmarkAction::Remove => plan_bookmarks_remove(channel, id),
+            BookmarkAction::Unchanged => continue,
+        };
+        match client.execute_planned(plan).await {
+            Ok(response) if !response.ok => {
+                change.error = Some(
+                    response
+                        .error
+                        .unwrap_or_else(|| "unknown_error".to_string()),
+                )
+            }
+            Ok(response) => {
+                if let Some(id) = response
+                    .data
+                    .get("bookmark")
+                    .and_then(|b| b.get("id"))
+                    .and_then(|id| id.as_str())
+                {
+                    change.bookmark_id = Some(id.to_string());
+                }
+            }
+            Err(e) => change.error = Some(e.to_string()),
+        }
+    }
+    Ok(BookmarkApplyReport::new(channel, false, changes))
+}
+
+/// Human-readable `bookmark apply` report
+pub fn format_bookmark_apply_text(report: &BookmarkApplyReport) -> String {
+    let mut out = if report.dry_run {
+        format!(
+            "Bookmarks of {} (dry run, nothing was changed)\n\n",
+            report.channel
+        )
+    } else {
+        format!("Bookmarks of {}\n\n", report.channel)
+    };
+    for change in &report.changes {
+        let (sign, verb) = match change.action {
+            BookmarkAction::Create => ('+', "create"),
+            BookmarkAction::Update => ('~', "update"),
+            BookmarkAction::Remove => ('-', "remove"),
+            BookmarkAction::Unchanged => (' ', "keep"),
+        };
+        out.push_str(&format!(
+            "{} {:<6} {}{} <{}>",
+            sign,
+            verb,
+            change
+                .emoji
+                .as_deref()
+                .map(|e| format!("{} ", e))
+                .unwrap_or_default(),
+            change.title,
+            change.link
+        ));
+        if let Some(ref previous) = change.previous {
+            out.push_str(&format!(" (was {} <{}>)", previous.title, previous.link));
+        }
+        if let Some(ref error) = change.error {
+            out.push_str(&format!("  FAILED: {}", error));
+        }
+        out.push('\n');
+    }
+    let verb = if report.dry_run { "would be " } else { "" };
+    out.push_str(&format!(
+        "\n{} {}created, {} {}updated, {} {}removed, {} unchanged",
+        report.created, verb, report.updated, verb, report.removed, verb, report.unchanged
+    ));
+    if report.failed > 0 {
+        out.push_str(&format!(", {} failed", report.failed));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_plan_bookmarks() {
+        let add = plan_bookmarks_add("C1", "Runbook", "https://example.com/runbook", None);
+        assert_eq!(add.method, ApiMethod::BookmarksAdd);
+        assert_eq!(add.params["channel_id"], "C1");
+        assert_eq!(add.params["type"], "link");
+        assert!(!add.params.contains_key("emoji"));
+        assert_eq!(
+            plan_bookmarks_add("C1", "CI", "https://ci.example", Some(":rocket:")).params["emoji"],
+            ":rocket:"
+        );
+        assert_eq!(
+            plan_bookmarks_remove("C1", "Bk1").params["bookmark_id"],
+            "Bk1"
+        );
+        assert_eq!(plan_bookmarks_list("C1").method, ApiMethod::BookmarksList);
+    }
+
+    fn spec(title: &str, link: &str, emoji: Option<&str>) -> BookmarkSpec {
+        BookmarkSpec {
+            title: title.to_string(),
+            link: link.to_string(),
+            emoji: emoji.map(|e| e.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_bookmark_spec() {
+        let yaml = "- title: Runbook\n  link: https://example.com/runbook\n  emoji: book\n- title: Grafana\n  link: https://grafana.example\n";
+        let specs = parse_bookmark_spec(yaml).unwrap();
+        assert_eq!(
+            specs,
+            vec![
+                spec("Runbook", "https://example.com/runbook", Some(":book:")),
+                spec("Grafana", "https://grafana.example", None),
+            ]
+        );
+
+        let json = r#"{"bookmarks": [{"title": "CI", "link": "https://ci.example", "emoji": ":rocket:"}]}"#;
+        assert_eq!(
+            parse_bookmark_spec(json).unwrap(),
+            vec![spec("CI", "https://ci.example", Some(":rocket:"))]
+        );
+
+        assert!(parse_bookmark_spec("- title: CI\n")
+            .unwrap_err()
+            .starts_with("Invalid bookmark spec"));
+        assert!(parse_bookmark_spec("- title: CI\n  link: a\n  url: b\n").is_err());
+        assert!(
+            parse_bookmark_spec("- title: CI\n  link: a\n- title: CI\n  link: b\n")
+                .unwrap_err()
+                .contains("duplicate title 'CI'")
+        );
+        assert!(
+            parse_bookmark_spec("- title: A\n  link: x\n- title: B\n  link: x\n")
+                .unwrap_err()
+                .contains("duplicate link x")
+        );
+    }
+
+    #[test]
+    fn test_diff_bookmarks() {
+        let existing = vec![
+            serde_json::json!({"id": "Bk1", "type": "link", "title": "Runbook", "link": "https://old.example/runbook", "emoji": ""}),
+            serde_json::json!({"id": "Bk2", "type": "link", "title": "Dashboard", "link": "https://grafana.example", "emoji": ":chart:"}),
+            serde_json::json!({"id": "Bk3", "type": "link", "title": "Stale", "link": "https://stale.example"}),
+            serde_json::json!({"id": "Bk4", "type": "folder", "title": "Docs"}),
+        ];
+        let desired = vec![
+            // Same title, new link: edited in place
+            spec("Runbook", "https://example.com/runbook", None),
+            // Same link and title, emoji left alone
+            spec("Dashboard", "https://grafana.example", None),
+            spec("CI", "https://ci.example", Some(":rocket:")),
+        ];
+
+        let changes = diff_bookmarks(&existing, &desired, false);
+        let actions: Vec<_> = changes
+            .iter()
+            .map(|c| (c.action, c.bookmark_id.as_deref()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (BookmarkAction::Update, Some("Bk1")),
+                (BookmarkAction::Unchanged, Some("Bk2")),
+                (BookmarkAction::Create, None),
+                (BookmarkAction::Remove, Some("Bk3")),
+            ]
+        );
+        assert_eq!(
+            changes[0].previous.as_ref().unwrap().link,
+            "https://old.example/runbook"
+        );
+
+        let changes = diff_bookmarks(&existing, &desired, true);
+        assert!(changes.iter().all(|c| c.action != BookmarkAction::Remove));
+
+        // A different emoji is an edit
+        let desired = vec![spec("Dashboard", "https://grafana.example", Some(":fire:"))];
+        assert_eq!(
+            diff_bookmarks(&existing, &desired, true)[0].action,
+            BookmarkAction::Update
+        );
+    }
+
+    #[test]
+    fn test_format_bookmark_apply_text() {
+        let existing =
+            vec![serde_json::json!({"id": "Bk1", "title": "Old", "link": "https://old.example"})];
+        let desired = vec![spec("CI", "https://ci.example", Some(":rocket:"))];
+        let report =
+            BookmarkApplyReport::new("C1", true, diff_bookmarks(&existing, &desired, false));
+        assert_eq!(report.created, 1);
+        assert_eq!(report.removed, 1);
+        let text = format_bookmark_apply_text(&report);
+        assert!(text.starts_with("Bookmarks of C1 (dry run, nothing was changed)"));
+        assert!(text.contains("+ create :rocket: CI <https://ci.example>"));
+        assert!(text.contains("- remove Old <https://old.example>"));
+        assert!(text
+            .ends_with("1 would be created, 0 would be updated, 1 would be removed, 0 unchanged"));
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_bookmark_remove_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result =
+            bookmark_remove(&client, "C1".to_string(), "Bk1".to_string(), true, false).await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+}
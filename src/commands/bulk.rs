@@ -0,0 +1,522 @@
+//! Bulk message sender (`msg bulk`)
+//!
+//! Reads one message per row from JSONL or CSV input:
+//!
+//! ```text
+//! {"channel":"C123","text":"Deploy done","thread_ts":"1700000000.000100"}
+//! ```
+//!
+//! ```text
+//! channel,text,thread_ts
+//! C123,"Deploy done, all green",
+//! ```
+//!
+//! Rows are posted in order at a fixed rate. Each row carries an
+//! idempotency key (the `idempotency_key` column, or one derived from
+//! channel, thread and text), so re-running the same input replays rows
+//! that were already posted instead of posting them twice. Every row
+//! produces one JSONL result line; a previous report passed as `--resume`
+//! skips the rows it recorded as done.
+
+use crate::api::{ApiClient, ApiError, ApiMethod};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+use crate::commands::msg::{msg_post, plan_msg_post};
+use crate::idempotency::{IdempotencyCheckResult, IdempotencyHandler};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Input file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkFormat {
+    Jsonl,
+    Csv,
+}
+
+impl BulkFormat {
+    /// Parse an `--input-format` value
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "jsonl" | "ndjson" => Ok(BulkFormat::Jsonl),
+            "csv" => Ok(BulkFormat::Csv),
+            _ => Err(format!(
+                "Invalid input format '{}'. Valid values: jsonl, csv",
+                s
+            )),
+        }
+    }
+
+    /// Guess the format from a file name (`.csv` is CSV, anything else JSONL)
+    pub fn from_path(path: &str) -> Self {
+        if path.to_lowercase().ends_with(".csv") {
+            BulkFormat::Csv
+        } else {
+            BulkFormat::Jsonl
+        }
+    }
+}
+
+/// One message to post
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkRow {
+    /// 1-based line (JSONL) or record (CSV, excluding the header) number
+    pub line: usize,
+    pub channel: String,
+    pub text: String,
+    pub thread_ts: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+impl BulkRow {
+    /// Idempotency key: the explicit one, or `bulk:` + a hash of the content
+    pub fn key(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        if let Some(key) = &self.idempotency_key {
+            return key.clone();
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(self.channel.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.thread_ts.as_deref().unwrap_or_default().as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.text.as_bytes());
+        let digest = format!("{:x}", hasher.finalize());
+        format!("bulk:{}", &digest[..16])
+    }
+
+    fn from_fields(line: usize, get: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let required = |name: &str| {
+            get(name)
+                .filter(|v| !v.is_empty())
+                .ok_or_else(|| format!("row {}: missing '{}'", line, name))
+        };
+        let optional = |name: &str| get(name).filter(|v| !v.is_empty());
+        Ok(Self {
+            line,
+            channel: required("channel")?,
+            text: required("text")?,
+            thread_ts: optional("thread_ts").or_else(|| optional("thread")),
+            idempotency_key: optional("idempotency_key"),
+        })
+    }
+}
+
+/// Parse bulk input into rows
+pub fn parse_bulk_input(content: &str, format: BulkFormat) -> Result<Vec<BulkRow>, String> {
+    match format {
+        BulkFormat::Jsonl => content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                let value: Value = serde_json::from_str(line)
+                    .map_err(|e| format!("row {}: invalid JSON: {}", index + 1, e))?;
+                BulkRow::from_fields(index + 1, |name| match value.get(name) {
+                    Some(Value::String(s)) => Some(s.clone()),
+                    Some(Value::Null) | None => None,
+                    Some(other) => Some(other.to_string()),
+                })
+            })
+            .collect(),
+        BulkFormat::Csv => {
+            let mut records = parse_csv(content)?.into_iter();
+            let header = records
+                .next()
+                .ok_or_else(|| "CSV input is empty".to_string())?;
+            if !header.iter().any(|h| h == "channel") || !header.iter().any(|h| h == "text") {
+                return Err("CSV header must contain 'channel' and 'text' columns".to_string());
+            }
+            records
+                .enumerate()
+                .filter(|(_, record)| record.iter().any(|field| !field.is_empty()))
+                .map(|(index, record)| {
+                    BulkRow::from_fields(index + 1, |name| {
+                        header
+                            .iter()
+                            .position(|h| h == name)
+                            .and_then(|i| record.get(i).cloned())
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+/// Split CSV content into records (RFC 4180 quoting, `\n` or `\r\n`)
+fn parse_csv(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.is_empty() => in_quotes = true,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("CSV input ends inside a quoted field".to_string());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Posting rate (`--rate`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rate {
+    /// Pause between two posts
+    pub interval: Duration,
+}
+
+impl Default for Rate {
+    /// One message per second, Slack's sustained `chat.postMessage` limit per channel
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Rate {
+    /// Parse `N/s`, `N/m` or `N/h` (e.g. `1/s`, `20/m`)
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid rate '{}': expected N/s, N/m or N/h", s);
+        let (count, unit) = s.split_once('/').ok_or_else(invalid)?;
+        let count: f64 = count
+            .trim()
+            .parse()
+            .ok()
+            .filter(|n: &f64| *n > 0.0 && n.is_finite())
+            .ok_or_else(invalid)?;
+        let unit_secs = match unit.trim() {
+            "s" | "sec" => 1.0,
+            "m" | "min" => 60.0,
+            "h" | "hour" => 3600.0,
+            _ => return Err(invalid()),
+        };
+        Ok(Self {
+            interval: Duration::from_secs_f64(unit_secs / count),
+        })
+    }
+}
+
+/// Outcome of one row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkStatus {
+    /// Posted by this run
+    Posted,
+    /// Posted by an earlier run (idempotency store)
+    Replayed,
+    /// Recorded as done in the `--resume` report
+    Skipped,
+    /// Would be posted (`--dry-run`)
+    DryRun,
+    Failed,
+}
+
+impl BulkStatus {
+    /// Whether the row needs no further attempt
+    pub fn is_done(self) -> bool {
+        matches!(
+            self,
+            BulkStatus::Posted | BulkStatus::Replayed | BulkStatus::Skipped
+        )
+    }
+}
+
+/// One line of the result report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkResult {
+    pub line: usize,
+    pub channel: String,
+    pub key: String,
+    pub status: BulkStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Keys recorded as done in a previous report
+pub fn completed_keys(report: &str) -> HashSet<String> {
+    report
+        .lines()
+        .filter_map(|line| serde_json::from_str::<BulkResult>(line).ok())
+        .filter(|result| result.status.is_done())
+        .map(|result| result.key)
+        .collect()
+}
+
+/// Settings for [`msg_bulk`]
+#[derive(Debug, Clone, Default)]
+pub struct BulkOptions {
+    pub rate: Rate,
+    pub dry_run: bool,
+    /// Keys to skip (see [`completed_keys`])
+    pub completed: HashSet<String>,
+}
+
+/// Idempotency store and the identity its keys are scoped to
+pub struct BulkIdempotency<'a> {
+    pub handler: &'a mut IdempotencyHandler,
+    pub team_id: String,
+    pub user_id: String,
+}
+
+/// Post every row, reporting each result through `on_result`
+///
+/// A failed row does not stop the run; it is reported with `failed` and can
+/// be retried by running the same input again.
+///
+/// # Arguments
+/// * `client` - API client
+/// * `rows` - Messages to post
+/// * `options` - Rate, dry run and rows to skip
+/// * `idempotency` - Store used to replay rows posted by earlier runs
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+/// * `on_result` - Called once per row, in order
+///
+/// # Returns
+/// * `Ok(Vec<BulkResult>)` with one result per row
+/// * `Err(ApiError)` if writes are not allowed or the run was not confirmed
+pub async fn msg_bulk(
+    client: &ApiClient,
+    rows: &[BulkRow],
+    options: &BulkOptions,
+    mut idempotency: Option<BulkIdempotency<'_>>,
+    yes: bool,
+    non_interactive: bool,
+    mut on_result: impl FnMut(&BulkResult),
+) -> Result<Vec<BulkResult>, ApiError> {
+    if !options.dry_run {
+        check_write_allowed()?;
+        let hint = "Example: slack-rs msg bulk --input=messages.jsonl --yes";
+        let action = format!("post {} message(s)", rows.len());
+        confirm_destructive_with_hint(yes, &action, non_interactive, Some(hint))?;
+    }
+
+    let mut results = Vec::with_capacity(rows.len());
+    let mut posted_any = false;
+    for row in rows {
+        let key = row.key();
+        let mut result = BulkResult {
+            line: row.line,
+            channel: row.channel.clone(),
+            key: key.clone(),
+            status: BulkStatus::DryRun,
+            ts: None,
+            error: None,
+        };
+
+        if options.completed.contains(&key) {
+            result.status = BulkStatus::Skipped;
+        } else if !options.dry_run {
+            if posted_any {
+                tokio::time::sleep(options.rate.interval).await;
+            }
+            posted_any = true;
+            match post_row(client, row, &key, idempotency.as_mut()).await {
+                Ok((status, response)) => {
+                    result.status = status;
+                    result.ts = response
+                        .get("ts")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string());
+                }
+                Err(e) => {
+                    result.status = BulkStatus::Failed;
+                    result.error = Some(e);
+                }
+            }
+        }
+
+        on_result(&result);
+        results.push(result);
+    }
+    Ok(results)
+}
+
+/// Post one row through the idempotency store
+async fn post_row(
+    client: &ApiClient,
+    row: &BulkRow,
+    key: &str,
+    idempotency: Option<&mut BulkIdempotency<'_>>,
+) -> Result<(BulkStatus, Value), String> {
+    let post = async || {
+        let response = msg_post(
+            client,
+            row.channel.clone(),
+            row.text.clone(),
+            row.thread_ts.clone(),
+            false,
+            true,
+            true,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        serde_json::to_value(&response).map_err(|e| e.to_string())
+    };
+
+    let Some(store) = idempotency else {
+        return Ok((BulkStatus::Posted, post().await?));
+    };
+    let params: serde_json::Map<String, Value> = plan_msg_post(
+        &row.channel,
+        &row.text,
+        None,
+        row.thread_ts.as_deref(),
+        false,
+    )
+    .params
+    .into_iter()
+    .collect();
+    match store
+        .handler
+        .check(
+            Some(key.to_string()),
+            store.team_id.clone(),
+            store.user_id.clone(),
+            ApiMethod::ChatPostMessage.as_str().to_string(),
+            &params,
+        )
+        .map_err(|e| e.to_string())?
+    {
+        IdempotencyCheckResult::Replay { response, .. } => Ok((BulkStatus::Replayed, response)),
+        IdempotencyCheckResult::Execute {
+            key: scoped_key,
+            fingerprint,
+        } => {
+            let response = post().await?;
+            store
+                .handler
+                .store(scoped_key, fingerprint, response.clone())
+                .map_err(|e| e.to_string())?;
+            Ok((BulkStatus::Posted, response))
+        }
+        IdempotencyCheckResult::NoKey => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use serial_test::serial;
+
+    #[test]
+    fn test_parse_bulk_input() {
+        let jsonl = "{\"channel\":\"C1\",\"text\":\"hello\"}\n\n{\"channel\":\"C2\",\"text\":\"reply\",\"thread\":\"1.2\",\"idempotency_key\":\"k2\"}\n";
+        let rows = parse_bulk_input(jsonl, BulkFormat::Jsonl).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].line, 3);
+        assert_eq!(rows[1].thread_ts.as_deref(), Some("1.2"));
+        assert_eq!(rows[1].key(), "k2");
+        assert!(rows[0].key().starts_with("bulk:"));
+        assert!(parse_bulk_input("{\"channel\":\"C1\"}", BulkFormat::Jsonl).is_err());
+
+        let csv = "channel,text,thread_ts\r\nC1,\"Deploy done, \"\"all\"\" green\",\nC2,\"two\nlines\",1.2\n";
+        let rows = parse_bulk_input(csv, BulkFormat::Csv).unwrap();
+        assert_eq!(rows[0].text, "Deploy done, \"all\" green");
+        assert_eq!(rows[0].thread_ts, None);
+        assert_eq!(rows[1].text, "two\nlines");
+        assert_eq!(rows[1].thread_ts.as_deref(), Some("1.2"));
+        assert!(parse_bulk_input("name,text\nC1,hi\n", BulkFormat::Csv).is_err());
+
+        assert_eq!(BulkFormat::from_path("rows.CSV"), BulkFormat::Csv);
+        assert_eq!(
+            Rate::parse("2/s").unwrap().interval,
+            Duration::from_millis(500)
+        );
+        assert_eq!(
+            Rate::parse("30/m").unwrap().interval,
+            Duration::from_secs(2)
+        );
+        assert!(Rate::parse("0/s").is_err());
+        assert!(Rate::parse("5").is_err());
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_msg_bulk_reports_each_row() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .and(body_string_contains("C_GONE"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "channel_not_found"})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"ok": true, "ts": "1.000100"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("xoxb-test".to_string(), mock_server.uri());
+        let rows = parse_bulk_input(
+            "{\"channel\":\"C1\",\"text\":\"a\"}\n{\"channel\":\"C_GONE\",\"text\":\"b\"}\n{\"channel\":\"C1\",\"text\":\"c\"}\n",
+            BulkFormat::Jsonl,
+        )
+        .unwrap();
+        let options = BulkOptions {
+            rate: Rate::parse("1000/s").unwrap(),
+            completed: HashSet::from([rows[2].key()]),
+            ..BulkOptions::default()
+        };
+
+        let mut report = String::new();
+        let results = msg_bulk(&client, &rows, &options, None, true, true, |result| {
+            report.push_str(&serde_json::to_string(result).unwrap());
+            report.push('\n');
+        })
+        .await
+        .unwrap();
+
+        let statuses: Vec<BulkStatus> = results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![BulkStatus::Posted, BulkStatus::Failed, BulkStatus::Skipped]
+        );
+        assert_eq!(results[0].ts.as_deref(), Some("1.000100"));
+        assert!(results[1]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("channel_not_found"));
+
+        // The report resumes: done rows are skipped, the failed one is retried
+        let completed = completed_keys(&report);
+        assert!(completed.contains(&rows[0].key()));
+        assert!(!completed.contains(&rows[1].key()));
+        assert!(completed.contains(&rows[2].key()));
+    }
+}
@@ -0,0 +1,422 @@
+//! Slack Connect (external shared channel) command implementations
+//!
+//! Covers the invitation lifecycle for partner channels:
+//! - invite: send an external invitation (`conversations.inviteShared`)
+//! - invites / accept / approve / decline: handle incoming invitations
+//! - requests list / approve / deny: admin approval of invitation requests
+//!   (`conversations.requestSharedInvite.*`)
+//! - permissions: upgrade/downgrade a connection
+//!   (`conversations.externalInvitePermissions.set`)
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Permission change for an external connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalPermissionAction {
+    /// Grant full channel access to the external organization
+    Upgrade,
+    /// Restrict the external organization to limited access
+    Downgrade,
+}
+
+impl ExternalPermissionAction {
+    /// Parse "upgrade" or "downgrade"
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "upgrade" => Ok(ExternalPermissionAction::Upgrade),
+            "downgrade" => Ok(ExternalPermissionAction::Downgrade),
+            _ => Err(format!(
+                "Invalid action '{}'. Valid values: upgrade, downgrade",
+                s
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExternalPermissionAction::Upgrade => "upgrade",
+            ExternalPermissionAction::Downgrade => "downgrade",
+        }
+    }
+}
+
+/// Filters for listing invitation requests
+#[derive(Debug, Clone, Default)]
+pub struct InviteRequestFilter {
+    pub include_approved: bool,
+    pub include_denied: bool,
+    pub include_expired: bool,
+    /// Only requests made by this user
+    pub user_id: Option<String>,
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+}
+
+/// Invite external users to a channel by email or user ID
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID to share
+/// * `emails` - Email addresses to invite
+/// * `user_ids` - User IDs to invite (users already known to Slack)
+/// * `external_limited` - Give the external organization limited access
+pub async fn connect_invite(
+    client: &ApiClient,
+    channel: String,
+    emails: Vec<String>,
+    user_ids: Vec<String>,
+    external_limited: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    if emails.is_empty() && user_ids.is_empty() {
+        return Err(ApiError::MissingParameter(
+            "--emails or --user-ids".to_string(),
+        ));
+    }
+
+    let mut params = HashMap::new();
+    params.insert("channel".to_string(), json!(channel));
+    if !emails.is_empty() {
+        params.insert("emails".to_string(), json!(emails.join(",")));
+    }
+    if !user_ids.is_empty() {
+        params.insert("user_ids".to_string(), json!(user_ids.join(",")));
+    }
+    if external_limited {
+        params.insert("external_limited".to_string(), json!(true));
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsInviteShared, params)
+        .await
+}
+
+/// List pending Slack Connect invitations for the workspace
+pub async fn connect_list_invites(
+    client: &ApiClient,
+    team_id: Option<String>,
+    cursor: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    let mut params = HashMap::new();
+    if let Some(team_id) = team_id {
+        params.insert("team_id".to_string(), json!(team_id));
+    }
+    if let Some(cursor) = cursor {
+        params.insert("cursor".to_string(), json!(cursor));
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsListConnectInvites, params)
+        .await
+}
+
+/// Accept an incoming invitation, creating the local side of the channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `invite_id` - Invitation ID
+/// * `channel_name` - Name for the channel in this workspace
+/// * `is_private` - Create the channel as private
+pub async fn connect_accept(
+    client: &ApiClient,
+    invite_id: String,
+    channel_name: String,
+    is_private: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let mut params = HashMap::new();
+    params.insert("invite_id".to_string(), json!(invite_id));
+    params.insert("channel_name".to_string(), json!(channel_name));
+    if is_private {
+        params.insert("is_private".to_string(), json!(true));
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsAcceptSharedInvite, params)
+        .await
+}
+
+/// Approve an accepted invitation on behalf of the organization (admin)
+pub async fn connect_approve(
+    client: &ApiClient,
+    invite_id: String,
+    target_team: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let mut params = HashMap::new();
+    params.insert("invite_id".to_string(), json!(invite_id));
+    if let Some(team) = target_team {
+        params.insert("target_team".to_string(), json!(team));
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsApproveSharedInvite, params)
+        .await
+}
+
+/// Decline an invitation
+pub async fn connect_decline(
+    client: &ApiClient,
+    invite_id: String,
+    target_team: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs connect decline {} --yes", invite_id);
+    confirm_destructive_with_hint(yes, "decline this invitation", non_interactive, Some(&hint))?;
+
+    let mut params = HashMap::new();
+    params.insert("invite_id".to_string(), json!(invite_id));
+    if let Some(team) = target_team {
+        params.insert("target_team".to_string(), json!(team));
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsDeclineSharedInvite, params)
+        .await
+}
+
+/// List requests from members to send external invitations
+pub async fn connect_requests_list(
+    client: &ApiClient,
+    filter: InviteRequestFilter,
+) -> Result<ApiResponse, ApiError> {
+    let mut params = HashMap::new();
+    if filter.include_approved {
+        params.insert("include_approved".to_string(), json!(true));
+    }
+    if filter.include_denied {
+        params.insert("include_denied".to_string(), json!(true));
+    }
+    if filter.include_expired {
+        params.insert("include_expired".to_string(), json!(true));
+    }
+    if let Some(user_id) = filter.user_id {
+        params.insert("user_id".to_string(), json!(user_id));
+    }
+    if let Some(limit) = filter.limit {
+        params.insert("limit".to_string(), json!(limit));
+    }
+    if let Some(cursor) = filter.cursor {
+        params.insert("cursor".to_string(), json!(cursor));
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsRequestSharedInviteList, params)
+        .await
+}
+
+/// Approve a member's request to send an external invitation
+///
+/// # Arguments
+/// * `client` - API client
+/// * `invite_id` - Request ID
+/// * `channel_id` - Channel to share (defaults to the one in the request)
+/// * `external_limited` - Give the external organization limited access
+/// * `message` - Optional note sent to the requester
+pub async fn connect_requests_approve(
+    client: &ApiClient,
+    invite_id: String,
+    channel_id: Option<String>,
+    external_limited: bool,
+    message: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let mut params = HashMap::new();
+    params.insert("invite_id".to_string(), json!(invite_id));
+    if let Some(channel) = channel_id {
+        params.insert("channel_id".to_string(), json!(channel));
+    }
+    if external_limited {
+        params.insert("is_external_limited".to_string(), json!(true));
+    }
+    if let Some(text) = message {
+        params.insert(
+            "message".to_string(),
+            json!({"text": text, "is_override": false}),
+        );
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsRequestSharedInviteApprove, params)
+        .await
+}
+
+/// Deny a member's request to send an external invitation
+pub async fn connect_requests_deny(
+    client: &ApiClient,
+    invite_id: String,
+    message: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs connect requests deny {} --yes",
+        invite_id
+    );
+    confirm_destructive_with_hint(
+        yes,
+        "deny this invitation request",
+        non_interactive,
+        Some(&hint),
+    )?;
+
+    let mut params = HashMap::new();
+    params.insert("invite_id".to_string(), json!(invite_id));
+    if let Some(text) = message {
+        params.insert("message".to_string(), json!(text));
+    }
+
+    client
+        .call_method(ApiMethod::ConversationsRequestSharedInviteDeny, params)
+        .await
+}
+
+/// Upgrade or downgrade an external organization's access to a channel
+pub async fn connect_set_permissions(
+    client: &ApiClient,
+    channel: String,
+    target_team: String,
+    action: ExternalPermissionAction,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    if action == ExternalPermissionAction::Downgrade {
+        let hint = format!(
+            "Example: slack-rs connect permissions {} {} downgrade --yes",
+            channel, target_team
+        );
+        confirm_destructive_with_hint(
+            yes,
+            "downgrade this external connection",
+            non_interactive,
+            Some(&hint),
+        )?;
+    }
+
+    let mut params = HashMap::new();
+    params.insert("channel".to_string(), json!(channel));
+    params.insert("target_team".to_string(), json!(target_team));
+    params.insert("action".to_string(), json!(action.as_str()));
+
+    client
+        .call_method(ApiMethod::ConversationsExternalInvitePermissionsSet, params)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_permission_action_parse() {
+        assert_eq!(
+            ExternalPermissionAction::parse("Upgrade").unwrap(),
+            ExternalPermissionAction::Upgrade
+        );
+        assert_eq!(
+            ExternalPermissionAction::parse("downgrade").unwrap(),
+            ExternalPermissionAction::Downgrade
+        );
+        assert!(ExternalPermissionAction::parse("remove").is_err());
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_connect_invite_requires_target() {
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = connect_invite(&client, "C123".to_string(), vec![], vec![], false).await;
+        assert!(matches!(result, Err(ApiError::MissingParameter(_))));
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_connect_invite_sends_joined_emails() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/conversations.inviteShared"))
+            .and(body_json(json!({
+                "channel": "C123",
+                "emails": "a@partner.example,b@partner.example",
+                "external_limited": true
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"ok": true, "invite_id": "I123"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let response = connect_invite(
+            &client,
+            "C123".to_string(),
+            vec![
+                "a@partner.example".to_string(),
+                "b@partner.example".to_string(),
+            ],
+            vec![],
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.data["invite_id"], "I123");
+    }
+
+    #[tokio::test]
+    async fn test_connect_requests_list_uses_get() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.requestSharedInvite.list"))
+            .and(query_param("include_denied", "true"))
+            .and(query_param("user_id", "U1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": true, "invite_requests": []})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let filter = InviteRequestFilter {
+            include_denied: true,
+            user_id: Some("U1".to_string()),
+            ..Default::default()
+        };
+        let response = connect_requests_list(&client, filter).await.unwrap();
+        assert!(response.ok);
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_connect_downgrade_requires_confirmation_non_interactive() {
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = connect_set_permissions(
+            &client,
+            "C123".to_string(),
+            "T999".to_string(),
+            ExternalPermissionAction::Downgrade,
+            false,
+            true,
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::NonInteractiveError(_))));
+    }
+}
@@ -0,0 +1,300 @@
+//! Last-activity enrichment for `conv list --with-last-activity`
+//!
+//! `conversations.list` does not say when a channel was last used, so each
+//! listed channel gets a `conversations.history limit=1` call and its newest
+//! message ts is written to `last_activity_ts`. Calls run concurrently and
+//! results are cached on disk for [`ACTIVITY_CACHE_TTL_SECS`], so sorting by
+//! activity or spotting inactive channels stays cheap on repeated runs.
+//! `--max-enrich` caps the number of history calls for large workspaces.
+
+use super::api::plan_conv_history;
+use crate::api::{ApiClient, ApiError, ApiResponse};
+use futures_util::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Channels enriched when `--max-enrich` is not given
+pub const DEFAULT_MAX_ENRICH: usize = 200;
+/// Concurrent history calls
+pub const ENRICH_CONCURRENCY: usize = 8;
+/// How long a cached last-activity value is reused (1 hour)
+pub const ACTIVITY_CACHE_TTL_SECS: u64 = 3600;
+
+/// Cached newest-message timestamp for one channel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    /// Newest message ts, `None` for an empty channel
+    pub latest_ts: Option<String>,
+    /// UNIX time the value was fetched
+    pub fetched_at: u64,
+}
+
+/// On-disk last-activity cache keyed by `team_id:channel_id`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActivityCache {
+    #[serde(default)]
+    pub entries: HashMap<String, ActivityEntry>,
+}
+
+impl ActivityCache {
+    /// Default cache location next to the users cache
+    pub fn default_path() -> Result<PathBuf, String> {
+        crate::profile::config_dir()
+            .map(|dir| dir.join("activity_cache.json"))
+            .ok_or_else(|| "Could not determine config directory".to_string())
+    }
+
+    /// Load the cache; a missing or unreadable file yields an empty cache
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the cache, creating the parent directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize activity cache: {}", e))?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write activity cache: {}", e))
+    }
+
+    /// Fresh cached entry for a channel
+    pub fn get_fresh(&self, team_id: &str, channel: &str, now: u64) -> Option<&ActivityEntry> {
+        self.entries
+            .get(&format!("{}:{}", team_id, channel))
+            .filter(|e| now.saturating_sub(e.fetched_at) < ACTIVITY_CACHE_TTL_SECS)
+    }
+
+    /// Store a freshly fetched entry
+    pub fn insert(&mut self, team_id: &str, channel: &str, entry: ActivityEntry) {
+        self.entries
+            .insert(format!("{}:{}", team_id, channel), entry);
+    }
+}
+
+/// Outcome of an enrichment pass
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnrichReport {
+    /// Channels fetched from the API
+    pub fetched: usize,
+    /// Channels served from the cache
+    pub cached: usize,
+    /// Channels left out because of `--max-enrich`
+    pub skipped: usize,
+    /// Channels whose history could not be read (e.g. `not_in_channel`)
+    pub failed: usize,
+}
+
+/// Newest message ts of a channel
+///
+/// # Returns
+/// * `Ok(Some(ts))` for the newest message, `Ok(None)` for an empty channel
+/// * `Err(ApiError)` if history cannot be read
+pub async fn fetch_last_activity(
+    client: &ApiClient,
+    channel: &str,
+) -> Result<Option<String>, ApiError> {
+    let response = client
+        .execute_planned(plan_conv_history(channel, Some(1), None, None, None))
+        .await?;
+    if !response.ok {
+        return Err(ApiError::SlackError(
+            response
+                .error
+                .unwrap_or_else(|| "unknown_error".to_string()),
+        ));
+    }
+    Ok(response
+        .data
+        .get("messages")
+        .and_then(|m| m.get(0))
+        .and_then(|m| m.get("ts"))
+        .and_then(|ts| ts.as_str())
+        .map(|ts| ts.to_string()))
+}
+
+/// Add `last_activity_ts` to the first `max_enrich` listed channels
+///
+/// Channels that could not be read or were skipped keep `last_activity_ts`
+/// unset, so they sort as the least active.
+pub async fn enrich_last_activity(
+    client: &ApiClient,
+    response: &mut ApiResponse,
+    cache: &mut ActivityCache,
+    team_id: &str,
+    max_enrich: usize,
+    now: u64,
+) -> EnrichReport {
+    let Some(channels) = response
+        .data
+        .get_mut("channels")
+        .and_then(|v| v.as_array_mut())
+    else {
+        return EnrichReport::default();
+    };
+
+    let mut report = EnrichReport {
+        skipped: channels.len().saturating_sub(max_enrich),
+        ..Default::default()
+    };
+    let ids: Vec<String> = channels
+        .iter()
+        .take(max_enrich)
+        .filter_map(|c| c.get("id").and_then(|v| v.as_str()).map(String::from))
+        .collect();
+
+    let mut latest: HashMap<String, Option<String>> = HashMap::new();
+    let mut to_fetch = Vec::new();
+    for id in ids {
+        match cache.get_fresh(team_id, &id, now) {
+            Some(entry) => {
+                report.cached += 1;
+                latest.insert(id, entry.latest_ts.clone());
+            }
+            None => to_fetch.push(id),
+        }
+    }
+
+    let results: Vec<(String, Result<Option<String>, ApiError>)> = stream::iter(to_fetch)
+        .map(|id| async move {
+            let result = fetch_last_activity(client, &id).await;
+            (id, result)
+        })
+        .buffer_unordered(ENRICH_CONCURRENCY)
+        .collect()
+        .await;
+
+    for (id, result) in results {
+        match result {
+            Ok(latest_ts) => {
+                report.fetched += 1;
+                cache.insert(
+                    team_id,
+                    &id,
+                    ActivityEntry {
+                        latest_ts: latest_ts.clone(),
+                        fetched_at: now,
+                    },
+                );
+                latest.insert(id, latest_ts);
+            }
+            Err(_) => report.failed += 1,
+        }
+    }
+
+    for channel in channels.iter_mut() {
+        let id = channel.get("id").and_then(|v| v.as_str()).unwrap_or("");
+        if let Some(ts) = latest.get(id) {
+            let value = ts.clone().map(Value::String).unwrap_or(Value::Null);
+            if let Some(obj) = channel.as_object_mut() {
+                obj.insert("last_activity_ts".to_string(), value);
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn channels(ids: &[&str]) -> ApiResponse {
+        ApiResponse {
+            ok: true,
+            data: HashMap::from([(
+                "channels".to_string(),
+                Value::Array(ids.iter().map(|id| json!({"id": id})).collect()),
+            )]),
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enrich_last_activity_uses_cache_and_limit() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("channel", "C2"))
+            .and(query_param("limit", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "1700000000.000200"}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("channel", "C3"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "not_in_channel"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let mut cache = ActivityCache::default();
+        cache.insert(
+            "T1",
+            "C1",
+            ActivityEntry {
+                latest_ts: Some("1600000000.000100".to_string()),
+                fetched_at: 1000,
+            },
+        );
+
+        let mut response = channels(&["C1", "C2", "C3", "C4"]);
+        let report =
+            enrich_last_activity(&client, &mut response, &mut cache, "T1", 3, 1000 + 60).await;
+
+        assert_eq!(
+            report,
+            EnrichReport {
+                fetched: 1,
+                cached: 1,
+                skipped: 1,
+                failed: 1,
+            }
+        );
+        let listed = response.data["channels"].as_array().unwrap();
+        assert_eq!(listed[0]["last_activity_ts"], "1600000000.000100");
+        assert_eq!(listed[1]["last_activity_ts"], "1700000000.000200");
+        assert!(listed[2].get("last_activity_ts").is_none());
+        assert!(listed[3].get("last_activity_ts").is_none());
+        assert!(cache.get_fresh("T1", "C2", 1060).is_some());
+    }
+
+    #[test]
+    fn test_activity_cache_expiry_and_roundtrip() {
+        let mut cache = ActivityCache::default();
+        cache.insert(
+            "T1",
+            "C1",
+            ActivityEntry {
+                latest_ts: None,
+                fetched_at: 100,
+            },
+        );
+        assert!(cache.get_fresh("T1", "C1", 100 + 10).is_some());
+        assert!(cache
+            .get_fresh("T1", "C1", 100 + ACTIVITY_CACHE_TTL_SECS)
+            .is_none());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/activity_cache.json");
+        cache.save(&path).unwrap();
+        assert_eq!(ActivityCache::load(&path).entries, cache.entries);
+    }
+}
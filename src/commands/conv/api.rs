@@ -1,9 +1,55 @@
 //! API call functionality for conversations
 
-use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse};
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
 use serde_json::json;
 use std::collections::HashMap;
 
+/// Build one `conversations.list` page request (`limit` defaults to 1000)
+pub fn plan_conv_list(
+    types: Option<&str>,
+    limit: Option<u32>,
+    cursor: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsList)
+        .param_opt("types", types)
+        .param("limit", limit.unwrap_or(1000))
+        .param_opt("cursor", cursor)
+}
+
+/// Build a `conversations.history` request
+pub fn plan_conv_history(
+    channel: &str,
+    limit: Option<u32>,
+    oldest: Option<&str>,
+    latest: Option<&str>,
+    cursor: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsHistory)
+        .param("channel", channel)
+        .param_opt("limit", limit)
+        .param_opt("oldest", oldest)
+        .param_opt("latest", latest)
+        .param_opt("cursor", cursor)
+}
+
+/// Build a `conversations.replies` request
+pub fn plan_conv_replies(
+    channel: &str,
+    ts: &str,
+    limit: Option<u32>,
+    oldest: Option<&str>,
+    latest: Option<&str>,
+    cursor: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsReplies)
+        .param("channel", channel)
+        .param("ts", ts)
+        .param_opt("limit", limit)
+        .param_opt("oldest", oldest)
+        .param_opt("latest", latest)
+        .param_opt("cursor", cursor)
+}
+
 /// List conversations with automatic pagination
 ///
 /// # Arguments
@@ -29,22 +75,8 @@ pub async fn conv_list(
     let mut error: Option<String> = None;
 
     loop {
-        let mut params = HashMap::new();
-
-        if let Some(ref types) = types {
-            params.insert("types".to_string(), json!(types));
-        }
-
-        // Use provided limit or default to 1000
-        let page_limit = limit.unwrap_or(1000);
-        params.insert("limit".to_string(), json!(page_limit));
-
-        if let Some(ref cursor_val) = cursor {
-            params.insert("cursor".to_string(), json!(cursor_val));
-        }
-
         let response = client
-            .call_method(ApiMethod::ConversationsList, params)
+            .execute_planned(plan_conv_list(types.as_deref(), limit, cursor.as_deref()))
             .await?;
 
         // Capture ok/error status from first response
@@ -90,6 +122,7 @@ pub async fn conv_list(
 /// * `limit` - Optional number of messages to return (default: 100)
 /// * `oldest` - Optional oldest timestamp to include
 /// * `latest` - Optional latest timestamp to include
+/// * `cursor` - Optional cursor to continue from a previous page
 ///
 /// # Returns
 /// * `Ok(ApiResponse)` with conversation history
@@ -100,31 +133,123 @@ pub async fn conv_history(
     limit: Option<u32>,
     oldest: Option<String>,
     latest: Option<String>,
+    cursor: Option<String>,
 ) -> Result<ApiResponse, ApiError> {
-    let mut params = HashMap::new();
-    params.insert("channel".to_string(), json!(channel));
-
-    if let Some(limit) = limit {
-        params.insert("limit".to_string(), json!(limit));
-    }
+    client
+        .execute_planned(plan_conv_history(
+            &channel,
+            limit,
+            oldest.as_deref(),
+            latest.as_deref(),
+            cursor.as_deref(),
+        ))
+        .await
+}
 
-    if let Some(oldest) = oldest {
-        params.insert("oldest".to_string(), json!(oldest));
-    }
+/// Get the full conversation history in the window by following cursors
+///
+/// Pages of `limit` messages (Slack default when unset) are fetched until
+/// `next_cursor` is exhausted and merged into a single response.
+pub async fn conv_history_all(
+    client: &ApiClient,
+    channel: String,
+    limit: Option<u32>,
+    oldest: Option<String>,
+    latest: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    let merged = crate::api::paginate(|cursor| {
+        let (channel, oldest, latest) = (channel.clone(), oldest.clone(), latest.clone());
+        async move {
+            let page = conv_history(client, channel, limit, oldest, latest, cursor).await?;
+            Ok::<_, ApiError>(serde_json::to_value(page)?)
+        }
+    })
+    .await?;
 
-    if let Some(latest) = latest {
-        params.insert("latest".to_string(), json!(latest));
-    }
+    Ok(serde_json::from_value(merged)?)
+}
 
+/// Get replies in a thread (one page)
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID containing the thread
+/// * `ts` - Timestamp of the parent message
+/// * `limit` - Optional number of messages to return
+/// * `oldest` - Optional oldest timestamp to include
+/// * `latest` - Optional latest timestamp to include
+/// * `cursor` - Optional cursor to continue from a previous page
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the parent message followed by its replies
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_replies(
+    client: &ApiClient,
+    channel: String,
+    ts: String,
+    limit: Option<u32>,
+    oldest: Option<String>,
+    latest: Option<String>,
+    cursor: Option<String>,
+) -> Result<ApiResponse, ApiError> {
     client
-        .call_method(ApiMethod::ConversationsHistory, params)
+        .execute_planned(plan_conv_replies(
+            &channel,
+            &ts,
+            limit,
+            oldest.as_deref(),
+            latest.as_deref(),
+            cursor.as_deref(),
+        ))
         .await
 }
 
+/// Get every reply in a thread by following cursors
+pub async fn conv_replies_all(
+    client: &ApiClient,
+    channel: String,
+    ts: String,
+    limit: Option<u32>,
+    oldest: Option<String>,
+    latest: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    let merged = crate::api::paginate(|cursor| {
+        let (channel, ts, oldest, latest) =
+            (channel.clone(), ts.clone(), oldest.clone(), latest.clone());
+        async move {
+            let page = conv_replies(client, channel, ts, limit, oldest, latest, cursor).await?;
+            Ok::<_, ApiError>(serde_json::to_value(page)?)
+        }
+    })
+    .await?;
+
+    Ok(serde_json::from_value(merged)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_plan_conv_requests() {
+        let list = plan_conv_list(Some("im"), None, Some("abc"));
+        assert_eq!(
+            list.to_json()["params"],
+            json!({"types": "im", "limit": 1000, "cursor": "abc"})
+        );
+
+        let history = plan_conv_history("C1", Some(50), None, Some("2.0"), None);
+        assert_eq!(history.http_method(), "GET");
+        assert_eq!(
+            history.to_json()["params"],
+            json!({"channel": "C1", "limit": 50, "latest": "2.0"})
+        );
+
+        let replies = plan_conv_replies("C1", "1.0", None, None, None, None);
+        assert_eq!(replies.method, ApiMethod::ConversationsReplies);
+        assert_eq!(replies.params["ts"], "1.0");
+    }
+
     #[tokio::test]
     async fn test_conv_list_basic() {
         let client = ApiClient::with_token("test_token".to_string());
@@ -136,8 +261,80 @@ mod tests {
     #[tokio::test]
     async fn test_conv_history_basic() {
         let client = ApiClient::with_token("test_token".to_string());
-        let result = conv_history(&client, "C123456".to_string(), None, None, None).await;
+        let result = conv_history(&client, "C123456".to_string(), None, None, None, None).await;
         // Result will fail because there's no mock server, but that's expected
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_conv_history_all_follows_cursor() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("cursor", "next"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "1.0"}],
+                "has_more": false,
+                "response_metadata": {"next_cursor": ""}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "3.0"}, {"ts": "2.0"}],
+                "has_more": true,
+                "response_metadata": {"next_cursor": "next"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let response = conv_history_all(&client, "C1".to_string(), Some(2), None, None)
+            .await
+            .unwrap();
+
+        let messages = response.data["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[2]["ts"], "1.0");
+    }
+
+    #[tokio::test]
+    async fn test_conv_replies_params() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.replies"))
+            .and(query_param("channel", "C123"))
+            .and(query_param("ts", "1.000"))
+            .and(query_param("limit", "50"))
+            .and(query_param("oldest", "1.500"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "1.000"}, {"ts": "2.000", "thread_ts": "1.000"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let response = conv_replies(
+            &client,
+            "C123".to_string(),
+            "1.000".to_string(),
+            Some(50),
+            Some("1.500".to_string()),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.data["messages"].as_array().unwrap().len(), 2);
+    }
 }
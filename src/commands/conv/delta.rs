@@ -0,0 +1,302 @@
+//! Channel list snapshots and deltas (`conv list --snapshot-out/--diff-against`)
+//!
+//! A snapshot records the listed channels with the fields that matter for a
+//! change feed. Comparing the current list to an earlier snapshot reports
+//! channels that were created, removed, archived, unarchived, renamed or
+//! whose membership changed, without needing the Audit Logs API.
+
+use crate::api::ApiResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// One channel as recorded in a snapshot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotChannel {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_member: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_members: Option<u64>,
+}
+
+/// Channel list at a point in time
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChannelSnapshot {
+    /// UNIX time the snapshot was taken (absent for plain `conv list --raw` output)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taken_at: Option<u64>,
+    pub channels: Vec<SnapshotChannel>,
+}
+
+impl ChannelSnapshot {
+    /// Snapshot the `channels` of a `conversations.list` response
+    pub fn from_response(response: &ApiResponse, taken_at: u64) -> Self {
+        let channels = response
+            .data
+            .get("channels")
+            .and_then(|c| c.as_array())
+            .map(|channels| channels.iter().filter_map(snapshot_channel).collect())
+            .unwrap_or_default();
+        Self {
+            taken_at: Some(taken_at),
+            channels,
+        }
+    }
+
+    /// Load a snapshot
+    ///
+    /// Besides files written with `--snapshot-out`, the saved output of
+    /// `conv list --raw` (or its envelope) is accepted.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read snapshot {}: {}", path.display(), e))?;
+        let value: Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse snapshot {}: {}", path.display(), e))?;
+        let root = match value.get("response") {
+            Some(response) if value.get("channels").is_none() => response,
+            _ => &value,
+        };
+        let channels = root
+            .get("channels")
+            .and_then(|c| c.as_array())
+            .ok_or_else(|| format!("Snapshot {} has no 'channels' array", path.display()))?;
+        Ok(Self {
+            taken_at: root.get("taken_at").and_then(|t| t.as_u64()),
+            channels: channels.iter().filter_map(snapshot_channel).collect(),
+        })
+    }
+
+    /// Save the snapshot, creating the parent directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create snapshot directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize snapshot: {}", e))?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("Failed to write snapshot {}: {}", path.display(), e))
+    }
+}
+
+fn snapshot_channel(channel: &Value) -> Option<SnapshotChannel> {
+    let flag = |key: &str| channel.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+    Some(SnapshotChannel {
+        id: channel.get("id")?.as_str()?.to_string(),
+        name: channel
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        is_archived: flag("is_archived"),
+        is_private: flag("is_private"),
+        is_member: flag("is_member"),
+        num_members: channel.get("num_members").and_then(|n| n.as_u64()),
+    })
+}
+
+/// Change record: `change` is `created`, `removed`, `archived`,
+/// `unarchived`, `renamed` or `membership_changed`
+fn change(kind: &str, channel: &SnapshotChannel, detail: String) -> Value {
+    json!({
+        "change": kind,
+        "id": channel.id,
+        "name": channel.name,
+        "detail": detail,
+    })
+}
+
+/// Compare two snapshots
+///
+/// Channels missing from `current` are reported as `removed` (deleted, or no
+/// longer visible to the token). A channel can produce several records, e.g.
+/// renamed and archived.
+///
+/// # Returns
+/// Change records ordered by channel name
+pub fn diff_snapshots(previous: &ChannelSnapshot, current: &ChannelSnapshot) -> Vec<Value> {
+    let before: HashMap<&str, &SnapshotChannel> = previous
+        .channels
+        .iter()
+        .map(|c| (c.id.as_str(), c))
+        .collect();
+    let after: HashMap<&str, &SnapshotChannel> = current
+        .channels
+        .iter()
+        .map(|c| (c.id.as_str(), c))
+        .collect();
+
+    let mut changes: BTreeMap<(String, String), Vec<Value>> = BTreeMap::new();
+    let mut record = |channel: &SnapshotChannel, value: Value| {
+        changes
+            .entry((channel.name.clone(), channel.id.clone()))
+            .or_default()
+            .push(value);
+    };
+
+    for new in &current.channels {
+        let Some(old) = before.get(new.id.as_str()) else {
+            let visibility = if new.is_private { "private" } else { "public" };
+            record(new, change("created", new, visibility.to_string()));
+            continue;
+        };
+        if old.name != new.name {
+            record(
+                new,
+                change("renamed", new, format!("#{} -> #{}", old.name, new.name)),
+            );
+        }
+        match (old.is_archived, new.is_archived) {
+            (false, true) => record(new, change("archived", new, String::new())),
+            (true, false) => record(new, change("unarchived", new, String::new())),
+            _ => {}
+        }
+        let mut membership = Vec::new();
+        match (old.is_member, new.is_member) {
+            (false, true) => membership.push("joined".to_string()),
+            (true, false) => membership.push("left".to_string()),
+            _ => {}
+        }
+        if let (Some(a), Some(b)) = (old.num_members, new.num_members) {
+            if a != b {
+                membership.push(format!("members {} -> {}", a, b));
+            }
+        }
+        if !membership.is_empty() {
+            record(
+                new,
+                change("membership_changed", new, membership.join(", ")),
+            );
+        }
+    }
+    for old in &previous.channels {
+        if !after.contains_key(old.id.as_str()) {
+            record(old, change("removed", old, String::new()));
+        }
+    }
+
+    changes.into_values().flatten().collect()
+}
+
+/// Response body for `conv list --diff-against`
+///
+/// `changes` holds the records, `summary` the count per change kind and
+/// `since` the time of the previous snapshot when it is known.
+pub fn delta_response(previous: &ChannelSnapshot, current: &ChannelSnapshot) -> ApiResponse {
+    let changes = diff_snapshots(previous, current);
+    let mut summary: BTreeMap<String, usize> = BTreeMap::new();
+    for c in &changes {
+        if let Some(kind) = c.get("change").and_then(|k| k.as_str()) {
+            *summary.entry(kind.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut data = HashMap::new();
+    data.insert("changes".to_string(), Value::Array(changes));
+    data.insert("summary".to_string(), json!(summary));
+    data.insert("since".to_string(), json!(previous.taken_at));
+    data.insert("until".to_string(), json!(current.taken_at));
+    ApiResponse {
+        ok: true,
+        data,
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(id: &str, name: &str, archived: bool, member: bool, members: u64) -> Value {
+        json!({
+            "id": id,
+            "name": name,
+            "is_archived": archived,
+            "is_private": false,
+            "is_member": member,
+            "num_members": members,
+        })
+    }
+
+    fn snapshot(channels: Vec<Value>, taken_at: u64) -> ChannelSnapshot {
+        let response = ApiResponse {
+            ok: true,
+            data: HashMap::from([("channels".to_string(), Value::Array(channels))]),
+            error: None,
+        };
+        ChannelSnapshot::from_response(&response, taken_at)
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let previous = snapshot(
+            vec![
+                channel("C1", "general", false, true, 10),
+                channel("C2", "old-name", false, false, 3),
+                channel("C3", "project-x", false, true, 5),
+                channel("C4", "gone", false, false, 2),
+            ],
+            100,
+        );
+        let current = snapshot(
+            vec![
+                channel("C1", "general", false, true, 10),
+                channel("C2", "new-name", false, true, 4),
+                channel("C3", "project-x", true, true, 5),
+                channel("C5", "incident-42", false, true, 1),
+            ],
+            200,
+        );
+
+        let changes = diff_snapshots(&previous, &current);
+        let kinds: Vec<(&str, &str)> = changes
+            .iter()
+            .map(|c| (c["change"].as_str().unwrap(), c["id"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("removed", "C4"),
+                ("created", "C5"),
+                ("renamed", "C2"),
+                ("membership_changed", "C2"),
+                ("archived", "C3"),
+            ]
+        );
+        assert_eq!(changes[2]["detail"], "#old-name -> #new-name");
+        assert_eq!(changes[3]["detail"], "joined, members 3 -> 4");
+
+        let delta = delta_response(&previous, &current);
+        assert_eq!(delta.data["since"], 100);
+        assert_eq!(delta.data["summary"]["renamed"], 1);
+        assert_eq!(delta.data["changes"].as_array().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip_and_raw_list() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("snapshot.json");
+        let taken = snapshot(vec![channel("C1", "general", false, true, 10)], 100);
+        taken.save(&path).unwrap();
+        assert_eq!(ChannelSnapshot::load(&path).unwrap(), taken);
+
+        // Saved `conv list` envelope output works as a snapshot too
+        std::fs::write(
+            &path,
+            json!({"ok": true, "response": {"channels": [channel("C1", "general", false, true, 10)]}})
+                .to_string(),
+        )
+        .unwrap();
+        let loaded = ChannelSnapshot::load(&path).unwrap();
+        assert_eq!(loaded.taken_at, None);
+        assert_eq!(loaded.channels, taken.channels);
+    }
+}
@@ -0,0 +1,169 @@
+//! Edit-event canonicalization (`--dedupe-edits`)
+//!
+//! History and tail output can contain `message_changed` events next to (or
+//! instead of) the message they edit. Collapsing them leaves one entry per
+//! message: the final version of its content, under the original `ts`, with
+//! Slack's `edited` marker set.
+
+use crate::api::ApiResponse;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Subtype of an edit event
+const MESSAGE_CHANGED: &str = "message_changed";
+
+fn ts_value(ts: &str) -> f64 {
+    ts.parse::<f64>().unwrap_or(0.0)
+}
+
+fn is_edit_event(message: &Value) -> bool {
+    message.get("subtype").and_then(|s| s.as_str()) == Some(MESSAGE_CHANGED)
+}
+
+/// Final message version carried by an edit event, with `edited` set
+fn edited_version(event: &Value) -> Option<Map<String, Value>> {
+    let mut message = event.get("message")?.as_object()?.clone();
+    message.get("ts")?.as_str()?;
+    if !message.contains_key("edited") {
+        let mut edited = Map::new();
+        if let Some(user) = message.get("user") {
+            edited.insert("user".to_string(), user.clone());
+        }
+        if let Some(ts) = event.get("ts") {
+            edited.insert("ts".to_string(), ts.clone());
+        }
+        message.insert("edited".to_string(), Value::Object(edited));
+    }
+    Some(message)
+}
+
+/// Collapse `message_changed` events into the messages they edit
+///
+/// Events are applied oldest first, so the latest edit wins. When the edited
+/// message is present its fields are overwritten by the final version (fields
+/// only the original has, such as `reply_count`, are kept) and the event is
+/// dropped; otherwise the event itself is replaced by the final version.
+/// Either way the result keeps the original `ts`.
+///
+/// # Returns
+/// Number of edit events collapsed
+pub fn dedupe_edits(messages: &mut Vec<Value>) -> usize {
+    let mut events: Vec<usize> = (0..messages.len())
+        .filter(|&i| is_edit_event(&messages[i]))
+        .collect();
+    if events.is_empty() {
+        return 0;
+    }
+    events.sort_by(|&a, &b| {
+        let ts = |i: usize| ts_value(messages[i].get("ts").and_then(|t| t.as_str()).unwrap_or(""));
+        ts(a).total_cmp(&ts(b))
+    });
+
+    let mut by_ts: HashMap<String, usize> = messages
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| !is_edit_event(m))
+        .filter_map(|(i, m)| Some((m.get("ts")?.as_str()?.to_string(), i)))
+        .collect();
+
+    let mut collapsed = vec![false; messages.len()];
+    let mut count = 0;
+    for i in events {
+        let Some(version) = edited_version(&messages[i]) else {
+            continue;
+        };
+        let ts = version["ts"].as_str().unwrap_or_default().to_string();
+        match by_ts.get(&ts) {
+            Some(&target) => {
+                if let Some(original) = messages[target].as_object_mut() {
+                    original.extend(version);
+                }
+                collapsed[i] = true;
+            }
+            None => {
+                messages[i] = Value::Object(version);
+                by_ts.insert(ts, i);
+            }
+        }
+        count += 1;
+    }
+
+    let mut index = 0;
+    messages.retain(|_| {
+        index += 1;
+        !collapsed[index - 1]
+    });
+    count
+}
+
+/// Collapse edit events in the `messages` of a response
+pub fn apply_dedupe_edits(response: &mut ApiResponse) -> usize {
+    match response.data.get_mut("messages") {
+        Some(Value::Array(messages)) => dedupe_edits(messages),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn edit_event(event_ts: &str, message: Value) -> Value {
+        json!({
+            "type": "message",
+            "subtype": MESSAGE_CHANGED,
+            "hidden": true,
+            "ts": event_ts,
+            "message": message,
+        })
+    }
+
+    #[test]
+    fn test_dedupe_edits_replaces_original() {
+        let mut messages = vec![
+            edit_event(
+                "30.0",
+                json!({"ts": "10.0", "user": "U1", "text": "final", "edited": {"user": "U1", "ts": "30.0"}}),
+            ),
+            edit_event(
+                "20.0",
+                json!({"ts": "10.0", "user": "U1", "text": "second"}),
+            ),
+            json!({"ts": "15.0", "user": "U2", "text": "unrelated"}),
+            json!({"ts": "10.0", "user": "U1", "text": "first", "reply_count": 2}),
+        ];
+
+        assert_eq!(dedupe_edits(&mut messages), 2);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1]["ts"], "10.0");
+        assert_eq!(messages[1]["text"], "final");
+        assert_eq!(messages[1]["reply_count"], 2);
+        assert_eq!(messages[1]["edited"]["ts"], "30.0");
+    }
+
+    #[test]
+    fn test_dedupe_edits_without_original() {
+        let mut messages = vec![
+            edit_event(
+                "20.0",
+                json!({"ts": "10.0", "user": "U1", "text": "edited"}),
+            ),
+            json!({"ts": "15.0", "text": "other"}),
+        ];
+
+        assert_eq!(dedupe_edits(&mut messages), 1);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0]["ts"], "10.0");
+        assert_eq!(messages[0]["text"], "edited");
+        assert!(messages[0].get("subtype").is_none());
+        assert_eq!(messages[0]["edited"], json!({"user": "U1", "ts": "20.0"}));
+    }
+
+    #[test]
+    fn test_dedupe_edits_noop() {
+        let mut messages = vec![json!({"ts": "1.0", "text": "hi"})];
+        assert_eq!(dedupe_edits(&mut messages), 0);
+        assert_eq!(messages, vec![json!({"ts": "1.0", "text": "hi"})]);
+    }
+}
@@ -0,0 +1,308 @@
+//! Conversation export functionality
+//!
+//! Fetches the complete history of a conversation (including thread replies)
+//! and renders it as JSON or as a self-contained static HTML page.
+
+use super::html::{render_html, HtmlOptions};
+use super::redact::RedactionReport;
+use crate::api::{ApiClient, ApiError, ApiMethod};
+use crate::commands::thread::thread_get;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum history pages to fetch to prevent infinite loops
+const MAX_PAGES: usize = 1000;
+
+/// Page size used when fetching history for export
+const EXPORT_PAGE_LIMIT: u32 = 200;
+
+/// Output format for conversation export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "json" => Ok(ExportFormat::Json),
+            "html" => Ok(ExportFormat::Html),
+            _ => Err(format!(
+                "Invalid export format '{}'. Valid values: json, html",
+                s
+            )),
+        }
+    }
+}
+
+/// Exported conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationExport {
+    /// Channel ID
+    pub channel: String,
+    /// Unix timestamp of the export
+    pub exported_at: u64,
+    /// Top-level messages in chronological order; thread replies are
+    /// attached to their parent under a `replies` array
+    pub messages: Vec<Value>,
+    /// Per-rule match counts when `--redact` was applied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redaction: Option<RedactionReport>,
+}
+
+impl ConversationExport {
+    /// Total number of messages including thread replies
+    pub fn message_count(&self) -> usize {
+        self.messages
+            .iter()
+            .map(|m| {
+                1 + m
+                    .get("replies")
+                    .and_then(|r| r.as_array())
+                    .map_or(0, |r| r.len())
+            })
+            .sum()
+    }
+
+    /// Render the export in the requested format
+    ///
+    /// # Arguments
+    /// * `format` - Output format
+    /// * `html_options` - Rendering options (used for HTML only)
+    ///
+    /// # Returns
+    /// * `Ok(String)` with the rendered document
+    /// * `Err(String)` if serialization fails
+    pub fn render(
+        &self,
+        format: ExportFormat,
+        html_options: &HtmlOptions,
+    ) -> Result<String, String> {
+        match format {
+            ExportFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| format!("Failed to serialize JSON: {}", e)),
+            ExportFormat::Html => Ok(render_html(self, html_options)),
+        }
+    }
+}
+
+/// Export a conversation with automatic pagination
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `oldest` - Optional oldest timestamp to include
+/// * `latest` - Optional latest timestamp to include
+/// * `include_threads` - Whether to fetch thread replies for threaded messages
+///
+/// # Returns
+/// * `Ok(ConversationExport)` with messages in chronological order
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_export(
+    client: &ApiClient,
+    channel: String,
+    oldest: Option<String>,
+    latest: Option<String>,
+    include_threads: bool,
+) -> Result<ConversationExport, ApiError> {
+    let mut messages = Vec::new();
+    let mut cursor: Option<String> = None;
+    let mut page_count = 0;
+
+    loop {
+        page_count += 1;
+        if page_count > MAX_PAGES {
+            return Err(ApiError::SlackError(format!(
+                "Pagination exceeded max pages ({}), possible infinite loop",
+                MAX_PAGES
+            )));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("channel".to_string(), json!(channel));
+        params.insert("limit".to_string(), json!(EXPORT_PAGE_LIMIT));
+        if let Some(ref oldest) = oldest {
+            params.insert("oldest".to_string(), json!(oldest));
+        }
+        if let Some(ref latest) = latest {
+            params.insert("latest".to_string(), json!(latest));
+        }
+        if let Some(ref cursor_val) = cursor {
+            params.insert("cursor".to_string(), json!(cursor_val));
+        }
+
+        let response = client
+            .call_method(ApiMethod::ConversationsHistory, params)
+            .await?;
+
+        if let Some(page) = response.data.get("messages").and_then(|m| m.as_array()) {
+            messages.extend(page.iter().cloned());
+        }
+        crate::progress::report("export", messages.len() as u64, None);
+
+        cursor = response
+            .data
+            .get("response_metadata")
+            .and_then(|meta| meta.get("next_cursor"))
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_string());
+
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    sort_by_ts(&mut messages);
+
+    if include_threads {
+        let thread_total = messages
+            .iter()
+            .filter(|m| m.get("reply_count").and_then(|c| c.as_u64()).unwrap_or(0) > 0)
+            .count() as u64;
+        let mut threads_done = 0;
+        for message in messages.iter_mut() {
+            let reply_count = message
+                .get("reply_count")
+                .and_then(|c| c.as_u64())
+                .unwrap_or(0);
+            let Some(thread_ts) = message.get("ts").and_then(|t| t.as_str()) else {
+                continue;
+            };
+            if reply_count == 0 {
+                continue;
+            }
+
+            let thread_ts = thread_ts.to_string();
+            let response =
+                thread_get(client, channel.clone(), thread_ts.clone(), None, None).await?;
+            let mut replies: Vec<Value> = response
+                .data
+                .get("messages")
+                .and_then(|m| m.as_array())
+                .map(|m| {
+                    m.iter()
+                        .filter(|r| r.get("ts").and_then(|t| t.as_str()) != Some(&thread_ts))
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default();
+            sort_by_ts(&mut replies);
+
+            if let Some(obj) = message.as_object_mut() {
+                obj.insert("replies".to_string(), json!(replies));
+            }
+            threads_done += 1;
+            crate::progress::report("export.threads", threads_done, Some(thread_total));
+        }
+    }
+
+    let exported_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    Ok(ConversationExport {
+        channel,
+        exported_at,
+        messages,
+        redaction: None,
+    })
+}
+
+/// Sort messages by `ts` ascending (oldest first)
+fn sort_by_ts(messages: &mut [Value]) {
+    messages.sort_by(|a, b| {
+        let ts = |m: &Value| {
+            m.get("ts")
+                .and_then(|t| t.as_str())
+                .and_then(|t| t.parse::<f64>().ok())
+                .unwrap_or(0.0)
+        };
+        ts(a).total_cmp(&ts(b))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn test_export_format_parse() {
+        assert_eq!(ExportFormat::parse("json").unwrap(), ExportFormat::Json);
+        assert_eq!(ExportFormat::parse("html").unwrap(), ExportFormat::Html);
+        assert!(ExportFormat::parse("pdf").is_err());
+    }
+
+    #[test]
+    fn test_message_count_includes_replies() {
+        let export = ConversationExport {
+            channel: "C123".to_string(),
+            exported_at: 0,
+            messages: vec![
+                json!({"ts": "1.0", "text": "a", "replies": [{"ts": "2.0"}, {"ts": "3.0"}]}),
+                json!({"ts": "4.0", "text": "b"}),
+            ],
+            redaction: None,
+        };
+        assert_eq!(export.message_count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_conv_export_paginates_and_attaches_replies() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("cursor", "next"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "100.000001", "text": "first", "reply_count": 1}],
+                "response_metadata": {"next_cursor": ""}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "200.000001", "text": "second"}],
+                "response_metadata": {"next_cursor": "next"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/conversations.replies"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "100.000001", "text": "first"},
+                    {"ts": "150.000001", "text": "reply", "thread_ts": "100.000001"}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let export = conv_export(&client, "C123".to_string(), None, None, true)
+            .await
+            .unwrap();
+
+        assert_eq!(export.messages.len(), 2);
+        assert_eq!(export.messages[0]["text"], "first");
+        assert_eq!(export.messages[1]["text"], "second");
+        let replies = export.messages[0]["replies"].as_array().unwrap();
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0]["text"], "reply");
+        assert_eq!(export.message_count(), 3);
+    }
+}
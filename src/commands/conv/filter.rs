@@ -90,8 +90,19 @@ impl ConversationFilter {
     }
 }
 
+impl std::fmt::Display for ConversationFilter {
+    /// Format in the `key:value` form accepted by [`ConversationFilter::parse`]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversationFilter::Name(pattern) => write!(f, "name:{}", pattern),
+            ConversationFilter::IsMember(value) => write!(f, "is_member:{}", value),
+            ConversationFilter::IsPrivate(value) => write!(f, "is_private:{}", value),
+        }
+    }
+}
+
 /// Pattern matching: glob if contains *, otherwise case-insensitive substring match
-fn glob_match(pattern: &str, text: &str) -> bool {
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
     if pattern == "*" {
         return true;
     }
@@ -1,42 +1,9 @@
 //! Output formatting functionality for conversations
 
 use crate::api::ApiResponse;
-use std::fmt;
-
-/// Output format for conversation list
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum OutputFormat {
-    Json,
-    Jsonl,
-    Table,
-    Tsv,
-}
+use crate::commands::output::format_output;
 
-impl OutputFormat {
-    pub fn parse(s: &str) -> Result<Self, String> {
-        match s {
-            "json" => Ok(OutputFormat::Json),
-            "jsonl" => Ok(OutputFormat::Jsonl),
-            "table" => Ok(OutputFormat::Table),
-            "tsv" => Ok(OutputFormat::Tsv),
-            _ => Err(format!(
-                "Invalid format '{}'. Valid values: json, jsonl, table, tsv",
-                s
-            )),
-        }
-    }
-}
-
-impl fmt::Display for OutputFormat {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            OutputFormat::Json => write!(f, "json"),
-            OutputFormat::Jsonl => write!(f, "jsonl"),
-            OutputFormat::Table => write!(f, "table"),
-            OutputFormat::Tsv => write!(f, "tsv"),
-        }
-    }
-}
+pub use crate::commands::output::OutputFormat;
 
 /// Format response for output
 pub fn format_response(response: &ApiResponse, format: OutputFormat) -> Result<String, String> {
@@ -60,9 +27,21 @@ pub fn format_response(response: &ApiResponse, format: OutputFormat) -> Result<S
         }
         OutputFormat::Table => format_as_table(response),
         OutputFormat::Tsv => format_as_tsv(response),
+        OutputFormat::Csv | OutputFormat::Yaml => format_shared(response, "conv list", format),
     }
 }
 
+/// Format through the shared formatter (CSV, YAML)
+fn format_shared(
+    response: &ApiResponse,
+    command: &str,
+    format: OutputFormat,
+) -> Result<String, String> {
+    let value =
+        serde_json::to_value(response).map_err(|e| format!("Failed to serialize JSON: {}", e))?;
+    format_output(&value, command, format, None)
+}
+
 /// Format response as table
 fn format_as_table(response: &ApiResponse) -> Result<String, String> {
     let channels = match response.data.get("channels").and_then(|v| v.as_array()) {
@@ -194,6 +173,105 @@ fn format_as_tsv(response: &ApiResponse) -> Result<String, String> {
     Ok(output)
 }
 
+/// Format the `messages` of a response (history, replies)
+///
+/// Table and TSV show ts, user, reply count and the first line of text.
+pub fn format_messages(response: &ApiResponse, format: OutputFormat) -> Result<String, String> {
+    let messages = response
+        .data
+        .get("messages")
+        .and_then(|v| v.as_array())
+        .map(|m| m.as_slice())
+        .unwrap_or_default();
+
+    let str_field = |message: &serde_json::Value, key: &str| {
+        message
+            .get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string()
+    };
+    let rows: Vec<[String; 4]> = messages
+        .iter()
+        .map(|m| {
+            let user = m
+                .get("user")
+                .or_else(|| m.get("bot_id"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+            let replies = m
+                .get("reply_count")
+                .and_then(|v| v.as_u64())
+                .map(|n| n.to_string())
+                .unwrap_or_default();
+            let text = str_field(m, "text")
+                .lines()
+                .next()
+                .unwrap_or("")
+                .replace('\t', " ");
+            [str_field(m, "ts"), user, replies, text]
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&response)
+            .map_err(|e| format!("Failed to serialize JSON: {}", e)),
+        OutputFormat::Jsonl => Ok(messages
+            .iter()
+            .filter_map(|m| serde_json::to_string(m).ok())
+            .collect::<Vec<_>>()
+            .join("\n")),
+        OutputFormat::Csv | OutputFormat::Yaml => format_shared(response, "conv history", format),
+        OutputFormat::Tsv => {
+            if rows.is_empty() {
+                return Ok(String::new());
+            }
+            let mut output = String::from("ts\tuser\treply_count\ttext\n");
+            for row in rows {
+                output.push_str(&row.join("\t"));
+                output.push('\n');
+            }
+            Ok(output)
+        }
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                return Ok(String::new());
+            }
+            let header = ["TS", "USER", "REPLIES", "TEXT"];
+            let mut widths = header.map(|h| h.len());
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row.iter()).take(3) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+            let line = |cells: [&str; 4]| {
+                format!(
+                    "{:w0$}  {:w1$}  {:w2$}  {}\n",
+                    cells[0],
+                    cells[1],
+                    cells[2],
+                    cells[3],
+                    w0 = widths[0],
+                    w1 = widths[1],
+                    w2 = widths[2],
+                )
+            };
+            let mut output = line(header);
+            output.push_str(&line([
+                &"-".repeat(widths[0]),
+                &"-".repeat(widths[1]),
+                &"-".repeat(widths[2]),
+                "----",
+            ]));
+            for row in &rows {
+                output.push_str(&line([&row[0], &row[1], &row[2], &row[3]]));
+            }
+            Ok(output)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,4 +353,31 @@ mod tests {
         assert!(output.contains("general"));
         assert!(output.contains("42"));
     }
+
+    #[test]
+    fn test_format_messages() {
+        let response = ApiResponse {
+            ok: true,
+            data: HashMap::from([(
+                "messages".to_string(),
+                json!([
+                    {"ts": "1.000", "user": "U1", "text": "Deploy\nDetails", "reply_count": 2},
+                    {"ts": "2.000", "bot_id": "B1", "text": "done", "thread_ts": "1.000"},
+                ]),
+            )]),
+            error: None,
+        };
+
+        let tsv = format_messages(&response, OutputFormat::Tsv).unwrap();
+        assert_eq!(
+            tsv,
+            "ts\tuser\treply_count\ttext\n1.000\tU1\t2\tDeploy\n2.000\tB1\t\tdone\n"
+        );
+
+        let jsonl = format_messages(&response, OutputFormat::Jsonl).unwrap();
+        assert_eq!(jsonl.lines().count(), 2);
+
+        let table = format_messages(&response, OutputFormat::Table).unwrap();
+        assert!(table.starts_with("TS     USER  REPLIES  TEXT\n"));
+    }
 }
@@ -0,0 +1,256 @@
+//! Static HTML rendering for conversation exports
+//!
+//! Produces a single self-contained page (inline CSS and JS, no external
+//! requests unless avatars are enabled) with:
+//! - Messages in chronological order
+//! - Thread replies folded under their parent
+//! - A search box filtering messages client-side
+
+use super::export::ConversationExport;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Rendering options for the HTML export
+#[derive(Debug, Clone, Default)]
+pub struct HtmlOptions {
+    /// Page title (defaults to the channel ID)
+    pub title: Option<String>,
+    /// Show user avatars (loaded from Slack-hosted image URLs)
+    pub avatars: bool,
+    /// Known user ID to display name mappings
+    pub user_names: HashMap<String, String>,
+}
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, sans-serif; margin: 0; color: #1d1c1d; background: #fff; }
+header { position: sticky; top: 0; background: #3f0e40; color: #fff; padding: 12px 20px; }
+header h1 { margin: 0 0 4px; font-size: 18px; }
+header .meta { margin: 0 0 8px; font-size: 12px; opacity: 0.8; }
+#search { width: 100%; max-width: 480px; padding: 6px 8px; border-radius: 4px; border: none; font-size: 14px; }
+main { padding: 12px 20px; }
+.msg { display: flex; gap: 8px; padding: 6px 0; border-bottom: 1px solid #eee; }
+.msg.hidden { display: none; }
+.avatar { width: 36px; height: 36px; border-radius: 4px; flex-shrink: 0; }
+.body { flex: 1; min-width: 0; }
+.user { font-weight: bold; margin-right: 6px; }
+time { color: #616061; font-size: 12px; }
+.text { white-space: pre-wrap; word-wrap: break-word; margin-top: 2px; }
+details.thread { margin-top: 4px; }
+details.thread summary { color: #1264a3; cursor: pointer; font-size: 13px; }
+details.thread .msg { border-bottom: none; padding-left: 8px; border-left: 2px solid #ddd; }
+"#;
+
+const SCRIPT: &str = r#"
+document.querySelectorAll('time[data-ts]').forEach(function (el) {
+  var ts = parseFloat(el.getAttribute('data-ts'));
+  if (!isNaN(ts)) { el.textContent = new Date(ts * 1000).toLocaleString(); }
+});
+document.getElementById('search').addEventListener('input', function (e) {
+  var q = e.target.value.toLowerCase();
+  document.querySelectorAll('#messages > .msg').forEach(function (msg) {
+    var match = !q || msg.textContent.toLowerCase().indexOf(q) !== -1;
+    msg.classList.toggle('hidden', !match);
+    var thread = msg.querySelector('details.thread');
+    if (thread && q) { thread.open = match && thread.textContent.toLowerCase().indexOf(q) !== -1; }
+  });
+});
+"#;
+
+/// Render a conversation export as a self-contained HTML page
+///
+/// # Arguments
+/// * `export` - Exported conversation
+/// * `options` - Rendering options
+///
+/// # Returns
+/// Complete HTML document
+pub fn render_html(export: &ConversationExport, options: &HtmlOptions) -> String {
+    let title = options.title.as_deref().unwrap_or(&export.channel);
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!("<title>{}</title>\n", escape_html(title)));
+    html.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", STYLE));
+    html.push_str("<header>\n");
+    html.push_str(&format!("<h1>{}</h1>\n", escape_html(title)));
+    let redacted = export
+        .redaction
+        .as_ref()
+        .map(|r| format!(" &middot; {} values redacted", r.total()))
+        .unwrap_or_default();
+    html.push_str(&format!(
+        "<p class=\"meta\">{} messages &middot; exported <time data-ts=\"{}\">{}</time>{}</p>\n",
+        export.message_count(),
+        export.exported_at,
+        export.exported_at,
+        redacted
+    ));
+    html.push_str("<input id=\"search\" type=\"search\" placeholder=\"Search messages\">\n");
+    html.push_str("</header>\n<main id=\"messages\">\n");
+
+    for message in &export.messages {
+        render_message(&mut html, message, options);
+    }
+
+    html.push_str("</main>\n");
+    html.push_str(&format!("<script>{}</script>\n", SCRIPT));
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Render a single message (and its folded thread replies)
+fn render_message(html: &mut String, message: &Value, options: &HtmlOptions) {
+    let ts = message.get("ts").and_then(|t| t.as_str()).unwrap_or("");
+    let text = message.get("text").and_then(|t| t.as_str()).unwrap_or("");
+
+    html.push_str(&format!(
+        "<article class=\"msg\" data-ts=\"{}\">",
+        escape_html(ts)
+    ));
+
+    if options.avatars {
+        if let Some(url) = message
+            .get("user_profile")
+            .and_then(|p| p.get("image_72"))
+            .and_then(|u| u.as_str())
+        {
+            html.push_str(&format!(
+                "<img class=\"avatar\" src=\"{}\" alt=\"\">",
+                escape_html(url)
+            ));
+        }
+    }
+
+    html.push_str("<div class=\"body\"><div class=\"head\">");
+    html.push_str(&format!(
+        "<span class=\"user\">{}</span><time data-ts=\"{}\">{}</time>",
+        escape_html(&display_name(message, options)),
+        escape_html(ts),
+        escape_html(ts)
+    ));
+    html.push_str("</div>");
+    html.push_str(&format!("<div class=\"text\">{}</div>", escape_html(text)));
+
+    if let Some(replies) = message
+        .get("replies")
+        .and_then(|r| r.as_array())
+        .filter(|r| !r.is_empty())
+    {
+        html.push_str(&format!(
+            "<details class=\"thread\"><summary>{} {}</summary>",
+            replies.len(),
+            if replies.len() == 1 {
+                "reply"
+            } else {
+                "replies"
+            }
+        ));
+        for reply in replies {
+            render_message(html, reply, options);
+        }
+        html.push_str("</details>");
+    }
+
+    html.push_str("</div></article>\n");
+}
+
+/// Resolve the display name for a message author
+fn display_name(message: &Value, options: &HtmlOptions) -> String {
+    let user_id = message.get("user").and_then(|u| u.as_str());
+
+    if let Some(name) = user_id.and_then(|id| options.user_names.get(id)) {
+        return name.clone();
+    }
+
+    let profile = message.get("user_profile");
+    ["display_name", "real_name"]
+        .iter()
+        .filter_map(|key| profile.and_then(|p| p.get(*key)).and_then(|v| v.as_str()))
+        .chain(message.get("username").and_then(|u| u.as_str()))
+        .chain(user_id)
+        .find(|s| !s.is_empty())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+/// Escape text for safe inclusion in HTML content and attribute values
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_export() -> ConversationExport {
+        ConversationExport {
+            channel: "C123".to_string(),
+            exported_at: 1700000000,
+            messages: vec![
+                json!({
+                    "ts": "1700000000.000100",
+                    "user": "U1",
+                    "text": "Hello <script>alert(1)</script>",
+                    "user_profile": {"display_name": "alice", "image_72": "https://example.com/a.png"},
+                    "replies": [{"ts": "1700000001.000100", "user": "U2", "text": "reply"}]
+                }),
+                json!({"ts": "1700000002.000100", "username": "deploy-bot", "text": "done"}),
+            ],
+            redaction: None,
+        }
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<a href=\"x\">&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_render_html_structure() {
+        let html = render_html(&sample_export(), &HtmlOptions::default());
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>C123</title>"));
+        assert!(html.contains("id=\"search\""));
+        assert!(html.contains("3 messages"));
+        assert!(html.contains("<summary>1 reply</summary>"));
+        assert!(html.contains("Hello &lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<script>alert(1)"));
+        // Avatars are opt-in
+        assert!(!html.contains("class=\"avatar\""));
+    }
+
+    #[test]
+    fn test_render_html_names_and_avatars() {
+        let mut user_names = HashMap::new();
+        user_names.insert("U2".to_string(), "bob".to_string());
+        let options = HtmlOptions {
+            title: Some("#general".to_string()),
+            avatars: true,
+            user_names,
+        };
+        let html = render_html(&sample_export(), &options);
+
+        assert!(html.contains("<title>#general</title>"));
+        assert!(html.contains("<span class=\"user\">alice</span>"));
+        assert!(html.contains("<span class=\"user\">bob</span>"));
+        assert!(html.contains("<span class=\"user\">deploy-bot</span>"));
+        assert!(html.contains("src=\"https://example.com/a.png\""));
+    }
+}
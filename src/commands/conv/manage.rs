@@ -0,0 +1,345 @@
+//! Conversation management (`conv create/archive/unarchive/rename/invite/kick/join/leave`)
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+
+/// Build a `conversations.create` request
+pub fn plan_conv_create(name: &str, is_private: bool) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsCreate)
+        .param("name", name)
+        .param("is_private", is_private)
+}
+
+/// Build a `conversations.archive` request
+pub fn plan_conv_archive(channel: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsArchive).param("channel", channel)
+}
+
+/// Build a `conversations.unarchive` request
+pub fn plan_conv_unarchive(channel: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsUnarchive).param("channel", channel)
+}
+
+/// Build a `conversations.rename` request
+pub fn plan_conv_rename(channel: &str, name: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsRename)
+        .param("channel", channel)
+        .param("name", name)
+}
+
+/// Build a `conversations.invite` request (`users` is sent comma-separated)
+pub fn plan_conv_invite(channel: &str, users: &[String]) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsInvite)
+        .param("channel", channel)
+        .param("users", users.join(","))
+}
+
+/// Build a `conversations.kick` request
+pub fn plan_conv_kick(channel: &str, user: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsKick)
+        .param("channel", channel)
+        .param("user", user)
+}
+
+/// Build a `conversations.join` request
+pub fn plan_conv_join(channel: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsJoin).param("channel", channel)
+}
+
+/// Build a `conversations.leave` request
+pub fn plan_conv_leave(channel: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsLeave).param("channel", channel)
+}
+
+/// Create a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `name` - Channel name (lowercase, no spaces)
+/// * `is_private` - Create a private channel
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the created `channel`
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_create(
+    client: &ApiClient,
+    name: String,
+    is_private: bool,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let private_flag = if is_private { " --private" } else { "" };
+    let hint = format!(
+        "Example: slack-rs conv create {}{} --yes",
+        name, private_flag
+    );
+    let action = format!("create channel '{}'", name);
+    confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_conv_create(&name, is_private))
+        .await
+}
+
+/// Archive a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with archive confirmation
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_archive(
+    client: &ApiClient,
+    channel: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs conv archive {} --yes", channel);
+    confirm_destructive_with_hint(yes, "archive this channel", non_interactive, Some(&hint))?;
+
+    client.execute_planned(plan_conv_archive(&channel)).await
+}
+
+/// Unarchive a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with unarchive confirmation
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_unarchive(
+    client: &ApiClient,
+    channel: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs conv unarchive {} --yes", channel);
+    confirm_destructive_with_hint(yes, "unarchive this channel", non_interactive, Some(&hint))?;
+
+    client.execute_planned(plan_conv_unarchive(&channel)).await
+}
+
+/// Rename a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `name` - New channel name
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the renamed `channel`
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_rename(
+    client: &ApiClient,
+    channel: String,
+    name: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs conv rename {} {} --yes", channel, name);
+    let action = format!("rename this channel to '{}'", name);
+    confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_conv_rename(&channel, &name))
+        .await
+}
+
+/// Invite users to a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `users` - User IDs to invite
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the updated `channel`
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_invite(
+    client: &ApiClient,
+    channel: String,
+    users: Vec<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs conv invite {} {} --yes",
+        channel,
+        users.join(" ")
+    );
+    let action = format!("invite {} user(s) to this channel", users.len());
+    confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_conv_invite(&channel, &users))
+        .await
+}
+
+/// Remove a user from a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `user` - User ID to remove
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with removal confirmation
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_kick(
+    client: &ApiClient,
+    channel: String,
+    user: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs conv kick {} {} --yes", channel, user);
+    let action = format!("remove {} from this channel", user);
+    confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_conv_kick(&channel, &user))
+        .await
+}
+
+/// Join a public channel as the token's user or bot
+///
+/// Private channels, DMs and group DMs cannot be joined this way; Slack
+/// answers `method_not_supported_for_channel_type` and a member has to
+/// invite the user instead.
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the joined `channel`
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_join(
+    client: &ApiClient,
+    channel: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs conv join {} --yes", channel);
+    confirm_destructive_with_hint(yes, "join this channel", non_interactive, Some(&hint))?;
+
+    client.execute_planned(plan_conv_join(&channel)).await
+}
+
+/// Leave a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with leave confirmation
+/// * `Err(ApiError)` if the operation fails
+pub async fn conv_leave(
+    client: &ApiClient,
+    channel: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs conv leave {} --yes", channel);
+    confirm_destructive_with_hint(yes, "leave this channel", non_interactive, Some(&hint))?;
+
+    client.execute_planned(plan_conv_leave(&channel)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_plan_conv_manage() {
+        let create = plan_conv_create("ops-alerts", true);
+        assert_eq!(create.method, ApiMethod::ConversationsCreate);
+        assert_eq!(create.params["name"], "ops-alerts");
+        assert_eq!(create.params["is_private"], true);
+
+        let invite = plan_conv_invite("C1", &["U1".to_string(), "U2".to_string()]);
+        assert_eq!(invite.method, ApiMethod::ConversationsInvite);
+        assert_eq!(invite.params["users"], "U1,U2");
+
+        let rename = plan_conv_rename("C1", "ops");
+        assert_eq!(rename.params["channel"], "C1");
+        assert_eq!(rename.params["name"], "ops");
+
+        assert_eq!(
+            plan_conv_archive("C1").method,
+            ApiMethod::ConversationsArchive
+        );
+        assert_eq!(
+            plan_conv_unarchive("C1").method,
+            ApiMethod::ConversationsUnarchive
+        );
+        assert_eq!(plan_conv_kick("C1", "U1").params["user"], "U1");
+        assert_eq!(plan_conv_join("C1").method, ApiMethod::ConversationsJoin);
+        assert_eq!(plan_conv_leave("C1").params["channel"], "C1");
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_conv_archive_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = conv_archive(&client, "C123456".to_string(), true, false).await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_conv_kick_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = conv_kick(
+            &client,
+            "C123456".to_string(),
+            "U123456".to_string(),
+            true,
+            false,
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+}
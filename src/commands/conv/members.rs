@@ -0,0 +1,319 @@
+//! Conversation members (`conv members`)
+//!
+//! With `--resolve` member IDs become records from the users cache, with
+//! the guest flags and an `is_external` marker for people from other
+//! organizations in Slack Connect channels, which `--only-guests` and
+//! `--only-external` filter on.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::users_cache::WorkspaceCache;
+use serde_json::{json, Value};
+
+/// Maximum pages to follow before assuming a cursor loop
+const MAX_PAGES: usize = 1000;
+
+/// Build one `conversations.members` page request
+pub fn plan_conv_members(channel: &str, limit: u32, cursor: Option<&str>) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsMembers)
+        .param("channel", channel)
+        .param("limit", limit)
+        .param_opt("cursor", cursor)
+}
+
+/// List every member of a conversation, following cursors
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with all member IDs in `members`; an error response
+///   from the first page is returned as-is
+/// * `Err(ApiError)` if a request fails
+pub async fn conv_members(client: &ApiClient, channel: &str) -> Result<ApiResponse, ApiError> {
+    let mut members = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    for _ in 0..MAX_PAGES {
+        let response = client
+            .execute_planned(plan_conv_members(channel, 200, cursor.as_deref()))
+            .await?;
+        if !response.ok {
+            return Ok(response);
+        }
+
+        if let Some(page) = response.data.get("members").and_then(|m| m.as_array()) {
+            members.extend(page.iter().cloned());
+        }
+
+        cursor = response
+            .data
+            .get("response_metadata")
+            .and_then(|meta| meta.get("next_cursor"))
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_string());
+        if cursor.is_none() {
+            return Ok(ApiResponse {
+                ok: true,
+                data: [
+                    ("channel".to_string(), json!(channel)),
+                    ("count".to_string(), json!(members.len())),
+                    ("members".to_string(), Value::Array(members)),
+                ]
+                .into_iter()
+                .collect(),
+                error: None,
+            });
+        }
+    }
+
+    Err(ApiError::SlackError(format!(
+        "Pagination exceeded max pages ({}), possible infinite loop",
+        MAX_PAGES
+    )))
+}
+
+/// Build a `conversations.info` request
+pub fn plan_conv_info(channel: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ConversationsInfo).param("channel", channel)
+}
+
+/// Slack Connect state of a channel
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectInfo {
+    /// Shared with at least one other organization
+    pub is_ext_shared: bool,
+    /// Teams the channel is shared with
+    pub connected_team_ids: Vec<String>,
+}
+
+impl ConnectInfo {
+    /// Read the connect fields of a `conversations.info` channel object
+    pub fn from_channel(channel: &Value) -> Self {
+        let connected_team_ids = ["connected_team_ids", "shared_team_ids"]
+            .iter()
+            .find_map(|key| channel.get(*key).and_then(|v| v.as_array()))
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect();
+        Self {
+            is_ext_shared: channel
+                .get("is_ext_shared")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            connected_team_ids,
+        }
+    }
+}
+
+/// Fetch the Slack Connect state of a channel
+pub async fn fetch_connect_info(
+    client: &ApiClient,
+    channel: &str,
+) -> Result<ConnectInfo, ApiError> {
+    let response = client.execute_planned(plan_conv_info(channel)).await?;
+    Ok(response
+        .data
+        .get("channel")
+        .map(ConnectInfo::from_channel)
+        .unwrap_or_default())
+}
+
+/// Access cut of `conv members --only-guests` / `--only-external`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberAccessFilter {
+    /// Multi- and single-channel guests
+    Guests,
+    /// People whose home workspace is another organization
+    External,
+}
+
+/// Mark each member record with `is_external`
+///
+/// A member is external when the users cache records a home team other
+/// than `team_id`. The cache is built from `users.list`, which covers every
+/// member of the workspace including guests, so a member missing from it in
+/// an externally shared channel is also counted as external.
+pub fn annotate_external_members(response: &mut ApiResponse, team_id: &str, connect: &ConnectInfo) {
+    let Some(Value::Array(members)) = response.data.get_mut("members") else {
+        return;
+    };
+    for member in members.iter_mut() {
+        let Some(record) = member.as_object_mut() else {
+            continue;
+        };
+        let is_external = match record.get("team_id").and_then(|v| v.as_str()) {
+            Some(home) => home != team_id,
+            None => !record.contains_key("name") && connect.is_ext_shared,
+        };
+        record.insert("is_external".to_string(), json!(is_external));
+    }
+}
+
+/// Keep only the member records matching `filter` and update `count`
+pub fn retain_members(response: &mut ApiResponse, filter: MemberAccessFilter) {
+    let flag = |member: &Value, key: &str| member.get(key).and_then(|v| v.as_bool()) == Some(true);
+    let count = match response.data.get_mut("members") {
+        Some(Value::Array(members)) => {
+            members.retain(|member| match filter {
+                MemberAccessFilter::Guests => {
+                    flag(member, "is_restricted") || flag(member, "is_ultra_restricted")
+                }
+                MemberAccessFilter::External => flag(member, "is_external"),
+            });
+            members.len()
+        }
+        _ => return,
+    };
+    response.data.insert("count".to_string(), json!(count));
+}
+
+/// Turn the member IDs of a `conv members` response into records
+///
+/// With a users cache each record carries `name`, `display_name`,
+/// `real_name`, `is_bot`, `deleted`, the guest flags `is_restricted` and
+/// `is_ultra_restricted`, and the home `team_id` when known; members missing
+/// from the cache (or without a cache) only have `id`.
+///
+/// # Returns
+/// Number of members not found in the cache
+pub fn resolve_member_records(response: &mut ApiResponse, cache: Option<&WorkspaceCache>) -> usize {
+    let Some(Value::Array(members)) = response.data.get_mut("members") else {
+        return 0;
+    };
+    let mut unresolved = 0;
+    for member in members.iter_mut() {
+        let Some(id) = member.as_str().map(str::to_string) else {
+            continue;
+        };
+        *member = match cache.and_then(|c| c.users.get(&id)) {
+            Some(user) => {
+                let mut record = json!({
+                    "id": id,
+                    "name": user.name,
+                    "display_name": user.display_name,
+                    "real_name": user.real_name,
+                    "is_bot": user.is_bot,
+                    "deleted": user.deleted,
+                    "is_restricted": user.is_restricted,
+                    "is_ultra_restricted": user.is_ultra_restricted,
+                });
+                if let Some(team_id) = &user.team_id {
+                    record["team_id"] = json!(team_id);
+                }
+                record
+            }
+            None => {
+                unresolved += 1;
+                json!({ "id": id })
+            }
+        };
+    }
+    unresolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::users_cache::CachedUser;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_resolve_member_records() {
+        let mut response = ApiResponse {
+            ok: true,
+            data: HashMap::from([("members".to_string(), json!(["U1", "U2"]))]),
+            error: None,
+        };
+        let cache = WorkspaceCache {
+            team_id: "T1".to_string(),
+            updated_at: 0,
+            users: HashMap::from([(
+                "U1".to_string(),
+                CachedUser {
+                    id: "U1".to_string(),
+                    name: "alice".to_string(),
+                    real_name: Some("Alice Liddell".to_string()),
+                    display_name: Some("alice.l".to_string()),
+                    deleted: false,
+                    is_bot: false,
+                    is_restricted: false,
+                    is_ultra_restricted: false,
+                    team_id: None,
+                    tz: None,
+                    tz_offset: None,
+                },
+            )]),
+        };
+
+        assert_eq!(resolve_member_records(&mut response, Some(&cache)), 1);
+        let members = &response.data["members"];
+        assert_eq!(members[0]["display_name"], "alice.l");
+        assert_eq!(members[0]["real_name"], "Alice Liddell");
+        assert_eq!(members[1], json!({"id": "U2"}));
+    }
+
+    #[test]
+    fn test_external_and_guest_members() {
+        let members = || ApiResponse {
+            ok: true,
+            data: HashMap::from([
+                (
+                    "members".to_string(),
+                    json!([
+                        {"id": "U1", "name": "alice", "team_id": "T1", "is_restricted": false, "is_ultra_restricted": false},
+                        {"id": "U2", "name": "guest", "team_id": "T1", "is_restricted": true, "is_ultra_restricted": false},
+                        {"id": "U3", "name": "partner", "team_id": "T9", "is_restricted": false, "is_ultra_restricted": false},
+                        {"id": "U4"}
+                    ]),
+                ),
+                ("count".to_string(), json!(4)),
+            ]),
+            error: None,
+        };
+        let connect = ConnectInfo::from_channel(&json!({
+            "id": "C1",
+            "is_ext_shared": true,
+            "connected_team_ids": ["T1", "T9"]
+        }));
+        assert!(connect.is_ext_shared);
+        assert_eq!(connect.connected_team_ids, vec!["T1", "T9"]);
+
+        let mut response = members();
+        annotate_external_members(&mut response, "T1", &connect);
+        let external: Vec<bool> = response.data["members"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["is_external"].as_bool().unwrap())
+            .collect();
+        assert_eq!(external, vec![false, false, true, true]);
+
+        let mut guests = members();
+        retain_members(&mut guests, MemberAccessFilter::Guests);
+        assert_eq!(guests.data["members"][0]["id"], "U2");
+        assert_eq!(guests.data["count"], 1);
+
+        retain_members(&mut response, MemberAccessFilter::External);
+        assert_eq!(response.data["count"], 2);
+
+        // Unknown members of an internal channel are not assumed external
+        let mut internal = ApiResponse {
+            ok: true,
+            data: HashMap::from([("members".to_string(), json!([{"id": "U4"}]))]),
+            error: None,
+        };
+        annotate_external_members(&mut internal, "T1", &ConnectInfo::default());
+        assert_eq!(internal.data["members"][0]["is_external"], false);
+    }
+
+    #[test]
+    fn test_plan_conv_members() {
+        let plan = plan_conv_members("C1", 200, Some("abc"));
+        assert_eq!(plan.method, ApiMethod::ConversationsMembers);
+        assert_eq!(plan.params["limit"], 200);
+        assert_eq!(plan.params["cursor"], "abc");
+    }
+}
@@ -1,18 +1,55 @@
 //! Conversation command implementations
 
 // Module declarations
+pub mod activity;
 pub mod api;
+pub mod delta;
+pub mod edits;
+pub mod export;
 pub mod filter;
 pub mod format;
+pub mod html;
+pub mod manage;
+pub mod members;
+pub mod redact;
 pub mod select;
+pub mod snapshot;
 pub mod sort;
+pub mod sync;
+pub mod tail;
+pub mod watch;
 
 // Re-export public API to maintain backward compatibility
-pub use api::{conv_history, conv_list};
+pub use activity::{enrich_last_activity, ActivityCache, EnrichReport};
+pub use api::{
+    conv_history, conv_history_all, conv_list, conv_replies, conv_replies_all, plan_conv_history,
+    plan_conv_list, plan_conv_replies,
+};
+pub use delta::{delta_response, diff_snapshots, ChannelSnapshot, SnapshotChannel};
+pub use edits::{apply_dedupe_edits, dedupe_edits};
+pub use export::{conv_export, ConversationExport, ExportFormat};
 pub use filter::{apply_filters, ConversationFilter, FilterError};
-pub use format::{format_response, OutputFormat};
+pub use format::{format_messages, format_response, OutputFormat};
+pub use html::{render_html, HtmlOptions};
+pub use manage::{
+    conv_archive, conv_create, conv_invite, conv_join, conv_kick, conv_leave, conv_rename,
+    conv_unarchive, plan_conv_archive, plan_conv_create, plan_conv_invite, plan_conv_join,
+    plan_conv_kick, plan_conv_leave, plan_conv_rename, plan_conv_unarchive,
+};
+pub use members::{
+    annotate_external_members, conv_members, fetch_connect_info, plan_conv_info, plan_conv_members,
+    resolve_member_records, retain_members, ConnectInfo, MemberAccessFilter,
+};
+pub use redact::{RedactionReport, Redactor};
 pub use select::{extract_conversations, ConversationItem, ConversationSelector, StdinSelector};
+pub use snapshot::{conv_history_stable, detect_changes, ConsistencyReport};
 pub use sort::{sort_conversations, SortDirection, SortKey};
+pub use sync::{newest_message_ts, HistorySyncState, SyncEntry};
+pub use tail::{
+    advance_cursor, apply_message_filters, fetch_new_messages, message_matches, now_ts, run_exec,
+    thread_shape_filter, MessageFilter,
+};
+pub use watch::{default_watch_state_path, socket_message, UserNames};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,246 @@
+//! PII redaction for `conv export --redact`
+//!
+//! Rules are given as a comma-separated list:
+//! - `emails`: email addresses, replaced with `[REDACTED:email]`
+//! - `phones`: phone numbers with separators (`090-1234-5678`, `+1 415 555 0100`),
+//!   replaced with `[REDACTED:phone]`
+//! - `custom:<regex>`: any match of the pattern, replaced with `[REDACTED]`;
+//!   the pattern extends to the end of the flag value, so it may contain commas
+//!
+//! Only human-readable fields (`text`, `fallback`, `title`, ...) are scanned,
+//! recursively through blocks, attachments and thread replies; IDs and
+//! timestamps are left untouched. Match counts per rule are recorded in the
+//! export under `redaction`.
+
+use super::export::ConversationExport;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}";
+const PHONE_PATTERN: &str =
+    r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{2,4}\)\s?|\b\d{2,4}[\s.-])\d{2,4}[\s.-]?\d{3,4}\b";
+
+/// Message fields whose string values are redacted
+const REDACTED_FIELDS: &[&str] = &["text", "fallback", "pretext", "title", "value", "url"];
+
+/// A single redaction rule
+#[derive(Debug, Clone)]
+struct RedactionRule {
+    name: String,
+    pattern: Regex,
+    replacement: &'static str,
+}
+
+/// Number of matches replaced by one rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleCount {
+    pub rule: String,
+    pub count: usize,
+}
+
+/// Per-rule redaction counts recorded in the export
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RedactionReport {
+    pub rules: Vec<RuleCount>,
+}
+
+impl RedactionReport {
+    /// Total number of replaced matches
+    pub fn total(&self) -> usize {
+        self.rules.iter().map(|r| r.count).sum()
+    }
+}
+
+/// Configured set of redaction rules
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    rules: Vec<RedactionRule>,
+}
+
+impl Redactor {
+    /// Parse one or more `--redact` values
+    ///
+    /// # Errors
+    /// Unknown rule names and invalid custom patterns
+    pub fn parse(specs: &[String]) -> Result<Self, String> {
+        let mut rules = Vec::new();
+        for spec in specs {
+            let mut rest = spec.as_str();
+            while !rest.is_empty() {
+                if let Some(pattern) = rest.strip_prefix("custom:") {
+                    let regex = Regex::new(pattern)
+                        .map_err(|e| format!("Invalid custom redaction pattern: {}", e))?;
+                    rules.push(RedactionRule {
+                        name: format!("custom:{}", pattern),
+                        pattern: regex,
+                        replacement: "[REDACTED]",
+                    });
+                    break;
+                }
+                let (name, tail) = rest.split_once(',').unwrap_or((rest, ""));
+                rest = tail;
+                let (pattern, replacement) = match name.trim() {
+                    "" => continue,
+                    "emails" => (EMAIL_PATTERN, "[REDACTED:email]"),
+                    "phones" => (PHONE_PATTERN, "[REDACTED:phone]"),
+                    other => {
+                        return Err(format!(
+                        "Invalid redaction rule '{}'. Valid values: emails, phones, custom:<regex>",
+                        other
+                    ))
+                    }
+                };
+                rules.push(RedactionRule {
+                    name: name.trim().to_string(),
+                    pattern: Regex::new(pattern).expect("built-in pattern is valid"),
+                    replacement,
+                });
+            }
+        }
+        Ok(Self { rules })
+    }
+
+    /// Whether no rule is configured
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Redact all messages (and replies) of an export in place
+    pub fn redact_export(&self, export: &mut ConversationExport) -> RedactionReport {
+        let mut counts = vec![0; self.rules.len()];
+        for message in export.messages.iter_mut() {
+            self.redact_value(message, false, &mut counts);
+        }
+        RedactionReport {
+            rules: self
+                .rules
+                .iter()
+                .zip(counts)
+                .map(|(rule, count)| RuleCount {
+                    rule: rule.name.clone(),
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    fn redact_value(&self, value: &mut Value, redact_strings: bool, counts: &mut [usize]) {
+        match value {
+            Value::String(s) if redact_strings => {
+                for (rule, count) in self.rules.iter().zip(counts.iter_mut()) {
+                    let matches = rule.pattern.find_iter(s).count();
+                    if matches > 0 {
+                        *count += matches;
+                        *s = rule.pattern.replace_all(s, rule.replacement).into_owned();
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.redact_value(item, redact_strings, counts);
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    self.redact_value(item, REDACTED_FIELDS.contains(&key.as_str()), counts);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn export(messages: Vec<Value>) -> ConversationExport {
+        ConversationExport {
+            channel: "C123".to_string(),
+            exported_at: 0,
+            messages,
+            redaction: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let redactor = Redactor::parse(&["emails,phones".to_string()]).unwrap();
+        assert_eq!(redactor.rules.len(), 2);
+
+        let redactor = Redactor::parse(&["emails,custom:ID-\\d{3,}".to_string()]).unwrap();
+        assert_eq!(redactor.rules[1].name, "custom:ID-\\d{3,}");
+
+        // custom patterns keep their commas
+        let redactor = Redactor::parse(&["custom:a{1,2}".to_string()]).unwrap();
+        assert_eq!(redactor.rules.len(), 1);
+
+        assert!(Redactor::parse(&["ssn".to_string()]).is_err());
+        assert!(Redactor::parse(&["custom:(".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_redact_export_counts_per_rule() {
+        let mut export = export(vec![json!({
+            "ts": "1700000000.000100",
+            "user": "U123",
+            "text": "mail alice@example.com or bob@example.org, call 090-1234-5678",
+            "replies": [{
+                "ts": "1700000001.000100",
+                "text": "+1 415-555-0100 ticket ID-12345",
+                "attachments": [{"fallback": "carol@example.net"}]
+            }]
+        })]);
+
+        let redactor =
+            Redactor::parse(&["emails,phones".to_string(), "custom:ID-\\d+".to_string()]).unwrap();
+        let report = redactor.redact_export(&mut export);
+
+        assert_eq!(
+            report.rules,
+            vec![
+                RuleCount {
+                    rule: "emails".to_string(),
+                    count: 3
+                },
+                RuleCount {
+                    rule: "phones".to_string(),
+                    count: 2
+                },
+                RuleCount {
+                    rule: "custom:ID-\\d+".to_string(),
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(report.total(), 6);
+
+        let message = &export.messages[0];
+        assert_eq!(
+            message["text"],
+            "mail [REDACTED:email] or [REDACTED:email], call [REDACTED:phone]"
+        );
+        assert_eq!(message["ts"], "1700000000.000100");
+        assert_eq!(
+            message["replies"][0]["text"],
+            "[REDACTED:phone] ticket [REDACTED]"
+        );
+        assert_eq!(
+            message["replies"][0]["attachments"][0]["fallback"],
+            "[REDACTED:email]"
+        );
+    }
+
+    #[test]
+    fn test_phone_pattern_ignores_dates_and_versions() {
+        let mut export = export(vec![json!({
+            "text": "released 2024-07-01 as v1.2.3 on 192.168.1.100"
+        })]);
+        let report = Redactor::parse(&["phones".to_string()])
+            .unwrap()
+            .redact_export(&mut export);
+        assert_eq!(report.total(), 0);
+    }
+}
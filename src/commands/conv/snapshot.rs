@@ -0,0 +1,242 @@
+//! Snapshot-consistent history reads (`conv history --cursor-stability`)
+//!
+//! Long paginated reads can observe writes made while they run. With cursor
+//! stability the `latest` boundary is recorded once before the first page and
+//! every page is pinned to it, so new messages never shift the pages. When
+//! the read finishes the same window is fetched again and compared with what
+//! was returned; messages edited or deleted in the meantime are reported so
+//! audit exports can flag them instead of silently going stale.
+
+use super::api::{conv_history, conv_history_all};
+use super::tail::now_ts;
+use crate::api::{ApiClient, ApiError, ApiResponse};
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, HashMap};
+
+/// Messages that changed while a pinned read was in progress
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsistencyReport {
+    /// `latest` boundary every page was pinned to
+    pub snapshot_latest: String,
+    /// Timestamps of messages edited during the read
+    pub edited: Vec<String>,
+    /// Timestamps of messages deleted during the read
+    pub deleted: Vec<String>,
+}
+
+impl ConsistencyReport {
+    /// Whether the returned messages still match the workspace
+    pub fn is_consistent(&self) -> bool {
+        self.edited.is_empty() && self.deleted.is_empty()
+    }
+
+    /// JSON form for the envelope `meta.consistency`
+    pub fn to_json(&self) -> Value {
+        json!({
+            "snapshot_latest": self.snapshot_latest,
+            "consistent": self.is_consistent(),
+            "edited": self.edited,
+            "deleted": self.deleted,
+        })
+    }
+}
+
+/// Boundary to pin pages to: the requested `latest`, or now
+pub fn snapshot_boundary(latest: Option<String>) -> String {
+    latest.unwrap_or_else(now_ts)
+}
+
+fn str_field<'a>(message: &'a Value, path: &[&str]) -> Option<&'a str> {
+    path.iter()
+        .try_fold(message, |v, key| v.get(*key))
+        .and_then(|v| v.as_str())
+}
+
+fn ts_later(ts: &str, boundary: &str) -> bool {
+    match (ts.parse::<f64>(), boundary.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a > b,
+        _ => false,
+    }
+}
+
+/// Compare the messages returned by a read with a verification re-read
+///
+/// A message is edited when its `edited.ts` is after the snapshot boundary,
+/// or its text / edit marker differs between the two reads. It is deleted
+/// when it is missing from the re-read or has become a tombstone.
+///
+/// # Returns
+/// `(edited, deleted)` message timestamps, sorted and de-duplicated
+pub fn detect_changes(
+    snapshot_latest: &str,
+    read: &[Value],
+    verify: &[Value],
+) -> (Vec<String>, Vec<String>) {
+    let current: HashMap<&str, &Value> = verify
+        .iter()
+        .filter_map(|m| str_field(m, &["ts"]).map(|ts| (ts, m)))
+        .collect();
+
+    let mut edited = BTreeSet::new();
+    let mut deleted = BTreeSet::new();
+    for message in read {
+        let Some(ts) = str_field(message, &["ts"]) else {
+            continue;
+        };
+        if str_field(message, &["edited", "ts"]).is_some_and(|e| ts_later(e, snapshot_latest)) {
+            edited.insert(ts.to_string());
+        }
+        match current.get(ts) {
+            None => {
+                deleted.insert(ts.to_string());
+            }
+            Some(now) if str_field(now, &["subtype"]) == Some("tombstone") => {
+                deleted.insert(ts.to_string());
+            }
+            Some(now) => {
+                if str_field(now, &["text"]) != str_field(message, &["text"])
+                    || str_field(now, &["edited", "ts"]) != str_field(message, &["edited", "ts"])
+                {
+                    edited.insert(ts.to_string());
+                }
+            }
+        }
+    }
+
+    (edited.into_iter().collect(), deleted.into_iter().collect())
+}
+
+fn messages_of(response: &ApiResponse) -> &[Value] {
+    response
+        .data
+        .get("messages")
+        .and_then(|v| v.as_array())
+        .map(|v| v.as_slice())
+        .unwrap_or_default()
+}
+
+/// Read history pinned to a snapshot boundary and verify it afterwards
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `limit` - Optional page size
+/// * `oldest` - Optional oldest timestamp to include
+/// * `latest` - Optional boundary; defaults to the time the read starts
+/// * `cursor` - Cursor of a single-page read (ignored with `all`)
+/// * `all` - Follow cursors until the window is exhausted
+///
+/// # Returns
+/// The history response and a report of messages that changed during the read
+pub async fn conv_history_stable(
+    client: &ApiClient,
+    channel: String,
+    limit: Option<u32>,
+    oldest: Option<String>,
+    latest: Option<String>,
+    cursor: Option<String>,
+    all: bool,
+) -> Result<(ApiResponse, ConsistencyReport), ApiError> {
+    let boundary = snapshot_boundary(latest);
+    let read = |cursor: Option<String>| {
+        let (channel, oldest, latest) = (channel.clone(), oldest.clone(), Some(boundary.clone()));
+        async move {
+            if all {
+                conv_history_all(client, channel, limit, oldest, latest).await
+            } else {
+                conv_history(client, channel, limit, oldest, latest, cursor).await
+            }
+        }
+    };
+
+    let response = read(cursor.clone()).await?;
+    let verify = read(cursor).await?;
+    let (edited, deleted) = detect_changes(&boundary, messages_of(&response), messages_of(&verify));
+
+    Ok((
+        response,
+        ConsistencyReport {
+            snapshot_latest: boundary,
+            edited,
+            deleted,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_changes() {
+        let read = vec![
+            json!({"ts": "5.0", "text": "kept"}),
+            json!({"ts": "4.0", "text": "before"}),
+            json!({"ts": "3.0", "text": "gone"}),
+            json!({"ts": "2.0", "text": "thread root", "reply_count": 2}),
+            json!({"ts": "1.0", "text": "late edit", "edited": {"ts": "11.0"}}),
+        ];
+        let verify = vec![
+            json!({"ts": "5.0", "text": "kept"}),
+            json!({"ts": "4.0", "text": "after", "edited": {"ts": "12.0"}}),
+            json!({"ts": "2.0", "subtype": "tombstone", "text": "This message was deleted."}),
+            json!({"ts": "1.0", "text": "late edit", "edited": {"ts": "11.0"}}),
+        ];
+
+        let (edited, deleted) = detect_changes("10.0", &read, &verify);
+        assert_eq!(edited, vec!["1.0", "4.0"]);
+        assert_eq!(deleted, vec!["2.0", "3.0"]);
+    }
+
+    #[test]
+    fn test_snapshot_boundary() {
+        assert_eq!(snapshot_boundary(Some("9.0".to_string())), "9.0");
+        assert!(ts_later(&snapshot_boundary(None), "1700000000.000000"));
+    }
+
+    #[tokio::test]
+    async fn test_conv_history_stable_pins_latest() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("latest", "9.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "2.0", "text": "b"}, {"ts": "1.0", "text": "a"}]
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("latest", "9.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [{"ts": "2.0", "text": "b"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let (response, report) = conv_history_stable(
+            &client,
+            "C1".to_string(),
+            None,
+            None,
+            Some("9.0".to_string()),
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(messages_of(&response).len(), 2);
+        assert_eq!(report.snapshot_latest, "9.0");
+        assert_eq!(report.deleted, vec!["1.0"]);
+        assert!(!report.is_consistent());
+        assert_eq!(report.to_json()["consistent"], false);
+    }
+}
@@ -8,6 +8,8 @@ pub enum SortKey {
     Name,
     Created,
     NumMembers,
+    /// Newest message ts (`last_activity_ts`, see `--with-last-activity`)
+    LastActivity,
 }
 
 impl SortKey {
@@ -16,8 +18,9 @@ impl SortKey {
             "name" => Ok(SortKey::Name),
             "created" => Ok(SortKey::Created),
             "num_members" => Ok(SortKey::NumMembers),
+            "last_activity" => Ok(SortKey::LastActivity),
             _ => Err(format!(
-                "Invalid sort key '{}'. Valid values: name, created, num_members",
+                "Invalid sort key '{}'. Valid values: name, created, num_members, last_activity",
                 s
             )),
         }
@@ -66,6 +69,15 @@ pub fn sort_conversations(response: &mut ApiResponse, key: SortKey, direction: S
                         let b_members = b.get("num_members").and_then(|v| v.as_i64()).unwrap_or(0);
                         a_members.cmp(&b_members)
                     }
+                    SortKey::LastActivity => {
+                        let ts = |c: &serde_json::Value| {
+                            c.get("last_activity_ts")
+                                .and_then(|v| v.as_str())
+                                .and_then(|v| v.parse::<f64>().ok())
+                                .unwrap_or(0.0)
+                        };
+                        ts(a).total_cmp(&ts(b))
+                    }
                 };
 
                 match direction {
@@ -88,6 +100,10 @@ mod tests {
         assert_eq!(SortKey::parse("name").unwrap(), SortKey::Name);
         assert_eq!(SortKey::parse("created").unwrap(), SortKey::Created);
         assert_eq!(SortKey::parse("num_members").unwrap(), SortKey::NumMembers);
+        assert_eq!(
+            SortKey::parse("last_activity").unwrap(),
+            SortKey::LastActivity
+        );
         assert!(SortKey::parse("invalid").is_err());
     }
 
@@ -231,4 +247,26 @@ mod tests {
             50
         );
     }
+
+    #[test]
+    fn test_sort_conversations_by_last_activity_desc() {
+        let mut response = ApiResponse {
+            ok: true,
+            data: HashMap::from([(
+                "channels".to_string(),
+                json!([
+                    {"id": "C1", "last_activity_ts": "1600000000.000100"},
+                    {"id": "C2"},
+                    {"id": "C3", "last_activity_ts": "1700000000.000100"},
+                ]),
+            )]),
+            error: None,
+        };
+
+        sort_conversations(&mut response, SortKey::LastActivity, SortDirection::Desc);
+
+        let channels = response.data.get("channels").unwrap().as_array().unwrap();
+        let ids: Vec<_> = channels.iter().map(|c| c["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["C3", "C1", "C2"]);
+    }
 }
@@ -0,0 +1,183 @@
+//! Incremental history sync for `conv history --sync`
+//!
+//! After a full read the newest message ts of the channel is recorded in a
+//! local state file. The next run passes it as `oldest` (exclusive in
+//! `conversations.history`), so only messages posted since the previous run
+//! are fetched. Running the command from cron gives a lightweight channel
+//! archive without re-downloading the whole history each time.
+
+use crate::api::ApiResponse;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Sync cursor of one channel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncEntry {
+    /// Newest message ts fetched so far
+    pub latest_ts: String,
+    /// UNIX time of the last successful sync
+    pub synced_at: u64,
+}
+
+/// On-disk sync state keyed by `team_id:channel_id`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HistorySyncState {
+    #[serde(default)]
+    pub channels: HashMap<String, SyncEntry>,
+}
+
+impl HistorySyncState {
+    /// Default state location next to the other caches
+    pub fn default_path() -> Result<PathBuf, String> {
+        crate::profile::config_dir()
+            .map(|dir| dir.join("history_sync.json"))
+            .ok_or_else(|| "Could not determine config directory".to_string())
+    }
+
+    /// Load the state; a missing file yields an empty state
+    ///
+    /// Unlike the caches, a corrupted file is an error: silently starting
+    /// over would re-export the whole channel.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read sync state {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse sync state {}: {}", path.display(), e))
+    }
+
+    /// Save the state, creating the parent directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create state directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize sync state: {}", e))?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write sync state: {}", e))
+    }
+
+    /// Sync cursor of a channel, if it was synced before
+    pub fn get(&self, team_id: &str, channel: &str) -> Option<&SyncEntry> {
+        self.channels.get(&format!("{}:{}", team_id, channel))
+    }
+
+    /// Record a finished sync
+    ///
+    /// The cursor only moves forward; a run without new messages keeps the
+    /// previous `latest_ts` and just refreshes `synced_at`. Returns the
+    /// recorded cursor, `None` when the channel has never had a message.
+    pub fn record(
+        &mut self,
+        team_id: &str,
+        channel: &str,
+        newest: Option<String>,
+        now: u64,
+    ) -> Option<&SyncEntry> {
+        let key = format!("{}:{}", team_id, channel);
+        let previous = self.channels.get(&key).map(|e| e.latest_ts.clone());
+        let latest_ts = match (previous, newest) {
+            (Some(prev), Some(new)) => Some(max_ts(prev, new)),
+            (prev, new) => prev.or(new),
+        }?;
+        self.channels.insert(
+            key.clone(),
+            SyncEntry {
+                latest_ts,
+                synced_at: now,
+            },
+        );
+        self.channels.get(&key)
+    }
+}
+
+/// Newest message ts in a `conversations.history` response
+pub fn newest_message_ts(response: &ApiResponse) -> Option<String> {
+    response
+        .data
+        .get("messages")
+        .and_then(|v| v.as_array())?
+        .iter()
+        .filter_map(|m| m.get("ts").and_then(|v| v.as_str()))
+        .fold(None, |acc: Option<String>, ts| match acc {
+            Some(best) => Some(max_ts(best, ts.to_string())),
+            None => Some(ts.to_string()),
+        })
+}
+
+/// Later of two Slack timestamps (`seconds.micros`)
+fn max_ts(a: String, b: String) -> String {
+    let parse = |ts: &str| ts.parse::<f64>().unwrap_or(0.0);
+    if parse(&b) > parse(&a) {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn history(ts: &[&str]) -> ApiResponse {
+        ApiResponse {
+            ok: true,
+            data: HashMap::from([(
+                "messages".to_string(),
+                json!(ts.iter().map(|t| json!({"ts": t})).collect::<Vec<_>>()),
+            )]),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_newest_message_ts() {
+        let response = history(&["1700000002.000100", "1700000010.000000", "1700000001.5"]);
+        assert_eq!(
+            newest_message_ts(&response).as_deref(),
+            Some("1700000010.000000")
+        );
+        assert_eq!(newest_message_ts(&history(&[])), None);
+    }
+
+    #[test]
+    fn test_record_only_moves_forward() {
+        let mut state = HistorySyncState::default();
+        assert!(state.record("T1", "C1", None, 100).is_none());
+        assert!(state.get("T1", "C1").is_none());
+
+        state.record("T1", "C1", Some("1700000010.000000".to_string()), 200);
+        let entry = state.record("T1", "C1", None, 300).unwrap();
+        assert_eq!(entry.latest_ts, "1700000010.000000");
+        assert_eq!(entry.synced_at, 300);
+
+        state.record("T1", "C1", Some("1700000005.000000".to_string()), 400);
+        assert_eq!(
+            state.get("T1", "C1").unwrap().latest_ts,
+            "1700000010.000000"
+        );
+        assert!(state.get("T2", "C1").is_none());
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("history_sync.json");
+        assert!(HistorySyncState::load(&path).unwrap().channels.is_empty());
+
+        let mut state = HistorySyncState::default();
+        state.record("T1", "C1", Some("1700000010.000000".to_string()), 200);
+        state.save(&path).unwrap();
+
+        let loaded = HistorySyncState::load(&path).unwrap();
+        assert_eq!(loaded.get("T1", "C1"), state.get("T1", "C1"));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(HistorySyncState::load(&path).is_err());
+    }
+}
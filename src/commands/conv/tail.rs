@@ -0,0 +1,447 @@
+//! Conversation tail: poll a channel for new messages and dispatch them
+//!
+//! Each new message can be printed as a JSON line or piped to a handler
+//! command (`--exec`), which receives the message JSON on stdin. Combined with
+//! `--filter` conditions this acts as a minimal bot runtime.
+
+use super::filter::{glob_match, FilterError};
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Maximum history pages to fetch per poll to prevent infinite loops
+const MAX_PAGES: usize = 1000;
+
+/// Filter condition for tailed messages
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageFilter {
+    /// Message author user ID
+    User(String),
+    /// Message text pattern (glob, or case-insensitive substring without `*`)
+    Text(String),
+    /// Whether the message was posted by a bot
+    Bot(bool),
+    /// Whether the message is a thread reply
+    Thread(bool),
+    /// Whether the message is a thread parent with at least one reply
+    Parent(bool),
+    /// Whether the message is a reply also sent to the channel
+    Broadcast(bool),
+    /// Message subtype (`none` matches regular messages)
+    Subtype(String),
+}
+
+impl MessageFilter {
+    /// Parse filter from string format "key:value"
+    pub fn parse(s: &str) -> Result<Self, FilterError> {
+        let (key, value) = s.split_once(':').ok_or_else(|| {
+            FilterError::InvalidFormat(format!("Expected format 'key:value', got '{}'", s))
+        })?;
+
+        let parse_bool = |v: &str| {
+            v.parse::<bool>().map_err(|_| {
+                FilterError::InvalidBoolean(format!("Expected 'true' or 'false', got '{}'", v))
+            })
+        };
+
+        match key {
+            "user" => Ok(MessageFilter::User(value.to_string())),
+            "text" => Ok(MessageFilter::Text(value.to_string())),
+            "bot" => Ok(MessageFilter::Bot(parse_bool(value)?)),
+            "thread" => Ok(MessageFilter::Thread(parse_bool(value)?)),
+            "parent" => Ok(MessageFilter::Parent(parse_bool(value)?)),
+            "broadcast" => Ok(MessageFilter::Broadcast(parse_bool(value)?)),
+            "subtype" => Ok(MessageFilter::Subtype(value.to_string())),
+            _ => Err(FilterError::InvalidFormat(format!(
+                "Unknown filter key: {} (valid: user, text, bot, thread, parent, broadcast, subtype)",
+                key
+            ))),
+        }
+    }
+
+    /// Apply filter to a message JSON value
+    pub fn matches(&self, message: &Value) -> bool {
+        let str_field = |key: &str| message.get(key).and_then(|v| v.as_str());
+
+        match self {
+            MessageFilter::User(user) => str_field("user") == Some(user.as_str()),
+            MessageFilter::Text(pattern) => {
+                str_field("text").is_some_and(|text| glob_match(pattern, text))
+            }
+            MessageFilter::Bot(expected) => {
+                let is_bot =
+                    message.get("bot_id").is_some() || str_field("subtype") == Some("bot_message");
+                is_bot == *expected
+            }
+            MessageFilter::Thread(expected) => {
+                let is_reply = matches!(
+                    (str_field("thread_ts"), str_field("ts")),
+                    (Some(thread_ts), Some(ts)) if thread_ts != ts
+                );
+                is_reply == *expected
+            }
+            MessageFilter::Parent(expected) => {
+                let is_root = str_field("thread_ts").is_none_or(|t| Some(t) == str_field("ts"));
+                let has_replies = message
+                    .get("reply_count")
+                    .and_then(|v| v.as_u64())
+                    .is_some_and(|n| n > 0);
+                (is_root && has_replies) == *expected
+            }
+            MessageFilter::Broadcast(expected) => {
+                (str_field("subtype") == Some("thread_broadcast")) == *expected
+            }
+            MessageFilter::Subtype(expected) => match str_field("subtype") {
+                Some(subtype) => subtype == expected,
+                None => expected == "none",
+            },
+        }
+    }
+}
+
+/// Thread-shape filter selected by `conv history --only-*` flags
+///
+/// `threads` keeps thread parents with replies, `top_level` keeps messages
+/// posted to the channel itself (broadcast replies excluded), `broadcasts`
+/// keeps replies also sent to the channel. At most one may be set.
+pub fn thread_shape_filter(
+    threads: bool,
+    top_level: bool,
+    broadcasts: bool,
+) -> Result<Option<MessageFilter>, String> {
+    match (threads, top_level, broadcasts) {
+        (false, false, false) => Ok(None),
+        (true, false, false) => Ok(Some(MessageFilter::Parent(true))),
+        (false, true, false) => Ok(Some(MessageFilter::Thread(false))),
+        (false, false, true) => Ok(Some(MessageFilter::Broadcast(true))),
+        _ => Err(
+            "Error: --only-threads, --only-top-level and --only-broadcasts are mutually exclusive"
+                .to_string(),
+        ),
+    }
+}
+
+/// Check whether a message passes all filters (AND logic)
+pub fn message_matches(message: &Value, filters: &[MessageFilter]) -> bool {
+    filters.iter().all(|filter| filter.matches(message))
+}
+
+/// Keep only the `messages` of a response that pass all filters
+pub fn apply_message_filters(response: &mut ApiResponse, filters: &[MessageFilter]) {
+    if filters.is_empty() {
+        return;
+    }
+    if let Some(Value::Array(messages)) = response.data.get_mut("messages") {
+        messages.retain(|message| message_matches(message, filters));
+    }
+}
+
+/// Fetch messages newer than `oldest` in chronological order
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `oldest` - Only messages strictly after this timestamp are returned
+///
+/// # Returns
+/// * `Ok(Vec<Value>)` with new messages, oldest first
+/// * `Err(ApiError)` if the operation fails
+pub async fn fetch_new_messages(
+    client: &ApiClient,
+    channel: &str,
+    oldest: &str,
+) -> Result<Vec<Value>, ApiError> {
+    let mut messages = Vec::new();
+    let mut cursor: Option<String> = None;
+    let mut page_count = 0;
+
+    loop {
+        page_count += 1;
+        if page_count > MAX_PAGES {
+            return Err(ApiError::SlackError(format!(
+                "Pagination exceeded max pages ({}), possible infinite loop",
+                MAX_PAGES
+            )));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("channel".to_string(), json!(channel));
+        params.insert("oldest".to_string(), json!(oldest));
+        params.insert("limit".to_string(), json!(200));
+        if let Some(ref c) = cursor {
+            params.insert("cursor".to_string(), json!(c));
+        }
+
+        let response = client
+            .call_method(ApiMethod::ConversationsHistory, params)
+            .await?;
+
+        if !response.ok {
+            return Err(ApiError::SlackError(
+                response
+                    .error
+                    .unwrap_or_else(|| "conversations.history failed".to_string()),
+            ));
+        }
+
+        if let Some(page) = response.data.get("messages").and_then(|m| m.as_array()) {
+            messages.extend(
+                page.iter()
+                    .filter(|m| ts_of(m).is_some_and(|ts| ts_after(ts, oldest)))
+                    .cloned(),
+            );
+        }
+
+        cursor = response
+            .data
+            .get("response_metadata")
+            .and_then(|meta| meta.get("next_cursor"))
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_string());
+
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    messages.sort_by(|a, b| ts_value(a).total_cmp(&ts_value(b)));
+    Ok(messages)
+}
+
+/// Get the `ts` of a message
+fn ts_of(message: &Value) -> Option<&str> {
+    message.get("ts").and_then(|t| t.as_str())
+}
+
+fn ts_value(message: &Value) -> f64 {
+    ts_of(message)
+        .and_then(|t| t.parse::<f64>().ok())
+        .unwrap_or(0.0)
+}
+
+/// Compare Slack timestamps (`seconds.micros`) numerically
+fn ts_after(ts: &str, oldest: &str) -> bool {
+    match (ts.parse::<f64>(), oldest.parse::<f64>()) {
+        (Ok(ts), Ok(oldest)) => ts > oldest,
+        _ => ts > oldest,
+    }
+}
+
+/// Latest `ts` among messages, or the current value if none are newer
+pub fn advance_cursor(current: &str, messages: &[Value]) -> String {
+    messages
+        .iter()
+        .filter_map(ts_of)
+        .filter(|ts| ts_after(ts, current))
+        .max_by(|a, b| {
+            a.parse::<f64>()
+                .unwrap_or(0.0)
+                .total_cmp(&b.parse::<f64>().unwrap_or(0.0))
+        })
+        .unwrap_or(current)
+        .to_string()
+}
+
+/// Current time as a Slack timestamp
+pub fn now_ts() -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    format!("{}.{:06}", now.as_secs(), now.subsec_micros())
+}
+
+/// Run a handler command for one message
+///
+/// The command runs through the platform shell with the message JSON on stdin.
+/// `SLACK_CHANNEL`, `SLACK_TS` and `SLACK_USER` are set in its environment.
+///
+/// # Arguments
+/// * `command` - Shell command line
+/// * `channel` - Channel ID
+/// * `message` - Message JSON
+///
+/// # Returns
+/// * `Ok(ExitStatus)` of the handler
+/// * `Err(String)` if the handler could not be started
+pub fn run_exec(command: &str, channel: &str, message: &Value) -> Result<ExitStatus, String> {
+    let mut cmd = if cfg!(windows) {
+        let mut c = Command::new("cmd");
+        c.arg("/C").arg(command);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.arg("-c").arg(command);
+        c
+    };
+
+    cmd.env("SLACK_CHANNEL", channel)
+        .env("SLACK_TS", ts_of(message).unwrap_or(""))
+        .env(
+            "SLACK_USER",
+            message.get("user").and_then(|u| u.as_str()).unwrap_or(""),
+        )
+        .stdin(Stdio::piped());
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to run '{}': {}", command, e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let payload = serde_json::to_string(message).map_err(|e| e.to_string())?;
+        // A handler that exits without reading stdin is not an error
+        let _ = stdin.write_all(payload.as_bytes());
+        let _ = stdin.write_all(b"\n");
+    }
+
+    child
+        .wait()
+        .map_err(|e| format!("Failed to wait for '{}': {}", command, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn test_message_filter_parse() {
+        assert_eq!(
+            MessageFilter::parse("user:U123").unwrap(),
+            MessageFilter::User("U123".to_string())
+        );
+        assert_eq!(
+            MessageFilter::parse("bot:false").unwrap(),
+            MessageFilter::Bot(false)
+        );
+        assert!(MessageFilter::parse("bot:maybe").is_err());
+        assert!(MessageFilter::parse("reaction:+1").is_err());
+        assert!(MessageFilter::parse("user").is_err());
+    }
+
+    #[test]
+    fn test_message_filter_matches() {
+        let human = json!({"ts": "2.0", "user": "U1", "text": "Deploy please"});
+        let bot = json!({"ts": "3.0", "bot_id": "B1", "subtype": "bot_message", "text": "done"});
+        let reply = json!({"ts": "4.0", "thread_ts": "2.0", "user": "U2", "text": "ok"});
+
+        assert!(MessageFilter::User("U1".to_string()).matches(&human));
+        assert!(!MessageFilter::User("U1".to_string()).matches(&bot));
+        assert!(MessageFilter::Text("deploy".to_string()).matches(&human));
+        assert!(MessageFilter::Text("Deploy*".to_string()).matches(&human));
+        assert!(MessageFilter::Bot(true).matches(&bot));
+        assert!(MessageFilter::Bot(false).matches(&human));
+        assert!(MessageFilter::Thread(true).matches(&reply));
+        assert!(MessageFilter::Thread(false).matches(&human));
+        assert!(MessageFilter::Subtype("none".to_string()).matches(&human));
+        assert!(MessageFilter::Subtype("bot_message".to_string()).matches(&bot));
+
+        let filters = vec![MessageFilter::Bot(false), MessageFilter::Thread(false)];
+        assert!(message_matches(&human, &filters));
+        assert!(!message_matches(&reply, &filters));
+    }
+
+    #[test]
+    fn test_thread_shape_filters() {
+        let plain = json!({"ts": "1.0", "text": "hi"});
+        let parent = json!({"ts": "2.0", "thread_ts": "2.0", "reply_count": 3});
+        let reply = json!({"ts": "2.1", "thread_ts": "2.0"});
+        let broadcast = json!({"ts": "2.2", "thread_ts": "2.0", "subtype": "thread_broadcast"});
+        let messages = [&plain, &parent, &reply, &broadcast];
+        let kept = |filter: Option<MessageFilter>| {
+            let filters: Vec<_> = filter.into_iter().collect();
+            messages
+                .iter()
+                .filter(|m| message_matches(m, &filters))
+                .map(|m| m["ts"].as_str().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kept(thread_shape_filter(true, false, false).unwrap()),
+            vec!["2.0"]
+        );
+        assert_eq!(
+            kept(thread_shape_filter(false, true, false).unwrap()),
+            vec!["1.0", "2.0"]
+        );
+        assert_eq!(
+            kept(thread_shape_filter(false, false, true).unwrap()),
+            vec!["2.2"]
+        );
+        assert_eq!(
+            kept(thread_shape_filter(false, false, false).unwrap()).len(),
+            4
+        );
+        assert!(thread_shape_filter(true, false, true).is_err());
+        assert_eq!(
+            MessageFilter::parse("parent:false").unwrap(),
+            MessageFilter::Parent(false)
+        );
+        assert!(MessageFilter::Broadcast(false).matches(&reply));
+    }
+
+    #[test]
+    fn test_advance_cursor() {
+        let messages = vec![json!({"ts": "10.000002"}), json!({"ts": "10.000010"})];
+        assert_eq!(advance_cursor("10.000001", &messages), "10.000010");
+        assert_eq!(advance_cursor("11.0", &messages), "11.0");
+        assert_eq!(advance_cursor("11.0", &[]), "11.0");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_exec_pipes_message_json() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let out = temp_dir.path().join("out.json");
+        let command = format!(
+            "cat > '{}'; echo \"$SLACK_TS\" >> '{}'",
+            out.display(),
+            out.display()
+        );
+
+        let status = run_exec(&command, "C1", &json!({"ts": "5.0", "text": "hi"})).unwrap();
+        assert!(status.success());
+
+        let written = std::fs::read_to_string(&out).unwrap();
+        let mut lines = written.lines();
+        let message: Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(message["text"], "hi");
+        assert_eq!(lines.next(), Some("5.0"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_new_messages_sorted_and_exclusive() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("oldest", "100.000001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "100.000003", "text": "third"},
+                    {"ts": "100.000002", "text": "second"},
+                    {"ts": "100.000001", "text": "already seen"}
+                ],
+                "response_metadata": {"next_cursor": ""}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let messages = fetch_new_messages(&client, "C1", "100.000001")
+            .await
+            .unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0]["text"], "second");
+        assert_eq!(messages[1]["text"], "third");
+        assert_eq!(advance_cursor("100.000001", &messages), "100.000003");
+    }
+}
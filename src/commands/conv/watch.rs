@@ -0,0 +1,216 @@
+//! Conversation watch: live tail of a channel with a persisted cursor
+//!
+//! `conv watch` prints every new message of a channel as a JSON line, with
+//! the channel ID and the author's display name attached. Messages arrive
+//! either by polling `conversations.history` or through Socket Mode. The
+//! newest ts seen is saved per channel, so a restarted watcher resumes where
+//! the previous one stopped instead of skipping or replaying messages.
+
+use crate::api::{ApiClient, ApiMethod};
+use crate::commands::users_cache::{parse_user_from_json, MentionFormat, WorkspaceCache};
+use crate::socket::ReceivedEvent;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Default cursor state location next to the other caches
+///
+/// The file uses the [`HistorySyncState`](super::HistorySyncState) format,
+/// keyed by `team_id:channel`.
+pub fn default_watch_state_path() -> Result<PathBuf, String> {
+    crate::profile::config_dir()
+        .map(|dir| dir.join("watch_state.json"))
+        .ok_or_else(|| "Could not determine config directory".to_string())
+}
+
+/// User ID to name map used to annotate watched messages
+#[derive(Debug, Clone, Default)]
+pub struct UserNames {
+    names: HashMap<String, String>,
+    style: MentionFormat,
+    /// IDs already looked up with `users.info` (found or not)
+    looked_up: HashSet<String>,
+}
+
+impl UserNames {
+    /// Seed names from a users cache workspace, rendered in `style`
+    pub fn from_cache(cache: Option<&WorkspaceCache>, style: MentionFormat) -> Self {
+        let names = cache
+            .map(|ws| {
+                ws.users
+                    .values()
+                    .map(|u| (u.id.clone(), u.display_as(style).to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            names,
+            style,
+            looked_up: Default::default(),
+        }
+    }
+
+    /// Name of a user, if known
+    pub fn get(&self, user_id: &str) -> Option<&str> {
+        self.names.get(user_id).map(|s| s.as_str())
+    }
+
+    /// Look up authors missing from the cache with `users.info`
+    ///
+    /// Each unknown ID is requested at most once per watcher; failures are
+    /// ignored and the message is printed without a name.
+    pub async fn resolve_missing(&mut self, client: &ApiClient, messages: &[Value]) {
+        for message in messages {
+            let Some(user) = message.get("user").and_then(|u| u.as_str()) else {
+                continue;
+            };
+            if self.names.contains_key(user) || !self.looked_up.insert(user.to_string()) {
+                continue;
+            }
+            let mut params = HashMap::new();
+            params.insert("user".to_string(), json!(user));
+            let Ok(response) = client.call_method(ApiMethod::UsersInfo, params).await else {
+                continue;
+            };
+            let Some(found) = response.data.get("user") else {
+                continue;
+            };
+            let name = match self.style {
+                MentionFormat::DisplayName => user_display_name(found),
+                style => parse_user_from_json(found).map(|u| u.display_as(style).to_string()),
+            };
+            if let Some(name) = name {
+                self.names.insert(user.to_string(), name);
+            }
+        }
+    }
+
+    /// Copy of a message with `channel` and `user_name` attached
+    pub fn annotate(&self, channel: &str, message: &Value) -> Value {
+        let mut annotated = message.clone();
+        if let Value::Object(ref mut map) = annotated {
+            map.entry("channel").or_insert_with(|| json!(channel));
+            let name = map
+                .get("user")
+                .and_then(|u| u.as_str())
+                .and_then(|u| self.get(u));
+            map.insert("user_name".to_string(), json!(name));
+        }
+        annotated
+    }
+}
+
+/// Display name of a `users.info` user object
+fn user_display_name(user: &Value) -> Option<String> {
+    let profile = user.get("profile");
+    let field = |v: Option<&Value>, key: &str| {
+        v.and_then(|v| v.get(key))
+            .and_then(|s| s.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    };
+    field(profile, "display_name")
+        .or_else(|| field(profile, "real_name"))
+        .or_else(|| field(Some(user), "name"))
+}
+
+/// Message carried by a Socket Mode event, if it belongs to `channel`
+///
+/// Only `message` events are considered. Edits and deletions
+/// (`message_changed`, `message_deleted`) are passed through as-is so the
+/// output mirrors what polling with `--dedupe-edits` off would show.
+pub fn socket_message(event: &ReceivedEvent, channel: &str) -> Option<Value> {
+    let inner = event.inner_event();
+    if inner.get("type").and_then(|t| t.as_str()) != Some("message") {
+        return None;
+    }
+    if inner.get("channel").and_then(|c| c.as_str()) != Some(channel) {
+        return None;
+    }
+    Some(inner.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::users_cache::CachedUser;
+
+    fn cache() -> WorkspaceCache {
+        let user = |id: &str, display: Option<&str>, real: Option<&str>| CachedUser {
+            id: id.to_string(),
+            name: format!("{}-handle", id),
+            real_name: real.map(|s| s.to_string()),
+            display_name: display.map(|s| s.to_string()),
+            deleted: false,
+            is_bot: false,
+            is_restricted: false,
+            is_ultra_restricted: false,
+            team_id: None,
+            tz: None,
+            tz_offset: None,
+        };
+        WorkspaceCache {
+            team_id: "T1".to_string(),
+            updated_at: 0,
+            users: HashMap::from([
+                ("U1".to_string(), user("U1", Some("alice"), Some("Alice A"))),
+                ("U2".to_string(), user("U2", Some(""), Some("Bob B"))),
+                ("U3".to_string(), user("U3", None, None)),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_names_from_cache() {
+        let names = UserNames::from_cache(Some(&cache()), MentionFormat::DisplayName);
+        assert_eq!(names.get("U1"), Some("alice"));
+        assert_eq!(names.get("U2"), Some("Bob B"));
+        assert_eq!(names.get("U3"), Some("U3-handle"));
+        assert_eq!(names.get("U9"), None);
+        assert!(UserNames::from_cache(None, MentionFormat::DisplayName)
+            .get("U1")
+            .is_none());
+
+        let names = UserNames::from_cache(Some(&cache()), MentionFormat::RealName);
+        assert_eq!(names.get("U1"), Some("Alice A"));
+        assert_eq!(names.get("U2"), Some("Bob B"));
+    }
+
+    #[test]
+    fn test_annotate() {
+        let names = UserNames::from_cache(Some(&cache()), MentionFormat::DisplayName);
+        let message = json!({"type": "message", "user": "U1", "ts": "1.0", "text": "hi"});
+        let annotated = names.annotate("C1", &message);
+        assert_eq!(annotated["channel"], "C1");
+        assert_eq!(annotated["user_name"], "alice");
+        assert_eq!(annotated["text"], "hi");
+
+        let bot = json!({"type": "message", "bot_id": "B1", "ts": "2.0"});
+        assert!(names.annotate("C1", &bot)["user_name"].is_null());
+    }
+
+    #[test]
+    fn test_user_display_name() {
+        let user =
+            json!({"name": "carol", "profile": {"display_name": "", "real_name": "Carol C"}});
+        assert_eq!(user_display_name(&user).as_deref(), Some("Carol C"));
+        assert_eq!(
+            user_display_name(&json!({"name": "dave"})).as_deref(),
+            Some("dave")
+        );
+    }
+
+    #[test]
+    fn test_socket_message() {
+        let event = |inner: Value| ReceivedEvent {
+            envelope_type: "events_api".to_string(),
+            envelope_id: Some("E1".to_string()),
+            payload: json!({"event": inner}),
+        };
+        let message = event(json!({"type": "message", "channel": "C1", "ts": "1.0"}));
+        assert_eq!(socket_message(&message, "C1").unwrap()["ts"], "1.0");
+        assert!(socket_message(&message, "C2").is_none());
+        let reaction = event(json!({"type": "reaction_added", "channel": "C1"}));
+        assert!(socket_message(&reaction, "C1").is_none());
+    }
+}
@@ -0,0 +1,312 @@
+//! Session context (`slack-rs ctx ...`, global `--context`)
+//!
+//! A saved channel and thread that commands fall back to when their leading
+//! channel / ts arguments are omitted, so iterative thread work needs only
+//! the text or emoji:
+//!
+//! ```text
+//! slack-rs ctx set channel '#ops' thread 1700000000.000100
+//! slack-rs msg post "deploying now"      # posts into the thread
+//! slack-rs react add eyes                # reacts to the thread root
+//! ```
+//!
+//! `--context channel=C thread=TS` supplies the same values for a single
+//! invocation and takes precedence over the saved context.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::commands::thread_ref::{parse_permalink, ThreadRef};
+
+/// Saved session context
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionContext {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Thread root ts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread: Option<String>,
+}
+
+/// Leading arguments a command can take from the context
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Slot {
+    Channel,
+    Thread,
+}
+
+/// Commands that consult the context:
+/// `(command, subcommand, fillable leading positionals, other positionals)`
+const CONTEXT_COMMANDS: &[(&str, &str, &[Slot], usize)] = &[
+    ("msg", "post", &[Slot::Channel], 1),
+    ("react", "add", &[Slot::Channel, Slot::Thread], 1),
+    ("react", "remove", &[Slot::Channel, Slot::Thread], 1),
+    ("pin", "add", &[Slot::Channel, Slot::Thread], 0),
+    ("pin", "remove", &[Slot::Channel, Slot::Thread], 0),
+    ("thread", "get", &[Slot::Channel, Slot::Thread], 0),
+    ("conv", "history", &[Slot::Channel], 0),
+    ("conv", "replies", &[Slot::Channel, Slot::Thread], 0),
+];
+
+impl SessionContext {
+    /// Default context location in the config directory
+    pub fn default_path() -> Result<PathBuf, String> {
+        crate::profile::config_dir()
+            .map(|dir| dir.join("session_context.json"))
+            .ok_or_else(|| "Could not determine config directory".to_string())
+    }
+
+    /// Load the context; a missing file yields an empty context
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read context {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse context {}: {}", path.display(), e))
+    }
+
+    /// Save the context, creating the parent directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize context: {}", e))?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write context: {}", e))
+    }
+
+    /// Whether neither channel nor thread is set
+    pub fn is_empty(&self) -> bool {
+        self.channel.is_none() && self.thread.is_none()
+    }
+
+    /// Parse `channel <C> thread <TS>` / `channel=<C> thread=<TS>` pairs
+    ///
+    /// A message permalink given as the thread also sets the channel.
+    pub fn parse_pairs(items: &[String]) -> Result<Self, String> {
+        let mut context = Self::default();
+        let mut iter = items.iter();
+        while let Some(item) = iter.next() {
+            let (key, value) = match item.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                None => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("Missing value for '{}'", item))?;
+                    (item.clone(), value.clone())
+                }
+            };
+            match key.as_str() {
+                "channel" => context.channel = Some(value),
+                "thread" => match parse_permalink(&value) {
+                    Some(ThreadRef::Permalink {
+                        channel,
+                        ts,
+                        thread_ts,
+                    }) => {
+                        context.channel.get_or_insert(channel);
+                        context.thread = Some(thread_ts.unwrap_or(ts));
+                    }
+                    _ => context.thread = Some(value),
+                },
+                other => {
+                    return Err(format!(
+                        "Unknown context key '{}' (valid: channel, thread)",
+                        other
+                    ))
+                }
+            }
+        }
+        Ok(context)
+    }
+
+    /// Overlay `other` on this context; values set in `other` win
+    pub fn merged_with(&self, other: &Self) -> Self {
+        Self {
+            channel: other.channel.clone().or_else(|| self.channel.clone()),
+            thread: other.thread.clone().or_else(|| self.thread.clone()),
+        }
+    }
+
+    fn get(&self, slot: Slot) -> Option<&String> {
+        match slot {
+            Slot::Channel => self.channel.as_ref(),
+            Slot::Thread => self.thread.as_ref(),
+        }
+    }
+}
+
+/// Insert context values for omitted leading positionals
+///
+/// Positionals are the run of non-flag arguments after the subcommand.
+/// When a command receives fewer than it takes, the missing leading ones
+/// are taken from the context; if any of them is unset the arguments are
+/// left unchanged so the command reports its usage. `msg post` filled from
+/// the context also posts into the context thread unless `--thread-ts` is
+/// given.
+pub fn apply_session_context(args: Vec<String>, context: &SessionContext) -> Vec<String> {
+    if context.is_empty() || args.len() < 3 {
+        return args;
+    }
+    let Some((_, _, slots, rest)) = CONTEXT_COMMANDS
+        .iter()
+        .find(|(command, sub, _, _)| args[1] == *command && args[2] == *sub)
+    else {
+        return args;
+    };
+
+    let given = args[3..]
+        .iter()
+        .take_while(|arg| !arg.starts_with("--"))
+        .count();
+    let missing = (slots.len() + rest).saturating_sub(given).min(slots.len());
+    if missing == 0 {
+        return args;
+    }
+    let Some(values) = slots[..missing]
+        .iter()
+        .map(|slot| context.get(*slot).cloned())
+        .collect::<Option<Vec<_>>>()
+    else {
+        return args;
+    };
+
+    let mut filled = args[..3].to_vec();
+    filled.extend(values);
+    filled.extend(args[3..].iter().cloned());
+
+    if args[1] == "msg" && args[2] == "post" {
+        let has_thread = filled
+            .iter()
+            .any(|arg| arg == "--thread-ts" || arg.starts_with("--thread-ts="));
+        if let (false, Some(thread)) = (has_thread, &context.thread) {
+            filled.push(format!("--thread-ts={}", thread));
+        }
+    }
+    filled
+}
+
+/// Render the context for `ctx show`
+pub fn format_context_text(context: &SessionContext) -> String {
+    if context.is_empty() {
+        return "No session context set\n".to_string();
+    }
+    format!(
+        "channel: {}\nthread:  {}\n",
+        context.channel.as_deref().unwrap_or("-"),
+        context.thread.as_deref().unwrap_or("-")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn context() -> SessionContext {
+        SessionContext {
+            channel: Some("C0123ABCD".to_string()),
+            thread: Some("1700000000.000100".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_pairs() {
+        let parsed =
+            SessionContext::parse_pairs(&args(&["channel", "#ops", "thread=1.2"])).unwrap();
+        assert_eq!(parsed.channel.as_deref(), Some("#ops"));
+        assert_eq!(parsed.thread.as_deref(), Some("1.2"));
+
+        let parsed = SessionContext::parse_pairs(&args(&[
+            "thread",
+            "https://acme.slack.com/archives/C0123ABCD/p1700000000000200?thread_ts=1700000000.000100",
+        ]))
+        .unwrap();
+        assert_eq!(parsed, context());
+
+        assert!(SessionContext::parse_pairs(&args(&["channel"])).is_err());
+        assert!(SessionContext::parse_pairs(&args(&["user", "U1"])).is_err());
+    }
+
+    #[test]
+    fn test_merged_with() {
+        let flag = SessionContext {
+            channel: Some("C999".to_string()),
+            thread: None,
+        };
+        let merged = context().merged_with(&flag);
+        assert_eq!(merged.channel.as_deref(), Some("C999"));
+        assert_eq!(merged.thread.as_deref(), Some("1700000000.000100"));
+    }
+
+    #[test]
+    fn test_apply_session_context() {
+        let ctx = context();
+
+        assert_eq!(
+            apply_session_context(args(&["slack-rs", "msg", "post", "hello", "--yes"]), &ctx),
+            args(&[
+                "slack-rs",
+                "msg",
+                "post",
+                "C0123ABCD",
+                "hello",
+                "--yes",
+                "--thread-ts=1700000000.000100"
+            ])
+        );
+        assert_eq!(
+            apply_session_context(args(&["slack-rs", "react", "add", "eyes"]), &ctx),
+            args(&[
+                "slack-rs",
+                "react",
+                "add",
+                "C0123ABCD",
+                "1700000000.000100",
+                "eyes"
+            ])
+        );
+        // A given ts only needs the channel
+        assert_eq!(
+            apply_session_context(args(&["slack-rs", "thread", "get", "1.0"]), &ctx),
+            args(&["slack-rs", "thread", "get", "C0123ABCD", "1.0"])
+        );
+    }
+
+    #[test]
+    fn test_apply_session_context_leaves_args() {
+        let ctx = context();
+        let full = args(&["slack-rs", "msg", "post", "C1", "hello"]);
+        assert_eq!(apply_session_context(full.clone(), &ctx), full);
+
+        let other = args(&["slack-rs", "conv", "list"]);
+        assert_eq!(apply_session_context(other.clone(), &ctx), other);
+
+        // Thread needed but not set: leave it to the command's usage error
+        let channel_only = SessionContext {
+            channel: Some("C1".to_string()),
+            thread: None,
+        };
+        let react = args(&["slack-rs", "react", "add", "eyes"]);
+        assert_eq!(apply_session_context(react.clone(), &channel_only), react);
+
+        // Explicit --thread-ts is kept
+        let posted = apply_session_context(
+            args(&["slack-rs", "msg", "post", "hi", "--thread-ts=2.0"]),
+            &ctx,
+        );
+        assert_eq!(
+            posted
+                .iter()
+                .filter(|a| a.starts_with("--thread-ts"))
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Do Not Disturb and presence commands (`dnd info/set/end`, `presence get/set`)
+//!
+//! `dnd.setSnooze`, `dnd.endSnooze` and `users.setPresence` act on the
+//! token's own user and only accept user tokens, so every command runs with
+//! the profile's user token, like reminders.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+use crate::commands::schedule::parse_duration;
+
+/// Values accepted by `presence set`
+pub const PRESENCE_VALUES: &[&str] = &["auto", "away"];
+
+/// Build a `dnd.info` request
+pub fn plan_dnd_info(user: Option<&str>) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::DndInfo).param_opt("user", user)
+}
+
+/// Build a `dnd.setSnooze` request
+pub fn plan_dnd_set_snooze(num_minutes: u64) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::DndSetSnooze).param("num_minutes", num_minutes)
+}
+
+/// Build a `dnd.endSnooze` request
+pub fn plan_dnd_end_snooze() -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::DndEndSnooze)
+}
+
+/// Build a `users.getPresence` request
+pub fn plan_presence_get(user: Option<&str>) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::UsersGetPresence).param_opt("user", user)
+}
+
+/// Build a `users.setPresence` request
+pub fn plan_presence_set(presence: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::UsersSetPresence).param("presence", presence)
+}
+
+/// Parse a snooze length: minutes (`30`) or a duration (`90m`, `1h30m`)
+///
+/// Durations are rounded up to whole minutes.
+pub fn parse_snooze_minutes(s: &str) -> Result<u64, String> {
+    if let Ok(minutes) = s.trim().parse::<u64>() {
+        if minutes == 0 {
+            return Err("Snooze length must be at least one minute".to_string());
+        }
+        return Ok(minutes);
+    }
+    let seconds = parse_duration(s)?;
+    Ok((seconds as u64).div_ceil(60))
+}
+
+/// Check a `presence set` value
+pub fn parse_presence(s: &str) -> Result<String, String> {
+    let presence = s.trim().to_lowercase();
+    if PRESENCE_VALUES.contains(&presence.as_str()) {
+        Ok(presence)
+    } else {
+        Err(format!(
+            "Invalid presence '{}' (expected: {})",
+            s,
+            PRESENCE_VALUES.join(", ")
+        ))
+    }
+}
+
+/// Get Do Not Disturb status
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `user` - Optional user ID (default: the token's user)
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with `dnd_enabled`, `snooze_enabled` and the schedule
+/// * `Err(ApiError)` if the operation fails
+pub async fn dnd_info(client: &ApiClient, user: Option<&str>) -> Result<ApiResponse, ApiError> {
+    client.execute_planned(plan_dnd_info(user)).await
+}
+
+/// Turn on Do Not Disturb for a number of minutes
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `num_minutes` - Snooze length in minutes
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with `snooze_endtime`
+/// * `Err(ApiError)` if the operation fails
+pub async fn dnd_set(
+    client: &ApiClient,
+    num_minutes: u64,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs dnd set {} --yes", num_minutes);
+    let action = format!("snooze notifications for {} minute(s)", num_minutes);
+    confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_dnd_set_snooze(num_minutes))
+        .await
+}
+
+/// End the current Do Not Disturb snooze
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+pub async fn dnd_end(
+    client: &ApiClient,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = "Example: slack-rs dnd end --yes";
+    confirm_destructive_with_hint(yes, "end the snooze", non_interactive, Some(hint))?;
+
+    client.execute_planned(plan_dnd_end_snooze()).await
+}
+
+/// Get a user's presence
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `user` - Optional user ID (default: the token's user)
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with `presence` (`active` or `away`)
+/// * `Err(ApiError)` if the operation fails
+pub async fn presence_get(client: &ApiClient, user: Option<&str>) -> Result<ApiResponse, ApiError> {
+    client.execute_planned(plan_presence_get(user)).await
+}
+
+/// Set the token user's presence
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `presence` - `auto` or `away`
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+pub async fn presence_set(
+    client: &ApiClient,
+    presence: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs presence set {} --yes", presence);
+    let action = format!("set your presence to {}", presence);
+    confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+    client.execute_planned(plan_presence_set(&presence)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_plan_dnd_and_presence() {
+        assert_eq!(plan_dnd_set_snooze(30).params["num_minutes"], 30);
+        assert!(!plan_dnd_info(None).params.contains_key("user"));
+        assert_eq!(plan_presence_get(Some("U1")).params["user"], "U1");
+        assert_eq!(
+            plan_presence_set("away").method,
+            ApiMethod::UsersSetPresence
+        );
+
+        assert_eq!(parse_snooze_minutes("45").unwrap(), 45);
+        assert_eq!(parse_snooze_minutes("1h30m").unwrap(), 90);
+        assert_eq!(parse_snooze_minutes("90s").unwrap(), 2);
+        assert!(parse_snooze_minutes("0").is_err());
+        assert_eq!(parse_presence("Away").unwrap(), "away");
+        assert!(parse_presence("active").is_err());
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_presence_set_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = presence_set(&client, "away".to_string(), true, false).await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+}
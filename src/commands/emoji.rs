@@ -0,0 +1,359 @@
+//! Custom emoji listing and cache (`emoji list`, `react --check-emoji`)
+//!
+//! `emoji.list` returns the workspace's custom emoji as `name -> url`, or
+//! `name -> alias:target` for aliases. The result is cached per workspace
+//! (same TTL as the users cache) so reactions can be checked without an API
+//! call each time.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::users_cache::DEFAULT_TTL_SECONDS;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Frequently used standard emoji, which `emoji.list` does not return
+const COMMON_STANDARD_EMOJI: &[&str] = &[
+    "+1",
+    "-1",
+    "100",
+    "bangbang",
+    "bell",
+    "bulb",
+    "bug",
+    "clap",
+    "construction",
+    "eyes",
+    "fire",
+    "heart",
+    "heavy_check_mark",
+    "heavy_plus_sign",
+    "hourglass",
+    "hourglass_flowing_sand",
+    "joy",
+    "laughing",
+    "memo",
+    "no_entry",
+    "ok",
+    "ok_hand",
+    "pray",
+    "question",
+    "raised_hands",
+    "recycle",
+    "rocket",
+    "rotating_light",
+    "see_no_evil",
+    "smile",
+    "sparkles",
+    "star",
+    "tada",
+    "thinking_face",
+    "thumbsdown",
+    "thumbsup",
+    "warning",
+    "wave",
+    "white_check_mark",
+    "x",
+    "zap",
+];
+
+/// Cached custom emoji of a workspace
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EmojiWorkspaceCache {
+    pub team_id: String,
+    pub updated_at: u64,
+    /// Emoji name -> image URL or `alias:<target>`
+    pub emoji: HashMap<String, String>,
+}
+
+impl EmojiWorkspaceCache {
+    /// Resolve an alias to its target (one hop, as Slack does not chain aliases)
+    pub fn resolve_alias<'a>(&'a self, name: &'a str) -> &'a str {
+        self.emoji
+            .get(name)
+            .and_then(|value| value.strip_prefix("alias:"))
+            .unwrap_or(name)
+    }
+
+    /// Custom emoji names resembling `name`, closest first
+    pub fn suggestions(&self, name: &str, limit: usize) -> Vec<String> {
+        let mut scored: Vec<(usize, &String)> = self
+            .emoji
+            .keys()
+            .filter_map(|candidate| {
+                let distance = edit_distance(name, candidate);
+                let related = candidate.contains(name) || name.contains(candidate.as_str());
+                (distance <= 2 || related).then_some((distance, candidate))
+            })
+            .collect();
+        scored.sort();
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, name)| name.clone())
+            .collect()
+    }
+}
+
+/// Emoji cache file containing multiple workspace caches
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EmojiCacheFile {
+    pub caches: HashMap<String, EmojiWorkspaceCache>,
+}
+
+impl EmojiCacheFile {
+    /// Get the default cache file path
+    pub fn default_path() -> Result<PathBuf, String> {
+        crate::profile::config_dir()
+            .map(|dir| dir.join("emoji_cache.json"))
+            .ok_or_else(|| "Could not determine config directory".to_string())
+    }
+
+    /// Load cache from file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read cache file: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse cache file: {}", e))
+    }
+
+    /// Save cache to file
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize cache: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("Failed to write cache file: {}", e))
+    }
+
+    /// Get workspace cache if it is younger than `ttl_seconds`
+    pub fn fresh_workspace(&self, team_id: &str, ttl_seconds: u64) -> Option<&EmojiWorkspaceCache> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        self.caches
+            .get(team_id)
+            .filter(|cache| now.saturating_sub(cache.updated_at) <= ttl_seconds)
+    }
+}
+
+/// Build an `emoji.list` request
+pub fn plan_emoji_list() -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::EmojiList)
+}
+
+/// Fetch the custom emoji of a workspace
+///
+/// # Returns
+/// * `Ok(EmojiWorkspaceCache)` stamped with the current time
+/// * `Err(ApiError)` if the request fails or Slack returns an error
+pub async fn fetch_emoji(
+    client: &ApiClient,
+    team_id: String,
+) -> Result<EmojiWorkspaceCache, ApiError> {
+    let response = client.execute_planned(plan_emoji_list()).await?;
+    if !response.ok {
+        return Err(ApiError::SlackError(
+            response
+                .error
+                .unwrap_or_else(|| "unknown_error".to_string()),
+        ));
+    }
+    let emoji = response
+        .data
+        .get("emoji")
+        .and_then(|e| e.as_object())
+        .map(|emoji| {
+            emoji
+                .iter()
+                .filter_map(|(name, value)| Some((name.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(EmojiWorkspaceCache {
+        team_id,
+        updated_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        emoji,
+    })
+}
+
+/// Load the workspace's emoji from the cache, refreshing it when expired
+///
+/// # Arguments
+/// * `client` - API client
+/// * `team_id` - Workspace ID
+/// * `force` - Refresh even if the cache is still valid
+///
+/// # Returns
+/// * `Ok((cache, refreshed))`
+/// * `Err(String)` if the cache cannot be read or the fetch fails
+pub async fn load_emoji(
+    client: &ApiClient,
+    team_id: String,
+    force: bool,
+) -> Result<(EmojiWorkspaceCache, bool), String> {
+    let cache_path = EmojiCacheFile::default_path()?;
+    let mut cache_file = EmojiCacheFile::load(&cache_path)?;
+    if !force {
+        if let Some(cache) = cache_file.fresh_workspace(&team_id, DEFAULT_TTL_SECONDS) {
+            return Ok((cache.clone(), false));
+        }
+    }
+
+    let cache = fetch_emoji(client, team_id)
+        .await
+        .map_err(|e| format!("Failed to fetch emoji: {}", e))?;
+    cache_file
+        .caches
+        .insert(cache.team_id.clone(), cache.clone());
+    cache_file.save(&cache_path)?;
+    Ok((cache, true))
+}
+
+/// `emoji list` response: one record per emoji, sorted by name
+pub fn emoji_list_response(cache: &EmojiWorkspaceCache, cached: bool) -> ApiResponse {
+    let sorted: BTreeMap<&String, &String> = cache.emoji.iter().collect();
+    let records: Vec<Value> = sorted
+        .into_iter()
+        .map(|(name, value)| match value.strip_prefix("alias:") {
+            Some(target) => json!({"name": name, "alias_for": target, "url": null}),
+            None => json!({"name": name, "alias_for": null, "url": value}),
+        })
+        .collect();
+
+    let mut data = HashMap::new();
+    data.insert("count".to_string(), json!(records.len()));
+    data.insert("emoji".to_string(), Value::Array(records));
+    data.insert("updated_at".to_string(), json!(cache.updated_at));
+    data.insert("cached".to_string(), json!(cached));
+    ApiResponse {
+        ok: true,
+        data,
+        error: None,
+    }
+}
+
+/// Strip surrounding colons from an emoji name (`:eyes:` -> `eyes`)
+///
+/// A skin tone suffix (`thumbsup::skin-tone-2`) is kept.
+pub fn normalize_emoji_name(name: &str) -> String {
+    name.trim().trim_matches(':').to_string()
+}
+
+/// Outcome of checking an emoji name against the cache
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmojiCheck {
+    /// Custom or common standard emoji, possibly rewritten from an alias
+    Known(String),
+    /// Not a custom emoji; may still be a standard one
+    Unknown { suggestions: Vec<String> },
+}
+
+/// Check an emoji name against a workspace's custom emoji
+///
+/// Colons are stripped and aliases are resolved to their target.
+pub fn check_emoji_name(name: &str, cache: &EmojiWorkspaceCache) -> EmojiCheck {
+    let name = normalize_emoji_name(name);
+    let (base, skin_tone) = match name.split_once("::") {
+        Some((base, tone)) => (base, Some(tone)),
+        None => (name.as_str(), None),
+    };
+    if cache.emoji.contains_key(base) || COMMON_STANDARD_EMOJI.contains(&base) {
+        let resolved = cache.resolve_alias(base);
+        return EmojiCheck::Known(match skin_tone {
+            Some(tone) => format!("{}::{}", resolved, tone),
+            None => resolved.to_string(),
+        });
+    }
+    EmojiCheck::Unknown {
+        suggestions: cache.suggestions(base, 5),
+    }
+}
+
+/// Levenshtein distance between two names
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache() -> EmojiWorkspaceCache {
+        EmojiWorkspaceCache {
+            team_id: "T1".to_string(),
+            updated_at: 0,
+            emoji: HashMap::from([
+                (
+                    "shipit".to_string(),
+                    "https://emoji.example/shipit.png".to_string(),
+                ),
+                ("squirrel".to_string(), "alias:shipit".to_string()),
+                (
+                    "party-parrot".to_string(),
+                    "https://emoji.example/parrot.gif".to_string(),
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_check_emoji_name() {
+        let cache = cache();
+        assert_eq!(
+            check_emoji_name(":squirrel:", &cache),
+            EmojiCheck::Known("shipit".to_string())
+        );
+        assert_eq!(
+            check_emoji_name("thumbsup::skin-tone-2", &cache),
+            EmojiCheck::Known("thumbsup::skin-tone-2".to_string())
+        );
+        assert_eq!(
+            check_emoji_name("party-parot", &cache),
+            EmojiCheck::Unknown {
+                suggestions: vec!["party-parrot".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn test_emoji_list_response_and_cache_file() {
+        let response = emoji_list_response(&cache(), true);
+        assert_eq!(response.data["count"], 3);
+        assert_eq!(response.data["emoji"][0]["name"], "party-parrot");
+        assert_eq!(response.data["emoji"][2]["alias_for"], "shipit");
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("emoji_cache.json");
+        let mut file = EmojiCacheFile::default();
+        file.caches.insert("T1".to_string(), cache());
+        file.save(&path).unwrap();
+        let loaded = EmojiCacheFile::load(&path).unwrap();
+        assert_eq!(loaded, file);
+        assert!(loaded.fresh_workspace("T1", 60).is_none());
+        assert!(loaded.fresh_workspace("T1", u64::MAX).is_some());
+    }
+}
@@ -4,13 +4,22 @@
 //! 1. Call files.getUploadURLExternal to get upload_url and file_id
 //! 2. POST raw file bytes to upload_url (not a Slack API endpoint)
 //! 3. Call files.completeUploadExternal to finalize and share the file
+//!
+//! Before upload, the content type is sniffed from the file bytes so the
+//! correct MIME type and extension reach Slack. Images can optionally be
+//! downscaled or stripped of metadata when built with `image-processing`.
+//!
+//! The content can also come from stdin (`file upload -`). Slack needs its
+//! length before the transfer starts, so it is collected in memory as it
+//! arrives instead of being written to a temporary file.
 
-use crate::api::{ApiClient, ApiError};
+use crate::api::{ApiClient, ApiError, ApiMethod, PlannedRequest};
 use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+use crate::i18n::tf;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashMap;
+use std::io::Read;
 use std::path::Path;
 
 /// Response from files.getUploadURLExternal
@@ -32,6 +41,299 @@ struct CompleteUploadResponse {
     error: Option<String>,
 }
 
+/// Image size above which a warning is printed before upload (5 MiB)
+const LARGE_IMAGE_WARN_BYTES: usize = 5 * 1024 * 1024;
+
+/// Optional processing and naming applied to the file before upload
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UploadOptions {
+    /// Downscale images so that neither side exceeds this many pixels
+    pub max_dim: Option<u32>,
+    /// Re-encode images to drop EXIF and other embedded metadata
+    pub strip_exif: bool,
+    /// Name shown in Slack instead of the file's own name
+    pub file_name: Option<String>,
+    /// Snippet syntax type (`text`, `python`, ...) for text content
+    pub snippet_type: Option<String>,
+}
+
+impl UploadOptions {
+    /// Whether any image processing was requested
+    pub fn requires_image_processing(&self) -> bool {
+        self.max_dim.is_some() || self.strip_exif
+    }
+}
+
+/// Compute a content-addressable key for an upload
+///
+/// The key covers the file bytes, the (order-independent) target channels,
+/// the title and comment shown with the file and the processing and naming
+/// options, so re-running the same upload yields the same key while a
+/// changed title or comment uploads again.
+///
+/// # Arguments
+/// * `file_bytes` - Original file contents
+/// * `channels` - Comma-separated target channel IDs
+/// * `title` - File title
+/// * `comment` - Initial comment posted with the file
+/// * `options` - Processing options applied before upload
+///
+/// # Returns
+/// Key in the form `sha256:<hex>`
+pub fn upload_content_key(
+    file_bytes: &[u8],
+    channels: Option<&str>,
+    title: Option<&str>,
+    comment: Option<&str>,
+    options: &UploadOptions,
+) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut channel_list: Vec<&str> = channels
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .collect();
+    channel_list.sort_unstable();
+    channel_list.dedup();
+
+    let mut hasher = Sha256::new();
+    hasher.update(file_bytes);
+    hasher.update(b"\0channels:");
+    hasher.update(channel_list.join(",").as_bytes());
+    hasher.update(b"\0max_dim:");
+    hasher.update(options.max_dim.unwrap_or(0).to_le_bytes());
+    hasher.update(b"\0strip_exif:");
+    hasher.update([options.strip_exif as u8]);
+    // Optional text fields are only hashed when set, so keys of uploads
+    // without them stay the same
+    for (label, value) in [
+        ("title", title),
+        ("comment", comment),
+        ("file_name", options.file_name.as_deref()),
+        ("snippet_type", options.snippet_type.as_deref()),
+    ] {
+        if let Some(value) = value {
+            hasher.update(format!("\0{}:", label).as_bytes());
+            hasher.update(value.as_bytes());
+        }
+    }
+
+    format!("sha256:{:x}", hasher.finalize())
+}
+
+/// Content type detected from file bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SniffedType {
+    /// MIME type (e.g., "image/png")
+    pub mime: &'static str,
+    /// Canonical file extension without the dot (e.g., "png")
+    pub extension: &'static str,
+}
+
+impl SniffedType {
+    const fn new(mime: &'static str, extension: &'static str) -> Self {
+        Self { mime, extension }
+    }
+
+    /// Whether the detected type is an image
+    pub fn is_image(&self) -> bool {
+        self.mime.starts_with("image/")
+    }
+}
+
+/// Detect the content type of a file from its leading magic bytes
+///
+/// # Arguments
+/// * `bytes` - File contents (only the first few bytes are inspected)
+///
+/// # Returns
+/// * `Some(SniffedType)` if a known signature matches
+/// * `None` if the type could not be determined
+pub fn sniff_content_type(bytes: &[u8]) -> Option<SniffedType> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some(SniffedType::new("image/png", "png"))
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(SniffedType::new("image/jpeg", "jpg"))
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some(SniffedType::new("image/gif", "gif"))
+    } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some(SniffedType::new("image/webp", "webp"))
+    } else if bytes.starts_with(b"II*\0") || bytes.starts_with(b"MM\0*") {
+        Some(SniffedType::new("image/tiff", "tiff"))
+    } else if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" {
+        // ISO media: the major brand tells images, audio and video apart
+        match &bytes[8..12] {
+            b"heic" | b"heix" | b"heim" | b"heis" | b"hevc" | b"mif1" | b"msf1" => {
+                Some(SniffedType::new("image/heic", "heic"))
+            }
+            b"avif" | b"avis" => Some(SniffedType::new("image/avif", "avif")),
+            b"qt  " => Some(SniffedType::new("video/quicktime", "mov")),
+            b"M4A " | b"M4B " => Some(SniffedType::new("audio/mp4", "m4a")),
+            b"3gp4" | b"3gp5" | b"3gp6" | b"3gg6" => Some(SniffedType::new("video/3gpp", "3gp")),
+            b"isom" | b"iso2" | b"iso4" | b"iso5" | b"iso6" | b"mp41" | b"mp42" | b"avc1"
+            | b"dash" | b"mmp4" | b"M4V " | b"M4VP" | b"f4v " => {
+                Some(SniffedType::new("video/mp4", "mp4"))
+            }
+            _ => None,
+        }
+    } else if bytes.starts_with(b"%PDF-") {
+        Some(SniffedType::new("application/pdf", "pdf"))
+    } else if bytes.starts_with(b"PK\x03\x04") {
+        Some(SniffedType::new("application/zip", "zip"))
+    } else if bytes.starts_with(&[0x1F, 0x8B]) {
+        Some(SniffedType::new("application/gzip", "gz"))
+    } else {
+        None
+    }
+}
+
+/// Append the sniffed extension when the file name has none
+fn file_name_with_extension(file_name: &str, sniffed: Option<SniffedType>) -> String {
+    match sniffed {
+        Some(t) if Path::new(file_name).extension().is_none() => {
+            format!("{}.{}", file_name, t.extension)
+        }
+        _ => file_name.to_string(),
+    }
+}
+
+/// Refuse `--strip-exif` for content whose metadata cannot be removed
+///
+/// Only PNG and JPEG are re-encoded; uploading a HEIC, AVIF, WebP or other
+/// file unchanged would silently keep its metadata.
+fn check_strip_exif(sniffed: Option<SniffedType>, options: &UploadOptions) -> Result<(), ApiError> {
+    match sniffed.map(|t| t.mime) {
+        _ if !options.strip_exif => Ok(()),
+        Some("image/png" | "image/jpeg") => Ok(()),
+        other => Err(ApiError::SlackError(format!(
+            "cannot strip metadata from {}; remove --strip-exif to upload as-is",
+            other.unwrap_or("content of unknown type")
+        ))),
+    }
+}
+
+/// Apply requested image processing to file bytes
+///
+/// Only PNG and JPEG images are processed; with `--max-dim` alone other
+/// content is returned unchanged with a warning (see [`check_strip_exif`]
+/// for `--strip-exif`). Re-encoding always drops EXIF metadata.
+#[cfg(feature = "image-processing")]
+fn process_image(
+    bytes: Vec<u8>,
+    sniffed: Option<SniffedType>,
+    options: &UploadOptions,
+) -> Result<Vec<u8>, ApiError> {
+    use image::{imageops::FilterType, ImageFormat};
+
+    let format = match sniffed.map(|t| t.mime) {
+        Some("image/png") => ImageFormat::Png,
+        Some("image/jpeg") => ImageFormat::Jpeg,
+        _ => {
+            eprintln!(
+                "Warning: --max-dim only applies to PNG and JPEG images; uploading file unchanged"
+            );
+            return Ok(bytes);
+        }
+    };
+
+    let img = image::load_from_memory_with_format(&bytes, format)
+        .map_err(|e| ApiError::SlackError(format!("Failed to decode image: {}", e)))?;
+
+    let needs_resize = options
+        .max_dim
+        .is_some_and(|max| img.width() > max || img.height() > max);
+    if !needs_resize && !options.strip_exif {
+        return Ok(bytes);
+    }
+
+    let img = match options.max_dim {
+        Some(max) if needs_resize => img.resize(max, max, FilterType::Lanczos3),
+        _ => img,
+    };
+
+    let mut out = std::io::Cursor::new(Vec::new());
+    img.write_to(&mut out, format)
+        .map_err(|e| ApiError::SlackError(format!("Failed to encode image: {}", e)))?;
+    Ok(out.into_inner())
+}
+
+/// Image processing is unavailable without the `image-processing` feature
+#[cfg(not(feature = "image-processing"))]
+fn process_image(
+    _bytes: Vec<u8>,
+    _sniffed: Option<SniffedType>,
+    _options: &UploadOptions,
+) -> Result<Vec<u8>, ApiError> {
+    Err(ApiError::SlackError(
+        "--max-dim/--strip-exif require slack-rs to be built with the `image-processing` feature"
+            .to_string(),
+    ))
+}
+
+/// Path argument that makes `file upload` read the content from stdin
+pub const STDIN_UPLOAD_PATH: &str = "-";
+
+/// Largest file Slack accepts (1 GB)
+pub const MAX_UPLOAD_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// File name for stdin content without `--filename`
+const STDIN_FILE_NAME: &str = "stdin";
+
+/// Read upload content from `reader` (stdin) until it ends
+///
+/// Stops with an error once the content exceeds [`MAX_UPLOAD_BYTES`] instead
+/// of buffering it all; empty input is an error as Slack rejects empty files.
+pub fn read_upload_content(reader: impl std::io::Read) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    reader
+        .take(MAX_UPLOAD_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Failed to read stdin: {}", e))?;
+    if bytes.len() as u64 > MAX_UPLOAD_BYTES {
+        return Err("stdin exceeds Slack's 1 GB file size limit".to_string());
+    }
+    if bytes.is_empty() {
+        return Err("stdin is empty: nothing to upload".to_string());
+    }
+    Ok(bytes)
+}
+
+/// Build the `files.getUploadURLExternal` request for a file
+pub fn plan_get_upload_url(file_name: &str, length: usize) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::FilesGetUploadUrlExternal)
+        .param("filename", file_name)
+        .param("length", length)
+}
+
+/// Build the `files.completeUploadExternal` request that shares an uploaded file
+pub fn plan_complete_upload(
+    file_id: &str,
+    title: &str,
+    channels: Option<&str>,
+    comment: Option<&str>,
+) -> PlannedRequest {
+    plan_complete_upload_files(&[(file_id, title)], channels, comment)
+}
+
+/// Build a `files.completeUploadExternal` request sharing several uploaded
+/// files (`(file_id, title)` pairs) in one message
+pub fn plan_complete_upload_files(
+    files: &[(&str, &str)],
+    channels: Option<&str>,
+    comment: Option<&str>,
+) -> PlannedRequest {
+    let files: Vec<serde_json::Value> = files
+        .iter()
+        .map(|(id, title)| json!({ "id": id, "title": title }))
+        .collect();
+    PlannedRequest::new(ApiMethod::FilesCompleteUploadExternal)
+        .param("files", files)
+        .param_opt("channel_id", channels)
+        .param_opt("initial_comment", comment)
+}
+
 /// Upload a file using external upload flow
 ///
 /// # Arguments
@@ -54,6 +356,45 @@ pub async fn file_upload(
     comment: Option<String>,
     yes: bool,
     non_interactive: bool,
+) -> Result<serde_json::Value, ApiError> {
+    file_upload_with_options(
+        client,
+        file_path,
+        channels,
+        title,
+        comment,
+        yes,
+        non_interactive,
+        &UploadOptions::default(),
+    )
+    .await
+}
+
+/// Upload a file using external upload flow with pre-upload processing
+///
+/// # Arguments
+/// * `client` - API client with token
+/// * `file_path` - Path to file to upload
+/// * `channels` - Optional channel IDs to share to (comma-separated)
+/// * `title` - Optional file title
+/// * `comment` - Optional initial comment
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+/// * `options` - Image processing options
+///
+/// # Returns
+/// * `Ok(serde_json::Value)` with upload result
+/// * `Err(ApiError)` if the operation fails
+#[allow(clippy::too_many_arguments)]
+pub async fn file_upload_with_options(
+    client: &ApiClient,
+    file_path: String,
+    channels: Option<String>,
+    title: Option<String>,
+    comment: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+    options: &UploadOptions,
 ) -> Result<serde_json::Value, ApiError> {
     check_write_allowed()?;
 
@@ -62,7 +403,332 @@ pub async fn file_upload(
     confirm_destructive_with_hint(yes, "upload this file", non_interactive, Some(&hint))?;
 
     // Step 1: Read file and get metadata
-    let path = Path::new(&file_path);
+    let prepared = prepare_upload(&file_path, options)?;
+
+    upload_prepared(client, prepared, channels, title, comment, options).await
+}
+
+/// Upload content already in memory, such as a file piped to stdin
+///
+/// Same flow as [`file_upload_with_options`]; the file is named after
+/// `options.file_name` (default `stdin`, plus the sniffed extension).
+///
+/// # Returns
+/// * `Ok(serde_json::Value)` with upload result
+/// * `Err(ApiError)` if the operation fails
+#[allow(clippy::too_many_arguments)]
+pub async fn file_upload_content(
+    client: &ApiClient,
+    content: Vec<u8>,
+    channels: Option<String>,
+    title: Option<String>,
+    comment: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+    options: &UploadOptions,
+) -> Result<serde_json::Value, ApiError> {
+    check_write_allowed()?;
+
+    let hint = "Example: ./build.sh 2>&1 | slack-rs file upload - --filename build.log --yes";
+    confirm_destructive_with_hint(yes, "upload this file", non_interactive, Some(hint))?;
+
+    let prepared = prepare_content(STDIN_FILE_NAME, content, options)?;
+    upload_prepared(client, prepared, channels, title, comment, options).await
+}
+
+/// Transfer and share one prepared file
+async fn upload_prepared(
+    client: &ApiClient,
+    prepared: PreparedUpload,
+    channels: Option<String>,
+    title: Option<String>,
+    comment: Option<String>,
+    options: &UploadOptions,
+) -> Result<serde_json::Value, ApiError> {
+    let file_id = transfer_bytes(
+        client,
+        &prepared.file_name,
+        prepared.bytes,
+        prepared.content_type,
+        options.snippet_type.as_deref(),
+    )
+    .await?;
+    let complete_result = complete_upload(
+        client,
+        &[(&file_id, title.as_deref().unwrap_or(&prepared.file_name))],
+        channels.as_deref(),
+        comment.as_deref(),
+        None,
+    )
+    .await?;
+
+    // Return the complete result as JSON
+    serde_json::to_value(complete_result)
+        .map_err(|e| ApiError::SlackError(format!("Failed to serialize result: {}", e)))
+}
+
+/// Files shared per message by a multi-file upload (Slack's own limit for
+/// attachments on one message)
+pub const MAX_FILES_PER_MESSAGE: usize = 10;
+
+/// Default number of files transferred at the same time
+pub const DEFAULT_UPLOAD_PARALLELISM: usize = 4;
+
+/// Outcome of one file in a multi-file upload
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileUploadResult {
+    pub path: String,
+    /// "uploaded", "skipped" (identical content uploaded before) or "failed"
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permalink: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl FileUploadResult {
+    /// Result for a file that was not uploaded again
+    pub fn skipped(path: &str, previous: Option<&serde_json::Value>) -> Self {
+        let field = |key: &str| {
+            previous
+                .and_then(|file| file.get(key))
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+        Self {
+            path: path.to_string(),
+            status: "skipped".to_string(),
+            id: field("id"),
+            name: field("name"),
+            permalink: field("permalink"),
+            error: None,
+        }
+    }
+
+    fn failed(path: &str, error: String) -> Self {
+        Self {
+            path: path.to_string(),
+            status: "failed".to_string(),
+            id: None,
+            name: None,
+            permalink: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Result of a multi-file upload
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FileUploadReport {
+    pub uploaded: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub results: Vec<FileUploadResult>,
+}
+
+impl FileUploadReport {
+    /// Build a report from results, counting them by status
+    pub fn from_results(results: Vec<FileUploadResult>) -> Self {
+        let count = |status: &str| results.iter().filter(|r| r.status == status).count();
+        Self {
+            uploaded: count("uploaded"),
+            skipped: count("skipped"),
+            failed: count("failed"),
+            results,
+        }
+    }
+}
+
+/// Expand `file upload` path arguments
+///
+/// A `*` or `?` in the file name part matches files in that directory
+/// (`reports/*.pdf`), sorted by name; a pattern that matches nothing is an
+/// error. Wildcards in directory parts are not supported. Other arguments
+/// are kept as given. Duplicates are dropped.
+pub fn expand_upload_paths(args: &[String]) -> Result<Vec<String>, String> {
+    let mut paths: Vec<String> = Vec::new();
+    for arg in args {
+        let path = Path::new(arg);
+        let file_pattern = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if !arg.contains(['*', '?']) {
+            if !paths.contains(arg) {
+                paths.push(arg.clone());
+            }
+            continue;
+        }
+        let dir = path.parent().filter(|p| !p.as_os_str().is_empty());
+        if dir.is_some_and(|d| d.to_string_lossy().contains(['*', '?'])) {
+            return Err(format!(
+                "'{}': wildcards are only supported in the file name",
+                arg
+            ));
+        }
+        let entries = std::fs::read_dir(dir.unwrap_or(Path::new(".")))
+            .map_err(|e| format!("'{}': {}", arg, e))?;
+        let mut matches: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .filter(|name| wildcard_match(file_pattern, name))
+            .map(|name| match dir {
+                Some(dir) => dir.join(name).to_string_lossy().into_owned(),
+                None => name,
+            })
+            .collect();
+        if matches.is_empty() {
+            return Err(format!("No files match '{}'", arg));
+        }
+        matches.sort();
+        for matched in matches {
+            if !paths.contains(&matched) {
+                paths.push(matched);
+            }
+        }
+    }
+    Ok(paths)
+}
+
+/// Match a file name against a pattern with `*` (any run) and `?` (one character)
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Upload several files, transferring up to `parallelism` at a time
+///
+/// One confirmation covers the whole batch. Transferred files are shared in
+/// messages of up to [`MAX_FILES_PER_MESSAGE`] files; `comment` goes with
+/// the first message. A file that cannot be read or transferred is recorded
+/// as failed and the others continue. Results keep the order of `paths`.
+#[allow(clippy::too_many_arguments)]
+pub async fn file_upload_many(
+    client: &ApiClient,
+    paths: &[String],
+    channels: Option<String>,
+    comment: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+    options: &UploadOptions,
+    parallelism: usize,
+) -> Result<FileUploadReport, ApiError> {
+    use futures_util::stream::{self, StreamExt};
+
+    check_write_allowed()?;
+    if paths.is_empty() {
+        return Err(ApiError::MissingParameter("path".to_string()));
+    }
+    let hint = format!("Example: slack-rs file upload {} --yes", paths.join(" "));
+    let operation = match paths {
+        [_] => "upload this file".to_string(),
+        _ => format!("upload {} files", paths.len()),
+    };
+    confirm_destructive_with_hint(yes, &operation, non_interactive, Some(&hint))?;
+
+    let transfers: Vec<Result<(String, String), ApiError>> = stream::iter(paths)
+        .map(|path| async move {
+            let prepared = prepare_upload(path, options)?;
+            let file_id = transfer_bytes(
+                client,
+                &prepared.file_name,
+                prepared.bytes,
+                prepared.content_type,
+                options.snippet_type.as_deref(),
+            )
+            .await?;
+            Ok((file_id, prepared.file_name))
+        })
+        .buffered(parallelism.max(1))
+        .collect()
+        .await;
+
+    let mut results: Vec<FileUploadResult> = paths
+        .iter()
+        .zip(&transfers)
+        .map(|(path, transfer)| match transfer {
+            Ok((id, name)) => FileUploadResult {
+                path: path.clone(),
+                status: "uploaded".to_string(),
+                id: Some(id.clone()),
+                name: Some(name.clone()),
+                permalink: None,
+                error: None,
+            },
+            Err(e) => FileUploadResult::failed(path, e.to_string()),
+        })
+        .collect();
+
+    let transferred: Vec<usize> = (0..results.len())
+        .filter(|i| results[*i].status == "uploaded")
+        .collect();
+    for (batch, indexes) in transferred.chunks(MAX_FILES_PER_MESSAGE).enumerate() {
+        let files: Vec<(&str, &str)> = indexes
+            .iter()
+            .filter_map(|i| match &transfers[*i] {
+                Ok((id, name)) => Some((id.as_str(), name.as_str())),
+                Err(_) => None,
+            })
+            .collect();
+        let comment = comment.as_deref().filter(|_| batch == 0);
+        match complete_upload(client, &files, channels.as_deref(), comment, None).await {
+            Ok(response) => {
+                for i in indexes {
+                    let shared = response.files.iter().flatten().find(|file| {
+                        file.get("id").and_then(|v| v.as_str()) == results[*i].id.as_deref()
+                    });
+                    results[*i].permalink = shared
+                        .and_then(|file| file.get("permalink"))
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string);
+                }
+            }
+            Err(e) => {
+                for i in indexes {
+                    results[*i] = FileUploadResult::failed(&results[*i].path, e.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(FileUploadReport::from_results(results))
+}
+
+/// File contents ready for upload
+struct PreparedUpload {
+    file_name: String,
+    bytes: Vec<u8>,
+    content_type: &'static str,
+}
+
+/// Read a file, sniff its type and apply image processing
+fn prepare_upload(file_path: &str, options: &UploadOptions) -> Result<PreparedUpload, ApiError> {
+    let path = Path::new(file_path);
     if !path.exists() {
         return Err(ApiError::SlackError(format!(
             "File not found: {}",
@@ -73,34 +739,105 @@ pub async fn file_upload(
     let file_bytes = std::fs::read(path)
         .map_err(|e| ApiError::SlackError(format!("Failed to read file {}: {}", file_path, e)))?;
 
-    let file_name = path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("file")
-        .to_string();
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("file");
+    prepare_content(file_name, file_bytes, options)
+}
+
+/// Sniff the type of file contents, apply image processing and name them
+///
+/// `options.file_name` replaces `default_name` when set.
+fn prepare_content(
+    default_name: &str,
+    file_bytes: Vec<u8>,
+    options: &UploadOptions,
+) -> Result<PreparedUpload, ApiError> {
+    let sniffed = sniff_content_type(&file_bytes);
+
+    let file_bytes = if options.requires_image_processing() {
+        check_strip_exif(sniffed, options)?;
+        process_image(file_bytes, sniffed, options)?
+    } else {
+        file_bytes
+    };
+
+    let file_name = file_name_with_extension(
+        options.file_name.as_deref().unwrap_or(default_name),
+        sniffed,
+    );
 
     let file_length = file_bytes.len();
 
-    // Step 2: Get upload URL
-    let mut params = HashMap::new();
-    params.insert("filename".to_string(), json!(file_name));
-    params.insert("length".to_string(), json!(file_length));
+    if sniffed.is_some_and(|t| t.is_image()) && file_length > LARGE_IMAGE_WARN_BYTES {
+        let size = format!("{:.1}", file_length as f64 / (1024.0 * 1024.0));
+        crate::gha::warn(&tf("warn.large_image", &[("size", &size)]));
+    }
 
-    // Call files.getUploadURLExternal using the base_url from ApiClient
-    let url = format!("{}/files.getUploadURLExternal", client.base_url());
+    Ok(PreparedUpload {
+        file_name,
+        bytes: file_bytes,
+        content_type: sniffed.map_or("application/octet-stream", |t| t.mime),
+    })
+}
+
+/// Run the external upload sequence for in-memory content
+#[allow(clippy::too_many_arguments)]
+async fn upload_bytes(
+    client: &ApiClient,
+    file_name: &str,
+    file_bytes: Vec<u8>,
+    content_type: &str,
+    title: &str,
+    channels: Option<&str>,
+    comment: Option<&str>,
+    thread_ts: Option<&str>,
+) -> Result<CompleteUploadResponse, ApiError> {
+    let file_id = transfer_bytes(client, file_name, file_bytes, content_type, None).await?;
+    complete_upload(client, &[(&file_id, title)], channels, comment, thread_ts).await
+}
+
+/// Bearer token of `client` for calls that bypass `ApiClient`, recorded as a use
+fn client_token(client: &ApiClient) -> Result<&str, ApiError> {
     let token = client
         .token
-        .as_ref()
+        .as_deref()
         .ok_or_else(|| ApiError::SlackError("No token configured".to_string()))?;
+    crate::profile::token_meta::record_token_sent(token);
+    Ok(token)
+}
+
+/// Get an upload URL and send the bytes to it; returns the new file ID
+///
+/// The file is not visible anywhere until [`complete_upload`] shares it.
+async fn transfer_bytes(
+    client: &ApiClient,
+    file_name: &str,
+    file_bytes: Vec<u8>,
+    content_type: &str,
+    snippet_type: Option<&str>,
+) -> Result<String, ApiError> {
+    let file_length = file_bytes.len();
+
+    // The upload bypasses ApiClient, so it is charged to the write budget
+    // here, once per file for the whole sequence
+    crate::api::budget::consume_write(ApiMethod::FilesCompleteUploadExternal.as_str())
+        .map_err(ApiError::WriteBudgetExceeded)?;
+
+    // Step 2: Get upload URL
+    let request =
+        plan_get_upload_url(file_name, file_length).param_opt("snippet_type", snippet_type);
+
+    // Call files.getUploadURLExternal using the base_url from ApiClient
+    let url = format!("{}/{}", client.base_url(), request.method.as_str());
+    let token = client_token(client)?;
 
     let http_client = Client::new();
+    crate::api::usage::record_call(request.method.as_str());
     let get_url_response = http_client
         .post(&url)
         .bearer_auth(token)
-        .json(&params)
+        .json(&request.params)
         .send()
-        .await
-        .map_err(|e| ApiError::SlackError(format!("Failed to get upload URL: {}", e)))?;
+        .await?;
 
     let get_url_result: GetUploadUrlResponse = get_url_response
         .json()
@@ -127,11 +864,10 @@ pub async fn file_upload(
     // Step 3: Upload file bytes to external URL
     let upload_response = http_client
         .post(&upload_url)
-        .header("Content-Type", "application/octet-stream")
+        .header("Content-Type", content_type)
         .body(file_bytes)
         .send()
-        .await
-        .map_err(|e| ApiError::SlackError(format!("Failed to upload file: {}", e)))?;
+        .await?;
 
     if !upload_response.status().is_success() {
         return Err(ApiError::SlackError(format!(
@@ -140,32 +876,31 @@ pub async fn file_upload(
         )));
     }
 
-    // Step 4: Complete the upload
-    let mut complete_params = HashMap::new();
+    Ok(file_id)
+}
 
-    // Create files array with single file
-    let file_upload = json!({
-        "id": file_id,
-        "title": title.unwrap_or_else(|| file_name.clone())
-    });
-    complete_params.insert("files".to_string(), json!([file_upload]));
+/// Finalize transferred files (`(file_id, title)` pairs) and share them
+async fn complete_upload(
+    client: &ApiClient,
+    files: &[(&str, &str)],
+    channels: Option<&str>,
+    comment: Option<&str>,
+    thread_ts: Option<&str>,
+) -> Result<CompleteUploadResponse, ApiError> {
+    let token = client_token(client)?;
 
-    // Add optional parameters
-    if let Some(ch) = channels {
-        complete_params.insert("channel_id".to_string(), json!(ch));
-    }
-    if let Some(cmt) = comment {
-        complete_params.insert("initial_comment".to_string(), json!(cmt));
-    }
+    // Step 4: Complete the upload
+    let complete =
+        plan_complete_upload_files(files, channels, comment).param_opt("thread_ts", thread_ts);
 
-    let complete_url = format!("{}/files.completeUploadExternal", client.base_url());
-    let complete_response = http_client
+    let complete_url = format!("{}/{}", client.base_url(), complete.method.as_str());
+    crate::api::usage::record_call(complete.method.as_str());
+    let complete_response = Client::new()
         .post(&complete_url)
         .bearer_auth(token)
-        .json(&complete_params)
+        .json(&complete.params)
         .send()
-        .await
-        .map_err(|e| ApiError::SlackError(format!("Failed to complete upload: {}", e)))?;
+        .await?;
 
     let complete_result: CompleteUploadResponse = complete_response
         .json()
@@ -181,9 +916,89 @@ pub async fn file_upload(
         )));
     }
 
-    // Return the complete result as JSON
-    serde_json::to_value(complete_result)
-        .map_err(|e| ApiError::SlackError(format!("Failed to serialize result: {}", e)))
+    Ok(complete_result)
+}
+
+/// Text length above which `msg post` offers a snippet upload instead
+pub const SNIPPET_THRESHOLD: usize = 4000;
+
+const SNIPPET_LEAD_IN_CHARS: usize = 150;
+
+/// Short message posted with a snippet: the first non-empty line, shortened
+pub fn snippet_lead_in(text: &str) -> String {
+    let first = text
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty() && !l.starts_with("```"))
+        .unwrap_or("");
+    let mut lead_in: String = first.chars().take(SNIPPET_LEAD_IN_CHARS).collect();
+    if first.chars().count() > SNIPPET_LEAD_IN_CHARS {
+        lead_in.push('…');
+    }
+    lead_in
+}
+
+/// Post text as a plain-text snippet file with a lead-in message
+///
+/// The text is uploaded unchanged as `message.txt` and shared to `channel`
+/// (in `thread_ts` when given) with `lead_in` as the message.
+///
+/// # Returns
+/// * `Ok(serde_json::Value)` with `channel`, `files` and a `snippet` object
+///   (`file_id`, `permalink`, `lead_in`, `length`)
+/// * `Err(ApiError)` if the operation fails
+pub async fn file_upload_snippet(
+    client: &ApiClient,
+    channel: String,
+    text: String,
+    lead_in: String,
+    thread_ts: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<serde_json::Value, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs msg post {} \"...\" --auto-snippet --yes",
+        channel
+    );
+    confirm_destructive_with_hint(
+        yes,
+        "post this text as a snippet",
+        non_interactive,
+        Some(&hint),
+    )?;
+
+    let length = text.chars().count();
+    let result = upload_bytes(
+        client,
+        "message.txt",
+        text.into_bytes(),
+        "text/plain; charset=utf-8",
+        "message.txt",
+        Some(&channel),
+        (!lead_in.is_empty()).then_some(lead_in.as_str()),
+        thread_ts.as_deref(),
+    )
+    .await?;
+
+    let file = result
+        .files
+        .as_ref()
+        .and_then(|files| files.first())
+        .cloned()
+        .unwrap_or_default();
+    Ok(json!({
+        "ok": result.ok,
+        "channel": channel,
+        "files": result.files,
+        "snippet": {
+            "file_id": file.get("id"),
+            "permalink": file.get("permalink"),
+            "lead_in": lead_in,
+            "length": length,
+        },
+    }))
 }
 
 /// Response from files.info
@@ -234,14 +1049,14 @@ pub async fn file_download(
         // Note: files.info expects form-encoded parameters, not JSON body
         let info_url = format!("{}/files.info", client.base_url());
         let form_params = vec![("file".to_string(), fid.clone())];
+        crate::api::usage::record_call("files.info");
 
         let info_response = http_client
             .post(&info_url)
             .bearer_auth(token)
             .form(&form_params)
             .send()
-            .await
-            .map_err(|e| ApiError::SlackError(format!("Failed to call files.info: {}", e)))?;
+            .await?;
 
         let info_result: FilesInfoResponse = info_response.json().await.map_err(|e| {
             ApiError::SlackError(format!("Failed to parse files.info response: {}", e))
@@ -291,7 +1106,7 @@ pub async fn file_download(
     let mut redirect_count = 0;
     const MAX_REDIRECTS: u8 = 10;
 
-    let download_response = loop {
+    let mut download_response = loop {
         // Build client with no automatic redirects
         let no_redirect_client = reqwest::ClientBuilder::new()
             .redirect(reqwest::redirect::Policy::none())
@@ -302,8 +1117,7 @@ pub async fn file_download(
             .get(&current_url)
             .bearer_auth(token)
             .send()
-            .await
-            .map_err(|e| ApiError::SlackError(format!("Failed to download file: {}", e)))?;
+            .await?;
 
         let status = response.status();
 
@@ -396,66 +1210,101 @@ pub async fn file_download(
         )));
     }
 
-    // Get response bytes
-    let bytes = download_response
-        .bytes()
-        .await
-        .map_err(|e| ApiError::SlackError(format!("Failed to read response body: {}", e)))?;
-
-    // Handle output
-    let output_path = match out.as_deref() {
-        Some("-") => {
-            // Write to stdout
-            use std::io::Write;
-            std::io::stdout()
-                .write_all(&bytes)
-                .map_err(|e| ApiError::SlackError(format!("Failed to write to stdout: {}", e)))?;
-            "-".to_string()
+    // Resolve the destination before reading so the body can be streamed
+    let target_path = match out.as_deref() {
+        Some("-") => None,
+        // Directory: auto-generate filename
+        Some(path) if Path::new(path).is_dir() => {
+            Some(Path::new(path).join(sanitize_filename(&filename_hint)))
         }
-        Some(path) => {
-            // Write to file
-            let target_path = if Path::new(path).is_dir() {
-                // Directory: auto-generate filename
-                Path::new(path).join(sanitize_filename(&filename_hint))
-            } else {
-                // File path
-                Path::new(path).to_path_buf()
-            };
-
-            std::fs::write(&target_path, &bytes).map_err(|e| {
-                ApiError::SlackError(format!(
-                    "Failed to write file to {}: {}",
-                    target_path.display(),
-                    e
-                ))
-            })?;
-
-            target_path.display().to_string()
-        }
-        None => {
-            // Default: current directory with sanitized filename
-            let target_path = Path::new(".").join(sanitize_filename(&filename_hint));
-            std::fs::write(&target_path, &bytes).map_err(|e| {
-                ApiError::SlackError(format!(
-                    "Failed to write file to {}: {}",
-                    target_path.display(),
-                    e
-                ))
-            })?;
+        Some(path) => Some(Path::new(path).to_path_buf()),
+        // Default: current directory with sanitized filename
+        None => Some(Path::new(".").join(sanitize_filename(&filename_hint))),
+    };
 
-            target_path.display().to_string()
+    let result = stream_download(&mut download_response, target_path.as_deref()).await;
+    let size = match result {
+        Ok(size) => size,
+        Err(e) => {
+            // Do not leave a truncated or HTML file behind
+            if let Some(path) = &target_path {
+                let _ = std::fs::remove_file(path);
+            }
+            return Err(e);
         }
     };
+    let output_path = target_path
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "-".to_string());
 
     // Return metadata
     Ok(json!({
         "ok": true,
         "output": output_path,
-        "size": bytes.len(),
+        "size": size,
         "url": download_url
     }))
 }
 
+/// Whether the first bytes of a download look like an HTML page
+///
+/// Slack serves its login page with a 200 when the token is not accepted,
+/// sometimes without a `text/html` content type.
+pub fn looks_like_html(head: &[u8]) -> bool {
+    let head = String::from_utf8_lossy(&head[..head.len().min(512)]).to_ascii_lowercase();
+    let head = head.trim_start_matches('\u{feff}').trim_start();
+    head.starts_with("<!doctype html") || head.starts_with("<html")
+}
+
+/// Stream a download body to `target` (stdout when `None`)
+///
+/// Returns the number of bytes written. Progress is reported per chunk.
+async fn stream_download(
+    response: &mut reqwest::Response,
+    target: Option<&Path>,
+) -> Result<u64, ApiError> {
+    use std::io::Write;
+
+    let mut writer: Box<dyn Write + Send> = match target {
+        Some(path) => Box::new(std::io::BufWriter::new(
+            std::fs::File::create(path).map_err(|e| {
+                ApiError::SlackError(format!("Failed to write file to {}: {}", path.display(), e))
+            })?,
+        )),
+        None => Box::new(std::io::stdout()),
+    };
+    let write_error = |e: std::io::Error| match target {
+        Some(path) => {
+            ApiError::SlackError(format!("Failed to write file to {}: {}", path.display(), e))
+        }
+        None => ApiError::SlackError(format!("Failed to write to stdout: {}", e)),
+    };
+
+    let total_bytes = response.content_length();
+    let mut written = 0u64;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| ApiError::SlackError(format!("Failed to read response body: {}", e)))?
+    {
+        if written == 0 && looks_like_html(&chunk) {
+            return Err(ApiError::SlackError(format!(
+                "Download returned HTML instead of file (content type did not say so). \
+                 The token was probably not accepted for this file.\n\
+                 \n\
+                 Response snippet:\n{}",
+                truncate_safely(&String::from_utf8_lossy(&chunk), 200)
+            )));
+        }
+        writer.write_all(&chunk).map_err(write_error)?;
+        written += chunk.len() as u64;
+        crate::progress::report("download", written, total_bytes);
+    }
+    writer.flush().map_err(write_error)?;
+
+    Ok(written)
+}
+
 /// Sanitize filename by replacing invalid characters
 fn sanitize_filename(name: &str) -> String {
     let invalid_chars = ['/', '\\', ':', '*', '?', '"', '<', '>', '|', '\0'];
@@ -488,6 +1337,166 @@ mod tests {
     use super::*;
     use serial_test::serial;
 
+    #[test]
+    fn test_snippet_lead_in() {
+        assert_eq!(snippet_lead_in("\n```\nfn main() {}\n```"), "fn main() {}");
+        assert_eq!(snippet_lead_in("  Deploy log  \nline 2"), "Deploy log");
+        let long = "a".repeat(200);
+        let lead_in = snippet_lead_in(&long);
+        assert_eq!(lead_in.chars().count(), 151);
+        assert!(lead_in.ends_with('…'));
+        assert_eq!(snippet_lead_in(""), "");
+    }
+
+    #[test]
+    fn test_plan_upload_requests() {
+        let get_url = plan_get_upload_url("report.pdf", 1024);
+        assert_eq!(get_url.method, ApiMethod::FilesGetUploadUrlExternal);
+        assert_eq!(get_url.params["length"], 1024);
+
+        let complete = plan_complete_upload("F1", "Report", Some("C1"), None);
+        assert_eq!(
+            complete.to_json()["params"],
+            json!({"files": [{"id": "F1", "title": "Report"}], "channel_id": "C1"})
+        );
+    }
+
+    #[test]
+    fn test_plan_complete_upload_files() {
+        let complete = plan_complete_upload_files(
+            &[("F1", "a.pdf"), ("F2", "b.pdf")],
+            Some("C1"),
+            Some("nightly"),
+        );
+        assert_eq!(
+            complete.to_json()["params"],
+            json!({
+                "files": [{"id": "F1", "title": "a.pdf"}, {"id": "F2", "title": "b.pdf"}],
+                "channel_id": "C1",
+                "initial_comment": "nightly"
+            })
+        );
+    }
+
+    #[test]
+    fn test_expand_upload_paths() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["b.pdf", "a.pdf", "notes.txt", "c.PDF"] {
+            std::fs::write(dir.path().join(name), name).unwrap();
+        }
+        std::fs::create_dir(dir.path().join("sub.pdf")).unwrap();
+        let root = dir.path().to_string_lossy().into_owned();
+        let path = |name: &str| dir.path().join(name).to_string_lossy().into_owned();
+
+        let expanded = expand_upload_paths(&[
+            format!("{}/*.pdf", root),
+            path("a.pdf"),
+            format!("{}/note?.txt", root),
+        ])
+        .unwrap();
+        assert_eq!(
+            expanded,
+            vec![path("a.pdf"), path("b.pdf"), path("notes.txt")]
+        );
+
+        // Plain paths are kept as given, even if missing
+        assert_eq!(
+            expand_upload_paths(&["missing.pdf".to_string()]).unwrap(),
+            vec!["missing.pdf"]
+        );
+        assert!(expand_upload_paths(&[format!("{}/*.zip", root)]).is_err());
+        assert!(expand_upload_paths(&[format!("{}/*/x.pdf", root)]).is_err());
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.pdf", "report.pdf"));
+        assert!(wildcard_match("r?port-*.pdf", "report-2026.pdf"));
+        assert!(wildcard_match("a*a", "abca"));
+        assert!(!wildcard_match("a*a", "a"));
+        assert!(!wildcard_match("*.pdf", "report.pdf.txt"));
+    }
+
+    #[test]
+    fn test_looks_like_html() {
+        assert!(looks_like_html(b"<!DOCTYPE html><html><body>Sign in"));
+        assert!(looks_like_html(b"\n  <html lang=\"en\">"));
+        assert!(!looks_like_html(b"%PDF-1.7"));
+        assert!(!looks_like_html(b"plain text mentioning <html>"));
+    }
+
+    #[test]
+    fn test_read_upload_content() {
+        let content = read_upload_content(std::io::Cursor::new(b"build ok\n".to_vec())).unwrap();
+        assert_eq!(content, b"build ok\n");
+        let err = read_upload_content(std::io::empty()).unwrap_err();
+        assert!(err.contains("empty"));
+    }
+
+    #[test]
+    fn test_prepare_content_uses_file_name_option() {
+        let options = UploadOptions {
+            file_name: Some("build.log".to_string()),
+            ..Default::default()
+        };
+        let prepared = prepare_content(STDIN_FILE_NAME, b"log".to_vec(), &options).unwrap();
+        assert_eq!(prepared.file_name, "build.log");
+        assert_eq!(prepared.content_type, "application/octet-stream");
+
+        let png = b"\x89PNG\r\n\x1a\n rest".to_vec();
+        let prepared = prepare_content(STDIN_FILE_NAME, png, &UploadOptions::default()).unwrap();
+        assert_eq!(prepared.file_name, "stdin.png");
+    }
+
+    #[test]
+    fn test_upload_content_key_stable_and_channel_order_independent() {
+        let options = UploadOptions::default();
+        let a = upload_content_key(b"hello", Some("C1,C2"), None, None, &options);
+        let b = upload_content_key(b"hello", Some("C2, C1"), None, None, &options);
+        assert_eq!(a, b);
+        assert!(a.starts_with("sha256:"));
+        assert_eq!(a.len(), "sha256:".len() + 64);
+    }
+
+    #[test]
+    fn test_upload_content_key_differs_by_content_channel_and_options() {
+        let options = UploadOptions::default();
+        let base = upload_content_key(b"hello", Some("C1"), None, None, &options);
+        assert_ne!(
+            base,
+            upload_content_key(b"hello!", Some("C1"), None, None, &options)
+        );
+        assert_ne!(
+            base,
+            upload_content_key(b"hello", Some("C2"), None, None, &options)
+        );
+        assert_ne!(
+            base,
+            upload_content_key(b"hello", None, None, None, &options)
+        );
+        let resized = UploadOptions {
+            max_dim: Some(800),
+            strip_exif: false,
+            ..Default::default()
+        };
+        assert_ne!(
+            base,
+            upload_content_key(b"hello", Some("C1"), None, None, &resized)
+        );
+    }
+
+    #[test]
+    fn test_upload_content_key_differs_by_title_and_comment() {
+        let options = UploadOptions::default();
+        let key =
+            |title, comment| upload_content_key(b"hello", Some("C1"), title, comment, &options);
+        let base = key(None, None);
+        assert_ne!(base, key(Some("Q3 report"), None));
+        assert_ne!(base, key(None, Some("nightly")));
+        assert_ne!(key(Some("a"), None), key(None, Some("a")));
+        assert_eq!(key(Some("a"), Some("b")), key(Some("a"), Some("b")));
+    }
+
     #[tokio::test]
     #[serial(write_guard)]
     async fn test_file_upload_write_not_allowed() {
@@ -533,6 +1542,172 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_file_upload_strip_exif_refuses_webp() {
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("photo.webp");
+        std::fs::write(&path, b"RIFF\x24\0\0\0WEBPVP8 ").unwrap();
+
+        // Nothing listens here: the upload must stop before any request
+        let client = ApiClient::new_with_base_url(
+            "test_token".to_string(),
+            "http://127.0.0.1:1".to_string(),
+        );
+        let options = UploadOptions {
+            strip_exif: true,
+            ..Default::default()
+        };
+        let result = file_upload_with_options(
+            &client,
+            path.to_str().unwrap().to_string(),
+            None,
+            None,
+            None,
+            true,
+            false,
+            &options,
+        )
+        .await;
+        match result {
+            Err(ApiError::SlackError(msg)) => assert_eq!(
+                msg,
+                "cannot strip metadata from image/webp; remove --strip-exif to upload as-is"
+            ),
+            other => panic!("Expected the upload to be refused, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_strip_exif() {
+        let heic = sniff_content_type(b"\0\0\0\x18ftypheic");
+        let jpeg = sniff_content_type(&[0xFF, 0xD8, 0xFF, 0xE0]);
+        let strip = UploadOptions {
+            strip_exif: true,
+            ..Default::default()
+        };
+        assert!(check_strip_exif(heic, &strip)
+            .unwrap_err()
+            .to_string()
+            .contains("image/heic"));
+        assert!(check_strip_exif(None, &strip).is_err());
+        assert!(check_strip_exif(jpeg, &strip).is_ok());
+        // --max-dim alone keeps uploading other content unchanged
+        let resize = UploadOptions {
+            max_dim: Some(100),
+            ..Default::default()
+        };
+        assert!(check_strip_exif(heic, &resize).is_ok());
+    }
+
+    #[test]
+    fn test_sniff_content_type() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(sniff_content_type(png).unwrap().mime, "image/png");
+        assert_eq!(
+            sniff_content_type(&[0xFF, 0xD8, 0xFF, 0xE0])
+                .unwrap()
+                .extension,
+            "jpg"
+        );
+        assert_eq!(sniff_content_type(b"GIF89a...").unwrap().mime, "image/gif");
+        assert_eq!(
+            sniff_content_type(b"RIFF\0\0\0\0WEBPVP8 ").unwrap().mime,
+            "image/webp"
+        );
+        assert_eq!(
+            sniff_content_type(b"\0\0\0\x18ftypheic").unwrap().mime,
+            "image/heic"
+        );
+        let m4a = sniff_content_type(b"\0\0\0\x20ftypM4A \0\0\0\0").unwrap();
+        assert_eq!((m4a.mime, m4a.extension), ("audio/mp4", "m4a"));
+        assert_eq!(
+            sniff_content_type(b"\0\0\0\x1cftypavif").unwrap().mime,
+            "image/avif"
+        );
+        assert_eq!(
+            sniff_content_type(b"\0\0\0\x18ftypisom").unwrap().mime,
+            "video/mp4"
+        );
+        // Unknown brands are not guessed
+        assert!(sniff_content_type(b"\0\0\0\x18ftypcrx ").is_none());
+        assert_eq!(
+            sniff_content_type(b"%PDF-1.7").unwrap().mime,
+            "application/pdf"
+        );
+        assert!(sniff_content_type(b"hello world").is_none());
+        assert!(sniff_content_type(b"").is_none());
+    }
+
+    #[test]
+    fn test_sniffed_type_is_image() {
+        assert!(sniff_content_type(b"GIF87a").unwrap().is_image());
+        assert!(!sniff_content_type(b"%PDF-1.4").unwrap().is_image());
+    }
+
+    #[test]
+    fn test_file_name_with_extension() {
+        let png = sniff_content_type(b"\x89PNG\r\n\x1a\n");
+        assert_eq!(
+            file_name_with_extension("screenshot", png),
+            "screenshot.png"
+        );
+        assert_eq!(file_name_with_extension("shot.jpeg", png), "shot.jpeg");
+        assert_eq!(file_name_with_extension("notes", None), "notes");
+    }
+
+    #[test]
+    fn test_upload_options_requires_image_processing() {
+        assert!(!UploadOptions::default().requires_image_processing());
+        assert!(UploadOptions {
+            max_dim: Some(1600),
+            strip_exif: false,
+            ..Default::default()
+        }
+        .requires_image_processing());
+        assert!(UploadOptions {
+            max_dim: None,
+            strip_exif: true,
+            ..Default::default()
+        }
+        .requires_image_processing());
+    }
+
+    #[cfg(not(feature = "image-processing"))]
+    #[test]
+    fn test_process_image_requires_feature() {
+        let options = UploadOptions {
+            max_dim: Some(1600),
+            strip_exif: false,
+            ..Default::default()
+        };
+        let result = process_image(vec![0xFF, 0xD8, 0xFF], None, &options);
+        match result {
+            Err(ApiError::SlackError(msg)) => assert!(msg.contains("image-processing")),
+            _ => panic!("Expected SlackError mentioning the feature"),
+        }
+    }
+
+    #[cfg(feature = "image-processing")]
+    #[test]
+    fn test_process_image_downscales_png() {
+        let img = image::DynamicImage::new_rgb8(400, 200);
+        let mut buf = std::io::Cursor::new(Vec::new());
+        img.write_to(&mut buf, image::ImageFormat::Png).unwrap();
+        let bytes = buf.into_inner();
+
+        let options = UploadOptions {
+            max_dim: Some(100),
+            strip_exif: false,
+            ..Default::default()
+        };
+        let sniffed = sniff_content_type(&bytes);
+        let out = process_image(bytes, sniffed, &options).unwrap();
+        let resized = image::load_from_memory(&out).unwrap();
+        assert_eq!((resized.width(), resized.height()), (100, 50));
+    }
+
     #[test]
     fn test_sanitize_filename() {
         assert_eq!(sanitize_filename("test.txt"), "test.txt");
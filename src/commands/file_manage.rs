@@ -0,0 +1,343 @@
+//! File listing and cleanup (`file list`, `file delete`)
+//!
+//! `file list` wraps `files.list` with channel, user, type and age filters
+//! and can follow every result page. `file delete` removes files one by one
+//! after a single confirmation and reports the outcome per file, so a
+//! cleanup run over a saved list can be repeated safely: files that are
+//! already gone are reported as failures instead of stopping the batch.
+
+use super::guards::{check_write_allowed, confirm_destructive_with_hint};
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+/// Maximum `files.list` pages followed with `--all`
+const MAX_PAGES: u32 = 100;
+
+/// Filters and paging for `file list`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileListOptions {
+    pub channel: Option<String>,
+    pub user: Option<String>,
+    /// Comma-separated Slack file types (`images`, `pdfs`, `snippets`, ...)
+    pub types: Option<String>,
+    /// Only files created at or before this Unix time (`--older-than`)
+    pub ts_to: Option<i64>,
+    /// Only files created at or after this Unix time
+    pub ts_from: Option<i64>,
+    pub count: Option<u32>,
+    pub page: Option<u32>,
+}
+
+/// Build one `files.list` page request
+pub fn plan_file_list(options: &FileListOptions, page: Option<u32>) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::FilesList)
+        .param_opt("channel", options.channel.as_deref())
+        .param_opt("user", options.user.as_deref())
+        .param_opt("types", options.types.as_deref())
+        .param_opt("ts_from", options.ts_from)
+        .param_opt("ts_to", options.ts_to)
+        .param_opt("count", options.count)
+        .param_opt("page", page)
+}
+
+/// Build a `files.delete` request
+pub fn plan_file_delete(file_id: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::FilesDelete).param("file", file_id)
+}
+
+/// List files matching `options`
+///
+/// With `all`, pages are followed (up to 100) and their `files` merged into
+/// the first response; `paging` then describes the whole result.
+pub async fn file_list(
+    client: &ApiClient,
+    options: &FileListOptions,
+    all: bool,
+) -> Result<ApiResponse, ApiError> {
+    if !all {
+        return client
+            .execute_planned(plan_file_list(options, options.page))
+            .await;
+    }
+
+    let mut first = client
+        .execute_planned(plan_file_list(options, Some(1)))
+        .await?;
+    let mut files = take_files(&mut first);
+    let mut pages = page_count(&first);
+    let mut page = 1;
+    while page < pages && page < MAX_PAGES {
+        page += 1;
+        let mut response = client
+            .execute_planned(plan_file_list(options, Some(page)))
+            .await?;
+        files.extend(take_files(&mut response));
+        pages = page_count(&response);
+    }
+
+    let total = files.len();
+    first.data.insert("files".to_string(), Value::Array(files));
+    first.data.insert(
+        "paging".to_string(),
+        json!({"count": total, "total": total, "page": 1, "pages": 1}),
+    );
+    Ok(first)
+}
+
+fn take_files(response: &mut ApiResponse) -> Vec<Value> {
+    match response.data.remove("files") {
+        Some(Value::Array(files)) => files,
+        _ => Vec::new(),
+    }
+}
+
+fn page_count(response: &ApiResponse) -> u32 {
+    response
+        .data
+        .get("paging")
+        .and_then(|p| p.get("pages"))
+        .and_then(|p| p.as_u64())
+        .map_or(1, |p| p.min(u64::from(u32::MAX)) as u32)
+}
+
+/// Outcome of deleting one file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileDeleteResult {
+    pub id: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of `file delete`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FileDeleteReport {
+    pub deleted: usize,
+    pub failed: usize,
+    pub results: Vec<FileDeleteResult>,
+}
+
+/// Delete `file_ids` after one confirmation for the whole batch
+///
+/// Slack errors for a file (`file_not_found`, `cant_delete_file`, ...) are
+/// recorded and the batch continues; transport and guard errors abort it.
+pub async fn file_delete(
+    client: &ApiClient,
+    file_ids: &[String],
+    yes: bool,
+    non_interactive: bool,
+) -> Result<FileDeleteReport, ApiError> {
+    check_write_allowed()?;
+    if file_ids.is_empty() {
+        return Err(ApiError::MissingParameter("file_id".to_string()));
+    }
+
+    let operation = match file_ids {
+        [_] => "delete this file".to_string(),
+        _ => format!("delete {} files", file_ids.len()),
+    };
+    let hint = if file_ids.len() <= 5 {
+        format!("Example: slack-rs file delete {} --yes", file_ids.join(" "))
+    } else {
+        "Example: slack-rs file delete --from-list ids.txt --yes".to_string()
+    };
+    confirm_destructive_with_hint(yes, &operation, non_interactive, Some(&hint))?;
+
+    let mut report = FileDeleteReport::default();
+    for id in file_ids {
+        let error = match client.execute_planned(plan_file_delete(id)).await {
+            Ok(_) => None,
+            Err(ApiError::SlackError(code)) => Some(code),
+            Err(e) => return Err(e),
+        };
+        if error.is_none() {
+            report.deleted += 1;
+        } else {
+            report.failed += 1;
+        }
+        report.results.push(FileDeleteResult {
+            id: id.clone(),
+            ok: error.is_none(),
+            error,
+        });
+    }
+    Ok(report)
+}
+
+/// File IDs from a `--from-list` input, in order and without duplicates
+///
+/// Accepts the JSON output of `file list` (with or without the envelope),
+/// a JSON array of IDs or file objects, JSON lines with an `id` field
+/// (`file list --format jsonl`), or plain text with one ID per line
+/// (`#` starts a comment; anything after the first space or tab is ignored).
+pub fn parse_file_id_list(content: &str) -> Result<Vec<String>, String> {
+    let mut ids: Vec<String> = Vec::new();
+    if let Ok(value) = serde_json::from_str::<Value>(content.trim()) {
+        let files = value
+            .pointer("/response/files")
+            .or_else(|| value.get("files"))
+            .unwrap_or(&value);
+        match files {
+            Value::Array(items) => ids.extend(items.iter().filter_map(file_id)),
+            other => ids.extend(file_id(other)),
+        }
+    } else {
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('{') {
+                let value: Value = serde_json::from_str(line)
+                    .map_err(|e| format!("Invalid JSON line '{}': {}", line, e))?;
+                ids.extend(file_id(&value));
+            } else if let Some(id) = line.split([' ', '\t', ',']).next() {
+                ids.push(id.to_string());
+            }
+        }
+    }
+
+    if let Some(bad) = ids.iter().find(|id| !looks_like_file_id(id)) {
+        return Err(format!("'{}' is not a file ID (expected F...)", bad));
+    }
+    let mut seen = HashSet::new();
+    ids.retain(|id| seen.insert(id.clone()));
+    Ok(ids)
+}
+
+fn file_id(value: &Value) -> Option<String> {
+    match value {
+        Value::String(id) => Some(id.clone()),
+        Value::Object(_) => value.get("id")?.as_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+fn looks_like_file_id(id: &str) -> bool {
+    id.len() > 1
+        && id.starts_with('F')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_plan_file_list_params() {
+        let options = FileListOptions {
+            user: Some("U1".to_string()),
+            types: Some("images".to_string()),
+            ts_to: Some(1_700_000_000),
+            count: Some(50),
+            ..Default::default()
+        };
+        let request = plan_file_list(&options, Some(2));
+        assert_eq!(request.http_method(), "GET");
+        assert_eq!(
+            serde_json::to_string(&request.to_json()["params"]).unwrap(),
+            r#"{"count":50,"page":2,"ts_to":1700000000,"types":"images","user":"U1"}"#
+        );
+        assert!(plan_file_delete("F1").is_write());
+    }
+
+    #[test]
+    fn test_parse_file_id_list_formats() {
+        let envelope = r#"{"response": {"ok": true, "files": [{"id": "F1"}, {"id": "F2"}]}}"#;
+        assert_eq!(parse_file_id_list(envelope).unwrap(), vec!["F1", "F2"]);
+
+        let raw = r#"{"ok": true, "files": [{"id": "F3", "name": "a.png"}]}"#;
+        assert_eq!(parse_file_id_list(raw).unwrap(), vec!["F3"]);
+
+        assert_eq!(
+            parse_file_id_list(r#"["F1", "F2", "F1"]"#).unwrap(),
+            vec!["F1", "F2"]
+        );
+
+        let jsonl = "{\"id\":\"F1\",\"name\":\"a\"}\n{\"id\":\"F2\",\"name\":\"b\"}\n";
+        assert_eq!(parse_file_id_list(jsonl).unwrap(), vec!["F1", "F2"]);
+
+        let text = "# old screenshots\nF1 screenshot.png\n\nF2\tdump.zip\nF3,report.pdf\n";
+        assert_eq!(parse_file_id_list(text).unwrap(), vec!["F1", "F2", "F3"]);
+
+        assert!(parse_file_id_list("C123\n").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_list_all_merges_pages() {
+        let mock_server = MockServer::start().await;
+        for page in 1..=2 {
+            Mock::given(method("GET"))
+                .and(path("/files.list"))
+                .and(query_param("page", page.to_string()))
+                .and(query_param("channel", "C1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "files": [{"id": format!("F{}", page)}],
+                    "paging": {"count": 1, "total": 2, "page": page, "pages": 2}
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let options = FileListOptions {
+            channel: Some("C1".to_string()),
+            ..Default::default()
+        };
+        let response = file_list(&client, &options, true).await.unwrap();
+        assert_eq!(response.data["files"], json!([{"id": "F1"}, {"id": "F2"}]));
+        assert_eq!(response.data["paging"]["total"], 2);
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_file_delete_reports_each_file() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/files.delete"))
+            .and(body_json(json!({"file": "F1"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ok": true})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/files.delete"))
+            .and(body_json(json!({"file": "F2"})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "file_not_found"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let ids = vec!["F1".to_string(), "F2".to_string()];
+        let report = file_delete(&client, &ids, true, true).await.unwrap();
+        assert_eq!(report.deleted, 1);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.results[1].error.as_deref(), Some("file_not_found"));
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_file_delete_requires_confirmation_non_interactive() {
+        let client = ApiClient::with_token("test_token".to_string());
+        let ids = vec!["F1".to_string(), "F2".to_string()];
+        let result = file_delete(&client, &ids, false, true).await;
+        match result {
+            Err(ApiError::NonInteractiveError(message)) => {
+                assert!(message.contains("slack-rs file delete F1 F2 --yes"))
+            }
+            other => panic!("expected NonInteractiveError, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Provenance footer for automated posts
+//!
+//! A profile (or the global config) can set `output.post_footer` to a
+//! template such as `sent by slack-rs on {hostname} for {job}`. The expanded
+//! footer is appended to every message `msg post`, `msg schedule` and
+//! `msg bulk` send with that profile, so recipients can tell which machine
+//! and job produced a bot message. Plain-text posts get the footer as a last
+//! line; posts with `--blocks` get it as a trailing `context` block.
+//!
+//! Placeholders:
+//! - `{hostname}`: `HOSTNAME`/`COMPUTERNAME`, else `/etc/hostname`
+//! - `{job}`: `SLACKRS_JOB`, else `GITHUB_JOB`, else `CI_JOB_NAME`, else `manual`
+//! - `{profile}`: the profile used to post
+//! - `{date}`: today's date in UTC (`YYYY-MM-DD`)
+//!
+//! Unknown placeholders are left as-is.
+
+use serde_json::{json, Value};
+
+/// Values substituted into a footer template
+#[derive(Debug, Clone, PartialEq)]
+pub struct FooterContext {
+    pub hostname: String,
+    pub job: String,
+    pub profile: String,
+    pub date: String,
+}
+
+impl FooterContext {
+    /// Context of the current process for `profile`
+    pub fn detect(profile: &str) -> Self {
+        Self::from_env(profile, |name| std::env::var(name).ok())
+    }
+
+    fn from_env(profile: &str, var: impl Fn(&str) -> Option<String>) -> Self {
+        let non_empty = |name: &str| var(name).filter(|v| !v.trim().is_empty());
+        let hostname = non_empty("HOSTNAME")
+            .or_else(|| non_empty("COMPUTERNAME"))
+            .or_else(|| {
+                std::fs::read_to_string("/etc/hostname")
+                    .ok()
+                    .map(|h| h.trim().to_string())
+                    .filter(|h| !h.is_empty())
+            })
+            .unwrap_or_else(|| "unknown".to_string());
+        let job = non_empty("SLACKRS_JOB")
+            .or_else(|| non_empty("GITHUB_JOB"))
+            .or_else(|| non_empty("CI_JOB_NAME"))
+            .unwrap_or_else(|| "manual".to_string());
+        Self {
+            hostname: hostname.trim().to_string(),
+            job: job.trim().to_string(),
+            profile: profile.to_string(),
+            date: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+/// Expand the placeholders of a footer template
+pub fn expand_footer(template: &str, ctx: &FooterContext) -> String {
+    template
+        .replace("{hostname}", &ctx.hostname)
+        .replace("{job}", &ctx.job)
+        .replace("{profile}", &ctx.profile)
+        .replace("{date}", &ctx.date)
+}
+
+/// Append an expanded footer to message text
+pub fn append_footer_text(text: &str, footer: &str) -> String {
+    if text.is_empty() {
+        footer.to_string()
+    } else {
+        format!("{}\n{}", text.trim_end(), footer)
+    }
+}
+
+/// Append an expanded footer to a block array as a `context` block
+pub fn append_footer_block(blocks: &Value, footer: &str) -> Value {
+    let mut blocks = blocks.clone();
+    if let Value::Array(ref mut items) = blocks {
+        items.push(json!({
+            "type": "context",
+            "elements": [{"type": "mrkdwn", "text": footer}],
+        }));
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn ctx() -> FooterContext {
+        FooterContext {
+            hostname: "ci-runner-1".to_string(),
+            job: "nightly".to_string(),
+            profile: "work".to_string(),
+            date: "2026-01-02".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_expand_footer() {
+        assert_eq!(
+            expand_footer("sent by slack-rs on {hostname} for {job}", &ctx()),
+            "sent by slack-rs on ci-runner-1 for nightly"
+        );
+        assert_eq!(
+            expand_footer("{profile} {date} {unknown}", &ctx()),
+            "work 2026-01-02 {unknown}"
+        );
+    }
+
+    #[test]
+    fn test_context_from_env() {
+        let env = HashMap::from([
+            ("HOSTNAME", "build-7"),
+            ("GITHUB_JOB", "deploy"),
+            ("CI_JOB_NAME", "other"),
+        ]);
+        let ctx = FooterContext::from_env("work", |name| env.get(name).map(|v| v.to_string()));
+        assert_eq!(ctx.hostname, "build-7");
+        assert_eq!(ctx.job, "deploy");
+        assert_eq!(ctx.profile, "work");
+
+        let env = HashMap::from([("SLACKRS_JOB", "backup"), ("GITHUB_JOB", "deploy")]);
+        let ctx = FooterContext::from_env("work", |name| env.get(name).map(|v| v.to_string()));
+        assert_eq!(ctx.job, "backup");
+
+        let ctx = FooterContext::from_env("work", |_| None);
+        assert_eq!(ctx.job, "manual");
+        assert!(!ctx.hostname.is_empty());
+    }
+
+    #[test]
+    fn test_append_footer() {
+        assert_eq!(append_footer_text("hello\n", "via ci"), "hello\nvia ci");
+        assert_eq!(append_footer_text("", "via ci"), "via ci");
+
+        let blocks = json!([{"type": "section", "text": {"type": "mrkdwn", "text": "hi"}}]);
+        let with_footer = append_footer_block(&blocks, "via ci");
+        assert_eq!(with_footer.as_array().unwrap().len(), 2);
+        assert_eq!(with_footer[1]["type"], "context");
+        assert_eq!(with_footer[1]["elements"][0]["text"], "via ci");
+    }
+}
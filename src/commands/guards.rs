@@ -1,6 +1,8 @@
 //! Safety guards for write and destructive operations
 
 use crate::api::ApiError;
+use crate::i18n::tf;
+use crate::profile::TokenType;
 use std::io::{self, Write};
 
 /// Check if write operations are allowed
@@ -72,10 +74,7 @@ pub fn confirm_destructive_with_hint(
 
     // In non-interactive mode, require --yes flag
     if non_interactive {
-        let base_message = format!(
-            "Operation requires confirmation: {}. Use --yes flag to confirm in non-interactive mode.",
-            operation
-        );
+        let base_message = tf("error.requires_confirmation", &[("operation", operation)]);
         let full_message = if let Some(hint_text) = hint {
             format!("{}\n{}", base_message, hint_text)
         } else {
@@ -84,7 +83,10 @@ pub fn confirm_destructive_with_hint(
         return Err(ApiError::NonInteractiveError(full_message));
     }
 
-    print!("Are you sure you want to {}? [y/N]: ", operation);
+    print!(
+        "{}",
+        tf("prompt.confirm_operation", &[("operation", operation)])
+    );
     io::stdout().flush().unwrap();
 
     let mut input = String::new();
@@ -98,11 +100,54 @@ pub fn confirm_destructive_with_hint(
     }
 }
 
+/// Reject a `--token-type` other than `user` for methods that only accept
+/// user tokens
+///
+/// `feature` names the commands in the message, e.g. "Reminders".
+pub fn require_user_token_type(feature: &str, requested: Option<TokenType>) -> Result<(), String> {
+    match requested {
+        Some(requested) if requested != TokenType::User => Err(format!(
+            "{} require a user token; --token-type {} is not supported",
+            feature, requested
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a resolved token that is not a user token (checked by prefix)
+///
+/// Tokens with an unknown prefix, e.g. from `SLACK_TOKEN`, are left to Slack.
+pub fn check_user_token(feature: &str, token: &str) -> Result<(), String> {
+    match TokenType::from_token(token) {
+        Some(actual) if actual != TokenType::User => Err(format!(
+            "{} require a user token ({}), but the resolved token is a {} token ({})",
+            feature,
+            TokenType::User.token_prefix(),
+            actual,
+            actual.token_prefix()
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serial_test::serial;
 
+    #[test]
+    fn test_check_user_token() {
+        assert!(require_user_token_type("Reminders", None).is_ok());
+        assert!(require_user_token_type("Reminders", Some(TokenType::User)).is_ok());
+        let err = require_user_token_type("Reminders", Some(TokenType::Bot)).unwrap_err();
+        assert!(err.contains("--token-type bot"));
+
+        assert!(check_user_token("Reminders", "xoxp-1").is_ok());
+        assert!(check_user_token("Reminders", "test-token").is_ok());
+        let err = check_user_token("Reminders", "xoxb-1").unwrap_err();
+        assert!(err.contains("bot token (xoxb-)"));
+    }
+
     #[test]
     #[serial(write_guard)]
     fn test_check_write_allowed_default() {
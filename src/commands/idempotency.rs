@@ -0,0 +1,234 @@
+//! Idempotency store management (`slack-rs idempotency ...`)
+//!
+//! Write commands run with `--idempotency-key` remember their response in
+//! the local idempotency store and replay it on a repeated call. These
+//! helpers make that store visible: list and show entries, and remove them
+//! (all, by key or by age) so a replayed operation can be forced to run
+//! again.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::idempotency::{IdempotencyEntry, IdempotencyError, IdempotencyStore};
+
+/// One stored operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    /// Scoped key (`team_id/user_id/method/idempotency_key`)
+    pub key: String,
+    pub team_id: String,
+    pub user_id: String,
+    pub method: String,
+    pub idempotency_key: String,
+    pub created_at: u64,
+    pub expires_at: u64,
+    /// Hash of the request parameters
+    pub fingerprint: String,
+    /// Stored response (`show` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<Value>,
+}
+
+impl IdempotencyRecord {
+    fn from_entry(key: &str, entry: &IdempotencyEntry, with_response: bool) -> Self {
+        let mut parts = key.splitn(4, '/');
+        let mut next = || parts.next().unwrap_or_default().to_string();
+        Self {
+            key: key.to_string(),
+            team_id: next(),
+            user_id: next(),
+            method: next(),
+            idempotency_key: next(),
+            created_at: entry.created_at,
+            expires_at: entry.expires_at,
+            fingerprint: entry.fingerprint.hash.clone(),
+            response: with_response.then(|| entry.response.clone()),
+        }
+    }
+}
+
+/// Whether a scoped key is `key` itself or ends with the idempotency key `key`
+fn key_matches(scoped: &str, key: &str) -> bool {
+    scoped == key || scoped.splitn(4, '/').nth(3) == Some(key)
+}
+
+/// Live entries, oldest first (responses omitted)
+pub fn list_records(store: &IdempotencyStore) -> Vec<IdempotencyRecord> {
+    store
+        .entries()
+        .into_iter()
+        .map(|(k, e)| IdempotencyRecord::from_entry(k, e, false))
+        .collect()
+}
+
+/// Entries matching a scoped key or a bare idempotency key, with responses
+pub fn find_records(store: &IdempotencyStore, key: &str) -> Vec<IdempotencyRecord> {
+    store
+        .entries()
+        .into_iter()
+        .filter(|(k, _)| key_matches(k, key))
+        .map(|(k, e)| IdempotencyRecord::from_entry(k, e, true))
+        .collect()
+}
+
+/// Remove entries, optionally only those matching `key` or created before `cutoff`
+///
+/// # Arguments
+/// * `key` - Scoped key or bare idempotency key
+/// * `cutoff` - UNIX time; entries created earlier are removed
+pub fn clear_entries(
+    store: &mut IdempotencyStore,
+    key: Option<&str>,
+    cutoff: Option<u64>,
+) -> Result<usize, IdempotencyError> {
+    store.remove_where(|k, e| {
+        key.is_none_or(|key| key_matches(k, key)) && cutoff.is_none_or(|c| e.created_at < c)
+    })
+}
+
+/// Print the live entries as JSON
+pub fn idempotency_list() -> Result<(), String> {
+    let store = IdempotencyStore::new().map_err(|e| e.to_string())?;
+    let records = list_records(&store);
+    let output = serde_json::json!({ "count": records.len(), "entries": records });
+    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    Ok(())
+}
+
+/// Print one entry, including its stored response, as JSON
+pub fn idempotency_show(key: &str) -> Result<(), String> {
+    let store = IdempotencyStore::new().map_err(|e| e.to_string())?;
+    let mut records = find_records(&store, key);
+    match records.len() {
+        0 => Err(format!("No idempotency entry found for '{}'", key)),
+        1 => {
+            let record = records.remove(0);
+            println!("{}", serde_json::to_string_pretty(&record).unwrap());
+            Ok(())
+        }
+        _ => Err(format!(
+            "'{}' matches {} entries; use the full key: {}",
+            key,
+            records.len(),
+            records
+                .iter()
+                .map(|r| r.key.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Remove entries and print how many were removed as JSON
+///
+/// # Arguments
+/// * `key` - Only remove entries for this key
+/// * `older_than` - Only remove entries older than this many seconds
+/// * `yes` - Skip confirmation when clearing everything
+/// * `non_interactive` - Whether running in non-interactive mode
+pub fn idempotency_clear(
+    key: Option<&str>,
+    older_than: Option<u64>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<(), String> {
+    if key.is_none() && older_than.is_none() {
+        crate::commands::guards::confirm_destructive_with_hint(
+            yes,
+            "clear all idempotency entries",
+            non_interactive,
+            Some("Use --yes to confirm, or narrow it down with --key or --older-than"),
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut store = IdempotencyStore::new().map_err(|e| e.to_string())?;
+    let removed = clear_entries(
+        &mut store,
+        key,
+        older_than.map(|secs| now.saturating_sub(secs)),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let output = serde_json::json!({ "removed": removed, "remaining": store.len() });
+    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    Ok(())
+}
+
+/// Drop expired entries (and the oldest ones beyond capacity), printing JSON
+pub fn idempotency_gc() -> Result<(), String> {
+    let mut store = IdempotencyStore::new().map_err(|e| e.to_string())?;
+    let removed = store.collect_garbage().map_err(|e| e.to_string())?;
+    let output = serde_json::json!({ "removed": removed, "remaining": store.len() });
+    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idempotency::{RequestFingerprint, ScopedKey};
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn store_with_entries(dir: &TempDir) -> IdempotencyStore {
+        let mut store = IdempotencyStore::with_path(dir.path().join("store.json")).unwrap();
+        for (method, key) in [
+            ("chat.postMessage", "deploy-42"),
+            ("files.completeUploadExternal", "deploy-42"),
+            ("chat.postMessage", "release/v1"),
+        ] {
+            let mut params = serde_json::Map::new();
+            params.insert("key".into(), json!(key));
+            store
+                .put(
+                    ScopedKey::new("T1".into(), "U1".into(), method.into(), key.into()),
+                    RequestFingerprint::from_params(&params),
+                    json!({"ok": true, "ts": "1.0"}),
+                )
+                .unwrap();
+        }
+        store
+    }
+
+    #[test]
+    fn test_list_and_find_records() {
+        let dir = TempDir::new().unwrap();
+        let store = store_with_entries(&dir);
+
+        let records = list_records(&store);
+        assert_eq!(records.len(), 3);
+        assert!(records.iter().all(|r| r.response.is_none()));
+
+        let slash = find_records(&store, "release/v1");
+        assert_eq!(slash.len(), 1);
+        assert_eq!(slash[0].method, "chat.postMessage");
+        assert_eq!(slash[0].idempotency_key, "release/v1");
+        assert_eq!(slash[0].response, Some(json!({"ok": true, "ts": "1.0"})));
+
+        assert_eq!(find_records(&store, "deploy-42").len(), 2);
+        assert_eq!(
+            find_records(&store, "T1/U1/chat.postMessage/deploy-42").len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_clear_entries() {
+        let dir = TempDir::new().unwrap();
+        let mut store = store_with_entries(&dir);
+
+        // Nothing was created before the epoch
+        assert_eq!(clear_entries(&mut store, None, Some(0)).unwrap(), 0);
+        assert_eq!(
+            clear_entries(&mut store, Some("deploy-42"), None).unwrap(),
+            2
+        );
+        assert_eq!(clear_entries(&mut store, None, None).unwrap(), 1);
+        assert!(store.is_empty());
+    }
+}
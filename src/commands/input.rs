@@ -0,0 +1,489 @@
+//! Stdin JSON parameters for write commands (`--input json`)
+//!
+//! Programmatic callers can pass the whole parameter object on stdin instead
+//! of building a shell command line:
+//!
+//! ```text
+//! echo '{"channel":"C123","text":"Deploy done","thread_ts":"1700000000.000100"}' \
+//!   | slack-rs msg post --input json --yes
+//! ```
+//!
+//! The object is checked against the command's input schema (unknown fields
+//! and wrong types are rejected) and turned into regular arguments, so the
+//! command itself runs unchanged. Values given on the command line win over
+//! the same field in the JSON.
+//!
+//! `--stdin` is the pipe-friendly variant: it also covers `api call`, where
+//! every field becomes a `key=value` parameter, and accepts a stream of
+//! objects (JSONL), running the command once per object:
+//!
+//! ```text
+//! jq -c '.[] | {channel, text}' rows.json | slack-rs api call chat.postMessage --stdin
+//! ```
+
+use serde_json::Value;
+
+/// How a field maps onto the command line
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FieldKind {
+    /// Leading positional argument (string)
+    Positional,
+    /// `--flag=<string>`
+    Text(&'static str),
+    /// `--flag=<non-negative integer>`
+    Integer(&'static str),
+    /// `--flag` when `true`
+    Switch(&'static str),
+    /// `--flag=a,b`; a string or an array of strings
+    List(&'static str),
+    /// `--flag=<json>`; any value, strings are passed as-is
+    Json(&'static str),
+}
+
+use FieldKind::*;
+
+/// JSON field name and how it maps onto the command line
+type InputField = (&'static str, FieldKind);
+
+/// Input schemas: `(command, subcommand, fields)`, positionals in order
+const INPUT_SCHEMAS: &[(&str, &str, &[InputField])] = &[
+    (
+        "msg",
+        "post",
+        &[
+            ("channel", Positional),
+            ("text", Positional),
+            ("thread_ts", Text("--thread-ts")),
+            ("reply_broadcast", Switch("--reply-broadcast")),
+            ("blocks", Json("--blocks")),
+            ("split", Switch("--split")),
+            ("split_limit", Integer("--split-limit")),
+            ("auto_snippet", Switch("--auto-snippet")),
+            ("from", Text("--from")),
+            ("markdown", Switch("--markdown")),
+            ("resolve_mentions", Switch("--resolve-mentions")),
+            ("pin", Switch("--pin")),
+            ("react", List("--react")),
+            ("idempotency_key", Text("--idempotency-key")),
+            ("unique_per", Text("--unique-per")),
+        ],
+    ),
+    (
+        "msg",
+        "update",
+        &[
+            ("channel", Positional),
+            ("ts", Positional),
+            ("text", Positional),
+            ("from", Text("--from")),
+            ("markdown", Switch("--markdown")),
+            ("idempotency_key", Text("--idempotency-key")),
+        ],
+    ),
+    (
+        "react",
+        "add",
+        &[
+            ("channel", Positional),
+            ("ts", Positional),
+            ("emoji", Positional),
+            ("check_emoji", Switch("--check-emoji")),
+            ("idempotency_key", Text("--idempotency-key")),
+        ],
+    ),
+    (
+        "react",
+        "remove",
+        &[
+            ("channel", Positional),
+            ("ts", Positional),
+            ("emoji", Positional),
+            ("check_emoji", Switch("--check-emoji")),
+            ("idempotency_key", Text("--idempotency-key")),
+        ],
+    ),
+];
+
+/// Commands that accept `--input json`, as `command subcommand`
+pub fn input_commands() -> Vec<String> {
+    INPUT_SCHEMAS
+        .iter()
+        .map(|(command, sub, _)| format!("{} {}", command, sub))
+        .collect()
+}
+
+fn has_cli_flag(args: &[String], flag: &str) -> bool {
+    args.iter()
+        .any(|arg| arg == flag || arg.starts_with(&format!("{}=", flag)))
+}
+
+fn expect_string<'a>(name: &str, value: &'a Value) -> Result<&'a str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("'{}' must be a string", name))
+}
+
+/// Merge a JSON parameter object into the arguments of a write command
+///
+/// Positionals given on the command line come first; JSON positionals fill
+/// the ones after them, in schema order (omitted ones are left to the
+/// session context or the command's usage error). JSON flags are appended
+/// unless the same flag is already on the command line.
+///
+/// # Errors
+/// The command has no input schema, the input is not an object, or a field
+/// is unknown or has the wrong type.
+pub fn apply_json_input(args: Vec<String>, input: &Value) -> Result<Vec<String>, String> {
+    let (command, sub) = match (args.get(1), args.get(2)) {
+        (Some(command), Some(sub)) => (command.as_str(), sub.as_str()),
+        _ => return Err("--input json requires a command".to_string()),
+    };
+    let fields = INPUT_SCHEMAS
+        .iter()
+        .find(|(c, s, _)| *c == command && *s == sub)
+        .map(|(_, _, fields)| *fields)
+        .ok_or_else(|| {
+            format!(
+                "'{} {}' does not accept --input json (supported: {})",
+                command,
+                sub,
+                input_commands().join(", ")
+            )
+        })?;
+    let object = input
+        .as_object()
+        .ok_or_else(|| "input must be a JSON object".to_string())?;
+
+    if let Some(unknown) = object
+        .keys()
+        .find(|key| !fields.iter().any(|(name, _)| name == key))
+    {
+        return Err(format!(
+            "unknown field '{}' for {} {} (valid: {})",
+            unknown,
+            command,
+            sub,
+            fields
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    let given = args[3..]
+        .iter()
+        .take_while(|arg| !arg.starts_with("--"))
+        .count();
+    let mut positionals = Vec::new();
+    let mut flags = Vec::new();
+    let mut position = 0;
+    for (name, kind) in fields {
+        let value = object.get(*name).filter(|v| !v.is_null());
+        match (kind, value) {
+            (Positional, value) => {
+                if let (true, Some(value)) = (position >= given, value) {
+                    positionals.push(expect_string(name, value)?.to_string());
+                }
+                position += 1;
+            }
+            (_, None) => {}
+            (Text(flag), Some(value)) => {
+                let value = expect_string(name, value)?;
+                if !has_cli_flag(&args, flag) {
+                    flags.push(format!("{}={}", flag, value));
+                }
+            }
+            (Integer(flag), Some(value)) => {
+                let value = value
+                    .as_u64()
+                    .ok_or_else(|| format!("'{}' must be a non-negative integer", name))?;
+                if !has_cli_flag(&args, flag) {
+                    flags.push(format!("{}={}", flag, value));
+                }
+            }
+            (Switch(flag), Some(value)) => {
+                let value = value
+                    .as_bool()
+                    .ok_or_else(|| format!("'{}' must be a boolean", name))?;
+                if value && !has_cli_flag(&args, flag) {
+                    flags.push(flag.to_string());
+                }
+            }
+            (List(flag), Some(value)) => {
+                let items: Vec<String> = match value {
+                    Value::String(item) => Some(vec![item.clone()]),
+                    Value::Array(items) => items
+                        .iter()
+                        .map(|item| item.as_str().map(str::to_string))
+                        .collect(),
+                    _ => None,
+                }
+                .ok_or_else(|| format!("'{}' must be a string or an array of strings", name))?;
+                if !has_cli_flag(&args, flag) {
+                    flags.push(format!("{}={}", flag, items.join(",")));
+                }
+            }
+            (Json(flag), Some(value)) => {
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                if !has_cli_flag(&args, flag) {
+                    flags.push(format!("{}={}", flag, value));
+                }
+            }
+        }
+    }
+
+    let mut merged = args[..3 + given].to_vec();
+    merged.extend(positionals);
+    merged.extend(args[3 + given..].iter().cloned());
+    merged.extend(flags);
+    Ok(merged)
+}
+
+/// Merge a JSON object into `api call` arguments as `key=value` parameters
+///
+/// Strings are passed as-is, other values (numbers, arrays such as
+/// `blocks`) as JSON text. Parameters given on the command line win.
+pub fn apply_api_call_input(args: Vec<String>, input: &Value) -> Result<Vec<String>, String> {
+    if args.get(3).is_none_or(|method| method.starts_with("--")) {
+        return Err("api call --stdin requires a method".to_string());
+    }
+    let object = input
+        .as_object()
+        .ok_or_else(|| "input must be a JSON object".to_string())?;
+
+    let mut merged = args;
+    for (key, value) in object {
+        let prefix = format!("{}=", key);
+        if value.is_null() || merged[4..].iter().any(|arg| arg.starts_with(&prefix)) {
+            continue;
+        }
+        let value = match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        merged.push(format!("{}{}", prefix, value));
+    }
+    Ok(merged)
+}
+
+/// Merge one `--stdin` object into the arguments of `api call` or a write command
+pub fn apply_stdin_input(args: Vec<String>, input: &Value) -> Result<Vec<String>, String> {
+    let is_api_call = args.get(1).map(String::as_str) == Some("api")
+        && args.get(2).map(String::as_str) == Some("call");
+    if is_api_call {
+        apply_api_call_input(args, input)
+    } else {
+        apply_json_input(args, input).map_err(|e| e.replace("--input json", "--stdin"))
+    }
+}
+
+/// Result of running a stream of `--stdin` objects
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StdinRunSummary {
+    /// Runs that exited unsuccessfully
+    pub failed: usize,
+    /// Runs not started because the `--max-writes` budget was used up
+    pub skipped: usize,
+}
+
+/// Run each `--stdin` object in order, sharing one `--max-writes` budget
+///
+/// `run` executes one argument list allowed `remaining` writes (`None` =
+/// unlimited) and returns whether it succeeded and how many writes it made.
+/// Once the budget is used up the remaining objects are skipped, so the
+/// whole stream never writes more than the limit.
+pub fn run_stdin_objects<F>(
+    runs: &[Vec<String>],
+    max_writes: Option<u64>,
+    mut run: F,
+) -> StdinRunSummary
+where
+    F: FnMut(usize, &[String], Option<u64>) -> (bool, u64),
+{
+    let mut summary = StdinRunSummary::default();
+    let mut remaining = max_writes;
+    for (index, args) in runs.iter().enumerate() {
+        if remaining == Some(0) {
+            summary.skipped = runs.len() - index;
+            break;
+        }
+        let (success, writes) = run(index, args, remaining);
+        if !success {
+            summary.failed += 1;
+        }
+        remaining = remaining.map(|left| left.saturating_sub(writes));
+    }
+    summary
+}
+
+/// Parse stdin as one JSON object or a stream of objects (JSONL)
+pub fn parse_json_stream(content: &str) -> Result<Vec<Value>, String> {
+    let mut objects = Vec::new();
+    for (index, value) in serde_json::Deserializer::from_str(content)
+        .into_iter::<Value>()
+        .enumerate()
+    {
+        let value = value.map_err(|e| format!("invalid JSON in object {}: {}", index + 1, e))?;
+        if !value.is_object() {
+            return Err(format!("object {} is not a JSON object", index + 1));
+        }
+        objects.push(value);
+    }
+    if objects.is_empty() {
+        return Err("no JSON object on stdin".to_string());
+    }
+    Ok(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn args(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_run_stdin_objects_shares_max_writes() {
+        let runs = vec![
+            args(&["slack-rs", "msg", "post", "C1", "first"]),
+            args(&["slack-rs", "msg", "post", "C1", "second"]),
+        ];
+        let mut started = Vec::new();
+        let summary = run_stdin_objects(&runs, Some(1), |index, _, remaining| {
+            started.push((index, remaining));
+            (true, 1)
+        });
+        assert_eq!(started, vec![(0, Some(1))]);
+        assert_eq!(
+            summary,
+            StdinRunSummary {
+                failed: 0,
+                skipped: 1
+            }
+        );
+
+        // Read-only runs leave the budget to the next object
+        let mut started = Vec::new();
+        let summary = run_stdin_objects(&runs, Some(1), |index, _, remaining| {
+            started.push((index, remaining));
+            (index == 0, 0)
+        });
+        assert_eq!(started, vec![(0, Some(1)), (1, Some(1))]);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.skipped, 0);
+
+        let summary = run_stdin_objects(&runs, None, |_, _, remaining| {
+            assert_eq!(remaining, None);
+            (true, 5)
+        });
+        assert_eq!(summary, StdinRunSummary::default());
+    }
+
+    #[test]
+    fn test_apply_json_input_msg_post() {
+        let input = json!({
+            "channel": "C123",
+            "text": "it's \"done\"",
+            "thread_ts": "1700000000.000100",
+            "reply_broadcast": true,
+            "pin": false,
+            "react": ["eyes", "white_check_mark"],
+            "blocks": [{"type": "divider"}],
+        });
+        let merged = apply_json_input(args(&["slack-rs", "msg", "post", "--yes"]), &input).unwrap();
+        assert_eq!(
+            merged,
+            args(&[
+                "slack-rs",
+                "msg",
+                "post",
+                "C123",
+                "it's \"done\"",
+                "--yes",
+                "--thread-ts=1700000000.000100",
+                "--reply-broadcast",
+                r#"--blocks=[{"type":"divider"}]"#,
+                "--react=eyes,white_check_mark",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_apply_json_input_cli_wins() {
+        let input =
+            json!({"channel": "C999", "ts": "1.0", "emoji": "eyes", "idempotency_key": "k1"});
+        let merged = apply_json_input(
+            args(&["slack-rs", "react", "add", "C123", "--idempotency-key=k2"]),
+            &input,
+        )
+        .unwrap();
+        assert_eq!(
+            merged,
+            args(&[
+                "slack-rs",
+                "react",
+                "add",
+                "C123",
+                "1.0",
+                "eyes",
+                "--idempotency-key=k2"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_apply_json_input_validation() {
+        let post = args(&["slack-rs", "msg", "post"]);
+        assert!(apply_json_input(post.clone(), &json!(["C1"])).is_err());
+        assert!(apply_json_input(post.clone(), &json!({"chanel": "C1"}))
+            .unwrap_err()
+            .contains("unknown field 'chanel'"));
+        assert!(apply_json_input(post.clone(), &json!({"thread_ts": 1.5})).is_err());
+        assert!(apply_json_input(post.clone(), &json!({"split_limit": -1})).is_err());
+        assert!(apply_json_input(post, &json!({"pin": "yes"})).is_err());
+        assert!(
+            apply_json_input(args(&["slack-rs", "conv", "list"]), &json!({}))
+                .unwrap_err()
+                .contains("does not accept")
+        );
+    }
+
+    #[test]
+    fn test_stdin_input_api_call_and_stream() {
+        let objects = parse_json_stream(
+            "{\"channel\":\"C1\",\"text\":\"a\"}\n{\"channel\":\"C2\",\"text\":\"b\",\"blocks\":[{\"type\":\"divider\"}],\"unfurl_links\":false}\n",
+        )
+        .unwrap();
+        assert_eq!(objects.len(), 2);
+        assert!(parse_json_stream("").is_err());
+        assert!(parse_json_stream("[1]").is_err());
+
+        let base = args(&["slack-rs", "api", "call", "chat.postMessage", "channel=C9"]);
+        let merged = apply_stdin_input(base.clone(), &objects[1]).unwrap();
+        assert_eq!(
+            merged,
+            args(&[
+                "slack-rs",
+                "api",
+                "call",
+                "chat.postMessage",
+                "channel=C9",
+                "blocks=[{\"type\":\"divider\"}]",
+                "text=b",
+                "unfurl_links=false",
+            ])
+        );
+        assert!(apply_stdin_input(args(&["slack-rs", "api", "call"]), &objects[0]).is_err());
+
+        let merged = apply_stdin_input(args(&["slack-rs", "msg", "post"]), &objects[0]).unwrap();
+        assert_eq!(merged, args(&["slack-rs", "msg", "post", "C1", "a"]));
+        let err = apply_stdin_input(args(&["slack-rs", "conv", "list"]), &objects[0]).unwrap_err();
+        assert!(err.contains("does not accept --stdin"));
+    }
+}
@@ -0,0 +1,520 @@
+//! Single message inspection (`msg inspect <permalink>`)
+//!
+//! Resolves a permalink to its message and summarizes what is in it: the
+//! text, blocks rendered as readable lines, reactions, thread stats and
+//! attached files. The raw message JSON is kept in the report for `--raw`.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, PlannedRequest};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Build the request that fetches one message
+///
+/// Top-level messages come from `conversations.history`; replies are only
+/// returned by `conversations.replies` on their thread.
+pub fn plan_message_fetch(channel: &str, ts: &str, thread_ts: Option<&str>) -> PlannedRequest {
+    let request = match thread_ts.filter(|thread| *thread != ts) {
+        Some(thread) => PlannedRequest::new(ApiMethod::ConversationsReplies).param("ts", thread),
+        None => PlannedRequest::new(ApiMethod::ConversationsHistory),
+    };
+    request
+        .param("channel", channel)
+        .param("oldest", ts)
+        .param("latest", ts)
+        .param("inclusive", true)
+        .param("limit", 1)
+}
+
+/// Reaction on the message
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InspectReaction {
+    pub name: String,
+    pub count: u64,
+    pub users: Vec<String>,
+}
+
+/// Thread position and activity
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InspectThread {
+    pub thread_ts: String,
+    /// Whether the message is the thread root
+    pub is_root: bool,
+    /// Root only: number of replies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<u64>,
+    /// Root only: distinct repliers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_users_count: Option<u64>,
+    /// Root only: ts of the latest reply
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_reply: Option<String>,
+}
+
+/// File attached to the message
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InspectFile {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filetype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permalink: Option<String>,
+}
+
+/// Summary of one message
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InspectReport {
+    pub channel: String,
+    pub ts: String,
+    /// Author user ID (`None` for bot and system messages)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    pub text: String,
+    /// ts of the last edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited: Option<String>,
+    /// Blocks rendered as readable lines
+    pub blocks: Vec<String>,
+    pub reactions: Vec<InspectReaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<InspectThread>,
+    pub files: Vec<InspectFile>,
+    /// Message as returned by Slack
+    pub message: Value,
+}
+
+fn str_at(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
+/// `text` of a text object
+fn text_of(value: Option<&Value>) -> Option<&str> {
+    value.and_then(|t| t.get("text")).and_then(|t| t.as_str())
+}
+
+/// Render Block Kit blocks as readable lines
+///
+/// Unknown block types are shown as `[type]` so nothing silently disappears.
+pub fn render_blocks(blocks: &Value) -> Vec<String> {
+    let mut lines = Vec::new();
+    for block in blocks.as_array().into_iter().flatten() {
+        match block.get("type").and_then(|t| t.as_str()).unwrap_or("") {
+            "header" => lines.push(format!("# {}", text_of(block.get("text")).unwrap_or(""))),
+            "section" => {
+                if let Some(text) = text_of(block.get("text")) {
+                    lines.extend(text.lines().map(str::to_string));
+                }
+                for field in block
+                    .get("fields")
+                    .and_then(|f| f.as_array())
+                    .into_iter()
+                    .flatten()
+                {
+                    if let Some(text) = text_of(Some(field)) {
+                        lines.push(format!("  {}", text));
+                    }
+                }
+                if let Some(accessory) = block.get("accessory") {
+                    lines.push(format!("  [{}]", element_label(accessory)));
+                }
+            }
+            "context" => {
+                let parts: Vec<String> = block
+                    .get("elements")
+                    .and_then(|e| e.as_array())
+                    .into_iter()
+                    .flatten()
+                    .map(|e| match text_of(Some(e)) {
+                        Some(text) => text.to_string(),
+                        None => format!("[{}]", element_label(e)),
+                    })
+                    .collect();
+                lines.push(format!("> {}", parts.join(" | ")));
+            }
+            "divider" => lines.push("---".to_string()),
+            "image" => lines.push(format!(
+                "[image: {}]",
+                str_at(block, "alt_text").unwrap_or_default()
+            )),
+            "actions" => {
+                let labels: Vec<String> = block
+                    .get("elements")
+                    .and_then(|e| e.as_array())
+                    .into_iter()
+                    .flatten()
+                    .map(element_label)
+                    .collect();
+                lines.push(format!("[actions: {}]", labels.join(", ")));
+            }
+            "rich_text" => {
+                for element in block
+                    .get("elements")
+                    .and_then(|e| e.as_array())
+                    .into_iter()
+                    .flatten()
+                {
+                    render_rich_text(element, &mut lines);
+                }
+            }
+            other => lines.push(format!("[{}]", other)),
+        }
+    }
+    lines
+}
+
+/// Label of an interactive or image element
+fn element_label(element: &Value) -> String {
+    let kind = element.get("type").and_then(|t| t.as_str()).unwrap_or("");
+    let text = text_of(element.get("text"))
+        .map(|s| s.to_string())
+        .or_else(|| str_at(element, "alt_text"));
+    match text {
+        Some(text) => format!("{}: {}", kind, text),
+        None => kind.to_string(),
+    }
+}
+
+/// Append the lines of one `rich_text` container element
+fn render_rich_text(element: &Value, lines: &mut Vec<String>) {
+    let inline = |element: &Value| -> String {
+        element
+            .get("elements")
+            .and_then(|e| e.as_array())
+            .into_iter()
+            .flatten()
+            .map(render_rich_text_inline)
+            .collect()
+    };
+    match element.get("type").and_then(|t| t.as_str()).unwrap_or("") {
+        "rich_text_section" => lines.extend(inline(element).lines().map(str::to_string)),
+        "rich_text_preformatted" => {
+            lines.push("```".to_string());
+            lines.extend(inline(element).lines().map(str::to_string));
+            lines.push("```".to_string());
+        }
+        "rich_text_quote" => lines.extend(inline(element).lines().map(|l| format!("> {}", l))),
+        "rich_text_list" => {
+            let ordered = element.get("style").and_then(|s| s.as_str()) == Some("ordered");
+            let items = element.get("elements").and_then(|e| e.as_array());
+            for (i, item) in items.into_iter().flatten().enumerate() {
+                let marker = if ordered {
+                    format!("{}.", i + 1)
+                } else {
+                    "-".to_string()
+                };
+                lines.push(format!("{} {}", marker, inline(item)));
+            }
+        }
+        other => lines.push(format!("[{}]", other)),
+    }
+}
+
+/// Text of one inline `rich_text` element, in mrkdwn-like notation
+fn render_rich_text_inline(element: &Value) -> String {
+    let field = |key: &str| str_at(element, key).unwrap_or_default();
+    match element.get("type").and_then(|t| t.as_str()).unwrap_or("") {
+        "text" => field("text"),
+        "link" => match str_at(element, "text") {
+            Some(text) => format!("<{}|{}>", field("url"), text),
+            None => format!("<{}>", field("url")),
+        },
+        "user" => format!("<@{}>", field("user_id")),
+        "channel" => format!("<#{}>", field("channel_id")),
+        "usergroup" => format!("<!subteam^{}>", field("usergroup_id")),
+        "broadcast" => format!("<!{}>", field("range")),
+        "emoji" => format!(":{}:", field("name")),
+        other => format!("[{}]", other),
+    }
+}
+
+/// Build the report for a fetched message
+pub fn build_inspect_report(channel: &str, message: &Value) -> InspectReport {
+    let ts = str_at(message, "ts").unwrap_or_default();
+    let u64_at = |key: &str| message.get(key).and_then(|v| v.as_u64());
+
+    let reactions = message
+        .get("reactions")
+        .and_then(|r| r.as_array())
+        .into_iter()
+        .flatten()
+        .map(|reaction| InspectReaction {
+            name: str_at(reaction, "name").unwrap_or_default(),
+            count: reaction.get("count").and_then(|c| c.as_u64()).unwrap_or(0),
+            users: reaction
+                .get("users")
+                .and_then(|u| u.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|u| u.as_str().map(str::to_string))
+                .collect(),
+        })
+        .collect();
+
+    let thread = str_at(message, "thread_ts").map(|thread_ts| {
+        let is_root = thread_ts == ts;
+        InspectThread {
+            is_root,
+            reply_count: u64_at("reply_count").filter(|_| is_root),
+            reply_users_count: u64_at("reply_users_count").filter(|_| is_root),
+            latest_reply: str_at(message, "latest_reply").filter(|_| is_root),
+            thread_ts,
+        }
+    });
+
+    let files = message
+        .get("files")
+        .and_then(|f| f.as_array())
+        .into_iter()
+        .flatten()
+        .map(|file| InspectFile {
+            id: str_at(file, "id").unwrap_or_default(),
+            name: str_at(file, "name")
+                .or_else(|| str_at(file, "title"))
+                .unwrap_or_default(),
+            filetype: str_at(file, "filetype"),
+            size: file.get("size").and_then(|s| s.as_u64()),
+            permalink: str_at(file, "permalink"),
+        })
+        .collect();
+
+    InspectReport {
+        channel: channel.to_string(),
+        ts,
+        user: str_at(message, "user"),
+        bot_id: str_at(message, "bot_id"),
+        subtype: str_at(message, "subtype"),
+        text: str_at(message, "text").unwrap_or_default(),
+        edited: message.get("edited").and_then(|e| str_at(e, "ts")),
+        blocks: message.get("blocks").map(render_blocks).unwrap_or_default(),
+        reactions,
+        thread,
+        files,
+        message: message.clone(),
+    }
+}
+
+/// Fetch one message and build its report
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `ts` - Message timestamp
+/// * `thread_ts` - Thread root when the message is a reply
+///
+/// # Returns
+/// * `Ok(InspectReport)` for the message
+/// * `Err(ApiError)` if the message is gone or a request fails
+pub async fn msg_inspect(
+    client: &ApiClient,
+    channel: &str,
+    ts: &str,
+    thread_ts: Option<&str>,
+) -> Result<InspectReport, ApiError> {
+    let response = client
+        .execute_planned(plan_message_fetch(channel, ts, thread_ts))
+        .await?;
+    if !response.ok {
+        return Err(ApiError::SlackError(
+            response
+                .error
+                .unwrap_or_else(|| "unknown_error".to_string()),
+        ));
+    }
+    let message = response
+        .data
+        .get("messages")
+        .and_then(|m| m.as_array())
+        .and_then(|messages| {
+            messages
+                .iter()
+                .find(|m| m.get("ts").and_then(|t| t.as_str()) == Some(ts))
+        })
+        .ok_or_else(|| ApiError::SlackError("message_not_found".to_string()))?;
+
+    Ok(build_inspect_report(channel, message))
+}
+
+/// Render the report as text
+pub fn format_inspect_text(report: &InspectReport) -> String {
+    let author = match (&report.user, &report.bot_id) {
+        (Some(user), _) => user.clone(),
+        (None, Some(bot)) => format!("bot {}", bot),
+        (None, None) => "(unknown)".to_string(),
+    };
+    let mut out = format!("Message {} in {}\n", report.ts, report.channel);
+    out.push_str(&format!("  Author:  {}\n", author));
+    if let Some(subtype) = &report.subtype {
+        out.push_str(&format!("  Subtype: {}\n", subtype));
+    }
+    if let Some(edited) = &report.edited {
+        out.push_str(&format!("  Edited:  {}\n", edited));
+    }
+
+    out.push_str("\nText:\n");
+    for line in report.text.lines() {
+        out.push_str(&format!("  {}\n", line));
+    }
+
+    if !report.blocks.is_empty() {
+        out.push_str("\nBlocks:\n");
+        for line in &report.blocks {
+            out.push_str(&format!("  {}\n", line));
+        }
+    }
+
+    out.push_str(&format!("\nReactions ({}):\n", report.reactions.len()));
+    for reaction in &report.reactions {
+        out.push_str(&format!(
+            "  :{}: x{} ({})\n",
+            reaction.name,
+            reaction.count,
+            reaction.users.join(", ")
+        ));
+    }
+
+    match &report.thread {
+        Some(thread) if thread.is_root => out.push_str(&format!(
+            "\nThread: root, {} repl{} from {} user(s), latest {}\n",
+            thread.reply_count.unwrap_or(0),
+            if thread.reply_count == Some(1) {
+                "y"
+            } else {
+                "ies"
+            },
+            thread.reply_users_count.unwrap_or(0),
+            thread.latest_reply.as_deref().unwrap_or("-")
+        )),
+        Some(thread) => out.push_str(&format!("\nThread: reply in {}\n", thread.thread_ts)),
+        None => out.push_str("\nThread: none\n"),
+    }
+
+    out.push_str(&format!("\nFiles ({}):\n", report.files.len()));
+    for file in &report.files {
+        let mut line = format!("  {} {}", file.id, file.name);
+        if let Some(filetype) = &file.filetype {
+            line.push_str(&format!(" [{}]", filetype));
+        }
+        if let Some(size) = file.size {
+            line.push_str(&format!(" {} bytes", size));
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_plan_message_fetch() {
+        let plan = plan_message_fetch("C1", "1700000000.000100", None);
+        assert_eq!(plan.method, ApiMethod::ConversationsHistory);
+        assert_eq!(plan.params["latest"], "1700000000.000100");
+        assert_eq!(plan.params["inclusive"], true);
+
+        let plan = plan_message_fetch("C1", "1700000000.000100", Some("1700000000.000100"));
+        assert_eq!(plan.method, ApiMethod::ConversationsHistory);
+
+        let plan = plan_message_fetch("C1", "1700000000.000200", Some("1700000000.000100"));
+        assert_eq!(plan.method, ApiMethod::ConversationsReplies);
+        assert_eq!(plan.params["ts"], "1700000000.000100");
+        assert_eq!(plan.params["oldest"], "1700000000.000200");
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        let blocks = json!([
+            {"type": "header", "text": {"type": "plain_text", "text": "Deploy"}},
+            {"type": "section", "text": {"type": "mrkdwn", "text": "*done*\nall green"},
+             "fields": [{"type": "mrkdwn", "text": "env: prod"}],
+             "accessory": {"type": "button", "text": {"type": "plain_text", "text": "Open"}}},
+            {"type": "divider"},
+            {"type": "context", "elements": [{"type": "mrkdwn", "text": "by bot"},
+                                             {"type": "image", "alt_text": "logo"}]},
+            {"type": "actions", "elements": [{"type": "button", "text": {"type": "plain_text", "text": "Approve"}}]},
+            {"type": "rich_text", "elements": [
+                {"type": "rich_text_section", "elements": [
+                    {"type": "text", "text": "hi "}, {"type": "user", "user_id": "U1"},
+                    {"type": "emoji", "name": "wave"}]},
+                {"type": "rich_text_list", "style": "ordered", "elements": [
+                    {"type": "rich_text_section", "elements": [
+                        {"type": "link", "url": "https://example.com", "text": "docs"}]}]},
+                {"type": "rich_text_preformatted", "elements": [{"type": "text", "text": "ls"}]}
+            ]},
+            {"type": "video"}
+        ]);
+        assert_eq!(
+            render_blocks(&blocks),
+            vec![
+                "# Deploy",
+                "*done*",
+                "all green",
+                "  env: prod",
+                "  [button: Open]",
+                "---",
+                "> by bot | [image: logo]",
+                "[actions: button: Approve]",
+                "hi <@U1>:wave:",
+                "1. <https://example.com|docs>",
+                "```",
+                "ls",
+                "```",
+                "[video]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_report_and_text() {
+        let message = json!({
+            "type": "message",
+            "user": "U1",
+            "ts": "1700000000.000100",
+            "thread_ts": "1700000000.000100",
+            "reply_count": 3,
+            "reply_users_count": 2,
+            "latest_reply": "1700000100.000000",
+            "text": "release notes",
+            "edited": {"user": "U1", "ts": "1700000050.000000"},
+            "reactions": [{"name": "tada", "count": 2, "users": ["U2", "U3"]}],
+            "files": [{"id": "F1", "name": "notes.pdf", "filetype": "pdf", "size": 1024}]
+        });
+        let report = build_inspect_report("C1", &message);
+        assert_eq!(report.user.as_deref(), Some("U1"));
+        assert_eq!(report.edited.as_deref(), Some("1700000050.000000"));
+        assert_eq!(report.reactions[0].users, vec!["U2", "U3"]);
+        let thread = report.thread.as_ref().unwrap();
+        assert!(thread.is_root);
+        assert_eq!(thread.reply_count, Some(3));
+        assert_eq!(report.files[0].size, Some(1024));
+        assert_eq!(report.message, message);
+
+        let text = format_inspect_text(&report);
+        assert!(text.contains("Message 1700000000.000100 in C1"));
+        assert!(text.contains(":tada: x2 (U2, U3)"));
+        assert!(text.contains("Thread: root, 3 replies from 2 user(s), latest 1700000100.000000"));
+        assert!(text.contains("F1 notes.pdf [pdf] 1024 bytes"));
+
+        let reply = json!({"bot_id": "B1", "ts": "2.000000", "thread_ts": "1.000000",
+                           "reply_count": 9, "text": ""});
+        let report = build_inspect_report("C1", &reply);
+        let thread = report.thread.as_ref().unwrap();
+        assert!(!thread.is_root);
+        assert_eq!(thread.reply_count, None);
+        let text = format_inspect_text(&report);
+        assert!(text.contains("Author:  bot B1"));
+        assert!(text.contains("Thread: reply in 1.000000"));
+    }
+}
@@ -0,0 +1,427 @@
+//! Key-value store backed by a Slack channel
+//!
+//! Each key is a single message in a designated (usually private) channel.
+//! The value lives in the message metadata (`event_type: slack_rs_kv`) with
+//! a version counter, and the message text mirrors it for humans:
+//!
+//! ```text
+//! kv mybot/last-deploy (v3)
+//! ```
+//!
+//! `set` updates the existing message in place, so the channel holds one
+//! message per key. `--if-version` gives optimistic locking: the write is
+//! refused when the stored version differs, and after every write the entry
+//! is read back so a concurrent writer is reported as a conflict. Slack has
+//! no compare-and-swap, so this narrows the race rather than closing it.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::conv::plan_conv_history;
+use crate::commands::guards::check_write_allowed;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Metadata event type identifying key-value messages
+pub const KV_EVENT_TYPE: &str = "slack_rs_kv";
+/// Namespace used when none is given
+pub const DEFAULT_NAMESPACE: &str = "default";
+/// Environment variable naming the store channel
+pub const KV_CHANNEL_ENV: &str = "SLACK_RS_KV_CHANNEL";
+
+/// Key-value store errors
+#[derive(Debug, Error)]
+pub enum KvError {
+    #[error("No store channel: pass --channel or set {KV_CHANNEL_ENV}")]
+    MissingChannel,
+    #[error("Value is not valid JSON: {0}")]
+    InvalidValue(String),
+    #[error("Key not found: {namespace}/{key}")]
+    NotFound { namespace: String, key: String },
+    #[error("Version conflict on {key}: expected v{expected}, found v{actual}")]
+    Conflict {
+        key: String,
+        expected: u64,
+        actual: u64,
+    },
+    #[error(transparent)]
+    Api(#[from] ApiError),
+}
+
+/// A stored key-value entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct KvEntry {
+    pub namespace: String,
+    pub key: String,
+    pub value: Value,
+    pub version: u64,
+    /// Timestamp of the backing message
+    pub ts: Option<String>,
+}
+
+impl KvEntry {
+    /// Parse an entry from a history message carrying kv metadata
+    pub fn from_message(message: &Value) -> Option<Self> {
+        let metadata = message.get("metadata")?;
+        if metadata.get("event_type")?.as_str()? != KV_EVENT_TYPE {
+            return None;
+        }
+        let payload = metadata.get("event_payload")?;
+        Some(Self {
+            namespace: payload.get("namespace")?.as_str()?.to_string(),
+            key: payload.get("key")?.as_str()?.to_string(),
+            value: serde_json::from_str(payload.get("value")?.as_str()?).ok()?,
+            version: payload.get("version")?.as_u64()?,
+            ts: message
+                .get("ts")
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string()),
+        })
+    }
+
+    /// Message text mirroring the entry
+    pub fn text(&self) -> String {
+        format!("kv {}/{} (v{})", self.namespace, self.key, self.version)
+    }
+
+    /// Message metadata carrying the entry (value stored as a JSON string)
+    pub fn metadata(&self) -> Value {
+        json!({
+            "event_type": KV_EVENT_TYPE,
+            "event_payload": {
+                "namespace": self.namespace,
+                "key": self.key,
+                "value": self.value.to_string(),
+                "version": self.version,
+            }
+        })
+    }
+
+    /// JSON form for command output
+    pub fn to_json(&self) -> Value {
+        json!({
+            "namespace": self.namespace,
+            "key": self.key,
+            "value": self.value,
+            "version": self.version,
+            "ts": self.ts,
+        })
+    }
+}
+
+/// Resolve the store channel from `--channel` or the environment
+pub fn resolve_kv_channel(channel: Option<String>) -> Result<String, KvError> {
+    channel
+        .or_else(|| std::env::var(KV_CHANNEL_ENV).ok())
+        .filter(|c| !c.is_empty())
+        .ok_or(KvError::MissingChannel)
+}
+
+/// Parse a value argument as JSON, falling back to a JSON string
+///
+/// `42`, `true` and `{"a":1}` are stored as-is; bare words like `green`
+/// become `"green"`. Values that look like objects or arrays must parse.
+pub fn parse_kv_value(raw: &str) -> Result<Value, KvError> {
+    match serde_json::from_str(raw) {
+        Ok(value) => Ok(value),
+        Err(e) if raw.trim_start().starts_with(['{', '[']) => {
+            Err(KvError::InvalidValue(e.to_string()))
+        }
+        Err(_) => Ok(Value::String(raw.to_string())),
+    }
+}
+
+/// Build the `chat.postMessage` request creating an entry
+pub fn plan_kv_post(channel: &str, entry: &KvEntry) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatPostMessage)
+        .param("channel", channel)
+        .param("text", entry.text())
+        .param("metadata", entry.metadata())
+}
+
+/// Build the `chat.update` request replacing an entry in place
+pub fn plan_kv_update(channel: &str, ts: &str, entry: &KvEntry) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatUpdate)
+        .param("channel", channel)
+        .param("ts", ts)
+        .param("text", entry.text())
+        .param("metadata", entry.metadata())
+}
+
+fn ensure_ok(response: ApiResponse) -> Result<ApiResponse, ApiError> {
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(ApiError::SlackError(
+            response
+                .error
+                .unwrap_or_else(|| "unknown_error".to_string()),
+        ))
+    }
+}
+
+/// Read every entry of a namespace, keyed by key
+///
+/// When a key has several messages (two writers created it at once) the
+/// oldest one wins, so every reader agrees on the same entry.
+pub async fn kv_scan(
+    client: &ApiClient,
+    channel: &str,
+    namespace: &str,
+) -> Result<BTreeMap<String, KvEntry>, KvError> {
+    let merged = crate::api::paginate(|cursor| async move {
+        let page = client
+            .execute_planned(
+                plan_conv_history(channel, Some(200), None, None, cursor.as_deref())
+                    .param("include_all_metadata", true),
+            )
+            .await
+            .and_then(ensure_ok)?;
+        serde_json::to_value(page).map_err(ApiError::from)
+    })
+    .await?;
+
+    let mut entries: BTreeMap<String, KvEntry> = BTreeMap::new();
+    // History is newest first; walk oldest first so the first writer wins
+    for entry in merged
+        .get("messages")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .rev()
+        .filter_map(KvEntry::from_message)
+        .filter(|e| e.namespace == namespace)
+    {
+        entries.entry(entry.key.clone()).or_insert(entry);
+    }
+    Ok(entries)
+}
+
+/// Get one entry
+pub async fn kv_get(
+    client: &ApiClient,
+    channel: &str,
+    namespace: &str,
+    key: &str,
+) -> Result<KvEntry, KvError> {
+    kv_scan(client, channel, namespace)
+        .await?
+        .remove(key)
+        .ok_or_else(|| KvError::NotFound {
+            namespace: namespace.to_string(),
+            key: key.to_string(),
+        })
+}
+
+/// List the entries of a namespace in key order
+pub async fn kv_list(
+    client: &ApiClient,
+    channel: &str,
+    namespace: &str,
+) -> Result<Vec<KvEntry>, KvError> {
+    Ok(kv_scan(client, channel, namespace)
+        .await?
+        .into_values()
+        .collect())
+}
+
+/// Set an entry, creating it or bumping its version
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Store channel ID
+/// * `namespace` - Key namespace
+/// * `key` - Key
+/// * `value` - New value
+/// * `if_version` - Expected current version (`0` = must not exist yet)
+///
+/// # Returns
+/// * `Ok(KvEntry)` with the stored entry and its new version
+/// * `Err(KvError::Conflict)` when the stored version is not the expected one,
+///   or another writer changed the entry concurrently
+pub async fn kv_set(
+    client: &ApiClient,
+    channel: &str,
+    namespace: &str,
+    key: &str,
+    value: Value,
+    if_version: Option<u64>,
+) -> Result<KvEntry, KvError> {
+    check_write_allowed()?;
+
+    let current = kv_scan(client, channel, namespace).await?.remove(key);
+    let current_version = current.as_ref().map(|e| e.version).unwrap_or(0);
+    if let Some(expected) = if_version {
+        if expected != current_version {
+            return Err(KvError::Conflict {
+                key: key.to_string(),
+                expected,
+                actual: current_version,
+            });
+        }
+    }
+
+    let mut entry = KvEntry {
+        namespace: namespace.to_string(),
+        key: key.to_string(),
+        value,
+        version: current_version + 1,
+        ts: None,
+    };
+    let request = match current.as_ref().and_then(|e| e.ts.as_deref()) {
+        Some(ts) => plan_kv_update(channel, ts, &entry),
+        None => plan_kv_post(channel, &entry),
+    };
+    let response = ensure_ok(client.execute_planned(request).await?)?;
+    let written_ts = response
+        .data
+        .get("ts")
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string());
+    entry.ts = current.and_then(|e| e.ts).or(written_ts.clone());
+
+    // Read back: another writer may have updated or created the key meanwhile
+    let stored = kv_scan(client, channel, namespace).await?.remove(key);
+    match stored {
+        Some(stored) if stored == entry => Ok(entry),
+        stored => {
+            // Drop a duplicate we created that lost the race to an older message
+            if let (Some(ts), Some(stored)) = (&written_ts, &stored) {
+                if stored.ts.as_deref() != Some(ts.as_str()) && entry.version == 1 {
+                    let _ = client
+                        .execute_planned(crate::commands::plan_msg_delete(channel, ts))
+                        .await;
+                }
+            }
+            Err(KvError::Conflict {
+                key: key.to_string(),
+                expected: entry.version,
+                actual: stored.map(|e| e.version).unwrap_or(0),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn kv_message(ts: &str, namespace: &str, key: &str, value: &str, version: u64) -> Value {
+        json!({
+            "ts": ts,
+            "text": format!("kv {}/{} (v{})", namespace, key, version),
+            "metadata": {
+                "event_type": KV_EVENT_TYPE,
+                "event_payload": {"namespace": namespace, "key": key, "value": value, "version": version}
+            }
+        })
+    }
+
+    #[test]
+    fn test_entry_roundtrip() {
+        let entry = KvEntry {
+            namespace: "mybot".to_string(),
+            key: "state".to_string(),
+            value: json!({"count": 2}),
+            version: 3,
+            ts: Some("1.0".to_string()),
+        };
+        let message = json!({"ts": "1.0", "text": entry.text(), "metadata": entry.metadata()});
+        assert_eq!(KvEntry::from_message(&message), Some(entry));
+        assert_eq!(
+            KvEntry::from_message(&json!({"ts": "1.0", "text": "hi"})),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_kv_value() {
+        assert_eq!(parse_kv_value("42").unwrap(), json!(42));
+        assert_eq!(parse_kv_value(r#"{"a":1}"#).unwrap(), json!({"a": 1}));
+        assert_eq!(parse_kv_value("green").unwrap(), json!("green"));
+        assert!(matches!(
+            parse_kv_value("{broken"),
+            Err(KvError::InvalidValue(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_kv_scan_oldest_wins_and_filters_namespace() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [
+                    kv_message("3.0", "mybot", "a", "\"newer duplicate\"", 1),
+                    {"ts": "2.5", "text": "chatter"},
+                    kv_message("2.0", "other", "a", "1", 1),
+                    kv_message("1.0", "mybot", "a", "\"first\"", 1)
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let entries = kv_list(&client, "C1", "mybot").await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].value, json!("first"));
+        assert_eq!(entries[0].ts.as_deref(), Some("1.0"));
+
+        let err = kv_get(&client, "C1", "mybot", "missing").await.unwrap_err();
+        assert!(matches!(err, KvError::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_kv_set_updates_in_place_and_checks_version() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [kv_message("1.0", "mybot", "a", "\"old\"", 1)]
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [kv_message("1.0", "mybot", "a", "\"new\"", 2)]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat.update"))
+            .and(body_partial_json(
+                json!({"ts": "1.0", "text": "kv mybot/a (v2)"}),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"ok": true, "ts": "1.0"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let entry = kv_set(&client, "C1", "mybot", "a", json!("new"), Some(1))
+            .await
+            .unwrap();
+        assert_eq!(entry.version, 2);
+
+        // Stored version is now 2, so expecting 1 conflicts without writing
+        let err = kv_set(&client, "C1", "mybot", "a", json!("x"), Some(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            KvError::Conflict {
+                expected: 1,
+                actual: 2,
+                ..
+            }
+        ));
+    }
+}
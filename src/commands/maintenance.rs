@@ -0,0 +1,461 @@
+//! Local state maintenance (`slack-rs maintenance run`)
+//!
+//! Several commands keep state in the config directory that only grows or
+//! goes stale: idempotency keys, the users and last-activity caches, daily
+//! usage counters and temporary files left behind by interrupted writes.
+//! `maintenance run` prunes everything past its expiry in one pass and
+//! reports the reclaimed entries and bytes, so it can run from cron.
+//! With `--dry-run` nothing is written.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::api::usage::UsageStore;
+use crate::commands::conv::activity::{ActivityCache, ACTIVITY_CACHE_TTL_SECS};
+use crate::commands::users_cache::{self, UsersCacheFile};
+use crate::idempotency::IdempotencyStore;
+
+/// Temporary files younger than this may belong to a running write
+pub const TEMP_FILE_MIN_AGE_SECS: u64 = 3600;
+
+/// Outcome for one store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreReport {
+    pub name: String,
+    pub path: String,
+    /// Whether the file (or directory, for temp files) exists
+    pub present: bool,
+    pub entries_before: usize,
+    pub entries_removed: usize,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+    /// Why the store could not be processed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl StoreReport {
+    fn new(name: &str, path: &Path) -> Self {
+        Self {
+            name: name.to_string(),
+            path: path.display().to_string(),
+            present: false,
+            entries_before: 0,
+            entries_removed: 0,
+            bytes_before: 0,
+            bytes_after: 0,
+            error: None,
+        }
+    }
+}
+
+/// Result of a maintenance run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceReport {
+    pub dry_run: bool,
+    pub stores: Vec<StoreReport>,
+    pub entries_removed: usize,
+    pub bytes_reclaimed: u64,
+}
+
+/// Paths of the stores handled by a maintenance run
+#[derive(Debug, Clone)]
+pub struct MaintenancePaths {
+    pub idempotency: PathBuf,
+    pub users_cache: PathBuf,
+    pub activity_cache: PathBuf,
+    pub usage: PathBuf,
+    /// Directory searched for leftover `*.tmp` files
+    pub config_dir: PathBuf,
+}
+
+impl MaintenancePaths {
+    /// Default locations in the config directory
+    pub fn default_paths() -> Result<Self, String> {
+        let config_dir = crate::profile::config_dir()
+            .ok_or_else(|| "Could not determine config directory".to_string())?;
+        Ok(Self {
+            idempotency: config_dir.join("idempotency_store.json"),
+            users_cache: UsersCacheFile::default_path()?,
+            activity_cache: ActivityCache::default_path()?,
+            usage: UsageStore::default_path().map_err(|e| e.to_string())?,
+            config_dir,
+        })
+    }
+}
+
+/// Prune every store and build the report
+///
+/// # Arguments
+/// * `paths` - Store locations
+/// * `now` - Current UNIX time in seconds
+/// * `dry_run` - Report what would be removed without writing
+pub fn run_maintenance(paths: &MaintenancePaths, now: u64, dry_run: bool) -> MaintenanceReport {
+    let stores = vec![
+        prune_json_file(
+            "idempotency",
+            &paths.idempotency,
+            dry_run,
+            |store: &mut IdempotencyStore| (store.len(), store.prune_expired()),
+        ),
+        prune_json_file(
+            "users cache",
+            &paths.users_cache,
+            dry_run,
+            |cache: &mut UsersCacheFile| {
+                let before = cache.caches.len();
+                cache.caches.retain(|_, ws| {
+                    now.saturating_sub(ws.updated_at) <= users_cache::DEFAULT_TTL_SECONDS
+                });
+                (before, before - cache.caches.len())
+            },
+        ),
+        prune_json_file(
+            "activity cache",
+            &paths.activity_cache,
+            dry_run,
+            |cache: &mut ActivityCache| {
+                let before = cache.entries.len();
+                cache
+                    .entries
+                    .retain(|_, e| now.saturating_sub(e.fetched_at) < ACTIVITY_CACHE_TTL_SECS);
+                (before, before - cache.entries.len())
+            },
+        ),
+        prune_json_file("usage", &paths.usage, dry_run, |store: &mut UsageStore| {
+            (store.days().len(), store.prune_expired(now))
+        }),
+        prune_temp_files(&paths.config_dir, now, dry_run),
+    ];
+
+    MaintenanceReport {
+        dry_run,
+        entries_removed: stores.iter().map(|s| s.entries_removed).sum(),
+        bytes_reclaimed: stores
+            .iter()
+            .map(|s| s.bytes_before.saturating_sub(s.bytes_after))
+            .sum(),
+        stores,
+    }
+}
+
+/// Load a JSON store, prune it and write it back if anything was removed
+///
+/// `prune` returns the entry count before pruning and the number removed.
+fn prune_json_file<T, F>(name: &str, path: &Path, dry_run: bool, prune: F) -> StoreReport
+where
+    T: DeserializeOwned + Serialize,
+    F: FnOnce(&mut T) -> (usize, usize),
+{
+    let mut report = StoreReport::new(name, path);
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return report,
+        Err(e) => {
+            report.error = Some(e.to_string());
+            return report;
+        }
+    };
+    report.present = true;
+    report.bytes_before = content.len() as u64;
+    report.bytes_after = report.bytes_before;
+
+    let mut store: T = match serde_json::from_str(&content) {
+        Ok(store) => store,
+        Err(e) => {
+            report.error = Some(format!("Failed to parse: {}", e));
+            return report;
+        }
+    };
+    let (before, removed) = prune(&mut store);
+    report.entries_before = before;
+    report.entries_removed = removed;
+    if removed == 0 {
+        return report;
+    }
+
+    let pruned = match serde_json::to_string_pretty(&store) {
+        Ok(pruned) => pruned,
+        Err(e) => {
+            report.error = Some(e.to_string());
+            return report;
+        }
+    };
+    report.bytes_after = pruned.len() as u64;
+    if !dry_run {
+        // Writing in place keeps the file's permissions (0600 for idempotency)
+        if let Err(e) = fs::write(path, pruned) {
+            report.error = Some(format!("Failed to write: {}", e));
+            report.bytes_after = report.bytes_before;
+        }
+    }
+    report
+}
+
+/// Remove `*.tmp` files older than [`TEMP_FILE_MIN_AGE_SECS`]
+fn prune_temp_files(dir: &Path, now: u64, dry_run: bool) -> StoreReport {
+    let mut report = StoreReport::new("temp files", dir);
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return report,
+    };
+    report.present = true;
+
+    let cutoff = UNIX_EPOCH + Duration::from_secs(now.saturating_sub(TEMP_FILE_MIN_AGE_SECS));
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("tmp") {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        report.entries_before += 1;
+        report.bytes_before += metadata.len();
+        let stale = metadata.modified().map(|m| m <= cutoff).unwrap_or(false);
+        if !stale {
+            report.bytes_after += metadata.len();
+            continue;
+        }
+        if dry_run {
+            report.entries_removed += 1;
+        } else if let Err(e) = fs::remove_file(&path) {
+            report.bytes_after += metadata.len();
+            report.error = Some(format!("Failed to remove {}: {}", path.display(), e));
+        } else {
+            report.entries_removed += 1;
+        }
+    }
+    report
+}
+
+/// Render a byte count with a binary unit
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// Render a report as plain text
+pub fn format_maintenance_text(report: &MaintenanceReport) -> String {
+    let mut out = String::new();
+    if report.dry_run {
+        out.push_str("Maintenance (dry run, nothing was written)\n\n");
+    } else {
+        out.push_str("Maintenance\n\n");
+    }
+
+    let width = report
+        .stores
+        .iter()
+        .map(|s| s.name.len())
+        .max()
+        .unwrap_or(0);
+    for store in &report.stores {
+        let detail = if let Some(ref error) = store.error {
+            format!("error: {}", error)
+        } else if !store.present {
+            "not present".to_string()
+        } else {
+            format!(
+                "{} of {} removed, {} -> {}",
+                store.entries_removed,
+                store.entries_before,
+                format_bytes(store.bytes_before),
+                format_bytes(store.bytes_after)
+            )
+        };
+        out.push_str(&format!(
+            "  {:<width$}  {}\n",
+            store.name,
+            detail,
+            width = width
+        ));
+    }
+
+    out.push_str(&format!(
+        "\n{} {} entr{}, {}\n",
+        if report.dry_run {
+            "Would reclaim"
+        } else {
+            "Reclaimed"
+        },
+        report.entries_removed,
+        if report.entries_removed == 1 {
+            "y"
+        } else {
+            "ies"
+        },
+        format_bytes(report.bytes_reclaimed)
+    ));
+    out
+}
+
+/// Prune local state in the config directory
+///
+/// # Arguments
+/// * `dry_run` - Report what would be removed without writing
+/// * `json_output` - Whether to output JSON format
+pub fn maintenance_run(dry_run: bool, json_output: bool) -> Result<(), String> {
+    let paths = MaintenancePaths::default_paths()?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let report = run_maintenance(&paths, now, dry_run);
+
+    if json_output {
+        let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        print!("{}", format_maintenance_text(&report));
+    }
+
+    if let Some(store) = report.stores.iter().find(|s| s.error.is_some()) {
+        return Err(format!(
+            "{}: {}",
+            store.name,
+            store.error.as_deref().unwrap_or_default()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    // 2023-11-14T22:13:20Z
+    const NOW: u64 = 1_700_000_000;
+
+    fn paths(dir: &Path) -> MaintenancePaths {
+        MaintenancePaths {
+            idempotency: dir.join("idempotency_store.json"),
+            users_cache: dir.join("users_cache.json"),
+            activity_cache: dir.join("activity_cache.json"),
+            usage: dir.join("usage.json"),
+            config_dir: dir.to_path_buf(),
+        }
+    }
+
+    fn write_fixtures(dir: &Path) {
+        fs::write(
+            dir.join("activity_cache.json"),
+            json!({"entries": {
+                "T1:C1": {"latest_ts": "1.0", "fetched_at": NOW - 60},
+                "T1:C2": {"latest_ts": null, "fetched_at": NOW - 7200},
+            }})
+            .to_string(),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("users_cache.json"),
+            json!({"caches": {
+                "T1": {"team_id": "T1", "updated_at": NOW - 100, "users": {}},
+                "T2": {"team_id": "T2", "updated_at": NOW - 200_000, "users": {}},
+            }})
+            .to_string(),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("usage.json"),
+            json!({"days": {
+                "2023-11-14": {"users.info": 1},
+                "2023-01-01": {"users.info": 5},
+            }})
+            .to_string(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_run_prunes_expired_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        write_fixtures(temp_dir.path());
+
+        let report = run_maintenance(&paths(temp_dir.path()), NOW, false);
+        let store = |name: &str| report.stores.iter().find(|s| s.name == name).unwrap();
+
+        assert!(!store("idempotency").present);
+        assert_eq!(store("activity cache").entries_removed, 1);
+        assert_eq!(store("users cache").entries_removed, 1);
+        assert_eq!(store("usage").entries_removed, 1);
+        assert_eq!(report.entries_removed, 3);
+        assert!(report.bytes_reclaimed > 0);
+
+        let activity = ActivityCache::load(&temp_dir.path().join("activity_cache.json"));
+        assert_eq!(activity.entries.len(), 1);
+        assert!(activity.entries.contains_key("T1:C1"));
+
+        // A second run has nothing left to do
+        let again = run_maintenance(&paths(temp_dir.path()), NOW, false);
+        assert_eq!(again.entries_removed, 0);
+        assert_eq!(again.bytes_reclaimed, 0);
+    }
+
+    #[test]
+    fn test_dry_run_leaves_files_untouched() {
+        let temp_dir = TempDir::new().unwrap();
+        write_fixtures(temp_dir.path());
+        let before = fs::read_to_string(temp_dir.path().join("users_cache.json")).unwrap();
+
+        let report = run_maintenance(&paths(temp_dir.path()), NOW, true);
+        assert!(report.dry_run);
+        assert_eq!(report.entries_removed, 3);
+
+        let after = fs::read_to_string(temp_dir.path().join("users_cache.json")).unwrap();
+        assert_eq!(before, after);
+        assert!(format_maintenance_text(&report).contains("Would reclaim 3 entries"));
+    }
+
+    #[test]
+    fn test_unparsable_store_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("usage.json"), "not json").unwrap();
+
+        let report = run_maintenance(&paths(temp_dir.path()), NOW, false);
+        let usage = report.stores.iter().find(|s| s.name == "usage").unwrap();
+        assert!(usage
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("Failed to parse"));
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("usage.json")).unwrap(),
+            "not json"
+        );
+    }
+
+    #[test]
+    fn test_fresh_temp_files_are_kept() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("usage.json.tmp"), "{}").unwrap();
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let report = prune_temp_files(temp_dir.path(), now, false);
+        assert_eq!(report.entries_before, 1);
+        assert_eq!(report.entries_removed, 0);
+        assert!(temp_dir.path().join("usage.json.tmp").exists());
+
+        let report = prune_temp_files(temp_dir.path(), now + TEMP_FILE_MIN_AGE_SECS + 1, false);
+        assert_eq!(report.entries_removed, 1);
+        assert!(!temp_dir.path().join("usage.json.tmp").exists());
+    }
+}
@@ -1,40 +1,202 @@
 //! Command implementations for Slack CLI wrapper commands
 //!
 //! Provides high-level commands that wrap the generic API client:
-//! - search: Search messages
-//! - conv: Conversation operations (list, history)
+//! - search: Search messages (one workspace, or merged across profiles)
+//! - conv: Conversation operations (list, history, replies, members, export, tail, channel management)
+//! - ctx: Session context (default channel/thread for omitted arguments)
 //! - thread: Thread operations (get replies)
+//! - thread_ref: `--thread-ts` permalinks and `latest` lookups
 //! - users: User operations (info)
 //! - users_cache: User cache and mention resolution
-//! - msg: Message operations (post, update, delete)
+//! - users_tz: Timezone report and meeting-time suggestions
+//! - msg: Message operations (post with pin/react follow-ups, update, delete)
+//! - inspect: Single message summary from a permalink (`msg inspect`)
+//! - footer: Provenance footer (`output.post_footer`) for automated posts
+//! - bulk: Bulk posting from JSONL/CSV rows with a per-row result report (`msg bulk`)
+//! - output: Shared `--format` / `--columns` rendering of command output
+//! - schedule: Scheduled messages (`msg schedule`, `msg scheduled list/delete`)
+//! - split: Long message chunking for `msg post --split`
+//! - blocks: Block Kit loading and validation for `msg post --blocks`
+//! - input: Stdin JSON parameters for write commands and `api call` (`--input json`, `--stdin`)
 //! - react: Reaction operations (add, remove)
+//! - emoji: Custom emoji list and cache, emoji name checks for reactions
+//! - pin: Pin operations (add, remove, list)
+//! - bookmark: Channel bookmarks (list, add, remove, apply from a spec)
+//! - remind: Reminders (add, list, complete, delete; user token only)
+//! - dnd: Do Not Disturb and presence (user token only)
 //! - file: File operations (upload using external upload method)
+//! - file_manage: File listing and cleanup (`file list`, `file delete`)
+//! - connect: Slack Connect invitations, approvals and permissions
+//! - kv: Key-value store backed by a Slack channel
+//! - idempotency: Inspection and cleanup of the idempotency store
 //! - config: Configuration management (OAuth settings)
 //! - doctor: Diagnostics and environment troubleshooting
+//! - doctor_network: DNS/TCP/TLS/API/proxy/WebSocket probe (`doctor network`)
+//! - usage: API consumption trends per method and rate-limit tier
+//! - token_compare: Bot vs user token visibility comparison
+//! - auth_test: Token identity and granted scopes (`auth test`)
+//! - maintenance: Pruning of expired local state (`maintenance run`)
+//! - mrkdwn: Markdown to Slack mrkdwn conversion (`msg post --from markdown`)
+//! - offboard: User deactivation impact report (`users offboard-report`)
+//! - usergroup: Usergroup listing, management, membership sync and handle mentions
+//!
+//! Wrappers are split into a pure `plan_*` builder returning a
+//! [`PlannedRequest`](crate::api::PlannedRequest) and an executor call, so
+//! requests can be inspected without being sent.
 
+pub mod auth_test;
+pub mod blocks;
+pub mod bookmark;
+pub mod bulk;
 pub mod config;
+pub mod connect;
 pub mod conv;
+pub mod ctx;
+pub mod dnd;
 pub mod doctor;
+pub mod doctor_network;
+pub mod emoji;
 pub mod file;
+pub mod file_manage;
+pub mod footer;
 pub mod guards;
+pub mod idempotency;
+pub mod input;
+pub mod inspect;
+pub mod kv;
+pub mod maintenance;
+pub mod mrkdwn;
 pub mod msg;
+pub mod offboard;
+pub mod output;
+pub mod pin;
+pub mod post_preview;
 pub mod react;
+pub mod remind;
+pub mod schedule;
 pub mod search;
+pub mod split;
 pub mod thread;
+pub mod thread_ref;
+pub mod token_compare;
+pub mod usage;
+pub mod usergroup;
 pub mod users;
 pub mod users_cache;
+pub mod users_tz;
 
-pub use config::{oauth_delete, oauth_set, oauth_show, set_default_token_type, OAuthSetParams};
+pub use auth_test::{auth_test, format_auth_test_text, format_whoami_text, AuthTestReport};
+pub use blocks::{load_blocks, parse_blocks, validate_blocks, BlocksError};
+pub use bookmark::{
+    bookmark_add, bookmark_apply, bookmark_list, bookmark_remove, format_bookmark_apply_text,
+    parse_bookmark_spec, BookmarkApplyReport,
+};
+pub use bulk::{
+    completed_keys, msg_bulk, parse_bulk_input, BulkFormat, BulkIdempotency, BulkOptions,
+    BulkResult, BulkRow, BulkStatus, Rate,
+};
+pub use config::{
+    migrate_token_backend, oauth_delete, oauth_set, oauth_show, set_default_token_type,
+    set_output_setting, OAuthSetParams,
+};
+pub use connect::{
+    connect_accept, connect_approve, connect_decline, connect_invite, connect_list_invites,
+    connect_requests_approve, connect_requests_deny, connect_requests_list,
+    connect_set_permissions, ExternalPermissionAction, InviteRequestFilter,
+};
 pub use conv::{
-    apply_filters, conv_history, conv_list, extract_conversations, format_response,
-    sort_conversations, ConversationFilter, ConversationItem, ConversationSelector, OutputFormat,
-    SortDirection, SortKey, StdinSelector,
+    advance_cursor, annotate_external_members, apply_dedupe_edits, apply_filters,
+    apply_message_filters, conv_archive, conv_create, conv_export, conv_history, conv_history_all,
+    conv_history_stable, conv_invite, conv_join, conv_kick, conv_leave, conv_list, conv_members,
+    conv_rename, conv_replies, conv_replies_all, conv_unarchive, dedupe_edits,
+    default_watch_state_path, delta_response, enrich_last_activity, extract_conversations,
+    fetch_connect_info, fetch_new_messages, format_messages, format_response, message_matches,
+    newest_message_ts, now_ts, plan_conv_archive, plan_conv_create, plan_conv_history,
+    plan_conv_info, plan_conv_invite, plan_conv_join, plan_conv_kick, plan_conv_leave,
+    plan_conv_list, plan_conv_members, plan_conv_rename, plan_conv_replies, plan_conv_unarchive,
+    render_html, resolve_member_records, retain_members, run_exec, socket_message,
+    sort_conversations, thread_shape_filter, ActivityCache, ChannelSnapshot, ConnectInfo,
+    ConsistencyReport, ConversationExport, ConversationFilter, ConversationItem,
+    ConversationSelector, ExportFormat, HistorySyncState, HtmlOptions, MemberAccessFilter,
+    MessageFilter, OutputFormat, Redactor, SortDirection, SortKey, StdinSelector, UserNames,
+};
+pub use ctx::{apply_session_context, format_context_text, SessionContext};
+pub use dnd::{
+    dnd_end, dnd_info, dnd_set, parse_presence, parse_snooze_minutes, presence_get, presence_set,
 };
 pub use doctor::doctor;
-pub use file::{file_download, file_upload};
-pub use msg::{msg_delete, msg_post, msg_update};
-pub use react::{react_add, react_remove};
-pub use search::search;
+pub use doctor_network::{doctor_network, NetworkOptions};
+pub use emoji::{
+    check_emoji_name, emoji_list_response, load_emoji, normalize_emoji_name, EmojiCheck,
+};
+pub use file::{
+    expand_upload_paths, file_download, file_upload, file_upload_content, file_upload_many,
+    file_upload_snippet, file_upload_with_options, plan_complete_upload,
+    plan_complete_upload_files, plan_get_upload_url, read_upload_content, sniff_content_type,
+    snippet_lead_in, upload_content_key, FileUploadReport, FileUploadResult, SniffedType,
+    UploadOptions, DEFAULT_UPLOAD_PARALLELISM, MAX_UPLOAD_BYTES, SNIPPET_THRESHOLD,
+    STDIN_UPLOAD_PATH,
+};
+pub use file_manage::{
+    file_delete, file_list, parse_file_id_list, plan_file_delete, plan_file_list, FileDeleteReport,
+    FileDeleteResult, FileListOptions,
+};
+pub use footer::{append_footer_block, append_footer_text, expand_footer, FooterContext};
+pub use guards::{check_user_token, require_user_token_type};
+pub use input::{
+    apply_api_call_input, apply_json_input, apply_stdin_input, input_commands, parse_json_stream,
+    run_stdin_objects, StdinRunSummary,
+};
+pub use inspect::{
+    build_inspect_report, format_inspect_text, msg_inspect, plan_message_fetch, render_blocks,
+    InspectReport,
+};
+pub use maintenance::{maintenance_run, run_maintenance, MaintenanceReport};
+pub use mrkdwn::markdown_to_mrkdwn;
+pub use msg::{
+    msg_delete, msg_permalink, msg_post, msg_post_with_blocks, msg_update, plan_msg_delete,
+    plan_msg_permalink, plan_msg_post, plan_msg_update, run_post_follow_ups, FollowUpReport,
+    FollowUpStep, PostFollowUps,
+};
+pub use offboard::{format_offboard_text, offboard_report, OffboardReport};
+pub use pin::{pin_add, pin_list, pin_remove, plan_pins_add, plan_pins_list, plan_pins_remove};
+pub use post_preview::{decode_message_text, render_post_preview, PostPreview};
+pub use react::{plan_react_add, plan_react_remove, react_add, react_remove};
+pub use remind::{
+    plan_remind_add, plan_remind_complete, plan_remind_delete, plan_remind_list, remind_add,
+    remind_complete, remind_delete, remind_list,
+};
+pub use schedule::{
+    msg_schedule, msg_scheduled_delete, msg_scheduled_list, plan_msg_schedule,
+    plan_scheduled_delete, plan_scheduled_list, resolve_post_at,
+};
+pub use search::{
+    build_search_query, channel_modifier, flatten_search_matches, format_search_summary,
+    group_search_matches, merge_workspace_searches, plan_search, plan_search_target, search,
+    search_all, search_summary_json, search_target, search_top, user_modifier, MergeOrder,
+    QueryModifiers, SearchGroup, SearchGroupBy, SearchTarget, WorkspaceSearch,
+};
+pub use split::{
+    msg_post_split, split_message, truncate_message, truncate_message_body, SplitReport,
+    DEFAULT_SPLIT_LIMIT, MAX_MESSAGE_CHARS,
+};
 pub use thread::thread_get;
-pub use users::users_info;
-pub use users_cache::{resolve_mentions, update_cache, MentionFormat, UsersCacheFile};
+pub use thread_ref::{
+    parse_permalink, resolve_channel_id, resolve_thread_ts, ThreadRef, ThreadRefError,
+};
+pub use token_compare::{
+    compare_tokens, format_token_comparison, ProbeOutcome, TokenComparison, TOKEN_PROBES,
+};
+pub use usage::{build_usage_report, format_usage_text, usage, UsageReport};
+pub use usergroup::{
+    parse_members_file, resolve_usergroup_mentions, usergroup_create, usergroup_list,
+    usergroup_update, usergroup_users, usergroup_users_update, UsergroupFields,
+};
+pub use users::{plan_users_info, users_info};
+pub use users_cache::{
+    encode_mentions, resolve_mentions, update_cache, EncodedMentions, MentionFormat, UsersCacheFile,
+};
+pub use users_tz::{
+    build_tz_report, fetch_channel_members, render_tz_table, resolve_user_timezones, TzReport,
+    UserTimezone, WorkingHours,
+};
@@ -0,0 +1,189 @@
+//! Markdown to Slack mrkdwn conversion (`msg post`/`msg update` with
+//! `--markdown` or `--from markdown`)
+//!
+//! Slack's `mrkdwn` differs from standard Markdown: bold is `*x*`, italic
+//! `_x_`, strikethrough `~x~`, links `<url|text>`, and there are no headings
+//! or list markers. Text written as regular Markdown (docs, LLM output) is
+//! rewritten line by line; fenced code blocks and inline code are left
+//! untouched.
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Supported `--from` source formats
+pub const SOURCE_FORMATS: &[&str] = &["markdown"];
+
+struct Patterns {
+    heading: Regex,
+    bullet: Regex,
+    image: Regex,
+    link: Regex,
+    bold: Regex,
+    italic: Regex,
+    strike: Regex,
+}
+
+fn patterns() -> &'static Patterns {
+    static PATTERNS: OnceLock<Patterns> = OnceLock::new();
+    PATTERNS.get_or_init(|| Patterns {
+        heading: Regex::new(r"^\s{0,3}#{1,6}\s+(.*?)\s*#*\s*$").unwrap(),
+        bullet: Regex::new(r"^(\s*)[-*+]\s+").unwrap(),
+        image: Regex::new(r"!\[([^\]]*)\]\(([^)\s]+)(?:\s+[^)]*)?\)").unwrap(),
+        link: Regex::new(r"\[([^\]]+)\]\(([^)\s]+)(?:\s+[^)]*)?\)").unwrap(),
+        bold: Regex::new(r"\*\*(\S(?:.*?\S)?)\*\*|__(\S(?:.*?\S)?)__").unwrap(),
+        italic: Regex::new(r"\*(\S(?:[^*]*?\S)?)\*").unwrap(),
+        strike: Regex::new(r"~~(\S(?:.*?\S)?)~~").unwrap(),
+    })
+}
+
+/// Bold marker used while italics are rewritten, so `**x**` -> `*x*` is not
+/// mistaken for italic afterwards
+const BOLD_PLACEHOLDER: char = '\u{1}';
+
+/// Convert inline Markdown outside of code spans
+fn convert_inline(text: &str) -> String {
+    let p = patterns();
+    let text = p.image.replace_all(text, "<$2|$1>");
+    let text = p.link.replace_all(&text, "<$2|$1>");
+    let text = p.bold.replace_all(&text, |caps: &regex::Captures| {
+        let inner = caps.get(1).or_else(|| caps.get(2)).unwrap().as_str();
+        format!("{0}{1}{0}", BOLD_PLACEHOLDER, inner)
+    });
+    let text = p.italic.replace_all(&text, "_${1}_");
+    let text = p.strike.replace_all(&text, "~$1~");
+    text.replace(BOLD_PLACEHOLDER, "*")
+}
+
+/// Convert a line, leaving `inline code` spans as they are
+fn convert_line(line: &str) -> String {
+    let p = patterns();
+    if let Some(caps) = p.heading.captures(line) {
+        return format!("*{}*", convert_spans(&caps[1]));
+    }
+    let (prefix, rest) = match p.bullet.find(line) {
+        Some(m) => {
+            let indent = p.bullet.captures(line).unwrap()[1].to_string();
+            (format!("{}• ", indent), &line[m.end()..])
+        }
+        None => (String::new(), line),
+    };
+    format!("{}{}", prefix, convert_spans(rest))
+}
+
+fn convert_spans(text: &str) -> String {
+    text.split('`')
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 0 {
+                convert_inline(part)
+            } else {
+                part.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("`")
+}
+
+/// Convert standard Markdown to Slack mrkdwn
+///
+/// Headings become bold lines, `-`/`*`/`+` bullets become `•` (indentation
+/// is kept for nested lists), `**bold**`/`__bold__` become `*bold*`,
+/// `*italic*` becomes `_italic_`, `~~strike~~` becomes `~strike~`, and
+/// links and images become `<url|text>`. Numbered lists, block quotes and
+/// code are already valid mrkdwn and pass through.
+pub fn markdown_to_mrkdwn(markdown: &str) -> String {
+    let mut in_fence = false;
+    markdown
+        .lines()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                return line.trim_start().to_string();
+            }
+            if in_fence {
+                line.to_string()
+            } else {
+                convert_line(line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert `text` according to a `--from` source format
+///
+/// # Errors
+/// The format is not one of [`SOURCE_FORMATS`].
+pub fn convert_from(format: &str, text: &str) -> Result<String, String> {
+    match format {
+        "markdown" => Ok(markdown_to_mrkdwn(text)),
+        other => Err(format!(
+            "Unsupported --from format '{}' (supported: {})",
+            other,
+            SOURCE_FORMATS.join(", ")
+        )),
+    }
+}
+
+/// Source format selected by `--from=FORMAT` or the `--markdown` shorthand
+///
+/// # Errors
+/// `--markdown` is combined with a `--from` other than `markdown`.
+pub fn source_format(from: Option<String>, markdown: bool) -> Result<Option<String>, String> {
+    match (from, markdown) {
+        (Some(format), true) if format != "markdown" => Err(format!(
+            "--markdown cannot be combined with --from {}",
+            format
+        )),
+        (_, true) => Ok(Some("markdown".to_string())),
+        (from, false) => Ok(from),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_format() {
+        assert_eq!(source_format(None, false).unwrap(), None);
+        assert_eq!(
+            source_format(None, true).unwrap().as_deref(),
+            Some("markdown")
+        );
+        assert_eq!(
+            source_format(Some("markdown".to_string()), true)
+                .unwrap()
+                .as_deref(),
+            Some("markdown")
+        );
+        assert!(source_format(Some("html".to_string()), true).is_err());
+    }
+
+    #[test]
+    fn test_markdown_to_mrkdwn() {
+        let markdown = "# Release 1.2\n\
+                        Deploy **done**, see [the notes](https://example.com/notes).\n\
+                        - *fast* path\n  \
+                        * ~~old~~ flag\n\
+                        1. numbered stays";
+        assert_eq!(
+            markdown_to_mrkdwn(markdown),
+            "*Release 1.2*\n\
+             Deploy *done*, see <https://example.com/notes|the notes>.\n\
+             • _fast_ path\n  \
+             • ~old~ flag\n\
+             1. numbered stays"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_mrkdwn_keeps_code() {
+        let markdown = "Run `**not bold**` now\n```\n# not a heading\n- not a bullet\n```";
+        assert_eq!(
+            markdown_to_mrkdwn(markdown),
+            "Run `**not bold**` now\n```\n# not a heading\n- not a bullet\n```"
+        );
+        assert!(convert_from("html", "x").is_err());
+    }
+}
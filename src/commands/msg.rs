@@ -1,9 +1,56 @@
 //! Message command implementations
 
-use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse};
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
 use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
-use serde_json::json;
-use std::collections::HashMap;
+use crate::commands::pin::plan_pins_add;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Build a `chat.postMessage` request
+///
+/// `reply_broadcast` only applies to thread replies. With `blocks`, `text`
+/// is the notification fallback.
+pub fn plan_msg_post(
+    channel: &str,
+    text: &str,
+    blocks: Option<&Value>,
+    thread_ts: Option<&str>,
+    reply_broadcast: bool,
+) -> PlannedRequest {
+    let request = PlannedRequest::new(ApiMethod::ChatPostMessage)
+        .param("channel", channel)
+        .param("text", text)
+        .param_opt("blocks", blocks.cloned());
+    match thread_ts {
+        Some(ts) if reply_broadcast => request
+            .param("thread_ts", ts)
+            .param("reply_broadcast", true),
+        Some(ts) => request.param("thread_ts", ts),
+        None => request,
+    }
+}
+
+/// Build a `chat.update` request
+pub fn plan_msg_update(channel: &str, ts: &str, text: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatUpdate)
+        .param("channel", channel)
+        .param("ts", ts)
+        .param("text", text)
+}
+
+/// Build a `chat.delete` request
+pub fn plan_msg_delete(channel: &str, ts: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatDelete)
+        .param("channel", channel)
+        .param("ts", ts)
+}
+
+/// Build a `chat.getPermalink` request
+pub fn plan_msg_permalink(channel: &str, ts: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatGetPermalink)
+        .param("channel", channel)
+        .param("message_ts", ts)
+}
 
 /// Post a message to a channel
 ///
@@ -27,6 +74,45 @@ pub async fn msg_post(
     reply_broadcast: bool,
     yes: bool,
     non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    msg_post_with_blocks(
+        client,
+        channel,
+        text,
+        None,
+        thread_ts,
+        reply_broadcast,
+        yes,
+        non_interactive,
+    )
+    .await
+}
+
+/// Post a message with optional Block Kit blocks
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `text` - Message text (notification fallback when `blocks` is set)
+/// * `blocks` - Optional validated block array (see [`crate::commands::blocks`])
+/// * `thread_ts` - Optional thread timestamp to reply to
+/// * `reply_broadcast` - Whether to broadcast thread reply to channel
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with posted message information
+/// * `Err(ApiError)` if the operation fails
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_post_with_blocks(
+    client: &ApiClient,
+    channel: String,
+    text: String,
+    blocks: Option<Value>,
+    thread_ts: Option<String>,
+    reply_broadcast: bool,
+    yes: bool,
+    non_interactive: bool,
 ) -> Result<ApiResponse, ApiError> {
     check_write_allowed()?;
 
@@ -34,18 +120,15 @@ pub async fn msg_post(
     let hint = format!("Example: slack-rs msg post {} \"{}\" --yes", channel, text);
     confirm_destructive_with_hint(yes, "post this message", non_interactive, Some(&hint))?;
 
-    let mut params = HashMap::new();
-    params.insert("channel".to_string(), json!(channel));
-    params.insert("text".to_string(), json!(text));
-
-    if let Some(ts) = thread_ts {
-        params.insert("thread_ts".to_string(), json!(ts));
-        if reply_broadcast {
-            params.insert("reply_broadcast".to_string(), json!(true));
-        }
-    }
-
-    client.call_method(ApiMethod::ChatPostMessage, params).await
+    client
+        .execute_planned(plan_msg_post(
+            &channel,
+            &text,
+            blocks.as_ref(),
+            thread_ts.as_deref(),
+            reply_broadcast,
+        ))
+        .await
 }
 
 /// Update a message
@@ -78,12 +161,9 @@ pub async fn msg_update(
     );
     confirm_destructive_with_hint(yes, "update this message", non_interactive, Some(&hint))?;
 
-    let mut params = HashMap::new();
-    params.insert("channel".to_string(), json!(channel));
-    params.insert("ts".to_string(), json!(ts));
-    params.insert("text".to_string(), json!(text));
-
-    client.call_method(ApiMethod::ChatUpdate, params).await
+    client
+        .execute_planned(plan_msg_update(&channel, &ts, &text))
+        .await
 }
 
 /// Delete a message
@@ -111,18 +191,189 @@ pub async fn msg_delete(
     let hint = format!("Example: slack-rs msg delete {} {} --yes", channel, ts);
     confirm_destructive_with_hint(yes, "delete this message", non_interactive, Some(&hint))?;
 
-    let mut params = HashMap::new();
-    params.insert("channel".to_string(), json!(channel));
-    params.insert("ts".to_string(), json!(ts));
+    client.execute_planned(plan_msg_delete(&channel, &ts)).await
+}
+
+/// Get the permalink URL of a message
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `ts` - Message timestamp
+///
+/// # Returns
+/// * `Ok(String)` with the permalink
+/// * `Err(ApiError)` if the operation fails
+pub async fn msg_permalink(
+    client: &ApiClient,
+    channel: &str,
+    ts: &str,
+) -> Result<String, ApiError> {
+    let response = client
+        .execute_planned(plan_msg_permalink(channel, ts))
+        .await?;
+    response
+        .data
+        .get("permalink")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| ApiError::SlackError("missing_permalink".to_string()))
+}
+
+/// Secondary steps run after `msg post` (`--pin`, `--react`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PostFollowUps {
+    /// Pin the posted message
+    pub pin: bool,
+    /// Reactions to add, in order (emoji names without colons)
+    pub reactions: Vec<String>,
+}
+
+impl PostFollowUps {
+    /// Parse a comma-separated `--react` value (e.g. "rocket,:eyes:")
+    pub fn parse_reactions(value: &str) -> Vec<String> {
+        value
+            .split(',')
+            .map(|name| name.trim().trim_matches(':'))
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    /// True when there is nothing to do after posting
+    pub fn is_empty(&self) -> bool {
+        !self.pin && self.reactions.is_empty()
+    }
+}
+
+/// Outcome of a single follow-up step
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FollowUpStep {
+    /// Step name, e.g. "pin" or "react:rocket"
+    pub step: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of all follow-up steps, including rollback of the post
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FollowUpReport {
+    pub steps: Vec<FollowUpStep>,
+    /// Whether the posted message was deleted after a failed step
+    pub rolled_back: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollback_error: Option<String>,
+}
+
+impl FollowUpReport {
+    /// First failed step, if any
+    pub fn failed_step(&self) -> Option<&FollowUpStep> {
+        self.steps.iter().find(|step| !step.ok)
+    }
+}
+
+/// Run the follow-up steps for a freshly posted message
+///
+/// Steps run in order (pin, then reactions) and stop at the first failure.
+/// When `rollback` is set, a failure deletes the posted message so the
+/// announcement is either complete or absent; the report records what
+/// succeeded, what failed and whether the rollback itself worked.
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID the message was posted to
+/// * `ts` - Timestamp of the posted message
+/// * `follow_ups` - Steps to run
+/// * `rollback` - Delete the message if a step fails
+pub async fn run_post_follow_ups(
+    client: &ApiClient,
+    channel: &str,
+    ts: &str,
+    follow_ups: &PostFollowUps,
+    rollback: bool,
+) -> FollowUpReport {
+    let mut steps = Vec::new();
+
+    let mut planned = Vec::new();
+    if follow_ups.pin {
+        planned.push(("pin".to_string(), plan_pins_add(channel, ts)));
+    }
+    for name in &follow_ups.reactions {
+        planned.push((
+            format!("react:{}", name),
+            crate::commands::react::plan_react_add(channel, ts, name),
+        ));
+    }
+
+    for (step, request) in planned {
+        let result = client.execute_planned(request).await;
+        let failed = result.is_err();
+        steps.push(FollowUpStep {
+            step,
+            ok: !failed,
+            error: result.err().map(|e| e.to_string()),
+        });
+        if failed {
+            break;
+        }
+    }
+
+    let mut report = FollowUpReport {
+        steps,
+        rolled_back: false,
+        rollback_error: None,
+    };
+
+    if rollback && report.failed_step().is_some() {
+        match client.execute_planned(plan_msg_delete(channel, ts)).await {
+            Ok(_) => report.rolled_back = true,
+            Err(e) => report.rollback_error = Some(e.to_string()),
+        }
+    }
 
-    client.call_method(ApiMethod::ChatDelete, params).await
+    report
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
     use serial_test::serial;
 
+    #[test]
+    fn test_plan_msg_post() {
+        let request = plan_msg_post("C123", "hello", None, Some("1.000"), true);
+        assert_eq!(request.method, ApiMethod::ChatPostMessage);
+        assert_eq!(
+            request.to_json()["params"],
+            json!({"channel": "C123", "text": "hello", "thread_ts": "1.000", "reply_broadcast": true})
+        );
+
+        // reply_broadcast is ignored outside threads
+        let request = plan_msg_post("C123", "hello", None, None, true);
+        assert!(!request.params.contains_key("reply_broadcast"));
+    }
+
+    #[test]
+    fn test_plan_msg_post_with_blocks() {
+        let blocks = json!([{"type": "divider"}]);
+        let request = plan_msg_post("C123", "fallback", Some(&blocks), None, false);
+        assert_eq!(request.params["blocks"], blocks);
+        assert_eq!(request.params["text"], "fallback");
+    }
+
+    #[test]
+    fn test_plan_msg_update_and_delete() {
+        assert_eq!(
+            plan_msg_update("C1", "1.0", "new").to_json()["params"],
+            json!({"channel": "C1", "ts": "1.0", "text": "new"})
+        );
+        let delete = plan_msg_delete("C1", "1.0");
+        assert_eq!(delete.method, ApiMethod::ChatDelete);
+        assert_eq!(delete.http_method(), "POST");
+    }
+
     #[tokio::test]
     #[serial(write_guard)]
     async fn test_msg_post_with_env_false() {
@@ -179,4 +430,116 @@ mod tests {
         assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
         std::env::remove_var("SLACKCLI_ALLOW_WRITE");
     }
+
+    #[test]
+    fn test_parse_reactions() {
+        assert_eq!(
+            PostFollowUps::parse_reactions("rocket, :eyes:,,"),
+            vec!["rocket".to_string(), "eyes".to_string()]
+        );
+        assert!(PostFollowUps::default().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_follow_ups_run_in_order() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/pins.add"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ok": true})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/reactions.add"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ok": true})))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat.delete"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ok": true})))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let follow_ups = PostFollowUps {
+            pin: true,
+            reactions: vec!["rocket".to_string(), "eyes".to_string()],
+        };
+        let report = run_post_follow_ups(&client, "C123", "1.000", &follow_ups, true).await;
+
+        let names: Vec<_> = report.steps.iter().map(|s| s.step.as_str()).collect();
+        assert_eq!(names, vec!["pin", "react:rocket", "react:eyes"]);
+        assert!(report.failed_step().is_none());
+        assert!(!report.rolled_back);
+    }
+
+    #[tokio::test]
+    async fn test_follow_up_failure_rolls_back() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/pins.add"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "not_pinnable"})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/reactions.add"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ok": true})))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat.delete"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ok": true})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let follow_ups = PostFollowUps {
+            pin: true,
+            reactions: vec!["rocket".to_string()],
+        };
+        let report = run_post_follow_ups(&client, "C123", "1.000", &follow_ups, true).await;
+
+        assert_eq!(report.steps.len(), 1);
+        let failed = report.failed_step().unwrap();
+        assert_eq!(failed.step, "pin");
+        assert!(failed.error.as_deref().unwrap().contains("not_pinnable"));
+        assert!(report.rolled_back);
+        assert!(report.rollback_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_msg_permalink() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/chat.getPermalink"))
+            .and(query_param("channel", "C123"))
+            .and(query_param("message_ts", "1.000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "channel": "C123",
+                "permalink": "https://example.slack.com/archives/C123/p1000"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let permalink = msg_permalink(&client, "C123", "1.000").await.unwrap();
+        assert_eq!(permalink, "https://example.slack.com/archives/C123/p1000");
+    }
 }
@@ -0,0 +1,401 @@
+//! User deactivation impact report (`users offboard-report`)
+//!
+//! Read-only composition of the calls an admin would otherwise make by hand
+//! before deactivating someone: the channels they are in, the usergroups
+//! they belong to, the files they own and their pending scheduled messages.
+//! A section that cannot be read (missing scope, wrong token type) is
+//! reported as a note instead of failing the whole report.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::schedule::plan_scheduled_list;
+use crate::commands::usergroup::plan_usergroups_list;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Maximum pages to follow per section
+const MAX_PAGES: u32 = 100;
+
+/// Build one `users.conversations` page request
+pub fn plan_users_conversations(user: &str, cursor: Option<&str>) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::UsersConversations)
+        .param("user", user)
+        .param("types", "public_channel,private_channel,mpim")
+        .param("limit", 200)
+        .param_opt("cursor", cursor)
+}
+
+/// Build one `files.list` page request for files owned by `user`
+pub fn plan_files_list(user: &str, page: u32) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::FilesList)
+        .param("user", user)
+        .param("count", 200)
+        .param("page", page)
+}
+
+/// Channel the user is a member of
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OffboardChannel {
+    pub id: String,
+    pub name: String,
+    pub is_private: bool,
+}
+
+/// Usergroup the user belongs to
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OffboardUsergroup {
+    pub id: String,
+    pub handle: String,
+    pub name: String,
+    /// Members besides the user; 0 means the group becomes empty
+    pub other_members: usize,
+}
+
+/// File owned by the user
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OffboardFile {
+    pub id: String,
+    pub name: String,
+    pub created: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permalink: Option<String>,
+}
+
+/// Offboarding report
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OffboardReport {
+    pub user: String,
+    pub channels: Vec<OffboardChannel>,
+    pub usergroups: Vec<OffboardUsergroup>,
+    pub files: Vec<OffboardFile>,
+    /// Pending scheduled messages (`None` when not visible to the token)
+    pub scheduled_messages: Option<Vec<Value>>,
+    /// Sections that could not be read and why
+    pub notes: Vec<String>,
+    /// Follow-up actions derived from the findings
+    pub checklist: Vec<String>,
+}
+
+fn str_field(value: &Value, key: &str) -> String {
+    value
+        .get(key)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn next_cursor(response: &ApiResponse) -> Option<String> {
+    response
+        .data
+        .get("response_metadata")
+        .and_then(|meta| meta.get("next_cursor"))
+        .and_then(|c| c.as_str())
+        .filter(|c| !c.is_empty())
+        .map(|c| c.to_string())
+}
+
+fn section_error(section: &str, response: &ApiResponse) -> String {
+    format!(
+        "{}: {}",
+        section,
+        response.error.as_deref().unwrap_or("unknown_error")
+    )
+}
+
+/// Usergroups whose `users` include `user`
+pub fn usergroups_with_member(usergroups: &[Value], user: &str) -> Vec<OffboardUsergroup> {
+    usergroups
+        .iter()
+        .filter_map(|group| {
+            let users = group.get("users")?.as_array()?;
+            if !users.iter().any(|u| u.as_str() == Some(user)) {
+                return None;
+            }
+            Some(OffboardUsergroup {
+                id: str_field(group, "id"),
+                handle: str_field(group, "handle"),
+                name: str_field(group, "name"),
+                other_members: users.len() - 1,
+            })
+        })
+        .collect()
+}
+
+/// Follow-up actions for a report
+pub fn build_checklist(report: &OffboardReport) -> Vec<String> {
+    let mut checklist = Vec::new();
+    if !report.channels.is_empty() {
+        let private = report.channels.iter().filter(|c| c.is_private).count();
+        checklist.push(format!(
+            "Review membership of {} channel(s) ({} private); make sure private channels keep another member",
+            report.channels.len(),
+            private
+        ));
+    }
+    for group in &report.usergroups {
+        if group.other_members == 0 {
+            checklist.push(format!(
+                "Usergroup @{} will have no members; add a replacement or disable it",
+                group.handle
+            ));
+        } else {
+            checklist.push(format!(
+                "Remove from usergroup @{} ({} other member(s))",
+                group.handle, group.other_members
+            ));
+        }
+    }
+    if !report.files.is_empty() {
+        checklist.push(format!(
+            "Transfer or archive {} file(s) owned by the user",
+            report.files.len()
+        ));
+    }
+    if let Some(scheduled) = &report.scheduled_messages {
+        if !scheduled.is_empty() {
+            checklist.push(format!(
+                "Cancel or reassign {} pending scheduled message(s)",
+                scheduled.len()
+            ));
+        }
+    }
+    if !report.notes.is_empty() {
+        checklist.push("Re-check the sections listed under notes by hand".to_string());
+    }
+    checklist
+}
+
+/// Gather the offboarding report for a user
+///
+/// # Arguments
+/// * `client` - API client
+/// * `user` - User ID
+/// * `include_scheduled` - List scheduled messages; Slack only returns those
+///   of the token's own user, so this should be set only when `user` is the
+///   token's user
+///
+/// # Returns
+/// * `Ok(OffboardReport)`; sections Slack refused are listed in `notes`
+/// * `Err(ApiError)` if a request fails
+pub async fn offboard_report(
+    client: &ApiClient,
+    user: &str,
+    include_scheduled: bool,
+) -> Result<OffboardReport, ApiError> {
+    let mut report = OffboardReport {
+        user: user.to_string(),
+        ..Default::default()
+    };
+
+    // Channels
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_PAGES {
+        let response = client
+            .execute_planned(plan_users_conversations(user, cursor.as_deref()))
+            .await?;
+        if !response.ok {
+            report.notes.push(section_error("channels", &response));
+            break;
+        }
+        if let Some(channels) = response.data.get("channels").and_then(|c| c.as_array()) {
+            report.channels.extend(channels.iter().map(|channel| {
+                OffboardChannel {
+                    id: str_field(channel, "id"),
+                    name: str_field(channel, "name"),
+                    is_private: channel
+                        .get("is_private")
+                        .and_then(|p| p.as_bool())
+                        .unwrap_or(false),
+                }
+            }));
+        }
+        cursor = next_cursor(&response);
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    // Usergroups
+    let response = client
+        .execute_planned(plan_usergroups_list(true, false))
+        .await?;
+    if response.ok {
+        let groups = response
+            .data
+            .get("usergroups")
+            .and_then(|g| g.as_array())
+            .cloned()
+            .unwrap_or_default();
+        report.usergroups = usergroups_with_member(&groups, user);
+    } else {
+        report.notes.push(section_error("usergroups", &response));
+    }
+
+    // Files
+    for page in 1..=MAX_PAGES {
+        let response = client.execute_planned(plan_files_list(user, page)).await?;
+        if !response.ok {
+            report.notes.push(section_error("files", &response));
+            break;
+        }
+        if let Some(files) = response.data.get("files").and_then(|f| f.as_array()) {
+            report.files.extend(files.iter().map(|file| {
+                OffboardFile {
+                    id: str_field(file, "id"),
+                    name: str_field(file, "name"),
+                    created: file.get("created").and_then(|c| c.as_i64()).unwrap_or(0),
+                    permalink: file
+                        .get("permalink")
+                        .and_then(|p| p.as_str())
+                        .map(str::to_string),
+                }
+            }));
+        }
+        let pages = response
+            .data
+            .get("paging")
+            .and_then(|p| p.get("pages"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(1);
+        if u64::from(page) >= pages {
+            break;
+        }
+    }
+
+    // Scheduled messages
+    if include_scheduled {
+        let mut scheduled = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MAX_PAGES {
+            let response = client
+                .execute_planned(plan_scheduled_list(None, Some(100), cursor.as_deref()))
+                .await?;
+            if !response.ok {
+                report
+                    .notes
+                    .push(section_error("scheduled_messages", &response));
+                break;
+            }
+            if let Some(messages) = response
+                .data
+                .get("scheduled_messages")
+                .and_then(|m| m.as_array())
+            {
+                scheduled.extend(messages.iter().cloned());
+            }
+            cursor = next_cursor(&response);
+            if cursor.is_none() {
+                break;
+            }
+        }
+        report.scheduled_messages = Some(scheduled);
+    } else {
+        report.notes.push(
+            "scheduled_messages: only visible to the user's own token; run the report with their profile"
+                .to_string(),
+        );
+    }
+
+    report.checklist = build_checklist(&report);
+    Ok(report)
+}
+
+/// Render the report as text
+pub fn format_offboard_text(report: &OffboardReport) -> String {
+    let mut out = format!("Offboarding report for {}\n", report.user);
+
+    out.push_str(&format!("\nChannels ({}):\n", report.channels.len()));
+    for channel in &report.channels {
+        let marker = if channel.is_private { " (private)" } else { "" };
+        out.push_str(&format!("  #{} {}{}\n", channel.name, channel.id, marker));
+    }
+
+    out.push_str(&format!("\nUsergroups ({}):\n", report.usergroups.len()));
+    for group in &report.usergroups {
+        out.push_str(&format!(
+            "  @{} {} - {} other member(s)\n",
+            group.handle, group.id, group.other_members
+        ));
+    }
+
+    out.push_str(&format!("\nFiles ({}):\n", report.files.len()));
+    for file in &report.files {
+        out.push_str(&format!("  {} {}\n", file.id, file.name));
+    }
+
+    if let Some(scheduled) = &report.scheduled_messages {
+        out.push_str(&format!("\nScheduled messages ({}):\n", scheduled.len()));
+        for message in scheduled {
+            out.push_str(&format!(
+                "  {} in {} at {}\n",
+                str_field(message, "id"),
+                str_field(message, "channel_id"),
+                message.get("post_at").and_then(|p| p.as_i64()).unwrap_or(0)
+            ));
+        }
+    }
+
+    if !report.notes.is_empty() {
+        out.push_str("\nNotes:\n");
+        for note in &report.notes {
+            out.push_str(&format!("  - {}\n", note));
+        }
+    }
+
+    out.push_str("\nChecklist:\n");
+    if report.checklist.is_empty() {
+        out.push_str("  (nothing to do)\n");
+    }
+    for item in &report.checklist {
+        out.push_str(&format!("  [ ] {}\n", item));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_usergroups_with_member() {
+        let groups = vec![
+            json!({"id": "S1", "handle": "oncall", "name": "On-call", "users": ["U1", "U2"]}),
+            json!({"id": "S2", "handle": "design", "name": "Design", "users": ["U2"]}),
+            json!({"id": "S3", "handle": "solo", "name": "Solo", "users": ["U1"]}),
+        ];
+        let found = usergroups_with_member(&groups, "U1");
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].handle, "oncall");
+        assert_eq!(found[0].other_members, 1);
+        assert_eq!(found[1].other_members, 0);
+    }
+
+    #[test]
+    fn test_build_checklist_and_text() {
+        let mut report = OffboardReport {
+            user: "U1".to_string(),
+            channels: vec![OffboardChannel {
+                id: "C1".to_string(),
+                name: "secret".to_string(),
+                is_private: true,
+            }],
+            usergroups: vec![OffboardUsergroup {
+                id: "S3".to_string(),
+                handle: "solo".to_string(),
+                name: "Solo".to_string(),
+                other_members: 0,
+            }],
+            files: vec![],
+            scheduled_messages: Some(vec![json!({"id": "Q1", "channel_id": "C1", "post_at": 1})]),
+            notes: vec![],
+            checklist: vec![],
+        };
+        report.checklist = build_checklist(&report);
+        assert_eq!(report.checklist.len(), 3);
+        assert!(report.checklist[1].contains("@solo will have no members"));
+
+        let text = format_offboard_text(&report);
+        assert!(text.contains("#secret C1 (private)"));
+        assert!(text.contains("[ ] Cancel or reassign 1 pending scheduled message(s)"));
+    }
+}
@@ -0,0 +1,427 @@
+//! Shared output formatting for wrapper commands (`--format`, `--columns`)
+//!
+//! Each command that supports the global `--format` flag registers a
+//! [`RecordView`]: where its records live in the API response and which
+//! columns the tabular modes show by default. `--columns=a,b.c` replaces the
+//! default columns with dotted paths into each record.
+//!
+//! - `json`: the response (or envelope) as-is
+//! - `yaml`: the response as YAML
+//! - `jsonl`: one record per line
+//! - `table` / `tsv` / `csv`: one row per record
+
+use serde_json::Value;
+use std::fmt;
+
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Jsonl,
+    Table,
+    Tsv,
+    Csv,
+    Yaml,
+}
+
+impl OutputFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "jsonl" => Ok(OutputFormat::Jsonl),
+            "table" => Ok(OutputFormat::Table),
+            "tsv" => Ok(OutputFormat::Tsv),
+            "csv" => Ok(OutputFormat::Csv),
+            "yaml" => Ok(OutputFormat::Yaml),
+            _ => Err(format!(
+                "Invalid format '{}'. Valid values: json, jsonl, table, tsv, csv, yaml",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Jsonl => write!(f, "jsonl"),
+            OutputFormat::Table => write!(f, "table"),
+            OutputFormat::Tsv => write!(f, "tsv"),
+            OutputFormat::Csv => write!(f, "csv"),
+            OutputFormat::Yaml => write!(f, "yaml"),
+        }
+    }
+}
+
+/// Clock style for human-readable times (`output.time_format`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClockFormat {
+    /// `13:05`
+    #[default]
+    H24,
+    /// `1:05 PM`
+    H12,
+}
+
+impl ClockFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "24h" => Ok(ClockFormat::H24),
+            "12h" => Ok(ClockFormat::H12),
+            _ => Err(format!(
+                "Invalid time format '{}'. Valid values: 24h, 12h",
+                s
+            )),
+        }
+    }
+
+    /// Format minutes after midnight (`1440` is `24:00` / `12:00 AM`)
+    pub fn format_minutes(self, minute: u32) -> String {
+        match self {
+            ClockFormat::H24 => format!("{:02}:{:02}", minute / 60, minute % 60),
+            ClockFormat::H12 => {
+                let (hour, minute) = ((minute / 60) % 24, minute % 60);
+                let suffix = if hour < 12 { "AM" } else { "PM" };
+                let hour12 = if hour % 12 == 0 { 12 } else { hour % 12 };
+                format!("{}:{:02} {}", hour12, minute, suffix)
+            }
+        }
+    }
+}
+
+/// Where a command's records live and which columns are shown by default
+#[derive(Debug, Clone, Copy)]
+pub struct RecordView {
+    /// Dotted path to the record array, or to a single record object;
+    /// empty for the response root
+    pub path: &'static str,
+    /// Default columns (dotted paths into each record)
+    pub columns: &'static [&'static str],
+}
+
+/// Record view of a wrapper command, `None` when it has no tabular form
+pub fn view_for(command: &str) -> Option<RecordView> {
+    let view = |path, columns| Some(RecordView { path, columns });
+    match command {
+        "search" => view(
+            "messages.matches",
+            &["ts", "channel.name", "username", "text"],
+        ),
+        "search files" => view(
+            "files.matches",
+            &["id", "name", "filetype", "user", "permalink"],
+        ),
+        "search --profiles" => view(
+            "messages.matches",
+            &[
+                "workspace.profile",
+                "ts",
+                "channel.name",
+                "username",
+                "text",
+            ],
+        ),
+        "conv list" | "conv search" => view(
+            "channels",
+            &["id", "name", "is_private", "is_member", "num_members"],
+        ),
+        "conv members" => view(
+            "members",
+            &["id", "name", "display_name", "real_name", "is_bot"],
+        ),
+        "conv list delta" => view("changes", &["change", "id", "name", "detail"]),
+        "conv history" | "conv replies" => view("messages", &["ts", "user", "reply_count", "text"]),
+        "users info" => view("user", &["id", "name", "real_name", "profile.email", "tz"]),
+        "msg post" => view("", &["channel", "ts", "message.text"]),
+        "msg update" => view("", &["channel", "ts", "text"]),
+        "msg delete" => view("", &["channel", "ts"]),
+        "msg schedule" => view("", &["channel", "scheduled_message_id", "post_at"]),
+        "msg scheduled list" => view(
+            "scheduled_messages",
+            &["id", "channel_id", "post_at", "text"],
+        ),
+        "remind list" => view(
+            "reminders",
+            &["id", "text", "time", "complete_ts", "recurring"],
+        ),
+        "emoji list" => view("emoji", &["name", "alias_for", "url"]),
+        "bookmark list" => view("bookmarks", &["id", "title", "link", "emoji"]),
+        "usergroup list" => view(
+            "usergroups",
+            &["id", "handle", "name", "user_count", "description"],
+        ),
+        "file upload" => view("files", &["id", "name", "title", "permalink"]),
+        "file upload batch" => view("results", &["path", "status", "id", "permalink", "error"]),
+        "file list" => view(
+            "files",
+            &["id", "name", "filetype", "size", "created", "user"],
+        ),
+        "file delete" => view("results", &["id", "ok", "error"]),
+        _ => None,
+    }
+}
+
+/// Parse a `--columns` value (comma-separated dotted paths)
+pub fn parse_columns(spec: &str) -> Result<Vec<String>, String> {
+    let columns: Vec<String> = spec
+        .split(',')
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect();
+    if columns.is_empty() {
+        return Err("--columns requires at least one column".to_string());
+    }
+    Ok(columns)
+}
+
+/// Look up a dotted path (`channel.name`, `files.0.id`) in a value
+pub fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if path.is_empty() {
+        return Some(value);
+    }
+    path.split('.')
+        .try_fold(value, |current, key| match current {
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => current.get(key),
+        })
+}
+
+/// Records of a response according to its view
+pub fn extract_records(response: &Value, view: &RecordView) -> Vec<Value> {
+    match lookup(response, view.path) {
+        Some(Value::Array(items)) => items.clone(),
+        Some(Value::Null) | None => Vec::new(),
+        Some(single) => vec![single.clone()],
+    }
+}
+
+/// Render a command response in a non-envelope format
+///
+/// # Arguments
+/// * `response` - API response value
+/// * `command` - Command name used to select the [`RecordView`]
+/// * `format` - Output format
+/// * `columns` - Column override from `--columns`
+pub fn format_output(
+    response: &Value,
+    command: &str,
+    format: OutputFormat,
+    columns: Option<&[String]>,
+) -> Result<String, String> {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(response)
+            .map_err(|e| format!("Failed to serialize JSON: {}", e)),
+        OutputFormat::Yaml => {
+            serde_yaml::to_string(response).map_err(|e| format!("Failed to serialize YAML: {}", e))
+        }
+        _ => {
+            let view = view_for(command)
+                .ok_or_else(|| format!("--format {} is not supported by {}", format, command))?;
+            let default_columns: Vec<String> = view.columns.iter().map(|c| c.to_string()).collect();
+            format_records(
+                &extract_records(response, &view),
+                columns.unwrap_or(&default_columns),
+                format,
+            )
+        }
+    }
+}
+
+/// Render records as JSON lines or rows of the given columns
+pub fn format_records(
+    records: &[Value],
+    columns: &[String],
+    format: OutputFormat,
+) -> Result<String, String> {
+    let cells = |record: &Value| -> Vec<String> {
+        columns
+            .iter()
+            .map(|c| cell_text(lookup(record, c)))
+            .collect()
+    };
+
+    let mut output = String::new();
+    match format {
+        OutputFormat::Json => {
+            output = serde_json::to_string_pretty(records)
+                .map_err(|e| format!("Failed to serialize JSON: {}", e))?;
+        }
+        OutputFormat::Yaml => {
+            output = serde_yaml::to_string(records)
+                .map_err(|e| format!("Failed to serialize YAML: {}", e))?;
+        }
+        OutputFormat::Jsonl => {
+            for record in records {
+                output.push_str(&serde_json::to_string(record).map_err(|e| e.to_string())?);
+                output.push('\n');
+            }
+        }
+        OutputFormat::Tsv => {
+            output.push_str(&columns.join("\t"));
+            output.push('\n');
+            for record in records {
+                let row: Vec<String> = cells(record)
+                    .into_iter()
+                    .map(|c| c.replace(['\t', '\n'], " "))
+                    .collect();
+                output.push_str(&row.join("\t"));
+                output.push('\n');
+            }
+        }
+        OutputFormat::Csv => {
+            let header: Vec<String> = columns.iter().map(|c| csv_escape(c)).collect();
+            output.push_str(&header.join(","));
+            output.push('\n');
+            for record in records {
+                let row: Vec<String> = cells(record).iter().map(|c| csv_escape(c)).collect();
+                output.push_str(&row.join(","));
+                output.push('\n');
+            }
+        }
+        OutputFormat::Table => {
+            let header: Vec<String> = columns.iter().map(|c| c.to_uppercase()).collect();
+            let rows: Vec<Vec<String>> = records
+                .iter()
+                .map(|r| {
+                    cells(r)
+                        .into_iter()
+                        .map(|c| c.lines().next().unwrap_or("").to_string())
+                        .collect()
+                })
+                .collect();
+            let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+            let line = |cells: &[String]| {
+                let padded: Vec<String> = cells
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                    .collect();
+                format!("{}\n", padded.join("  ").trim_end())
+            };
+            output.push_str(&line(&header));
+            let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+            output.push_str(&line(&separator));
+            for row in &rows {
+                output.push_str(&line(row));
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// Text of one cell: strings as-is, scalars printed, nested values as JSON
+fn cell_text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other @ (Value::Array(_) | Value::Object(_))) => other.to_string(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_clock_format() {
+        assert_eq!(ClockFormat::H24.format_minutes(13 * 60 + 5), "13:05");
+        assert_eq!(ClockFormat::H24.format_minutes(1440), "24:00");
+        assert_eq!(ClockFormat::H12.format_minutes(13 * 60 + 5), "1:05 PM");
+        assert_eq!(ClockFormat::H12.format_minutes(30), "12:30 AM");
+        assert_eq!(ClockFormat::H12.format_minutes(12 * 60), "12:00 PM");
+        assert_eq!(ClockFormat::H12.format_minutes(1440), "12:00 AM");
+        assert!(ClockFormat::parse("ampm").is_err());
+    }
+
+    fn search_response() -> Value {
+        json!({
+            "ok": true,
+            "messages": {"matches": [
+                {"ts": "1.000", "channel": {"id": "C1", "name": "general"}, "username": "alice", "text": "hello, \"world\""},
+                {"ts": "2.000", "channel": {"id": "C2", "name": "dev"}, "username": "bob", "text": "line1\nline2"},
+            ]}
+        })
+    }
+
+    #[test]
+    fn test_output_format_parse_new_formats() {
+        assert_eq!(OutputFormat::parse("csv").unwrap(), OutputFormat::Csv);
+        assert_eq!(OutputFormat::parse("yaml").unwrap(), OutputFormat::Yaml);
+        assert_eq!(OutputFormat::Csv.to_string(), "csv");
+        assert!(OutputFormat::parse("xml").is_err());
+    }
+
+    #[test]
+    fn test_lookup_dotted_paths() {
+        let value = json!({"files": [{"id": "F1"}], "user": {"profile": {"email": "a@b.c"}}});
+        assert_eq!(lookup(&value, "files.0.id"), Some(&json!("F1")));
+        assert_eq!(lookup(&value, "user.profile.email"), Some(&json!("a@b.c")));
+        assert_eq!(lookup(&value, "user.missing"), None);
+        assert_eq!(lookup(&value, ""), Some(&value));
+    }
+
+    #[test]
+    fn test_format_output_csv_and_tsv() {
+        let response = search_response();
+        let csv = format_output(&response, "search", OutputFormat::Csv, None).unwrap();
+        assert_eq!(
+            csv,
+            "ts,channel.name,username,text\n1.000,general,alice,\"hello, \"\"world\"\"\"\n2.000,dev,bob,\"line1\nline2\"\n"
+        );
+
+        let columns = vec!["channel.id".to_string(), "text".to_string()];
+        let tsv = format_output(&response, "search", OutputFormat::Tsv, Some(&columns)).unwrap();
+        assert_eq!(
+            tsv,
+            "channel.id\ttext\nC1\thello, \"world\"\nC2\tline1 line2\n"
+        );
+    }
+
+    #[test]
+    fn test_format_output_table_single_record() {
+        let response =
+            json!({"ok": true, "channel": "C1", "ts": "1.000", "message": {"text": "hi"}});
+        let table = format_output(&response, "msg post", OutputFormat::Table, None).unwrap();
+        assert_eq!(
+            table,
+            "CHANNEL  TS     MESSAGE.TEXT\n-------  -----  ------------\nC1       1.000  hi\n"
+        );
+    }
+
+    #[test]
+    fn test_format_output_yaml_and_jsonl() {
+        let response = search_response();
+        let yaml = format_output(&response, "search", OutputFormat::Yaml, None).unwrap();
+        assert!(yaml.contains("matches:"));
+
+        let jsonl = format_output(&response, "search", OutputFormat::Jsonl, None).unwrap();
+        assert_eq!(jsonl.lines().count(), 2);
+
+        assert!(format_output(&response, "auth status", OutputFormat::Csv, None).is_err());
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        assert_eq!(
+            parse_columns("id, name,,profile.email").unwrap(),
+            vec!["id", "name", "profile.email"]
+        );
+        assert!(parse_columns(" , ").is_err());
+    }
+}
@@ -0,0 +1,150 @@
+//! Pin command implementations
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+
+/// Build a `pins.add` request
+pub fn plan_pins_add(channel: &str, ts: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::PinsAdd)
+        .param("channel", channel)
+        .param("timestamp", ts)
+}
+
+/// Build a `pins.remove` request
+pub fn plan_pins_remove(channel: &str, ts: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::PinsRemove)
+        .param("channel", channel)
+        .param("timestamp", ts)
+}
+
+/// Build a `pins.list` request
+pub fn plan_pins_list(channel: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::PinsList).param("channel", channel)
+}
+
+/// Pin a message to its channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `timestamp` - Message timestamp
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with pin confirmation
+/// * `Err(ApiError)` if the operation fails
+pub async fn pin_add(
+    client: &ApiClient,
+    channel: String,
+    timestamp: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    // Build hint with example command for non-interactive mode
+    let hint = format!("Example: slack-rs pin add {} {} --yes", channel, timestamp);
+    confirm_destructive_with_hint(yes, "pin this message", non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_pins_add(&channel, &timestamp))
+        .await
+}
+
+/// Unpin a message
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `timestamp` - Message timestamp
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with removal confirmation
+/// * `Err(ApiError)` if the operation fails
+pub async fn pin_remove(
+    client: &ApiClient,
+    channel: String,
+    timestamp: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    // Build hint with example command for non-interactive mode
+    let hint = format!(
+        "Example: slack-rs pin remove {} {} --yes",
+        channel, timestamp
+    );
+    confirm_destructive_with_hint(yes, "unpin this message", non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_pins_remove(&channel, &timestamp))
+        .await
+}
+
+/// List the items pinned in a channel
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the pinned `items`
+/// * `Err(ApiError)` if the operation fails
+pub async fn pin_list(client: &ApiClient, channel: String) -> Result<ApiResponse, ApiError> {
+    client.execute_planned(plan_pins_list(&channel)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_plan_pins() {
+        let add = plan_pins_add("C1", "1.0");
+        assert_eq!(add.method, ApiMethod::PinsAdd);
+        assert_eq!(add.params["timestamp"], "1.0");
+        assert_eq!(plan_pins_remove("C1", "1.0").method, ApiMethod::PinsRemove);
+        let list = plan_pins_list("C1");
+        assert_eq!(list.method, ApiMethod::PinsList);
+        assert_eq!(list.params["channel"], "C1");
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_pin_add_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = pin_add(
+            &client,
+            "C123456".to_string(),
+            "1234567890.123456".to_string(),
+            true,
+            false,
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_pin_remove_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = pin_remove(
+            &client,
+            "C123456".to_string(),
+            "1234567890.123456".to_string(),
+            true,
+            false,
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+}
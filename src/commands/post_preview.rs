@@ -0,0 +1,227 @@
+//! Local preview of a message before it is sent (`msg post --preview`)
+//!
+//! The preview shows the message the way it will read in Slack: mrkdwn
+//! conversion has already happened, encoded mentions (`<@U123>`,
+//! `<#C123|general>`, `<!here>`) are shown as names, links as their label,
+//! and blocks are rendered as readable lines with a summary of their types.
+//! Nothing here talks to Slack; user names come from the users cache.
+
+use super::inspect::render_blocks;
+use super::msg::PostFollowUps;
+use super::users_cache::{resolve_mentions, MentionFormat, WorkspaceCache};
+use regex::Regex;
+use serde_json::Value;
+
+/// What `msg post` is about to send
+#[derive(Debug, Clone, Copy)]
+pub struct PostPreview<'a> {
+    pub channel: &'a str,
+    pub thread_ts: Option<&'a str>,
+    pub reply_broadcast: bool,
+    /// Message text, or the notification fallback when `blocks` is set
+    pub text: &'a str,
+    pub blocks: Option<&'a Value>,
+    /// Number of messages with `--split`
+    pub split_chunks: Option<usize>,
+    /// Uploaded as a snippet instead of a message
+    pub as_snippet: bool,
+    pub follow_ups: &'a PostFollowUps,
+}
+
+/// Show encoded mentions, channels, broadcasts and links as readable text
+///
+/// User mentions are resolved through `users` when given; unknown users
+/// keep their `<@U...>` form so nothing is guessed.
+pub fn decode_message_text(text: &str, users: Option<&WorkspaceCache>) -> String {
+    let text = match users {
+        Some(cache) => resolve_mentions(text, cache, MentionFormat::DisplayName),
+        None => text.to_string(),
+    };
+    let token_regex = Regex::new(r"<([#!][^>|]+|[a-z][a-z0-9+.-]*:[^>|]+)(?:\|([^>]*))?>").unwrap();
+    token_regex
+        .replace_all(&text, |caps: &regex::Captures| {
+            let target = &caps[1];
+            let label = caps.get(2).map(|m| m.as_str()).filter(|l| !l.is_empty());
+            if let Some(id) = target.strip_prefix('#') {
+                format!("#{}", label.unwrap_or(id))
+            } else if let Some(special) = target.strip_prefix('!') {
+                match (special.strip_prefix("subteam^"), label) {
+                    (Some(_), Some(handle)) => format!("@{}", handle.trim_start_matches('@')),
+                    (Some(id), None) => format!("@{}", id),
+                    (None, _) => format!("@{}", special),
+                }
+            } else {
+                match label {
+                    Some(label) => format!("{} ({})", label, target),
+                    None => target.to_string(),
+                }
+            }
+        })
+        .into_owned()
+}
+
+/// Render the preview as lines for the terminal
+pub fn render_post_preview(preview: &PostPreview, users: Option<&WorkspaceCache>) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    let mut target = format!("To: {}", preview.channel);
+    if let Some(ts) = preview.thread_ts {
+        target.push_str(&format!(" (thread {}", ts));
+        if preview.reply_broadcast {
+            target.push_str(", also sent to the channel");
+        }
+        target.push(')');
+    }
+    lines.push(target);
+    if let Some(chunks) = preview.split_chunks.filter(|n| *n > 1) {
+        lines.push(format!(
+            "Delivery: split into {} messages, threaded under the first",
+            chunks
+        ));
+    } else if preview.as_snippet {
+        lines.push("Delivery: uploaded as a snippet".to_string());
+    }
+    let mut then: Vec<String> = Vec::new();
+    if preview.follow_ups.pin {
+        then.push("pin".to_string());
+    }
+    then.extend(
+        preview
+            .follow_ups
+            .reactions
+            .iter()
+            .map(|name| format!(":{}:", name)),
+    );
+    if !then.is_empty() {
+        lines.push(format!("Then: {}", then.join(", ")));
+    }
+
+    let rule = "-".repeat(40);
+    lines.push(rule.clone());
+    if preview.blocks.is_some() {
+        lines.push(format!(
+            "Notification text: {}",
+            decode_message_text(preview.text, users)
+        ));
+    } else {
+        lines.extend(
+            decode_message_text(preview.text, users)
+                .lines()
+                .map(str::to_string),
+        );
+    }
+    if let Some(blocks) = preview.blocks {
+        let types: Vec<&str> = blocks
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|b| b.get("type").and_then(|t| t.as_str()).unwrap_or("?"))
+            .collect();
+        lines.push(format!("Blocks ({}): {}", types.len(), types.join(", ")));
+        for line in render_blocks(blocks) {
+            lines.push(format!("  {}", decode_message_text(&line, users)));
+        }
+    }
+    lines.push(rule);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::users_cache::CachedUser;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn cache() -> WorkspaceCache {
+        let mut users = HashMap::new();
+        users.insert(
+            "U123".to_string(),
+            CachedUser {
+                id: "U123".to_string(),
+                name: "john".to_string(),
+                real_name: Some("John Doe".to_string()),
+                display_name: Some("johnd".to_string()),
+                deleted: false,
+                is_bot: false,
+                is_restricted: false,
+                is_ultra_restricted: false,
+                team_id: None,
+                tz: None,
+                tz_offset: None,
+            },
+        );
+        WorkspaceCache {
+            team_id: "T1".to_string(),
+            updated_at: 0,
+            users,
+        }
+    }
+
+    #[test]
+    fn test_decode_message_text() {
+        let text = "<!here> ping <@U123> and <@U999> in <#C1|general> / <#C2>, \
+                    <!subteam^S1|@oncall>: see <https://example.com|the doc> or <mailto:a@b.c>";
+        assert_eq!(
+            decode_message_text(text, Some(&cache())),
+            "@here ping @johnd and <@U999> in #general / #C2, \
+             @oncall: see the doc (https://example.com) or mailto:a@b.c"
+        );
+        assert_eq!(decode_message_text("hi <@U123>", None), "hi <@U123>");
+    }
+
+    #[test]
+    fn test_render_post_preview_blocks_and_follow_ups() {
+        let blocks = json!([
+            {"type": "header", "text": {"type": "plain_text", "text": "Release"}},
+            {"type": "section", "text": {"type": "mrkdwn", "text": "cc <@U123>"}},
+            {"type": "divider"}
+        ]);
+        let follow_ups = PostFollowUps {
+            pin: true,
+            reactions: vec!["rocket".to_string()],
+        };
+        let preview = PostPreview {
+            channel: "#releases",
+            thread_ts: Some("1700000000.000100"),
+            reply_broadcast: true,
+            text: "Release",
+            blocks: Some(&blocks),
+            split_chunks: None,
+            as_snippet: false,
+            follow_ups: &follow_ups,
+        };
+        let lines = render_post_preview(&preview, Some(&cache()));
+        assert_eq!(
+            lines[0],
+            "To: #releases (thread 1700000000.000100, also sent to the channel)"
+        );
+        assert_eq!(lines[1], "Then: pin, :rocket:");
+        assert!(lines.contains(&"Notification text: Release".to_string()));
+        assert!(lines.contains(&"Blocks (3): header, section, divider".to_string()));
+        assert!(lines.contains(&"  cc @johnd".to_string()));
+    }
+
+    #[test]
+    fn test_render_post_preview_split_text() {
+        let follow_ups = PostFollowUps::default();
+        let preview = PostPreview {
+            channel: "C1",
+            thread_ts: None,
+            reply_broadcast: false,
+            text: "*bold* line\nsecond",
+            blocks: None,
+            split_chunks: Some(3),
+            as_snippet: false,
+            follow_ups: &follow_ups,
+        };
+        let lines = render_post_preview(&preview, None);
+        assert_eq!(lines[0], "To: C1");
+        assert_eq!(
+            lines[1],
+            "Delivery: split into 3 messages, threaded under the first"
+        );
+        assert_eq!(lines[3], "*bold* line");
+        assert_eq!(lines[4], "second");
+    }
+}
@@ -1,9 +1,23 @@
 //! Reaction command implementations
 
-use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse};
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
 use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
-use serde_json::json;
-use std::collections::HashMap;
+
+/// Build a `reactions.add` request
+pub fn plan_react_add(channel: &str, timestamp: &str, name: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ReactionsAdd)
+        .param("channel", channel)
+        .param("timestamp", timestamp)
+        .param("name", name)
+}
+
+/// Build a `reactions.remove` request
+pub fn plan_react_remove(channel: &str, timestamp: &str, name: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ReactionsRemove)
+        .param("channel", channel)
+        .param("timestamp", timestamp)
+        .param("name", name)
+}
 
 /// Add a reaction to a message
 ///
@@ -35,12 +49,9 @@ pub async fn react_add(
     );
     confirm_destructive_with_hint(yes, "add this reaction", non_interactive, Some(&hint))?;
 
-    let mut params = HashMap::new();
-    params.insert("channel".to_string(), json!(channel));
-    params.insert("timestamp".to_string(), json!(timestamp));
-    params.insert("name".to_string(), json!(name));
-
-    client.call_method(ApiMethod::ReactionsAdd, params).await
+    client
+        .execute_planned(plan_react_add(&channel, &timestamp, &name))
+        .await
 }
 
 /// Remove a reaction from a message
@@ -73,12 +84,9 @@ pub async fn react_remove(
     );
     confirm_destructive_with_hint(yes, "remove this reaction", non_interactive, Some(&hint))?;
 
-    let mut params = HashMap::new();
-    params.insert("channel".to_string(), json!(channel));
-    params.insert("timestamp".to_string(), json!(timestamp));
-    params.insert("name".to_string(), json!(name));
-
-    client.call_method(ApiMethod::ReactionsRemove, params).await
+    client
+        .execute_planned(plan_react_remove(&channel, &timestamp, &name))
+        .await
 }
 
 #[cfg(test)]
@@ -86,6 +94,17 @@ mod tests {
     use super::*;
     use serial_test::serial;
 
+    #[test]
+    fn test_plan_react() {
+        let add = plan_react_add("C1", "1.0", "rocket");
+        assert_eq!(add.method, ApiMethod::ReactionsAdd);
+        assert_eq!(add.params["name"], "rocket");
+        assert_eq!(
+            plan_react_remove("C1", "1.0", "rocket").method,
+            ApiMethod::ReactionsRemove
+        );
+    }
+
     #[tokio::test]
     #[serial(write_guard)]
     async fn test_react_add_with_env_false() {
@@ -0,0 +1,155 @@
+//! Reminder commands (`remind add/list/complete/delete`)
+//!
+//! The `reminders.*` methods only accept user tokens, so every command runs
+//! with the profile's user token. The `--time` value is passed to Slack
+//! unchanged: a UNIX timestamp, a number of seconds from now, or natural
+//! language such as "in 15 minutes" or "every Thursday at 9am".
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+
+/// Build a `reminders.add` request
+pub fn plan_remind_add(text: &str, time: &str, user: Option<&str>) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::RemindersAdd)
+        .param("text", text)
+        .param("time", time)
+        .param_opt("user", user)
+}
+
+/// Build a `reminders.list` request
+pub fn plan_remind_list() -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::RemindersList)
+}
+
+/// Build a `reminders.complete` request
+pub fn plan_remind_complete(reminder: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::RemindersComplete).param("reminder", reminder)
+}
+
+/// Build a `reminders.delete` request
+pub fn plan_remind_delete(reminder: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::RemindersDelete).param("reminder", reminder)
+}
+
+/// Create a reminder
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `text` - Reminder text
+/// * `time` - When to remind, passed through to Slack
+/// * `user` - Optional user ID to remind (default: the token's user)
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the created `reminder`
+/// * `Err(ApiError)` if the operation fails
+pub async fn remind_add(
+    client: &ApiClient,
+    text: String,
+    time: String,
+    user: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs remind add \"{}\" --time=\"{}\" --yes",
+        text, time
+    );
+    confirm_destructive_with_hint(yes, "create this reminder", non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_remind_add(&text, &time, user.as_deref()))
+        .await
+}
+
+/// List the reminders created by or for the token's user
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with `reminders`
+/// * `Err(ApiError)` if the operation fails
+pub async fn remind_list(client: &ApiClient) -> Result<ApiResponse, ApiError> {
+    client.execute_planned(plan_remind_list()).await
+}
+
+/// Mark a reminder as complete
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `reminder` - Reminder ID (`Rm...`)
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+pub async fn remind_complete(
+    client: &ApiClient,
+    reminder: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs remind complete {} --yes", reminder);
+    confirm_destructive_with_hint(
+        yes,
+        "mark this reminder complete",
+        non_interactive,
+        Some(&hint),
+    )?;
+
+    client
+        .execute_planned(plan_remind_complete(&reminder))
+        .await
+}
+
+/// Delete a reminder
+///
+/// # Arguments
+/// * `client` - API client (user token)
+/// * `reminder` - Reminder ID (`Rm...`)
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+pub async fn remind_delete(
+    client: &ApiClient,
+    reminder: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs remind delete {} --yes", reminder);
+    confirm_destructive_with_hint(yes, "delete this reminder", non_interactive, Some(&hint))?;
+
+    client.execute_planned(plan_remind_delete(&reminder)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_plan_remind() {
+        let add = plan_remind_add("Stand-up", "every weekday at 9am", None);
+        assert_eq!(add.method, ApiMethod::RemindersAdd);
+        assert_eq!(add.params["time"], "every weekday at 9am");
+        assert!(!add.params.contains_key("user"));
+        assert_eq!(
+            plan_remind_add("Ping", "in 5 minutes", Some("U2")).params["user"],
+            "U2"
+        );
+        assert_eq!(plan_remind_list().method, ApiMethod::RemindersList);
+        assert_eq!(plan_remind_complete("Rm1").params["reminder"], "Rm1");
+        assert_eq!(plan_remind_delete("Rm1").method, ApiMethod::RemindersDelete);
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_remind_delete_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = remind_delete(&client, "Rm1".to_string(), true, false).await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+}
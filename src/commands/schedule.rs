@@ -0,0 +1,349 @@
+//! Scheduled message commands (`msg schedule`, `msg scheduled list/delete`)
+//!
+//! The delivery time is given either as an absolute `--at` or a relative
+//! `--in`:
+//! - `--at "2024-07-01 09:00"` (local time), `--at 09:00` (next occurrence),
+//!   `--at 2024-07-01T09:00:00+09:00` (RFC 3339) or a UNIX timestamp
+//! - `--in 2h`, `--in 1h30m`, `--in 3d` (units: s, m, h, d, w)
+//!
+//! Slack only accepts times in the future and at most 120 days ahead.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, TimeZone};
+
+/// Furthest ahead Slack allows a message to be scheduled (120 days)
+pub const MAX_SCHEDULE_AHEAD_SECS: i64 = 120 * 24 * 3600;
+
+const AT_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+];
+
+/// Parse a relative duration such as `2h`, `1h30m` or `45m` into seconds
+pub fn parse_duration(s: &str) -> Result<i64, String> {
+    let invalid = || {
+        format!(
+            "Invalid duration '{}': expected e.g. 30m, 2h, 1h30m, 3d (units: s, m, h, d, w)",
+            s
+        )
+    };
+    let mut total: i64 = 0;
+    let mut digits = String::new();
+    for c in s.trim().chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            'w' => 7 * 86400,
+            _ => return Err(invalid()),
+        };
+        let value: i64 = digits.parse().map_err(|_| invalid())?;
+        total = value
+            .checked_mul(unit)
+            .and_then(|v| total.checked_add(v))
+            .ok_or_else(invalid)?;
+        digits.clear();
+    }
+    if !digits.is_empty() || total == 0 {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+/// Parse an absolute `--at` time into a UNIX timestamp
+///
+/// Times without an offset are interpreted in the timezone of `now`.
+pub fn parse_at<Tz: TimeZone>(s: &str, now: &DateTime<Tz>) -> Result<i64, String> {
+    let s = s.trim();
+    if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+        return s.parse().map_err(|_| format!("Invalid timestamp '{}'", s));
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.timestamp());
+    }
+
+    let tz = now.timezone();
+    let local = |naive: NaiveDateTime| {
+        tz.from_local_datetime(&naive)
+            .earliest()
+            .map(|dt| dt.timestamp())
+            .ok_or_else(|| format!("'{}' does not exist in the local timezone", s))
+    };
+    if let Some(naive) = AT_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+    {
+        return local(naive);
+    }
+    if let Ok(time) = NaiveTime::parse_from_str(s, "%H:%M") {
+        // Time of day only: today, or tomorrow once it has passed
+        let today = now.naive_local().date().and_time(time);
+        let ts = local(today)?;
+        return if ts > now.timestamp() {
+            Ok(ts)
+        } else {
+            local(today + Duration::days(1))
+        };
+    }
+
+    Err(format!(
+        "Invalid --at value '{}': expected \"YYYY-MM-DD HH:MM\", HH:MM, RFC 3339 or a UNIX timestamp",
+        s
+    ))
+}
+
+/// Resolve `--at` / `--in` into the `post_at` timestamp
+///
+/// # Errors
+/// When neither or both are given, on parse errors, and when the time is
+/// not in the future or more than 120 days ahead
+pub fn resolve_post_at<Tz: TimeZone>(
+    at: Option<&str>,
+    in_: Option<&str>,
+    now: &DateTime<Tz>,
+) -> Result<i64, String> {
+    let post_at = match (at, in_) {
+        (Some(at), None) => parse_at(at, now)?,
+        (None, Some(in_)) => now.timestamp() + parse_duration(in_)?,
+        (Some(_), Some(_)) => return Err("Use either --at or --in, not both".to_string()),
+        (None, None) => {
+            return Err("Delivery time required: --at=TIME or --in=DURATION".to_string())
+        }
+    };
+
+    if post_at <= now.timestamp() {
+        return Err(format!("Scheduled time {} is in the past", post_at));
+    }
+    if post_at - now.timestamp() > MAX_SCHEDULE_AHEAD_SECS {
+        return Err(format!(
+            "Scheduled time {} is more than 120 days ahead",
+            post_at
+        ));
+    }
+    Ok(post_at)
+}
+
+/// Build a `chat.scheduleMessage` request
+pub fn plan_msg_schedule(
+    channel: &str,
+    text: &str,
+    post_at: i64,
+    thread_ts: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatScheduleMessage)
+        .param("channel", channel)
+        .param("text", text)
+        .param("post_at", post_at)
+        .param_opt("thread_ts", thread_ts)
+}
+
+/// Build a `chat.scheduledMessages.list` request
+pub fn plan_scheduled_list(
+    channel: Option<&str>,
+    limit: Option<u32>,
+    cursor: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatScheduledMessagesList)
+        .param_opt("channel", channel)
+        .param_opt("limit", limit)
+        .param_opt("cursor", cursor)
+}
+
+/// Build a `chat.deleteScheduledMessage` request
+pub fn plan_scheduled_delete(channel: &str, scheduled_message_id: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::ChatDeleteScheduledMessage)
+        .param("channel", channel)
+        .param("scheduled_message_id", scheduled_message_id)
+}
+
+/// Schedule a message for later delivery
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+/// * `text` - Message text
+/// * `post_at` - UNIX timestamp of the delivery time
+/// * `thread_ts` - Optional thread timestamp to reply to
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with `scheduled_message_id` and `post_at`
+/// * `Err(ApiError)` if the operation fails
+pub async fn msg_schedule(
+    client: &ApiClient,
+    channel: String,
+    text: String,
+    post_at: i64,
+    thread_ts: Option<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs msg schedule {} \"{}\" --at={} --yes",
+        channel, text, post_at
+    );
+    confirm_destructive_with_hint(yes, "schedule this message", non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_msg_schedule(
+            &channel,
+            &text,
+            post_at,
+            thread_ts.as_deref(),
+        ))
+        .await
+}
+
+/// List pending scheduled messages
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Optional channel ID to restrict the list to
+/// * `limit` - Optional page size
+/// * `cursor` - Optional pagination cursor
+pub async fn msg_scheduled_list(
+    client: &ApiClient,
+    channel: Option<String>,
+    limit: Option<u32>,
+    cursor: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    client
+        .execute_planned(plan_scheduled_list(
+            channel.as_deref(),
+            limit,
+            cursor.as_deref(),
+        ))
+        .await
+}
+
+/// Delete a pending scheduled message
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID the message was scheduled in
+/// * `scheduled_message_id` - ID returned by `msg schedule`
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+pub async fn msg_scheduled_delete(
+    client: &ApiClient,
+    channel: String,
+    scheduled_message_id: String,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs msg scheduled delete {} {} --yes",
+        channel, scheduled_message_id
+    );
+    confirm_destructive_with_hint(
+        yes,
+        "delete this scheduled message",
+        non_interactive,
+        Some(&hint),
+    )?;
+
+    client
+        .execute_planned(plan_scheduled_delete(&channel, &scheduled_message_id))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+    use serde_json::json;
+
+    fn tokyo_now() -> DateTime<FixedOffset> {
+        // 2024-06-30 12:00:00 +09:00
+        FixedOffset::east_opt(9 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2024, 6, 30, 12, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90s").unwrap(), 90);
+        assert_eq!(parse_duration("2h").unwrap(), 7200);
+        assert_eq!(parse_duration("1h30m").unwrap(), 5400);
+        assert_eq!(parse_duration("3d").unwrap(), 3 * 86400);
+        assert_eq!(parse_duration("1w").unwrap(), 7 * 86400);
+        for bad in ["", "2", "h", "2x", "0m", "-1h"] {
+            assert!(parse_duration(bad).is_err(), "{} should be rejected", bad);
+        }
+    }
+
+    #[test]
+    fn test_parse_at_formats() {
+        let now = tokyo_now();
+        // 2024-07-01 09:00 +09:00 == 2024-07-01 00:00 UTC
+        let expected = 1719792000;
+        assert_eq!(parse_at("2024-07-01 09:00", &now).unwrap(), expected);
+        assert_eq!(parse_at("2024-07-01T09:00:00", &now).unwrap(), expected);
+        assert_eq!(parse_at("2024-07-01T00:00:00Z", &now).unwrap(), expected);
+        assert_eq!(parse_at("1719792000", &now).unwrap(), expected);
+        assert!(parse_at("next tuesday", &now).is_err());
+    }
+
+    #[test]
+    fn test_parse_at_time_of_day_rolls_over() {
+        let now = tokyo_now();
+        // 13:00 is still ahead today, 09:00 has passed and means tomorrow
+        assert_eq!(parse_at("13:00", &now).unwrap(), now.timestamp() + 3600);
+        assert_eq!(
+            parse_at("09:00", &now).unwrap(),
+            now.timestamp() + 21 * 3600
+        );
+    }
+
+    #[test]
+    fn test_resolve_post_at() {
+        let now = tokyo_now();
+        assert_eq!(
+            resolve_post_at(None, Some("2h"), &now).unwrap(),
+            now.timestamp() + 7200
+        );
+        assert!(resolve_post_at(None, None, &now).is_err());
+        assert!(resolve_post_at(Some("13:00"), Some("2h"), &now).is_err());
+        assert!(resolve_post_at(Some("2024-06-30 11:00"), None, &now)
+            .unwrap_err()
+            .contains("past"));
+        assert!(resolve_post_at(None, Some("121d"), &now)
+            .unwrap_err()
+            .contains("120 days"));
+    }
+
+    #[test]
+    fn test_plan_schedule_requests() {
+        let request = plan_msg_schedule("C1", "hi", 1719792000, Some("1.0"));
+        assert_eq!(request.method, ApiMethod::ChatScheduleMessage);
+        assert_eq!(
+            request.to_json()["params"],
+            json!({"channel": "C1", "text": "hi", "post_at": 1719792000, "thread_ts": "1.0"})
+        );
+
+        let list = plan_scheduled_list(Some("C1"), None, None);
+        assert_eq!(list.http_method(), "GET");
+        assert_eq!(list.to_json()["params"], json!({"channel": "C1"}));
+
+        let delete = plan_scheduled_delete("C1", "Q123");
+        assert_eq!(
+            delete.to_json()["params"],
+            json!({"channel": "C1", "scheduled_message_id": "Q123"})
+        );
+        assert!(delete.is_write());
+    }
+}
@@ -1,9 +1,252 @@
 //! Search command implementation
 
-use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse};
-use serde_json::json;
+use super::output::{format_records, OutputFormat};
+use super::users_cache::WorkspaceCache;
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use serde_json::{json, Value};
 use std::collections::HashMap;
 
+/// What a search looks through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    /// Messages (`search`, search.messages)
+    Messages,
+    /// Files (`search files`, search.files)
+    Files,
+    /// Messages and files (`search all`, search.all)
+    All,
+}
+
+impl SearchTarget {
+    /// API method searching this target
+    pub fn method(self) -> ApiMethod {
+        match self {
+            SearchTarget::Messages => ApiMethod::SearchMessages,
+            SearchTarget::Files => ApiMethod::SearchFiles,
+            SearchTarget::All => ApiMethod::SearchAll,
+        }
+    }
+
+    /// CLI command name, also the output view name
+    pub fn command(self) -> &'static str {
+        match self {
+            SearchTarget::Messages => "search",
+            SearchTarget::Files => "search files",
+            SearchTarget::All => "search all",
+        }
+    }
+}
+
+/// Search modifiers set with `--in`, `--from`, `--after`, `--before`,
+/// `--has` and `--during`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryModifiers {
+    /// Channels as IDs, `#name` or `name`; `@name` searches a DM
+    pub channels: Vec<String>,
+    /// Authors as IDs, `@name`, `name` or `me`
+    pub users: Vec<String>,
+    /// `YYYY-MM-DD`
+    pub after: Option<String>,
+    /// `YYYY-MM-DD`
+    pub before: Option<String>,
+    /// `:emoji:` for a reaction, or `link`, `pin`, `star`, `reaction`
+    pub has: Vec<String>,
+    /// Month name, `YYYY`, `YYYY-MM`, `today`, `yesterday`, `week`, `month` or `year`
+    pub during: Option<String>,
+}
+
+impl QueryModifiers {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Whether `value` looks like a Slack ID with one of the given prefixes
+fn is_slack_id(value: &str, prefixes: &[char]) -> bool {
+    value.len() >= 9
+        && value.starts_with(prefixes)
+        && value
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// `in:` term for a channel
+///
+/// Channel IDs are turned into `#name` with `channel_names` (ID to name, e.g.
+/// the shell's channel cache); unknown IDs use `<#ID>` mention syntax.
+pub fn channel_modifier(value: &str, channel_names: &HashMap<String, String>) -> String {
+    let value = value.trim();
+    if let Some(user) = value.strip_prefix('@') {
+        return format!("in:@{}", user);
+    }
+    let id = value
+        .strip_prefix("<#")
+        .and_then(|v| v.strip_suffix('>'))
+        .map(|v| v.split('|').next().unwrap_or(v))
+        .unwrap_or(value);
+    if is_slack_id(id, &['C', 'G', 'D']) {
+        return match channel_names.get(id) {
+            Some(name) => format!("in:#{}", name),
+            None => format!("in:<#{}>", id),
+        };
+    }
+    format!("in:#{}", value.trim_start_matches('#'))
+}
+
+/// `from:` term for an author
+///
+/// User IDs and names are looked up in the users cache and written as
+/// `@username`; IDs missing from it use `<@ID>` mention syntax and unknown
+/// names are kept as typed.
+pub fn user_modifier(value: &str, cache: Option<&WorkspaceCache>) -> String {
+    let value = value.trim();
+    if value == "me" {
+        return "from:me".to_string();
+    }
+    let id = value
+        .strip_prefix("<@")
+        .and_then(|v| v.strip_suffix('>'))
+        .unwrap_or(value);
+    if is_slack_id(id, &['U', 'W', 'B']) {
+        return match cache.and_then(|c| c.users.get(id)) {
+            Some(user) => format!("from:@{}", user.name),
+            None => format!("from:<@{}>", id),
+        };
+    }
+    let name = value.trim_start_matches('@');
+    match cache.and_then(|c| c.find_by_name(name)) {
+        Some(user) => format!("from:@{}", user.name),
+        None => format!("from:@{}", name),
+    }
+}
+
+/// Append the search modifiers to `text`
+///
+/// Names are resolved by [`channel_modifier`] and [`user_modifier`]; dates,
+/// `--has` and `--during` values are validated.
+pub fn build_search_query(
+    text: &str,
+    modifiers: &QueryModifiers,
+    channel_names: &HashMap<String, String>,
+    users: Option<&WorkspaceCache>,
+) -> Result<String, String> {
+    let mut terms: Vec<String> = Vec::new();
+    if !text.trim().is_empty() {
+        terms.push(text.trim().to_string());
+    }
+    terms.extend(
+        modifiers
+            .channels
+            .iter()
+            .map(|c| channel_modifier(c, channel_names)),
+    );
+    terms.extend(modifiers.users.iter().map(|u| user_modifier(u, users)));
+    for (flag, key, date) in [
+        ("--after", "after", &modifiers.after),
+        ("--before", "before", &modifiers.before),
+    ] {
+        if let Some(date) = date {
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                format!(
+                    "Error: {} expects a date as YYYY-MM-DD, got '{}'",
+                    flag, date
+                )
+            })?;
+            terms.push(format!("{}:{}", key, date));
+        }
+    }
+    for has in &modifiers.has {
+        let has = has.trim();
+        let term = match has {
+            "link" | "pin" | "star" | "reaction" => has.to_string(),
+            _ => {
+                let emoji = has.trim_matches(':');
+                if emoji.is_empty() || emoji.contains(char::is_whitespace) {
+                    return Err(format!(
+                        "Error: --has expects :emoji:, link, pin, star or reaction, got '{}'",
+                        has
+                    ));
+                }
+                format!(":{}:", emoji)
+            }
+        };
+        terms.push(format!("has:{}", term));
+    }
+    if let Some(during) = &modifiers.during {
+        let value = during.trim().to_lowercase();
+        let month = MONTHS
+            .iter()
+            .find(|m| value.len() >= 3 && m.starts_with(value.as_str()))
+            .map(|m| m.to_string());
+        let padded = match value.len() {
+            4 => format!("{}-01-01", value),
+            7 => format!("{}-01", value),
+            _ => value.clone(),
+        };
+        let valid_date =
+            value.len() >= 4 && chrono::NaiveDate::parse_from_str(&padded, "%Y-%m-%d").is_ok();
+        let term = match value.as_str() {
+            "today" | "yesterday" | "week" | "month" | "year" => value.clone(),
+            _ if month.is_some() => month.unwrap_or_default(),
+            _ if valid_date => value.clone(),
+            _ => {
+                return Err(format!(
+                    "Error: --during expects a month name, YYYY, YYYY-MM, today, yesterday, week, month or year, got '{}'",
+                    during
+                ))
+            }
+        };
+        terms.push(format!("during:{}", term));
+    }
+    if terms.is_empty() {
+        return Err("Error: search needs a query or at least one of --in, --from, --after, --before, --has, --during".to_string());
+    }
+    Ok(terms.join(" "))
+}
+
+/// Build a `search.messages` request
+pub fn plan_search(
+    query: &str,
+    count: Option<u32>,
+    page: Option<u32>,
+    sort: Option<&str>,
+    sort_dir: Option<&str>,
+) -> PlannedRequest {
+    plan_search_target(SearchTarget::Messages, query, count, page, sort, sort_dir)
+}
+
+/// Build a search request for `target`
+pub fn plan_search_target(
+    target: SearchTarget,
+    query: &str,
+    count: Option<u32>,
+    page: Option<u32>,
+    sort: Option<&str>,
+    sort_dir: Option<&str>,
+) -> PlannedRequest {
+    PlannedRequest::new(target.method())
+        .param("query", query)
+        .param_opt("count", count)
+        .param_opt("page", page)
+        .param_opt("sort", sort)
+        .param_opt("sort_dir", sort_dir)
+}
+
 /// Search messages in Slack
 ///
 /// # Arguments
@@ -25,31 +268,531 @@ pub async fn search(
     sort: Option<String>,
     sort_dir: Option<String>,
 ) -> Result<ApiResponse, ApiError> {
-    let mut params = HashMap::new();
-    params.insert("query".to_string(), json!(query));
+    client
+        .execute_planned(plan_search(
+            &query,
+            count,
+            page,
+            sort.as_deref(),
+            sort_dir.as_deref(),
+        ))
+        .await
+}
+
+/// Search files (search.files) or messages and files (search.all)
+///
+/// Takes the same paging and sort options as [`search`]; search.all returns
+/// a `messages` and a `files` object, each with its own `matches` and
+/// `paging`.
+pub async fn search_target(
+    client: &ApiClient,
+    target: SearchTarget,
+    query: String,
+    count: Option<u32>,
+    page: Option<u32>,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    client
+        .execute_planned(plan_search_target(
+            target,
+            &query,
+            count,
+            page,
+            sort.as_deref(),
+            sort_dir.as_deref(),
+        ))
+        .await
+}
+
+/// Maximum pages `search_all` fetches (Slack stops serving search results past page 100)
+pub const SEARCH_MAX_PAGES: u64 = 100;
+
+/// Largest page size search.messages accepts
+const SEARCH_MAX_COUNT: usize = 100;
 
-    if let Some(count) = count {
-        params.insert("count".to_string(), json!(count));
+/// Identity of a match: channel and ts, or the result's `iid` when either is missing
+fn match_key(m: &Value) -> String {
+    match (
+        m.get("channel")
+            .and_then(|c| c.get("id"))
+            .and_then(|v| v.as_str()),
+        m.get("ts").and_then(|v| v.as_str()),
+    ) {
+        (Some(channel), Some(ts)) => format!("{}:{}", channel, ts),
+        _ => m
+            .get("iid")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .unwrap_or_else(|| m.to_string()),
     }
+}
 
-    if let Some(page) = page {
-        params.insert("page".to_string(), json!(page));
+/// Search and walk result pages, merging `messages.matches`
+///
+/// search.messages pages by number rather than cursor, so pages are walked
+/// up to `messages.paging.pages` (and at most `max_pages`). Results that move
+/// between pages while walking would show up twice, so matches are
+/// de-duplicated by channel and ts. With `limit`, walking stops once that
+/// many matches were collected.
+async fn search_pages(
+    client: &ApiClient,
+    query: String,
+    count: Option<u32>,
+    max_pages: u64,
+    limit: Option<usize>,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    let mut response = search(
+        client,
+        query.clone(),
+        count,
+        Some(1),
+        sort.clone(),
+        sort_dir.clone(),
+    )
+    .await?;
+    let pages = response
+        .data
+        .get("messages")
+        .and_then(|m| m.get("paging"))
+        .and_then(|p| p.get("pages"))
+        .and_then(|p| p.as_u64())
+        .unwrap_or(1)
+        .min(max_pages)
+        .min(SEARCH_MAX_PAGES);
+
+    let Some(all) = response
+        .data
+        .get_mut("messages")
+        .and_then(|m| m.get_mut("matches"))
+        .and_then(|m| m.as_array_mut())
+    else {
+        return Ok(response);
+    };
+    let mut seen = std::collections::HashSet::new();
+    all.retain(|m| seen.insert(match_key(m)));
+    let full = |all: &Vec<Value>| limit.is_some_and(|limit| all.len() >= limit);
+
+    for page in 2..=pages {
+        if full(all) {
+            break;
+        }
+        let next = search(
+            client,
+            query.clone(),
+            count,
+            Some(page as u32),
+            sort.clone(),
+            sort_dir.clone(),
+        )
+        .await?;
+        let matches = next
+            .data
+            .get("messages")
+            .and_then(|m| m.get("matches"))
+            .and_then(|m| m.as_array())
+            .cloned()
+            .unwrap_or_default();
+        all.extend(matches.into_iter().filter(|m| seen.insert(match_key(m))));
     }
+    if let Some(limit) = limit {
+        all.truncate(limit);
+    }
+
+    Ok(response)
+}
 
-    if let Some(sort) = sort {
-        params.insert("sort".to_string(), json!(sort));
+/// Search messages and fetch every result page
+///
+/// See [`search_pages`]; matches are merged into the first page's
+/// `messages.matches`.
+pub async fn search_all(
+    client: &ApiClient,
+    query: String,
+    count: Option<u32>,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    search_pages(client, query, count, SEARCH_MAX_PAGES, None, sort, sort_dir).await
+}
+
+/// Fetch the first `limit` matches, walking as many pages as needed
+pub async fn search_top(
+    client: &ApiClient,
+    query: String,
+    limit: usize,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+) -> Result<ApiResponse, ApiError> {
+    let count = limit.clamp(1, SEARCH_MAX_COUNT);
+    search_pages(
+        client,
+        query,
+        Some(count as u32),
+        // One spare page for matches dropped as duplicates
+        limit.div_ceil(count) as u64 + 1,
+        Some(limit),
+        sort,
+        sort_dir,
+    )
+    .await
+}
+
+/// Replace the `messages` object of a search response with its matches
+///
+/// `{"messages": {"matches": [...], "paging": ..., "total": N}}` becomes
+/// `{"messages": [...], "total": N}`, where `total` is Slack's count of all
+/// results, not only the fetched ones.
+pub fn flatten_search_matches(response: &mut ApiResponse) {
+    let Some(messages) = response.data.remove("messages") else {
+        return;
+    };
+    let total = messages.get("total").cloned();
+    let matches = messages
+        .get("matches")
+        .cloned()
+        .unwrap_or_else(|| json!([]));
+    response.data.insert("messages".to_string(), matches);
+    if let Some(total) = total {
+        response.data.insert("total".to_string(), total);
+    }
+}
+
+/// One workspace's share of a multi-profile search
+#[derive(Debug)]
+pub struct WorkspaceSearch {
+    pub profile: String,
+    pub team_id: String,
+    pub team_name: Option<String>,
+    /// Search response, or the error that workspace returned
+    pub result: Result<ApiResponse, String>,
+}
+
+/// Order of merged multi-profile matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeOrder {
+    /// By message timestamp across all workspaces
+    Timestamp,
+    /// Round-robin over each workspace's own ranking (scores are not
+    /// comparable between workspaces)
+    Score,
+}
+
+/// Merge per-workspace results into one search response
+///
+/// Every match gets a `workspace` object (`profile`, `team_id`,
+/// `team_name`). `page` selects a page of `count` merged matches; `None`
+/// keeps them all. Per-workspace totals and errors are listed under
+/// `workspaces`.
+pub fn merge_workspace_searches(
+    searches: &[WorkspaceSearch],
+    order: MergeOrder,
+    ascending: bool,
+    count: usize,
+    page: Option<usize>,
+) -> Value {
+    let mut ranked: Vec<Vec<Value>> = Vec::new();
+    let mut workspaces = Vec::new();
+    let mut total = 0;
+    for search in searches {
+        let tag = json!({
+            "profile": search.profile,
+            "team_id": search.team_id,
+            "team_name": search.team_name,
+        });
+        match &search.result {
+            Ok(response) => {
+                let messages = response.data.get("messages");
+                let workspace_total = messages
+                    .and_then(|m| m.get("total"))
+                    .and_then(|t| t.as_u64())
+                    .unwrap_or(0);
+                total += workspace_total;
+                let matches = messages
+                    .and_then(|m| m.get("matches"))
+                    .and_then(|m| m.as_array())
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|mut m| {
+                        if let Some(object) = m.as_object_mut() {
+                            object.insert("workspace".to_string(), tag.clone());
+                        }
+                        m
+                    })
+                    .collect();
+                ranked.push(matches);
+                workspaces.push(json!({
+                    "profile": search.profile,
+                    "team_id": search.team_id,
+                    "team_name": search.team_name,
+                    "total": workspace_total,
+                }));
+            }
+            Err(error) => workspaces.push(json!({
+                "profile": search.profile,
+                "team_id": search.team_id,
+                "team_name": search.team_name,
+                "error": error,
+            })),
+        }
     }
 
-    if let Some(sort_dir) = sort_dir {
-        params.insert("sort_dir".to_string(), json!(sort_dir));
+    let mut merged: Vec<Value> = match order {
+        MergeOrder::Timestamp => {
+            let mut all: Vec<Value> = ranked.into_iter().flatten().collect();
+            let ts = |m: &Value| ts_value(m.get("ts").and_then(|v| v.as_str()).unwrap_or(""));
+            all.sort_by(|a, b| {
+                let ordering = ts(a).total_cmp(&ts(b));
+                if ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            });
+            all
+        }
+        MergeOrder::Score => {
+            let mut iters: Vec<_> = ranked.into_iter().map(|r| r.into_iter()).collect();
+            let mut all = Vec::new();
+            loop {
+                let before = all.len();
+                all.extend(iters.iter_mut().filter_map(|it| it.next()));
+                if all.len() == before {
+                    break all;
+                }
+            }
+        }
+    };
+
+    let count = count.max(1);
+    let paging = match page {
+        Some(page) => {
+            let page = page.max(1);
+            merged = merged
+                .into_iter()
+                .skip((page - 1) * count)
+                .take(count)
+                .collect();
+            json!({
+                "count": count,
+                "page": page,
+                "pages": (total as usize).div_ceil(count).max(1),
+                "total": total,
+            })
+        }
+        None => json!({"count": merged.len(), "page": 1, "pages": 1, "total": total}),
+    };
+
+    json!({
+        "ok": true,
+        "messages": {
+            "total": total,
+            "matches": merged,
+            "paging": paging,
+        },
+        "workspaces": workspaces,
+    })
+}
+
+/// Key for `search --group-by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchGroupBy {
+    Channel,
+    User,
+}
+
+impl SearchGroupBy {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "channel" => Ok(SearchGroupBy::Channel),
+            "user" => Ok(SearchGroupBy::User),
+            _ => Err(format!(
+                "Invalid --group-by '{}'. Valid values: channel, user",
+                s
+            )),
+        }
     }
 
-    client.call_method(ApiMethod::SearchMessages, params).await
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchGroupBy::Channel => "channel",
+            SearchGroupBy::User => "user",
+        }
+    }
+}
+
+/// Match count and first/last match timestamps for one channel or user
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchGroup {
+    /// Channel or user ID
+    pub id: String,
+    /// Channel name or username, when the match carries one
+    pub name: Option<String>,
+    pub count: usize,
+    pub first_ts: String,
+    pub last_ts: String,
+}
+
+fn ts_value(ts: &str) -> f64 {
+    ts.parse().unwrap_or(0.0)
+}
+
+/// Aggregate `messages.matches` of a search response
+///
+/// # Returns
+/// Groups ordered by match count (descending), then by ID
+pub fn group_search_matches(response: &ApiResponse, group_by: SearchGroupBy) -> Vec<SearchGroup> {
+    let matches = response
+        .data
+        .get("messages")
+        .and_then(|m| m.get("matches"))
+        .and_then(|m| m.as_array())
+        .map(|m| m.as_slice())
+        .unwrap_or_default();
+
+    let mut groups: HashMap<String, SearchGroup> = HashMap::new();
+    for m in matches {
+        let (id, name) = match group_by {
+            SearchGroupBy::Channel => (
+                m.get("channel").and_then(|c| c.get("id")),
+                m.get("channel").and_then(|c| c.get("name")),
+            ),
+            SearchGroupBy::User => (m.get("user").or_else(|| m.get("bot_id")), m.get("username")),
+        };
+        let Some(id) = id.and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let ts = m.get("ts").and_then(|v| v.as_str()).unwrap_or("");
+
+        let group = groups.entry(id.to_string()).or_insert_with(|| SearchGroup {
+            id: id.to_string(),
+            name: None,
+            count: 0,
+            first_ts: ts.to_string(),
+            last_ts: ts.to_string(),
+        });
+        group.count += 1;
+        if group.name.is_none() {
+            group.name = name.and_then(|v| v.as_str()).map(String::from);
+        }
+        if ts_value(ts) < ts_value(&group.first_ts) {
+            group.first_ts = ts.to_string();
+        }
+        if ts_value(ts) > ts_value(&group.last_ts) {
+            group.last_ts = ts.to_string();
+        }
+    }
+
+    let mut groups: Vec<SearchGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.id.cmp(&b.id)));
+    groups
+}
+
+/// JSON form of a search summary
+pub fn search_summary_json(groups: &[SearchGroup], group_by: SearchGroupBy) -> Value {
+    json!({
+        "group_by": group_by.as_str(),
+        "total_matches": groups.iter().map(|g| g.count).sum::<usize>(),
+        "groups": groups
+            .iter()
+            .map(|g| json!({
+                "id": g.id,
+                "name": g.name,
+                "count": g.count,
+                "first_ts": g.first_ts,
+                "last_ts": g.last_ts,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Render a search summary as table, TSV, CSV, YAML or JSONL
+pub fn format_search_summary(
+    groups: &[SearchGroup],
+    group_by: SearchGroupBy,
+    format: OutputFormat,
+) -> Result<String, String> {
+    let rows: Vec<[String; 5]> = groups
+        .iter()
+        .map(|g| {
+            [
+                g.id.clone(),
+                g.name.clone().unwrap_or_default(),
+                g.count.to_string(),
+                g.first_ts.clone(),
+                g.last_ts.clone(),
+            ]
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&search_summary_json(groups, group_by))
+            .map_err(|e| format!("Failed to serialize JSON: {}", e)),
+        OutputFormat::Jsonl => Ok(search_summary_json(groups, group_by)["groups"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")),
+        OutputFormat::Yaml => serde_yaml::to_string(&search_summary_json(groups, group_by))
+            .map_err(|e| format!("Failed to serialize YAML: {}", e)),
+        OutputFormat::Csv => {
+            let summary = search_summary_json(groups, group_by);
+            let records = summary["groups"].as_array().cloned().unwrap_or_default();
+            let columns: Vec<String> = ["id", "name", "count", "first_ts", "last_ts"]
+                .iter()
+                .map(|c| c.to_string())
+                .collect();
+            format_records(&records, &columns, format)
+        }
+        OutputFormat::Tsv => {
+            let mut output = format!("{}\tname\tcount\tfirst_ts\tlast_ts\n", group_by.as_str());
+            for row in rows {
+                output.push_str(&row.join("\t"));
+                output.push('\n');
+            }
+            Ok(output)
+        }
+        OutputFormat::Table => {
+            let header = [
+                group_by.as_str().to_uppercase(),
+                "NAME".to_string(),
+                "COUNT".to_string(),
+                "FIRST_TS".to_string(),
+                "LAST_TS".to_string(),
+            ];
+            let mut widths = header.clone().map(|h| h.len());
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+            let line = |cells: &[String; 5]| {
+                let padded: Vec<String> = cells
+                    .iter()
+                    .zip(widths.iter())
+                    .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                    .collect();
+                format!("{}\n", padded.join("  ").trim_end())
+            };
+            let mut output = line(&header);
+            output.push_str(&line(&widths.map(|w| "-".repeat(w))));
+            for row in &rows {
+                output.push_str(&line(row));
+            }
+            Ok(output)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[tokio::test]
     async fn test_search_basic() {
@@ -60,4 +803,338 @@ mod tests {
         // Result will fail because there's no mock server, but that's expected
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_plan_search_target() {
+        let plan = plan_search_target(
+            SearchTarget::Files,
+            "report",
+            Some(5),
+            None,
+            Some("timestamp"),
+            None,
+        );
+        assert_eq!(plan.method, ApiMethod::SearchFiles);
+        assert_eq!(plan.params["query"], "report");
+        assert_eq!(plan.params["count"], 5);
+        assert!(!plan.params.contains_key("page"));
+        assert_eq!(SearchTarget::All.method().as_str(), "search.all");
+        assert_eq!(SearchTarget::Files.command(), "search files");
+    }
+
+    #[tokio::test]
+    async fn test_search_all_walks_pages() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        for page in 1..=3 {
+            Mock::given(method("GET"))
+                .and(path("/search.messages"))
+                .and(query_param("page", page.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": {
+                        "matches": [{"ts": format!("{}.0", page)}],
+                        "paging": {"page": page, "pages": 3}
+                    }
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let response = search_all(&client, "deploy".to_string(), Some(1), None, None)
+            .await
+            .unwrap();
+
+        let matches = response.data["messages"]["matches"].as_array().unwrap();
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[2]["ts"], "3.0");
+    }
+
+    #[tokio::test]
+    async fn test_search_top_dedupes_shifted_results() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        // A new result pushed "2.0" from page 1 onto page 2 between requests;
+        // the spare page makes up for the dropped duplicate
+        let pages = [vec!["1.0", "2.0"], vec!["2.0", "3.0"], vec!["4.0", "5.0"]];
+        for (i, ts) in pages.iter().enumerate() {
+            let matches: Vec<Value> = ts
+                .iter()
+                .map(|ts| json!({"ts": ts, "channel": {"id": "C1"}}))
+                .collect();
+            Mock::given(method("GET"))
+                .and(path("/search.messages"))
+                .and(query_param("page", (i + 1).to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "messages": {
+                        "matches": matches,
+                        "paging": {"page": i + 1, "pages": 3},
+                        "total": 6
+                    }
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let mut response = search_top(&client, "deploy".to_string(), 3, None, None)
+            .await
+            .unwrap();
+        let ts: Vec<&str> = response.data["messages"]["matches"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["ts"].as_str().unwrap())
+            .collect();
+        assert_eq!(ts, vec!["1.0", "2.0", "3.0"]);
+
+        flatten_search_matches(&mut response);
+        assert_eq!(response.data["messages"].as_array().unwrap().len(), 3);
+        assert_eq!(response.data["messages"][0]["ts"], "1.0");
+        assert_eq!(response.data["total"], 6);
+    }
+
+    #[test]
+    fn test_merge_workspace_searches() {
+        let workspace = |profile: &str, matches: Value| WorkspaceSearch {
+            profile: profile.to_string(),
+            team_id: format!("T_{}", profile),
+            team_name: None,
+            result: Ok(ApiResponse {
+                ok: true,
+                data: HashMap::from([(
+                    "messages".to_string(),
+                    json!({"total": matches.as_array().unwrap().len(), "matches": matches}),
+                )]),
+                error: None,
+            }),
+        };
+        let searches = vec![
+            workspace("acme", json!([{"ts": "300.0"}, {"ts": "100.0"}])),
+            workspace("home", json!([{"ts": "200.0"}, {"ts": "400.0"}])),
+            WorkspaceSearch {
+                profile: "old".to_string(),
+                team_id: "T_old".to_string(),
+                team_name: None,
+                result: Err("Slack API error: invalid_auth".to_string()),
+            },
+        ];
+
+        let merged = merge_workspace_searches(&searches, MergeOrder::Timestamp, false, 3, Some(1));
+        let ts: Vec<&str> = merged["messages"]["matches"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["ts"].as_str().unwrap())
+            .collect();
+        assert_eq!(ts, vec!["400.0", "300.0", "200.0"]);
+        assert_eq!(
+            merged["messages"]["matches"][0]["workspace"]["profile"],
+            "home"
+        );
+        assert_eq!(merged["messages"]["paging"]["pages"], 2);
+        assert_eq!(merged["messages"]["total"], 4);
+        assert_eq!(
+            merged["workspaces"][2]["error"],
+            "Slack API error: invalid_auth"
+        );
+
+        let page2 = merge_workspace_searches(&searches, MergeOrder::Timestamp, false, 3, Some(2));
+        assert_eq!(page2["messages"]["matches"][0]["ts"], "100.0");
+
+        // Score order interleaves each workspace's own ranking
+        let by_score = merge_workspace_searches(&searches, MergeOrder::Score, false, 20, None);
+        let ts: Vec<&str> = by_score["messages"]["matches"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["ts"].as_str().unwrap())
+            .collect();
+        assert_eq!(ts, vec!["300.0", "200.0", "100.0", "400.0"]);
+    }
+
+    fn summary_fixture() -> ApiResponse {
+        ApiResponse {
+            ok: true,
+            data: HashMap::from([(
+                "messages".to_string(),
+                json!({"matches": [
+                    {"ts": "300.0", "user": "U1", "username": "alice", "channel": {"id": "C1", "name": "general"}},
+                    {"ts": "100.0", "user": "U2", "username": "bob", "channel": {"id": "C1", "name": "general"}},
+                    {"ts": "200.0", "user": "U1", "username": "alice", "channel": {"id": "C2", "name": "deploys"}},
+                    {"ts": "250.0", "user": "U1", "username": "alice", "channel": {"id": "C1", "name": "general"}}
+                ]}),
+            )]),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_group_search_matches() {
+        let response = summary_fixture();
+
+        let by_channel = group_search_matches(&response, SearchGroupBy::Channel);
+        assert_eq!(
+            by_channel[0],
+            SearchGroup {
+                id: "C1".to_string(),
+                name: Some("general".to_string()),
+                count: 3,
+                first_ts: "100.0".to_string(),
+                last_ts: "300.0".to_string(),
+            }
+        );
+        assert_eq!(by_channel[1].id, "C2");
+
+        let by_user = group_search_matches(&response, SearchGroupBy::User);
+        assert_eq!(by_user[0].id, "U1");
+        assert_eq!(by_user[0].count, 3);
+        assert_eq!(by_user[1].name.as_deref(), Some("bob"));
+
+        let summary = search_summary_json(&by_channel, SearchGroupBy::Channel);
+        assert_eq!(summary["total_matches"], 4);
+        assert_eq!(summary["groups"][0]["last_ts"], "300.0");
+        assert!(SearchGroupBy::parse("team").is_err());
+    }
+
+    #[test]
+    fn test_format_search_summary() {
+        let groups = group_search_matches(&summary_fixture(), SearchGroupBy::Channel);
+
+        let tsv =
+            format_search_summary(&groups, SearchGroupBy::Channel, OutputFormat::Tsv).unwrap();
+        assert_eq!(
+            tsv,
+            "channel\tname\tcount\tfirst_ts\tlast_ts\nC1\tgeneral\t3\t100.0\t300.0\nC2\tdeploys\t1\t200.0\t200.0\n"
+        );
+
+        let table =
+            format_search_summary(&groups, SearchGroupBy::Channel, OutputFormat::Table).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "CHANNEL  NAME     COUNT  FIRST_TS  LAST_TS");
+        assert_eq!(lines[2], "C1       general  3      100.0     300.0");
+    }
+
+    fn search_users_cache() -> WorkspaceCache {
+        let user = |id: &str, name: &str, display: &str| {
+            (
+                id.to_string(),
+                crate::commands::users_cache::CachedUser {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    real_name: None,
+                    display_name: Some(display.to_string()),
+                    deleted: false,
+                    is_bot: false,
+                    is_restricted: false,
+                    is_ultra_restricted: false,
+                    team_id: None,
+                    tz: None,
+                    tz_offset: None,
+                },
+            )
+        };
+        WorkspaceCache {
+            team_id: "T1".to_string(),
+            updated_at: 0,
+            users: HashMap::from([
+                user("U01ALICE1", "alice", "Alice"),
+                user("U01BOB001", "bob.smith", "Bobby"),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_build_search_query_resolves_names() {
+        let channels = HashMap::from([("C01GENERAL".to_string(), "general".to_string())]);
+        let cache = search_users_cache();
+        let modifiers = QueryModifiers {
+            channels: vec![
+                "C01GENERAL".to_string(),
+                "#random".to_string(),
+                "C09UNKNOWN".to_string(),
+                "@alice".to_string(),
+            ],
+            users: vec![
+                "U01ALICE1".to_string(),
+                "@bobby".to_string(),
+                "carol".to_string(),
+                "U09NOBODY".to_string(),
+                "me".to_string(),
+            ],
+            ..Default::default()
+        };
+        let query = build_search_query("deploy", &modifiers, &channels, Some(&cache)).unwrap();
+        assert_eq!(
+            query,
+            "deploy in:#general in:#random in:<#C09UNKNOWN> in:@alice \
+             from:@alice from:@bob.smith from:@carol from:<@U09NOBODY> from:me"
+        );
+    }
+
+    #[test]
+    fn test_build_search_query_dates_and_has() {
+        let modifiers = QueryModifiers {
+            after: Some("2026-01-01".to_string()),
+            before: Some("2026-02-01".to_string()),
+            has: vec![
+                ":tada:".to_string(),
+                "white_check_mark".to_string(),
+                "link".to_string(),
+            ],
+            during: Some("Mar".to_string()),
+            ..Default::default()
+        };
+        let query = build_search_query("", &modifiers, &HashMap::new(), None).unwrap();
+        assert_eq!(
+            query,
+            "after:2026-01-01 before:2026-02-01 has::tada: has::white_check_mark: has:link during:march"
+        );
+
+        for (during, expected) in [
+            ("2025", "during:2025"),
+            ("2025-07", "during:2025-07"),
+            ("Yesterday", "during:yesterday"),
+        ] {
+            let modifiers = QueryModifiers {
+                during: Some(during.to_string()),
+                ..Default::default()
+            };
+            assert_eq!(
+                build_search_query("", &modifiers, &HashMap::new(), None).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_search_query_rejects_bad_values() {
+        let build =
+            |modifiers: QueryModifiers| build_search_query("", &modifiers, &HashMap::new(), None);
+        assert!(build(QueryModifiers {
+            after: Some("01/02/2026".to_string()),
+            ..Default::default()
+        })
+        .unwrap_err()
+        .contains("--after"));
+        assert!(build(QueryModifiers {
+            during: Some("2025-13".to_string()),
+            ..Default::default()
+        })
+        .unwrap_err()
+        .contains("--during"));
+        assert!(build(QueryModifiers {
+            has: vec!["::".to_string()],
+            ..Default::default()
+        })
+        .is_err());
+        assert!(build(QueryModifiers::default()).is_err());
+    }
 }
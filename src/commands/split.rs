@@ -0,0 +1,383 @@
+//! Long message splitting for `msg post --split`
+//!
+//! Slack truncates or rejects very long `chat.postMessage` texts. With
+//! `--split`, the text is cut into chunks at safe boundaries (line breaks,
+//! then whitespace), the first chunk is posted and the remaining chunks are
+//! threaded under it. A chunk that ends inside a ``` code fence is closed
+//! and the fence (with its language tag) is reopened in the next chunk, so
+//! every chunk renders on its own.
+
+use crate::api::{ApiClient, ApiError, ApiResponse};
+use crate::commands::msg::msg_post_with_blocks;
+use serde::Serialize;
+
+/// Default chunk size in characters (Slack recommends at most 4,000)
+pub const DEFAULT_SPLIT_LIMIT: usize = 3900;
+
+/// Smallest accepted `--split-limit`
+pub const MIN_SPLIT_LIMIT: usize = 200;
+
+/// Longest text Slack accepts in one message; longer texts are cut off
+pub const MAX_MESSAGE_CHARS: usize = 40_000;
+
+const TRUNCATION_MARKER: &str = "\n… (truncated)";
+
+const FENCE: &str = "```";
+
+/// Split `text` into chunks of at most `limit` characters
+///
+/// Texts that already fit are returned unchanged as a single chunk.
+pub fn split_message(text: &str, limit: usize) -> Vec<String> {
+    let limit = limit.max(MIN_SPLIT_LIMIT);
+    if text.chars().count() <= limit {
+        return vec![text.to_string()];
+    }
+
+    // Leave room for the closing fence of a chunk that ends in a code block
+    let mut splitter = Splitter {
+        budget: limit - FENCE.len() - 1,
+        chunks: Vec::new(),
+        current: String::new(),
+        open_fence: None,
+        fresh: true,
+    };
+    for line in text.split_inclusive('\n') {
+        if line.trim_start().starts_with(FENCE) {
+            splitter.push_fence(line);
+        } else {
+            for piece in split_long_line(line, splitter.budget / 2) {
+                splitter.push(piece);
+            }
+        }
+    }
+    splitter.finish()
+}
+
+/// Cut `text` to at most `limit` characters, ending with a truncation marker
+///
+/// The cut is made at the last line break when one is close to the limit,
+/// so the message does not end mid-line. Texts that fit are returned as-is.
+pub fn truncate_message(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+    let budget = limit.saturating_sub(TRUNCATION_MARKER.chars().count());
+    let kept: String = text.chars().take(budget).collect();
+    let kept = match kept.rfind('\n') {
+        Some(pos) if kept[..pos].chars().count() >= budget - budget / 10 => &kept[..pos],
+        _ => kept.as_str(),
+    };
+    format!("{}{}", kept.trim_end(), TRUNCATION_MARKER)
+}
+
+/// Cut the body of a message so that `prefix`, body and `suffix` fit in
+/// `limit` characters together
+///
+/// The prefix (usergroup mentions) and suffix (the post footer) are kept
+/// whole and only the body is truncated, so a long message still notifies
+/// its groups and ends with its footer.
+pub fn truncate_message_body(prefix: &str, body: &str, suffix: &str, limit: usize) -> String {
+    let budget = limit.saturating_sub(prefix.chars().count() + suffix.chars().count());
+    format!("{}{}{}", prefix, truncate_message(body, budget), suffix)
+}
+
+struct Splitter {
+    budget: usize,
+    chunks: Vec<String>,
+    current: String,
+    /// Opening line of the code block the current position is in
+    open_fence: Option<String>,
+    /// Whether `current` is empty or holds only a reopened fence
+    fresh: bool,
+}
+
+impl Splitter {
+    fn fits(&self, piece: &str) -> bool {
+        self.current.chars().count() + piece.chars().count() <= self.budget
+    }
+
+    fn push(&mut self, piece: &str) {
+        if !self.fits(piece) && !self.fresh {
+            self.flush();
+        }
+        self.current.push_str(piece);
+        self.fresh = false;
+    }
+
+    fn push_fence(&mut self, line: &str) {
+        match self.open_fence.take() {
+            Some(opening) => {
+                if self.fits(line) || self.fresh {
+                    self.current.push_str(line);
+                    self.fresh = false;
+                } else {
+                    // The flush closes the block; nothing is reopened
+                    self.open_fence = Some(opening);
+                    self.flush();
+                    self.open_fence = None;
+                    self.current.clear();
+                    self.fresh = true;
+                }
+            }
+            None => {
+                self.push(line);
+                self.open_fence = Some(line.trim_end().to_string());
+            }
+        }
+    }
+
+    /// Finish the current chunk and start the next one
+    fn flush(&mut self) {
+        let mut chunk = std::mem::take(&mut self.current).trim_end().to_string();
+        if let Some(ref opening) = self.open_fence {
+            if !chunk.is_empty() {
+                chunk.push('\n');
+                chunk.push_str(FENCE);
+            }
+            self.current = format!("{}\n", opening);
+        }
+        if !chunk.trim().is_empty() {
+            self.chunks.push(chunk);
+        }
+        self.fresh = true;
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        // A chunk holding only a reopened fence has nothing left to post
+        let reopened_only = self.fresh && self.open_fence.is_some();
+        let chunk = std::mem::take(&mut self.current).trim_end().to_string();
+        if !reopened_only && !chunk.trim().is_empty() {
+            self.chunks.push(chunk);
+        }
+        self.chunks
+    }
+}
+
+/// Cut a line longer than `max` characters at whitespace, or hard at `max`
+fn split_long_line(line: &str, max: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = line;
+    while rest.chars().count() > max {
+        let hard = rest
+            .char_indices()
+            .nth(max)
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        let cut = rest[..hard]
+            .rfind(char::is_whitespace)
+            .filter(|&i| i >= hard / 2)
+            .map(|i| i + rest[i..].chars().next().map_or(1, char::len_utf8))
+            .unwrap_or(hard);
+        pieces.push(&rest[..cut]);
+        rest = &rest[cut..];
+    }
+    if !rest.is_empty() {
+        pieces.push(rest);
+    }
+    pieces
+}
+
+/// Outcome of a split post
+#[derive(Debug, Clone, Serialize)]
+pub struct SplitReport {
+    /// Number of chunks the text was split into
+    pub chunks: usize,
+    /// Thread the remaining chunks were posted under
+    pub thread_ts: Option<String>,
+    /// ts of every posted chunk, in order
+    pub ts: Vec<String>,
+    /// Error of the first chunk that could not be posted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Post chunks as a message followed by thread replies
+///
+/// The first chunk goes to `thread_ts` when given (and is the only one
+/// broadcast with `reply_broadcast`), otherwise it starts a new thread.
+/// Confirmation is asked once. A failing later chunk stops the post and is
+/// recorded in the report; the chunks posted so far are kept.
+///
+/// # Returns
+/// * `Ok((ApiResponse, SplitReport))` with the first chunk's response
+/// * `Err(ApiError)` if the first chunk could not be posted
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_post_split(
+    client: &ApiClient,
+    channel: String,
+    chunks: Vec<String>,
+    thread_ts: Option<String>,
+    reply_broadcast: bool,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<(ApiResponse, SplitReport), ApiError> {
+    let mut chunks = chunks.into_iter();
+    let first_text = chunks.next().unwrap_or_default();
+    let total = chunks.len() + 1;
+
+    let first = msg_post_with_blocks(
+        client,
+        channel.clone(),
+        first_text,
+        None,
+        thread_ts.clone(),
+        reply_broadcast,
+        yes,
+        non_interactive,
+    )
+    .await?;
+    let first_ts = first
+        .data
+        .get("ts")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let posted_channel = first
+        .data
+        .get("channel")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&channel)
+        .to_string();
+
+    let parent = thread_ts.unwrap_or_else(|| first_ts.clone());
+    let mut report = SplitReport {
+        chunks: total,
+        thread_ts: (total > 1).then(|| parent.clone()),
+        ts: vec![first_ts],
+        error: None,
+    };
+
+    for (index, text) in chunks.enumerate() {
+        match msg_post_with_blocks(
+            client,
+            posted_channel.clone(),
+            text,
+            None,
+            Some(parent.clone()),
+            false,
+            true,
+            non_interactive,
+        )
+        .await
+        {
+            Ok(response) => report.ts.push(
+                response
+                    .data
+                    .get("ts")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            Err(e) => {
+                report.error = Some(format!("chunk {}/{}: {}", index + 2, total, e));
+                break;
+            }
+        }
+    }
+
+    Ok((first, report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_message() {
+        assert_eq!(truncate_message("short", 100), "short");
+
+        let text = "line\n".repeat(100);
+        let truncated = truncate_message(&text, 200);
+        assert!(truncated.chars().count() <= 200);
+        assert!(truncated.ends_with("line\n… (truncated)"));
+
+        // No line break near the limit: cut mid-text
+        let text = "あ".repeat(500);
+        let truncated = truncate_message(&text, 300);
+        assert_eq!(truncated.chars().count(), 300);
+        assert!(truncated.ends_with("… (truncated)"));
+    }
+
+    #[test]
+    fn test_truncate_message_body_keeps_footer() {
+        let body = "log line\n".repeat(5_000);
+        assert!(body.chars().count() > MAX_MESSAGE_CHARS);
+        let footer = "\nPosted by CI job 42";
+        let text = truncate_message_body("", &body, footer, MAX_MESSAGE_CHARS);
+        assert!(text.chars().count() <= MAX_MESSAGE_CHARS);
+        assert!(text.ends_with("… (truncated)\nPosted by CI job 42"));
+        assert!(text.starts_with("log line\n"));
+    }
+
+    #[test]
+    fn test_truncate_message_body_counts_mentions() {
+        let body = "x".repeat(MAX_MESSAGE_CHARS + 10);
+        let text = truncate_message_body("<!subteam^S1> ", &body, "", MAX_MESSAGE_CHARS);
+        assert_eq!(text.chars().count(), MAX_MESSAGE_CHARS);
+        assert!(text.starts_with("<!subteam^S1> xxx"));
+    }
+
+    fn fence_count(chunk: &str) -> usize {
+        chunk
+            .lines()
+            .filter(|l| l.trim_start().starts_with(FENCE))
+            .count()
+    }
+
+    #[test]
+    fn test_short_text_is_one_chunk() {
+        assert_eq!(split_message("hello\n", 1000), vec!["hello\n"]);
+    }
+
+    #[test]
+    fn test_splits_at_line_boundaries() {
+        let text = (0..100)
+            .map(|i| format!("line {:03} of the log output", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let chunks = split_message(&text, 500);
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= 500);
+            assert!(chunk.starts_with("line "));
+        }
+        assert_eq!(chunks.join("\n"), text);
+    }
+
+    #[test]
+    fn test_code_fences_are_reopened() {
+        let body = (0..60)
+            .map(|i| format!("let value_{} = {};", i, i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let text = format!("Intro paragraph\n```rust\n{}\n```\nOutro", body);
+        let chunks = split_message(&text, 300);
+        assert!(chunks.len() > 2);
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= 300, "{}", chunk);
+            assert_eq!(fence_count(chunk) % 2, 0, "unbalanced fence in {:?}", chunk);
+        }
+        assert!(chunks[1].starts_with("```rust\n"));
+        assert!(chunks.last().unwrap().ends_with("Outro"));
+    }
+
+    #[test]
+    fn test_long_line_without_breaks() {
+        let text = "x".repeat(1000);
+        let chunks = split_message(&text, 200);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 200));
+        assert_eq!(chunks.concat(), text);
+
+        let words = "word ".repeat(200);
+        let chunks = split_message(&words, 200);
+        assert!(chunks.iter().all(|c| c.ends_with("word")));
+    }
+
+    #[test]
+    fn test_multibyte_text() {
+        let text = "日本語のテキスト。".repeat(100);
+        let chunks = split_message(&text, 250);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 250));
+        assert_eq!(chunks.concat(), text);
+    }
+}
@@ -0,0 +1,387 @@
+//! Thread references for `msg post --thread-ts`
+//!
+//! Besides a raw timestamp, `--thread-ts` accepts:
+//! - a message permalink (`https://team.slack.com/archives/C123/p1700000000000100`)
+//! - `latest[:#channel][:from=@name][:text=PATTERN]`, the most recent
+//!   top-level message in the channel matching the conditions
+//!
+//! Both resolve to the ts of the thread root, so "append to today's status
+//! thread" needs a single call.
+
+use crate::api::{ApiClient, ApiError};
+use crate::commands::conv::filter::glob_match;
+use crate::commands::conv::{conv_history, conv_list};
+use serde_json::Value;
+use thiserror::Error;
+
+/// Messages scanned when resolving `latest`
+pub const LATEST_SCAN_LIMIT: u32 = 200;
+
+/// Thread reference errors
+#[derive(Debug, Error)]
+pub enum ThreadRefError {
+    #[error("Invalid thread reference: {0}")]
+    InvalidFormat(String),
+    #[error("Channel not found: {0}")]
+    ChannelNotFound(String),
+    #[error("Permalink points to channel {permalink}, but posting to {target}")]
+    ChannelMismatch { permalink: String, target: String },
+    #[error("No message matching '{0}' in the last {LATEST_SCAN_LIMIT} messages")]
+    NoMatchingMessage(String),
+    #[error(transparent)]
+    Api(#[from] ApiError),
+}
+
+/// Parsed `--thread-ts` value
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThreadRef {
+    /// Raw message timestamp
+    Ts(String),
+    /// Message permalink
+    Permalink {
+        channel: String,
+        ts: String,
+        /// Thread root from the `thread_ts` query parameter (links to replies)
+        thread_ts: Option<String>,
+    },
+    /// Most recent matching message
+    Latest {
+        /// Channel name (`#general`) or ID; defaults to the post channel
+        channel: Option<String>,
+        /// Author user/bot ID or name (`@` optional)
+        from: Option<String>,
+        /// Text pattern (glob, or case-insensitive substring without `*`)
+        text: Option<String>,
+    },
+}
+
+impl ThreadRef {
+    /// Parse a `--thread-ts` value
+    pub fn parse(value: &str) -> Result<Self, ThreadRefError> {
+        if value.starts_with("https://") || value.starts_with("http://") {
+            return parse_permalink(value).ok_or_else(|| {
+                ThreadRefError::InvalidFormat(format!("not a message permalink: {}", value))
+            });
+        }
+
+        let Some(rest) = value.strip_prefix("latest") else {
+            return Ok(ThreadRef::Ts(value.to_string()));
+        };
+        if !rest.is_empty() && !rest.starts_with(':') {
+            return Ok(ThreadRef::Ts(value.to_string()));
+        }
+
+        let (mut channel, mut from, mut text) = (None, None, None);
+        for segment in rest.split(':').filter(|s| !s.is_empty()) {
+            match segment.split_once('=') {
+                Some(("from", v)) => from = Some(v.trim_start_matches('@').to_string()),
+                Some(("text", v)) => text = Some(v.to_string()),
+                Some((key, _)) => {
+                    return Err(ThreadRefError::InvalidFormat(format!(
+                        "unknown condition '{}' (valid: from, text)",
+                        key
+                    )))
+                }
+                None => channel = Some(segment.to_string()),
+            }
+        }
+
+        Ok(ThreadRef::Latest {
+            channel,
+            from,
+            text,
+        })
+    }
+}
+
+/// Parse a Slack message permalink into a [`ThreadRef::Permalink`]
+///
+/// The `p` segment is the message ts without the dot
+/// (`p1700000000000100` -> `1700000000.000100`).
+pub fn parse_permalink(url: &str) -> Option<ThreadRef> {
+    let parsed = url::Url::parse(url).ok()?;
+    let mut segments = parsed.path_segments()?;
+    if segments.next()? != "archives" {
+        return None;
+    }
+    let channel = segments.next()?.to_string();
+    let digits = segments.next()?.strip_prefix('p')?;
+    if digits.len() <= 6 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (secs, micros) = digits.split_at(digits.len() - 6);
+    let thread_ts = parsed
+        .query_pairs()
+        .find(|(k, _)| k == "thread_ts")
+        .map(|(_, v)| v.into_owned());
+
+    Some(ThreadRef::Permalink {
+        channel,
+        ts: format!("{}.{}", secs, micros),
+        thread_ts,
+    })
+}
+
+/// Whether a message was posted by `from` (user/bot ID or name)
+pub fn message_from(message: &Value, from: &str) -> bool {
+    let str_at = |path: &[&str]| {
+        path.iter()
+            .try_fold(message, |v, key| v.get(*key))
+            .and_then(|v| v.as_str())
+    };
+
+    if [str_at(&["user"]), str_at(&["bot_id"])].contains(&Some(from)) {
+        return true;
+    }
+    [
+        str_at(&["username"]),
+        str_at(&["bot_profile", "name"]),
+        str_at(&["user_profile", "name"]),
+        str_at(&["user_profile", "display_name"]),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|name| name.eq_ignore_ascii_case(from))
+}
+
+/// Whether `value` looks like a conversation ID rather than a name
+pub fn is_conversation_id(value: &str) -> bool {
+    value.len() >= 9
+        && value.starts_with(['C', 'G', 'D'])
+        && value
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Resolve `#name` / `name` / ID to a conversation ID
+pub async fn resolve_channel_id(
+    client: &ApiClient,
+    channel: &str,
+) -> Result<String, ThreadRefError> {
+    let name = channel.trim_start_matches('#');
+    if is_conversation_id(name) {
+        return Ok(name.to_string());
+    }
+
+    let response = conv_list(
+        client,
+        Some("public_channel,private_channel".to_string()),
+        None,
+    )
+    .await?;
+    response
+        .data
+        .get("channels")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .find(|c| c.get("name").and_then(|n| n.as_str()) == Some(name))
+        .and_then(|c| c.get("id").and_then(|id| id.as_str()))
+        .map(|id| id.to_string())
+        .ok_or_else(|| ThreadRefError::ChannelNotFound(channel.to_string()))
+}
+
+/// Resolve a thread reference to the thread root ts
+///
+/// # Arguments
+/// * `client` - API client
+/// * `thread_ref` - Parsed `--thread-ts` value
+/// * `target_channel` - Channel the reply will be posted to
+pub async fn resolve_thread_ts(
+    client: &ApiClient,
+    thread_ref: &ThreadRef,
+    target_channel: &str,
+) -> Result<String, ThreadRefError> {
+    match thread_ref {
+        ThreadRef::Ts(ts) => Ok(ts.clone()),
+        ThreadRef::Permalink {
+            channel,
+            ts,
+            thread_ts,
+        } => {
+            if is_conversation_id(target_channel) && channel != target_channel {
+                return Err(ThreadRefError::ChannelMismatch {
+                    permalink: channel.clone(),
+                    target: target_channel.to_string(),
+                });
+            }
+            Ok(thread_ts.clone().unwrap_or_else(|| ts.clone()))
+        }
+        ThreadRef::Latest {
+            channel,
+            from,
+            text,
+        } => {
+            let channel =
+                resolve_channel_id(client, channel.as_deref().unwrap_or(target_channel)).await?;
+            let response =
+                conv_history(client, channel, Some(LATEST_SCAN_LIMIT), None, None, None).await?;
+
+            // History is newest first
+            let found = response
+                .data
+                .get("messages")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .find(|m| {
+                    from.as_deref().is_none_or(|from| message_from(m, from))
+                        && text.as_deref().is_none_or(|pattern| {
+                            m.get("text")
+                                .and_then(|t| t.as_str())
+                                .is_some_and(|t| glob_match(pattern, t))
+                        })
+                });
+
+            found
+                .and_then(|m| {
+                    m.get("thread_ts")
+                        .or_else(|| m.get("ts"))
+                        .and_then(|v| v.as_str())
+                })
+                .map(|ts| ts.to_string())
+                .ok_or_else(|| ThreadRefError::NoMatchingMessage(describe_latest(thread_ref)))
+        }
+    }
+}
+
+fn describe_latest(thread_ref: &ThreadRef) -> String {
+    match thread_ref {
+        ThreadRef::Latest {
+            channel,
+            from,
+            text,
+        } => {
+            let mut parts = vec!["latest".to_string()];
+            parts.extend(channel.clone());
+            parts.extend(from.as_ref().map(|f| format!("from=@{}", f)));
+            parts.extend(text.as_ref().map(|t| format!("text={}", t)));
+            parts.join(":")
+        }
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_thread_ref() {
+        assert_eq!(
+            ThreadRef::parse("1700000000.000100").unwrap(),
+            ThreadRef::Ts("1700000000.000100".to_string())
+        );
+        assert_eq!(
+            ThreadRef::parse(
+                "https://acme.slack.com/archives/C0123ABCD/p1700000000000100?thread_ts=1699999999.000001&cid=C0123ABCD"
+            )
+            .unwrap(),
+            ThreadRef::Permalink {
+                channel: "C0123ABCD".to_string(),
+                ts: "1700000000.000100".to_string(),
+                thread_ts: Some("1699999999.000001".to_string()),
+            }
+        );
+        assert_eq!(
+            ThreadRef::parse("latest:#status:from=@deploy-bot").unwrap(),
+            ThreadRef::Latest {
+                channel: Some("#status".to_string()),
+                from: Some("deploy-bot".to_string()),
+                text: None,
+            }
+        );
+        assert_eq!(
+            ThreadRef::parse("latest").unwrap(),
+            ThreadRef::Latest {
+                channel: None,
+                from: None,
+                text: None,
+            }
+        );
+        assert!(ThreadRef::parse("latest:#status:when=today").is_err());
+        assert!(ThreadRef::parse("https://acme.slack.com/team/U123").is_err());
+    }
+
+    #[test]
+    fn test_message_from() {
+        let message = json!({"user": "U1", "bot_id": "B1", "bot_profile": {"name": "Deploy-Bot"}});
+        assert!(message_from(&message, "U1"));
+        assert!(message_from(&message, "B1"));
+        assert!(message_from(&message, "deploy-bot"));
+        assert!(!message_from(&message, "alice"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_latest() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "channels": [{"id": "C0STATUS1", "name": "status"}]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.history"))
+            .and(query_param("channel", "C0STATUS1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": [
+                    {"ts": "3.0", "user": "U1", "text": "lunch?"},
+                    {"ts": "2.0", "bot_id": "B1", "username": "deploy-bot", "text": "Status for today"},
+                    {"ts": "1.0", "bot_id": "B1", "username": "deploy-bot", "text": "Status for yesterday"}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let thread_ref = ThreadRef::parse("latest:#status:from=@deploy-bot").unwrap();
+        let ts = resolve_thread_ts(&client, &thread_ref, "C0STATUS1")
+            .await
+            .unwrap();
+        assert_eq!(ts, "2.0");
+
+        let thread_ref = ThreadRef::parse("latest:#status:from=@nobody").unwrap();
+        let err = resolve_thread_ts(&client, &thread_ref, "C0STATUS1").await;
+        assert!(matches!(err, Err(ThreadRefError::NoMatchingMessage(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_channel_id_unique_key() {
+        use crate::idempotency::UniqueWindow;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "channels": [{"id": "C0GENERAL1", "name": "general"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".into(), mock_server.uri());
+        let by_name = resolve_channel_id(&client, "#general").await.unwrap();
+        let by_id = resolve_channel_id(&client, "C0GENERAL1").await.unwrap();
+        assert_eq!(by_name, "C0GENERAL1");
+
+        // `--unique-per` keys on the resolved ID, so both spellings match
+        let now = 1_700_000_000;
+        assert_eq!(
+            UniqueWindow::Day.derive_key(&by_name, None, "standup", now),
+            UniqueWindow::Day.derive_key(&by_id, None, "standup", now)
+        );
+
+        let err = resolve_channel_id(&client, "#random").await;
+        assert!(matches!(err, Err(ThreadRefError::ChannelNotFound(_))));
+    }
+}
@@ -0,0 +1,313 @@
+//! Bot vs user token comparison
+//!
+//! Runs the same representative read calls with both tokens of a profile
+//! and reports what each one can see. Bot tokens only see private channels
+//! the bot is a member of and cannot search at all, which is the usual
+//! answer to "why can't I see private channels".
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+use crate::api::{ApiClient, ApiError, ApiMethod};
+
+/// One read call run with each token
+#[derive(Debug, Clone)]
+pub struct TokenProbe {
+    /// Short description of the data being read
+    pub label: &'static str,
+    pub method: ApiMethod,
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+/// Representative read calls used by `auth compare-tokens`
+pub const TOKEN_PROBES: &[TokenProbe] = &[
+    TokenProbe {
+        label: "public channels",
+        method: ApiMethod::ConversationsList,
+        params: &[("types", "public_channel"), ("limit", "1000")],
+    },
+    TokenProbe {
+        label: "private channels",
+        method: ApiMethod::ConversationsList,
+        params: &[("types", "private_channel"), ("limit", "1000")],
+    },
+    TokenProbe {
+        label: "direct messages",
+        method: ApiMethod::ConversationsList,
+        params: &[("types", "im"), ("limit", "1000")],
+    },
+    TokenProbe {
+        label: "group DMs",
+        method: ApiMethod::ConversationsList,
+        params: &[("types", "mpim"), ("limit", "1000")],
+    },
+    TokenProbe {
+        label: "users",
+        method: ApiMethod::UsersList,
+        params: &[("limit", "1000")],
+    },
+    TokenProbe {
+        label: "message search",
+        method: ApiMethod::SearchMessages,
+        params: &[("query", "a"), ("count", "1")],
+    },
+];
+
+/// Result of one probe with one token
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeOutcome {
+    pub ok: bool,
+    /// Items visible in the first page (search: total matches)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    /// Whether more results exist beyond the first page
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Side-by-side result of one probe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenComparison {
+    pub label: String,
+    pub method: String,
+    /// `None` when the profile has no bot token
+    pub bot: Option<ProbeOutcome>,
+    /// `None` when the profile has no user token
+    pub user: Option<ProbeOutcome>,
+}
+
+/// Count visible items in a successful response
+fn count_items(method: &ApiMethod, data: &HashMap<String, Value>) -> Option<u64> {
+    match method {
+        ApiMethod::SearchMessages => data
+            .get("messages")
+            .and_then(|m| m.get("total"))
+            .and_then(|t| t.as_u64()),
+        _ => ["channels", "members"]
+            .iter()
+            .find_map(|key| data.get(*key).and_then(|v| v.as_array()))
+            .map(|items| items.len() as u64),
+    }
+}
+
+/// Run a single probe
+pub async fn run_probe(client: &ApiClient, probe: &TokenProbe) -> ProbeOutcome {
+    let params: HashMap<String, Value> = probe
+        .params
+        .iter()
+        .map(|(k, v)| (k.to_string(), json!(v)))
+        .collect();
+
+    match client.call_method(probe.method.clone(), params).await {
+        Ok(response) => ProbeOutcome {
+            ok: true,
+            count: count_items(&probe.method, &response.data),
+            more: response
+                .data
+                .get("response_metadata")
+                .and_then(|m| m.get("next_cursor"))
+                .and_then(|c| c.as_str())
+                .is_some_and(|c| !c.is_empty()),
+            error: None,
+        },
+        Err(ApiError::SlackError(code)) => ProbeOutcome {
+            ok: false,
+            count: None,
+            more: false,
+            error: Some(code),
+        },
+        Err(e) => ProbeOutcome {
+            ok: false,
+            count: None,
+            more: false,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Run every probe with the available tokens
+///
+/// # Arguments
+/// * `bot` - Client using the bot token, if the profile has one
+/// * `user` - Client using the user token, if the profile has one
+pub async fn compare_tokens(
+    bot: Option<&ApiClient>,
+    user: Option<&ApiClient>,
+) -> Vec<TokenComparison> {
+    let mut results = Vec::with_capacity(TOKEN_PROBES.len());
+    for probe in TOKEN_PROBES {
+        let bot_outcome = match bot {
+            Some(client) => Some(run_probe(client, probe).await),
+            None => None,
+        };
+        let user_outcome = match user {
+            Some(client) => Some(run_probe(client, probe).await),
+            None => None,
+        };
+        results.push(TokenComparison {
+            label: probe.label.to_string(),
+            method: probe.method.as_str().to_string(),
+            bot: bot_outcome,
+            user: user_outcome,
+        });
+    }
+    results
+}
+
+fn format_outcome(outcome: &Option<ProbeOutcome>) -> String {
+    match outcome {
+        None => "(no token)".to_string(),
+        Some(o) if o.ok => match o.count {
+            Some(n) if o.more => format!("{}+", n),
+            Some(n) => n.to_string(),
+            None => "ok".to_string(),
+        },
+        Some(o) => format!("error: {}", o.error.as_deref().unwrap_or("unknown")),
+    }
+}
+
+/// Render the comparison as a plain-text table
+pub fn format_token_comparison(results: &[TokenComparison]) -> String {
+    let label_width = results
+        .iter()
+        .map(|r| r.label.len())
+        .max()
+        .unwrap_or(0)
+        .max("DATA".len());
+    let rows: Vec<(String, String, String)> = results
+        .iter()
+        .map(|r| {
+            (
+                r.label.clone(),
+                format_outcome(&r.bot),
+                format_outcome(&r.user),
+            )
+        })
+        .collect();
+    let bot_width = rows
+        .iter()
+        .map(|(_, b, _)| b.len())
+        .max()
+        .unwrap_or(0)
+        .max("BOT".len());
+
+    let mut out = format!(
+        "{:<lw$}  {:<bw$}  USER\n",
+        "DATA",
+        "BOT",
+        lw = label_width,
+        bw = bot_width
+    );
+    for (label, bot, user) in rows {
+        out.push_str(&format!(
+            "{:<lw$}  {:<bw$}  {}\n",
+            label,
+            bot,
+            user,
+            lw = label_width,
+            bw = bot_width
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_compare_tokens() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .and(header("authorization", "Bearer xoxp-user"))
+            .and(query_param("types", "private_channel"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "channels": [{"id": "G1"}, {"id": "G2"}],
+                "response_metadata": {"next_cursor": "more"}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "channels": [{"id": "C1"}]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users.list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "members": [{"id": "U1"}, {"id": "U2"}, {"id": "U3"}]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search.messages"))
+            .and(header("authorization", "Bearer xoxb-bot"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": false,
+                "error": "not_allowed_token_type"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search.messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "messages": {"total": 42, "matches": []}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let bot = ApiClient::new_with_base_url("xoxb-bot".into(), mock_server.uri());
+        let user = ApiClient::new_with_base_url("xoxp-user".into(), mock_server.uri());
+        let results = compare_tokens(Some(&bot), Some(&user)).await;
+
+        assert_eq!(results.len(), TOKEN_PROBES.len());
+        let private = &results[1];
+        assert_eq!(private.bot.as_ref().unwrap().count, Some(1));
+        assert_eq!(private.user.as_ref().unwrap().count, Some(2));
+        assert!(private.user.as_ref().unwrap().more);
+
+        let search = &results[5];
+        assert_eq!(
+            search.bot.as_ref().unwrap().error.as_deref(),
+            Some("not_allowed_token_type")
+        );
+        assert_eq!(search.user.as_ref().unwrap().count, Some(42));
+
+        let table = format_token_comparison(&results);
+        assert!(table.contains("private channels"));
+        assert!(table.contains("2+"));
+        assert!(table.contains("error: not_allowed_token_type"));
+    }
+
+    #[tokio::test]
+    async fn test_compare_tokens_missing_token() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "channels": []
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let bot = ApiClient::new_with_base_url("xoxb-bot".into(), mock_server.uri());
+        let results = compare_tokens(Some(&bot), None).await;
+        assert!(results.iter().all(|r| r.user.is_none()));
+        assert!(format_token_comparison(&results).contains("(no token)"));
+    }
+}
@@ -0,0 +1,247 @@
+//! Usage command for API consumption trends
+//!
+//! Summarizes the daily per-method call counters recorded in the local
+//! usage store and relates each method to its Slack rate-limit tier.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::api::usage::{day_key, UsageStore};
+use crate::api::RateLimitTier;
+
+/// Default number of days shown by `slack-rs usage`
+pub const DEFAULT_USAGE_DAYS: u64 = 7;
+
+/// Usage report output structure
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageReport {
+    /// First day covered (inclusive, UTC)
+    pub since: String,
+    /// Total calls in the window
+    pub total_calls: u64,
+    /// Per-day totals, oldest first (days without calls are included)
+    pub days: Vec<DayUsage>,
+    /// Per-method totals, busiest first
+    pub methods: Vec<MethodUsage>,
+}
+
+/// Calls made on one day
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayUsage {
+    pub day: String,
+    pub calls: u64,
+}
+
+/// Calls made to one method across the window
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodUsage {
+    pub method: String,
+    pub tier: String,
+    /// Documented minimum requests per minute for the tier, if fixed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier_per_minute: Option<u32>,
+    pub total_calls: u64,
+    /// Highest single-day count
+    pub peak_daily_calls: u64,
+}
+
+/// Build a report for the `days` days ending at `now_secs`
+pub fn build_usage_report(
+    recorded: &BTreeMap<String, BTreeMap<String, u64>>,
+    days: u64,
+    now_secs: u64,
+) -> UsageReport {
+    let days = days.max(1);
+    let window: Vec<String> = (0..days)
+        .rev()
+        .map(|offset| day_key(now_secs.saturating_sub(offset * 86_400)))
+        .collect();
+
+    let mut per_method: BTreeMap<&str, (u64, u64)> = BTreeMap::new();
+    let mut day_totals = Vec::with_capacity(window.len());
+    for day in &window {
+        let mut calls = 0;
+        if let Some(counters) = recorded.get(day) {
+            for (method, count) in counters {
+                let entry = per_method.entry(method.as_str()).or_insert((0, 0));
+                entry.0 += count;
+                entry.1 = entry.1.max(*count);
+                calls += count;
+            }
+        }
+        day_totals.push(DayUsage {
+            day: day.clone(),
+            calls,
+        });
+    }
+
+    let mut methods: Vec<MethodUsage> = per_method
+        .into_iter()
+        .map(|(method, (total_calls, peak_daily_calls))| {
+            let tier = RateLimitTier::for_method(method);
+            MethodUsage {
+                method: method.to_string(),
+                tier: tier.label().to_string(),
+                tier_per_minute: tier.per_minute(),
+                total_calls,
+                peak_daily_calls,
+            }
+        })
+        .collect();
+    methods.sort_by_key(|m| std::cmp::Reverse(m.total_calls));
+
+    UsageReport {
+        since: window[0].clone(),
+        total_calls: day_totals.iter().map(|d| d.calls).sum(),
+        days: day_totals,
+        methods,
+    }
+}
+
+/// Render a report as plain text
+pub fn format_usage_text(report: &UsageReport) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "API usage since {} ({} call(s))\n\n",
+        report.since, report.total_calls
+    ));
+
+    let max_day = report.days.iter().map(|d| d.calls).max().unwrap_or(0);
+    out.push_str("Daily:\n");
+    for day in &report.days {
+        let bar_len = if max_day == 0 {
+            0
+        } else {
+            ((day.calls * 40).div_ceil(max_day)) as usize
+        };
+        out.push_str(&format!(
+            "  {}  {:>6}  {}\n",
+            day.day,
+            day.calls,
+            "#".repeat(bar_len)
+        ));
+    }
+
+    if !report.methods.is_empty() {
+        let width = report
+            .methods
+            .iter()
+            .map(|m| m.method.len())
+            .max()
+            .unwrap_or(0)
+            .max("METHOD".len());
+        out.push_str(&format!(
+            "\n  {:<width$}  {:>6}  {:>6}  TIER\n",
+            "METHOD",
+            "TOTAL",
+            "PEAK",
+            width = width
+        ));
+        for m in &report.methods {
+            let limit = m
+                .tier_per_minute
+                .map(|n| format!(" (~{}/min)", n))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "  {:<width$}  {:>6}  {:>6}  {}{}\n",
+                m.method,
+                m.total_calls,
+                m.peak_daily_calls,
+                m.tier,
+                limit,
+                width = width
+            ));
+        }
+    }
+
+    out
+}
+
+/// Show API consumption trends from the local usage store
+///
+/// # Arguments
+/// * `days` - Number of days to include (ending today)
+/// * `json_output` - Whether to output JSON format
+pub fn usage(days: u64, json_output: bool) -> Result<(), String> {
+    let store = UsageStore::open().map_err(|e| format!("Failed to load usage store: {}", e))?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let report = build_usage_report(store.days(), days, now);
+
+    if json_output {
+        let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        print!("{}", format_usage_text(&report));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2023-11-14T22:13:20Z
+    const NOW: u64 = 1_700_000_000;
+
+    fn recorded() -> BTreeMap<String, BTreeMap<String, u64>> {
+        let mut recorded = BTreeMap::new();
+        recorded.insert(
+            "2023-11-14".to_string(),
+            BTreeMap::from([
+                ("conversations.history".to_string(), 10),
+                ("chat.postMessage".to_string(), 2),
+            ]),
+        );
+        recorded.insert(
+            "2023-11-12".to_string(),
+            BTreeMap::from([("conversations.history".to_string(), 30)]),
+        );
+        recorded.insert(
+            "2023-10-01".to_string(),
+            BTreeMap::from([("users.list".to_string(), 99)]),
+        );
+        recorded
+    }
+
+    #[test]
+    fn test_build_usage_report_window() {
+        let report = build_usage_report(&recorded(), 3, NOW);
+        assert_eq!(report.since, "2023-11-12");
+        assert_eq!(
+            report.days.iter().map(|d| d.calls).collect::<Vec<_>>(),
+            vec![30, 0, 12]
+        );
+        assert_eq!(report.total_calls, 42);
+
+        // users.list falls outside the window
+        assert_eq!(report.methods.len(), 2);
+        assert_eq!(report.methods[0].method, "conversations.history");
+        assert_eq!(report.methods[0].total_calls, 40);
+        assert_eq!(report.methods[0].peak_daily_calls, 30);
+        assert_eq!(report.methods[0].tier, "tier3");
+        assert_eq!(report.methods[1].tier_per_minute, None);
+    }
+
+    #[test]
+    fn test_format_usage_text() {
+        let report = build_usage_report(&recorded(), 3, NOW);
+        let text = format_usage_text(&report);
+        assert!(text.starts_with("API usage since 2023-11-12 (42 call(s))"));
+        assert!(text.contains("2023-11-13       0  \n"));
+        assert!(text.contains("tier3 (~50/min)"));
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = build_usage_report(&BTreeMap::new(), 1, NOW);
+        assert_eq!(report.days.len(), 1);
+        assert_eq!(report.total_calls, 0);
+        assert!(!format_usage_text(&report).contains("METHOD"));
+    }
+}
@@ -0,0 +1,516 @@
+//! Usergroup command implementations (`usergroup list/create/update/users`)
+//!
+//! Also resolves usergroup handles to `<!subteam^ID>` mentions for
+//! `msg post --notify-group`, using a per-workspace handle cache.
+
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+use crate::commands::guards::{check_write_allowed, confirm_destructive_with_hint};
+use crate::commands::users_cache::DEFAULT_TTL_SECONDS;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Usergroup attributes set by `usergroup create` / `usergroup update`
+///
+/// `None` leaves a field unchanged on update.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsergroupFields {
+    pub name: Option<String>,
+    pub handle: Option<String>,
+    pub description: Option<String>,
+    /// Default channel IDs, sent comma-separated
+    pub channels: Option<Vec<String>>,
+}
+
+impl UsergroupFields {
+    fn apply(&self, plan: PlannedRequest) -> PlannedRequest {
+        plan.param_opt("name", self.name.as_deref())
+            .param_opt("handle", self.handle.as_deref())
+            .param_opt("description", self.description.as_deref())
+            .param_opt("channels", self.channels.as_ref().map(|c| c.join(",")))
+    }
+
+    /// Whether no field is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Build a `usergroups.list` request
+pub fn plan_usergroups_list(include_users: bool, include_disabled: bool) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::UsergroupsList)
+        .param("include_users", include_users)
+        .param("include_disabled", include_disabled)
+}
+
+/// Build a `usergroups.create` request
+pub fn plan_usergroups_create(name: &str, fields: &UsergroupFields) -> PlannedRequest {
+    let fields = UsergroupFields {
+        name: None,
+        ..fields.clone()
+    };
+    fields.apply(PlannedRequest::new(ApiMethod::UsergroupsCreate).param("name", name))
+}
+
+/// Build a `usergroups.update` request
+pub fn plan_usergroups_update(usergroup: &str, fields: &UsergroupFields) -> PlannedRequest {
+    fields.apply(PlannedRequest::new(ApiMethod::UsergroupsUpdate).param("usergroup", usergroup))
+}
+
+/// Build a `usergroups.users.list` request
+pub fn plan_usergroups_users_list(usergroup: &str) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::UsergroupsUsersList).param("usergroup", usergroup)
+}
+
+/// Build a `usergroups.users.update` request (`users` is sent comma-separated)
+pub fn plan_usergroups_users_update(usergroup: &str, users: &[String]) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::UsergroupsUsersUpdate)
+        .param("usergroup", usergroup)
+        .param("users", users.join(","))
+}
+
+/// Parse a members file for `usergroup users update --from-file`
+///
+/// User IDs are separated by newlines, commas or whitespace; `#` starts a
+/// comment. Duplicates are dropped, keeping the first occurrence.
+pub fn parse_members_file(content: &str) -> Vec<String> {
+    let mut users: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        for user in line.split(|c: char| c == ',' || c.is_whitespace()) {
+            if !user.is_empty() && !users.iter().any(|u| u == user) {
+                users.push(user.to_string());
+            }
+        }
+    }
+    users
+}
+
+/// Members to add and remove to get from `current` to `desired`
+pub fn membership_diff(current: &[String], desired: &[String]) -> (Vec<String>, Vec<String>) {
+    let added = desired
+        .iter()
+        .filter(|u| !current.contains(u))
+        .cloned()
+        .collect();
+    let removed = current
+        .iter()
+        .filter(|u| !desired.contains(u))
+        .cloned()
+        .collect();
+    (added, removed)
+}
+
+/// List usergroups
+///
+/// # Arguments
+/// * `client` - API client
+/// * `include_users` - Include member IDs of each group
+/// * `include_disabled` - Include disabled groups
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the `usergroups`
+/// * `Err(ApiError)` if the operation fails
+pub async fn usergroup_list(
+    client: &ApiClient,
+    include_users: bool,
+    include_disabled: bool,
+) -> Result<ApiResponse, ApiError> {
+    client
+        .execute_planned(plan_usergroups_list(include_users, include_disabled))
+        .await
+}
+
+/// Create a usergroup
+///
+/// # Arguments
+/// * `client` - API client
+/// * `name` - Group name
+/// * `fields` - Handle, description and default channels (`name` is ignored)
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the created `usergroup`
+/// * `Err(ApiError)` if the operation fails
+pub async fn usergroup_create(
+    client: &ApiClient,
+    name: String,
+    fields: UsergroupFields,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!("Example: slack-rs usergroup create \"{}\" --yes", name);
+    let action = format!("create usergroup '{}'", name);
+    confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_usergroups_create(&name, &fields))
+        .await
+}
+
+/// Update a usergroup's name, handle, description or default channels
+///
+/// # Arguments
+/// * `client` - API client
+/// * `usergroup` - Usergroup ID
+/// * `fields` - Fields to change
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with the updated `usergroup`
+/// * `Err(ApiError)` if the operation fails
+pub async fn usergroup_update(
+    client: &ApiClient,
+    usergroup: String,
+    fields: UsergroupFields,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let hint = format!(
+        "Example: slack-rs usergroup update {} --handle=oncall --yes",
+        usergroup
+    );
+    confirm_destructive_with_hint(yes, "update this usergroup", non_interactive, Some(&hint))?;
+
+    client
+        .execute_planned(plan_usergroups_update(&usergroup, &fields))
+        .await
+}
+
+/// List the members of a usergroup
+///
+/// # Arguments
+/// * `client` - API client
+/// * `usergroup` - Usergroup ID
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with member IDs in `users`
+/// * `Err(ApiError)` if the operation fails
+pub async fn usergroup_users(client: &ApiClient, usergroup: &str) -> Result<ApiResponse, ApiError> {
+    client
+        .execute_planned(plan_usergroups_users_list(usergroup))
+        .await
+}
+
+/// Replace the members of a usergroup
+///
+/// The current members are read first so the confirmation and the result
+/// can show who is added and removed. When nothing changes, no update is
+/// sent and the response carries `"changed": false`.
+///
+/// # Arguments
+/// * `client` - API client
+/// * `usergroup` - Usergroup ID
+/// * `users` - Complete list of member IDs
+/// * `yes` - Skip confirmation prompt
+/// * `non_interactive` - Whether running in non-interactive mode
+///
+/// # Returns
+/// * `Ok(ApiResponse)` with `added` and `removed` member IDs
+/// * `Err(ApiError)` if the operation fails
+pub async fn usergroup_users_update(
+    client: &ApiClient,
+    usergroup: String,
+    users: Vec<String>,
+    yes: bool,
+    non_interactive: bool,
+) -> Result<ApiResponse, ApiError> {
+    check_write_allowed()?;
+
+    let current = usergroup_users(client, &usergroup).await?;
+    if !current.ok {
+        return Ok(current);
+    }
+    let current: Vec<String> = current
+        .data
+        .get("users")
+        .and_then(|u| u.as_array())
+        .map(|users| {
+            users
+                .iter()
+                .filter_map(|u| u.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let (added, removed) = membership_diff(&current, &users);
+
+    let mut response = if added.is_empty() && removed.is_empty() {
+        ApiResponse {
+            ok: true,
+            data: [("changed".to_string(), json!(false))]
+                .into_iter()
+                .collect(),
+            error: None,
+        }
+    } else {
+        let hint = format!(
+            "Example: slack-rs usergroup users update {} --from-file members.txt --yes",
+            usergroup
+        );
+        let action = format!(
+            "replace the members of this usergroup (+{} -{})",
+            added.len(),
+            removed.len()
+        );
+        confirm_destructive_with_hint(yes, &action, non_interactive, Some(&hint))?;
+
+        let mut response = client
+            .execute_planned(plan_usergroups_users_update(&usergroup, &users))
+            .await?;
+        response.data.insert("changed".to_string(), json!(true));
+        response
+    };
+    if response.ok {
+        response
+            .data
+            .insert("added".to_string(), Value::from(added));
+        response
+            .data
+            .insert("removed".to_string(), Value::from(removed));
+    }
+    Ok(response)
+}
+
+/// Cached handle -> ID map of a workspace's enabled usergroups
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UsergroupWorkspaceCache {
+    pub team_id: String,
+    pub updated_at: u64,
+    /// Handle (without `@`) -> usergroup ID
+    pub handles: HashMap<String, String>,
+}
+
+/// Usergroup cache file containing multiple workspace caches
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UsergroupCacheFile {
+    pub caches: HashMap<String, UsergroupWorkspaceCache>,
+}
+
+impl UsergroupCacheFile {
+    /// Get the default cache file path
+    pub fn default_path() -> Result<PathBuf, String> {
+        crate::profile::config_dir()
+            .map(|dir| dir.join("usergroups_cache.json"))
+            .ok_or_else(|| "Could not determine config directory".to_string())
+    }
+
+    /// Load cache from file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read cache file: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse cache file: {}", e))
+    }
+
+    /// Save cache to file
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize cache: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("Failed to write cache file: {}", e))
+    }
+
+    /// Get workspace cache if it is younger than `ttl_seconds`
+    pub fn fresh_workspace(
+        &self,
+        team_id: &str,
+        ttl_seconds: u64,
+    ) -> Option<&UsergroupWorkspaceCache> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        self.caches
+            .get(team_id)
+            .filter(|cache| now.saturating_sub(cache.updated_at) <= ttl_seconds)
+    }
+}
+
+/// Build the handle -> ID map from a `usergroups.list` response
+pub fn usergroup_handles(response: &ApiResponse) -> HashMap<String, String> {
+    response
+        .data
+        .get("usergroups")
+        .and_then(|g| g.as_array())
+        .map(|groups| {
+            groups
+                .iter()
+                .filter_map(|g| {
+                    let handle = g.get("handle")?.as_str()?;
+                    let id = g.get("id")?.as_str()?;
+                    Some((handle.to_string(), id.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Mention syntax for a usergroup ID
+pub fn subteam_mention(id: &str) -> String {
+    format!("<!subteam^{}>", id)
+}
+
+/// Fetch the enabled usergroups of a workspace
+async fn fetch_usergroup_handles(
+    client: &ApiClient,
+    team_id: String,
+) -> Result<UsergroupWorkspaceCache, ApiError> {
+    let response = usergroup_list(client, false, false).await?;
+    if !response.ok {
+        return Err(ApiError::SlackError(
+            response
+                .error
+                .unwrap_or_else(|| "unknown_error".to_string()),
+        ));
+    }
+    Ok(UsergroupWorkspaceCache {
+        team_id,
+        updated_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        handles: usergroup_handles(&response),
+    })
+}
+
+/// Resolve usergroup handles to `<!subteam^ID>` mentions
+///
+/// Handles may carry a leading `@`. The cached handle map is used while
+/// fresh; an unknown handle triggers one refresh (the group may be new)
+/// before it is reported as not found.
+///
+/// # Returns
+/// * `Ok(mentions)` in the order of `handles`
+/// * `Err(String)` if a handle does not exist or the lookup fails
+pub async fn resolve_usergroup_mentions(
+    client: &ApiClient,
+    team_id: String,
+    handles: &[String],
+) -> Result<Vec<String>, String> {
+    let handles: Vec<&str> = handles.iter().map(|h| h.trim_start_matches('@')).collect();
+    let cache_path = UsergroupCacheFile::default_path()?;
+    let mut cache_file = UsergroupCacheFile::load(&cache_path)?;
+
+    let cached = cache_file
+        .fresh_workspace(&team_id, DEFAULT_TTL_SECONDS)
+        .filter(|cache| handles.iter().all(|h| cache.handles.contains_key(*h)))
+        .cloned();
+    let cache = match cached {
+        Some(cache) => cache,
+        None => {
+            let cache = fetch_usergroup_handles(client, team_id)
+                .await
+                .map_err(|e| format!("Failed to list usergroups: {}", e))?;
+            cache_file
+                .caches
+                .insert(cache.team_id.clone(), cache.clone());
+            cache_file.save(&cache_path)?;
+            cache
+        }
+    };
+
+    handles
+        .iter()
+        .map(|handle| {
+            cache
+                .handles
+                .get(*handle)
+                .map(|id| subteam_mention(id))
+                .ok_or_else(|| format!("Usergroup not found: @{}", handle))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_plan_usergroups() {
+        let fields = UsergroupFields {
+            name: Some("ignored".to_string()),
+            handle: Some("oncall".to_string()),
+            channels: Some(vec!["C1".to_string(), "C2".to_string()]),
+            ..Default::default()
+        };
+        let create = plan_usergroups_create("On-call", &fields);
+        assert_eq!(create.method, ApiMethod::UsergroupsCreate);
+        assert_eq!(create.params["name"], "On-call");
+        assert_eq!(create.params["handle"], "oncall");
+        assert_eq!(create.params["channels"], "C1,C2");
+        assert!(!create.params.contains_key("description"));
+
+        let update = plan_usergroups_update("S1", &fields);
+        assert_eq!(update.params["usergroup"], "S1");
+        assert_eq!(update.params["name"], "ignored");
+
+        let users = plan_usergroups_users_update("S1", &["U1".to_string(), "U2".to_string()]);
+        assert_eq!(users.method, ApiMethod::UsergroupsUsersUpdate);
+        assert_eq!(users.params["users"], "U1,U2");
+        assert_eq!(
+            plan_usergroups_list(true, false).params["include_users"],
+            true
+        );
+    }
+
+    #[test]
+    fn test_usergroup_handles() {
+        let response: ApiResponse = serde_json::from_value(json!({
+            "ok": true,
+            "usergroups": [
+                {"id": "S1", "handle": "oncall"},
+                {"id": "S2", "handle": "design"},
+                {"id": "S3"}
+            ]
+        }))
+        .unwrap();
+        let handles = usergroup_handles(&response);
+        assert_eq!(handles.len(), 2);
+        assert_eq!(subteam_mention(&handles["oncall"]), "<!subteam^S1>");
+    }
+
+    #[test]
+    fn test_parse_members_file_and_diff() {
+        let users = parse_members_file("# week 42\nU1\nU2, U3  # backup\n\nU1\n");
+        assert_eq!(users, vec!["U1", "U2", "U3"]);
+
+        let current = vec!["U1".to_string(), "U4".to_string()];
+        let (added, removed) = membership_diff(&current, &users);
+        assert_eq!(added, vec!["U2", "U3"]);
+        assert_eq!(removed, vec!["U4"]);
+    }
+
+    #[tokio::test]
+    #[serial(write_guard)]
+    async fn test_usergroup_users_update_with_env_false() {
+        std::env::set_var("SLACKCLI_ALLOW_WRITE", "false");
+        let client = ApiClient::with_token("test_token".to_string());
+        let result = usergroup_users_update(
+            &client,
+            "S1".to_string(),
+            vec!["U1".to_string()],
+            true,
+            false,
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), ApiError::WriteNotAllowed));
+        std::env::remove_var("SLACKCLI_ALLOW_WRITE");
+    }
+}
@@ -1,8 +1,15 @@
 //! Users command implementations
 
-use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse};
-use serde_json::json;
-use std::collections::HashMap;
+use crate::api::{ApiClient, ApiError, ApiMethod, ApiResponse, PlannedRequest};
+
+/// Build a `users.info` request
+///
+/// With `include_locale` Slack adds the user's `locale` to the response.
+pub fn plan_users_info(user: &str, include_locale: bool) -> PlannedRequest {
+    PlannedRequest::new(ApiMethod::UsersInfo)
+        .param("user", user)
+        .param_opt("include_locale", include_locale.then_some(true))
+}
 
 /// Get user information
 ///
@@ -14,10 +21,7 @@ use std::collections::HashMap;
 /// * `Ok(ApiResponse)` with user information
 /// * `Err(ApiError)` if the operation fails
 pub async fn users_info(client: &ApiClient, user: String) -> Result<ApiResponse, ApiError> {
-    let mut params = HashMap::new();
-    params.insert("user".to_string(), json!(user));
-
-    client.call_method(ApiMethod::UsersInfo, params).await
+    client.execute_planned(plan_users_info(&user, false)).await
 }
 
 #[cfg(test)]
@@ -31,4 +35,12 @@ mod tests {
         // Result will fail because there's no mock server, but that's expected
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_plan_users_info_include_locale() {
+        let plan = plan_users_info("U1", false);
+        assert!(!plan.params.contains_key("include_locale"));
+        let plan = plan_users_info("U1", true);
+        assert_eq!(plan.params["include_locale"], true);
+    }
 }
@@ -2,6 +2,9 @@
 //!
 //! Provides caching for user information to enable mention resolution
 //! without repeated API calls. Cache is stored per workspace with TTL.
+//! Resolution works both ways: `<@U…>` in received text becomes a name
+//! ([`resolve_mentions`]), and `@name` / `#channel` in outgoing text becomes
+//! mention syntax ([`encode_mentions`], `msg post --resolve-mentions`).
 
 use crate::api::{ApiClient, ApiError};
 use regex::Regex;
@@ -12,7 +15,7 @@ use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Default cache TTL in seconds (24 hours)
-const DEFAULT_TTL_SECONDS: u64 = 86400;
+pub const DEFAULT_TTL_SECONDS: u64 = 86400;
 
 /// Cached user information
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -23,6 +26,21 @@ pub struct CachedUser {
     pub display_name: Option<String>,
     pub deleted: bool,
     pub is_bot: bool,
+    /// Multi-channel guest
+    #[serde(default)]
+    pub is_restricted: bool,
+    /// Single-channel guest
+    #[serde(default)]
+    pub is_ultra_restricted: bool,
+    /// Home workspace; another team's ID for people from other organizations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// IANA timezone name (e.g. "America/New_York")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+    /// Current UTC offset in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz_offset: Option<i64>,
 }
 
 /// Workspace-specific user cache
@@ -39,6 +57,23 @@ pub struct UsersCacheFile {
     pub caches: HashMap<String, WorkspaceCache>,
 }
 
+impl CachedUser {
+    /// Name in the given style, falling back to the username
+    ///
+    /// Display names fall back to the real name first, as many users never
+    /// set a display name.
+    pub fn display_as(&self, format: MentionFormat) -> &str {
+        let display_name = self.display_name.as_deref().filter(|n| !n.is_empty());
+        let real_name = self.real_name.as_deref().filter(|n| !n.is_empty());
+        match format {
+            MentionFormat::DisplayName => display_name.or(real_name),
+            MentionFormat::RealName => real_name,
+            MentionFormat::Username => None,
+        }
+        .unwrap_or(&self.name)
+    }
+}
+
 impl UsersCacheFile {
     /// Create a new empty cache file
     pub fn new() -> Self {
@@ -49,8 +84,8 @@ impl UsersCacheFile {
 
     /// Get the default cache file path
     pub fn default_path() -> Result<PathBuf, String> {
-        directories::ProjectDirs::from("", "", "slack-rs")
-            .map(|dirs| dirs.config_dir().join("users_cache.json"))
+        crate::profile::config_dir()
+            .map(|dir| dir.join("users_cache.json"))
             .ok_or_else(|| "Could not determine config directory".to_string())
     }
 
@@ -108,9 +143,12 @@ impl Default for UsersCacheFile {
     }
 }
 
-/// Format option for mention resolution
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// How user names are rendered (mention resolution and other name output)
+///
+/// The default comes from the `output.user_name` setting.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum MentionFormat {
+    #[default]
     DisplayName,
     RealName,
     Username,
@@ -165,6 +203,7 @@ pub async fn fetch_all_users(
                 }
             }
         }
+        crate::progress::report("users.cache", all_users.len() as u64, None);
 
         // Check for next cursor
         cursor = response
@@ -193,7 +232,7 @@ pub async fn fetch_all_users(
 }
 
 /// Parse user from JSON value
-fn parse_user_from_json(value: &serde_json::Value) -> Option<CachedUser> {
+pub(crate) fn parse_user_from_json(value: &serde_json::Value) -> Option<CachedUser> {
     let id = value.get("id")?.as_str()?.to_string();
     let name = value.get("name")?.as_str()?.to_string();
 
@@ -214,10 +253,21 @@ fn parse_user_from_json(value: &serde_json::Value) -> Option<CachedUser> {
         .get("deleted")
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
-    let is_bot = value
-        .get("is_bot")
-        .and_then(|v| v.as_bool())
-        .unwrap_or(false);
+    let flag = |key: &str| value.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+    let is_bot = flag("is_bot");
+    let is_restricted = flag("is_restricted");
+    let is_ultra_restricted = flag("is_ultra_restricted");
+    let team_id = value
+        .get("team_id")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    let tz = value
+        .get("tz")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string());
+    let tz_offset = value.get("tz_offset").and_then(|v| v.as_i64());
 
     Some(CachedUser {
         id,
@@ -226,6 +276,11 @@ fn parse_user_from_json(value: &serde_json::Value) -> Option<CachedUser> {
         display_name,
         deleted,
         is_bot,
+        is_restricted,
+        is_ultra_restricted,
+        team_id,
+        tz,
+        tz_offset,
     })
 }
 
@@ -246,16 +301,15 @@ pub fn resolve_mentions(text: &str, cache: &WorkspaceCache, format: MentionForma
             let user_id = &caps[1];
             match cache.users.get(user_id) {
                 Some(user) => {
+                    // Mentions keep Slack's own fallback to the handle
                     let name = match format {
                         MentionFormat::DisplayName => user
                             .display_name
                             .as_deref()
-                            .or(Some(&user.name))
+                            .filter(|n| !n.is_empty())
                             .unwrap_or(&user.name),
-                        MentionFormat::RealName => user.real_name.as_deref().unwrap_or(&user.name),
-                        MentionFormat::Username => &user.name,
+                        format => user.display_as(format),
                     };
-
                     format!("@{}", name)
                 }
                 None => caps[0].to_string(), // Keep original if not found
@@ -264,6 +318,89 @@ pub fn resolve_mentions(text: &str, cache: &WorkspaceCache, format: MentionForma
         .to_string()
 }
 
+impl WorkspaceCache {
+    /// Reverse lookup of a user by display name, username or real name
+    ///
+    /// Matching is case-insensitive and tries display names first, then
+    /// usernames, then real names; deleted users are skipped. A name shared
+    /// by several users at the first level that matches is ambiguous and
+    /// yields `None`.
+    pub fn find_by_name(&self, name: &str) -> Option<&CachedUser> {
+        let fields: [fn(&CachedUser) -> Option<&str>; 3] = [
+            |u| u.display_name.as_deref(),
+            |u| Some(u.name.as_str()),
+            |u| u.real_name.as_deref(),
+        ];
+        for field in fields {
+            let mut matches = self
+                .users
+                .values()
+                .filter(|u| !u.deleted && field(u).is_some_and(|n| n.eq_ignore_ascii_case(name)));
+            if let Some(user) = matches.next() {
+                return matches.next().is_none().then_some(user);
+            }
+        }
+        None
+    }
+}
+
+/// Text with `@name` / `#channel` tokens rewritten by [`encode_mentions`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodedMentions {
+    pub text: String,
+    /// Tokens left as typed (`@name` or `#channel`), in order of appearance
+    pub unresolved: Vec<String>,
+}
+
+/// Rewrite `@name` and `#channel-name` tokens into `<@U…>` / `<#C…>`
+///
+/// Users are looked up with [`WorkspaceCache::find_by_name`] and channels
+/// in `channels` (name to ID). `@here`, `@channel` and `@everyone` become
+/// the matching special mentions. A token only counts at the start of the
+/// text or after whitespace or an opening bracket, so emails, URLs and
+/// existing `<@U…>` mentions are left alone, as is anything inside code
+/// spans and fences. Names with spaces cannot be written as a token.
+pub fn encode_mentions(
+    text: &str,
+    cache: &WorkspaceCache,
+    channels: &HashMap<String, String>,
+) -> EncodedMentions {
+    let token_regex =
+        Regex::new(r"```[\s\S]*?```|`[^`\n]*`|(^|[\s(\[])([@#])([\w][\w.\-]*)").unwrap();
+    let mut unresolved = Vec::new();
+
+    let text = token_regex
+        .replace_all(text, |caps: &regex::Captures| {
+            let (Some(lead), Some(sigil), Some(name)) = (caps.get(1), caps.get(2), caps.get(3))
+            else {
+                return caps[0].to_string();
+            };
+            // A trailing period ends the sentence, not the name
+            let name = name.as_str();
+            let trimmed = name.trim_end_matches('.');
+            let rest = &name[trimmed.len()..];
+            let mention = match sigil.as_str() {
+                "@" => match trimmed.to_ascii_lowercase().as_str() {
+                    special @ ("here" | "channel" | "everyone") => Some(format!("<!{}>", special)),
+                    _ => cache.find_by_name(trimmed).map(|u| format!("<@{}>", u.id)),
+                },
+                _ => channels
+                    .get(&trimmed.to_lowercase())
+                    .map(|id| format!("<#{}>", id)),
+            };
+            match mention {
+                Some(mention) => format!("{}{}{}", lead.as_str(), mention, rest),
+                None => {
+                    unresolved.push(format!("{}{}", sigil.as_str(), trimmed));
+                    caps[0].to_string()
+                }
+            }
+        })
+        .to_string();
+
+    EncodedMentions { text, unresolved }
+}
+
 /// Update users cache for a workspace
 ///
 /// # Arguments
@@ -374,6 +511,11 @@ mod tests {
                 display_name: Some("johnd".to_string()),
                 deleted: false,
                 is_bot: false,
+                is_restricted: false,
+                is_ultra_restricted: false,
+                team_id: None,
+                tz: None,
+                tz_offset: None,
             },
         );
         users.insert(
@@ -385,6 +527,11 @@ mod tests {
                 display_name: None,
                 deleted: true,
                 is_bot: false,
+                is_restricted: false,
+                is_ultra_restricted: false,
+                team_id: None,
+                tz: None,
+                tz_offset: None,
             },
         );
 
@@ -418,6 +565,69 @@ mod tests {
         assert_eq!(result, "Hello @johnd!");
     }
 
+    fn reverse_cache() -> WorkspaceCache {
+        let user = |id: &str, name: &str, display: Option<&str>, deleted: bool| CachedUser {
+            id: id.to_string(),
+            name: name.to_string(),
+            real_name: Some(format!("{} Real", name)),
+            display_name: display.map(|d| d.to_string()),
+            deleted,
+            is_bot: false,
+            is_restricted: false,
+            is_ultra_restricted: false,
+            team_id: None,
+            tz: None,
+            tz_offset: None,
+        };
+        let users = [
+            user("U1", "alice", Some("Ali"), false),
+            user("U2", "bob", None, false),
+            user("U3", "carol", Some("sam"), false),
+            user("U4", "dave", Some("sam"), false),
+            user("U5", "old", Some("Ali"), true),
+        ];
+        WorkspaceCache {
+            team_id: "T1".to_string(),
+            updated_at: 0,
+            users: users.into_iter().map(|u| (u.id.clone(), u)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_find_by_name() {
+        let cache = reverse_cache();
+        // Display name, case-insensitive; the deleted user with the same name is skipped
+        assert_eq!(cache.find_by_name("ali").unwrap().id, "U1");
+        assert_eq!(cache.find_by_name("bob").unwrap().id, "U2");
+        assert_eq!(cache.find_by_name("carol real").unwrap().id, "U3");
+        // Shared display name
+        assert!(cache.find_by_name("sam").is_none());
+        assert!(cache.find_by_name("nobody").is_none());
+    }
+
+    #[test]
+    fn test_encode_mentions() {
+        let cache = reverse_cache();
+        let channels = HashMap::from([("ops".to_string(), "C100".to_string())]);
+
+        let encoded = encode_mentions(
+            "@Ali and @bob: see #ops. @here (cc @sam, #random)",
+            &cache,
+            &channels,
+        );
+        assert_eq!(
+            encoded.text,
+            "<@U1> and <@U2>: see <#C100>. <!here> (cc @sam, #random)"
+        );
+        assert_eq!(encoded.unresolved, vec!["@sam", "#random"]);
+
+        // Emails, URLs, existing mentions and code are left alone
+        let text = "mail a@b.com, https://x.example/#ops, <@U9>, `@bob` and\n```\n#ops\n```";
+        let encoded = encode_mentions(text, &cache, &channels);
+        assert_eq!(encoded.text, text);
+        assert!(encoded.unresolved.is_empty());
+    }
+
     #[test]
     fn test_parse_user_from_json() {
         let json = serde_json::json!({
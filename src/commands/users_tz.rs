@@ -0,0 +1,731 @@
+//! Timezone report for scheduling across distributed teams
+//!
+//! Reads user timezones from the users cache (falling back to `users.info`),
+//! computes the UTC windows where everyone is within working hours, and
+//! suggests a meeting slot.
+
+use super::conv::plan_conv_members;
+use super::output::ClockFormat;
+use super::users_cache::{parse_user_from_json, MentionFormat, WorkspaceCache};
+use crate::api::{ApiClient, ApiError, ApiMethod};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Maximum member pages to fetch to prevent infinite loops
+const MAX_PAGES: usize = 1000;
+
+/// Granularity of the overlap computation in minutes
+const SLOT_MINUTES: u32 = 30;
+
+/// Minutes in a day
+const DAY_MINUTES: u32 = 24 * 60;
+
+/// Local working hours, applied to every user in their own timezone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct WorkingHours {
+    /// Start of the working day in minutes after local midnight
+    pub start_minute: u32,
+    /// End of the working day in minutes after local midnight (exclusive)
+    pub end_minute: u32,
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self {
+            start_minute: 9 * 60,
+            end_minute: 17 * 60,
+        }
+    }
+}
+
+impl WorkingHours {
+    /// Parse a range such as `09:00-17:00` or `9-17`
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "Invalid working hours '{}'. Expected HH:MM-HH:MM (e.g. 09:00-17:00)",
+                s
+            )
+        };
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let start_minute = parse_clock(start).ok_or_else(invalid)?;
+        let end_minute = parse_clock(end).ok_or_else(invalid)?;
+        if start_minute == end_minute {
+            return Err(invalid());
+        }
+        Ok(Self {
+            start_minute,
+            end_minute,
+        })
+    }
+
+    /// Check whether a local time (minutes after midnight) is within working hours
+    fn contains(&self, local_minute: u32) -> bool {
+        if self.start_minute < self.end_minute {
+            local_minute >= self.start_minute && local_minute < self.end_minute
+        } else {
+            // Overnight shift, e.g. 22:00-06:00
+            local_minute >= self.start_minute || local_minute < self.end_minute
+        }
+    }
+}
+
+/// Parse `HH`, `HH:MM`, or `24:00` into minutes after midnight
+fn parse_clock(s: &str) -> Option<u32> {
+    let s = s.trim();
+    let (hours, minutes) = match s.split_once(':') {
+        Some((h, m)) => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+        None => (s.parse::<u32>().ok()?, 0),
+    };
+    let total = hours * 60 + minutes;
+    (minutes < 60 && total <= DAY_MINUTES).then_some(total)
+}
+
+/// Format minutes after midnight as `HH:MM`
+fn format_clock(minute: u32) -> String {
+    format!("{:02}:{:02}", minute / 60, minute % 60)
+}
+
+/// Re-render an `HH:MM` value of the report in the given clock style
+fn display_clock(clock: ClockFormat, value: &str) -> String {
+    parse_clock(value)
+        .map(|minute| clock.format_minutes(minute))
+        .unwrap_or_else(|| value.to_string())
+}
+
+/// Format a UTC offset in seconds as `+HH:MM`
+fn format_offset(offset_seconds: i64) -> String {
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let minutes = offset_seconds.abs() / 60;
+    format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+}
+
+/// A user's timezone information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UserTimezone {
+    pub id: String,
+    pub name: String,
+    /// IANA timezone name
+    pub tz: Option<String>,
+    /// UTC offset in seconds; `None` if the user has no timezone set
+    pub tz_offset: Option<i64>,
+}
+
+impl UserTimezone {
+    /// Convert a UTC minute of the day into this user's local minute of the day
+    fn local_minute(&self, utc_minute: u32) -> Option<u32> {
+        self.tz_offset
+            .map(|offset| (utc_minute as i64 + offset / 60).rem_euclid(DAY_MINUTES as i64) as u32)
+    }
+
+    fn is_working(&self, utc_minute: u32, hours: &WorkingHours) -> bool {
+        self.local_minute(utc_minute)
+            .is_some_and(|local| hours.contains(local))
+    }
+}
+
+/// A range of UTC time (end exclusive, may wrap midnight)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UtcRange {
+    pub start: String,
+    pub end: String,
+    pub minutes: u32,
+}
+
+/// Local start time of a suggested slot for one attendee
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LocalTime {
+    pub user: String,
+    pub local: String,
+    pub tz: Option<String>,
+    pub within_hours: bool,
+}
+
+/// Suggested meeting slot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MeetingSlot {
+    /// Slot start in UTC (`HH:MM`)
+    pub start_utc: String,
+    /// Slot end in UTC (`HH:MM`)
+    pub end_utc: String,
+    pub duration_minutes: u32,
+    /// Number of users within working hours for the whole slot
+    pub attendees: usize,
+    /// Number of users with a known timezone
+    pub total: usize,
+    pub local_times: Vec<LocalTime>,
+}
+
+/// Timezone report
+#[derive(Debug, Clone, Serialize)]
+pub struct TzReport {
+    pub users: Vec<UserTimezone>,
+    pub working_hours: WorkingHours,
+    /// UTC windows where every user with a known timezone is working
+    pub overlap: Vec<UtcRange>,
+    pub suggested: Option<MeetingSlot>,
+}
+
+/// Build a timezone report
+///
+/// # Arguments
+/// * `users` - Users to include
+/// * `hours` - Local working hours applied to every user
+/// * `duration_minutes` - Desired meeting length
+///
+/// # Returns
+/// Report with overlapping working windows and the best meeting slot.
+/// If no slot fits everyone, the slot with the most attendees is suggested.
+pub fn build_tz_report(
+    users: Vec<UserTimezone>,
+    hours: WorkingHours,
+    duration_minutes: u32,
+) -> TzReport {
+    let known: Vec<&UserTimezone> = users.iter().filter(|u| u.tz_offset.is_some()).collect();
+    let slot_count = DAY_MINUTES / SLOT_MINUTES;
+
+    let all_working: Vec<bool> = (0..slot_count)
+        .map(|slot| {
+            !known.is_empty()
+                && known
+                    .iter()
+                    .all(|u| u.is_working(slot * SLOT_MINUTES, &hours))
+        })
+        .collect();
+
+    let overlap = overlap_ranges(&all_working);
+
+    let duration_slots = duration_minutes.max(1).div_ceil(SLOT_MINUTES);
+    let suggested = if known.is_empty() {
+        None
+    } else {
+        (0..slot_count)
+            .map(|start| {
+                let attendees = known
+                    .iter()
+                    .filter(|u| {
+                        (0..duration_slots).all(|i| {
+                            u.is_working(((start + i) % slot_count) * SLOT_MINUTES, &hours)
+                        })
+                    })
+                    .count();
+                (start, attendees)
+            })
+            // Highest attendance wins; ties go to the earliest UTC start
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+            .map(|(start, attendees)| {
+                let start_minute = start * SLOT_MINUTES;
+                let local_times = known
+                    .iter()
+                    .map(|u| LocalTime {
+                        user: u.name.clone(),
+                        local: format_clock(u.local_minute(start_minute).unwrap_or(0)),
+                        tz: u.tz.clone(),
+                        within_hours: (0..duration_slots).all(|i| {
+                            u.is_working(((start + i) % slot_count) * SLOT_MINUTES, &hours)
+                        }),
+                    })
+                    .collect();
+                MeetingSlot {
+                    start_utc: format_clock(start_minute),
+                    end_utc: format_clock((start_minute + duration_minutes) % DAY_MINUTES),
+                    duration_minutes,
+                    attendees,
+                    total: known.len(),
+                    local_times,
+                }
+            })
+    };
+
+    TzReport {
+        users,
+        working_hours: hours,
+        overlap,
+        suggested,
+    }
+}
+
+/// Collapse per-slot flags into contiguous UTC ranges, merging across midnight
+fn overlap_ranges(flags: &[bool]) -> Vec<UtcRange> {
+    let slot_count = flags.len() as u32;
+    if flags.iter().all(|&f| f) {
+        return vec![UtcRange {
+            start: format_clock(0),
+            end: format_clock(0),
+            minutes: DAY_MINUTES,
+        }];
+    }
+
+    // Start scanning just after a non-overlap slot so wrapping ranges stay whole
+    let Some(offset) = flags.iter().position(|&f| !f) else {
+        return Vec::new();
+    };
+
+    let mut ranges = Vec::new();
+    let mut run_start: Option<u32> = None;
+    for i in 1..=slot_count {
+        let slot = (offset as u32 + i) % slot_count;
+        match (flags[slot as usize], run_start) {
+            (true, None) => run_start = Some(slot),
+            (false, Some(start)) => {
+                let len = (slot + slot_count - start) % slot_count;
+                ranges.push(UtcRange {
+                    start: format_clock(start * SLOT_MINUTES),
+                    end: format_clock(slot * SLOT_MINUTES),
+                    minutes: len * SLOT_MINUTES,
+                });
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    ranges.sort_by(|a, b| a.start.cmp(&b.start));
+    ranges
+}
+
+/// Render a timezone report as a plain-text table
+///
+/// Each user gets a 24-character UTC hour grid where `#` marks working hours.
+/// Times are shown in `clock` style; the JSON report always uses `HH:MM`.
+pub fn render_tz_table(report: &TzReport, clock: ClockFormat) -> String {
+    let hours = &report.working_hours;
+    let name_width = report
+        .users
+        .iter()
+        .map(|u| u.name.chars().count())
+        .max()
+        .unwrap_or(4)
+        .max(4);
+    let tz_width = report
+        .users
+        .iter()
+        .map(|u| u.tz.as_deref().unwrap_or("unknown").len())
+        .max()
+        .unwrap_or(8)
+        .max(8);
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "Working hours: {}-{} local\n\n",
+        clock.format_minutes(hours.start_minute),
+        clock.format_minutes(hours.end_minute)
+    ));
+    out.push_str(&format!(
+        "{:<nw$}  {:<tw$}  {:<6}  {}\n",
+        "USER",
+        "TIMEZONE",
+        "OFFSET",
+        "UTC 0     6     12    18",
+        nw = name_width,
+        tw = tz_width
+    ));
+
+    for user in &report.users {
+        let offset = user
+            .tz_offset
+            .map(format_offset)
+            .unwrap_or_else(|| "?".to_string());
+        let grid: String = (0..24)
+            .map(|h| {
+                if user.tz_offset.is_none() {
+                    '?'
+                } else if user.is_working(h * 60, hours) && user.is_working(h * 60 + 30, hours) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        out.push_str(&format!(
+            "{:<nw$}  {:<tw$}  {:<6}  {}\n",
+            user.name,
+            user.tz.as_deref().unwrap_or("unknown"),
+            offset,
+            grid,
+            nw = name_width,
+            tw = tz_width
+        ));
+    }
+
+    out.push('\n');
+    if report.overlap.is_empty() {
+        out.push_str("Overlap (UTC): none\n");
+    } else {
+        let ranges: Vec<String> = report
+            .overlap
+            .iter()
+            .map(|r| {
+                format!(
+                    "{}-{}",
+                    display_clock(clock, &r.start),
+                    display_clock(clock, &r.end)
+                )
+            })
+            .collect();
+        out.push_str(&format!("Overlap (UTC): {}\n", ranges.join(", ")));
+    }
+
+    match &report.suggested {
+        Some(slot) => {
+            out.push_str(&format!(
+                "Suggested slot: {}-{} UTC ({}/{} within working hours)\n",
+                display_clock(clock, &slot.start_utc),
+                display_clock(clock, &slot.end_utc),
+                slot.attendees,
+                slot.total
+            ));
+            for local in &slot.local_times {
+                out.push_str(&format!(
+                    "  {:<nw$}  {}  {}{}\n",
+                    local.user,
+                    display_clock(clock, &local.local),
+                    local.tz.as_deref().unwrap_or("unknown"),
+                    if local.within_hours {
+                        ""
+                    } else {
+                        " (outside working hours)"
+                    },
+                    nw = name_width
+                ));
+            }
+        }
+        None => out.push_str("Suggested slot: none (no users with a known timezone)\n"),
+    }
+
+    out
+}
+
+/// Fetch all member IDs of a conversation with automatic pagination
+///
+/// # Arguments
+/// * `client` - API client
+/// * `channel` - Channel ID
+///
+/// # Returns
+/// * `Ok(Vec<String>)` with member user IDs
+/// * `Err(ApiError)` if the operation fails
+pub async fn fetch_channel_members(
+    client: &ApiClient,
+    channel: &str,
+) -> Result<Vec<String>, ApiError> {
+    let mut members = Vec::new();
+    let mut cursor: Option<String> = None;
+    let mut page_count = 0;
+
+    loop {
+        page_count += 1;
+        if page_count > MAX_PAGES {
+            return Err(ApiError::SlackError(format!(
+                "Pagination exceeded max pages ({}), possible infinite loop",
+                MAX_PAGES
+            )));
+        }
+
+        let response = client
+            .execute_planned(plan_conv_members(channel, 200, cursor.as_deref()))
+            .await?;
+
+        if let Some(page) = response.data.get("members").and_then(|m| m.as_array()) {
+            members.extend(page.iter().filter_map(|m| m.as_str().map(String::from)));
+        }
+
+        cursor = response
+            .data
+            .get("response_metadata")
+            .and_then(|meta| meta.get("next_cursor"))
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_string());
+
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    Ok(members)
+}
+
+/// Resolve timezones for a list of users
+///
+/// Users found in the cache with timezone data are used directly; others are
+/// looked up with `users.info`. Bots and deleted users are skipped.
+///
+/// # Arguments
+/// * `client` - API client
+/// * `user_ids` - User IDs to resolve
+/// * `cache` - Optional workspace users cache
+/// * `name_format` - How user names are rendered
+///
+/// # Returns
+/// * `Ok(Vec<UserTimezone>)` in input order
+/// * `Err(ApiError)` if a `users.info` lookup fails
+pub async fn resolve_user_timezones(
+    client: &ApiClient,
+    user_ids: &[String],
+    cache: Option<&WorkspaceCache>,
+    name_format: MentionFormat,
+) -> Result<Vec<UserTimezone>, ApiError> {
+    let mut result = Vec::new();
+
+    for id in user_ids {
+        let cached = cache
+            .and_then(|c| c.users.get(id))
+            .filter(|u| u.tz_offset.is_some())
+            .cloned();
+
+        let user = match cached {
+            Some(user) => user,
+            None => {
+                let mut params = HashMap::new();
+                params.insert("user".to_string(), json!(id));
+                let response = client.call_method(ApiMethod::UsersInfo, params).await?;
+                if !response.ok {
+                    return Err(ApiError::SlackError(
+                        response
+                            .error
+                            .unwrap_or_else(|| "users.info failed".to_string()),
+                    ));
+                }
+                match response.data.get("user").and_then(parse_user_from_json) {
+                    Some(user) => user,
+                    None => continue,
+                }
+            }
+        };
+
+        if user.deleted || user.is_bot {
+            continue;
+        }
+
+        result.push(UserTimezone {
+            name: user.display_as(name_format).to_string(),
+            id: user.id,
+            tz: user.tz,
+            tz_offset: user.tz_offset,
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::users_cache::CachedUser;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn user(name: &str, tz: &str, offset_hours: i64) -> UserTimezone {
+        UserTimezone {
+            id: format!("U{}", name.to_uppercase()),
+            name: name.to_string(),
+            tz: Some(tz.to_string()),
+            tz_offset: Some(offset_hours * 3600),
+        }
+    }
+
+    #[test]
+    fn test_working_hours_parse() {
+        assert_eq!(
+            WorkingHours::parse("09:00-17:30").unwrap(),
+            WorkingHours {
+                start_minute: 540,
+                end_minute: 1050
+            }
+        );
+        assert_eq!(
+            WorkingHours::parse("8-16").unwrap(),
+            WorkingHours {
+                start_minute: 480,
+                end_minute: 960
+            }
+        );
+        assert!(WorkingHours::parse("9").is_err());
+        assert!(WorkingHours::parse("09:75-17:00").is_err());
+        assert!(WorkingHours::parse("9-9").is_err());
+    }
+
+    #[test]
+    fn test_format_offset() {
+        assert_eq!(format_offset(-18000), "-05:00");
+        assert_eq!(format_offset(19800), "+05:30");
+        assert_eq!(format_offset(0), "+00:00");
+    }
+
+    #[test]
+    fn test_build_tz_report_overlap_and_suggestion() {
+        // New York (UTC-5) 14:00-22:00 UTC, London (UTC+0) 09:00-17:00 UTC
+        let users = vec![
+            user("alice", "America/New_York", -5),
+            user("bob", "Europe/London", 0),
+        ];
+        let report = build_tz_report(users, WorkingHours::default(), 30);
+
+        assert_eq!(report.overlap.len(), 1);
+        assert_eq!(report.overlap[0].start, "14:00");
+        assert_eq!(report.overlap[0].end, "17:00");
+        assert_eq!(report.overlap[0].minutes, 180);
+
+        let slot = report.suggested.unwrap();
+        assert_eq!(slot.start_utc, "14:00");
+        assert_eq!(slot.end_utc, "14:30");
+        assert_eq!(slot.attendees, 2);
+        assert_eq!(slot.local_times[0].local, "09:00");
+        assert_eq!(slot.local_times[1].local, "14:00");
+    }
+
+    #[test]
+    fn test_build_tz_report_no_full_overlap() {
+        // Tokyo (UTC+9) 00:00-08:00 UTC, San Francisco (UTC-8) 17:00-01:00 UTC, London 09:00-17:00 UTC
+        let users = vec![
+            user("taro", "Asia/Tokyo", 9),
+            user("sam", "America/Los_Angeles", -8),
+            user("bob", "Europe/London", 0),
+        ];
+        let report = build_tz_report(users, WorkingHours::default(), 60);
+
+        assert!(report.overlap.is_empty());
+        let slot = report.suggested.unwrap();
+        assert_eq!(slot.attendees, 2);
+        assert_eq!(slot.total, 3);
+        // Tokyo and San Francisco overlap 00:00-01:00 UTC
+        assert_eq!(slot.start_utc, "00:00");
+    }
+
+    #[test]
+    fn test_overlap_wraps_midnight() {
+        let users = vec![user("taro", "Asia/Tokyo", 9), user("sam", "Pacific", -8)];
+        let hours = WorkingHours::parse("08:00-18:00").unwrap();
+        let report = build_tz_report(users, hours, 30);
+
+        // Tokyo 23:00-09:00 UTC, Pacific 16:00-02:00 UTC -> 23:00-02:00
+        assert_eq!(report.overlap.len(), 1);
+        assert_eq!(report.overlap[0].start, "23:00");
+        assert_eq!(report.overlap[0].end, "02:00");
+        assert_eq!(report.overlap[0].minutes, 180);
+    }
+
+    #[test]
+    fn test_unknown_timezone_excluded() {
+        let users = vec![
+            user("alice", "America/New_York", -5),
+            UserTimezone {
+                id: "UX".to_string(),
+                name: "ghost".to_string(),
+                tz: None,
+                tz_offset: None,
+            },
+        ];
+        let report = build_tz_report(users, WorkingHours::default(), 30);
+        let slot = report.suggested.as_ref().unwrap();
+        assert_eq!(slot.total, 1);
+
+        let table = render_tz_table(&report, ClockFormat::H24);
+        assert!(table.contains("ghost"));
+        assert!(table.contains("????????????????????????"));
+        assert!(table.contains("Suggested slot: 14:00-14:30 UTC (1/1"));
+    }
+
+    #[test]
+    fn test_render_tz_table() {
+        let users = vec![
+            user("alice", "America/New_York", -5),
+            user("bob", "Europe/London", 0),
+        ];
+        let report = build_tz_report(users, WorkingHours::default(), 30);
+        let table = render_tz_table(&report, ClockFormat::H24);
+        assert!(table.contains("alice  America/New_York  -05:00  ..............########.."));
+        assert!(table.contains("Overlap (UTC): 14:00-17:00"));
+
+        let table = render_tz_table(&report, ClockFormat::H12);
+        assert!(table.contains("Working hours: 9:00 AM-5:00 PM local"));
+        assert!(table.contains("Overlap (UTC): 2:00 PM-5:00 PM"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_user_timezones_uses_cache_then_api() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/users.info"))
+            .and(query_param("user", "U2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "user": {"id": "U2", "name": "bob", "tz": "Europe/London", "tz_offset": 0}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut users = HashMap::new();
+        users.insert(
+            "U1".to_string(),
+            CachedUser {
+                id: "U1".to_string(),
+                name: "alice".to_string(),
+                real_name: None,
+                display_name: None,
+                deleted: false,
+                is_bot: false,
+                is_restricted: false,
+                is_ultra_restricted: false,
+                team_id: None,
+                tz: Some("America/New_York".to_string()),
+                tz_offset: Some(-18000),
+            },
+        );
+        let cache = WorkspaceCache {
+            team_id: "T1".to_string(),
+            updated_at: 0,
+            users,
+        };
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let resolved = resolve_user_timezones(
+            &client,
+            &["U1".to_string(), "U2".to_string()],
+            Some(&cache),
+            MentionFormat::DisplayName,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resolved.len(), 2);
+        assert_eq!(resolved[0].name, "alice");
+        assert_eq!(resolved[0].tz_offset, Some(-18000));
+        assert_eq!(resolved[1].tz.as_deref(), Some("Europe/London"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_channel_members_paginates() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/conversations.members"))
+            .and(query_param("cursor", "next"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "members": ["U3"],
+                "response_metadata": {"next_cursor": ""}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/conversations.members"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "members": ["U1", "U2"],
+                "response_metadata": {"next_cursor": "next"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("test-token".to_string(), mock_server.uri());
+        let members = fetch_channel_members(&client, "C123").await.unwrap();
+        assert_eq!(members, vec!["U1", "U2", "U3"]);
+    }
+}
@@ -0,0 +1,176 @@
+//! GitHub Actions integration (`--gha`).
+//!
+//! With `--gha`, errors and warnings are additionally emitted as workflow
+//! command annotations (`::error::` / `::notice::`) on stderr, and key
+//! results of write commands (channel ID, message ts, permalink, file ID)
+//! are appended to the file named by `$GITHUB_OUTPUT` so later steps can
+//! read them as `steps.<id>.outputs.<name>`.
+//!
+//! stdout is left untouched, so JSON output can still be captured or piped.
+
+use serde_json::Value;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable GitHub Actions mode for this process
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `--gha` is active
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Escape annotation message data (`%`, CR and LF)
+pub fn escape_data(message: &str) -> String {
+    message
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Format a workflow command annotation (`level` is `error`, `warning` or `notice`)
+pub fn format_annotation(level: &str, message: &str) -> String {
+    format!("::{}::{}", level, escape_data(message))
+}
+
+/// Emit an `::error::` annotation when `--gha` is active
+pub fn error(message: &str) {
+    if enabled() {
+        eprintln!("{}", format_annotation("error", message));
+    }
+}
+
+/// Print a warning to stderr, plus a `::notice::` annotation when `--gha` is active
+pub fn warn(message: &str) {
+    eprintln!("{}", message);
+    if enabled() {
+        eprintln!("{}", format_annotation("notice", message));
+    }
+}
+
+/// Key results worth exposing as step outputs
+///
+/// Picks `channel`, `ts`, `thread_ts`, `permalink` and `file.id` (as
+/// `file_id`) from a Slack response when present.
+pub fn response_outputs(response: &Value) -> Vec<(String, String)> {
+    let mut outputs = Vec::new();
+    for key in ["channel", "ts", "thread_ts", "permalink"] {
+        if let Some(value) = response.get(key).and_then(|v| v.as_str()) {
+            outputs.push((key.to_string(), value.to_string()));
+        }
+    }
+    if let Some(ts) = response
+        .get("message")
+        .and_then(|m| m.get("thread_ts"))
+        .and_then(|v| v.as_str())
+    {
+        if !outputs.iter().any(|(k, _)| k == "thread_ts") {
+            outputs.push(("thread_ts".to_string(), ts.to_string()));
+        }
+    }
+    if let Some(id) = response
+        .get("file")
+        .and_then(|f| f.get("id"))
+        .and_then(|v| v.as_str())
+    {
+        outputs.push(("file_id".to_string(), id.to_string()));
+    }
+    outputs
+}
+
+/// Format outputs in `$GITHUB_OUTPUT` syntax
+///
+/// Multi-line values use the `name<<DELIMITER` form.
+pub fn format_outputs(outputs: &[(String, String)]) -> String {
+    let mut out = String::new();
+    for (name, value) in outputs {
+        if value.contains('\n') {
+            let mut delimiter = "SLACK_RS_EOF".to_string();
+            while value.contains(&delimiter) {
+                delimiter.push('_');
+            }
+            out.push_str(&format!(
+                "{}<<{}\n{}\n{}\n",
+                name, delimiter, value, delimiter
+            ));
+        } else {
+            out.push_str(&format!("{}={}\n", name, value));
+        }
+    }
+    out
+}
+
+/// Append outputs to `$GITHUB_OUTPUT` when `--gha` is active
+///
+/// Without `$GITHUB_OUTPUT` (e.g. running locally) this is a no-op.
+pub fn write_outputs(outputs: &[(String, String)]) -> Result<(), String> {
+    if !enabled() || outputs.is_empty() {
+        return Ok(());
+    }
+    let Ok(path) = std::env::var("GITHUB_OUTPUT") else {
+        return Ok(());
+    };
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open GITHUB_OUTPUT ({}): {}", path, e))?;
+    file.write_all(format_outputs(outputs).as_bytes())
+        .map_err(|e| format!("Failed to write GITHUB_OUTPUT ({}): {}", path, e))
+}
+
+/// Write the key results of a Slack response to `$GITHUB_OUTPUT`
+///
+/// Failures are reported as warnings; they never fail the command.
+pub fn record_response(response: &Value) {
+    if !enabled() {
+        return;
+    }
+    if let Err(e) = write_outputs(&response_outputs(response)) {
+        warn(&format!("Warning: {}", e));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_format_annotation_escapes() {
+        assert_eq!(
+            format_annotation("error", "50% failed\nretry"),
+            "::error::50%25 failed%0Aretry"
+        );
+    }
+
+    #[test]
+    fn test_response_outputs() {
+        let outputs = response_outputs(&json!({
+            "ok": true,
+            "channel": "C123",
+            "ts": "1700000000.000100",
+            "message": {"text": "hi", "thread_ts": "1699999999.000001"},
+            "permalink": "https://example.slack.com/archives/C123/p1700000000000100"
+        }));
+        let names: Vec<&str> = outputs.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(names, vec!["channel", "ts", "permalink", "thread_ts"]);
+
+        let outputs = response_outputs(&json!({"ok": true, "file": {"id": "F1"}}));
+        assert_eq!(outputs, vec![("file_id".to_string(), "F1".to_string())]);
+    }
+
+    #[test]
+    fn test_format_outputs_multiline() {
+        let text = format_outputs(&[
+            ("ts".to_string(), "1.2".to_string()),
+            ("text".to_string(), "a\nb".to_string()),
+        ]);
+        assert_eq!(text, "ts=1.2\ntext<<SLACK_RS_EOF\na\nb\nSLACK_RS_EOF\n");
+    }
+}
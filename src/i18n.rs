@@ -0,0 +1,856 @@
+//! Internationalization for user-facing CLI messages
+//!
+//! Provides a single message catalog (English and Japanese) covering usage text,
+//! warnings, prompts, error guidance, and export/import messages.
+//!
+//! Language resolution priority:
+//! 1. Global `--lang <code>` flag (applied via [`set_language`])
+//! 2. `SLACKRS_LANG` environment variable
+//! 3. `output.locale` of the selected profile, then the global one
+//! 4. `LANG` environment variable (`ja*` selects Japanese)
+//! 5. English
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    Japanese,
+}
+
+impl Language {
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "en" | "EN" => Some(Language::English),
+            "ja" | "JA" => Some(Language::Japanese),
+            _ => None,
+        }
+    }
+
+    /// Parse a locale string such as `ja_JP.UTF-8` or `en-US`
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        Self::from_code(&code)
+    }
+
+    /// Detect language from `SLACKRS_LANG`, then `LANG`
+    pub fn detect() -> Self {
+        if let Some(lang) = std::env::var("SLACKRS_LANG")
+            .ok()
+            .and_then(|v| Self::from_locale(&v))
+        {
+            return lang;
+        }
+
+        std::env::var("LANG")
+            .ok()
+            .map(|lang| {
+                if lang.starts_with("ja") {
+                    Language::Japanese
+                } else {
+                    Language::English
+                }
+            })
+            .unwrap_or(Language::English)
+    }
+}
+
+/// Language explicitly selected via the global `--lang` flag
+static SELECTED_LANGUAGE: RwLock<Option<Language>> = RwLock::new(None);
+
+/// Set the language for all subsequent messages (global `--lang` flag)
+pub fn set_language(lang: Language) {
+    if let Ok(mut selected) = SELECTED_LANGUAGE.write() {
+        *selected = Some(lang);
+    }
+}
+
+/// Get the active language
+pub fn current_language() -> Language {
+    SELECTED_LANGUAGE
+        .read()
+        .ok()
+        .and_then(|selected| *selected)
+        .unwrap_or_else(Language::detect)
+}
+
+/// Look up a message in the active language
+pub fn t(key: &str) -> &'static str {
+    Messages::new(current_language()).get(key)
+}
+
+/// Look up a message in the active language and fill `{placeholder}` values
+pub fn tf(key: &str, replacements: &[(&str, &str)]) -> String {
+    Messages::new(current_language()).format(key, replacements)
+}
+
+/// Message catalog
+pub struct Messages {
+    lang: Language,
+    messages: &'static HashMap<&'static str, (&'static str, &'static str)>,
+}
+
+impl Messages {
+    pub fn new(lang: Language) -> Self {
+        Self {
+            lang,
+            messages: catalog(),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> &'static str {
+        match self.messages.get(key) {
+            Some(&(en, ja)) => match self.lang {
+                Language::English => en,
+                Language::Japanese => ja,
+            },
+            None => "",
+        }
+    }
+
+    pub fn format(&self, key: &str, replacements: &[(&str, &str)]) -> String {
+        let template = self.get(key);
+        let mut result = template.to_string();
+        for (placeholder, value) in replacements {
+            result = result.replace(&format!("{{{}}}", placeholder), value);
+        }
+        result
+    }
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Self::new(current_language())
+    }
+}
+
+/// Build the message catalog once
+fn catalog() -> &'static HashMap<&'static str, (&'static str, &'static str)> {
+    static CATALOG: OnceLock<HashMap<&'static str, (&'static str, &'static str)>> = OnceLock::new();
+    CATALOG.get_or_init(|| ENTRIES.iter().map(|&(k, en, ja)| (k, (en, ja))).collect())
+}
+
+/// Catalog entries: (key, English, Japanese)
+const ENTRIES: &[(&str, &str, &str)] = &[
+    // Export/import warnings
+    (
+        "warn.export_sensitive",
+        "WARNING: You are about to export sensitive authentication data.\n\
+         This file will contain access tokens that can be used to access your Slack workspaces.\n\
+         Store this file securely and delete it after use.",
+        "警告: 機密認証情報をエクスポートしようとしています。\n\
+         このファイルには Slack ワークスペースへのアクセスに使用できるアクセストークンが含まれます。\n\
+         このファイルは安全に保管し、使用後は削除してください。",
+    ),
+    (
+        "warn.export_skipped",
+        "Warning: The following profiles were skipped due to missing tokens:",
+        "警告: 以下のプロファイルはトークンが見つからないためスキップされました:",
+    ),
+    // General warnings
+    (
+        "warn.token_fallback",
+        "Warning: Primary token not found, falling back to alternative token",
+        "警告: 指定のトークンが見つからないため、別のトークンを使用します",
+    ),
+    (
+        "warn.large_image",
+        "Warning: uploading a large image ({size} MB); consider --max-dim to downscale it",
+        "警告: 大きな画像 ({size} MB) をアップロードします。--max-dim で縮小することを検討してください",
+    ),
+    (
+        "warn.unknown_lang",
+        "Warning: Unsupported language '{lang}', falling back to English (supported: en, ja)",
+        "警告: 未対応の言語 '{lang}' です。英語を使用します (対応: en, ja)",
+    ),
+    // Prompt messages
+    (
+        "prompt.passphrase",
+        "Enter passphrase: ",
+        "パスフレーズを入力してください: ",
+    ),
+    (
+        "prompt.passphrase_confirm",
+        "Confirm passphrase: ",
+        "パスフレーズを再入力してください: ",
+    ),
+    (
+        "prompt.confirm_operation",
+        "Are you sure you want to {operation}? [y/N]: ",
+        "{operation} を実行してもよろしいですか? [y/N]: ",
+    ),
+    // Error messages
+    (
+        "error.bad_permissions",
+        "Error: File must have 0600 permissions (owner read/write only)",
+        "エラー: ファイルは 0600 パーミッション（所有者の読み書きのみ）である必要があります",
+    ),
+    (
+        "error.passphrase_mismatch",
+        "Error: Passphrases do not match",
+        "エラー: パスフレーズが一致しません",
+    ),
+    (
+        "error.empty_passphrase",
+        "Error: Empty passphrase not allowed",
+        "エラー: 空のパスフレーズは許可されていません",
+    ),
+    (
+        "error.confirmation_required",
+        "Error: Export requires --yes flag for confirmation",
+        "エラー: エクスポートには --yes フラグによる確認が必要です",
+    ),
+    (
+        "error.profile_exists",
+        "Error: Profile already exists (use --force to overwrite)",
+        "エラー: プロファイルがすでに存在します（上書きするには --force を使用してください）",
+    ),
+    (
+        "error.requires_confirmation",
+        "Operation requires confirmation: {operation}. Use --yes flag to confirm in non-interactive mode.",
+        "操作には確認が必要です: {operation}。非対話モードでは --yes フラグで確認してください。",
+    ),
+    (
+        "error.no_token",
+        "No token found. Set SLACK_TOKEN environment variable or run 'slack login' to obtain a token.",
+        "トークンが見つかりません。SLACK_TOKEN 環境変数を設定するか、'slack login' を実行してトークンを取得してください。",
+    ),
+    (
+        "error.no_token_explicit",
+        "No token found for explicitly requested token type. Set SLACK_TOKEN environment variable or run 'slack login' to obtain a token.",
+        "指定されたトークン種別のトークンが見つかりません。SLACK_TOKEN 環境変数を設定するか、'slack login' を実行してトークンを取得してください。",
+    ),
+    // Success messages
+    (
+        "success.export",
+        "✓ Profiles exported successfully",
+        "✓ プロファイルのエクスポートが完了しました",
+    ),
+    (
+        "success.import",
+        "✓ Profiles imported successfully",
+        "✓ プロファイルのインポートが完了しました",
+    ),
+    // Info messages
+    (
+        "info.export_count",
+        "Exporting {count} profile(s)",
+        "{count} 件のプロファイルをエクスポート中",
+    ),
+    (
+        "info.import_count",
+        "Importing {count} profile(s)",
+        "{count} 件のプロファイルをインポート中",
+    ),
+    (
+        "info.export_summary",
+        "Export summary: {exported} profile(s) exported, {skipped} profile(s) skipped",
+        "エクスポート概要: {exported} 件のプロファイルをエクスポート、{skipped} 件をスキップ",
+    ),
+    // Error guidance labels
+    ("guidance.error", "Error", "エラー"),
+    ("guidance.cause", "Cause", "原因"),
+    ("guidance.resolution", "Resolution", "対処"),
+    // Error guidance per Slack error code
+    (
+        "guidance.not_allowed_token_type.cause",
+        "The token type used for this request is not allowed for this API method",
+        "このリクエストで使用したトークン種別は、この API メソッドでは許可されていません",
+    ),
+    (
+        "guidance.not_allowed_token_type.resolution",
+        "Use a different token type (bot or user). Try: --token-type user or --token-type bot",
+        "別のトークン種別 (bot または user) を使用してください。例: --token-type user または --token-type bot",
+    ),
+    (
+        "guidance.missing_scope.cause",
+        "The token does not have the required OAuth scope for this API method",
+        "トークンにこの API メソッドに必要な OAuth スコープがありません",
+    ),
+    (
+        "guidance.missing_scope.resolution",
+        "Re-authenticate with the required scopes. Run: slack auth login",
+        "必要なスコープで再認証してください。実行: slack auth login",
+    ),
+    (
+        "guidance.invalid_auth.cause",
+        "The authentication token is invalid, expired, or revoked",
+        "認証トークンが無効、期限切れ、または取り消されています",
+    ),
+    (
+        "guidance.invalid_auth.resolution",
+        "Re-authenticate to obtain a new token. Run: slack auth login",
+        "再認証して新しいトークンを取得してください。実行: slack auth login",
+    ),
+    (
+        "guidance.token_revoked.cause",
+        "The authentication token has been revoked",
+        "認証トークンが取り消されています",
+    ),
+    (
+        "guidance.token_revoked.resolution",
+        "Re-authenticate to obtain a new token. Run: slack auth login",
+        "再認証して新しいトークンを取得してください。実行: slack auth login",
+    ),
+    (
+        "guidance.token_expired.cause",
+        "The authentication token has expired",
+        "認証トークンの有効期限が切れています",
+    ),
+    (
+        "guidance.token_expired.resolution",
+        "Re-authenticate to obtain a new token. Run: slack auth login",
+        "再認証して新しいトークンを取得してください。実行: slack auth login",
+    ),
+    (
+        "guidance.not_authed.cause",
+        "No authentication token was provided",
+        "認証トークンが指定されていません",
+    ),
+    (
+        "guidance.not_authed.resolution",
+        "Authenticate first. Run: slack auth login",
+        "先に認証してください。実行: slack auth login",
+    ),
+    (
+        "guidance.account_inactive.cause",
+        "The authentication token is for a deleted user or workspace",
+        "認証トークンは削除されたユーザーまたはワークスペースのものです",
+    ),
+    (
+        "guidance.account_inactive.resolution",
+        "Use a valid workspace account and re-authenticate. Run: slack auth login",
+        "有効なワークスペースアカウントで再認証してください。実行: slack auth login",
+    ),
+    (
+        "guidance.no_permission.cause",
+        "The token does not have permission to perform this action",
+        "トークンにこの操作を実行する権限がありません",
+    ),
+    (
+        "guidance.no_permission.resolution",
+        "Check workspace permissions or use a token with appropriate privileges",
+        "ワークスペースの権限を確認するか、適切な権限を持つトークンを使用してください",
+    ),
+    (
+        "guidance.org_login_required.cause",
+        "The workspace requires organization-wide login",
+        "このワークスペースは組織全体でのログインが必要です",
+    ),
+    (
+        "guidance.org_login_required.resolution",
+        "Contact your workspace administrator for access",
+        "アクセスについてワークスペース管理者に問い合わせてください",
+    ),
+    (
+        "guidance.ekm_access_denied.cause",
+        "Enterprise Key Management (EKM) access was denied",
+        "Enterprise Key Management (EKM) へのアクセスが拒否されました",
+    ),
+    (
+        "guidance.ekm_access_denied.resolution",
+        "Contact your workspace administrator to check EKM settings",
+        "EKM 設定についてワークスペース管理者に確認してください",
+    ),
+    (
+        "guidance.channel_not_found.cause",
+        "The channel was not found. Possible causes: private channel without membership, incorrect channel ID, wrong workspace profile, or wrong token type",
+        "チャンネルが見つかりません。考えられる原因: 未参加のプライベートチャンネル、チャンネル ID の誤り、ワークスペースのプロファイル違い、トークン種別の誤り",
+    ),
+    (
+        "guidance.channel_not_found.resolution",
+        "Check: 1) Use --include-private or --all for private channels, 2) Verify channel ID, 3) Confirm correct --profile, 4) Try --token-type user for private channels",
+        "確認: 1) プライベートチャンネルには --include-private または --all を使用, 2) チャンネル ID を確認, 3) --profile が正しいか確認, 4) プライベートチャンネルには --token-type user を試す",
+    ),
+    (
+        "guidance.method_not_supported_for_channel_type.cause",
+        "This method does not work for this kind of conversation. conversations.join only joins public channels; private channels, DMs and group DMs cannot be joined",
+        "このメソッドはこの種類の会話では使えません。conversations.join で参加できるのはパブリックチャンネルのみで、プライベートチャンネル・DM・グループ DM には参加できません",
+    ),
+    (
+        "guidance.method_not_supported_for_channel_type.resolution",
+        "Ask a channel member to invite the bot (/invite @app, or slack-rs conv invite <channel> <bot_user_id>), or use a user token that is already a member",
+        "チャンネルのメンバーにボットを招待してもらってください (/invite @app、または slack-rs conv invite <channel> <bot_user_id>)。あるいは既にメンバーであるユーザートークンを使用してください",
+    ),
+    // Top-level usage
+    ("usage.title", "Slack CLI - Usage:", "Slack CLI - 使い方:"),
+    (
+        "usage.non_interactive",
+        "Run without interactive prompts (auto when stdin not a TTY)",
+        "対話プロンプトなしで実行 (stdin が TTY でない場合は自動)",
+    ),
+    (
+        "usage.lang",
+        "Message language: en, ja (or SLACKRS_LANG)",
+        "メッセージの言語: en, ja (または SLACKRS_LANG)",
+    ),
+    ("usage.api_call", "Call a Slack API method", "Slack API メソッドを呼び出す"),
+    ("usage.auth_login", "Authenticate with Slack", "Slack で認証する"),
+    ("usage.auth_status", "Show profile status", "プロファイルの状態を表示"),
+    ("usage.auth_list", "List all profiles", "すべてのプロファイルを一覧表示"),
+    (
+        "usage.auth_tokens_list",
+        "List token store keys with redacted values, owning profile and usage times",
+        "トークンストアのキーを値を伏せて一覧表示 (プロファイル・利用日時付き)",
+    ),
+    (
+        "usage.auth_compare_tokens",
+        "Compare what the bot and user tokens can see",
+        "Bot トークンと User トークンで見える範囲を比較",
+    ),
+    (
+        "usage.auth_test",
+        "Show the token's team, user, bot and granted scopes",
+        "トークンのチーム・ユーザー・Bot と付与スコープを表示",
+    ),
+    (
+        "usage.whoami",
+        "Show the profile, team, user, token type/source and scopes in one line",
+        "プロファイル・チーム・ユーザー・トークン種別/取得元・スコープを1行で表示",
+    ),
+    ("usage.auth_rename", "Rename a profile", "プロファイル名を変更"),
+    ("usage.auth_logout", "Remove authentication", "認証情報を削除"),
+    (
+        "usage.auth_set_token",
+        "Store a token from stdin (app-level xapp token by default)",
+        "標準入力からトークンを保存 (既定はアプリレベルの xapp トークン)",
+    ),
+    (
+        "usage.auth_export",
+        "Export profiles to encrypted file",
+        "プロファイルを暗号化ファイルにエクスポート",
+    ),
+    (
+        "usage.auth_import",
+        "Import profiles from encrypted file",
+        "暗号化ファイルからプロファイルをインポート",
+    ),
+    (
+        "usage.config_oauth_set",
+        "Set OAuth configuration for a profile",
+        "プロファイルの OAuth 設定を登録",
+    ),
+    (
+        "usage.config_oauth_show",
+        "Show OAuth configuration for a profile",
+        "プロファイルの OAuth 設定を表示",
+    ),
+    (
+        "usage.config_oauth_delete",
+        "Delete OAuth configuration for a profile",
+        "プロファイルの OAuth 設定を削除",
+    ),
+    (
+        "usage.config_set",
+        "Set default token type (bot/user)",
+        "既定のトークン種別 (bot/user) を設定",
+    ),
+    (
+        "usage.config_token_backend",
+        "Migrate stored tokens to another backend (file/encrypted-file)",
+        "保存済みトークンを別のバックエンド (file/encrypted-file) へ移行",
+    ),
+    (
+        "usage.search",
+        "Search messages (supports --in, --from, --after, --before, --has, --during, --count, --page, --all, --max-results, --flatten, --sort, --sort_dir, --group-by)",
+        "メッセージを検索 (--in, --from, --after, --before, --has, --during, --count, --page, --all, --max-results, --flatten, --sort, --sort_dir, --group-by に対応)",
+    ),
+    (
+        "usage.search_files",
+        "Search files (supports --count, --page, --sort, --sort_dir)",
+        "ファイルを検索 (--count, --page, --sort, --sort_dir に対応)",
+    ),
+    (
+        "usage.search_all",
+        "Search messages and files (supports --count, --page, --sort, --sort_dir)",
+        "メッセージとファイルをまとめて検索 (--count, --page, --sort, --sort_dir に対応)",
+    ),
+    (
+        "usage.conv_list",
+        "List conversations (supports --filter, --format, --sort)",
+        "会話を一覧表示 (--filter, --format, --sort に対応)",
+    ),
+    (
+        "usage.conv_search",
+        "Search conversations by name (supports --select)",
+        "名前で会話を検索 (--select に対応)",
+    ),
+    (
+        "usage.conv_select",
+        "Interactively select a conversation",
+        "会話を対話的に選択",
+    ),
+    (
+        "usage.conv_history",
+        "Get conversation history (supports --interactive)",
+        "会話履歴を取得 (--interactive に対応)",
+    ),
+    (
+        "usage.conv_replies",
+        "Get a thread's parent message and replies",
+        "スレッドの親メッセージと返信を取得",
+    ),
+    (
+        "usage.conv_members",
+        "List channel members (supports --resolve for names from the users cache, --only-guests, --only-external)",
+        "チャンネルのメンバー一覧 (--resolve でユーザーキャッシュから名前を付与、--only-guests, --only-external に対応)",
+    ),
+    (
+        "usage.conv_export",
+        "Export conversation history (supports --format json|html, --out)",
+        "会話履歴をエクスポート (--format json|html, --out に対応)",
+    ),
+    (
+        "usage.conv_tail",
+        "Watch a channel for new messages (supports --exec, --filter)",
+        "チャンネルの新着メッセージを監視 (--exec, --filter に対応)",
+    ),
+    (
+        "usage.conv_watch",
+        "Live tail of a channel as JSON lines with user names (supports --interval, --socket)",
+        "チャンネルの新着メッセージをユーザー名付き JSON 行で表示 (--interval, --socket に対応)",
+    ),
+    (
+        "usage.conv_create",
+        "Create a channel (supports --private; requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "チャンネルを作成 (--private に対応, SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.conv_archive",
+        "Archive or unarchive a channel (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "チャンネルをアーカイブ/アーカイブ解除 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.conv_rename",
+        "Rename a channel (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "チャンネル名を変更 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.conv_invite",
+        "Invite users to a channel (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "チャンネルにユーザーを招待 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.conv_kick",
+        "Remove a user from a channel (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "チャンネルからユーザーを削除 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.conv_join",
+        "Join a public channel or leave a channel (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "パブリックチャンネルに参加/チャンネルから退出 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.thread_get",
+        "Get thread messages (supports --limit, --inclusive)",
+        "スレッドのメッセージを取得 (--limit, --inclusive に対応)",
+    ),
+    ("usage.users_info", "Get user information", "ユーザー情報を取得"),
+    (
+        "usage.users_cache_update",
+        "Update user cache for mention resolution (supports --profile, --force)",
+        "メンション解決用のユーザーキャッシュを更新 (--profile, --force に対応)",
+    ),
+    (
+        "usage.users_resolve_mentions",
+        "Resolve user mentions in text (supports --profile, --format)",
+        "テキスト中のユーザーメンションを解決 (--profile, --format に対応)",
+    ),
+    (
+        "usage.users_tz_report",
+        "Show timezones, overlapping working hours and a meeting slot (supports --channel, --hours)",
+        "タイムゾーン、重なる勤務時間、会議の候補時間を表示 (--channel, --hours に対応)",
+    ),
+    (
+        "usage.users_offboard_report",
+        "Checklist of channels, usergroups, files and scheduled messages before deactivating a user",
+        "ユーザー無効化前に確認するチャンネル、ユーザーグループ、ファイル、予約メッセージの一覧",
+    ),
+    (
+        "usage.msg_post",
+        "Post a message (requires SLACKCLI_ALLOW_WRITE=true, supports --thread-ts, --reply-broadcast, and --idempotency-key)",
+        "メッセージを投稿 (SLACKCLI_ALLOW_WRITE=true が必要, --thread-ts, --reply-broadcast, --idempotency-key に対応)",
+    ),
+    (
+        "usage.msg_update",
+        "Update a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "メッセージを更新 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.msg_delete",
+        "Delete a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "メッセージを削除 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.msg_schedule",
+        "Schedule a message with --at or --in (requires SLACKCLI_ALLOW_WRITE=true)",
+        "--at または --in でメッセージを予約投稿 (SLACKCLI_ALLOW_WRITE=true が必要)",
+    ),
+    (
+        "usage.msg_scheduled_list",
+        "List pending scheduled messages",
+        "予約投稿の一覧を表示",
+    ),
+    (
+        "usage.msg_scheduled_delete",
+        "Cancel a scheduled message (requires SLACKCLI_ALLOW_WRITE=true)",
+        "予約投稿を取り消し (SLACKCLI_ALLOW_WRITE=true が必要)",
+    ),
+    (
+        "usage.msg_bulk",
+        "Post messages from JSONL/CSV rows with a per-row report (requires SLACKCLI_ALLOW_WRITE=true)",
+        "JSONL/CSV の各行をメッセージとして投稿し行ごとの結果を出力 (SLACKCLI_ALLOW_WRITE=true が必要)",
+    ),
+    (
+        "usage.msg_inspect",
+        "Inspect a message by permalink: rendered blocks, reactions, thread stats, files (supports --json, --raw)",
+        "パーマリンクのメッセージを表示: ブロック、リアクション、スレッド統計、ファイル (--json, --raw に対応)",
+    ),
+    (
+        "usage.react_add",
+        "Add a reaction (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "リアクションを追加 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.react_remove",
+        "Remove a reaction (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "リアクションを削除 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.pin_add",
+        "Pin a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "メッセージをピン留め (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.pin_remove",
+        "Unpin a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "ピン留めを解除 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.pin_list",
+        "List pinned items in a channel",
+        "チャンネルのピン留め一覧を表示",
+    ),
+    (
+        "usage.bookmark_list",
+        "List channel bookmarks (supports --format)",
+        "チャンネルのブックマーク一覧を表示 (--format に対応)",
+    ),
+    (
+        "usage.bookmark_add",
+        "Add a link bookmark to a channel (requires SLACKCLI_ALLOW_WRITE=true)",
+        "チャンネルにリンクのブックマークを追加 (SLACKCLI_ALLOW_WRITE=true が必要)",
+    ),
+    (
+        "usage.bookmark_remove",
+        "Remove a channel bookmark (requires SLACKCLI_ALLOW_WRITE=true)",
+        "チャンネルのブックマークを削除 (SLACKCLI_ALLOW_WRITE=true が必要)",
+    ),
+    (
+        "usage.bookmark_apply",
+        "Sync channel bookmarks to a YAML/JSON spec (supports --dry-run)",
+        "YAML/JSON の定義に合わせてチャンネルのブックマークを同期 (--dry-run に対応)",
+    ),
+    (
+        "usage.emoji_list",
+        "List custom emoji (cached for 24 hours, --refresh to fetch again)",
+        "カスタム絵文字一覧を表示 (24時間キャッシュ, --refresh で再取得)",
+    ),
+    (
+        "usage.usergroup_list",
+        "List usergroups (supports --include-users, --include-disabled)",
+        "ユーザーグループ一覧を表示 (--include-users, --include-disabled に対応)",
+    ),
+    (
+        "usage.usergroup_create",
+        "Create or update a usergroup (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)",
+        "ユーザーグループを作成・更新 (SLACKCLI_ALLOW_WRITE=true が必要, --idempotency-key に対応)",
+    ),
+    (
+        "usage.usergroup_users",
+        "List the members of a usergroup",
+        "ユーザーグループのメンバー一覧を表示",
+    ),
+    (
+        "usage.usergroup_users_update",
+        "Replace usergroup members from --users or --from-file (requires SLACKCLI_ALLOW_WRITE=true)",
+        "--users または --from-file でユーザーグループのメンバーを置き換え (SLACKCLI_ALLOW_WRITE=true が必要)",
+    ),
+    (
+        "usage.remind_add",
+        "Create a reminder (user token only, requires SLACKCLI_ALLOW_WRITE=true)",
+        "リマインダーを作成 (ユーザートークンのみ, SLACKCLI_ALLOW_WRITE=true が必要)",
+    ),
+    (
+        "usage.remind_list",
+        "List your reminders (user token only)",
+        "リマインダーの一覧を表示 (ユーザートークンのみ)",
+    ),
+    (
+        "usage.remind_complete",
+        "Mark a reminder as complete (user token only)",
+        "リマインダーを完了にする (ユーザートークンのみ)",
+    ),
+    (
+        "usage.remind_delete",
+        "Delete a reminder (user token only)",
+        "リマインダーを削除 (ユーザートークンのみ)",
+    ),
+    (
+        "usage.dnd",
+        "Show, start or end Do Not Disturb (user token only)",
+        "おやすみモードの確認・開始・終了 (ユーザートークンのみ)",
+    ),
+    (
+        "usage.presence",
+        "Show or set presence to auto/away (user token only)",
+        "プレゼンスの確認・auto/away の設定 (ユーザートークンのみ)",
+    ),
+    (
+        "usage.connect",
+        "Slack Connect invites, approvals and permissions (invite, requests, permissions)",
+        "Slack Connect の招待・承認・権限管理 (invite, requests, permissions)",
+    ),
+    (
+        "usage.events_listen",
+        "Print Socket Mode events as NDJSON (requires an xapp- token, supports --filter)",
+        "Socket Mode のイベントを NDJSON で出力 (xapp- トークンが必要, --filter に対応)",
+    ),
+    (
+        "usage.kv",
+        "Shared key-value store in a Slack channel (--namespace, --if-version)",
+        "Slack チャンネルを使った共有キーバリューストア (--namespace, --if-version)",
+    ),
+    (
+        "usage.idempotency",
+        "Inspect or clear stored --idempotency-key results",
+        "保存された --idempotency-key の結果を確認・削除",
+    ),
+    (
+        "usage.ctx",
+        "Save a default channel/thread used when arguments are omitted (--context overrides)",
+        "引数省略時に使う既定のチャンネル/スレッドを保存 (--context で上書き)",
+    ),
+    (
+        "usage.shell",
+        "Interactive shell with history and completion",
+        "履歴と補完付きの対話シェル",
+    ),
+    (
+        "usage.completions",
+        "Print a completion script for bash, zsh, fish or powershell",
+        "bash, zsh, fish, powershell 用の補完スクリプトを出力",
+    ),
+    (
+        "usage.file_upload",
+        "Upload files using external upload method (paths or patterns, supports --parallel and --idempotency-key)",
+        "外部アップロード方式でファイルをアップロード (複数パス・パターン可, --parallel, --idempotency-key に対応)",
+    ),
+    (
+        "usage.file_download",
+        "Download a file from Slack (supports --url, --out)",
+        "Slack からファイルをダウンロード (--url, --out に対応)",
+    ),
+    (
+        "usage.file_list",
+        "List files (supports --channel, --user, --types, --older-than, --all)",
+        "ファイル一覧を表示 (--channel, --user, --types, --older-than, --all に対応)",
+    ),
+    (
+        "usage.file_delete",
+        "Delete files (supports --from-list, --yes)",
+        "ファイルを削除 (--from-list, --yes に対応)",
+    ),
+    (
+        "usage.doctor",
+        "Show diagnostic information (supports --profile, --json)",
+        "診断情報を表示 (--profile, --json に対応)",
+    ),
+    (
+        "usage.usage",
+        "Show daily API call counts per method and rate-limit tier",
+        "API 呼び出し数を日別・メソッド別にレート制限ティアと共に表示",
+    ),
+    (
+        "usage.maintenance_run",
+        "Prune expired caches and idempotency keys, report reclaimed space",
+        "期限切れのキャッシュと冪等性キーを削除し、回収した容量を表示",
+    ),
+    (
+        "usage.install_skills",
+        "Install agent skill (default: self, supports local:<path>)",
+        "エージェントスキルをインストール (既定: self, local:<path> に対応)",
+    ),
+    ("usage.demo", "Run demonstration", "デモを実行"),
+    ("usage.help", "Show help", "ヘルプを表示"),
+    ("usage.version", "Show version", "バージョンを表示"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_english() {
+        let messages = Messages::new(Language::English);
+        assert!(messages.get("warn.export_sensitive").starts_with("WARNING"));
+        assert!(messages.get("success.export").contains("exported"));
+    }
+
+    #[test]
+    fn test_messages_japanese() {
+        let messages = Messages::new(Language::Japanese);
+        assert!(messages.get("warn.export_sensitive").starts_with("警告"));
+        assert!(messages.get("success.export").contains("エクスポート"));
+    }
+
+    #[test]
+    fn test_format_message() {
+        let messages = Messages::new(Language::English);
+        let formatted = messages.format("info.export_count", &[("count", "3")]);
+        assert!(formatted.contains("3"));
+        assert!(formatted.contains("profile"));
+    }
+
+    #[test]
+    fn test_language_from_code() {
+        assert_eq!(Language::from_code("en"), Some(Language::English));
+        assert_eq!(Language::from_code("ja"), Some(Language::Japanese));
+        assert_eq!(Language::from_code("EN"), Some(Language::English));
+        assert_eq!(Language::from_code("JA"), Some(Language::Japanese));
+        assert_eq!(Language::from_code("fr"), None);
+    }
+
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(
+            Language::from_locale("ja_JP.UTF-8"),
+            Some(Language::Japanese)
+        );
+        assert_eq!(Language::from_locale("en-US"), Some(Language::English));
+        assert_eq!(Language::from_locale("fr_FR"), None);
+    }
+
+    #[test]
+    fn test_catalog_has_both_languages() {
+        for (key, en, ja) in ENTRIES {
+            assert!(!en.is_empty(), "missing English message for {}", key);
+            assert!(!ja.is_empty(), "missing Japanese message for {}", key);
+        }
+    }
+
+    #[test]
+    fn test_catalog_keys_unique() {
+        assert_eq!(catalog().len(), ENTRIES.len());
+    }
+
+    #[test]
+    fn test_unknown_key_is_empty() {
+        assert_eq!(Messages::new(Language::English).get("no.such.key"), "");
+    }
+}
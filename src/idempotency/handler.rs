@@ -34,6 +34,11 @@ impl IdempotencyHandler {
         })
     }
 
+    /// Create a handler over an existing store
+    pub fn with_store(store: IdempotencyStore) -> Self {
+        Self { store }
+    }
+
     /// Check if operation should be executed or replayed
     ///
     /// # Arguments
@@ -5,11 +5,17 @@
 //! - Capacity limits (10,000 entries default)
 //! - Automatic garbage collection
 //! - Request fingerprinting for duplicate detection
+//! - Time-bucketed keys for `msg post --unique-per day|hour`
+//! - [`ReliablePoster`]: retry-safe posting with an outbox for library users
 
 pub mod handler;
+pub mod reliable;
 pub mod store;
 pub mod types;
 
 pub use handler::{IdempotencyCheckResult, IdempotencyHandler};
+pub use reliable::{
+    default_outbox_path, FlushReport, OutboxEntry, PostOutcome, ReliableError, ReliablePoster,
+};
 pub use store::{IdempotencyError, IdempotencyStore};
-pub use types::{IdempotencyEntry, IdempotencyStatus, RequestFingerprint, ScopedKey};
+pub use types::{IdempotencyEntry, IdempotencyStatus, RequestFingerprint, ScopedKey, UniqueWindow};
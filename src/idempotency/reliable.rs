@@ -0,0 +1,429 @@
+//! Retry-safe message posting for library users
+//!
+//! [`ReliablePoster`] combines the pieces `msg post` uses to deliver a
+//! message exactly once from the caller's point of view:
+//!
+//! - the client's rate-limit retries (HTTP 429 with `Retry-After`)
+//! - the idempotency store, so a key that was already delivered is replayed
+//!   instead of posted again
+//! - an outbox file, written before each send, holding every post whose
+//!   delivery is not confirmed yet
+//!
+//! A post that fails transiently (network error, rate limit exhausted,
+//! Slack-side outage) stays in the outbox and is retried by
+//! [`ReliablePoster::flush`], typically on the next start of the service.
+//! Since a retried key is checked against the idempotency store first, the
+//! result is at-least-once delivery without duplicates for keys that were
+//! confirmed. Permanent errors such as `channel_not_found` are returned and
+//! dropped from the outbox.
+//!
+//! ```no_run
+//! use slack_rs::idempotency::{PostOutcome, ReliablePoster};
+//! use slack_rs::api::ApiClient;
+//!
+//! # async fn run() -> Result<(), slack_rs::idempotency::ReliableError> {
+//! let client = ApiClient::with_token("xoxb-...".to_string());
+//! let mut poster = ReliablePoster::new(client, "T123", "U123")?;
+//! poster.flush().await?;
+//! match poster.post("C123", "Backup finished", "backup-2024-06-01").await? {
+//!     PostOutcome::Sent { ts, .. } => println!("posted {}", ts),
+//!     PostOutcome::Replayed { .. } => println!("already posted"),
+//!     PostOutcome::Queued { error } => eprintln!("queued for retry: {}", error),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::handler::{IdempotencyCheckResult, IdempotencyHandler};
+use super::store::{IdempotencyError, IdempotencyStore};
+use crate::api::{ApiClient, ApiError, ApiMethod, PlannedRequest};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Slack error codes worth retrying later
+const TRANSIENT_ERRORS: &[&str] = &[
+    "ratelimited",
+    "rate_limited",
+    "internal_error",
+    "fatal_error",
+    "service_unavailable",
+    "request_timeout",
+];
+
+/// Errors of [`ReliablePoster`]
+#[derive(Debug, Error)]
+pub enum ReliableError {
+    #[error(transparent)]
+    Idempotency(#[from] IdempotencyError),
+
+    #[error("Outbox error: {0}")]
+    Outbox(String),
+
+    /// Slack refused the post for a reason retrying does not fix
+    #[error("Slack API error: {0}")]
+    Rejected(String),
+}
+
+/// Result of [`ReliablePoster::post`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PostOutcome {
+    /// Posted now
+    Sent { ts: String, response: Value },
+    /// The key was delivered before; the stored response is returned
+    Replayed { response: Value },
+    /// Delivery failed transiently and the post waits in the outbox
+    Queued { error: String },
+}
+
+/// A post whose delivery is not confirmed yet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub key: String,
+    pub channel: String,
+    pub text: String,
+    /// Unix time the post was first attempted
+    pub queued_at: u64,
+    pub attempts: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+/// Outcome of [`ReliablePoster::flush`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlushReport {
+    /// Keys delivered by this flush
+    pub sent: Vec<String>,
+    /// Keys found delivered already (the process stopped before cleanup)
+    pub replayed: Vec<String>,
+    /// Keys still in the outbox after a transient failure
+    pub pending: Vec<String>,
+    /// Keys dropped after a permanent error, with the error
+    pub rejected: Vec<(String, String)>,
+}
+
+/// Poster with rate-limit retries, idempotency and a persistent outbox
+pub struct ReliablePoster {
+    client: ApiClient,
+    team_id: String,
+    user_id: String,
+    idempotency: IdempotencyHandler,
+    outbox_path: PathBuf,
+    outbox: Vec<OutboxEntry>,
+}
+
+impl ReliablePoster {
+    /// Create a poster using the default idempotency store and outbox
+    ///
+    /// `team_id` and `user_id` scope the idempotency keys, as they do for
+    /// `msg post --idempotency-key`, so a key delivered by the CLI with the
+    /// same identity is replayed here and vice versa.
+    pub fn new(client: ApiClient, team_id: &str, user_id: &str) -> Result<Self, ReliableError> {
+        let store = IdempotencyStore::new()?;
+        Self::with_store(client, team_id, user_id, store, default_outbox_path()?)
+    }
+
+    /// Create a poster with explicit idempotency store and outbox files
+    pub fn with_paths(
+        client: ApiClient,
+        team_id: &str,
+        user_id: &str,
+        store_path: PathBuf,
+        outbox_path: PathBuf,
+    ) -> Result<Self, ReliableError> {
+        let store = IdempotencyStore::with_path(store_path)?;
+        Self::with_store(client, team_id, user_id, store, outbox_path)
+    }
+
+    fn with_store(
+        client: ApiClient,
+        team_id: &str,
+        user_id: &str,
+        store: IdempotencyStore,
+        outbox_path: PathBuf,
+    ) -> Result<Self, ReliableError> {
+        let outbox = load_outbox(&outbox_path)?;
+        Ok(Self {
+            client,
+            team_id: team_id.to_string(),
+            user_id: user_id.to_string(),
+            idempotency: IdempotencyHandler::with_store(store),
+            outbox_path,
+            outbox,
+        })
+    }
+
+    /// Posts waiting in the outbox, oldest first
+    pub fn pending(&self) -> &[OutboxEntry] {
+        &self.outbox
+    }
+
+    /// Post `text` to `channel` at most once per `key`
+    ///
+    /// Reusing a key with a different channel or text is an error
+    /// ([`IdempotencyError::FingerprintMismatch`]).
+    pub async fn post(
+        &mut self,
+        channel: &str,
+        text: &str,
+        key: &str,
+    ) -> Result<PostOutcome, ReliableError> {
+        let entry = match self.outbox.iter().position(|e| e.key == key) {
+            Some(i) => self.outbox.remove(i),
+            None => OutboxEntry {
+                key: key.to_string(),
+                channel: channel.to_string(),
+                text: text.to_string(),
+                queued_at: now_secs(),
+                attempts: 0,
+                last_error: None,
+            },
+        };
+        if entry.channel != channel || entry.text != text {
+            self.outbox.push(entry);
+            return Err(IdempotencyError::FingerprintMismatch.into());
+        }
+        self.deliver(entry).await
+    }
+
+    /// Retry every post in the outbox
+    pub async fn flush(&mut self) -> Result<FlushReport, ReliableError> {
+        let mut report = FlushReport::default();
+        let keys: Vec<String> = self.outbox.iter().map(|e| e.key.clone()).collect();
+        for key in keys {
+            // Entries not retried yet stay in the outbox, and in its file
+            let Some(i) = self.outbox.iter().position(|e| e.key == key) else {
+                continue;
+            };
+            let entry = self.outbox.remove(i);
+            match self.deliver(entry).await {
+                Ok(PostOutcome::Sent { .. }) => report.sent.push(key),
+                Ok(PostOutcome::Replayed { .. }) => report.replayed.push(key),
+                Ok(PostOutcome::Queued { .. }) => report.pending.push(key),
+                Err(ReliableError::Rejected(error)) => report.rejected.push((key, error)),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Send one post, keeping it in the outbox until the outcome is known
+    async fn deliver(&mut self, mut entry: OutboxEntry) -> Result<PostOutcome, ReliableError> {
+        let request = PlannedRequest::new(ApiMethod::ChatPostMessage)
+            .param("channel", entry.channel.as_str())
+            .param("text", entry.text.as_str());
+        let params: serde_json::Map<String, Value> = request.params.clone().into_iter().collect();
+        let check = match self.idempotency.check(
+            Some(entry.key.clone()),
+            self.team_id.clone(),
+            self.user_id.clone(),
+            ApiMethod::ChatPostMessage.as_str().to_string(),
+            &params,
+        ) {
+            Ok(check) => check,
+            Err(e) => {
+                // Keep a queued post for inspection rather than losing it
+                if entry.attempts > 0 {
+                    self.outbox.push(entry);
+                }
+                return Err(e.into());
+            }
+        };
+        let (key, fingerprint) = match check {
+            IdempotencyCheckResult::Replay { response, .. } => {
+                self.save_outbox()?;
+                return Ok(PostOutcome::Replayed { response });
+            }
+            IdempotencyCheckResult::Execute { key, fingerprint } => (key, fingerprint),
+            IdempotencyCheckResult::NoKey => unreachable!("a key is always given"),
+        };
+
+        // Write ahead: a crash from here on leaves the post in the outbox
+        entry.attempts += 1;
+        self.outbox.push(entry);
+        self.save_outbox()?;
+
+        let result = self.client.execute_planned(request).await;
+        let mut entry = self.outbox.pop().expect("entry pushed above");
+        match result {
+            Ok(response) => {
+                let response = serde_json::to_value(&response)
+                    .map_err(|e| ReliableError::Outbox(e.to_string()))?;
+                self.idempotency.store(key, fingerprint, response.clone())?;
+                self.save_outbox()?;
+                let ts = response["ts"].as_str().unwrap_or_default().to_string();
+                Ok(PostOutcome::Sent { ts, response })
+            }
+            Err(error) if is_transient(&error) => {
+                let error = error.to_string();
+                entry.last_error = Some(error.clone());
+                self.outbox.push(entry);
+                self.save_outbox()?;
+                Ok(PostOutcome::Queued { error })
+            }
+            Err(error) => {
+                self.save_outbox()?;
+                Err(ReliableError::Rejected(match error {
+                    ApiError::SlackError(code) => code,
+                    other => other.to_string(),
+                }))
+            }
+        }
+    }
+
+    fn save_outbox(&self) -> Result<(), ReliableError> {
+        let err = |e: &dyn std::fmt::Display| ReliableError::Outbox(e.to_string());
+        if let Some(parent) = self.outbox_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| err(&e))?;
+        }
+        let content = serde_json::to_string_pretty(&self.outbox).map_err(|e| err(&e))?;
+        // Replace the file in one step so a crash never leaves half an outbox
+        let tmp_path = self.outbox_path.with_extension("json.tmp");
+        fs::write(&tmp_path, content).map_err(|e| err(&e))?;
+        fs::rename(&tmp_path, &self.outbox_path).map_err(|e| err(&e))
+    }
+}
+
+/// Default outbox file: `outbox.json` in the config directory
+pub fn default_outbox_path() -> Result<PathBuf, ReliableError> {
+    crate::profile::config_dir()
+        .map(|dir| dir.join("outbox.json"))
+        .ok_or_else(|| ReliableError::Outbox("Cannot find config directory".to_string()))
+}
+
+fn load_outbox(path: &Path) -> Result<Vec<OutboxEntry>, ReliableError> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| ReliableError::Outbox(format!("{}: {}", path.display(), e))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(ReliableError::Outbox(format!("{}: {}", path.display(), e))),
+    }
+}
+
+/// Whether a failed post may succeed when retried later
+fn is_transient(error: &ApiError) -> bool {
+    match error {
+        ApiError::RequestFailed(_) => true,
+        ApiError::SlackError(code) => TRANSIENT_ERRORS.contains(&code.as_str()),
+        _ => false,
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn poster(server: &MockServer, dir: &TempDir) -> ReliablePoster {
+        let client = ApiClient::new_with_base_url("xoxb-test".to_string(), server.uri());
+        ReliablePoster::with_paths(
+            client,
+            "T1",
+            "U1",
+            dir.path().join("idempotency_store.json"),
+            dir.path().join("outbox.json"),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_post_replays_delivered_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"ok": true, "ts": "1.5"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let dir = TempDir::new().unwrap();
+
+        let mut first = poster(&server, &dir);
+        let outcome = first.post("C1", "done", "job-1").await.unwrap();
+        assert!(matches!(outcome, PostOutcome::Sent { ref ts, .. } if ts == "1.5"));
+        assert!(first.pending().is_empty());
+
+        // A restarted service posting the same key again is answered locally
+        let mut second = poster(&server, &dir);
+        let outcome = second.post("C1", "done", "job-1").await.unwrap();
+        assert!(
+            matches!(outcome, PostOutcome::Replayed { ref response } if response["ts"] == "1.5")
+        );
+        assert!(second.post("C1", "changed", "job-1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_is_queued_and_flushed() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "service_unavailable"})),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"ok": true, "ts": "2.5"})),
+            )
+            .mount(&server)
+            .await;
+        let dir = TempDir::new().unwrap();
+
+        let mut poster_a = poster(&server, &dir);
+        let outcome = poster_a.post("C1", "hello", "job-2").await.unwrap();
+        assert_eq!(
+            outcome,
+            PostOutcome::Queued {
+                error: "Slack API error: service_unavailable".to_string()
+            }
+        );
+
+        // The outbox survives a restart
+        let mut poster_b = poster(&server, &dir);
+        assert_eq!(poster_b.pending().len(), 1);
+        assert_eq!(poster_b.pending()[0].attempts, 1);
+        let report = poster_b.flush().await.unwrap();
+        assert_eq!(report.sent, vec!["job-2".to_string()]);
+        assert!(poster_b.pending().is_empty());
+        assert!(load_outbox(&dir.path().join("outbox.json"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_permanent_failure_is_dropped() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "channel_not_found"})),
+            )
+            .mount(&server)
+            .await;
+        let dir = TempDir::new().unwrap();
+
+        let mut poster = poster(&server, &dir);
+        let error = poster.post("C404", "hello", "job-3").await.unwrap_err();
+        assert!(matches!(error, ReliableError::Rejected(ref code) if code == "channel_not_found"));
+        assert!(poster.pending().is_empty());
+    }
+}
@@ -42,6 +42,10 @@ pub struct IdempotencyStore {
     /// Store file path
     #[serde(skip)]
     store_path: PathBuf,
+
+    /// Number of entries in the file before the GC on load
+    #[serde(skip)]
+    loaded_entries: usize,
 }
 
 impl IdempotencyStore {
@@ -58,13 +62,12 @@ impl IdempotencyStore {
 
     /// Get default store path in config directory
     fn default_store_path() -> Result<PathBuf, IdempotencyError> {
-        let project_dirs = directories::ProjectDirs::from("", "", "slack-rs")
+        let config_dir = crate::profile::config_dir()
             .ok_or_else(|| IdempotencyError::StoreError("Cannot find config directory".into()))?;
-        let config_dir = project_dirs.config_dir();
 
         // Create directory if it doesn't exist
         if !config_dir.exists() {
-            fs::create_dir_all(config_dir)?;
+            fs::create_dir_all(&config_dir)?;
         }
 
         Ok(config_dir.join("idempotency_store.json"))
@@ -77,6 +80,7 @@ impl IdempotencyStore {
             let mut store: IdempotencyStore = serde_json::from_str(&content)?;
             store.store_path = store_path;
             store.capacity = capacity;
+            store.loaded_entries = store.entries.len();
 
             // Run GC on load
             store.gc()?;
@@ -86,6 +90,7 @@ impl IdempotencyStore {
                 entries: HashMap::new(),
                 capacity,
                 store_path,
+                loaded_entries: 0,
             };
 
             // Create parent directory if needed
@@ -200,6 +205,51 @@ impl IdempotencyStore {
         Ok(())
     }
 
+    /// All live entries with their scoped keys, oldest first
+    pub fn entries(&self) -> Vec<(&str, &IdempotencyEntry)> {
+        let mut entries: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(_, e)| !e.is_expired())
+            .map(|(k, e)| (k.as_str(), e))
+            .collect();
+        entries.sort_by(|a, b| a.1.created_at.cmp(&b.1.created_at).then(a.0.cmp(b.0)));
+        entries
+    }
+
+    /// Remove the entries matching `predicate` and save, returning how many were removed
+    pub fn remove_where<F>(&mut self, predicate: F) -> Result<usize, IdempotencyError>
+    where
+        F: Fn(&str, &IdempotencyEntry) -> bool,
+    {
+        let before = self.entries.len();
+        self.entries.retain(|k, e| !predicate(k, e));
+        let removed = before - self.entries.len();
+        if removed > 0 {
+            self.save()?;
+        }
+        Ok(removed)
+    }
+
+    /// Run GC and persist the result
+    ///
+    /// Returns the number of entries dropped since the store was loaded,
+    /// including the ones the GC on load already removed in memory.
+    pub fn collect_garbage(&mut self) -> Result<usize, IdempotencyError> {
+        self.gc()?;
+        self.save()?;
+        let removed = self.loaded_entries.saturating_sub(self.entries.len());
+        self.loaded_entries = self.entries.len();
+        Ok(removed)
+    }
+
+    /// Remove expired entries, returning how many were dropped
+    pub fn prune_expired(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|_, entry| !entry.is_expired());
+        before - self.entries.len()
+    }
+
     /// Get number of entries in store
     #[allow(dead_code)]
     pub fn len(&self) -> usize {
@@ -414,4 +464,47 @@ mod tests {
             assert_eq!(result, Some(response));
         }
     }
+
+    #[test]
+    fn test_collect_garbage_counts_entries_dropped_on_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let store_path = temp_dir.path().join("test_store.json");
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        {
+            let mut store = IdempotencyStore::with_path(store_path.clone()).unwrap();
+            for (i, expires_at) in [now - 5, now + 60, now + 120].into_iter().enumerate() {
+                let entry = IdempotencyEntry {
+                    fingerprint: RequestFingerprint {
+                        hash: i.to_string(),
+                    },
+                    response: json!({"ok": true}),
+                    created_at: now - 10 + i as u64,
+                    expires_at,
+                };
+                store
+                    .entries
+                    .insert(format!("T1/U1/chat.postMessage/k{}", i), entry);
+            }
+            store.save().unwrap();
+        }
+
+        let mut store = IdempotencyStore::with_path(store_path.clone()).unwrap();
+        let keys: Vec<&str> = store.entries().into_iter().map(|(k, _)| k).collect();
+        assert_eq!(
+            keys,
+            vec!["T1/U1/chat.postMessage/k1", "T1/U1/chat.postMessage/k2"]
+        );
+
+        assert_eq!(store.collect_garbage().unwrap(), 1);
+        assert_eq!(store.collect_garbage().unwrap(), 0);
+
+        let removed = store.remove_where(|k, _| k.ends_with("/k1")).unwrap();
+        assert_eq!(removed, 1);
+        let reloaded = IdempotencyStore::with_path(store_path).unwrap();
+        assert_eq!(reloaded.len(), 1);
+    }
 }
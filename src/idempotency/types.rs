@@ -122,6 +122,64 @@ impl IdempotencyEntry {
     }
 }
 
+/// Time window for `--unique-per` deduplication
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UniqueWindow {
+    /// One post per UTC calendar day
+    Day,
+    /// One post per UTC hour
+    Hour,
+}
+
+impl UniqueWindow {
+    /// Parse "day" or "hour"
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "day" => Ok(UniqueWindow::Day),
+            "hour" => Ok(UniqueWindow::Hour),
+            _ => Err(format!(
+                "Invalid --unique-per value '{}'. Valid values: day, hour",
+                s
+            )),
+        }
+    }
+
+    /// Bucket label for a UNIX timestamp (`2024-01-31` or `2024-01-31T09`)
+    pub fn bucket(&self, epoch_secs: u64) -> String {
+        let day = crate::api::usage::day_key(epoch_secs);
+        match self {
+            UniqueWindow::Day => day,
+            UniqueWindow::Hour => format!("{}T{:02}", day, (epoch_secs % 86_400) / 3_600),
+        }
+    }
+
+    /// Derive an idempotency key from channel, thread, text and the current window
+    ///
+    /// `channel_id` must be the resolved conversation ID so that a name and
+    /// its ID share a key. The same channel, thread and text map to the same
+    /// key until the window rolls over, so repeated runs within the window
+    /// replay the first post.
+    pub fn derive_key(
+        &self,
+        channel_id: &str,
+        thread_ts: Option<&str>,
+        text: &str,
+        epoch_secs: u64,
+    ) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(channel_id.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(thread_ts.unwrap_or("").as_bytes());
+        hasher.update(b"\0");
+        hasher.update(text.as_bytes());
+        let digest = format!("{:x}", hasher.finalize());
+
+        format!("unique:{}:{}", self.bucket(epoch_secs), &digest[..16])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +279,53 @@ mod tests {
         std::thread::sleep(std::time::Duration::from_secs(2));
         assert!(entry.is_expired());
     }
+
+    #[test]
+    fn test_unique_window_bucket() {
+        // 2023-11-14T22:13:20Z
+        let now = 1_700_000_000;
+        assert_eq!(UniqueWindow::Day.bucket(now), "2023-11-14");
+        assert_eq!(UniqueWindow::Hour.bucket(now), "2023-11-14T22");
+        assert!(UniqueWindow::parse("week").is_err());
+    }
+
+    #[test]
+    fn test_unique_window_derive_key() {
+        let now = 1_700_000_000;
+        let key = UniqueWindow::Day.derive_key("C1", None, "standup", now);
+        assert!(key.starts_with("unique:2023-11-14:"));
+
+        // Same window -> same key; next day or other text -> different key
+        assert_eq!(
+            key,
+            UniqueWindow::Day.derive_key("C1", None, "standup", now + 60)
+        );
+        assert_ne!(
+            key,
+            UniqueWindow::Day.derive_key("C1", None, "standup", now + 86_400)
+        );
+        assert_ne!(key, UniqueWindow::Day.derive_key("C1", None, "retro", now));
+        assert_ne!(
+            key,
+            UniqueWindow::Hour.derive_key("C1", None, "standup", now)
+        );
+    }
+
+    #[test]
+    fn test_unique_window_derive_key_thread() {
+        let now = 1_700_000_000;
+        let top = UniqueWindow::Day.derive_key("C1", None, "standup", now);
+        let reply = UniqueWindow::Day.derive_key("C1", Some("1700000000.000100"), "standup", now);
+
+        // A reply and a top-level post, or replies in two threads, differ
+        assert_ne!(top, reply);
+        assert_ne!(
+            reply,
+            UniqueWindow::Day.derive_key("C1", Some("1700000000.000200"), "standup", now)
+        );
+        assert_eq!(
+            reply,
+            UniqueWindow::Day.derive_key("C1", Some("1700000000.000100"), "standup", now + 60)
+        );
+    }
 }
@@ -5,13 +5,65 @@
 //! - OAuth authentication and profile management
 //! - Wrapper commands for common operations
 //! - Idempotency store for preventing duplicate writes
+//! - Retry-safe posting with a persistent outbox ([`idempotency::ReliablePoster`])
+//! - Socket Mode listener support
+//! - Bounded output queue for streaming commands
+//! - Lightweight JSON query engine for `--query`
+//! - Localized (English/Japanese) user-facing messages
+//! - Machine-readable progress events for long operations
+//! - GitHub Actions annotations and step outputs
+//! - Saving command envelopes to a results directory for pipelines
+//!
+//! Most library users only need [`prelude`].
+//!
+//! # Stability
+//!
+//! The crate follows semver for the modules below. Removing or renaming an
+//! item, or changing a signature, waits for a major release; new items,
+//! enum variants and struct fields may appear in minor releases (the config
+//! structs are deserialized with defaults, so construct them with their
+//! constructors or `..Default::default()`).
+//!
+//! | Module | Covered by semver |
+//! |--------|-------------------|
+//! | [`prelude`] | yes |
+//! | [`api`] | yes |
+//! | [`profile`] | yes |
+//! | [`idempotency`] | yes |
+//! | [`socket`] | yes |
+//! | [`stream`] | yes |
+//! | [`query`] | yes |
+//! | `cli`, `commands`, `auth`, `oauth`, `skills`, `i18n`, `progress`, `debug`, `gha`, `results_dir` | no |
+//!
+//! The modules in the last row implement the `slack-rs` binary. They are
+//! public so the binary and the integration tests can reach them, but they
+//! are hidden from the documentation and change with the CLI. The public
+//! surface of the covered modules is pinned by `tests/public_api.rs`.
 
 pub mod api;
+#[doc(hidden)]
 pub mod auth;
+#[doc(hidden)]
 pub mod cli;
+#[doc(hidden)]
 pub mod commands;
+#[doc(hidden)]
 pub mod debug;
+#[doc(hidden)]
+pub mod gha;
+#[doc(hidden)]
+pub mod i18n;
 pub mod idempotency;
+#[doc(hidden)]
 pub mod oauth;
+pub mod prelude;
 pub mod profile;
+#[doc(hidden)]
+pub mod progress;
+pub mod query;
+#[doc(hidden)]
+pub mod results_dir;
+#[doc(hidden)]
 pub mod skills;
+pub mod socket;
+pub mod stream;
@@ -4,16 +4,67 @@ use slack_rs::profile::{
     default_config_path, load_config, make_token_key, resolve_profile, save_config,
     InMemoryTokenStore, Profile, ProfilesConfig, TokenStore,
 };
-use slack_rs::{auth, cli, commands, profile};
+use slack_rs::{api, auth, cli, commands, debug, gha, i18n, profile, progress, query};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[tokio::main]
 async fn main() {
     let args: Vec<String> = std::env::args().collect();
 
+    // --config-dir / --ephemeral: where profiles, tokens and caches live
+    let args = apply_config_dir_flags(args).await;
+
+    run(args).await;
+}
+
+/// Run one command line: the process's own or a line of `slack-rs shell`
+async fn run(args: Vec<String>) {
     // Normalize arguments: extract global flags and reposition them after the command
     // This allows --profile and --non-interactive to work in any position
     let args = normalize_global_flags(&args);
 
+    // Parse with the clap command tree: reject unknown commands and flags,
+    // render `<command> --help`, and dispatch on the subcommand path it matched
+    let path = parse_command_line(&args);
+
+    // --query / --jq: print only the values extracted from the JSON output
+    let args = apply_query_flag(args);
+
+    // --results-dir: also save each JSON envelope as a file for pipelines
+    let args = apply_results_dir_flag(args);
+
+    // --stdin: parameters from a JSON object (or one run per JSONL object) on stdin
+    let args = apply_stdin_flag(args);
+
+    // --gha: GitHub Actions annotations and $GITHUB_OUTPUT results
+    let args = apply_gha_flag(args);
+
+    // Apply the global --lang flag before any output is produced
+    let args = apply_lang_flag(args);
+
+    // Per-command API usage report: --report-usage or verbose (--debug/--trace) mode
+    let args = apply_report_usage_flag(args);
+
+    // --explain: show how output settings were resolved
+    let args = apply_explain_flag(args);
+
+    // --progress json: structured progress events on stderr
+    let args = apply_progress_flag(args);
+
+    // --max-retries N: rate-limit retries per API request
+    let args = apply_max_retries_flag(args);
+
+    // --max-writes N / --max-writes-per-day N: write budget guard
+    let args = apply_max_writes_flags(args);
+
+    // --input json: take write command parameters from a JSON object on stdin
+    let args = apply_input_flag(args);
+
+    // --context / saved session context: fill omitted channel/ts arguments
+    let args = apply_context_flag(args);
+
     // Parse global --non-interactive flag
     let non_interactive = cli::has_flag(&args, "--non-interactive");
     let ctx = cli::CliContext::new(non_interactive);
@@ -42,19 +93,19 @@ async fn main() {
                 }
                 Err(e) => {
                     eprintln!("Help generation failed: {}", e);
-                    std::process::exit(1);
+                    cli::exit(1);
                 }
             }
         }
     }
 
-    match args[1].as_str() {
+    match subcommand(&path, 0) {
         "--version" | "-v" => {
             print_version();
             return;
         }
         "api" => {
-            if args.len() > 2 && args[2] == "call" {
+            if subcommand(&path, 1) == "call" {
                 // Run api call command
                 let api_args: Vec<String> = args[3..].to_vec();
                 if let Err(e) = cli::run_api_call(api_args).await {
@@ -65,40 +116,111 @@ async fn main() {
             }
         }
         "auth" => {
-            handle_auth_command(&args, &ctx).await;
+            handle_auth_command(&path, &args, &ctx).await;
+        }
+        "whoami" => {
+            if let Err(e) = cli::run_whoami(&args[2..]).await {
+                handle_command_error(&e, "Whoami failed");
+            }
         }
         "config" => {
-            handle_config_command(&args);
+            handle_config_command(&path, &args);
         }
         "search" => {
             if args.len() < 3 {
                 eprintln!(
-                    "Usage: {} search <query> [--count=N] [--page=N] [--sort=TYPE] [--sort_dir=DIR] [--profile=NAME]",
+                    "Usage: {} search [files|all] <query> [--in=CHANNEL] [--from=USER] [--after=DATE] [--before=DATE] [--has=:EMOJI:] [--during=PERIOD] [--count=N] [--page=N] [--all] [--max-results=N] [--flatten] [--sort=TYPE] [--sort_dir=DIR] [--group-by=channel|user --summary] [--profile=NAME | --profiles=A,B | --all-profiles]",
                     args[0]
                 );
-                std::process::exit(1);
+                cli::exit(1);
             }
             if let Err(e) = run_search(&args).await {
                 handle_command_error(&e.to_string(), "Search failed");
             }
         }
         "conv" => {
-            handle_conv_command(&args).await;
+            handle_conv_command(&path, &args, &ctx).await;
         }
         "thread" => {
-            handle_thread_command(&args).await;
+            handle_thread_command(&path, &args).await;
         }
         "users" => {
-            handle_users_command(&args).await;
+            handle_users_command(&path, &args).await;
         }
         "msg" => {
-            handle_msg_command(&args, &ctx).await;
+            handle_msg_command(&path, &args, &ctx).await;
         }
         "react" => {
-            handle_react_command(&args, &ctx).await;
+            handle_react_command(&path, &args, &ctx).await;
+        }
+        "pin" => {
+            handle_pin_command(&path, &args, &ctx).await;
+        }
+        "bookmark" => {
+            handle_bookmark_command(&path, &args, &ctx).await;
+        }
+        "usergroup" => {
+            handle_usergroup_command(&path, &args, &ctx).await;
+        }
+        "emoji" => match subcommand(&path, 1) {
+            "list" => {
+                if let Err(e) = run_emoji_list(&args).await {
+                    handle_command_error(&e, "Emoji list failed");
+                }
+            }
+            _ => print_emoji_usage(&args[0]),
+        },
+        "remind" => {
+            handle_remind_command(&path, &args, &ctx).await;
+        }
+        "dnd" | "presence" => {
+            handle_dnd_command(&path, &args, &ctx).await;
         }
         "file" => {
-            handle_file_command(&args, &ctx).await;
+            handle_file_command(&path, &args, &ctx).await;
+        }
+        "events" => match subcommand(&path, 1) {
+            "listen" => {
+                if let Err(e) = run_events_listen(&args).await {
+                    handle_command_error(&e, "Events listen failed");
+                }
+            }
+            _ => print_events_usage(&args[0]),
+        },
+        "kv" => {
+            if let Err(e) = run_kv(&args).await {
+                handle_command_error(&e, "KV command failed");
+            }
+        }
+        "ctx" => {
+            if let Err(e) = run_ctx(&args).await {
+                handle_command_error(&e, "Ctx command failed");
+            }
+        }
+        "idempotency" => {
+            if let Err(e) = run_idempotency(&args, ctx.is_non_interactive()) {
+                handle_command_error(&e, "Idempotency command failed");
+            }
+        }
+        "shell" => {
+            let language = i18n::current_language();
+            let run_line = move |args: Vec<String>| -> Pin<Box<dyn Future<Output = ()>>> {
+                reset_global_flags(language);
+                Box::pin(run(args))
+            };
+            if let Err(e) = run_shell(&args, &run_line).await {
+                handle_command_error(&e, "Shell failed");
+            }
+        }
+        "completions" => {
+            if let Err(e) = run_completions(&args) {
+                handle_command_error(&e, "Completions failed");
+            }
+        }
+        "connect" => {
+            if let Err(e) = run_connect(&args, ctx.is_non_interactive()).await {
+                handle_command_error(&e, "Connect command failed");
+            }
         }
         "commands" => {
             // Check for --json flag
@@ -108,7 +230,7 @@ async fn main() {
                 println!("{}", json);
             } else {
                 eprintln!("Usage: {} commands --json", args[0]);
-                std::process::exit(1);
+                cli::exit(1);
             }
         }
         "schema" => {
@@ -129,14 +251,26 @@ async fn main() {
                     }
                 } else {
                     eprintln!("Invalid output format. Use --output json-schema");
-                    std::process::exit(1);
+                    cli::exit(1);
                 }
             } else {
                 eprintln!(
                     "Usage: {} schema --command <cmd> --output json-schema",
                     args[0]
                 );
-                std::process::exit(1);
+                cli::exit(1);
+            }
+        }
+        "doctor" if subcommand(&path, 1) == "network" => {
+            let options = commands::NetworkOptions {
+                base_url: cli::get_option(&args, "--base-url="),
+                app_token: cli::get_option(&args, "--app-token=")
+                    .or_else(|| std::env::var("SLACK_APP_TOKEN").ok()),
+            };
+            let json_output = cli::has_flag(&args, "--json");
+
+            if let Err(e) = commands::doctor_network(&options, json_output).await {
+                handle_command_error(&e, "Doctor network failed");
             }
         }
         "doctor" => {
@@ -146,6 +280,9 @@ async fn main() {
                 println!();
                 println!("USAGE:");
                 println!("    slack-rs doctor [OPTIONS]");
+                println!(
+                    "    slack-rs doctor network [--base-url=<url>] [--app-token=<xapp>] [--json]"
+                );
                 println!();
                 println!("OPTIONS:");
                 println!("    --profile=<name>    Profile to diagnose (default: 'default')");
@@ -163,6 +300,7 @@ async fn main() {
                 println!("    slack-rs doctor");
                 println!("    slack-rs doctor --profile=work");
                 println!("    slack-rs doctor --json");
+                println!("    slack-rs doctor network --json");
                 return;
             }
 
@@ -174,6 +312,77 @@ async fn main() {
                 handle_command_error(&e.to_string(), "Doctor command failed");
             }
         }
+        "usage" => {
+            if cli::has_flag(&args, "--help") || cli::has_flag(&args, "-h") {
+                println!("API usage report");
+                println!();
+                println!("USAGE:");
+                println!("    slack-rs usage [OPTIONS]");
+                println!();
+                println!("OPTIONS:");
+                println!("    --days=<n>          Number of days to show (default: 7)");
+                println!("    --json              Output in JSON format");
+                println!("    --help, -h          Show this help message");
+                println!();
+                println!("DESCRIPTION:");
+                println!("    Shows API calls per day and per method, recorded locally by every");
+                println!("    command, together with each method's Slack rate-limit tier.");
+                println!();
+                println!("EXAMPLES:");
+                println!("    slack-rs usage");
+                println!("    slack-rs usage --days=30 --json");
+                return;
+            }
+
+            let days = match cli::get_option(&args, "--days=") {
+                Some(v) => match v.parse::<u64>() {
+                    Ok(n) if n > 0 => n,
+                    _ => handle_command_error(
+                        &format!("Invalid --days value '{}': expected a positive integer", v),
+                        "Usage command failed",
+                    ),
+                },
+                None => commands::usage::DEFAULT_USAGE_DAYS,
+            };
+            let json_output = cli::has_flag(&args, "--json");
+
+            if let Err(e) = commands::usage(days, json_output) {
+                handle_command_error(&e, "Usage command failed");
+            }
+        }
+        "maintenance" => {
+            if subcommand(&path, 1) != "run"
+                || cli::has_flag(&args, "--help")
+                || cli::has_flag(&args, "-h")
+            {
+                println!("Local state maintenance");
+                println!();
+                println!("USAGE:");
+                println!("    slack-rs maintenance run [OPTIONS]");
+                println!();
+                println!("OPTIONS:");
+                println!("    --dry-run           Report what would be removed without writing");
+                println!("    --json              Output in JSON format");
+                println!("    --help, -h          Show this help message");
+                println!();
+                println!("DESCRIPTION:");
+                println!("    Prunes expired idempotency keys, users and last-activity cache");
+                println!("    entries, usage counters past retention and leftover temporary");
+                println!("    files in the config directory, and reports the reclaimed space.");
+                println!();
+                println!("EXAMPLES:");
+                println!("    slack-rs maintenance run --dry-run");
+                println!("    0 3 * * * slack-rs maintenance run --json");
+                return;
+            }
+
+            let dry_run = cli::has_flag(&args, "--dry-run");
+            let json_output = cli::has_flag(&args, "--json");
+
+            if let Err(e) = commands::maintenance_run(dry_run, json_output) {
+                handle_command_error(&e, "Maintenance failed");
+            }
+        }
         "install-skills" => {
             if let Err(e) = cli::run_install_skill(&args[2..]) {
                 handle_command_error(&e, "Skill installation failed");
@@ -199,6 +408,163 @@ async fn main() {
             print_usage();
         }
     }
+
+    finish_usage_reporting();
+}
+
+/// Parse the command line with the clap command tree
+///
+/// Returns the subcommand path clap matched (see [`cli::parse_args`]).
+/// Prints the generated help and exits 0 for `--help`/`-h` on a command;
+/// invalid arguments exit 1. The top-level help, `--version` and
+/// `--help --json` keep their own handlers and dispatch on the first word.
+fn parse_command_line(args: &[String]) -> Vec<String> {
+    if args.len() < 2
+        || matches!(args[1].as_str(), "--help" | "-h" | "--version" | "-v")
+        || (cli::has_flag(args, "--help") && cli::has_flag(args, "--json"))
+    {
+        return args.iter().skip(1).take(1).cloned().collect();
+    }
+
+    match cli::parse_args(args) {
+        Ok(path) => path,
+        Err(e) if e.kind() == clap::error::ErrorKind::DisplayHelp => {
+            print!("{}", e);
+            cli::exit(0);
+        }
+        Err(e) => {
+            eprint!("{}", e);
+            cli::exit(1);
+        }
+    }
+}
+
+/// Extract the global `--config-dir <dir>` and `--ephemeral` flags
+///
+/// `--config-dir` sets `SLACKRS_CONFIG_DIR`, so child runs (`--query`,
+/// `--stdin`) use the same directory. `--ephemeral` runs the command in a
+/// child process with a fresh private directory and removes it once the
+/// child exits, also after Ctrl-C; no profile, token or cache outlives the run.
+async fn apply_config_dir_flags(args: Vec<String>) -> Vec<String> {
+    let fail = |message: String| -> ! {
+        eprintln!("Error: {}", message);
+        cli::exit(1);
+    };
+
+    let (result, config_dir, ephemeral) = take_config_dir_flags(args).unwrap_or_else(|e| fail(e));
+    if ephemeral && config_dir.is_some() {
+        fail("--ephemeral cannot be combined with --config-dir".to_string());
+    }
+    if let Some(dir) = config_dir {
+        if dir.is_empty() {
+            fail("--config-dir expects a directory".to_string());
+        }
+        let dir = std::path::absolute(&dir)
+            .unwrap_or_else(|e| fail(format!("invalid --config-dir '{}': {}", dir, e)));
+        std::env::set_var(profile::CONFIG_DIR_ENV, dir);
+    }
+    if !ephemeral {
+        return result;
+    }
+
+    let dir = create_private_dir("ephemeral")
+        .unwrap_or_else(|e| fail(format!("could not create the ephemeral directory: {}", e)));
+    let exe = std::env::current_exe()
+        .unwrap_or_else(|e| fail(format!("cannot locate the slack-rs executable: {}", e)));
+    let status = tokio::process::Command::new(&exe)
+        .args(&result[1..])
+        .env(profile::CONFIG_DIR_ENV, &dir)
+        .status();
+    tokio::pin!(status);
+    // Ctrl-C reaches the child too; keep waiting so the directory is removed
+    let status = loop {
+        tokio::select! {
+            status = &mut status => break status,
+            _ = tokio::signal::ctrl_c() => {}
+        }
+    };
+    let _ = std::fs::remove_dir_all(&dir);
+    match status {
+        Ok(status) => cli::exit(status.code().unwrap_or(1)),
+        Err(e) => fail(format!("could not run the command: {}", e)),
+    }
+}
+
+/// Remove `--config-dir <dir>` and `--ephemeral` from the global flags
+///
+/// Both are read only before the subcommand. Arguments after it, message
+/// text and anything after `--` included, are returned unchanged; a bare
+/// flag between the subcommand and `--` is rejected rather than ignored.
+/// Returns (remaining args, config dir, ephemeral).
+fn take_config_dir_flags(args: Vec<String>) -> Result<(Vec<String>, Option<String>, bool), String> {
+    let mut result = Vec::with_capacity(args.len());
+    let mut config_dir = None;
+    let mut ephemeral = false;
+
+    let mut iter = args.into_iter();
+    result.extend(iter.next());
+    while let Some(arg) = iter.next() {
+        if arg == "--config-dir" {
+            config_dir = iter.next();
+        } else if let Some(dir) = arg.strip_prefix("--config-dir=") {
+            config_dir = Some(dir.to_string());
+        } else if arg == "--ephemeral" {
+            ephemeral = true;
+        } else if arg == "--" {
+            result.push(arg);
+            result.extend(iter);
+            return Ok((result, config_dir, ephemeral));
+        } else {
+            // Other global flags keep their `--flag value` value
+            let takes_value = cli::GLOBAL_FLAGS
+                .iter()
+                .any(|(name, value, _)| *name == arg && value.is_some());
+            let is_subcommand = !arg.starts_with('-');
+            result.push(arg);
+            if is_subcommand {
+                break;
+            }
+            if takes_value {
+                result.extend(iter.next());
+            }
+        }
+    }
+    // clap would still read these as flags after the subcommand
+    for arg in iter.by_ref() {
+        if arg == "--ephemeral" || arg == "--config-dir" || arg.starts_with("--config-dir=") {
+            let flag = arg.split('=').next().unwrap_or_default();
+            return Err(format!("{} must come before the command", flag));
+        }
+        let end_of_flags = arg == "--";
+        result.push(arg);
+        if end_of_flags {
+            break;
+        }
+    }
+    result.extend(iter);
+    Ok((result, config_dir, ephemeral))
+}
+
+/// Create a private (0700), uniquely named `slack-rs-<purpose>-…` directory
+/// under the system temp dir
+///
+/// Creation fails rather than reusing a path that already exists.
+fn create_private_dir(purpose: &str) -> std::io::Result<std::path::PathBuf> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!(
+        "slack-rs-{}-{}-{:08x}",
+        purpose,
+        std::process::id(),
+        nanos
+    ));
+    let mut builder = std::fs::DirBuilder::new();
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(&dir)?;
+    Ok(dir)
 }
 
 /// Normalize global flags by moving them after the command
@@ -251,28 +617,621 @@ fn normalize_global_flags(args: &[String]) -> Vec<String> {
     result
 }
 
+/// Extract the global `--lang <code>` / `--lang=<code>` flag and apply it
+///
+/// The flag is removed from the returned arguments so command parsers never
+/// see it. Unsupported codes print a warning and fall back to English.
+fn apply_lang_flag(args: Vec<String>) -> Vec<String> {
+    let mut result = Vec::with_capacity(args.len());
+    let mut lang_code = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--lang" {
+            lang_code = iter.next();
+        } else if let Some(code) = arg.strip_prefix("--lang=") {
+            lang_code = Some(code.to_string());
+        } else {
+            result.push(arg);
+        }
+    }
+
+    if let Some(code) = lang_code {
+        match i18n::Language::from_locale(&code) {
+            Some(lang) => i18n::set_language(lang),
+            None => {
+                i18n::set_language(i18n::Language::English);
+                gha::warn(&i18n::tf("warn.unknown_lang", &[("lang", &code)]));
+            }
+        }
+    } else if std::env::var("SLACKRS_LANG").is_err() {
+        // `output.locale` sits between SLACKRS_LANG and LANG; locales without
+        // a catalog leave the LANG-based detection in place
+        if let Some(lang) = cli::resolve_display_preferences(&result)
+            .locale
+            .and_then(|locale| i18n::Language::from_locale(&locale))
+        {
+            i18n::set_language(lang);
+        }
+    }
+
+    result
+}
+
+/// Whether to print the API usage report when the command finishes
+static REPORT_USAGE: AtomicBool = AtomicBool::new(false);
+
+/// Extract the global `--report-usage` flag
+///
+/// The report is also enabled in verbose mode (`--debug`, `--trace` or
+/// `SLACK_RS_DEBUG`); the debug flags themselves are left in place.
+fn apply_report_usage_flag(args: Vec<String>) -> Vec<String> {
+    let before = args.len();
+    let result: Vec<String> = args
+        .into_iter()
+        .filter(|arg| arg != "--report-usage")
+        .collect();
+
+    let verbose = debug::get_debug_level(&result) >= debug::DebugLevel::Debug;
+    REPORT_USAGE.store(result.len() != before || verbose, Ordering::Relaxed);
+
+    result
+}
+
+/// Extract the global `--gha` flag and enable GitHub Actions mode
+fn apply_gha_flag(args: Vec<String>) -> Vec<String> {
+    if !args.iter().any(|arg| arg == "--gha") {
+        return args;
+    }
+    gha::set_enabled(true);
+    args.into_iter().filter(|arg| arg != "--gha").collect()
+}
+
+/// Extract the global `--explain` flag and print the effective output settings
+fn apply_explain_flag(args: Vec<String>) -> Vec<String> {
+    if !args.iter().any(|arg| arg == "--explain") {
+        return args;
+    }
+    eprintln!("{}", cli::explain_output_preferences(&args));
+    args.into_iter().filter(|arg| arg != "--explain").collect()
+}
+
+/// Extract the global `--progress <mode>` / `--progress=<mode>` flag
+///
+/// Unknown modes print a warning and leave progress events off.
+fn apply_progress_flag(args: Vec<String>) -> Vec<String> {
+    let mut result = Vec::with_capacity(args.len());
+    let mut mode = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--progress" {
+            mode = iter.next();
+        } else if let Some(value) = arg.strip_prefix("--progress=") {
+            mode = Some(value.to_string());
+        } else {
+            result.push(arg);
+        }
+    }
+
+    if let Some(mode) = mode {
+        match progress::ProgressMode::parse(&mode) {
+            Ok(mode) => progress::set_mode(mode),
+            Err(e) => gha::warn(&format!("Warning: {}", e)),
+        }
+    }
+
+    result
+}
+
+/// Extract the global `--max-retries <n>` / `--max-retries=<n>` flag
+///
+/// Invalid values print a warning and keep the default.
+fn apply_max_retries_flag(args: Vec<String>) -> Vec<String> {
+    let mut result = Vec::with_capacity(args.len());
+    let mut value = None;
+
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--max-retries" {
+            value = iter.next();
+        } else if let Some(v) = arg.strip_prefix("--max-retries=") {
+            value = Some(v.to_string());
+        } else {
+            result.push(arg);
+        }
+    }
+
+    if let Some(value) = value {
+        match value.parse::<u32>() {
+            Ok(n) => api::set_default_max_retries(n),
+            Err(_) => gha::warn(&format!(
+                "Warning: invalid --max-retries value '{}', using {}",
+                value,
+                api::DEFAULT_MAX_RETRIES
+            )),
+        }
+    }
+
+    result
+}
+
+/// Extract the global `--max-writes` / `--max-writes-per-day` flags
+///
+/// Limits from `SLACK_RS_MAX_WRITES` / `SLACK_RS_MAX_WRITES_PER_DAY` apply
+/// first and the flags override them. Unlike `--max-retries`, an invalid
+/// value is fatal: a safety limit must never be silently dropped.
+fn apply_max_writes_flags(args: Vec<String>) -> Vec<String> {
+    if let Err(e) = api::budget::init_from_env() {
+        eprintln!("Error: {}", e);
+        cli::exit(1);
+    }
+
+    let mut result = Vec::with_capacity(args.len());
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        let (flag, value) = if arg == "--max-writes" || arg == "--max-writes-per-day" {
+            let value = iter.next();
+            (arg, value)
+        } else if let Some(v) = arg.strip_prefix("--max-writes=") {
+            ("--max-writes".to_string(), Some(v.to_string()))
+        } else if let Some(v) = arg.strip_prefix("--max-writes-per-day=") {
+            ("--max-writes-per-day".to_string(), Some(v.to_string()))
+        } else {
+            result.push(arg);
+            continue;
+        };
+
+        match value.as_deref().map(str::parse::<u64>) {
+            Some(Ok(n)) if flag == "--max-writes" => api::budget::set_max_writes(Some(n)),
+            Some(Ok(n)) => api::budget::set_max_writes_per_day(Some(n)),
+            _ => {
+                eprintln!(
+                    "Error: invalid {} value '{}': expected a non-negative integer",
+                    flag,
+                    value.unwrap_or_default()
+                );
+                cli::exit(1);
+            }
+        }
+    }
+
+    result
+}
+
+/// Extract `--query` / `--jq` and filter the command's JSON output through it
+///
+/// The command runs in a child process whose stdout is parsed as a stream of
+/// JSON documents (pretty-printed or JSONL, so streaming commands work too);
+/// each document is passed through the query and every result is printed on
+/// its own line. Stdin and stderr are inherited, and the child's exit code is
+/// kept unless the output cannot be queried.
+fn apply_query_flag(args: Vec<String>) -> Vec<String> {
+    let mut expr = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if let Some(value) = arg
+            .strip_prefix("--query=")
+            .or_else(|| arg.strip_prefix("--jq="))
+        {
+            expr = Some(value.to_string());
+        } else if arg == "--query" || arg == "--jq" {
+            expr = iter.next();
+        } else {
+            rest.push(arg);
+        }
+    }
+    let Some(expr) = expr else {
+        return rest;
+    };
+    let fail = |message: String| -> ! {
+        eprintln!("Error: --query: {}", message);
+        cli::exit(1);
+    };
+    let query = query::Query::parse(&expr).unwrap_or_else(|e| fail(e));
+    let exe = std::env::current_exe()
+        .unwrap_or_else(|e| fail(format!("cannot locate the slack-rs executable: {}", e)));
+    let mut child = std::process::Command::new(&exe)
+        .args(&rest[1..])
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| fail(format!("could not run the command: {}", e)));
+    let stdout = std::io::BufReader::new(child.stdout.take().expect("piped stdout"));
+
+    let mut error = None;
+    for document in serde_json::Deserializer::from_reader(stdout).into_iter::<serde_json::Value>() {
+        let results = document
+            .map_err(|e| format!("command output is not JSON ({})", e))
+            .and_then(|value| query.evaluate(&value));
+        match results {
+            Ok(results) => {
+                for value in results {
+                    println!("{}", query::render_value(&value));
+                }
+            }
+            Err(e) => {
+                error = Some(e);
+                let _ = child.kill();
+                break;
+            }
+        }
+    }
+    let status = child.wait();
+    if let Some(e) = error {
+        fail(e);
+    }
+    match status {
+        Ok(status) => cli::exit(status.code().unwrap_or(1)),
+        Err(e) => fail(format!("could not wait for the command: {}", e)),
+    }
+}
+
+/// Environment variable naming a results directory for every invocation
+const RESULTS_DIR_ENV: &str = "SLACKRS_RESULTS_DIR";
+
+/// Extract `--results-dir <dir>` / `--results-keep <n>` and save the envelopes
+///
+/// `SLACKRS_RESULTS_DIR` sets the directory for every invocation. The
+/// command runs in a child process whose stdout is passed through as it
+/// arrives and kept; once it exits, the envelopes found in it are written
+/// to the directory (see [`slack_rs::results_dir`]) and the child's exit code
+/// is kept. A directory that cannot be written is reported as a warning so
+/// the command's own result is never lost.
+fn apply_results_dir_flag(args: Vec<String>) -> Vec<String> {
+    let mut dir = None;
+    let mut keep = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if let Some(value) = arg.strip_prefix("--results-dir=") {
+            dir = Some(value.to_string());
+        } else if arg == "--results-dir" {
+            dir = iter.next();
+        } else if let Some(value) = arg.strip_prefix("--results-keep=") {
+            keep = Some(value.to_string());
+        } else if arg == "--results-keep" {
+            keep = iter.next();
+        } else {
+            rest.push(arg);
+        }
+    }
+    let fail = |message: String| -> ! {
+        eprintln!("Error: --results-dir: {}", message);
+        cli::exit(1);
+    };
+    let explicit = dir.is_some();
+    let Some(dir) = dir
+        .or_else(|| std::env::var(RESULTS_DIR_ENV).ok())
+        .filter(|dir| !dir.is_empty())
+    else {
+        if explicit {
+            fail("expects a directory".to_string());
+        }
+        if keep.is_some() {
+            fail("--results-keep requires --results-dir".to_string());
+        }
+        return rest;
+    };
+    let keep = match keep {
+        Some(value) => match value.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => fail(format!(
+                "--results-keep expects a positive number, got '{}'",
+                value
+            )),
+        },
+        None => slack_rs::results_dir::DEFAULT_KEEP,
+    };
+    let dir = std::path::absolute(&dir)
+        .unwrap_or_else(|e| fail(format!("invalid directory '{}': {}", dir, e)));
+
+    let exe = std::env::current_exe()
+        .unwrap_or_else(|e| fail(format!("cannot locate the slack-rs executable: {}", e)));
+    let mut child = std::process::Command::new(&exe)
+        .args(&rest[1..])
+        .env_remove(RESULTS_DIR_ENV)
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| fail(format!("could not run the command: {}", e)));
+    let mut child_stdout = child.stdout.take().expect("piped stdout");
+
+    // Pass output through right away so prompts and streams are not delayed
+    let mut output = Vec::new();
+    let mut buf = [0u8; 8192];
+    let mut stdout = std::io::stdout();
+    loop {
+        match std::io::Read::read(&mut child_stdout, &mut buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                let _ = std::io::Write::write_all(&mut stdout, &buf[..n]);
+                let _ = std::io::Write::flush(&mut stdout);
+                output.extend_from_slice(&buf[..n]);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(_) => break,
+        }
+    }
+    let code = match child.wait() {
+        Ok(status) => status.code().unwrap_or(1),
+        Err(e) => fail(format!("could not wait for the command: {}", e)),
+    };
+
+    let envelopes = slack_rs::results_dir::extract_envelopes(&output);
+    if envelopes.is_empty() {
+        if explicit && !output.is_empty() {
+            eprintln!("Warning: --results-dir: the output has no JSON envelope; nothing was saved");
+        }
+    } else if let Err(e) = slack_rs::results_dir::write_results(&dir, &envelopes, code, keep) {
+        eprintln!("Warning: --results-dir: {}", e);
+    }
+    cli::exit(code);
+}
+
+/// Extract the `--stdin` flag and merge the JSON read from stdin
+///
+/// A single object is merged into the arguments of this run. A stream of
+/// objects (JSONL) runs the command once per object in a child process, so
+/// each run gets its own confirmation, output and exit status; the process
+/// exits with 1 when any run failed. Runs before the other global flags are
+/// applied so the children see them unchanged.
+fn apply_stdin_flag(args: Vec<String>) -> Vec<String> {
+    if !args.iter().any(|arg| arg == "--stdin") {
+        return args;
+    }
+    let args: Vec<String> = args.into_iter().filter(|arg| arg != "--stdin").collect();
+    let fail = |message: String| -> ! {
+        eprintln!("Error: --stdin: {}", message);
+        cli::exit(1);
+    };
+    if args
+        .iter()
+        .any(|arg| arg == "--input" || arg.starts_with("--input="))
+        && args.get(2).map(String::as_str) != Some("bulk")
+    {
+        fail("cannot be combined with --input json".to_string());
+    }
+    if cli::get_option(&args, "--blocks=").as_deref() == Some("-") {
+        fail("--blocks=- cannot be combined with --stdin (both read stdin)".to_string());
+    }
+    if args.get(1..3) == Some(&["file".to_string(), "upload".to_string()][..])
+        && args[3..]
+            .iter()
+            .any(|arg| arg == commands::STDIN_UPLOAD_PATH)
+    {
+        fail("file upload - cannot be combined with --stdin (both read stdin)".to_string());
+    }
+
+    let mut content = String::new();
+    if let Err(e) = std::io::Read::read_to_string(&mut std::io::stdin(), &mut content) {
+        fail(format!("failed to read stdin: {}", e));
+    }
+    let objects = commands::parse_json_stream(&content).unwrap_or_else(|e| fail(e));
+    if let [object] = objects.as_slice() {
+        return commands::apply_stdin_input(args, object).unwrap_or_else(|e| fail(e));
+    }
+
+    // The children share one --max-writes budget: each gets what is left
+    let (args, flag_limit) = api::budget::take_max_writes_flag(args).unwrap_or_else(|e| fail(e));
+    let max_writes = match flag_limit {
+        Some(limit) => Some(limit),
+        None => match std::env::var(api::budget::MAX_WRITES_ENV) {
+            Ok(value) => Some(value.parse::<u64>().unwrap_or_else(|_| {
+                fail(format!(
+                    "invalid {} value '{}'",
+                    api::budget::MAX_WRITES_ENV,
+                    value
+                ))
+            })),
+            Err(_) => None,
+        },
+    };
+    let runs = objects
+        .iter()
+        .map(|object| commands::apply_stdin_input(args.clone(), object))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|e| fail(e));
+    let exe = std::env::current_exe()
+        .unwrap_or_else(|e| fail(format!("cannot locate the slack-rs executable: {}", e)));
+    // The children report their write count in a private directory, so the
+    // file cannot be planted or redirected by another user
+    let count_dir = create_private_dir("stdin")
+        .unwrap_or_else(|e| fail(format!("could not create a temporary directory: {}", e)));
+    let count_file = count_dir.join("writes.txt");
+    let summary = commands::run_stdin_objects(&runs, max_writes, |index, run, remaining| {
+        let _ = std::fs::remove_file(&count_file);
+        let mut command = std::process::Command::new(&exe);
+        command.args(&run[1..]).stdin(std::process::Stdio::null());
+        if let Some(remaining) = remaining {
+            command
+                .env(api::budget::MAX_WRITES_ENV, remaining.to_string())
+                .env(api::budget::WRITE_COUNT_FILE_ENV, &count_file);
+        }
+        let success = match command.status() {
+            Ok(status) if status.success() => true,
+            Ok(status) => {
+                eprintln!("Error: --stdin object {} failed ({})", index + 1, status);
+                false
+            }
+            Err(e) => {
+                eprintln!("Error: --stdin object {} could not run: {}", index + 1, e);
+                false
+            }
+        };
+        (success, api::budget::read_write_count(&count_file))
+    });
+    let _ = std::fs::remove_dir_all(&count_dir);
+    if summary.skipped > 0 {
+        eprintln!(
+            "Error: --stdin: write budget of {} used up, skipped the last {} object(s)",
+            max_writes.unwrap_or_default(),
+            summary.skipped
+        );
+    }
+    if summary.failed > 0 {
+        eprintln!("{} of {} --stdin run(s) failed", summary.failed, runs.len());
+    }
+    if summary.failed > 0 || summary.skipped > 0 {
+        cli::exit(1);
+    }
+    cli::exit(0);
+}
+
+/// Extract the `--input json` flag and merge the JSON object read from stdin
+///
+/// Runs before the session context is applied, so JSON values take
+/// precedence over the saved context while command-line values win over
+/// both. Invalid input is an error rather than being ignored.
+fn apply_input_flag(args: Vec<String>) -> Vec<String> {
+    // `msg bulk --input=PATH` names its input file
+    if args.get(1).map(String::as_str) == Some("msg")
+        && args.get(2).map(String::as_str) == Some("bulk")
+    {
+        return args;
+    }
+    let mut result = Vec::with_capacity(args.len());
+    let mut mode = None;
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--input" {
+            mode = Some(iter.next().unwrap_or_default());
+        } else if let Some(v) = arg.strip_prefix("--input=") {
+            mode = Some(v.to_string());
+        } else {
+            result.push(arg);
+        }
+    }
+
+    let Some(mode) = mode else {
+        return result;
+    };
+    let fail = |message: String| -> ! {
+        eprintln!("Error: --input {}: {}", mode, message);
+        cli::exit(1);
+    };
+    if mode != "json" {
+        fail("unsupported input mode (expected: json)".to_string());
+    }
+    if cli::get_option(&result, "--blocks=").as_deref() == Some("-") {
+        fail("--blocks=- cannot be combined with --input json (both read stdin)".to_string());
+    }
+
+    let mut content = String::new();
+    if let Err(e) = std::io::Read::read_to_string(&mut std::io::stdin(), &mut content) {
+        fail(format!("failed to read stdin: {}", e));
+    }
+    let input: serde_json::Value = match serde_json::from_str(&content) {
+        Ok(input) => input,
+        Err(e) => fail(format!("invalid JSON on stdin: {}", e)),
+    };
+    commands::apply_json_input(result, &input).unwrap_or_else(|e| fail(e))
+}
+
+/// Extract the global `--context <key=value>...` flag and apply the session context
+///
+/// `--context channel=C thread=TS` (also `--context=channel=C,thread=TS`)
+/// overrides the context saved with `ctx set` for this invocation. A saved
+/// context that cannot be read prints a warning and is ignored.
+fn apply_context_flag(args: Vec<String>) -> Vec<String> {
+    let mut result = Vec::with_capacity(args.len());
+    let mut pairs = Vec::new();
+
+    let mut iter = args.into_iter().peekable();
+    while let Some(arg) = iter.next() {
+        let value = if arg == "--context" {
+            iter.next()
+        } else if let Some(v) = arg.strip_prefix("--context=") {
+            Some(v.to_string())
+        } else {
+            result.push(arg);
+            continue;
+        };
+        pairs.extend(
+            value
+                .unwrap_or_default()
+                .split([',', ' '])
+                .filter(|p| !p.is_empty())
+                .map(str::to_string),
+        );
+        // `--context channel=C thread=TS`: the following key=value pairs belong to the flag
+        while let Some(next) =
+            iter.next_if(|a| a.starts_with("channel=") || a.starts_with("thread="))
+        {
+            pairs.push(next);
+        }
+    }
+
+    if result.get(1).map(String::as_str) == Some("ctx") {
+        return result;
+    }
+
+    let flag = match commands::SessionContext::parse_pairs(&pairs) {
+        Ok(flag) => flag,
+        Err(e) => {
+            eprintln!("Error: invalid --context: {}", e);
+            cli::exit(1);
+        }
+    };
+    let saved = commands::SessionContext::default_path()
+        .and_then(|path| commands::SessionContext::load(&path))
+        .unwrap_or_else(|e| {
+            gha::warn(&format!("Warning: ignoring session context: {}", e));
+            commands::SessionContext::default()
+        });
+    commands::apply_session_context(result, &saved.merged_with(&flag))
+}
+
+/// Undo the global flags of the previous `slack-rs shell` line
+///
+/// Each line starts from the settings of a fresh invocation; only the
+/// shell's own language is kept.
+fn reset_global_flags(language: i18n::Language) {
+    i18n::set_language(language);
+    gha::set_enabled(false);
+    progress::set_mode(progress::ProgressMode::Off);
+    api::set_default_max_retries(api::DEFAULT_MAX_RETRIES);
+    api::budget::reset();
+}
+
+/// Print the per-method API usage report (if requested) and fold this
+/// run's calls into the local daily counters used by `slack-rs usage`
+fn finish_usage_reporting() {
+    let calls = api::usage::session_calls();
+    if REPORT_USAGE.load(Ordering::Relaxed) {
+        if let Some(report) = api::format_usage_report(&calls) {
+            eprintln!("{}", report);
+        }
+    }
+    if let Err(e) = api::usage::flush_session_usage() {
+        debug::log(format!("Failed to record API usage: {}", e));
+    }
+}
+
 /// Handle command error and exit with appropriate code
 ///
 /// This helper consolidates the common error handling pattern:
 /// - Print error message to stderr with prefix
-/// - Exit with code 2 for non-interactive errors, code 1 otherwise
+/// - Exit with the code for the error class (see `cli::exit_code_for_error`)
 fn handle_command_error(error: &str, prefix: &str) -> ! {
     eprintln!("{}: {}", prefix, error);
+    gha::error(&format!("{}: {}", prefix, error));
+    finish_usage_reporting();
 
-    // Check if this is a non-interactive error
-    if cli::is_non_interactive_error(error) {
-        std::process::exit(2);
-    }
-    std::process::exit(1);
+    cli::exit(cli::exit_code_for_error(error));
+}
+
+/// Word `depth` of the subcommand path clap matched (empty past its end)
+fn subcommand(path: &[String], depth: usize) -> &str {
+    path.get(depth).map_or("", String::as_str)
 }
 
 /// Handle auth subcommand dispatch
-async fn handle_auth_command(args: &[String], ctx: &cli::CliContext) {
-    if args.len() < 3 {
+async fn handle_auth_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
         print_auth_usage();
         return;
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "login" => {
             if let Err(e) = cli::run_auth_login(&args[3..], ctx.is_non_interactive()).await {
                 handle_command_error(&e.to_string(), "Login failed");
@@ -285,25 +1244,56 @@ async fn handle_auth_command(args: &[String], ctx: &cli::CliContext) {
             }
         }
         "list" => {
-            if let Err(e) = auth::list() {
+            let result = if cli::has_flag(args, "--json") {
+                auth::list_json()
+            } else {
+                auth::list()
+            };
+            if let Err(e) = result {
                 handle_command_error(&e.to_string(), "List command failed");
             }
         }
+        "compare-tokens" => {
+            if let Err(e) = cli::run_auth_compare_tokens(&args[3..]).await {
+                handle_command_error(&e.to_string(), "Compare tokens failed");
+            }
+        }
+        "test" => {
+            if let Err(e) = cli::run_auth_test(&args[3..]).await {
+                handle_command_error(&e, "Auth test failed");
+            }
+        }
         "rename" => {
             if args.len() < 5 {
                 eprintln!("Usage: {} auth rename <old_name> <new_name>", args[0]);
-                std::process::exit(1);
+                cli::exit(1);
             }
             if let Err(e) = auth::rename(args[3].clone(), args[4].clone()) {
                 handle_command_error(&e.to_string(), "Rename command failed");
             }
         }
+        "set-token" => {
+            if let Err(e) = cli::run_auth_set_token(&args[3..], ctx.is_non_interactive()) {
+                handle_command_error(&e, "Set token failed");
+            }
+        }
         "logout" => {
             let profile_name = args.get(3).cloned();
             if let Err(e) = auth::logout(profile_name) {
                 handle_command_error(&e.to_string(), "Logout command failed");
             }
         }
+        "tokens" => match path.get(2).map(String::as_str) {
+            Some("list") => {
+                if let Err(e) = cli::run_auth_tokens_list(&args[4..]) {
+                    handle_command_error(&e, "Tokens list failed");
+                }
+            }
+            _ => {
+                eprintln!("Usage: {} auth tokens list [--json]", args[0]);
+                cli::exit(1);
+            }
+        },
         "export" => {
             cli::handle_export_command(&args[3..]).await;
         }
@@ -317,18 +1307,18 @@ async fn handle_auth_command(args: &[String], ctx: &cli::CliContext) {
 }
 
 /// Handle config subcommand dispatch
-fn handle_config_command(args: &[String]) {
-    if args.len() < 3 {
+fn handle_config_command(path: &[String], args: &[String]) {
+    if path.len() < 2 {
         print_config_usage(&args[0]);
         return;
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "oauth" => {
-            if args.len() < 4 {
+            if path.len() < 3 {
                 print_config_oauth_usage(&args[0]);
-                std::process::exit(1);
+                cli::exit(1);
             }
-            match args[3].as_str() {
+            match subcommand(path, 2) {
                 "set" => {
                     if let Err(e) = run_config_oauth_set(&args[4..]) {
                         handle_command_error(&e, "OAuth config set failed");
@@ -354,6 +1344,20 @@ fn handle_config_command(args: &[String]) {
                 handle_command_error(&e, "Config set failed");
             }
         }
+        "token-backend" => {
+            let result = match args.get(3) {
+                Some(value) => value.parse::<profile::TokenBackend>().and_then(|backend| {
+                    commands::migrate_token_backend(backend).map_err(|e| e.to_string())
+                }),
+                None => Err(format!(
+                    "Usage: {} config token-backend <file|encrypted-file>",
+                    args[0]
+                )),
+            };
+            if let Err(e) = result {
+                handle_command_error(&e, "Token backend migration failed");
+            }
+        }
         _ => {
             print_config_usage(&args[0]);
         }
@@ -361,12 +1365,12 @@ fn handle_config_command(args: &[String]) {
 }
 
 /// Handle conv subcommand dispatch
-async fn handle_conv_command(args: &[String]) {
-    if args.len() < 3 {
+async fn handle_conv_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
         print_conv_usage(&args[0]);
-        std::process::exit(1);
+        cli::exit(1);
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "list" => {
             if let Err(e) = run_conv_list(args).await {
                 handle_command_error(&e.to_string(), "Conv list failed");
@@ -394,30 +1398,95 @@ async fn handle_conv_command(args: &[String]) {
                     "   or: {} conv history --interactive [--filter=KEY:VALUE]... [--profile=NAME]",
                     args[0]
                 );
-                std::process::exit(1);
+                cli::exit(1);
             }
             if let Err(e) = run_conv_history(args).await {
                 handle_command_error(&e.to_string(), "Conv history failed");
             }
         }
+        "replies" => {
+            if let Err(e) = run_conv_replies(args).await {
+                handle_command_error(&e.to_string(), "Conv replies failed");
+            }
+        }
+        "members" => {
+            if let Err(e) = cli::run_conv_members(args).await {
+                handle_command_error(&e.to_string(), "Conv members failed");
+            }
+        }
+        "export" => {
+            if let Err(e) = run_conv_export(args).await {
+                handle_command_error(&e.to_string(), "Conv export failed");
+            }
+        }
+        "tail" => {
+            if let Err(e) = run_conv_tail(args).await {
+                handle_command_error(&e.to_string(), "Conv tail failed");
+            }
+        }
+        "watch" => {
+            if let Err(e) = run_conv_watch(args).await {
+                handle_command_error(&e.to_string(), "Conv watch failed");
+            }
+        }
+        "create" => {
+            if let Err(e) = cli::run_conv_create(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv create failed");
+            }
+        }
+        "archive" => {
+            if let Err(e) = cli::run_conv_archive(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv archive failed");
+            }
+        }
+        "unarchive" => {
+            if let Err(e) = cli::run_conv_unarchive(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv unarchive failed");
+            }
+        }
+        "rename" => {
+            if let Err(e) = cli::run_conv_rename(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv rename failed");
+            }
+        }
+        "invite" => {
+            if let Err(e) = cli::run_conv_invite(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv invite failed");
+            }
+        }
+        "kick" => {
+            if let Err(e) = cli::run_conv_kick(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv kick failed");
+            }
+        }
+        "join" => {
+            if let Err(e) = cli::run_conv_join(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv join failed");
+            }
+        }
+        "leave" => {
+            if let Err(e) = cli::run_conv_leave(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Conv leave failed");
+            }
+        }
         _ => print_conv_usage(&args[0]),
     }
 }
 
 /// Handle thread subcommand dispatch
-async fn handle_thread_command(args: &[String]) {
-    if args.len() < 3 {
+async fn handle_thread_command(path: &[String], args: &[String]) {
+    if path.len() < 2 {
         cli::print_thread_usage(&args[0]);
-        std::process::exit(1);
+        cli::exit(1);
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "get" => {
             if args.len() < 5 && !cli::has_flag(args, "--help") && !cli::has_flag(args, "-h") {
                 eprintln!(
                     "Usage: {} thread get <channel> <thread_ts> [--limit=N] [--inclusive] [--raw] [--profile=NAME] [--token-type=bot|user]",
                     args[0]
                 );
-                std::process::exit(1);
+                cli::exit(1);
             }
             if let Err(e) = cli::run_thread_get(args).await {
                 handle_command_error(&e.to_string(), "Thread get failed");
@@ -430,16 +1499,16 @@ async fn handle_thread_command(args: &[String]) {
 }
 
 /// Handle users subcommand dispatch
-async fn handle_users_command(args: &[String]) {
-    if args.len() < 3 {
+async fn handle_users_command(path: &[String], args: &[String]) {
+    if path.len() < 2 {
         print_users_usage(&args[0]);
-        std::process::exit(1);
+        cli::exit(1);
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "info" => {
             if args.len() < 4 {
                 eprintln!("Usage: {} users info <user_id> [--profile=NAME]", args[0]);
-                std::process::exit(1);
+                cli::exit(1);
             }
             if let Err(e) = run_users_info(args).await {
                 handle_command_error(&e.to_string(), "Users info failed");
@@ -455,17 +1524,27 @@ async fn handle_users_command(args: &[String]) {
                 handle_command_error(&e.to_string(), "Users resolve-mentions failed");
             }
         }
+        "tz-report" => {
+            if let Err(e) = run_users_tz_report(args).await {
+                handle_command_error(&e.to_string(), "Users tz-report failed");
+            }
+        }
+        "offboard-report" => {
+            if let Err(e) = run_users_offboard_report(args).await {
+                handle_command_error(&e.to_string(), "Users offboard-report failed");
+            }
+        }
         _ => print_users_usage(&args[0]),
     }
 }
 
 /// Handle msg subcommand dispatch
-async fn handle_msg_command(args: &[String], ctx: &cli::CliContext) {
-    if args.len() < 3 {
+async fn handle_msg_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
         print_msg_usage(&args[0]);
-        std::process::exit(1);
+        cli::exit(1);
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "post" => {
             if let Err(e) = run_msg_post(args, ctx.is_non_interactive()).await {
                 handle_command_error(&e.to_string(), "Msg post failed");
@@ -481,17 +1560,45 @@ async fn handle_msg_command(args: &[String], ctx: &cli::CliContext) {
                 handle_command_error(&e.to_string(), "Msg delete failed");
             }
         }
+        "schedule" => {
+            if let Err(e) = run_msg_schedule(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Msg schedule failed");
+            }
+        }
+        "inspect" => {
+            if let Err(e) = run_msg_inspect(args).await {
+                handle_command_error(&e.to_string(), "Msg inspect failed");
+            }
+        }
+        "bulk" => {
+            if let Err(e) = run_msg_bulk(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Msg bulk failed");
+            }
+        }
+        "scheduled" => match path.get(2).map(String::as_str) {
+            Some("list") => {
+                if let Err(e) = run_msg_scheduled_list(args).await {
+                    handle_command_error(&e.to_string(), "Msg scheduled list failed");
+                }
+            }
+            Some("delete") => {
+                if let Err(e) = run_msg_scheduled_delete(args, ctx.is_non_interactive()).await {
+                    handle_command_error(&e.to_string(), "Msg scheduled delete failed");
+                }
+            }
+            _ => print_msg_usage(&args[0]),
+        },
         _ => print_msg_usage(&args[0]),
     }
 }
 
 /// Handle react subcommand dispatch
-async fn handle_react_command(args: &[String], ctx: &cli::CliContext) {
-    if args.len() < 3 {
+async fn handle_react_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
         print_react_usage(&args[0]);
-        std::process::exit(1);
+        cli::exit(1);
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "add" => {
             if let Err(e) = run_react_add(args, ctx.is_non_interactive()).await {
                 handle_command_error(&e.to_string(), "React add failed");
@@ -506,13 +1613,150 @@ async fn handle_react_command(args: &[String], ctx: &cli::CliContext) {
     }
 }
 
+/// Handle pin subcommand dispatch
+async fn handle_pin_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
+        print_pin_usage(&args[0]);
+        cli::exit(1);
+    }
+    match subcommand(path, 1) {
+        "add" => {
+            if let Err(e) = run_pin_add(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Pin add failed");
+            }
+        }
+        "remove" => {
+            if let Err(e) = run_pin_remove(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Pin remove failed");
+            }
+        }
+        "list" => {
+            if let Err(e) = run_pin_list(args).await {
+                handle_command_error(&e.to_string(), "Pin list failed");
+            }
+        }
+        _ => print_pin_usage(&args[0]),
+    }
+}
+
+/// Handle usergroup subcommand dispatch
+async fn handle_usergroup_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
+        print_usergroup_usage(&args[0]);
+        cli::exit(1);
+    }
+    match subcommand(path, 1) {
+        "list" => {
+            if let Err(e) = run_usergroup_list(args).await {
+                handle_command_error(&e.to_string(), "Usergroup list failed");
+            }
+        }
+        "create" => {
+            if let Err(e) = run_usergroup_create(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Usergroup create failed");
+            }
+        }
+        "update" => {
+            if let Err(e) = run_usergroup_update(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Usergroup update failed");
+            }
+        }
+        "users" if path.get(2).map(String::as_str) == Some("update") => {
+            if let Err(e) = run_usergroup_users_update(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e.to_string(), "Usergroup users update failed");
+            }
+        }
+        "users" => {
+            if let Err(e) = run_usergroup_users(args).await {
+                handle_command_error(&e.to_string(), "Usergroup users failed");
+            }
+        }
+        _ => print_usergroup_usage(&args[0]),
+    }
+}
+
+/// Handle bookmark subcommand dispatch
+async fn handle_bookmark_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
+        print_bookmark_usage(&args[0]);
+        cli::exit(1);
+    }
+    let non_interactive = ctx.is_non_interactive();
+    let result = match subcommand(path, 1) {
+        "list" => run_bookmark_list(args).await,
+        "add" => run_bookmark_add(args, non_interactive).await,
+        "remove" => run_bookmark_remove(args, non_interactive).await,
+        "apply" => run_bookmark_apply(args, non_interactive).await,
+        _ => {
+            print_bookmark_usage(&args[0]);
+            return;
+        }
+    };
+    if let Err(e) = result {
+        handle_command_error(&e, &format!("Bookmark {} failed", subcommand(path, 1)));
+    }
+}
+
+/// Handle remind subcommand dispatch
+async fn handle_remind_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
+        print_remind_usage(&args[0]);
+        cli::exit(1);
+    }
+    let non_interactive = ctx.is_non_interactive();
+    let result = match subcommand(path, 1) {
+        "add" => run_remind_add(args, non_interactive).await,
+        "list" => run_remind_list(args).await,
+        "complete" => run_remind_complete(args, non_interactive).await,
+        "delete" => run_remind_delete(args, non_interactive).await,
+        _ => {
+            print_remind_usage(&args[0]);
+            return;
+        }
+    };
+    if let Err(e) = result {
+        handle_command_error(&e, &format!("Remind {} failed", subcommand(path, 1)));
+    }
+}
+
+/// Handle dnd and presence subcommand dispatch
+async fn handle_dnd_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    let print_usage = || {
+        if path[0] == "dnd" {
+            print_dnd_usage(&args[0]);
+        } else {
+            print_presence_usage(&args[0]);
+        }
+    };
+    if path.len() < 2 {
+        print_usage();
+        cli::exit(1);
+    }
+    let non_interactive = ctx.is_non_interactive();
+    let result = match (subcommand(path, 0), subcommand(path, 1)) {
+        ("dnd", "info") => run_dnd_info(args).await,
+        ("dnd", "set") => run_dnd_set(args, non_interactive).await,
+        ("dnd", "end") => run_dnd_end(args, non_interactive).await,
+        ("presence", "get") => run_presence_get(args).await,
+        ("presence", "set") => run_presence_set(args, non_interactive).await,
+        _ => {
+            print_usage();
+            return;
+        }
+    };
+    if let Err(e) = result {
+        let label = if path[0] == "dnd" { "DND" } else { "Presence" };
+        handle_command_error(&e, &format!("{} {} failed", label, subcommand(path, 1)));
+    }
+}
+
 /// Handle file subcommand dispatch
-async fn handle_file_command(args: &[String], ctx: &cli::CliContext) {
-    if args.len() < 3 {
+async fn handle_file_command(path: &[String], args: &[String], ctx: &cli::CliContext) {
+    if path.len() < 2 {
         print_file_usage(&args[0]);
-        std::process::exit(1);
+        cli::exit(1);
     }
-    match args[2].as_str() {
+    match subcommand(path, 1) {
         "upload" => {
             if let Err(e) = run_file_upload(args, ctx.is_non_interactive()).await {
                 handle_command_error(&e.to_string(), "File upload failed");
@@ -523,6 +1767,16 @@ async fn handle_file_command(args: &[String], ctx: &cli::CliContext) {
                 handle_command_error(&e.to_string(), "File download failed");
             }
         }
+        "list" => {
+            if let Err(e) = cli::run_file_list(args).await {
+                handle_command_error(&e, "File list failed");
+            }
+        }
+        "delete" => {
+            if let Err(e) = cli::run_file_delete(args, ctx.is_non_interactive()).await {
+                handle_command_error(&e, "File delete failed");
+            }
+        }
         _ => print_file_usage(&args[0]),
     }
 }
@@ -545,51 +1799,40 @@ fn print_help() {
     println!("    --non-interactive              Run without interactive prompts (auto-enabled when stdin is not a TTY)");
     println!("    --debug                        Show debug information (profile, token type, API method)");
     println!("    --trace                        Show verbose trace information");
-    println!();
-    println!("COMMANDS:");
-    println!("    api call <method> [params...]    Call a Slack API method");
-    println!("    auth login [profile_name]        Authenticate with Slack");
-    println!("    auth status [profile_name]       Show profile status");
-    println!("    auth list                        List all profiles");
-    println!("    auth rename <old> <new>          Rename a profile");
-    println!("    auth logout [profile_name]       Remove authentication");
-    println!("    config oauth set <profile>       Set OAuth configuration for a profile");
-    println!("    config oauth show <profile>      Show OAuth configuration for a profile");
-    println!("    config oauth delete <profile>    Delete OAuth configuration for a profile");
-    println!("    config set <profile> --token-type <type>  Set default token type (bot/user)");
-    println!("    search <query>                   Search messages");
-    println!("    conv list                        List conversations (supports --filter, --format, --sort)");
-    println!("    conv search <pattern>            Search conversations by name");
-    println!("    conv select                      Interactively select a conversation");
-    println!(
-        "    conv history <channel>           Get conversation history (supports --interactive)"
-    );
+    println!("    --lang <en|ja>                 Language for messages, warnings and usage text");
+    println!("    --report-usage                 Print API calls per method and rate-limit tier when done");
+    println!("    --explain                      Show effective output settings and where they come from");
     println!(
-        "    thread get <channel> <thread_ts> Get thread messages (supports --limit, --inclusive)"
+        "    --progress json                Emit JSON progress lines on stderr for long operations"
     );
-    println!("    users info <user_id>             Get user information");
-    println!("    users cache-update               Update user cache for mention resolution");
-    println!("    users resolve-mentions <text>    Resolve user mentions in text");
-    println!("    msg post <channel> <text>        Post a message (requires SLACKCLI_ALLOW_WRITE=true, supports --thread-ts, --reply-broadcast, and --idempotency-key)");
-    println!("    msg update <channel> <ts> <text> Update a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)");
-    println!("    msg delete <channel> <ts>        Delete a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)");
+    println!("    --format <fmt>                 json (default), jsonl, table, tsv, csv or yaml for search/conv/users/msg/file output");
+    println!("    --columns <a,b.c>              Columns for table/tsv/csv (dotted paths into each record)");
+    println!("    --config-dir <dir>             Keep profiles, tokens and caches in <dir> (env: SLACKRS_CONFIG_DIR)");
+    println!("    --ephemeral                    Use a temporary config directory, deleted when the command exits");
+    println!("    --max-retries <n>              Retries for rate-limited (HTTP 429) requests (default: 3)");
     println!(
-        "    react add <channel> <ts> <emoji> Add a reaction (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)"
+        "    --max-writes <n>               Refuse write API calls beyond n in this invocation"
     );
-    println!("    react remove <channel> <ts> <emoji> Remove a reaction (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)");
-    println!("    file upload <path>               Upload a file (external upload method, supports --idempotency-key)");
+    println!("    --max-writes-per-day <n>       Refuse write API calls beyond n per UTC day (counted locally)");
+    println!("    --gha                          GitHub Actions mode: ::error::/::notice:: annotations and $GITHUB_OUTPUT results");
+    println!("    --query <expr>, --jq <expr>    Print only the values a jq-style path extracts from the JSON output");
+    println!("    --results-dir <dir>            Also save each JSON envelope in <dir> with an index (env: SLACKRS_RESULTS_DIR)");
     println!(
-        "    file download [<file_id>]        Download a file from Slack (supports --url, --out)"
+        "    --results-keep <n>             Result files kept in --results-dir (default: 100)"
     );
-    println!("    doctor [--profile=NAME] [--json] Show diagnostic information");
-    println!("    install-skills [source] [--global] Install agent skill (default: self)");
-    println!("    demo                             Run demonstration");
+    println!();
+    println!("COMMANDS:");
+    for (synopsis, key) in USAGE_COMMANDS {
+        println!("    {:<32} {}", synopsis, i18n::t(key));
+    }
     println!();
     println!("API CALL OPTIONS:");
     println!("    <method>                         Slack API method (e.g., chat.postMessage)");
     println!("    key=value                        Request parameters");
     println!("    --json                           Send as JSON body (default: form-urlencoded)");
     println!("    --get                            Use GET method (default: POST)");
+    println!("    --get-url                        Print the resolved request without sending it");
+    println!("    --all, --paginate                Follow next_cursor and merge all result pages");
     println!(
         "    --raw                            Output raw Slack API response (without envelope)"
     );
@@ -603,6 +1846,7 @@ fn print_help() {
     println!("    SLACKCLI_ALLOW_WRITE=true|false  Control write operations (default: true)");
     println!("    SLACK_PROFILE=<name>           Select profile (default: default)");
     println!("    SLACK_TOKEN=<token>            Override token from store");
+    println!("    SLACKRS_LANG=en|ja             Set message language (default: from LANG)");
     println!();
     println!("EXAMPLES:");
     println!("    # Profile selection");
@@ -618,52 +1862,132 @@ fn print_help() {
     println!("    SLACKRS_OUTPUT=raw slack-rs conv list  # Raw output without envelope");
 }
 
+/// Top-level command synopses with their message catalog keys
+const USAGE_COMMANDS: &[(&str, &str)] = &[
+    ("api call <method> [params...]", "usage.api_call"),
+    ("auth login [profile_name]", "usage.auth_login"),
+    ("auth status [profile_name]", "usage.auth_status"),
+    ("auth list [--json]", "usage.auth_list"),
+    ("auth compare-tokens [profile]", "usage.auth_compare_tokens"),
+    ("auth test [--token-type=TYPE]", "usage.auth_test"),
+    ("whoami [--json]", "usage.whoami"),
+    ("auth rename <old> <new>", "usage.auth_rename"),
+    ("auth logout [profile_name]", "usage.auth_logout"),
+    ("auth set-token [profile]", "usage.auth_set_token"),
+    ("auth tokens list [--json]", "usage.auth_tokens_list"),
+    ("auth export [options]", "usage.auth_export"),
+    ("auth import [options]", "usage.auth_import"),
+    ("config oauth set <profile>", "usage.config_oauth_set"),
+    ("config oauth show <profile>", "usage.config_oauth_show"),
+    ("config oauth delete <profile>", "usage.config_oauth_delete"),
+    (
+        "config set <profile> --token-type <type>",
+        "usage.config_set",
+    ),
+    (
+        "config token-backend <file|encrypted-file>",
+        "usage.config_token_backend",
+    ),
+    ("search <query>", "usage.search"),
+    ("search files <query>", "usage.search_files"),
+    ("search all <query>", "usage.search_all"),
+    ("conv list", "usage.conv_list"),
+    ("conv search <pattern>", "usage.conv_search"),
+    ("conv select", "usage.conv_select"),
+    ("conv history <channel>", "usage.conv_history"),
+    ("conv replies <channel> <ts>", "usage.conv_replies"),
+    ("conv members <channel>", "usage.conv_members"),
+    ("conv export <channel>", "usage.conv_export"),
+    ("conv tail <channel>", "usage.conv_tail"),
+    ("conv watch <channel>", "usage.conv_watch"),
+    ("conv create <name>", "usage.conv_create"),
+    ("conv archive|unarchive <channel>", "usage.conv_archive"),
+    ("conv rename <channel> <name>", "usage.conv_rename"),
+    ("conv invite <channel> <user>...", "usage.conv_invite"),
+    ("conv kick <channel> <user>", "usage.conv_kick"),
+    ("conv join|leave <channel>", "usage.conv_join"),
+    ("thread get <channel> <thread_ts>", "usage.thread_get"),
+    ("users info <user_id>", "usage.users_info"),
+    ("users cache-update", "usage.users_cache_update"),
+    (
+        "users resolve-mentions <text>",
+        "usage.users_resolve_mentions",
+    ),
+    (
+        "users offboard-report <user_id>",
+        "usage.users_offboard_report",
+    ),
+    ("msg post <channel> <text>", "usage.msg_post"),
+    ("msg update <channel> <ts> <text>", "usage.msg_update"),
+    ("msg delete <channel> <ts>", "usage.msg_delete"),
+    ("msg schedule <channel> <text>", "usage.msg_schedule"),
+    ("msg scheduled list", "usage.msg_scheduled_list"),
+    (
+        "msg scheduled delete <channel> <id>",
+        "usage.msg_scheduled_delete",
+    ),
+    ("msg bulk --input=<file>", "usage.msg_bulk"),
+    ("msg inspect <permalink>", "usage.msg_inspect"),
+    ("react add <channel> <ts> <emoji>", "usage.react_add"),
+    ("react remove <channel> <ts> <emoji>", "usage.react_remove"),
+    ("pin add <channel> <ts>", "usage.pin_add"),
+    ("pin remove <channel> <ts>", "usage.pin_remove"),
+    ("pin list <channel>", "usage.pin_list"),
+    ("bookmark list <channel>", "usage.bookmark_list"),
+    (
+        "bookmark add <channel> --title --link",
+        "usage.bookmark_add",
+    ),
+    ("bookmark remove <channel> <id>", "usage.bookmark_remove"),
+    (
+        "bookmark apply <channel> --spec=<file>",
+        "usage.bookmark_apply",
+    ),
+    ("emoji list", "usage.emoji_list"),
+    ("usergroup list", "usage.usergroup_list"),
+    ("usergroup create|update <...>", "usage.usergroup_create"),
+    ("usergroup users <usergroup>", "usage.usergroup_users"),
+    (
+        "usergroup users update <usergroup>",
+        "usage.usergroup_users_update",
+    ),
+    ("remind add <text> --time=WHEN", "usage.remind_add"),
+    ("remind list", "usage.remind_list"),
+    ("remind complete <id>", "usage.remind_complete"),
+    ("remind delete <id>", "usage.remind_delete"),
+    ("dnd info|set <minutes>|end", "usage.dnd"),
+    ("presence get|set <auto|away>", "usage.presence"),
+    ("file upload <path|->...", "usage.file_upload"),
+    ("file download [<file_id>]", "usage.file_download"),
+    ("file list", "usage.file_list"),
+    ("file delete <file_id>...", "usage.file_delete"),
+    ("connect <subcommand>", "usage.connect"),
+    ("events listen", "usage.events_listen"),
+    ("kv set|get|list", "usage.kv"),
+    ("idempotency list|show|clear|gc", "usage.idempotency"),
+    ("ctx set|show|clear", "usage.ctx"),
+    ("shell", "usage.shell"),
+    ("completions <shell>", "usage.completions"),
+    ("doctor [options]", "usage.doctor"),
+    ("usage [--days=N] [--json]", "usage.usage"),
+    ("maintenance run [--dry-run]", "usage.maintenance_run"),
+    ("install-skills [source] [--global]", "usage.install_skills"),
+    ("demo", "usage.demo"),
+];
+
 fn print_usage() {
-    println!("Slack CLI - Usage:");
-    println!("  [--non-interactive]                Run without interactive prompts (auto when stdin not a TTY)");
-    println!("  api call <method> [params...]  - Call a Slack API method");
-    println!("  auth login [profile_name]      - Authenticate with Slack");
-    println!("  auth status [profile_name]     - Show profile status");
-    println!("  auth list                      - List all profiles");
-    println!("  auth rename <old> <new>        - Rename a profile");
-    println!("  auth logout [profile_name]     - Remove authentication");
-    println!("  auth export [options]          - Export profiles to encrypted file");
-    println!("  auth import [options]          - Import profiles from encrypted file");
-    println!("  config oauth set <profile>     - Set OAuth configuration for a profile");
-    println!("  config oauth show <profile>    - Show OAuth configuration for a profile");
-    println!("  config oauth delete <profile>  - Delete OAuth configuration for a profile");
-    println!("  config set <profile> --token-type <type> - Set default token type (bot/user)");
-    println!("  search <query>                 - Search messages (supports --count, --page, --sort, --sort_dir)");
-    println!("  conv list                      - List conversations (supports --filter, --format, --sort)");
-    println!("  conv search <pattern>          - Search conversations by name (supports --select)");
-    println!("  conv select                    - Interactively select a conversation");
-    println!(
-        "  conv history <channel>         - Get conversation history (supports --interactive)"
-    );
+    println!("{}", i18n::t("usage.title"));
     println!(
-        "  thread get <channel> <thread_ts> - Get thread messages (supports --limit, --inclusive)"
+        "  {:<31}{}",
+        "[--non-interactive]",
+        i18n::t("usage.non_interactive")
     );
-    println!("  users info <user_id>           - Get user information");
-    println!("  users cache-update             - Update user cache for mention resolution (supports --profile, --force)");
-    println!("  users resolve-mentions <text>  - Resolve user mentions in text (supports --profile, --format)");
-    println!("  msg post <channel> <text>      - Post a message (requires SLACKCLI_ALLOW_WRITE=true, supports --thread-ts, --reply-broadcast, and --idempotency-key)");
-    println!("  msg update <channel> <ts> <text> - Update a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)");
-    println!(
-        "  msg delete <channel> <ts>      - Delete a message (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)"
-    );
-    println!(
-        "  react add <channel> <ts> <emoji> - Add a reaction (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)"
-    );
-    println!("  react remove <channel> <ts> <emoji> - Remove a reaction (requires SLACKCLI_ALLOW_WRITE=true, supports --idempotency-key)");
-    println!("  file upload <path>             - Upload a file using external upload method (supports --idempotency-key)");
-    println!(
-        "  file download [<file_id>]      - Download a file from Slack (supports --url, --out)"
-    );
-    println!("  doctor [options]               - Show diagnostic information (supports --profile, --json)");
-    println!("  install-skills [source] [--global] - Install agent skill (default: self, supports local:<path>)");
-    println!("  demo                           - Run demonstration");
-    println!("  --help, -h                     - Show help");
-    println!("  --version, -v                  - Show version");
+    println!("  {:<31}{}", "[--lang <en|ja>]", i18n::t("usage.lang"));
+    for (synopsis, key) in USAGE_COMMANDS {
+        println!("  {:<30} - {}", synopsis, i18n::t(key));
+    }
+    println!("  {:<30} - {}", "--help, -h", i18n::t("usage.help"));
+    println!("  {:<30} - {}", "--version, -v", i18n::t("usage.version"));
 }
 
 fn print_api_usage() {
@@ -676,6 +2000,8 @@ fn print_api_usage() {
     println!("    --json                       Send as JSON body (default: form-urlencoded)");
     println!("    --get                        Use GET method (default: POST)");
     println!("    --raw                        Output raw Slack API response (without envelope)");
+    println!("    --get-url                    Print the resolved request (token redacted) without sending it");
+    println!("    --stdin                      Read parameters from a JSON object on stdin (JSONL: one call per line)");
     println!("    --debug                      Show debug information");
     println!("    --trace                      Show verbose trace information");
     println!();
@@ -687,15 +2013,22 @@ fn print_api_usage() {
     println!("    slack-rs api call users.info user=U123456 --get");
     println!("    slack-rs api call chat.postMessage channel=C123 text=Hello --debug");
     println!("    SLACKRS_OUTPUT=raw slack-rs api call conversations.list");
+    println!("    jq -c '.[]' rows.json | slack-rs api call chat.postMessage --stdin");
 }
 
 fn print_auth_usage() {
     println!("Auth command usage:");
     println!("  auth login [profile_name] [options] - Authenticate with Slack");
     println!("  auth status [profile_name]          - Show profile status");
-    println!("  auth list                           - List all profiles");
+    println!("  auth list [--json]                  - List all profiles");
+    println!("  auth compare-tokens [profile] [--json] - Compare what bot and user tokens can see");
+    println!(
+        "  auth test [--token-type=bot|user|app] [--json] - Show token identity and granted scopes"
+    );
     println!("  auth rename <old> <new>             - Rename a profile");
     println!("  auth logout [profile_name]          - Remove authentication");
+    println!("  auth set-token [profile] [--token-type=app|bot|user] - Store a token read from stdin (default: app)");
+    println!("  auth tokens list [--json]           - List token store keys (values redacted)");
     println!("  auth export [options]               - Export profiles to encrypted file");
     println!("  auth import [options]               - Import profiles from encrypted file");
     println!();
@@ -703,6 +2036,7 @@ fn print_auth_usage() {
     println!("  --client-id <id>                    - OAuth client ID (optional)");
     println!("  --bot-scopes <scopes>               - Bot scopes (comma-separated or 'all')");
     println!("  --user-scopes <scopes>              - User scopes (comma-separated or 'all')");
+    println!("  --scopes-from-manifest <file>       - Bot and user scopes from an app manifest (YAML/JSON)");
     println!("  --cloudflared [path]                - Use cloudflared tunnel for redirect URI");
     println!("                                        (path optional, defaults to 'cloudflared' in PATH)");
     println!("  --ngrok [path]                      - Use ngrok tunnel for redirect URI");
@@ -763,6 +2097,44 @@ fn print_config_usage(prog: &str) {
         "  {} config set <profile> --token-type <type>  - Set default token type (bot/user)",
         prog
     );
+    println!(
+        "  {} config set <profile|--global> output.envelope=<true|false>  - Default envelope/raw output",
+        prog
+    );
+    println!(
+        "  {} config set <profile|--global> output.format=<json|jsonl|table|tsv>  - Default list format",
+        prog
+    );
+    println!(
+        "  {} config set <profile|--global> output.user_name=<display_name|real_name|username>  - How user names are shown",
+        prog
+    );
+    println!(
+        "  {} config set <profile|--global> output.time_format=<24h|12h>  - Clock style in text output",
+        prog
+    );
+    println!(
+        "  {} config set <profile|--global> output.locale=<locale>  - Message language (e.g. ja-JP)",
+        prog
+    );
+    println!(
+        "  {} config set <profile|--global> output.include_locale=<true|false>  - Request user locale from users.info",
+        prog
+    );
+    println!(
+        "  {} config set <profile|--global> output.post_footer=<template>  - Footer for msg post/schedule/bulk ({{hostname}}, {{job}}, {{profile}}, {{date}})",
+        prog
+    );
+    println!(
+        "      Flags (--raw, --format, --lang) and SLACKRS_OUTPUT/SLACKRS_LANG take precedence; an empty value unsets"
+    );
+    println!(
+        "  {} config token-backend <file|encrypted-file>  - Migrate stored tokens to another backend",
+        prog
+    );
+    println!(
+        "      encrypted-file is unlocked with SLACK_RS_TOKEN_PASSPHRASE or an interactive prompt"
+    );
 }
 
 fn print_config_oauth_usage(prog: &str) {
@@ -925,6 +2297,8 @@ fn run_config_oauth_delete(args: &[String]) -> Result<(), String> {
 fn run_config_set(args: &[String]) -> Result<(), String> {
     let mut profile_name: Option<String> = None;
     let mut token_type: Option<profile::TokenType> = None;
+    let mut global = false;
+    let mut settings: Vec<(String, String)> = Vec::new();
 
     let mut i = 0;
     while i < args.len() {
@@ -942,11 +2316,16 @@ fn run_config_set(args: &[String]) -> Result<(), String> {
                         return Err("--token-type requires a value".to_string());
                     }
                 }
+                "--global" => {
+                    global = true;
+                }
                 _ => {
                     return Err(format!("Unknown option: {}", args[i]));
                 }
             }
-        } else if profile_name.is_none() {
+        } else if let Some((key, value)) = args[i].split_once('=') {
+            settings.push((key.trim().to_string(), value.to_string()));
+        } else if profile_name.is_none() && !global {
             profile_name = Some(args[i].clone());
         } else {
             return Err(format!("Unexpected argument: {}", args[i]));
@@ -954,10 +2333,32 @@ fn run_config_set(args: &[String]) -> Result<(), String> {
         i += 1;
     }
 
+    if global {
+        if token_type.is_some() {
+            return Err("--token-type cannot be set globally".to_string());
+        }
+        if settings.is_empty() {
+            return Err("Usage: config set --global output.<key>=<value>".to_string());
+        }
+        for (key, value) in settings {
+            commands::set_output_setting(None, &key, &value).map_err(|e| e.to_string())?;
+        }
+        return Ok(());
+    }
+
     let profile = profile_name.ok_or_else(|| "Profile name is required".to_string())?;
-    let ttype = token_type.ok_or_else(|| "--token-type is required".to_string())?;
+    if token_type.is_none() && settings.is_empty() {
+        return Err("--token-type or output.<key>=<value> is required".to_string());
+    }
 
-    commands::set_default_token_type(profile, ttype).map_err(|e| e.to_string())
+    if let Some(ttype) = token_type {
+        commands::set_default_token_type(profile.clone(), ttype).map_err(|e| e.to_string())?;
+    }
+    for (key, value) in settings {
+        commands::set_output_setting(Some(profile.clone()), &key, &value)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
 }
 
 /// Demonstrates the profile storage functionality
@@ -1067,6 +2468,8 @@ fn example_profile_management() {
         bot_scopes: None,
         user_scopes: None,
         default_token_type: None,
+        output: None,
+        last_auth_test_at: None,
     };
 
     // Use add() to prevent duplicates
@@ -1102,6 +2505,8 @@ fn demonstrate_profile_persistence() {
         bot_scopes: None,
         user_scopes: None,
         default_token_type: None,
+        output: None,
+        last_auth_test_at: None,
     };
 
     let profile2 = Profile {
@@ -1115,6 +2520,8 @@ fn demonstrate_profile_persistence() {
         bot_scopes: None,
         user_scopes: None,
         default_token_type: None,
+        output: None,
+        last_auth_test_at: None,
     };
 
     // Demonstrate add() - should succeed for new profile
@@ -1141,6 +2548,8 @@ fn demonstrate_profile_persistence() {
         bot_scopes: None,
         user_scopes: None,
         default_token_type: None,
+        output: None,
+        last_auth_test_at: None,
     };
     match config.set_or_update("personal".to_string(), updated_profile2) {
         Ok(_) => println!("Updated 'personal' profile using set_or_update()"),
@@ -1195,3 +2604,54 @@ fn demonstrate_profile_persistence() {
 
     println!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_take_config_dir_flags_before_subcommand() {
+        let (rest, dir, ephemeral) =
+            take_config_dir_flags(args("slack-rs --profile p --config-dir /d conv list")).unwrap();
+        assert_eq!(rest, args("slack-rs --profile p conv list"));
+        assert_eq!(dir.as_deref(), Some("/d"));
+        assert!(!ephemeral);
+
+        let (rest, dir, ephemeral) =
+            take_config_dir_flags(args("slack-rs --ephemeral msg post C1 hi")).unwrap();
+        assert_eq!(rest, args("slack-rs msg post C1 hi"));
+        assert_eq!(dir, None);
+        assert!(ephemeral);
+    }
+
+    #[test]
+    fn test_take_config_dir_flags_keeps_literal_values() {
+        // After `--` the values are message text, not flags
+        let line = args("slack-rs msg post C1 -- --ephemeral --config-dir /d");
+        let (rest, dir, ephemeral) = take_config_dir_flags(line.clone()).unwrap();
+        assert_eq!(rest, line);
+        assert_eq!(dir, None);
+        assert!(!ephemeral);
+
+        let line = args("slack-rs --allow-write -- --ephemeral");
+        let (rest, _, ephemeral) = take_config_dir_flags(line.clone()).unwrap();
+        assert_eq!(rest, line);
+        assert!(!ephemeral);
+    }
+
+    #[test]
+    fn test_take_config_dir_flags_rejects_misplaced_flag() {
+        let err = take_config_dir_flags(args("slack-rs msg post C1 hi --ephemeral")).unwrap_err();
+        assert!(
+            err.contains("--ephemeral must come before the command"),
+            "{}",
+            err
+        );
+        let err = take_config_dir_flags(args("slack-rs conv list --config-dir=/d")).unwrap_err();
+        assert!(err.contains("--config-dir must come before"), "{}", err);
+    }
+}
@@ -6,21 +6,27 @@
 //! - Authorization URL generation
 //! - Token exchange with oauth.v2.access
 //! - Local callback server for receiving authorization codes
+//! - Callback reachability preflight before the browser is opened
 //! - Callback port resolution from environment variables
 //! - OAuth scope presets and expansion utilities
+//! - Refresh of rotated (expiring) access tokens
 
 pub mod pkce;
 pub mod port;
+pub mod preflight;
+pub mod refresh;
 pub mod scopes;
 pub mod server;
 pub mod types;
 
 pub use pkce::{generate_pkce, generate_state};
 pub use port::resolve_callback_port;
+pub use preflight::preflight_callback;
+pub use refresh::{refresh_access_token, refresh_if_expired, TokenRotation};
 pub use scopes::{
     all_scopes, bot_all_scopes, expand_scopes, expand_scopes_with_context, user_all_scopes,
 };
-pub use server::run_callback_server;
+pub use server::{bind_callback_listener, run_callback_server, serve_callback};
 pub use types::{OAuthConfig, OAuthError, OAuthResponse};
 
 use crate::debug;
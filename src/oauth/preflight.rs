@@ -0,0 +1,302 @@
+//! OAuth callback preflight
+//!
+//! Before the browser is opened, the callback server is requested through
+//! the advertised redirect URI, so a busy port, a tunnel that is not up yet
+//! or a hostname that does not resolve is reported in the terminal instead
+//! of as a browser error after the user has approved the app.
+//!
+//! Loopback redirect URIs are requested directly; other hosts (tunnels) go
+//! through the proxy configured in `HTTPS_PROXY`/`HTTP_PROXY`, like the
+//! browser would. Set `SLACK_OAUTH_SKIP_PREFLIGHT=1` to skip the check.
+
+use super::pkce::generate_state;
+use super::server::PREFLIGHT_PARAM;
+use super::types::OAuthError;
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// Environment variable that disables the preflight
+pub const SKIP_PREFLIGHT_ENV: &str = "SLACK_OAUTH_SKIP_PREFLIGHT";
+
+/// Time allowed for a loopback redirect URI
+pub const LOOPBACK_BUDGET: Duration = Duration::from_secs(3);
+
+/// Time allowed for a tunnel to become reachable
+pub const TUNNEL_BUDGET: Duration = Duration::from_secs(30);
+
+/// Why the callback server could not be reached
+#[derive(Debug, Clone, PartialEq)]
+pub enum PreflightFailure {
+    /// The redirect URI cannot be parsed or has no host
+    InvalidRedirectUri(String),
+    /// A loopback redirect URI points at another port than the server
+    PortMismatch { redirect_port: u16, local_port: u16 },
+    /// The host name does not resolve
+    Dns { host: String, detail: String },
+    /// Nothing accepted the connection, or it timed out
+    Unreachable { detail: String },
+    /// Something answered, but not the callback server
+    UnexpectedResponse { status: u16 },
+}
+
+impl PreflightFailure {
+    /// Whether the failure may go away while a tunnel comes up
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            PreflightFailure::Dns { .. }
+                | PreflightFailure::Unreachable { .. }
+                | PreflightFailure::UnexpectedResponse { .. }
+        )
+    }
+
+    /// Diagnostic message with a likely cause and what to do
+    pub fn diagnose(&self, redirect_uri: &str, tunneled: bool) -> String {
+        let (what, hint) = match self {
+            PreflightFailure::InvalidRedirectUri(detail) => (
+                format!("the redirect URI is invalid ({})", detail),
+                "Use a full URL such as http://127.0.0.1:8765/callback".to_string(),
+            ),
+            PreflightFailure::PortMismatch {
+                redirect_port,
+                local_port,
+            } => (
+                format!(
+                    "the redirect URI uses port {} but the callback server listens on {}",
+                    redirect_port, local_port
+                ),
+                format!(
+                    "Set {}={} or change the redirect URI (also in the Slack app settings)",
+                    super::port::OAUTH_PORT_ENV,
+                    redirect_port
+                ),
+            ),
+            PreflightFailure::Dns { host, detail } => (
+                format!("'{}' does not resolve ({})", host, detail),
+                if tunneled {
+                    "The tunnel hostname is not propagated yet; wait a moment and retry, or restart the tunnel".to_string()
+                } else {
+                    "Check the host name of the redirect URI".to_string()
+                },
+            ),
+            PreflightFailure::Unreachable { detail } => (
+                format!("no connection ({})", detail),
+                if tunneled {
+                    "The tunnel is not established or points elsewhere; check that it forwards to the callback port".to_string()
+                } else {
+                    "Check that nothing blocks local connections (firewall, proxy settings, NO_PROXY)".to_string()
+                },
+            ),
+            PreflightFailure::UnexpectedResponse { status } => (
+                format!(
+                    "HTTP {} from something other than the callback server",
+                    status
+                ),
+                if tunneled {
+                    "The tunnel answers but does not reach the callback server (502/530 usually means it is still connecting or forwards to the wrong port)".to_string()
+                } else {
+                    "Another program answers on this address; free the port or set a different one"
+                        .to_string()
+                },
+            ),
+        };
+        format!(
+            "Callback preflight failed for {}: {}\n  Hint: {}\n  (set {}=1 to skip this check)",
+            redirect_uri, what, hint, SKIP_PREFLIGHT_ENV
+        )
+    }
+}
+
+/// Whether the preflight is disabled through the environment
+pub fn preflight_skipped() -> bool {
+    std::env::var(SKIP_PREFLIGHT_ENV)
+        .map(|v| matches!(v.trim(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Whether a host name refers to this machine
+fn is_loopback(host: &str) -> bool {
+    host == "localhost"
+        || host
+            .trim_matches(|c| c == '[' || c == ']')
+            .parse::<std::net::IpAddr>()
+            .map(|ip| ip.is_loopback())
+            .unwrap_or(false)
+}
+
+/// Request the callback server once through `redirect_uri`
+async fn probe(url: &Url, loopback: bool) -> Result<(), PreflightFailure> {
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_matches(|c| c == '[' || c == ']')
+        .to_string();
+    let port = url.port_or_known_default().unwrap_or(80);
+    let lookup = tokio::net::lookup_host((host.as_str(), port))
+        .await
+        .map(|_| ());
+    if let Err(e) = lookup {
+        return Err(PreflightFailure::Dns {
+            host,
+            detail: e.to_string(),
+        });
+    }
+
+    let nonce = generate_state();
+    let mut probe_url = url.clone();
+    probe_url
+        .query_pairs_mut()
+        .append_pair(PREFLIGHT_PARAM, &nonce);
+
+    let mut builder = reqwest::Client::builder().timeout(Duration::from_secs(5));
+    if loopback {
+        builder = builder.no_proxy();
+    }
+    let client = builder.build().map_err(|e| PreflightFailure::Unreachable {
+        detail: e.to_string(),
+    })?;
+    let response =
+        client
+            .get(probe_url)
+            .send()
+            .await
+            .map_err(|e| PreflightFailure::Unreachable {
+                detail: if e.is_timeout() {
+                    "timed out".to_string()
+                } else if e.is_connect() {
+                    "connection refused or reset".to_string()
+                } else {
+                    e.to_string()
+                },
+            })?;
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    if status == 200 && body.trim() == format!("slack-rs-callback:{}", nonce) {
+        Ok(())
+    } else {
+        Err(PreflightFailure::UnexpectedResponse { status })
+    }
+}
+
+/// Check that the callback server is reachable through `redirect_uri`
+///
+/// Transient failures (DNS, connection, gateway errors) are retried once a
+/// second until `budget` is used up, which covers tunnels that are still
+/// connecting.
+///
+/// # Arguments
+/// * `redirect_uri` - Redirect URI sent to Slack
+/// * `local_port` - Port the callback server listens on
+/// * `budget` - Total time to keep retrying
+pub async fn check_callback(
+    redirect_uri: &str,
+    local_port: u16,
+    budget: Duration,
+) -> Result<(), PreflightFailure> {
+    let url = Url::parse(redirect_uri)
+        .map_err(|e| PreflightFailure::InvalidRedirectUri(e.to_string()))?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| PreflightFailure::InvalidRedirectUri("no host".to_string()))?
+        .to_string();
+    let loopback = is_loopback(&host);
+    if loopback {
+        let redirect_port = url.port_or_known_default().unwrap_or(80);
+        if redirect_port != local_port {
+            return Err(PreflightFailure::PortMismatch {
+                redirect_port,
+                local_port,
+            });
+        }
+    }
+
+    let deadline = Instant::now() + budget;
+    loop {
+        match probe(&url, loopback).await {
+            Ok(()) => return Ok(()),
+            Err(failure) if failure.is_transient() && Instant::now() < deadline => {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            Err(failure) => return Err(failure),
+        }
+    }
+}
+
+/// Run the preflight for a login and turn a failure into an [`OAuthError`]
+///
+/// Does nothing when [`SKIP_PREFLIGHT_ENV`] is set.
+pub async fn preflight_callback(redirect_uri: &str, local_port: u16) -> Result<(), OAuthError> {
+    if preflight_skipped() {
+        return Ok(());
+    }
+    let tunneled = Url::parse(redirect_uri)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| !is_loopback(host)))
+        .unwrap_or(false);
+    let budget = if tunneled {
+        TUNNEL_BUDGET
+    } else {
+        LOOPBACK_BUDGET
+    };
+    check_callback(redirect_uri, local_port, budget)
+        .await
+        .map_err(|failure| OAuthError::ServerError(failure.diagnose(redirect_uri, tunneled)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oauth::server::{bind_callback_listener, serve_callback};
+
+    #[tokio::test]
+    async fn test_check_callback_reaches_server() {
+        let listener = bind_callback_listener(0).await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(serve_callback(listener, "state".to_string(), 5));
+
+        let redirect_uri = format!("http://127.0.0.1:{}/callback", port);
+        check_callback(&redirect_uri, port, Duration::from_secs(2))
+            .await
+            .unwrap();
+        // The probe does not end the wait for the real callback
+        assert!(!server.is_finished());
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_check_callback_failures() {
+        assert_eq!(
+            check_callback("http://localhost:9000/callback", 8765, Duration::ZERO).await,
+            Err(PreflightFailure::PortMismatch {
+                redirect_port: 9000,
+                local_port: 8765
+            })
+        );
+
+        // Bound but not serving: reserve a port, then release it
+        let port = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let failure = check_callback(
+            &format!("http://127.0.0.1:{}/callback", port),
+            port,
+            Duration::ZERO,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(failure, PreflightFailure::Unreachable { .. }));
+        let message = failure.diagnose("http://127.0.0.1/callback", false);
+        assert!(message.contains(SKIP_PREFLIGHT_ENV));
+    }
+
+    #[tokio::test]
+    async fn test_bind_callback_listener_port_busy() {
+        let taken = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = taken.local_addr().unwrap().port();
+        match bind_callback_listener(port).await {
+            Err(OAuthError::ServerError(msg)) => assert!(msg.contains("already in use")),
+            other => panic!("Expected port busy error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
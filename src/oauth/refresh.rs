@@ -0,0 +1,317 @@
+//! Token rotation support
+//!
+//! Apps with token rotation enabled receive short-lived access tokens
+//! (12 hours) together with a `refresh_token`. The refresh token and the
+//! expiry are kept in the token store next to the access token:
+//! - `{token_key}:refresh` - refresh token
+//! - `{token_key}:expires_at` - UNIX time the access token expires
+//!
+//! Before a call, [`refresh_if_expired`] exchanges the refresh token for a
+//! new access token once the current one is within [`REFRESH_MARGIN_SECS`]
+//! of expiring, and persists the rotated pair.
+
+use super::types::{OAuthError, OAuthResponse};
+use crate::debug;
+use crate::profile::TokenStore;
+use reqwest::Client;
+use std::collections::HashMap;
+
+/// Refresh this long before the access token expires
+pub const REFRESH_MARGIN_SECS: u64 = 300;
+
+/// Refresh token and expiry issued with a rotating access token
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenRotation {
+    pub refresh_token: String,
+    /// UNIX time the access token expires, when Slack reported one
+    pub expires_at: Option<u64>,
+}
+
+impl TokenRotation {
+    /// Build from the `refresh_token` / `expires_in` fields of an OAuth response
+    ///
+    /// Returns `None` for non-rotating tokens (no refresh token issued).
+    pub fn from_issued(
+        refresh_token: Option<&String>,
+        expires_in: Option<u64>,
+        now: u64,
+    ) -> Option<Self> {
+        refresh_token.map(|refresh_token| Self {
+            refresh_token: refresh_token.clone(),
+            expires_at: expires_in.map(|secs| now + secs),
+        })
+    }
+
+    /// Whether the access token should be refreshed at `now`
+    pub fn needs_refresh(&self, now: u64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| now + REFRESH_MARGIN_SECS >= expires_at)
+    }
+}
+
+/// Token store key of the refresh token for an access token key
+pub fn make_refresh_token_key(token_key: &str) -> String {
+    format!("{}:refresh", token_key)
+}
+
+/// Token store key of the expiry for an access token key
+pub fn make_token_expiry_key(token_key: &str) -> String {
+    format!("{}:expires_at", token_key)
+}
+
+/// Persist rotation data for an access token key
+pub fn store_rotation(
+    token_store: &dyn TokenStore,
+    token_key: &str,
+    rotation: &TokenRotation,
+) -> Result<(), OAuthError> {
+    let to_config_error =
+        |e: crate::profile::TokenStoreError| OAuthError::ConfigError(e.to_string());
+    token_store
+        .set(&make_refresh_token_key(token_key), &rotation.refresh_token)
+        .map_err(to_config_error)?;
+    match rotation.expires_at {
+        Some(expires_at) => token_store
+            .set(&make_token_expiry_key(token_key), &expires_at.to_string())
+            .map_err(to_config_error),
+        None => {
+            let _ = token_store.delete(&make_token_expiry_key(token_key));
+            Ok(())
+        }
+    }
+}
+
+/// Load rotation data for an access token key (`None` for non-rotating tokens)
+pub fn load_rotation(token_store: &dyn TokenStore, token_key: &str) -> Option<TokenRotation> {
+    let refresh_token = token_store.get(&make_refresh_token_key(token_key)).ok()?;
+    let expires_at = token_store
+        .get(&make_token_expiry_key(token_key))
+        .ok()
+        .and_then(|v| v.parse().ok());
+    Some(TokenRotation {
+        refresh_token,
+        expires_at,
+    })
+}
+
+/// Remove rotation data for an access token key
+pub fn delete_rotation(token_store: &dyn TokenStore, token_key: &str) {
+    let _ = token_store.delete(&make_refresh_token_key(token_key));
+    let _ = token_store.delete(&make_token_expiry_key(token_key));
+}
+
+/// Exchange a refresh token for a new access token via `oauth.v2.access`
+///
+/// # Arguments
+/// * `client_id` - OAuth client ID of the app
+/// * `client_secret` - OAuth client secret of the app
+/// * `refresh_token` - Current refresh token
+/// * `base_url` - Optional base URL for testing (defaults to Slack's API)
+pub async fn refresh_access_token(
+    client_id: &str,
+    client_secret: &str,
+    refresh_token: &str,
+    base_url: Option<&str>,
+) -> Result<OAuthResponse, OAuthError> {
+    let url = format!(
+        "{}/oauth.v2.access",
+        base_url.unwrap_or("https://slack.com/api")
+    );
+
+    let mut params = HashMap::new();
+    params.insert("client_id", client_id);
+    params.insert("client_secret", client_secret);
+    params.insert("grant_type", "refresh_token");
+    params.insert("refresh_token", refresh_token);
+
+    let response = Client::new()
+        .post(&url)
+        .form(&params)
+        .send()
+        .await
+        .map_err(|e| OAuthError::NetworkError(e.to_string()))?;
+
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| OAuthError::NetworkError(e.to_string()))?;
+    if !status.is_success() {
+        return Err(OAuthError::HttpError(status.as_u16(), body));
+    }
+
+    let oauth_response: OAuthResponse =
+        serde_json::from_str(&body).map_err(|e| OAuthError::ParseError(e.to_string()))?;
+    if !oauth_response.ok {
+        return Err(OAuthError::SlackError(
+            oauth_response
+                .error
+                .unwrap_or_else(|| "unknown".to_string()),
+        ));
+    }
+
+    Ok(oauth_response)
+}
+
+/// Return a usable access token, refreshing it first when it is about to expire
+///
+/// Tokens without rotation data are returned unchanged.
+///
+/// # Arguments
+/// * `token_store` - Token store holding the access token and rotation data
+/// * `token_key` - Key of the access token (`team:user` or `team:user:user`)
+/// * `current_token` - Access token currently stored under `token_key`
+/// * `client_credentials` - OAuth client ID and secret of the app, if known
+/// * `now` - Current UNIX time
+/// * `base_url` - Optional base URL for testing
+pub async fn refresh_if_expired(
+    token_store: &dyn TokenStore,
+    token_key: &str,
+    current_token: String,
+    client_credentials: Option<(&str, &str)>,
+    now: u64,
+    base_url: Option<&str>,
+) -> Result<String, OAuthError> {
+    let Some(rotation) = load_rotation(token_store, token_key) else {
+        return Ok(current_token);
+    };
+    if !rotation.needs_refresh(now) {
+        return Ok(current_token);
+    }
+
+    let (client_id, client_secret) = client_credentials.ok_or_else(|| {
+        OAuthError::ConfigError(
+            "Access token expired and the OAuth client credentials needed to refresh it are not saved. Run 'auth login' again".to_string(),
+        )
+    })?;
+
+    debug::log(format!("Refreshing rotated token for {}", token_key));
+    let response =
+        refresh_access_token(client_id, client_secret, &rotation.refresh_token, base_url).await?;
+    let access_token = response
+        .access_token
+        .clone()
+        .ok_or_else(|| OAuthError::SlackError("No access token in refresh response".to_string()))?;
+
+    token_store
+        .set(token_key, &access_token)
+        .map_err(|e| OAuthError::ConfigError(e.to_string()))?;
+    let refreshed = TokenRotation {
+        refresh_token: response
+            .refresh_token
+            .clone()
+            .unwrap_or(rotation.refresh_token),
+        expires_at: response.expires_in.map(|secs| now + secs),
+    };
+    store_rotation(token_store, token_key, &refreshed)?;
+
+    Ok(access_token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::InMemoryTokenStore;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_rotation_roundtrip_and_expiry() {
+        let store = InMemoryTokenStore::new();
+        assert_eq!(load_rotation(&store, "T1:U1"), None);
+
+        let rotation =
+            TokenRotation::from_issued(Some(&"xoxe-1-refresh".to_string()), Some(43200), 1000)
+                .unwrap();
+        store_rotation(&store, "T1:U1", &rotation).unwrap();
+        assert_eq!(load_rotation(&store, "T1:U1"), Some(rotation.clone()));
+
+        assert!(!rotation.needs_refresh(1000));
+        assert!(rotation.needs_refresh(1000 + 43200 - REFRESH_MARGIN_SECS));
+        assert!(TokenRotation::from_issued(None, Some(43200), 1000).is_none());
+
+        delete_rotation(&store, "T1:U1");
+        assert_eq!(load_rotation(&store, "T1:U1"), None);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_if_expired_rotates_and_persists() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth.v2.access"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .and(body_string_contains("refresh_token=xoxe-1-old"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "access_token": "xoxe.xoxb-new",
+                "refresh_token": "xoxe-1-new",
+                "expires_in": 43200,
+                "token_type": "bot"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let store = InMemoryTokenStore::new();
+        store.set("T1:U1", "xoxe.xoxb-old").unwrap();
+        store_rotation(
+            &store,
+            "T1:U1",
+            &TokenRotation {
+                refresh_token: "xoxe-1-old".to_string(),
+                expires_at: Some(2000),
+            },
+        )
+        .unwrap();
+
+        // Still valid: returned unchanged without a network call
+        let token = refresh_if_expired(
+            &store,
+            "T1:U1",
+            "xoxe.xoxb-old".to_string(),
+            Some(("cid", "secret")),
+            1000,
+            Some(&mock_server.uri()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(token, "xoxe.xoxb-old");
+
+        let token = refresh_if_expired(
+            &store,
+            "T1:U1",
+            "xoxe.xoxb-old".to_string(),
+            Some(("cid", "secret")),
+            1900,
+            Some(&mock_server.uri()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(token, "xoxe.xoxb-new");
+        assert_eq!(store.get("T1:U1").unwrap(), "xoxe.xoxb-new");
+        assert_eq!(
+            load_rotation(&store, "T1:U1"),
+            Some(TokenRotation {
+                refresh_token: "xoxe-1-new".to_string(),
+                expires_at: Some(1900 + 43200),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_if_expired_without_credentials() {
+        let store = InMemoryTokenStore::new();
+        store_rotation(
+            &store,
+            "T1:U1",
+            &TokenRotation {
+                refresh_token: "xoxe-1-old".to_string(),
+                expires_at: Some(100),
+            },
+        )
+        .unwrap();
+
+        let result = refresh_if_expired(&store, "T1:U1", "old".to_string(), None, 1000, None).await;
+        assert!(matches!(result, Err(OAuthError::ConfigError(_))));
+    }
+}
@@ -16,6 +16,9 @@ pub struct CallbackResult {
     pub state: String,
 }
 
+/// Query parameter of the preflight probe (see [`super::preflight`])
+pub const PREFLIGHT_PARAM: &str = "slack_rs_preflight";
+
 /// Run a local HTTP server to receive OAuth callback
 ///
 /// Returns the authorization code and state received from the callback
@@ -29,17 +32,45 @@ pub async fn run_callback_server(
     expected_state: String,
     timeout_secs: u64,
 ) -> Result<CallbackResult, OAuthError> {
+    let listener = bind_callback_listener(port).await?;
+    serve_callback(listener, expected_state, timeout_secs).await
+}
+
+/// Bind the callback listener on 127.0.0.1
+///
+/// A port that is already taken fails with a hint instead of the bare OS
+/// error, since a previous login or another local app is the usual cause.
+pub async fn bind_callback_listener(port: u16) -> Result<TcpListener, OAuthError> {
     let bind_addr = format!("127.0.0.1:{}", port);
-    let listener = TcpListener::bind(&bind_addr)
-        .await
-        .map_err(|e| OAuthError::ServerError(format!("Failed to bind to port {}: {}", port, e)))?;
+    let listener = TcpListener::bind(&bind_addr).await.map_err(|e| {
+        if e.kind() == std::io::ErrorKind::AddrInUse {
+            OAuthError::ServerError(format!(
+                "Port {} is already in use. Stop the process using it (e.g. a previous login still waiting) or set {} to a free port and update the redirect URI to match",
+                port,
+                super::port::OAUTH_PORT_ENV
+            ))
+        } else {
+            OAuthError::ServerError(format!("Failed to bind to port {}: {}", port, e))
+        }
+    })?;
 
     let actual_port = listener.local_addr().map(|a| a.port()).unwrap_or(port);
     println!(
         "Listening for OAuth callback on http://127.0.0.1:{}",
         actual_port
     );
+    Ok(listener)
+}
 
+/// Serve OAuth callbacks on a bound listener until one arrives
+///
+/// Preflight probes (`?slack_rs_preflight=<nonce>`) are answered with the
+/// nonce and do not end the wait.
+pub async fn serve_callback(
+    listener: TcpListener,
+    expected_state: String,
+    timeout_secs: u64,
+) -> Result<CallbackResult, OAuthError> {
     let result: Arc<Mutex<Option<Result<CallbackResult, OAuthError>>>> = Arc::new(Mutex::new(None));
 
     let server_result = result.clone();
@@ -72,6 +103,14 @@ pub async fn run_callback_server(
                         let query = &path_part[query_start + 1..];
                         let params = parse_query_string(query);
 
+                        if let Some(nonce) = params.get(PREFLIGHT_PARAM) {
+                            let _ = socket
+                                .write_all(create_preflight_response(nonce).as_bytes())
+                                .await;
+                            let _ = socket.flush().await;
+                            continue;
+                        }
+
                         let response = if let (Some(code), Some(state)) =
                             (params.get("code"), params.get("state"))
                         {
@@ -174,6 +213,20 @@ fn create_success_response() -> String {
         .to_string()
 }
 
+fn create_preflight_response(nonce: &str) -> String {
+    let body = format!("slack-rs-callback:{}", nonce);
+    format!(
+        "HTTP/1.1 200 OK\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\
+         \r\n\
+         {}",
+        body.len(),
+        body
+    )
+}
+
 fn create_error_response(message: &str) -> String {
     format!(
         "HTTP/1.1 400 Bad Request\r\n\
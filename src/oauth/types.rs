@@ -80,6 +80,12 @@ pub struct OAuthResponse {
     pub team: Option<TeamInfo>,
     pub authed_user: Option<AuthedUser>,
     pub error: Option<String>,
+    /// Present when token rotation is enabled for the app
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// Access token lifetime in seconds (token rotation only)
+    #[serde(default)]
+    pub expires_in: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -94,6 +100,10 @@ pub struct AuthedUser {
     pub scope: Option<String>,
     pub access_token: Option<String>,
     pub token_type: Option<String>,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    #[serde(default)]
+    pub expires_in: Option<u64>,
 }
 
 #[cfg(test)]
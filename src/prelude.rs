@@ -0,0 +1,31 @@
+//! Commonly used library types in one import
+//!
+//! ```no_run
+//! use slack_rs::prelude::*;
+//!
+//! # async fn run() -> Result<(), ApiError> {
+//! let client = ApiClient::with_token("xoxb-...".to_string());
+//! let plan = PlannedRequest::new(ApiMethod::ConversationsList).param("limit", "100");
+//! let response: ApiResponse = client.execute_planned(plan).await?;
+//! # let _ = response;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Everything re-exported here is covered by the semver policy described
+//! in the crate documentation.
+
+pub use crate::api::{
+    execute_api_call, paginate, ApiCallArgs, ApiCallContext, ApiCallResponse, ApiClient,
+    ApiClientConfig, ApiError, ApiMethod, ApiResponse, CommandMeta, CommandResponse,
+    PlannedRequest,
+};
+pub use crate::idempotency::{
+    IdempotencyCheckResult, IdempotencyHandler, PostOutcome, ReliableError, ReliablePoster,
+};
+pub use crate::profile::{
+    default_config_path, load_config, resolve_profile, Profile, ProfilesConfig, TokenType,
+};
+pub use crate::query::Query;
+pub use crate::socket::{listen, EventFilter, ListenerOptions, ReceivedEvent};
+pub use crate::stream::{OutputQueue, OverflowPolicy, StreamConfig};
@@ -2,7 +2,7 @@
 //!
 //! This module provides the foundation for managing Slack CLI profiles and tokens:
 //! - Profile configuration (non-secret information)
-//! - Secure token storage (using OS keyring)
+//! - Secure token storage (plain or encrypted file)
 //! - Profile resolution (profile_name -> team_id, user_id)
 //!
 //! This is a foundational library module intended for use by future CLI features.
@@ -11,19 +11,26 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
+pub mod encrypted_token_store;
 pub mod resolver;
 pub mod storage;
+pub mod token_meta;
 pub mod token_store;
 pub mod token_type;
 pub mod types;
 
 // Re-export commonly used types and functions
+pub use encrypted_token_store::{EncryptedFileTokenStore, TOKEN_PASSPHRASE_ENV};
 pub use resolver::{list_profiles, resolve_profile, resolve_profile_full, ResolverError};
-pub use storage::{default_config_path, load_config, save_config, StorageError};
+pub use storage::{
+    config_dir, config_dir_override, default_config_path, load_config, save_config, StorageError,
+    CONFIG_DIR_ENV,
+};
+pub use token_meta::{load_token_meta, token_meta_path, TokenMeta, TOKEN_META_FILE};
 pub use token_store::{
     create_token_store, delete_oauth_client_secret, get_oauth_client_secret,
-    make_oauth_client_secret_key, make_token_key, store_oauth_client_secret, FileTokenStore,
-    InMemoryTokenStore, TokenStore, TokenStoreError,
+    make_oauth_client_secret_key, make_token_key, resolve_token_backend, store_oauth_client_secret,
+    FileTokenStore, InMemoryTokenStore, TokenStore, TokenStoreError, TOKEN_BACKEND_ENV,
 };
 pub use token_type::{TokenType, TokenTypeError};
-pub use types::{Profile, ProfileError, ProfilesConfig};
+pub use types::{OutputSettings, Profile, ProfileError, ProfilesConfig, TokenBackend};
@@ -62,6 +62,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         config.set(
@@ -77,6 +79,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
 
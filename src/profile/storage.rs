@@ -23,6 +23,30 @@ fn legacy_config_path() -> Result<PathBuf> {
         .ok_or(StorageError::ConfigDirNotFound)
 }
 
+/// Environment variable that moves every slack-rs file to another directory
+///
+/// Set by the global `--config-dir` and `--ephemeral` flags. Profiles,
+/// tokens, caches, the idempotency store and the outbox all live directly
+/// in this directory; nothing is migrated from the default locations.
+pub const CONFIG_DIR_ENV: &str = "SLACKRS_CONFIG_DIR";
+
+/// Directory set with [`CONFIG_DIR_ENV`], if any
+pub fn config_dir_override() -> Option<PathBuf> {
+    std::env::var_os(CONFIG_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The slack-rs config directory: [`CONFIG_DIR_ENV`] or the OS config directory
+///
+/// `None` when neither is available (no home directory).
+pub fn config_dir() -> Option<PathBuf> {
+    config_dir_override().or_else(|| {
+        directories::ProjectDirs::from("", "", "slack-rs")
+            .map(|dirs| dirs.config_dir().to_path_buf())
+    })
+}
+
 /// Get the default config file path using the OS config directory
 pub fn default_config_path() -> Result<PathBuf> {
     // Check for environment variable override (used in testing)
@@ -30,12 +54,10 @@ pub fn default_config_path() -> Result<PathBuf> {
         return Ok(PathBuf::from(config_path));
     }
 
-    let dirs = directories::ProjectDirs::from("", "", "slack-rs")
-        .ok_or(StorageError::ConfigDirNotFound)?;
-    let config_dir = dirs.config_dir();
+    let config_dir = config_dir().ok_or(StorageError::ConfigDirNotFound)?;
 
     // Create directory if it doesn't exist
-    fs::create_dir_all(config_dir)?;
+    fs::create_dir_all(&config_dir)?;
 
     Ok(config_dir.join("profiles.json"))
 }
@@ -115,7 +137,7 @@ pub fn load_config(path: &Path) -> Result<ProfilesConfig> {
     // Try to migrate legacy config if this is the default path
     // Only attempt migration when using default_config_path
     if let Ok(default_path) = default_config_path() {
-        if path == default_path {
+        if path == default_path && config_dir_override().is_none() {
             let _ = migrate_legacy_config_internal();
         }
     }
@@ -166,6 +188,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
 
@@ -218,6 +242,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         config.set(
@@ -233,6 +259,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
 
@@ -278,6 +306,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
@@ -323,6 +353,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
@@ -0,0 +1,180 @@
+//! Creation and last-use times of token store entries
+//!
+//! Token values stay in the token store; the times live in
+//! `token_meta.json` next to it so they can be shown without unlocking an
+//! encrypted store. Tracking is best effort: a metadata file that cannot
+//! be read or written never makes a token operation fail.
+//!
+//! Reading a token does not count as a use; the API client records the
+//! use when it actually sends a token that came from a store.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// File name of the metadata file, in the token store's directory
+pub const TOKEN_META_FILE: &str = "token_meta.json";
+
+/// Last-use times closer together than this are not rewritten
+const LAST_USED_RESOLUTION_SECS: u64 = 60;
+
+/// Times recorded for one token store key (UNIX seconds)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenMeta {
+    /// When the current value was stored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// When the value was last sent to Slack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used_at: Option<u64>,
+}
+
+/// Metadata file for the token store at `store_path`
+pub fn token_meta_path(store_path: &Path) -> PathBuf {
+    store_path.with_file_name(TOKEN_META_FILE)
+}
+
+/// Load all recorded times; a missing or unreadable file is empty
+pub fn load_token_meta(path: &Path) -> BTreeMap<String, TokenMeta> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Record that a new value was stored for `key`
+pub(crate) fn record_token_set(store_path: &Path, key: &str) {
+    update(store_path, |meta| {
+        meta.insert(
+            key.to_string(),
+            TokenMeta {
+                created_at: Some(now()),
+                last_used_at: None,
+            },
+        );
+        true
+    });
+}
+
+/// Store entry of each token read in this process, by SHA-256 of its value
+static READ_TOKENS: Mutex<BTreeMap<[u8; 32], (PathBuf, String)>> = Mutex::new(BTreeMap::new());
+
+fn token_digest(token: &str) -> [u8; 32] {
+    Sha256::digest(token.as_bytes()).into()
+}
+
+/// Remember that `token` was read from `key` of the store at `store_path`
+///
+/// Nothing is written until the token is sent (see [`record_token_sent`]).
+pub(crate) fn remember_token_read(store_path: &Path, key: &str, token: &str) {
+    if let Ok(mut read) = READ_TOKENS.lock() {
+        read.insert(
+            token_digest(token),
+            (store_path.to_path_buf(), key.to_string()),
+        );
+    }
+}
+
+/// Record a use of the store entry `token` was read from
+///
+/// Called where a token is sent to Slack; tokens that did not come from
+/// a token store (`SLACK_TOKEN`, `--token`) are ignored.
+pub(crate) fn record_token_sent(token: &str) {
+    let entry = READ_TOKENS
+        .lock()
+        .ok()
+        .and_then(|read| read.get(&token_digest(token)).cloned());
+    if let Some((store_path, key)) = entry {
+        record_token_use(&store_path, &key);
+    }
+}
+
+/// Record that `key` was used
+fn record_token_use(store_path: &Path, key: &str) {
+    let now = now();
+    update(store_path, |meta| {
+        let entry = meta.entry(key.to_string()).or_default();
+        if entry
+            .last_used_at
+            .is_some_and(|last| now < last + LAST_USED_RESOLUTION_SECS)
+        {
+            return false;
+        }
+        entry.last_used_at = Some(now);
+        true
+    });
+}
+
+/// Forget the times of a deleted key
+pub(crate) fn record_token_delete(store_path: &Path, key: &str) {
+    update(store_path, |meta| meta.remove(key).is_some());
+}
+
+/// Apply `change` and save when it reports a modification
+///
+/// The file is replaced atomically (temp file + rename).
+fn update(store_path: &Path, change: impl FnOnce(&mut BTreeMap<String, TokenMeta>) -> bool) {
+    let path = token_meta_path(store_path);
+    let mut meta = load_token_meta(&path);
+    if !change(&mut meta) {
+        return;
+    }
+    let Ok(content) = serde_json::to_string_pretty(&meta) else {
+        return;
+    };
+    let temp_path = path.with_extension("json.tmp");
+    if fs::write(&temp_path, content).is_err() {
+        return;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&temp_path, fs::Permissions::from_mode(0o600));
+    }
+    if fs::rename(&temp_path, &path).is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_token_meta_tracks_set_use_and_delete() {
+        let temp_dir = TempDir::new().unwrap();
+        let store_path = temp_dir.path().join("tokens.json");
+        let meta_path = token_meta_path(&store_path);
+        assert_eq!(meta_path, temp_dir.path().join(TOKEN_META_FILE));
+
+        record_token_set(&store_path, "T1:U1");
+        let meta = load_token_meta(&meta_path);
+        assert!(meta["T1:U1"].created_at.is_some());
+        assert_eq!(meta["T1:U1"].last_used_at, None);
+
+        // Reading a token is not a use; sending it is
+        remember_token_read(&store_path, "T1:U1", "xoxb-meta-test");
+        assert_eq!(load_token_meta(&meta_path)["T1:U1"].last_used_at, None);
+        record_token_sent("xoxb-meta-test");
+        let used = load_token_meta(&meta_path)["T1:U1"].last_used_at;
+        assert!(used.is_some());
+        assert!(!temp_dir.path().join("token_meta.json.tmp").exists());
+
+        // Tokens that did not come from a store are ignored
+        record_token_sent("xoxb-from-env");
+
+        record_token_delete(&store_path, "T1:U1");
+        assert!(load_token_meta(&meta_path).is_empty());
+    }
+}
@@ -1,3 +1,7 @@
+use super::encrypted_token_store::EncryptedFileTokenStore;
+use super::storage::{default_config_path, load_config};
+use super::token_meta::{record_token_delete, record_token_set, remember_token_read};
+use super::types::TokenBackend;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -14,6 +18,8 @@ pub enum TokenStoreError {
     DeleteFailed(String),
     #[error("IO error: {0}")]
     IoError(String),
+    #[error("Token store is locked: {0}")]
+    Locked(String),
 }
 
 pub type Result<T> = std::result::Result<T, TokenStoreError>;
@@ -113,7 +119,9 @@ impl FileTokenStore {
         }
 
         // Auto-migrate from old path if needed (only when using default path)
-        if std::env::var("SLACK_RS_TOKENS_PATH").is_err() {
+        if std::env::var("SLACK_RS_TOKENS_PATH").is_err()
+            && crate::profile::storage::config_dir_override().is_none()
+        {
             if let Some(old) = old_path {
                 Self::migrate_from_path(&old, &file_path)?;
             } else {
@@ -134,16 +142,31 @@ impl FileTokenStore {
         })
     }
 
+    /// Path of the tokens file
+    pub fn path(&self) -> &Path {
+        &self.file_path
+    }
+
+    /// Snapshot of all stored entries (used for backend migration)
+    pub fn entries(&self) -> HashMap<String, String> {
+        self.tokens.lock().unwrap().clone()
+    }
+
     /// Get the default path for the tokens file
     /// Can be overridden with SLACK_RS_TOKENS_PATH environment variable (useful for testing)
-    /// Respects XDG_DATA_HOME when set
+    /// Uses SLACKRS_CONFIG_DIR when set, then respects XDG_DATA_HOME
     pub fn default_path() -> Result<PathBuf> {
         // Priority 1: Check for environment variable override (useful for testing)
         if let Ok(path) = std::env::var("SLACK_RS_TOKENS_PATH") {
             return Ok(PathBuf::from(path));
         }
 
-        // Priority 2: Check for XDG_DATA_HOME
+        // Priority 2: SLACKRS_CONFIG_DIR (--config-dir / --ephemeral)
+        if let Some(dir) = crate::profile::storage::config_dir_override() {
+            return Ok(dir.join("tokens.json"));
+        }
+
+        // Priority 3: Check for XDG_DATA_HOME
         if let Ok(xdg_data_home) = std::env::var("XDG_DATA_HOME") {
             // Guard against empty or whitespace-only values
             let trimmed = xdg_data_home.trim();
@@ -157,7 +180,7 @@ impl FileTokenStore {
             }
         }
 
-        // Priority 3: Fallback to ~/.local/share/slack-rs/tokens.json
+        // Priority 4: Fallback to ~/.local/share/slack-rs/tokens.json
         let home = directories::BaseDirs::new()
             .ok_or_else(|| {
                 TokenStoreError::IoError("Failed to determine home directory".to_string())
@@ -278,17 +301,24 @@ impl Default for FileTokenStore {
 impl TokenStore for FileTokenStore {
     fn set(&self, key: &str, token: &str) -> Result<()> {
         let mut tokens = self.tokens.lock().unwrap();
-        tokens.insert(key.to_string(), token.to_string());
+        let changed = tokens.insert(key.to_string(), token.to_string()).as_deref() != Some(token);
         drop(tokens); // Release lock before saving
-        self.save_tokens()
+        self.save_tokens()?;
+        if changed {
+            record_token_set(&self.file_path, key);
+        }
+        Ok(())
     }
 
     fn get(&self, key: &str) -> Result<String> {
         let tokens = self.tokens.lock().unwrap();
-        tokens
+        let token = tokens
             .get(key)
             .cloned()
-            .ok_or_else(|| TokenStoreError::NotFound(key.to_string()))
+            .ok_or_else(|| TokenStoreError::NotFound(key.to_string()))?;
+        drop(tokens);
+        remember_token_read(&self.file_path, key, &token);
+        Ok(token)
     }
 
     fn delete(&self, key: &str) -> Result<()> {
@@ -297,7 +327,9 @@ impl TokenStore for FileTokenStore {
             .remove(key)
             .ok_or_else(|| TokenStoreError::NotFound(key.to_string()))?;
         drop(tokens); // Release lock before saving
-        self.save_tokens()
+        self.save_tokens()?;
+        record_token_delete(&self.file_path, key);
+        Ok(())
     }
 
     fn exists(&self, key: &str) -> bool {
@@ -338,14 +370,33 @@ pub fn delete_oauth_client_secret(token_store: &dyn TokenStore, profile_name: &s
     token_store.delete(&key)
 }
 
-/// Create a token store using FileTokenStore
+/// Environment variable overriding the configured token backend
+pub const TOKEN_BACKEND_ENV: &str = "SLACK_RS_TOKEN_BACKEND";
+
+/// Resolve the token backend: `SLACK_RS_TOKEN_BACKEND` > `token_backend` in
+/// the config file > plain file
+pub fn resolve_token_backend() -> Result<TokenBackend> {
+    if let Ok(value) = std::env::var(TOKEN_BACKEND_ENV) {
+        return value.parse().map_err(TokenStoreError::IoError);
+    }
+    Ok(default_config_path()
+        .ok()
+        .and_then(|path| load_config(&path).ok())
+        .and_then(|config| config.token_backend)
+        .unwrap_or_default())
+}
+
+/// Create a token store for the resolved backend
 ///
-/// This function creates a FileTokenStore with the default path.
+/// Uses FileTokenStore by default, or EncryptedFileTokenStore when
+/// `token_backend` is `encrypted-file`.
 ///
 /// Returns Box<dyn TokenStore> for runtime polymorphism
 pub fn create_token_store() -> Result<Box<dyn TokenStore>> {
-    let store = FileTokenStore::new()?;
-    Ok(Box::new(store))
+    match resolve_token_backend()? {
+        TokenBackend::File => Ok(Box::new(FileTokenStore::new()?)),
+        TokenBackend::EncryptedFile => Ok(Box::new(EncryptedFileTokenStore::new()?)),
+    }
 }
 
 #[cfg(test)]
@@ -1045,6 +1096,31 @@ mod tests {
         std::env::remove_var("XDG_DATA_HOME");
     }
 
+    /// Test that SLACKRS_CONFIG_DIR holds tokens and profiles, ahead of XDG_DATA_HOME
+    #[test]
+    #[serial_test::serial]
+    fn test_config_dir_override() {
+        use tempfile::TempDir;
+
+        std::env::remove_var("SLACK_RS_TOKENS_PATH");
+        std::env::remove_var("SLACK_RS_CONFIG_PATH");
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().join("slack-rs-config");
+        std::env::set_var("XDG_DATA_HOME", temp_dir.path().to_str().unwrap());
+        std::env::set_var(crate::profile::CONFIG_DIR_ENV, config_dir.to_str().unwrap());
+
+        let tokens_path = FileTokenStore::default_path().unwrap();
+        let config_path = crate::profile::default_config_path().unwrap();
+
+        std::env::remove_var(crate::profile::CONFIG_DIR_ENV);
+        std::env::remove_var("XDG_DATA_HOME");
+
+        assert_eq!(tokens_path, config_dir.join("tokens.json"));
+        assert_eq!(config_path, config_dir.join("profiles.json"));
+        assert!(config_dir.is_dir());
+    }
+
     /// Test that SLACK_RS_TOKENS_PATH takes priority over XDG_DATA_HOME
     #[test]
     #[serial_test::serial]
@@ -1,7 +1,7 @@
 //! Token type definitions and resolution logic
 //!
 //! This module provides:
-//! - TokenType enum for bot/user token distinction
+//! - TokenType enum for bot/user/app token distinction
 //! - Token type resolution logic with priority: CLI flag > profile default > fallback
 
 use serde::{Deserialize, Serialize};
@@ -17,14 +17,42 @@ pub enum TokenType {
     Bot,
     /// User token (xoxp-*)
     User,
+    /// App-level token (xapp-*), used for Socket Mode and app management APIs
+    App,
 }
 
 impl TokenType {
-    /// Returns "bot" or "user" as a string
+    /// Returns "bot", "user" or "app" as a string
     pub fn as_str(&self) -> &'static str {
         match self {
             TokenType::Bot => "bot",
             TokenType::User => "user",
+            TokenType::App => "app",
+        }
+    }
+
+    /// Prefix every token of this type starts with
+    pub fn token_prefix(&self) -> &'static str {
+        match self {
+            TokenType::Bot => "xoxb-",
+            TokenType::User => "xoxp-",
+            TokenType::App => "xapp-",
+        }
+    }
+
+    /// Token type of a token, judged by its prefix
+    pub fn from_token(token: &str) -> Option<Self> {
+        [TokenType::Bot, TokenType::User, TokenType::App]
+            .into_iter()
+            .find(|t| token.starts_with(t.token_prefix()))
+    }
+
+    /// Token store key of this token type for a profile
+    pub fn token_key(&self, team_id: &str, user_id: &str) -> String {
+        match self {
+            TokenType::Bot => super::make_token_key(team_id, user_id),
+            TokenType::User => format!("{}:{}:user", team_id, user_id),
+            TokenType::App => format!("{}:{}:app", team_id, user_id),
         }
     }
 
@@ -73,6 +101,7 @@ impl FromStr for TokenType {
         match s.to_lowercase().as_str() {
             "bot" => Ok(TokenType::Bot),
             "user" => Ok(TokenType::User),
+            "app" => Ok(TokenType::App),
             _ => Err(TokenTypeError::InvalidValue(s.to_string())),
         }
     }
@@ -80,7 +109,7 @@ impl FromStr for TokenType {
 
 #[derive(Debug, Error)]
 pub enum TokenTypeError {
-    #[error("Invalid token type: {0}. Valid values: bot, user")]
+    #[error("Invalid token type: {0}. Valid values: bot, user, app")]
     InvalidValue(String),
 }
 
@@ -92,6 +121,23 @@ mod tests {
     fn test_token_type_as_str() {
         assert_eq!(TokenType::Bot.as_str(), "bot");
         assert_eq!(TokenType::User.as_str(), "user");
+        assert_eq!(TokenType::App.as_str(), "app");
+    }
+
+    #[test]
+    fn test_token_type_token_key() {
+        assert_eq!(TokenType::Bot.token_key("T1", "U1"), "T1:U1");
+        assert_eq!(TokenType::User.token_key("T1", "U1"), "T1:U1:user");
+        assert_eq!(TokenType::App.token_key("T1", "U1"), "T1:U1:app");
+        assert_eq!(TokenType::App.token_prefix(), "xapp-");
+    }
+
+    #[test]
+    fn test_token_type_from_token() {
+        assert_eq!(TokenType::from_token("xoxb-1-2"), Some(TokenType::Bot));
+        assert_eq!(TokenType::from_token("xoxp-1-2"), Some(TokenType::User));
+        assert_eq!(TokenType::from_token("xapp-1-A1"), Some(TokenType::App));
+        assert_eq!(TokenType::from_token("xoxe.xoxp-1"), None);
     }
 
     #[test]
@@ -108,6 +154,7 @@ mod tests {
         assert_eq!("user".parse::<TokenType>().unwrap(), TokenType::User);
         assert_eq!("User".parse::<TokenType>().unwrap(), TokenType::User);
         assert_eq!("USER".parse::<TokenType>().unwrap(), TokenType::User);
+        assert_eq!("app".parse::<TokenType>().unwrap(), TokenType::App);
 
         assert!("invalid".parse::<TokenType>().is_err());
         assert!("admin".parse::<TokenType>().is_err());
@@ -36,6 +36,151 @@ pub struct Profile {
     /// Default token type for this profile (optional for backward compatibility)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_token_type: Option<TokenType>,
+    /// Output preferences for this profile (override the global ones)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputSettings>,
+    /// UNIX time of the last successful `auth.test` call with this profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_auth_test_at: Option<u64>,
+}
+
+/// Output preferences (`output.envelope`, `output.format` and the display
+/// keys `output.user_name`, `output.time_format`, `output.locale`,
+/// `output.include_locale`)
+///
+/// Stored globally and per profile; CLI flags and environment variables
+/// take precedence over both.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct OutputSettings {
+    /// Wrap responses in the `{response, meta}` envelope (false = raw)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub envelope: Option<bool>,
+    /// Default output format for list-style commands (json, jsonl, table, tsv)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Name shown for users (display_name, real_name, username)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    /// Clock style for human-readable times (24h, 12h)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<String>,
+    /// Locale such as `ja-JP`; selects the message language when neither
+    /// `--lang` nor `SLACKRS_LANG` is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Ask Slack for the `locale` of users and conversations where supported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_locale: Option<bool>,
+    /// Footer template appended to messages posted with this profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_footer: Option<String>,
+}
+
+fn parse_bool_setting(key: &str, value: &str) -> Result<Option<bool>, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "" => Ok(None),
+        "true" | "yes" | "on" | "1" => Ok(Some(true)),
+        "false" | "no" | "off" | "0" => Ok(Some(false)),
+        _ => Err(format!(
+            "Invalid value for {}: '{}' (expected true or false)",
+            key, value
+        )),
+    }
+}
+
+impl OutputSettings {
+    /// Supported `output.*` keys
+    pub const KEYS: &'static [&'static str] = &[
+        "output.envelope",
+        "output.format",
+        "output.user_name",
+        "output.time_format",
+        "output.locale",
+        "output.include_locale",
+        "output.post_footer",
+    ];
+
+    /// Set a key from its string form; an empty value unsets it
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        match key {
+            "output.envelope" => self.envelope = parse_bool_setting(key, value)?,
+            "output.include_locale" => self.include_locale = parse_bool_setting(key, value)?,
+            "output.user_name" => {
+                self.user_name = match value.to_ascii_lowercase().as_str() {
+                    "" => None,
+                    n @ ("display_name" | "real_name" | "username") => Some(n.to_string()),
+                    _ => {
+                        return Err(format!(
+                            "Invalid value for output.user_name: '{}' (expected display_name, real_name or username)",
+                            value
+                        ))
+                    }
+                };
+            }
+            "output.time_format" => {
+                self.time_format = match value.to_ascii_lowercase().as_str() {
+                    "" => None,
+                    f @ ("24h" | "12h") => Some(f.to_string()),
+                    _ => {
+                        return Err(format!(
+                            "Invalid value for output.time_format: '{}' (expected 24h or 12h)",
+                            value
+                        ))
+                    }
+                };
+            }
+            "output.locale" => {
+                let valid = value.split(['-', '_']).all(|part| {
+                    !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric())
+                });
+                self.locale = match value {
+                    "" => None,
+                    _ if valid => Some(value.replace('_', "-")),
+                    _ => {
+                        return Err(format!(
+                            "Invalid value for output.locale: '{}' (expected a locale such as en-US or ja-JP)",
+                            value
+                        ))
+                    }
+                };
+            }
+            "output.post_footer" => {
+                self.post_footer = (!value.is_empty()).then(|| value.to_string());
+            }
+            "output.format" => {
+                self.format = match value.to_ascii_lowercase().as_str() {
+                    "" => None,
+                    f @ ("json" | "jsonl" | "table" | "tsv") => Some(f.to_string()),
+                    _ => {
+                        return Err(format!(
+                            "Invalid value for output.format: '{}' (expected json, jsonl, table or tsv)",
+                            value
+                        ))
+                    }
+                };
+            }
+            _ => {
+                return Err(format!(
+                    "Unknown config key: {} (supported: {})",
+                    key,
+                    Self::KEYS.join(", ")
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// True when no preference is set
+    pub fn is_empty(&self) -> bool {
+        self.envelope.is_none()
+            && self.format.is_none()
+            && self.user_name.is_none()
+            && self.time_format.is_none()
+            && self.locale.is_none()
+            && self.include_locale.is_none()
+            && self.post_footer.is_none()
+    }
 }
 
 impl Profile {
@@ -72,6 +217,8 @@ impl Profile {
             bot_scopes,
             user_scopes,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         }
     }
 }
@@ -81,6 +228,53 @@ impl Profile {
 pub struct ProfilesConfig {
     pub version: u32,
     pub profiles: HashMap<String, Profile>,
+    /// Global output preferences (apply to every profile)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputSettings>,
+    /// Where tokens are stored (defaults to the plain file)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_backend: Option<TokenBackend>,
+}
+
+/// Token storage backend (`token_backend` in the config file)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TokenBackend {
+    /// Plain JSON file protected by file permissions
+    #[default]
+    File,
+    /// JSON file encrypted with a passphrase-derived key
+    EncryptedFile,
+}
+
+impl TokenBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenBackend::File => "file",
+            TokenBackend::EncryptedFile => "encrypted-file",
+        }
+    }
+}
+
+impl std::fmt::Display for TokenBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for TokenBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "file" => Ok(TokenBackend::File),
+            "encrypted-file" => Ok(TokenBackend::EncryptedFile),
+            other => Err(format!(
+                "Invalid token backend '{}'. Expected 'file' or 'encrypted-file'",
+                other
+            )),
+        }
+    }
 }
 
 impl ProfilesConfig {
@@ -88,6 +282,8 @@ impl ProfilesConfig {
         Self {
             version: 1,
             profiles: HashMap::new(),
+            output: None,
+            token_backend: None,
         }
     }
 
@@ -207,6 +403,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         config.set("default".to_string(), profile.clone());
@@ -228,6 +426,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         config.set("test".to_string(), profile.clone());
@@ -252,6 +452,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
         config.set(
@@ -267,6 +469,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
 
@@ -288,6 +492,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
@@ -311,6 +517,8 @@ mod tests {
                 bot_scopes: None,
                 user_scopes: None,
                 default_token_type: None,
+                output: None,
+                last_auth_test_at: None,
             },
         );
 
@@ -333,6 +541,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
         let profile2 = Profile {
             team_id: "T789".to_string(),
@@ -345,6 +555,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         // First add should succeed
@@ -375,6 +587,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         // Adding new profile should succeed
@@ -398,6 +612,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
         let profile2 = Profile {
             team_id: "T123".to_string(),
@@ -410,6 +626,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         config
@@ -437,6 +655,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
         let profile2 = Profile {
             team_id: "T789".to_string(),
@@ -449,6 +669,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         config
@@ -478,6 +700,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
         let profile2 = Profile {
             team_id: "T123".to_string(),
@@ -490,6 +714,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         config.set_or_update("old".to_string(), profile1).unwrap();
@@ -544,6 +770,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
@@ -567,6 +795,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
@@ -588,6 +818,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
@@ -619,6 +851,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
@@ -645,6 +879,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         config
@@ -663,6 +899,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         // This should succeed and update the profile
@@ -693,6 +931,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         config
@@ -711,6 +951,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         // This should succeed but keep the real values
@@ -765,6 +1007,8 @@ mod tests {
             bot_scopes: Some(vec!["chat:write".to_string()]),
             user_scopes: Some(vec!["users:read".to_string()]),
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         assert_eq!(
@@ -816,6 +1060,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: Some(super::super::token_type::TokenType::Bot),
+            output: None,
+            last_auth_test_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
@@ -842,6 +1088,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
@@ -866,6 +1114,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
         config
             .set_or_update("existing".to_string(), real_profile)
@@ -883,6 +1133,8 @@ mod tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         // This should succeed without conflicts
@@ -914,6 +1166,8 @@ mod backward_compat_tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let bot_scopes = profile.get_bot_scopes();
@@ -937,6 +1191,8 @@ mod backward_compat_tests {
             bot_scopes: Some(vec!["new:scope".to_string()]),
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let bot_scopes = profile.get_bot_scopes();
@@ -957,6 +1213,8 @@ mod backward_compat_tests {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         };
 
         let user_scopes = profile.get_user_scopes();
@@ -991,4 +1249,76 @@ mod backward_compat_tests {
             Some(vec!["chat:write".to_string(), "users:read".to_string()])
         );
     }
+
+    #[test]
+    fn test_output_settings_set() {
+        let mut settings = OutputSettings::default();
+        settings.set("output.envelope", "false").unwrap();
+        settings.set("output.format", "JSONL").unwrap();
+        assert_eq!(settings.envelope, Some(false));
+        assert_eq!(settings.format, Some("jsonl".to_string()));
+
+        assert!(settings.set("output.envelope", "maybe").is_err());
+        assert!(settings.set("output.format", "xml").is_err());
+        assert!(settings.set("output.color", "true").is_err());
+
+        settings.set("output.envelope", "").unwrap();
+        settings.set("output.format", "").unwrap();
+        assert!(settings.is_empty());
+    }
+
+    #[test]
+    fn test_output_settings_display_keys() {
+        let mut settings = OutputSettings::default();
+        settings.set("output.user_name", "Real_Name").unwrap();
+        settings.set("output.time_format", "12H").unwrap();
+        settings.set("output.locale", "ja_JP").unwrap();
+        settings.set("output.include_locale", "yes").unwrap();
+        assert_eq!(settings.user_name.as_deref(), Some("real_name"));
+        assert_eq!(settings.time_format.as_deref(), Some("12h"));
+        assert_eq!(settings.locale.as_deref(), Some("ja-JP"));
+        assert_eq!(settings.include_locale, Some(true));
+
+        assert!(settings.set("output.user_name", "nickname").is_err());
+        assert!(settings.set("output.time_format", "ampm").is_err());
+        assert!(settings.set("output.locale", "ja JP").is_err());
+        assert!(settings.set("output.include_locale", "sometimes").is_err());
+
+        for key in [
+            "output.user_name",
+            "output.time_format",
+            "output.locale",
+            "output.include_locale",
+        ] {
+            settings.set(key, "").unwrap();
+        }
+        assert!(settings.is_empty());
+    }
+
+    #[test]
+    fn test_output_settings_post_footer() {
+        let mut settings = OutputSettings::default();
+        settings
+            .set("output.post_footer", "  sent by slack-rs on {hostname} ")
+            .unwrap();
+        assert_eq!(
+            settings.post_footer.as_deref(),
+            Some("sent by slack-rs on {hostname}")
+        );
+        assert!(!settings.is_empty());
+        settings.set("output.post_footer", "").unwrap();
+        assert!(settings.is_empty());
+    }
+
+    #[test]
+    fn test_output_settings_backward_compatible() {
+        let json = r#"{"version":1,"profiles":{"default":{"team_id":"T1","user_id":"U1","team_name":null,"user_name":null}}}"#;
+        let config: ProfilesConfig = serde_json::from_str(json).unwrap();
+        assert!(config.output.is_none());
+        assert!(config.get("default").unwrap().output.is_none());
+
+        // Unset settings are not written back
+        let serialized = serde_json::to_string(&config).unwrap();
+        assert!(!serialized.contains("output"));
+    }
 }
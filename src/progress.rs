@@ -0,0 +1,109 @@
+//! Machine-readable progress events for long-running operations.
+//!
+//! With `--progress json`, operations that page through many results
+//! (conversation export, users cache update, file download) write one JSON
+//! line per step to stderr:
+//!
+//! ```text
+//! {"event":"progress","op":"export","done":120,"total":540}
+//! ```
+//!
+//! `total` is omitted when it is not known up front. Human-readable output
+//! on stdout is unaffected, so wrappers can render progress bars without
+//! parsing text.
+
+use serde_json::json;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const MODE_OFF: u8 = 0;
+const MODE_JSON: u8 = 1;
+
+static MODE: AtomicU8 = AtomicU8::new(MODE_OFF);
+
+/// Progress output mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// No progress events (default)
+    Off,
+    /// JSON lines on stderr
+    Json,
+}
+
+impl ProgressMode {
+    /// Parse a `--progress` value
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ProgressMode::Json),
+            "off" | "none" => Ok(ProgressMode::Off),
+            _ => Err(format!(
+                "Invalid progress mode '{}'. Valid values: json, off",
+                s
+            )),
+        }
+    }
+}
+
+/// Set the process-wide progress mode
+pub fn set_mode(mode: ProgressMode) {
+    let value = match mode {
+        ProgressMode::Off => MODE_OFF,
+        ProgressMode::Json => MODE_JSON,
+    };
+    MODE.store(value, Ordering::Relaxed);
+}
+
+/// Current process-wide progress mode
+pub fn mode() -> ProgressMode {
+    match MODE.load(Ordering::Relaxed) {
+        MODE_JSON => ProgressMode::Json,
+        _ => ProgressMode::Off,
+    }
+}
+
+/// Format a progress event as a single JSON line
+///
+/// Keys are written in a fixed order (`event`, `op`, `done`, `total`) so the
+/// lines are stable for consumers that match on prefixes.
+pub fn format_event(op: &str, done: u64, total: Option<u64>) -> String {
+    let total = total
+        .map(|t| format!(",\"total\":{}", t))
+        .unwrap_or_default();
+    format!(
+        "{{\"event\":\"progress\",\"op\":{},\"done\":{}{}}}",
+        json!(op),
+        done,
+        total
+    )
+}
+
+/// Emit a progress event for `op` when `--progress json` is active
+pub fn report(op: &str, done: u64, total: Option<u64>) {
+    if mode() == ProgressMode::Json {
+        eprintln!("{}", format_event(op, done, total));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!(ProgressMode::parse("json").unwrap(), ProgressMode::Json);
+        assert_eq!(ProgressMode::parse("JSON").unwrap(), ProgressMode::Json);
+        assert_eq!(ProgressMode::parse("off").unwrap(), ProgressMode::Off);
+        assert!(ProgressMode::parse("bar").is_err());
+    }
+
+    #[test]
+    fn test_format_event() {
+        assert_eq!(
+            format_event("export", 120, Some(540)),
+            r#"{"event":"progress","op":"export","done":120,"total":540}"#
+        );
+        assert_eq!(
+            format_event("users.cache", 200, None),
+            r#"{"event":"progress","op":"users.cache","done":200}"#
+        );
+    }
+}
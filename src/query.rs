@@ -0,0 +1,259 @@
+//! Lightweight JSON query engine for `--query` / `--jq`
+//!
+//! Supports the path subset of jq that covers extracting values from
+//! command output without an external `jq`:
+//!
+//! - `.` (identity), `.field`, `."quoted field"`, `.["field"]`
+//! - `.[N]` (negative indexes count from the end), `.[]` (array elements or
+//!   object values)
+//! - `?` after a segment to skip values it cannot be applied to
+//! - `|` to feed the results of one path into the next
+//!
+//! A query produces zero or more values; `.response.channels[].id` yields one
+//! value per channel.
+
+use serde_json::Value;
+
+/// One step of a path
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(i64),
+    Iterate,
+}
+
+/// A path segment and whether errors on it are suppressed (`?`)
+#[derive(Debug, Clone, PartialEq)]
+struct Step {
+    segment: Segment,
+    optional: bool,
+}
+
+/// Parsed query: paths joined by `|`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pipeline: Vec<Vec<Step>>,
+}
+
+impl Query {
+    /// Parse a query expression
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let pipeline = expr
+            .split('|')
+            .map(|part| parse_path(part.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Invalid query '{}': {}", expr, e))?;
+        Ok(Self { pipeline })
+    }
+
+    /// Evaluate the query against a value
+    pub fn evaluate(&self, input: &Value) -> Result<Vec<Value>, String> {
+        let mut values = vec![input.clone()];
+        for path in &self.pipeline {
+            let mut next = Vec::new();
+            for value in values {
+                next.extend(eval_path(path, value)?);
+            }
+            values = next;
+        }
+        Ok(values)
+    }
+}
+
+/// Render a query result: strings without quotes, everything else as compact JSON
+pub fn render_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn parse_path(path: &str) -> Result<Vec<Step>, String> {
+    let chars: Vec<char> = path.chars().collect();
+    if chars.first() != Some(&'.') {
+        return Err("a path must start with '.'".to_string());
+    }
+    let mut steps = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let segment = match chars[i] {
+            '.' => {
+                i += 1;
+                match chars.get(i) {
+                    None => break,
+                    Some('[') => continue,
+                    Some('"') => {
+                        let (name, end) = parse_string(&chars, i)?;
+                        i = end;
+                        Segment::Field(name)
+                    }
+                    Some(c) if c.is_alphabetic() || *c == '_' => {
+                        let start = i;
+                        while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                            i += 1;
+                        }
+                        Segment::Field(chars[start..i].iter().collect())
+                    }
+                    Some(c) => return Err(format!("unexpected '{}' after '.'", c)),
+                }
+            }
+            '[' => {
+                let close = chars[i..]
+                    .iter()
+                    .position(|c| *c == ']')
+                    .map(|p| p + i)
+                    .ok_or_else(|| "unclosed '['".to_string())?;
+                let inner: String = chars[i + 1..close].iter().collect();
+                let inner = inner.trim();
+                let segment = if inner.is_empty() {
+                    Segment::Iterate
+                } else if inner.starts_with('"') {
+                    let inner_chars: Vec<char> = inner.chars().collect();
+                    let (name, end) = parse_string(&inner_chars, 0)?;
+                    if end != inner_chars.len() {
+                        return Err(format!("unexpected text in [{}]", inner));
+                    }
+                    Segment::Field(name)
+                } else {
+                    Segment::Index(
+                        inner
+                            .parse()
+                            .map_err(|_| format!("invalid index [{}]", inner))?,
+                    )
+                };
+                i = close + 1;
+                segment
+            }
+            c => return Err(format!("unexpected '{}'", c)),
+        };
+        let optional = chars.get(i) == Some(&'?');
+        if optional {
+            i += 1;
+        }
+        steps.push(Step { segment, optional });
+    }
+    Ok(steps)
+}
+
+/// Parse a double-quoted string starting at `start`; returns it and the index after it
+fn parse_string(chars: &[char], start: usize) -> Result<(String, usize), String> {
+    let mut out = String::new();
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '"' => return Ok((out, i + 1)),
+            '\\' if i + 1 < chars.len() => {
+                out.push(chars[i + 1]);
+                i += 2;
+            }
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+fn eval_path(path: &[Step], input: Value) -> Result<Vec<Value>, String> {
+    let mut values = vec![input];
+    for step in path {
+        let mut next = Vec::new();
+        for value in values {
+            match apply_segment(&step.segment, value) {
+                Ok(results) => next.extend(results),
+                Err(_) if step.optional => {}
+                Err(e) => return Err(e),
+            }
+        }
+        values = next;
+    }
+    Ok(values)
+}
+
+fn apply_segment(segment: &Segment, value: Value) -> Result<Vec<Value>, String> {
+    match (segment, value) {
+        (Segment::Field(_) | Segment::Index(_), Value::Null) => Ok(vec![Value::Null]),
+        (Segment::Field(name), Value::Object(mut map)) => {
+            Ok(vec![map.remove(name).unwrap_or(Value::Null)])
+        }
+        (Segment::Index(index), Value::Array(mut items)) => {
+            let len = items.len() as i64;
+            let index = if *index < 0 { len + index } else { *index };
+            if (0..len).contains(&index) {
+                Ok(vec![items.swap_remove(index as usize)])
+            } else {
+                Ok(vec![Value::Null])
+            }
+        }
+        (Segment::Iterate, Value::Array(items)) => Ok(items),
+        (Segment::Iterate, Value::Object(map)) => Ok(map.into_iter().map(|(_, v)| v).collect()),
+        (Segment::Field(name), other) => Err(format!(
+            "cannot index {} with \"{}\"",
+            type_name(&other),
+            name
+        )),
+        (Segment::Index(index), other) => {
+            Err(format!("cannot index {} with {}", type_name(&other), index))
+        }
+        (Segment::Iterate, other) => Err(format!("cannot iterate over {}", type_name(&other))),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_query_paths() {
+        let value = json!({
+            "response": {
+                "channels": [
+                    {"id": "C1", "name": "general", "topic": {"value": "hi"}},
+                    {"id": "C2", "name": "random", "is-private": true}
+                ]
+            },
+            "meta": {"profile_name": "acme"}
+        });
+        let eval = |expr: &str| Query::parse(expr).unwrap().evaluate(&value).unwrap();
+
+        assert_eq!(eval("."), vec![value.clone()]);
+        assert_eq!(
+            eval(".response.channels[].id"),
+            vec![json!("C1"), json!("C2")]
+        );
+        assert_eq!(eval(".response.channels[-1].name"), vec![json!("random")]);
+        assert_eq!(eval(".response.channels[5]"), vec![Value::Null]);
+        assert_eq!(
+            eval(".response.channels[1].\"is-private\""),
+            vec![json!(true)]
+        );
+        assert_eq!(eval(".meta[\"profile_name\"]"), vec![json!("acme")]);
+        assert_eq!(
+            eval(".response.channels | .[].topic.value"),
+            vec![json!("hi"), Value::Null]
+        );
+        assert_eq!(eval(".response.channels[].id.x?"), Vec::<Value>::new());
+        assert!(Query::parse(".response.channels[].id.x")
+            .unwrap()
+            .evaluate(&value)
+            .is_err());
+        assert!(Query::parse("response").is_err());
+        assert!(Query::parse(".a[").is_err());
+
+        assert_eq!(render_value(&json!("C1")), "C1");
+        assert_eq!(render_value(&json!({"a": 1})), "{\"a\":1}");
+    }
+}
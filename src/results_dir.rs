@@ -0,0 +1,229 @@
+//! Envelope artifacts for pipelines (`--results-dir`)
+//!
+//! With `--results-dir DIR`, every JSON envelope a command prints is also
+//! saved as `DIR/<timestamp>-<command>.json`, and `DIR/index.json` lists the
+//! saved files in the order they were written. Multi-step pipelines can
+//! point all steps at one directory and collect the results afterwards
+//! instead of redirecting each step's output.
+//!
+//! Only the newest files are kept (`--results-keep`, 100 by default); older
+//! result files and their index entries are removed after each write.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Index of the saved results, in the results directory
+pub const INDEX_FILE: &str = "index.json";
+
+/// Result files kept when `--results-keep` is not given
+pub const DEFAULT_KEEP: usize = 100;
+
+/// One saved envelope in `index.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultEntry {
+    pub file: String,
+    pub command: String,
+    pub ok: bool,
+    /// RFC 3339 time the file was written
+    pub written_at: String,
+    /// Exit code of the command that printed the envelope
+    pub exit_code: i32,
+}
+
+/// Contents of `index.json`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResultIndex {
+    #[serde(default)]
+    pub results: Vec<ResultEntry>,
+}
+
+/// File name for an envelope of `command` written at `at`
+///
+/// `msg post` becomes `20260101T120000.000Z-msg-post.json`; `seq` numbers
+/// the envelopes of one run after the first (`-2`, `-3`, ...) so a command
+/// printing several envelopes keeps them all.
+pub fn result_file_name(command: &str, at: DateTime<Utc>, seq: usize) -> String {
+    let slug: String = command
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_ascii_lowercase();
+    let slug = if slug.is_empty() { "result" } else { &slug };
+    let stamp = at.format("%Y%m%dT%H%M%S%.3fZ");
+    if seq > 1 {
+        format!("{}-{}-{}.json", stamp, slug, seq)
+    } else {
+        format!("{}-{}.json", stamp, slug)
+    }
+}
+
+/// Envelopes in a command's stdout
+///
+/// The output is read as a stream of JSON documents (pretty-printed or
+/// JSONL); objects with `schemaVersion` and `meta.command` are envelopes.
+/// Reading stops at the first text that is not JSON, so `--format table`
+/// or `--raw` output yields nothing.
+pub fn extract_envelopes(output: &[u8]) -> Vec<Value> {
+    serde_json::Deserializer::from_slice(output)
+        .into_iter::<Value>()
+        .map_while(Result::ok)
+        .filter(|value| {
+            value.get("schemaVersion").is_some()
+                && value
+                    .pointer("/meta/command")
+                    .and_then(Value::as_str)
+                    .is_some()
+        })
+        .collect()
+}
+
+/// Save `envelopes` in `dir`, update the index and apply the rotation limit
+///
+/// Returns the paths of the files written.
+pub fn write_results(
+    dir: &Path,
+    envelopes: &[Value],
+    exit_code: i32,
+    keep: usize,
+) -> Result<Vec<PathBuf>, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+    let index_path = dir.join(INDEX_FILE);
+    let mut index: ResultIndex = match fs::read_to_string(&index_path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| format!("{} is not a results index: {}", index_path.display(), e))?,
+        Err(_) => ResultIndex::default(),
+    };
+
+    let now = Utc::now();
+    let mut written = Vec::with_capacity(envelopes.len());
+    let mut seq = 0;
+    for envelope in envelopes {
+        let command = envelope
+            .pointer("/meta/command")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        // Never overwrite a result of a concurrent run in the same millisecond
+        let (file, path) = loop {
+            seq += 1;
+            let file = result_file_name(command, now, seq);
+            let path = dir.join(&file);
+            if !path.exists() {
+                break (file, path);
+            }
+        };
+        let content = serde_json::to_string_pretty(envelope).map_err(|e| e.to_string())?;
+        fs::write(&path, content + "\n")
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        index.results.push(ResultEntry {
+            file,
+            command: command.to_string(),
+            ok: envelope.get("ok").and_then(Value::as_bool).unwrap_or(false),
+            written_at: now.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            exit_code,
+        });
+        written.push(path);
+    }
+
+    // Entries whose file is gone were rotated out or removed by hand
+    index
+        .results
+        .retain(|entry| dir.join(&entry.file).is_file());
+    if index.results.len() > keep {
+        let excess = index.results.len() - keep;
+        for entry in index.results.drain(..excess) {
+            let _ = fs::remove_file(dir.join(&entry.file));
+        }
+    }
+
+    // Write the index atomically so readers never see a partial file
+    let content = serde_json::to_string_pretty(&index).map_err(|e| e.to_string())?;
+    let tmp_path = dir.join(format!("{}.tmp", INDEX_FILE));
+    fs::write(&tmp_path, content + "\n")
+        .and_then(|_| fs::rename(&tmp_path, &index_path))
+        .map_err(|e| format!("cannot write {}: {}", index_path.display(), e))?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn envelope(command: &str, ok: bool) -> Value {
+        json!({
+            "schemaVersion": 1,
+            "type": command,
+            "ok": ok,
+            "response": {"ok": ok},
+            "meta": {"command": command}
+        })
+    }
+
+    #[test]
+    fn test_result_file_name() {
+        let at = Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap();
+        assert_eq!(
+            result_file_name("msg post", at, 1),
+            "20260102T030405.000Z-msg-post.json"
+        );
+        assert_eq!(
+            result_file_name("chat.postMessage", at, 2),
+            "20260102T030405.000Z-chat-postmessage-2.json"
+        );
+        assert_eq!(
+            result_file_name("", at, 1),
+            "20260102T030405.000Z-result.json"
+        );
+    }
+
+    #[test]
+    fn test_extract_envelopes_from_pretty_and_jsonl_output() {
+        let pretty = serde_json::to_string_pretty(&envelope("msg post", true)).unwrap();
+        let jsonl = format!(
+            "{}\n{}\n{{\"plain\":true}}\n",
+            serde_json::to_string(&envelope("conv list", true)).unwrap(),
+            serde_json::to_string(&envelope("conv list", false)).unwrap()
+        );
+        assert_eq!(extract_envelopes(pretty.as_bytes()).len(), 1);
+        assert_eq!(extract_envelopes(jsonl.as_bytes()).len(), 2);
+        assert!(extract_envelopes(b"CHANNEL  NAME\nC1  general\n").is_empty());
+    }
+
+    #[test]
+    fn test_write_results_indexes_and_rotates() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("results");
+
+        let first = write_results(&dir, &[envelope("msg post", true)], 0, 2).unwrap();
+        assert_eq!(first.len(), 1);
+        let saved: Value = serde_json::from_str(&fs::read_to_string(&first[0]).unwrap()).unwrap();
+        assert_eq!(saved["meta"]["command"], "msg post");
+
+        let second = write_results(
+            &dir,
+            &[envelope("conv list", true), envelope("conv list", false)],
+            1,
+            2,
+        )
+        .unwrap();
+        assert!(second[1].to_string_lossy().ends_with("-conv-list-2.json"));
+
+        // The oldest file was rotated out
+        assert!(!first[0].exists());
+        let index: ResultIndex =
+            serde_json::from_str(&fs::read_to_string(dir.join(INDEX_FILE)).unwrap()).unwrap();
+        assert_eq!(index.results.len(), 2);
+        assert_eq!(index.results[0].command, "conv list");
+        assert!(index.results[0].ok);
+        assert!(!index.results[1].ok);
+        assert_eq!(index.results[1].exit_code, 1);
+        assert!(!dir.join("index.json.tmp").exists());
+    }
+}
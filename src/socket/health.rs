@@ -0,0 +1,282 @@
+//! Health endpoint for Socket Mode listeners
+//!
+//! With `--health-addr`, a listener serves `GET /healthz` (also `/metrics`)
+//! in the Prometheus text format, so it can be probed by Kubernetes,
+//! systemd watchdogs or scraped directly:
+//!
+//! ```text
+//! slack_rs_up 1
+//! slack_rs_uptime_seconds 3612
+//! slack_rs_connected 1
+//! slack_rs_last_event_timestamp_seconds 1767225600
+//! slack_rs_reconnects_total 2
+//! slack_rs_token_healthy 1
+//! ```
+//!
+//! `/healthz` answers 200 while the socket is connected and the token is
+//! accepted, 503 otherwise; `/metrics` always answers 200.
+
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+#[derive(Debug)]
+struct HealthInner {
+    started_at: Instant,
+    connected: AtomicBool,
+    last_event_at: AtomicU64,
+    reconnects: AtomicU64,
+    /// Slack error of the last rejected token, `None` while healthy
+    token_error: Mutex<Option<String>>,
+}
+
+/// Shared listener state reported by the health endpoint
+#[derive(Debug, Clone)]
+pub struct Health {
+    inner: Arc<HealthInner>,
+}
+
+/// Point-in-time copy of [`Health`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthSnapshot {
+    pub uptime_secs: u64,
+    pub connected: bool,
+    /// Unix time of the last event, 0 before the first one
+    pub last_event_at: u64,
+    pub reconnects: u64,
+    pub token_error: Option<String>,
+}
+
+impl HealthSnapshot {
+    /// Whether `/healthz` should answer 200
+    pub fn is_healthy(&self) -> bool {
+        self.connected && self.token_error.is_none()
+    }
+
+    /// Prometheus text exposition of the snapshot
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: u64| {
+            out.push_str(&format!(
+                "# HELP {0} {1}\n# TYPE {0} {2}\n{0} {3}\n",
+                name, help, kind, value
+            ));
+        };
+        metric("slack_rs_up", "gauge", "Listener process is running", 1);
+        metric(
+            "slack_rs_uptime_seconds",
+            "gauge",
+            "Seconds since the listener started",
+            self.uptime_secs,
+        );
+        metric(
+            "slack_rs_connected",
+            "gauge",
+            "Socket Mode connection is open",
+            u64::from(self.connected),
+        );
+        metric(
+            "slack_rs_last_event_timestamp_seconds",
+            "gauge",
+            "Unix time of the last received event (0 if none)",
+            self.last_event_at,
+        );
+        metric(
+            "slack_rs_reconnects_total",
+            "counter",
+            "Reconnects since the listener started",
+            self.reconnects,
+        );
+        metric(
+            "slack_rs_token_healthy",
+            "gauge",
+            "App-level token was accepted by Slack",
+            u64::from(self.token_error.is_none()),
+        );
+        if let Some(error) = &self.token_error {
+            out.push_str(&format!("# token_error {}\n", error));
+        }
+        out
+    }
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Health {
+    /// Create health state, starting the uptime clock
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(HealthInner {
+                started_at: Instant::now(),
+                connected: AtomicBool::new(false),
+                last_event_at: AtomicU64::new(0),
+                reconnects: AtomicU64::new(0),
+                token_error: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Record that the socket is (or is no longer) connected
+    pub fn set_connected(&self, connected: bool) {
+        self.inner.connected.store(connected, Ordering::Relaxed);
+        if connected {
+            *self.inner.token_error.lock().unwrap() = None;
+        }
+    }
+
+    /// Record a received event
+    pub fn record_event(&self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.inner.last_event_at.store(now, Ordering::Relaxed);
+    }
+
+    /// Record a reconnect (the connection is down until the next hello)
+    pub fn record_reconnect(&self) {
+        self.inner.reconnects.fetch_add(1, Ordering::Relaxed);
+        self.inner.connected.store(false, Ordering::Relaxed);
+    }
+
+    /// Record that Slack rejected the token
+    pub fn record_token_error(&self, error: &str) {
+        *self.inner.token_error.lock().unwrap() = Some(error.to_string());
+        self.inner.connected.store(false, Ordering::Relaxed);
+    }
+
+    /// Current values
+    pub fn snapshot(&self) -> HealthSnapshot {
+        HealthSnapshot {
+            uptime_secs: self.inner.started_at.elapsed().as_secs(),
+            connected: self.inner.connected.load(Ordering::Relaxed),
+            last_event_at: self.inner.last_event_at.load(Ordering::Relaxed),
+            reconnects: self.inner.reconnects.load(Ordering::Relaxed),
+            token_error: self.inner.token_error.lock().unwrap().clone(),
+        }
+    }
+}
+
+/// Build the HTTP response for a request path
+fn health_response(path: &str, health: &Health) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+    let snapshot = health.snapshot();
+    let (status, body) = match path {
+        "/healthz" if snapshot.is_healthy() => ("200 OK", snapshot.to_prometheus()),
+        "/healthz" => ("503 Service Unavailable", snapshot.to_prometheus()),
+        "/metrics" => ("200 OK", snapshot.to_prometheus()),
+        _ => ("404 Not Found", "Not found\n".to_string()),
+    };
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+/// Bind `addr` and serve the health endpoint in the background
+///
+/// # Returns
+/// * `Ok((SocketAddr, JoinHandle))` with the bound address
+/// * `Err(String)` if the address cannot be bound
+pub async fn serve_health(
+    addr: &str,
+    health: Health,
+) -> Result<(SocketAddr, JoinHandle<()>), String> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind health endpoint on {}: {}", addr, e))?;
+    let local_addr = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to bind health endpoint on {}: {}", addr, e))?;
+
+    let handle = tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                continue;
+            };
+            let health = health.clone();
+            tokio::spawn(async move {
+                let mut buffer = vec![0; 1024];
+                let n = match socket.read(&mut buffer).await {
+                    Ok(n) if n > 0 => n,
+                    _ => return,
+                };
+                let request = String::from_utf8_lossy(&buffer[..n]);
+                let path = request
+                    .lines()
+                    .next()
+                    .and_then(|line| line.split_whitespace().nth(1))
+                    .unwrap_or("/");
+                let _ = socket
+                    .write_all(health_response(path, &health).as_bytes())
+                    .await;
+                let _ = socket.shutdown().await;
+            });
+        }
+    });
+    Ok((local_addr, handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_snapshot() {
+        let health = Health::new();
+        assert!(!health.snapshot().is_healthy());
+
+        health.set_connected(true);
+        health.record_event();
+        health.record_reconnect();
+        health.set_connected(true);
+        let snapshot = health.snapshot();
+        assert!(snapshot.is_healthy());
+        assert_eq!(snapshot.reconnects, 1);
+        assert!(snapshot.last_event_at > 0);
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("slack_rs_reconnects_total 1\n"));
+        assert!(text.contains("# TYPE slack_rs_reconnects_total counter\n"));
+
+        health.record_token_error("token_revoked");
+        let snapshot = health.snapshot();
+        assert!(!snapshot.is_healthy());
+        assert!(snapshot
+            .to_prometheus()
+            .contains("slack_rs_token_healthy 0\n"));
+    }
+
+    #[tokio::test]
+    async fn test_serve_health() {
+        let health = Health::new();
+        let (addr, server) = serve_health("127.0.0.1:0", health.clone()).await.unwrap();
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+        let url = |path: &str| format!("http://{}{}", addr, path);
+
+        let response = client.get(url("/healthz")).send().await.unwrap();
+        assert_eq!(response.status().as_u16(), 503);
+
+        health.set_connected(true);
+        let response = client.get(url("/healthz")).send().await.unwrap();
+        assert_eq!(response.status().as_u16(), 200);
+        assert!(response
+            .text()
+            .await
+            .unwrap()
+            .contains("slack_rs_connected 1\n"));
+
+        let response = client.get(url("/other")).send().await.unwrap();
+        assert_eq!(response.status().as_u16(), 404);
+        server.abort();
+    }
+}
@@ -0,0 +1,267 @@
+//! Heartbeat signalling for Socket Mode listeners
+//!
+//! On every received event or ping, a listener calls [`Heartbeat::signal`],
+//! which beats in the background at most once per [`BEAT_INTERVAL`]:
+//! - Touch a file (`--heartbeat-file`), writing the current Unix timestamp
+//! - Ping a URL (`--heartbeat-url`) with a GET request
+//!
+//! Supervisors can then detect dead listeners by checking the file's mtime
+//! or by the absence of pings on their uptime monitor. A slow monitor never
+//! holds up the listener, which must acknowledge events within 3 seconds.
+
+use reqwest::Client;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Timeout for heartbeat URL pings
+const PING_TIMEOUT_SECS: u64 = 10;
+
+/// Minimum time between two beats started by [`Heartbeat::signal`]
+pub const BEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Error)]
+pub enum HeartbeatError {
+    #[error("Failed to write heartbeat file {path}: {source}")]
+    File {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Heartbeat ping failed: {0}")]
+    Ping(#[from] reqwest::Error),
+
+    #[error("Heartbeat ping returned HTTP {0}")]
+    PingStatus(u16),
+}
+
+/// Heartbeat destinations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HeartbeatConfig {
+    /// File to touch on every beat
+    pub file: Option<PathBuf>,
+    /// URL to ping on every beat
+    pub url: Option<String>,
+}
+
+impl HeartbeatConfig {
+    /// Whether any heartbeat destination is configured
+    pub fn is_enabled(&self) -> bool {
+        self.file.is_some() || self.url.is_some()
+    }
+}
+
+/// Heartbeat emitter
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    config: HeartbeatConfig,
+    client: Client,
+    /// Start of the last background beat and whether it is still running
+    last_signal: Arc<Mutex<Option<(Instant, bool)>>>,
+}
+
+impl Heartbeat {
+    /// Create a heartbeat emitter for the given destinations
+    pub fn new(config: HeartbeatConfig) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(PING_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            client,
+            last_signal: Arc::default(),
+        }
+    }
+
+    /// Get the configured destinations
+    pub fn config(&self) -> &HeartbeatConfig {
+        &self.config
+    }
+
+    /// Signal liveness to all configured destinations
+    ///
+    /// Both destinations are attempted even if the first one fails;
+    /// the first error encountered is returned.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every configured destination was signalled
+    /// * `Err(HeartbeatError)` if touching the file or pinging the URL failed
+    pub async fn beat(&self) -> Result<(), HeartbeatError> {
+        let file_result = match &self.config.file {
+            Some(path) => touch(path),
+            None => Ok(()),
+        };
+
+        let url_result = match &self.config.url {
+            Some(url) => self.ping(url).await,
+            None => Ok(()),
+        };
+
+        file_result.and(url_result)
+    }
+
+    /// Beat in a background task without waiting for it
+    ///
+    /// Signals arriving while a beat is still running, or within
+    /// [`BEAT_INTERVAL`] of the last one, are dropped. Failures are printed
+    /// as warnings. Returns whether a beat was started; must be called
+    /// inside a Tokio runtime.
+    pub fn signal(&self) -> bool {
+        let now = Instant::now();
+        {
+            let mut last = self.last_signal.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((started, running)) = *last {
+                if running || now.duration_since(started) < BEAT_INTERVAL {
+                    return false;
+                }
+            }
+            *last = Some((now, true));
+        }
+
+        let heartbeat = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = heartbeat.beat().await {
+                crate::gha::warn(&format!("Warning: {}", e));
+            }
+            let mut last = heartbeat
+                .last_signal
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            if let Some((_, running)) = last.as_mut() {
+                *running = false;
+            }
+        });
+        true
+    }
+
+    async fn ping(&self, url: &str) -> Result<(), HeartbeatError> {
+        let response = self.client.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(HeartbeatError::PingStatus(response.status().as_u16()));
+        }
+        Ok(())
+    }
+}
+
+/// Write the current Unix timestamp to the heartbeat file (updates mtime)
+fn touch(path: &Path) -> Result<(), HeartbeatError> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    fs::write(path, format!("{}\n", now)).map_err(|e| HeartbeatError::File {
+        path: path.display().to_string(),
+        source: e,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn test_config_is_enabled() {
+        assert!(!HeartbeatConfig::default().is_enabled());
+        assert!(HeartbeatConfig {
+            file: Some(PathBuf::from("/tmp/hb")),
+            url: None,
+        }
+        .is_enabled());
+    }
+
+    #[tokio::test]
+    async fn test_beat_touches_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("heartbeat");
+        let heartbeat = Heartbeat::new(HeartbeatConfig {
+            file: Some(file.clone()),
+            url: None,
+        });
+
+        heartbeat.beat().await.unwrap();
+
+        let content = fs::read_to_string(&file).unwrap();
+        assert!(content.trim().parse::<u64>().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_beat_pings_url() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ping"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let heartbeat = Heartbeat::new(HeartbeatConfig {
+            file: None,
+            url: Some(format!("{}/ping", mock_server.uri())),
+        });
+
+        heartbeat.beat().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_signal_beats_in_background_once_per_interval() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(300)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("heartbeat");
+        let heartbeat = Heartbeat::new(HeartbeatConfig {
+            file: Some(file.clone()),
+            url: Some(mock_server.uri()),
+        });
+
+        // Returns before the slow ping answers
+        let started = Instant::now();
+        assert!(heartbeat.signal());
+        assert!(started.elapsed() < Duration::from_millis(200));
+        // Further signals within the interval are dropped
+        assert!(!heartbeat.signal());
+        assert!(!heartbeat.signal());
+
+        for _ in 0..50 {
+            if file.exists() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(file.exists());
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert!(!heartbeat.signal());
+    }
+
+    #[tokio::test]
+    async fn test_beat_reports_ping_status() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&mock_server)
+            .await;
+
+        let heartbeat = Heartbeat::new(HeartbeatConfig {
+            file: None,
+            url: Some(mock_server.uri()),
+        });
+
+        assert!(matches!(
+            heartbeat.beat().await,
+            Err(HeartbeatError::PingStatus(503))
+        ));
+    }
+}
@@ -0,0 +1,618 @@
+//! Socket Mode event listener
+//!
+//! Opens a WebSocket via `apps.connections.open` with an app-level
+//! (`xapp-`) token, acknowledges every envelope and hands the payloads to
+//! the caller. Connections are reopened after Slack asks to disconnect or
+//! the socket drops, with exponential backoff and jitter between failed
+//! attempts. Envelopes Slack redelivers after a reconnect are acknowledged
+//! again but emitted only once.
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio_tungstenite::tungstenite::Message;
+
+use super::health::Health;
+use super::heartbeat::Heartbeat;
+use crate::api::{ApiClient, ApiError, ApiMethod};
+
+/// First reconnect delay after a failed connection
+const INITIAL_RECONNECT_DELAY_SECS: u64 = 1;
+
+/// Upper bound for the reconnect delay
+const MAX_RECONNECT_DELAY_SECS: u64 = 30;
+
+/// Envelope IDs remembered for redelivery detection
+const DEDUP_CAPACITY: usize = 1000;
+
+#[derive(Debug, Error)]
+pub enum ListenerError {
+    #[error("Socket Mode requires an app-level token (xapp-...)")]
+    NotAppToken,
+
+    #[error("Failed to open Socket Mode connection: {0}")]
+    Open(#[from] ApiError),
+
+    #[error("apps.connections.open returned no url")]
+    MissingUrl,
+
+    #[error("WebSocket error: {0}")]
+    WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
+
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
+
+    #[error("Connection closed")]
+    Closed,
+
+    #[error("Socket Mode was disabled for this app (link_disabled)")]
+    LinkDisabled,
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for ListenerError {
+    fn from(e: tokio_tungstenite::tungstenite::Error) -> Self {
+        ListenerError::WebSocket(Box::new(e))
+    }
+}
+
+/// Condition on received events (`key=value`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventFilter {
+    /// Inner event type (`message`, `app_mention`, ...)
+    EventType(String),
+    /// Envelope type (`events_api`, `interactive`, `slash_commands`)
+    EnvelopeType(String),
+    /// Channel ID of the inner event
+    Channel(String),
+    /// User ID of the inner event
+    User(String),
+}
+
+impl EventFilter {
+    /// Parse a filter from "key=value"
+    pub fn parse(s: &str) -> Result<Self, ListenerError> {
+        let (key, value) = s.split_once('=').ok_or_else(|| {
+            ListenerError::InvalidFilter(format!("Expected format 'key=value', got '{}'", s))
+        })?;
+        match key {
+            "event_type" => Ok(EventFilter::EventType(value.to_string())),
+            "envelope_type" => Ok(EventFilter::EnvelopeType(value.to_string())),
+            "channel" => Ok(EventFilter::Channel(value.to_string())),
+            "user" => Ok(EventFilter::User(value.to_string())),
+            _ => Err(ListenerError::InvalidFilter(format!(
+                "Unknown filter key: {} (valid: event_type, envelope_type, channel, user)",
+                key
+            ))),
+        }
+    }
+
+    /// Apply the filter to a received event
+    pub fn matches(&self, event: &ReceivedEvent) -> bool {
+        let inner = |key: &str| event.inner_event().get(key).and_then(|v| v.as_str());
+        match self {
+            EventFilter::EventType(t) => event.event_type() == Some(t.as_str()),
+            EventFilter::EnvelopeType(t) => event.envelope_type == *t,
+            EventFilter::Channel(c) => inner("channel") == Some(c.as_str()),
+            EventFilter::User(u) => inner("user") == Some(u.as_str()),
+        }
+    }
+}
+
+/// A payload delivered over Socket Mode
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceivedEvent {
+    pub envelope_type: String,
+    pub envelope_id: Option<String>,
+    pub payload: Value,
+}
+
+impl ReceivedEvent {
+    /// The inner event (`payload.event` for Events API, otherwise the payload)
+    pub fn inner_event(&self) -> &Value {
+        self.payload.get("event").unwrap_or(&self.payload)
+    }
+
+    /// Type of the inner event
+    pub fn event_type(&self) -> Option<&str> {
+        self.inner_event().get("type").and_then(|v| v.as_str())
+    }
+
+    /// NDJSON representation printed by `events listen`
+    pub fn to_json(&self) -> Value {
+        json!({
+            "envelope_type": self.envelope_type,
+            "envelope_id": self.envelope_id,
+            "event_type": self.event_type(),
+            "payload": self.payload,
+        })
+    }
+}
+
+/// What to do with one WebSocket text frame
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FrameAction {
+    /// `hello`: connection is ready
+    Ready,
+    /// `disconnect`: Slack asks the client to reconnect
+    Reconnect(String),
+    /// Acknowledge (if an envelope ID is present) and emit the event
+    Event(ReceivedEvent),
+    /// Unparseable or unknown frame
+    Ignore,
+}
+
+/// Interpret a Socket Mode text frame
+pub(crate) fn handle_frame(text: &str) -> FrameAction {
+    let Ok(frame) = serde_json::from_str::<Value>(text) else {
+        return FrameAction::Ignore;
+    };
+    let Some(frame_type) = frame.get("type").and_then(|v| v.as_str()) else {
+        return FrameAction::Ignore;
+    };
+
+    match frame_type {
+        "hello" => FrameAction::Ready,
+        "disconnect" => FrameAction::Reconnect(
+            frame
+                .get("reason")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown")
+                .to_string(),
+        ),
+        _ => FrameAction::Event(ReceivedEvent {
+            envelope_type: frame_type.to_string(),
+            envelope_id: frame
+                .get("envelope_id")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            payload: frame.get("payload").cloned().unwrap_or(Value::Null),
+        }),
+    }
+}
+
+/// Acknowledgement frame for an envelope
+pub(crate) fn ack_frame(envelope_id: &str) -> String {
+    json!({ "envelope_id": envelope_id }).to_string()
+}
+
+/// Recently seen envelope IDs, oldest evicted first
+#[derive(Debug)]
+pub(crate) struct EnvelopeDedup {
+    seen: HashSet<String>,
+    order: VecDeque<String>,
+    capacity: usize,
+}
+
+impl Default for EnvelopeDedup {
+    fn default() -> Self {
+        Self::with_capacity(DEDUP_CAPACITY)
+    }
+}
+
+impl EnvelopeDedup {
+    /// Remember up to `capacity` IDs
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Record an envelope ID; `false` if it was already seen
+    pub fn insert(&mut self, envelope_id: &str) -> bool {
+        if self.seen.contains(envelope_id) {
+            return false;
+        }
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(envelope_id.to_string());
+        self.order.push_back(envelope_id.to_string());
+        true
+    }
+}
+
+/// Counters kept across connections
+#[derive(Debug, Default)]
+struct ListenerState {
+    emitted: usize,
+    reconnects: u64,
+    duplicates: u64,
+    dedup: EnvelopeDedup,
+    /// When and why the previous connection ended, until the next hello
+    down_since: Option<(Instant, String)>,
+}
+
+impl ListenerState {
+    fn connection_lost(&mut self, reason: String) {
+        self.reconnects += 1;
+        self.down_since.get_or_insert((Instant::now(), reason));
+    }
+
+    /// Log the reconnect once the new connection says hello
+    fn connection_ready(&mut self) {
+        if let Some((since, reason)) = self.down_since.take() {
+            eprintln!(
+                "Socket Mode reconnected after {} in {}ms (reconnects: {}, redelivered envelopes skipped: {})",
+                reason,
+                since.elapsed().as_millis(),
+                self.reconnects,
+                self.duplicates
+            );
+        }
+    }
+}
+
+/// Listener settings
+#[derive(Debug, Clone, Default)]
+pub struct ListenerOptions {
+    /// Only events matching all filters are emitted (all are acknowledged)
+    pub filters: Vec<EventFilter>,
+    /// Stop after emitting this many events
+    pub max_events: Option<usize>,
+    /// Reconnect after disconnects and connection errors
+    pub reconnect: bool,
+    /// State reported by the health endpoint
+    pub health: Option<Health>,
+}
+
+/// Open a Socket Mode WebSocket URL
+pub(crate) async fn open_connection(client: &ApiClient) -> Result<String, ListenerError> {
+    let response = client
+        .call_method(ApiMethod::AppsConnectionsOpen, HashMap::new())
+        .await?;
+    response
+        .data
+        .get("url")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or(ListenerError::MissingUrl)
+}
+
+/// Delay before reconnect attempt `attempt` (0-based)
+pub(crate) fn reconnect_delay(attempt: u32) -> Duration {
+    let secs = INITIAL_RECONNECT_DELAY_SECS
+        .saturating_mul(2_u64.saturating_pow(attempt))
+        .min(MAX_RECONNECT_DELAY_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Spread a delay by ±25% so many listeners do not reconnect in lockstep
+pub(crate) fn with_jitter(delay: Duration) -> Duration {
+    let millis = delay.as_millis() as u64;
+    let jitter = millis / 4;
+    let offset = rand::random::<u64>() % (jitter * 2 + 1);
+    Duration::from_millis(millis - jitter + offset)
+}
+
+/// Run the listener until `max_events` is reached or the connection ends
+///
+/// # Arguments
+/// * `client` - API client holding the app-level token
+/// * `options` - Filters, event limit and reconnect behaviour
+/// * `heartbeat` - Signalled in the background on hello, ping and event frames
+/// * `on_event` - Called for every event that passes the filters
+pub async fn listen<F>(
+    client: &ApiClient,
+    options: &ListenerOptions,
+    heartbeat: Option<&Heartbeat>,
+    mut on_event: F,
+) -> Result<(), ListenerError>
+where
+    F: FnMut(&ReceivedEvent),
+{
+    if !client
+        .token
+        .as_deref()
+        .is_some_and(|t| t.starts_with("xapp-"))
+    {
+        return Err(ListenerError::NotAppToken);
+    }
+
+    let mut state = ListenerState::default();
+    let mut failures = 0;
+
+    loop {
+        let result = run_connection(client, options, heartbeat, &mut on_event, &mut state).await;
+        if options.max_events.is_some_and(|max| state.emitted >= max) {
+            return Ok(());
+        }
+
+        match result {
+            // Slack-requested disconnect: reconnect immediately
+            Ok(reason) => {
+                crate::debug::log(format!("Socket Mode disconnect: {}", reason));
+                failures = 0;
+                if !options.reconnect {
+                    return Ok(());
+                }
+                state.connection_lost(format!("disconnect ({})", reason));
+                if let Some(health) = &options.health {
+                    health.record_reconnect();
+                }
+            }
+            Err(ListenerError::NotAppToken) => return Err(ListenerError::NotAppToken),
+            Err(ListenerError::LinkDisabled) => {
+                if let Some(health) = &options.health {
+                    health.set_connected(false);
+                }
+                return Err(ListenerError::LinkDisabled);
+            }
+            // Bad credentials will not fix themselves
+            Err(ListenerError::Open(ApiError::SlackError(code)))
+                if matches!(
+                    code.as_str(),
+                    "invalid_auth" | "not_authed" | "token_revoked" | "not_allowed_token_type"
+                ) =>
+            {
+                if let Some(health) = &options.health {
+                    health.record_token_error(&code);
+                }
+                return Err(ListenerError::Open(ApiError::SlackError(code)));
+            }
+            Err(e) => {
+                if !options.reconnect {
+                    return Err(e);
+                }
+                state.connection_lost(e.to_string());
+                if let Some(health) = &options.health {
+                    health.record_reconnect();
+                }
+                let delay = with_jitter(reconnect_delay(failures));
+                crate::gha::warn(&format!(
+                    "Warning: {}; reconnecting in {:.1}s",
+                    e,
+                    delay.as_secs_f64()
+                ));
+                tokio::time::sleep(delay).await;
+                failures += 1;
+            }
+        }
+    }
+}
+
+/// Handle one connection; returns the disconnect reason
+async fn run_connection<F>(
+    client: &ApiClient,
+    options: &ListenerOptions,
+    heartbeat: Option<&Heartbeat>,
+    on_event: &mut F,
+    state: &mut ListenerState,
+) -> Result<String, ListenerError>
+where
+    F: FnMut(&ReceivedEvent),
+{
+    let url = open_connection(client).await?;
+    let (mut socket, _) = tokio_tungstenite::connect_async(url.as_str()).await?;
+
+    while let Some(message) = socket.next().await {
+        let text = match message? {
+            Message::Text(text) => text,
+            Message::Ping(data) => {
+                socket.send(Message::Pong(data)).await?;
+                beat(heartbeat);
+                continue;
+            }
+            Message::Close(_) => return Err(ListenerError::Closed),
+            _ => continue,
+        };
+
+        match handle_frame(text.as_str()) {
+            FrameAction::Ready => {
+                state.connection_ready();
+                if let Some(health) = &options.health {
+                    health.set_connected(true);
+                }
+                beat(heartbeat);
+            }
+            FrameAction::Reconnect(reason) => {
+                let _ = socket.close(None).await;
+                if reason == "link_disabled" {
+                    return Err(ListenerError::LinkDisabled);
+                }
+                return Ok(reason);
+            }
+            FrameAction::Event(event) => {
+                if let Some(ref id) = event.envelope_id {
+                    // Ack redeliveries too, or Slack keeps retrying them
+                    socket.send(Message::text(ack_frame(id))).await?;
+                    if !state.dedup.insert(id) {
+                        state.duplicates += 1;
+                        crate::debug::log(format!("Skipping redelivered envelope {}", id));
+                        continue;
+                    }
+                }
+                if let Some(health) = &options.health {
+                    health.record_event();
+                }
+                beat(heartbeat);
+
+                if options.filters.iter().all(|f| f.matches(&event)) {
+                    on_event(&event);
+                    state.emitted += 1;
+                    if options.max_events.is_some_and(|max| state.emitted >= max) {
+                        let _ = socket.close(None).await;
+                        return Ok("max events reached".to_string());
+                    }
+                }
+            }
+            FrameAction::Ignore => {}
+        }
+    }
+
+    Err(ListenerError::Closed)
+}
+
+/// Signal the heartbeat in the background; never delays the receive/ack loop
+fn beat(heartbeat: Option<&Heartbeat>) {
+    if let Some(heartbeat) = heartbeat {
+        heartbeat.signal();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_frame() {
+        assert_eq!(
+            handle_frame(r#"{"type":"hello","num_connections":1}"#),
+            FrameAction::Ready
+        );
+        assert_eq!(
+            handle_frame(r#"{"type":"disconnect","reason":"refresh_requested"}"#),
+            FrameAction::Reconnect("refresh_requested".to_string())
+        );
+        assert_eq!(handle_frame("not json"), FrameAction::Ignore);
+
+        let FrameAction::Event(event) = handle_frame(
+            r#"{"type":"events_api","envelope_id":"E1","payload":{"event":{"type":"message","channel":"C1","user":"U1"}}}"#,
+        ) else {
+            panic!("expected event");
+        };
+        assert_eq!(event.envelope_id.as_deref(), Some("E1"));
+        assert_eq!(event.event_type(), Some("message"));
+        assert_eq!(event.to_json()["event_type"], "message");
+        assert_eq!(ack_frame("E1"), r#"{"envelope_id":"E1"}"#);
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let event = ReceivedEvent {
+            envelope_type: "events_api".to_string(),
+            envelope_id: Some("E1".to_string()),
+            payload: json!({"event": {"type": "message", "channel": "C1", "user": "U1"}}),
+        };
+        assert!(EventFilter::parse("event_type=message")
+            .unwrap()
+            .matches(&event));
+        assert!(EventFilter::parse("channel=C1").unwrap().matches(&event));
+        assert!(!EventFilter::parse("user=U2").unwrap().matches(&event));
+        assert!(!EventFilter::parse("envelope_type=interactive")
+            .unwrap()
+            .matches(&event));
+        assert!(EventFilter::parse("event_type:message").is_err());
+        assert!(EventFilter::parse("team=T1").is_err());
+    }
+
+    #[test]
+    fn test_reconnect_delay() {
+        assert_eq!(reconnect_delay(0), Duration::from_secs(1));
+        assert_eq!(reconnect_delay(3), Duration::from_secs(8));
+        assert_eq!(reconnect_delay(20), Duration::from_secs(30));
+        for _ in 0..20 {
+            let delay = with_jitter(Duration::from_secs(8));
+            assert!(delay >= Duration::from_secs(6) && delay <= Duration::from_secs(10));
+        }
+    }
+
+    #[test]
+    fn test_envelope_dedup() {
+        let mut dedup = EnvelopeDedup::with_capacity(2);
+        assert!(dedup.insert("E1"));
+        assert!(!dedup.insert("E1"));
+        assert!(dedup.insert("E2"));
+        assert!(dedup.insert("E3"));
+        // E1 was evicted, E2 is still remembered
+        assert!(dedup.insert("E1"));
+        assert!(!dedup.insert("E3"));
+    }
+
+    #[tokio::test]
+    async fn test_listen_requires_app_token() {
+        let client = ApiClient::with_token("xoxb-bot".to_string());
+        let result = listen(&client, &ListenerOptions::default(), None, |_| {}).await;
+        assert!(matches!(result, Err(ListenerError::NotAppToken)));
+    }
+
+    #[tokio::test]
+    async fn test_listen_reconnects_and_skips_redelivered_envelopes() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Each connection says hello and sends E1; the first also asks to reconnect
+        let ws = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_addr = ws.local_addr().unwrap();
+        tokio::spawn(async move {
+            for connection in 0..2 {
+                let (stream, _) = ws.accept().await.unwrap();
+                let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let event = |id: &str| {
+                    json!({"type": "events_api", "envelope_id": id,
+                        "payload": {"event": {"type": "message"}}})
+                    .to_string()
+                };
+                socket
+                    .send(Message::text(r#"{"type":"hello"}"#))
+                    .await
+                    .unwrap();
+                socket.send(Message::text(event("E1"))).await.unwrap();
+                if connection == 0 {
+                    socket
+                        .send(Message::text(
+                            r#"{"type":"disconnect","reason":"refresh_requested"}"#,
+                        ))
+                        .await
+                        .unwrap();
+                } else {
+                    socket.send(Message::text(event("E2"))).await.unwrap();
+                }
+                while let Some(Ok(_)) = socket.next().await {}
+            }
+        });
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/apps.connections.open"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "url": format!("ws://{}", ws_addr)
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("xapp-1".to_string(), mock_server.uri());
+        let options = ListenerOptions {
+            max_events: Some(2),
+            reconnect: true,
+            ..ListenerOptions::default()
+        };
+        let mut ids = Vec::new();
+        listen(&client, &options, None, |event| {
+            ids.push(event.envelope_id.clone().unwrap())
+        })
+        .await
+        .unwrap();
+        assert_eq!(ids, vec!["E1", "E2"]);
+    }
+
+    #[tokio::test]
+    async fn test_open_connection() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/apps.connections.open"))
+            .and(header("authorization", "Bearer xapp-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ok": true,
+                "url": "wss://wss-primary.slack.com/link/?ticket=abc"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new_with_base_url("xapp-1".to_string(), mock_server.uri());
+        assert_eq!(
+            open_connection(&client).await.unwrap(),
+            "wss://wss-primary.slack.com/link/?ticket=abc"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Socket Mode support
+//!
+//! Building blocks for long-running Socket Mode listeners:
+//! - Heartbeat signalling for process supervisors and uptime monitors
+//! - A Prometheus-style `/healthz` endpoint (`--health-addr`)
+//! - The event listener behind `events listen` (ack, filters, reconnect,
+//!   redelivery dedup)
+
+pub mod health;
+pub mod heartbeat;
+pub mod listener;
+
+pub use health::{serve_health, Health, HealthSnapshot};
+pub use heartbeat::{Heartbeat, HeartbeatConfig, HeartbeatError};
+pub(crate) use listener::open_connection;
+pub use listener::{listen, EventFilter, ListenerError, ListenerOptions, ReceivedEvent};
@@ -0,0 +1,490 @@
+//! Bounded output queue for streaming commands
+//!
+//! `events listen` and `conv tail` print one JSON line per event for as
+//! long as they run. Lines go through an [`OutputQueue`] drained by a
+//! writer thread, so a stalled stdout consumer cannot grow memory without
+//! bound. When the queue is full, the [`OverflowPolicy`] decides:
+//!
+//! - `block`: wait for the consumer (the default; Socket Mode stops reading)
+//! - `drop-oldest`: discard the oldest queued line and count it
+//! - `spill`: append to a file on disk and replay it in order later
+//!
+//! While lines are dropped or spilled, the writer interleaves a meta line at
+//! most once per interval (and once more at exit):
+//!
+//! ```text
+//! {"meta":"stream","dropped":12,"spilled":0,"queued":1000}
+//! ```
+
+use serde_json::json;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Default number of lines held in memory
+pub const DEFAULT_QUEUE_SIZE: usize = 1000;
+
+/// Default seconds between meta lines
+pub const DEFAULT_META_INTERVAL_SECS: u64 = 10;
+
+/// What to do with a line when the queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Wait until the consumer catches up
+    #[default]
+    Block,
+    /// Discard the oldest queued line
+    DropOldest,
+    /// Append to the spill file and replay it after the queue drains
+    Spill,
+}
+
+impl OverflowPolicy {
+    /// Parse an `--overflow` value
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "block" => Ok(OverflowPolicy::Block),
+            "drop-oldest" | "drop_oldest" => Ok(OverflowPolicy::DropOldest),
+            "spill" => Ok(OverflowPolicy::Spill),
+            _ => Err(format!(
+                "Invalid overflow policy '{}'. Valid values: block, drop-oldest, spill",
+                s
+            )),
+        }
+    }
+}
+
+/// Queue settings
+#[derive(Debug, Clone)]
+pub struct StreamConfig {
+    /// Lines held in memory before the overflow policy applies
+    pub capacity: usize,
+    pub policy: OverflowPolicy,
+    /// Spill file (default: a per-process file in the temp directory)
+    pub spill_path: Option<PathBuf>,
+    /// Minimum time between meta lines
+    pub meta_interval: Duration,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_QUEUE_SIZE,
+            policy: OverflowPolicy::Block,
+            spill_path: None,
+            meta_interval: Duration::from_secs(DEFAULT_META_INTERVAL_SECS),
+        }
+    }
+}
+
+/// Counters reported in meta lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StreamStats {
+    /// Lines waiting in memory
+    pub queued: usize,
+    /// Lines discarded by `drop-oldest`
+    pub dropped: u64,
+    /// Lines written to the spill file
+    pub spilled: u64,
+}
+
+impl StreamStats {
+    /// Meta line printed between regular lines
+    pub fn meta_line(&self) -> String {
+        json!({
+            "meta": "stream",
+            "dropped": self.dropped,
+            "spilled": self.spilled,
+            "queued": self.queued,
+        })
+        .to_string()
+    }
+}
+
+/// Spill file with separate append and read handles
+#[derive(Debug)]
+struct Spill {
+    path: PathBuf,
+    writer: File,
+    reader: BufReader<File>,
+    /// Lines written but not yet read back
+    pending: u64,
+}
+
+impl Spill {
+    fn open(path: PathBuf) -> io::Result<Self> {
+        let writer = File::options()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&path)?;
+        let reader = BufReader::new(File::open(&path)?);
+        Ok(Self {
+            path,
+            writer,
+            reader,
+            pending: 0,
+        })
+    }
+
+    fn append(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.writer, "{}", line)?;
+        self.pending += 1;
+        Ok(())
+    }
+
+    fn read(&mut self) -> io::Result<Option<String>> {
+        if self.pending == 0 {
+            return Ok(None);
+        }
+        let mut line = String::new();
+        self.reader.read_line(&mut line)?;
+        self.pending -= 1;
+        if self.pending == 0 {
+            // Everything was replayed: start over with an empty file
+            self.writer.set_len(0)?;
+            self.writer.seek(SeekFrom::Start(0))?;
+            self.reader.seek(SeekFrom::Start(0))?;
+        }
+        Ok(Some(line.trim_end_matches('\n').to_string()))
+    }
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Queue contents, guarded by the mutex in [`Shared`]
+#[derive(Debug)]
+struct Buffer {
+    lines: VecDeque<String>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    spill_path: PathBuf,
+    spill: Option<Spill>,
+    dropped: u64,
+    spilled: u64,
+    /// Counters of the last meta line
+    reported: (u64, u64),
+    closed: bool,
+    /// Set when the writer stopped (e.g. broken pipe)
+    failed: Option<String>,
+}
+
+impl Buffer {
+    fn new(config: &StreamConfig) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            capacity: config.capacity.max(1),
+            policy: config.policy,
+            spill_path: config.spill_path.clone().unwrap_or_else(|| {
+                std::env::temp_dir().join(format!("slack-rs-spill-{}.ndjson", std::process::id()))
+            }),
+            spill: None,
+            dropped: 0,
+            spilled: 0,
+            reported: (0, 0),
+            closed: false,
+            failed: None,
+        }
+    }
+
+    fn spill_pending(&self) -> bool {
+        self.spill.as_ref().is_some_and(|s| s.pending > 0)
+    }
+
+    /// Queue a line; gives it back when the caller has to wait (`block`)
+    fn try_push(&mut self, line: String) -> io::Result<Option<String>> {
+        // Keep order: once spilling started, new lines follow the spilled ones
+        if self.spill_pending() || self.lines.len() >= self.capacity {
+            match self.policy {
+                OverflowPolicy::Block => return Ok(Some(line)),
+                OverflowPolicy::DropOldest => {
+                    self.lines.pop_front();
+                    self.dropped += 1;
+                }
+                OverflowPolicy::Spill => {
+                    if self.spill.is_none() {
+                        self.spill = Some(Spill::open(self.spill_path.clone())?);
+                    }
+                    if let Some(spill) = self.spill.as_mut() {
+                        spill.append(&line)?;
+                    }
+                    self.spilled += 1;
+                    return Ok(None);
+                }
+            }
+        }
+        self.lines.push_back(line);
+        Ok(None)
+    }
+
+    /// Next line in order: memory first, then the spill file
+    fn pop(&mut self) -> io::Result<Option<String>> {
+        if let Some(line) = self.lines.pop_front() {
+            return Ok(Some(line));
+        }
+        match self.spill.as_mut() {
+            Some(spill) => spill.read(),
+            None => Ok(None),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lines.is_empty() && !self.spill_pending()
+    }
+
+    fn stats(&self) -> StreamStats {
+        StreamStats {
+            queued: self.lines.len(),
+            dropped: self.dropped,
+            spilled: self.spilled,
+        }
+    }
+
+    /// Meta line if lines were dropped or spilled since the last one
+    fn take_report(&mut self) -> Option<String> {
+        let counters = (self.dropped, self.spilled);
+        if counters == self.reported {
+            return None;
+        }
+        self.reported = counters;
+        Some(self.stats().meta_line())
+    }
+}
+
+#[derive(Debug)]
+struct Shared {
+    buffer: Mutex<Buffer>,
+    /// Signalled when a line was queued or the queue closed
+    available: Condvar,
+    /// Signalled when a line was taken or the writer stopped
+    space: Condvar,
+}
+
+/// Bounded line queue drained to a sink by a writer thread
+#[derive(Debug)]
+pub struct OutputQueue {
+    shared: Arc<Shared>,
+    writer: Option<JoinHandle<()>>,
+}
+
+impl OutputQueue {
+    /// Start a queue writing to `sink`
+    pub fn start<W>(config: StreamConfig, sink: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            buffer: Mutex::new(Buffer::new(&config)),
+            available: Condvar::new(),
+            space: Condvar::new(),
+        });
+        let writer_shared = Arc::clone(&shared);
+        let writer = std::thread::spawn(move || {
+            if let Err(e) = run_writer(&writer_shared, sink, config.meta_interval) {
+                let mut buffer = writer_shared.buffer.lock().unwrap();
+                buffer.failed = Some(format!("Failed to write output: {}", e));
+                buffer.closed = true;
+                writer_shared.space.notify_all();
+            }
+        });
+        Self {
+            shared,
+            writer: Some(writer),
+        }
+    }
+
+    /// Start a queue writing to stdout
+    pub fn stdout(config: StreamConfig) -> Self {
+        Self::start(config, io::stdout())
+    }
+
+    /// Queue a line, applying the overflow policy when full
+    ///
+    /// With `block`, this waits for the writer thread.
+    pub fn push(&self, line: String) -> Result<(), String> {
+        let mut buffer = self.shared.buffer.lock().unwrap();
+        let mut line = line;
+        loop {
+            if let Some(e) = &buffer.failed {
+                return Err(e.clone());
+            }
+            match buffer
+                .try_push(line)
+                .map_err(|e| format!("Failed to spill output: {}", e))?
+            {
+                None => break,
+                Some(rejected) => {
+                    line = rejected;
+                    buffer = self.shared.space.wait(buffer).unwrap();
+                }
+            }
+        }
+        self.shared.available.notify_one();
+        Ok(())
+    }
+
+    /// Current counters
+    pub fn stats(&self) -> StreamStats {
+        self.shared.buffer.lock().unwrap().stats()
+    }
+
+    /// Write out everything still queued and stop the writer
+    pub fn finish(mut self) -> Result<(), String> {
+        self.close();
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+        match self.shared.buffer.lock().unwrap().failed.take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn close(&self) {
+        self.shared.buffer.lock().unwrap().closed = true;
+        self.shared.available.notify_all();
+    }
+}
+
+impl Drop for OutputQueue {
+    fn drop(&mut self) {
+        if let Some(writer) = self.writer.take() {
+            self.close();
+            let _ = writer.join();
+        }
+    }
+}
+
+fn run_writer<W: Write>(shared: &Shared, mut sink: W, meta_interval: Duration) -> io::Result<()> {
+    let mut next_meta = Instant::now() + meta_interval;
+    loop {
+        let (line, meta, done) = {
+            let mut buffer = shared.buffer.lock().unwrap();
+            while buffer.is_empty() && !buffer.closed {
+                let now = Instant::now();
+                if now >= next_meta {
+                    break;
+                }
+                buffer = shared
+                    .available
+                    .wait_timeout(buffer, next_meta - now)
+                    .unwrap()
+                    .0;
+            }
+            let done = buffer.closed && buffer.is_empty();
+            let meta = if done || Instant::now() >= next_meta {
+                next_meta = Instant::now() + meta_interval;
+                buffer.take_report()
+            } else {
+                None
+            };
+            let line = buffer.pop()?;
+            if line.is_some() {
+                shared.space.notify_one();
+            }
+            (line, meta, done)
+        };
+
+        if let Some(meta) = meta {
+            writeln!(sink, "{}", meta)?;
+        }
+        if let Some(line) = line {
+            writeln!(sink, "{}", line)?;
+        }
+        sink.flush()?;
+        if done {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(policy: OverflowPolicy, spill_path: Option<PathBuf>) -> StreamConfig {
+        StreamConfig {
+            capacity: 2,
+            policy,
+            spill_path,
+            ..StreamConfig::default()
+        }
+    }
+
+    fn drain(buffer: &mut Buffer) -> Vec<String> {
+        std::iter::from_fn(|| buffer.pop().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_buffer_overflow_policies() {
+        assert_eq!(
+            OverflowPolicy::parse("drop-oldest").unwrap(),
+            OverflowPolicy::DropOldest
+        );
+        assert!(OverflowPolicy::parse("drop").is_err());
+
+        let mut block = Buffer::new(&config(OverflowPolicy::Block, None));
+        assert_eq!(block.try_push("1".into()).unwrap(), None);
+        assert_eq!(block.try_push("2".into()).unwrap(), None);
+        assert_eq!(block.try_push("3".into()).unwrap(), Some("3".into()));
+
+        let mut dropping = Buffer::new(&config(OverflowPolicy::DropOldest, None));
+        for line in ["1", "2", "3", "4"] {
+            dropping.try_push(line.into()).unwrap();
+        }
+        assert_eq!(dropping.stats().dropped, 2);
+        assert!(dropping.take_report().unwrap().contains("\"dropped\":2"));
+        assert_eq!(dropping.take_report(), None);
+        assert_eq!(drain(&mut dropping), vec!["3", "4"]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("spill.ndjson");
+        let mut spill = Buffer::new(&config(OverflowPolicy::Spill, Some(path.clone())));
+        for line in ["1", "2", "3", "4"] {
+            spill.try_push(line.into()).unwrap();
+        }
+        assert_eq!(spill.pop().unwrap().as_deref(), Some("1"));
+        // Room in memory again, but "5" must follow the spilled lines
+        spill.try_push("5".into()).unwrap();
+        assert_eq!(spill.stats().spilled, 3);
+        assert_eq!(drain(&mut spill), vec!["2", "3", "4", "5"]);
+        assert!(spill.is_empty());
+        drop(spill);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_output_queue_writes_in_order() {
+        #[derive(Clone, Default)]
+        struct Sink(Arc<Mutex<Vec<u8>>>);
+        impl Write for Sink {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let sink = Sink::default();
+        let queue = OutputQueue::start(StreamConfig::default(), sink.clone());
+        for i in 0..100 {
+            queue.push(format!("{{\"n\":{}}}", i)).unwrap();
+        }
+        queue.finish().unwrap();
+
+        let output = String::from_utf8(sink.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 100);
+        assert_eq!(lines[0], "{\"n\":0}");
+        assert_eq!(lines[99], "{\"n\":99}");
+    }
+}
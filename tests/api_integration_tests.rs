@@ -385,3 +385,72 @@ async fn test_api_call_conversations_replies_with_get() {
     // Verify mock was called (this ensures query params were sent correctly)
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_api_call_paginate_merges_pages() {
+    let server = MockServer::start();
+
+    let first = server.mock(|when, then| {
+        when.method(GET)
+            .path("/conversations.list")
+            .query_param_missing("cursor");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({
+                "ok": true,
+                "channels": [{"id": "C1"}, {"id": "C2"}],
+                "response_metadata": {"next_cursor": "page2"}
+            }));
+    });
+    let second = server.mock(|when, then| {
+        when.method(GET)
+            .path("/conversations.list")
+            .query_param("cursor", "page2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({
+                "ok": true,
+                "channels": [{"id": "C3"}],
+                "response_metadata": {"next_cursor": ""}
+            }));
+    });
+
+    let config = ApiClientConfig {
+        base_url: server.base_url(),
+        max_retries: 0,
+        initial_backoff_ms: 10,
+        max_backoff_ms: 10,
+    };
+    let client = ApiClient::with_config(config);
+
+    let args_vec = vec![
+        "conversations.list".to_string(),
+        "--get".to_string(),
+        "--all".to_string(),
+        "limit=2".to_string(),
+    ];
+    let args = ApiCallArgs::parse(&args_vec).unwrap();
+    assert!(args.paginate);
+
+    let context = ApiCallContext {
+        profile_name: Some("default".to_string()),
+        team_id: "T123ABC".to_string(),
+        user_id: "U456DEF".to_string(),
+    };
+
+    let response = execute_api_call(&client, &args, "test-token", &context, "bot", "api call")
+        .await
+        .unwrap();
+
+    let ids: Vec<&str> = response.response["channels"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["C1", "C2", "C3"]);
+    assert_eq!(response.response["response_metadata"]["next_cursor"], "");
+
+    first.assert();
+    second.assert();
+}
@@ -73,6 +73,8 @@ fn test_profile_with_token_storage() {
         bot_scopes: None,
         user_scopes: None,
         default_token_type: None,
+        output: None,
+        last_auth_test_at: None,
     };
 
     // Add profile
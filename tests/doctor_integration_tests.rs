@@ -32,6 +32,8 @@ fn setup_test_env() -> (TempDir, String) {
         bot_scopes: None,
         user_scopes: None,
         default_token_type: None,
+        output: None,
+        last_auth_test_at: None,
     };
     config.set("test_profile".to_string(), profile);
     save_config(&config_path, &config).unwrap();
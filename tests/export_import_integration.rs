@@ -31,6 +31,8 @@ fn test_export_import_single_profile() {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         },
     );
     save_config(&config_path, &config).unwrap();
@@ -110,6 +112,7 @@ fn test_import_rejects_empty_passphrase() {
         force: false,
         dry_run: false,
         json: false,
+        ..Default::default()
     };
 
     let result = import_profiles(&token_store, &options);
@@ -275,6 +278,7 @@ fn test_import_result_tracking_new_profile() {
         force: false,
         dry_run: false,
         json: false,
+        ..Default::default()
     };
 
     // Note: This will use default_config_path, so we can't fully test without mocking
@@ -291,16 +295,19 @@ fn test_import_result_json_serialization() {
         profiles: vec![
             ProfileImportResult {
                 profile_name: "profile1".to_string(),
+                source_name: None,
                 action: ImportAction::Updated,
                 reason: "New profile imported".to_string(),
             },
             ProfileImportResult {
                 profile_name: "profile2".to_string(),
+                source_name: None,
                 action: ImportAction::Skipped,
                 reason: "Skipped due to conflict".to_string(),
             },
             ProfileImportResult {
                 profile_name: "profile3".to_string(),
+                source_name: None,
                 action: ImportAction::Overwritten,
                 reason: "Overwritten with --force".to_string(),
             },
@@ -383,6 +390,8 @@ fn test_import_team_id_conflict_without_force_disabled() {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         },
     );
     save_config(&config_path, &initial_config).unwrap();
@@ -440,6 +449,7 @@ fn test_import_team_id_conflict_without_force_disabled() {
         force: false,
         dry_run: false,
         json: false,
+        ..Default::default()
     };
 
     let result = import_profiles(&token_store, &options).unwrap();
@@ -492,6 +502,8 @@ fn test_import_team_id_conflict_with_force() {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         },
     );
     save_config(&config_path, &initial_config).unwrap();
@@ -549,6 +561,7 @@ fn test_import_team_id_conflict_with_force() {
         force: true,
         dry_run: false,
         json: false,
+        ..Default::default()
     };
 
     let result = import_profiles(&token_store, &options).unwrap();
@@ -601,6 +614,8 @@ fn test_import_same_name_different_team_id_without_force() {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         },
     );
     save_config(&config_path, &initial_config).unwrap();
@@ -658,6 +673,7 @@ fn test_import_same_name_different_team_id_without_force() {
         force: false,
         dry_run: false,
         json: false,
+        ..Default::default()
     };
 
     let result = import_profiles(&token_store, &options).unwrap();
@@ -709,6 +725,8 @@ fn test_import_same_name_different_team_id_with_force() {
             bot_scopes: None,
             user_scopes: None,
             default_token_type: None,
+            output: None,
+            last_auth_test_at: None,
         },
     );
     save_config(&config_path, &initial_config).unwrap();
@@ -766,6 +784,7 @@ fn test_import_same_name_different_team_id_with_force() {
         force: true,
         dry_run: false,
         json: false,
+        ..Default::default()
     };
 
     let result = import_profiles(&token_store, &options).unwrap();
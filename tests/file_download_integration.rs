@@ -865,3 +865,80 @@ async fn test_file_download_image_both_paths() {
         "Both paths should download the same content"
     );
 }
+
+/// Test that an HTML login page is rejected even when served as a binary type
+#[tokio::test]
+async fn test_file_download_sniffs_html_body_and_removes_partial_file() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("report.pdf");
+
+    Mock::given(method("GET"))
+        .and(path("/files-pri/report.pdf"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(b"<!DOCTYPE html><html><title>Slack</title>".to_vec())
+                .insert_header("Content-Type", "application/octet-stream"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = ApiClient::new_with_base_url("test_token".to_string(), mock_server.uri());
+    let result = commands::file_download(
+        &client,
+        None,
+        Some(format!("{}/files-pri/report.pdf", mock_server.uri())),
+        Some(output_path.to_str().unwrap().to_string()),
+    )
+    .await;
+
+    let error_msg = result.unwrap_err().to_string();
+    assert!(error_msg.contains("HTML instead of file"), "{}", error_msg);
+    assert!(!output_path.exists(), "partial file should be removed");
+}
+
+/// Test that the body is streamed to a directory target with the file's name
+#[tokio::test]
+async fn test_file_download_streams_into_directory() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = TempDir::new().unwrap();
+    let file_content: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+
+    Mock::given(method("POST"))
+        .and(path("/files.info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "file": {
+                "id": "F1",
+                "name": "data.bin",
+                "url_private_download": format!("{}/files-pri/data.bin", mock_server.uri())
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files-pri/data.bin"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(file_content.clone())
+                .insert_header("Content-Type", "application/octet-stream"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = ApiClient::new_with_base_url("test_token".to_string(), mock_server.uri());
+    let result = commands::file_download(
+        &client,
+        Some("F1".to_string()),
+        None,
+        Some(temp_dir.path().to_str().unwrap().to_string()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result["size"], file_content.len());
+    assert_eq!(
+        std::fs::read(temp_dir.path().join("data.bin")).unwrap(),
+        file_content
+    );
+}
@@ -86,6 +86,9 @@ fn test_command_meta_serialization() {
         token_type: Some("bot".to_string()),
         idempotency_key: None,
         idempotency_status: None,
+        follow_ups: None,
+        consistency: None,
+        next_cursor: None,
     };
 
     let json = serde_json::to_string(&meta).unwrap();
@@ -121,6 +124,9 @@ fn test_different_commands_have_different_command_names() {
         token_type: Some("bot".to_string()),
         idempotency_key: None,
         idempotency_status: None,
+        follow_ups: None,
+        consistency: None,
+        next_cursor: None,
     };
 
     let api_json = serde_json::to_value(&api_meta).unwrap();
@@ -198,3 +204,28 @@ fn test_command_response_with_user_token_type() {
     let json = serde_json::to_value(&response).unwrap();
     assert_eq!(json["meta"]["token_type"], "user");
 }
+
+#[test]
+fn test_command_response_next_cursor() {
+    let with_more = CommandResponse::new(
+        json!({"ok": true, "messages": [], "response_metadata": {"next_cursor": "bmV4dA=="}}),
+        Some("default".to_string()),
+        "T123".to_string(),
+        "U123".to_string(),
+        "conversations.history".to_string(),
+        "conv history".to_string(),
+    );
+    let json = serde_json::to_value(&with_more).unwrap();
+    assert_eq!(json["meta"]["next_cursor"], "bmV4dA==");
+
+    let last_page = CommandResponse::new(
+        json!({"ok": true, "messages": [], "response_metadata": {"next_cursor": ""}}),
+        Some("default".to_string()),
+        "T123".to_string(),
+        "U123".to_string(),
+        "conversations.history".to_string(),
+        "conv history".to_string(),
+    );
+    let json = serde_json::to_value(&last_page).unwrap();
+    assert!(json["meta"].get("next_cursor").is_none());
+}
@@ -0,0 +1,148 @@
+//! Snapshot tests for output contracts
+//!
+//! Downstream scripts parse the envelope JSON, the table/tsv/csv/jsonl
+//! formatters, error guidance and the introspection JSON, so changes to any
+//! of them must be deliberate. Snapshots live in `tests/snapshots/`; review
+//! changes with `cargo insta review` (or `INSTA_UPDATE=always cargo test`).
+
+use serde_json::{json, Value};
+use slack_rs::api::guidance::format_error_guidance_in;
+use slack_rs::api::CommandResponse;
+use slack_rs::cli::{generate_commands_list, generate_help};
+use slack_rs::commands::output::format_output;
+use slack_rs::commands::OutputFormat;
+use slack_rs::i18n::Language;
+
+fn conv_list_response() -> Value {
+    json!({
+        "ok": true,
+        "channels": [
+            {"id": "C001", "name": "general", "is_private": false, "is_member": true, "num_members": 42},
+            {"id": "C002", "name": "dev\tops", "is_private": true, "is_member": false, "num_members": 7},
+            {"id": "C003", "name": "random, misc", "is_private": false, "is_member": true}
+        ],
+        "response_metadata": {"next_cursor": "dGVhbTpDMDA0"}
+    })
+}
+
+fn search_response() -> Value {
+    json!({
+        "ok": true,
+        "messages": {
+            "total": 2,
+            "matches": [
+                {"ts": "1700000000.000100", "channel": {"id": "C001", "name": "general"}, "username": "alice", "text": "deploy done"},
+                {"ts": "1700000100.000200", "channel": {"id": "C002", "name": "dev"}, "username": "bob", "text": "line one\nline two"}
+            ]
+        }
+    })
+}
+
+#[test]
+fn snapshot_envelope() {
+    let response = CommandResponse::new(
+        conv_list_response(),
+        Some("acme".to_string()),
+        "T123ABC".to_string(),
+        "U456DEF".to_string(),
+        "conversations.list".to_string(),
+        "conv list".to_string(),
+    );
+    insta::assert_json_snapshot!("envelope_conv_list", response);
+
+    let response = CommandResponse::with_token_type(
+        json!({"ok": true, "channel": "C001", "ts": "1700000000.000100"}),
+        Some("acme".to_string()),
+        "T123ABC".to_string(),
+        "U456DEF".to_string(),
+        "chat.postMessage".to_string(),
+        "msg post".to_string(),
+        Some("bot".to_string()),
+    )
+    .with_idempotency("acme:post-1".to_string(), "executed".to_string());
+    insta::assert_json_snapshot!("envelope_msg_post", response);
+
+    let response = CommandResponse::new(
+        json!({"ok": false, "error": "channel_not_found"}),
+        Some("acme".to_string()),
+        "T123ABC".to_string(),
+        "U456DEF".to_string(),
+        "conversations.history".to_string(),
+        "conv history".to_string(),
+    );
+    insta::assert_json_snapshot!("envelope_error", response);
+}
+
+#[test]
+fn snapshot_formatters() {
+    let conv = conv_list_response();
+    let search = search_response();
+    for (name, format) in [
+        ("table", OutputFormat::Table),
+        ("tsv", OutputFormat::Tsv),
+        ("csv", OutputFormat::Csv),
+        ("jsonl", OutputFormat::Jsonl),
+    ] {
+        insta::assert_snapshot!(
+            format!("conv_list_{}", name),
+            format_output(&conv, "conv list", format, None).unwrap()
+        );
+        insta::assert_snapshot!(
+            format!("search_{}", name),
+            format_output(&search, "search", format, None).unwrap()
+        );
+    }
+
+    let columns = vec!["ts".to_string(), "channel.id".to_string()];
+    insta::assert_snapshot!(
+        "search_table_columns",
+        format_output(&search, "search", OutputFormat::Table, Some(&columns)).unwrap()
+    );
+    insta::assert_snapshot!(
+        "msg_post_table",
+        format_output(
+            &json!({"ok": true, "channel": "C001", "ts": "1700000000.000100", "message": {"text": "hi"}}),
+            "msg post",
+            OutputFormat::Table,
+            None
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn snapshot_error_guidance() {
+    let codes = [
+        "not_allowed_token_type",
+        "missing_scope",
+        "invalid_auth",
+        "token_revoked",
+        "token_expired",
+        "not_authed",
+        "account_inactive",
+        "no_permission",
+        "org_login_required",
+        "ekm_access_denied",
+        "channel_not_found",
+        "method_not_supported_for_channel_type",
+    ];
+    for (lang_name, lang) in [("en", Language::English), ("ja", Language::Japanese)] {
+        let guidance: String = codes
+            .iter()
+            .map(|code| {
+                format_error_guidance_in(code, lang)
+                    .unwrap_or_else(|| panic!("no guidance for {}", code))
+            })
+            .collect();
+        insta::assert_snapshot!(format!("guidance_{}", lang_name), guidance);
+    }
+    assert!(format_error_guidance_in("not_a_real_error", Language::English).is_none());
+}
+
+#[test]
+fn snapshot_introspection() {
+    let list = generate_commands_list();
+    insta::assert_json_snapshot!("commands_list", list);
+
+    insta::assert_json_snapshot!("help_msg_post", generate_help("msg post").unwrap());
+}
@@ -0,0 +1,95 @@
+//! Property tests for argument and filter parsers
+//!
+//! Agents generate arguments programmatically, so the parsers must neither
+//! panic on arbitrary input nor lose information on the happy path. The
+//! same parsers are exercised by the cargo-fuzz targets in `fuzz/`.
+
+use proptest::prelude::*;
+use slack_rs::api::ApiCallArgs;
+use slack_rs::commands::schedule::parse_duration;
+use slack_rs::commands::thread_ref::parse_permalink;
+use slack_rs::commands::{ConversationFilter, ThreadRef};
+use std::collections::HashMap;
+
+fn conversation_filter() -> impl Strategy<Value = ConversationFilter> {
+    prop_oneof![
+        any::<String>().prop_map(ConversationFilter::Name),
+        any::<bool>().prop_map(ConversationFilter::IsMember),
+        any::<bool>().prop_map(ConversationFilter::IsPrivate),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn api_call_args_parse_never_panics(args in prop::collection::vec(any::<String>(), 0..8)) {
+        let _ = ApiCallArgs::parse(&args);
+    }
+
+    #[test]
+    fn api_call_args_params_round_trip(
+        method in "[a-z]{1,12}\\.[a-zA-Z]{1,16}",
+        params in prop::collection::hash_map("[a-z_]{1,12}", any::<String>(), 0..6),
+    ) {
+        let mut args = vec![method.clone()];
+        args.extend(params.iter().map(|(k, v)| format!("{}={}", k, v)));
+
+        let parsed = ApiCallArgs::parse(&args).unwrap();
+        prop_assert_eq!(parsed.method, method);
+        prop_assert_eq!(parsed.params, params.into_iter().collect::<HashMap<_, _>>());
+    }
+
+    #[test]
+    fn conversation_filter_round_trip(filter in conversation_filter()) {
+        let parsed = ConversationFilter::parse(&filter.to_string()).unwrap();
+        prop_assert_eq!(parsed, filter);
+    }
+
+    #[test]
+    fn conversation_filter_parse_is_stable(input in any::<String>()) {
+        if let Ok(filter) = ConversationFilter::parse(&input) {
+            prop_assert_eq!(ConversationFilter::parse(&filter.to_string()).unwrap(), filter);
+        }
+    }
+
+    #[test]
+    fn duration_round_trip(days in 0i64..400, hours in 0i64..24, minutes in 0i64..60, seconds in 0i64..60) {
+        let expected = days * 86400 + hours * 3600 + minutes * 60 + seconds;
+        prop_assume!(expected > 0);
+        let spec = format!("{}d{}h{}m{}s", days, hours, minutes, seconds);
+        prop_assert_eq!(parse_duration(&spec).unwrap(), expected);
+    }
+
+    #[test]
+    fn duration_parse_never_panics(input in any::<String>()) {
+        if let Ok(seconds) = parse_duration(&input) {
+            prop_assert!(seconds > 0);
+        }
+    }
+
+    #[test]
+    fn permalink_round_trip(
+        channel in "[CGD][A-Z0-9]{8,10}",
+        secs in 1u64..10_000_000_000,
+        micros in 0u32..1_000_000,
+        thread in proptest::option::of((1u64..10_000_000_000, 0u32..1_000_000)),
+    ) {
+        let ts = format!("{}.{:06}", secs, micros);
+        let mut url = format!(
+            "https://acme.slack.com/archives/{}/p{}{:06}",
+            channel, secs, micros
+        );
+        let thread_ts = thread.map(|(s, m)| format!("{}.{:06}", s, m));
+        if let Some(thread_ts) = &thread_ts {
+            url.push_str(&format!("?thread_ts={}&cid={}", thread_ts, channel));
+        }
+
+        let expected = ThreadRef::Permalink { channel, ts, thread_ts };
+        prop_assert_eq!(parse_permalink(&url), Some(expected.clone()));
+        prop_assert_eq!(ThreadRef::parse(&url).unwrap(), expected);
+    }
+
+    #[test]
+    fn thread_ref_parse_never_panics(input in any::<String>()) {
+        let _ = ThreadRef::parse(&input);
+    }
+}
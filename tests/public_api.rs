@@ -0,0 +1,138 @@
+//! Public API snapshot of the semver-covered modules
+//!
+//! Lists every public item of `prelude`, `api`, `profile`, `idempotency`,
+//! `socket`, `stream` and `query` (the modules the crate docs promise to
+//! keep stable) with its kind, the inherent methods of public types, and
+//! the prelude re-exports. The list is built from rustdoc JSON, the input
+//! `cargo-public-api` works from, and compared with
+//! `tests/snapshots/public_api__public_api.snap`.
+//!
+//! A diff means the library surface changed. Additions can be accepted with
+//! `INSTA_UPDATE=always`; a removed or renamed line breaks library users and
+//! needs a major release.
+//!
+//! Rustdoc JSON needs a nightly toolchain, so the test is ignored by
+//! default:
+//!
+//! ```text
+//! cargo test --test public_api -- --ignored
+//! ```
+
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command;
+
+fn rustdoc_json() -> Value {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let target_dir = Path::new(manifest_dir).join("target").join("public-api");
+    let status = Command::new("cargo")
+        .current_dir(manifest_dir)
+        .args(["+nightly", "rustdoc", "--lib", "--quiet", "--target-dir"])
+        .arg(&target_dir)
+        .args(["--", "-Z", "unstable-options", "--output-format", "json"])
+        .status()
+        .expect("failed to run cargo +nightly rustdoc");
+    assert!(
+        status.success(),
+        "rustdoc JSON generation failed (is a nightly toolchain installed?)"
+    );
+    let json = std::fs::read_to_string(target_dir.join("doc").join("slack_rs.json"))
+        .expect("rustdoc JSON not found");
+    serde_json::from_str(&json).expect("invalid rustdoc JSON")
+}
+
+/// Index key of an item ID (numeric in recent formats, a string before)
+fn id_key(id: &Value) -> String {
+    id.as_u64()
+        .map(|n| n.to_string())
+        .or_else(|| id.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Kind of an index item (`function`, `struct`, `use`, ...)
+fn inner_kind(item: &Value) -> &str {
+    item["inner"]
+        .as_object()
+        .and_then(|inner| inner.keys().next())
+        .map(String::as_str)
+        .unwrap_or("unknown")
+}
+
+fn public_api(doc: &Value) -> Vec<String> {
+    let index = doc["index"].as_object().expect("index");
+    let paths = doc["paths"].as_object().expect("paths");
+    let local_path = |id: &Value| {
+        paths
+            .get(&id_key(id))
+            .filter(|entry| entry["crate_id"] == 0)
+            .and_then(|entry| entry["path"].as_array())
+            .map(|segments| {
+                segments
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join("::")
+            })
+    };
+
+    let mut items = BTreeSet::new();
+    for (id, entry) in paths {
+        if entry["crate_id"] != 0 {
+            continue;
+        }
+        if let (Some(kind), Some(path)) = (entry["kind"].as_str(), local_path(&id.clone().into())) {
+            items.insert(format!("{} {}", kind, path));
+        }
+    }
+
+    for item in index.values() {
+        // Inherent methods and associated constants of public types
+        if let Some(imp) = item["inner"].get("impl") {
+            if !imp["trait"].is_null() || !imp["blanket_impl"].is_null() {
+                continue;
+            }
+            let Some(type_path) = local_path(&imp["for"]["resolved_path"]["id"]) else {
+                continue;
+            };
+            for member in imp["items"].as_array().into_iter().flatten() {
+                let Some(member) = index.get(&id_key(member)) else {
+                    continue;
+                };
+                if member["visibility"] == "public" {
+                    if let Some(name) = member["name"].as_str() {
+                        items.insert(format!("{} {}::{}", inner_kind(member), type_path, name));
+                    }
+                }
+            }
+        }
+        // Prelude re-exports
+        if item["name"] == "prelude" {
+            for member in item["inner"]["module"]["items"]
+                .as_array()
+                .into_iter()
+                .flatten()
+            {
+                let Some(reexport) = index
+                    .get(&id_key(member))
+                    .and_then(|m| m["inner"].get("use"))
+                else {
+                    continue;
+                };
+                items.insert(format!(
+                    "use slack_rs::prelude::{} = {}",
+                    reexport["name"].as_str().unwrap_or_default(),
+                    reexport["source"].as_str().unwrap_or_default()
+                ));
+            }
+        }
+    }
+    items.into_iter().collect()
+}
+
+#[test]
+#[ignore = "needs a nightly toolchain for rustdoc JSON; run with --ignored"]
+fn test_public_api() {
+    let doc = rustdoc_json();
+    insta::assert_snapshot!("public_api", public_api(&doc).join("\n"));
+}
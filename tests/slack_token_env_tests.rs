@@ -27,6 +27,8 @@ fn setup_test_profile() -> (TempDir, String) {
         bot_scopes: None,
         user_scopes: None,
         default_token_type: None,
+        output: None,
+        last_auth_test_at: None,
     };
     config.set("default".to_string(), profile);
     save_config(&config_path, &config).unwrap();